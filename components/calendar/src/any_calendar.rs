@@ -16,6 +16,7 @@ use icu_locale_core::subtags::region;
 use icu_provider::prelude::*;
 
 use core::fmt;
+use tinystr::{tinystr, TinyStr16};
 
 define_preferences!(
     /// The preferences for calendars formatting.
@@ -85,6 +86,10 @@ pub enum AnyCalendar {
     Gregorian(Gregorian),
     /// A [`Hebrew`] calendar
     Hebrew(Hebrew),
+    /// A [`HinduLunisolar`] calendar
+    HinduLunisolar(HinduLunisolar),
+    /// A [`HinduSolar`] calendar
+    HinduSolar(HinduSolar),
     /// An [`Indian`] calendar
     Indian(Indian),
     /// A [`HijriTabular`] calendar
@@ -99,10 +104,16 @@ pub enum AnyCalendar {
     Japanese(Japanese),
     /// A [`JapaneseExtended`] calendar
     JapaneseExtended(JapaneseExtended),
+    /// A [`Julian`] calendar
+    Julian(Julian),
+    /// A [`JulianGregorian`] calendar
+    JulianGregorian(JulianGregorian),
     /// A [`Persian`] calendar
     Persian(Persian),
     /// A [`Roc`] calendar
     Roc(Roc),
+    /// A [`Vietnamese`](chinese::Vietnamese) calendar
+    Vietnamese(LunarChinese<chinese::Vietnamese>),
 }
 
 // TODO(#3469): Decide on the best way to implement Ord.
@@ -124,6 +135,10 @@ pub enum AnyDateInner {
     Gregorian(<Gregorian as Calendar>::DateInner),
     /// A date for a [`Hebrew`] calendar
     Hebrew(<Hebrew as Calendar>::DateInner),
+    /// A date for a [`HinduLunisolar`] calendar
+    HinduLunisolar(<HinduLunisolar as Calendar>::DateInner),
+    /// A date for a [`HinduSolar`] calendar
+    HinduSolar(<HinduSolar as Calendar>::DateInner),
     /// A date for an [`Indian`] calendar
     Indian(<Indian as Calendar>::DateInner),
     /// A date for a [`HijriTabular`] calendar
@@ -141,10 +156,16 @@ pub enum AnyDateInner {
     Japanese(<Japanese as Calendar>::DateInner),
     /// A date for a [`JapaneseExtended`] calendar
     JapaneseExtended(<JapaneseExtended as Calendar>::DateInner),
+    /// A date for a [`Julian`] calendar
+    Julian(<Julian as Calendar>::DateInner),
+    /// A date for a [`JulianGregorian`] calendar
+    JulianGregorian(<JulianGregorian as Calendar>::DateInner),
     /// A date for a [`Persian`] calendar
     Persian(<Persian as Calendar>::DateInner),
     /// A date for a [`Roc`] calendar
     Roc(<Roc as Calendar>::DateInner),
+    /// A date for a [`Vietnamese`](chinese::Vietnamese) calendar
+    Vietnamese(<LunarChinese<chinese::Vietnamese> as Calendar>::DateInner),
 }
 
 macro_rules! match_cal_and_date {
@@ -157,6 +178,13 @@ macro_rules! match_cal_and_date {
             (&Self::Ethiopian(ref $cal_matched), &AnyDateInner::Ethiopian(ref $date_matched)) => $e,
             (&Self::Gregorian(ref $cal_matched), &AnyDateInner::Gregorian(ref $date_matched)) => $e,
             (&Self::Hebrew(ref $cal_matched), &AnyDateInner::Hebrew(ref $date_matched)) => $e,
+            (
+                &Self::HinduLunisolar(ref $cal_matched),
+                &AnyDateInner::HinduLunisolar(ref $date_matched),
+            ) => $e,
+            (&Self::HinduSolar(ref $cal_matched), &AnyDateInner::HinduSolar(ref $date_matched)) => {
+                $e
+            }
             (&Self::Indian(ref $cal_matched), &AnyDateInner::Indian(ref $date_matched)) => $e,
             (
                 &Self::HijriTabular(ref $cal_matched),
@@ -176,8 +204,16 @@ macro_rules! match_cal_and_date {
                 &Self::JapaneseExtended(ref $cal_matched),
                 &AnyDateInner::JapaneseExtended(ref $date_matched),
             ) => $e,
+            (&Self::Julian(ref $cal_matched), &AnyDateInner::Julian(ref $date_matched)) => $e,
+            (
+                &Self::JulianGregorian(ref $cal_matched),
+                &AnyDateInner::JulianGregorian(ref $date_matched),
+            ) => $e,
             (&Self::Persian(ref $cal_matched), &AnyDateInner::Persian(ref $date_matched)) => $e,
             (&Self::Roc(ref $cal_matched), &AnyDateInner::Roc(ref $date_matched)) => $e,
+            (&Self::Vietnamese(ref $cal_matched), &AnyDateInner::Vietnamese(ref $date_matched)) => {
+                $e
+            }
             _ => panic!(
                 "Found AnyCalendar with mixed calendar type {:?} and date type {:?}!",
                 $cal.kind().debug_name(),
@@ -197,6 +233,8 @@ macro_rules! match_cal {
             &Self::Ethiopian(ref $cal_matched) => AnyDateInner::Ethiopian($e),
             &Self::Gregorian(ref $cal_matched) => AnyDateInner::Gregorian($e),
             &Self::Hebrew(ref $cal_matched) => AnyDateInner::Hebrew($e),
+            &Self::HinduLunisolar(ref $cal_matched) => AnyDateInner::HinduLunisolar($e),
+            &Self::HinduSolar(ref $cal_matched) => AnyDateInner::HinduSolar($e),
             &Self::Indian(ref $cal_matched) => AnyDateInner::Indian($e),
             &Self::HijriSimulated(ref $cal_matched) => AnyDateInner::HijriSimulated($e),
             &Self::HijriTabular(ref $cal_matched) => AnyDateInner::HijriTabular($e, $cal_matched.0),
@@ -204,8 +242,11 @@ macro_rules! match_cal {
             &Self::Iso(ref $cal_matched) => AnyDateInner::Iso($e),
             &Self::Japanese(ref $cal_matched) => AnyDateInner::Japanese($e),
             &Self::JapaneseExtended(ref $cal_matched) => AnyDateInner::JapaneseExtended($e),
+            &Self::Julian(ref $cal_matched) => AnyDateInner::Julian($e),
+            &Self::JulianGregorian(ref $cal_matched) => AnyDateInner::JulianGregorian($e),
             &Self::Persian(ref $cal_matched) => AnyDateInner::Persian($e),
             &Self::Roc(ref $cal_matched) => AnyDateInner::Roc($e),
+            &Self::Vietnamese(ref $cal_matched) => AnyDateInner::Vietnamese($e),
         }
     };
 }
@@ -276,6 +317,12 @@ impl Calendar for AnyCalendar {
             (Self::Hebrew(c), AnyDateInner::Hebrew(ref mut d)) => {
                 c.offset_date(d, offset.cast_unit())
             }
+            (Self::HinduLunisolar(c), AnyDateInner::HinduLunisolar(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (Self::HinduSolar(c), AnyDateInner::HinduSolar(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
             (Self::Indian(c), AnyDateInner::Indian(ref mut d)) => {
                 c.offset_date(d, offset.cast_unit())
             }
@@ -297,10 +344,19 @@ impl Calendar for AnyCalendar {
             (Self::JapaneseExtended(c), AnyDateInner::JapaneseExtended(ref mut d)) => {
                 c.offset_date(d, offset.cast_unit())
             }
+            (Self::Julian(c), AnyDateInner::Julian(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (Self::JulianGregorian(c), AnyDateInner::JulianGregorian(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
             (Self::Persian(c), AnyDateInner::Persian(ref mut d)) => {
                 c.offset_date(d, offset.cast_unit())
             }
             (Self::Roc(c), AnyDateInner::Roc(ref mut d)) => c.offset_date(d, offset.cast_unit()),
+            (Self::Vietnamese(c), AnyDateInner::Vietnamese(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
             // This is only reached from misuse of from_raw, a semi-internal api
             #[expect(clippy::panic)]
             (_, d) => panic!(
@@ -376,6 +432,22 @@ impl Calendar for AnyCalendar {
             ) => c1
                 .until(d1, d2, c2, largest_unit, smallest_unit)
                 .cast_unit(),
+            (
+                Self::HinduLunisolar(c1),
+                Self::HinduLunisolar(c2),
+                AnyDateInner::HinduLunisolar(d1),
+                AnyDateInner::HinduLunisolar(d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                Self::HinduSolar(c1),
+                Self::HinduSolar(c2),
+                AnyDateInner::HinduSolar(d1),
+                AnyDateInner::HinduSolar(d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
             (
                 Self::Indian(c1),
                 Self::Indian(c2),
@@ -427,6 +499,22 @@ impl Calendar for AnyCalendar {
             ) => c1
                 .until(d1, d2, c2, largest_unit, smallest_unit)
                 .cast_unit(),
+            (
+                Self::Julian(c1),
+                Self::Julian(c2),
+                AnyDateInner::Julian(d1),
+                AnyDateInner::Julian(d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                Self::JulianGregorian(c1),
+                Self::JulianGregorian(c2),
+                AnyDateInner::JulianGregorian(d1),
+                AnyDateInner::JulianGregorian(d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
             (
                 Self::Persian(c1),
                 Self::Persian(c2),
@@ -438,6 +526,14 @@ impl Calendar for AnyCalendar {
             (Self::Roc(c1), Self::Roc(c2), AnyDateInner::Roc(d1), AnyDateInner::Roc(d2)) => c1
                 .until(d1, d2, c2, largest_unit, smallest_unit)
                 .cast_unit(),
+            (
+                Self::Vietnamese(c1),
+                Self::Vietnamese(c2),
+                AnyDateInner::Vietnamese(d1),
+                AnyDateInner::Vietnamese(d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
             _ => {
                 // attempt to convert
                 let iso = calendar2.to_iso(date2);
@@ -487,6 +583,11 @@ impl Calendar for AnyCalendar {
             AnyCalendarKind::EthiopianAmeteAlem => "AnyCalendar (Ethiopian, Amete Alem)",
             AnyCalendarKind::Gregorian => "AnyCalendar (Gregorian)",
             AnyCalendarKind::Hebrew => "AnyCalendar (Hebrew)",
+            AnyCalendarKind::HinduLunisolar => "AnyCalendar (Hindu lunisolar)",
+            AnyCalendarKind::HinduLunisolarPurnimanta => {
+                "AnyCalendar (Hindu lunisolar, pūrṇimānta)"
+            }
+            AnyCalendarKind::HinduSolar => "AnyCalendar (Hindu solar)",
             AnyCalendarKind::Indian => "AnyCalendar (Indian)",
             AnyCalendarKind::HijriTabularTypeIIFriday => {
                 "AnyCalendar (Hijri, tabular, type II leap years, Friday epoch)"
@@ -499,8 +600,11 @@ impl Calendar for AnyCalendar {
             AnyCalendarKind::Iso => "AnyCalendar (Iso)",
             AnyCalendarKind::Japanese => "AnyCalendar (Japanese)",
             AnyCalendarKind::JapaneseExtended => "AnyCalendar (Japanese, historical era data)",
+            AnyCalendarKind::Julian => "AnyCalendar (Julian)",
+            AnyCalendarKind::JulianGregorian => "AnyCalendar (Julian-Gregorian)",
             AnyCalendarKind::Persian => "AnyCalendar (Persian)",
             AnyCalendarKind::Roc => "AnyCalendar (Roc)",
+            AnyCalendarKind::Vietnamese => "AnyCalendar (Vietnamese)",
         }
     }
 
@@ -513,6 +617,8 @@ impl Calendar for AnyCalendar {
             Self::Ethiopian(ref c) => c.calendar_algorithm(),
             Self::Gregorian(ref c) => c.calendar_algorithm(),
             Self::Hebrew(ref c) => c.calendar_algorithm(),
+            Self::HinduLunisolar(ref c) => c.calendar_algorithm(),
+            Self::HinduSolar(ref c) => c.calendar_algorithm(),
             Self::Indian(ref c) => c.calendar_algorithm(),
             Self::HijriSimulated(ref c) => c.calendar_algorithm(),
             Self::HijriTabular(ref c) => c.calendar_algorithm(),
@@ -520,8 +626,11 @@ impl Calendar for AnyCalendar {
             Self::Iso(ref c) => c.calendar_algorithm(),
             Self::Japanese(ref c) => c.calendar_algorithm(),
             Self::JapaneseExtended(ref c) => c.calendar_algorithm(),
+            Self::Julian(ref c) => c.calendar_algorithm(),
+            Self::JulianGregorian(ref c) => c.calendar_algorithm(),
             Self::Persian(ref c) => c.calendar_algorithm(),
             Self::Roc(ref c) => c.calendar_algorithm(),
+            Self::Vietnamese(ref c) => c.calendar_algorithm(),
         }
     }
 }
@@ -547,6 +656,11 @@ impl AnyCalendar {
             }
             AnyCalendarKind::Gregorian => AnyCalendar::Gregorian(Gregorian),
             AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::HinduLunisolar => AnyCalendar::HinduLunisolar(HinduLunisolar::new()),
+            AnyCalendarKind::HinduLunisolarPurnimanta => {
+                AnyCalendar::HinduLunisolar(HinduLunisolar::new_purnimanta())
+            }
+            AnyCalendarKind::HinduSolar => AnyCalendar::HinduSolar(HinduSolar::new()),
             AnyCalendarKind::Indian => AnyCalendar::Indian(Indian),
             AnyCalendarKind::HijriTabularTypeIIFriday => {
                 AnyCalendar::HijriTabular(Hijri::new_tabular(
@@ -571,8 +685,13 @@ impl AnyCalendar {
             AnyCalendarKind::JapaneseExtended => {
                 AnyCalendar::JapaneseExtended(JapaneseExtended::new())
             }
+            AnyCalendarKind::Julian => AnyCalendar::Julian(Julian),
+            AnyCalendarKind::JulianGregorian => {
+                AnyCalendar::JulianGregorian(JulianGregorian::new())
+            }
             AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
             AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::Vietnamese => AnyCalendar::Vietnamese(LunarChinese::new_vietnamese()),
         }
     }
 
@@ -598,6 +717,11 @@ impl AnyCalendar {
             }
             AnyCalendarKind::Gregorian => AnyCalendar::Gregorian(Gregorian),
             AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::HinduLunisolar => AnyCalendar::HinduLunisolar(HinduLunisolar::new()),
+            AnyCalendarKind::HinduLunisolarPurnimanta => {
+                AnyCalendar::HinduLunisolar(HinduLunisolar::new_purnimanta())
+            }
+            AnyCalendarKind::HinduSolar => AnyCalendar::HinduSolar(HinduSolar::new()),
             AnyCalendarKind::Indian => AnyCalendar::Indian(Indian),
             AnyCalendarKind::HijriTabularTypeIIFriday => {
                 AnyCalendar::HijriTabular(Hijri::new_tabular(
@@ -624,8 +748,13 @@ impl AnyCalendar {
             AnyCalendarKind::JapaneseExtended => AnyCalendar::JapaneseExtended(
                 JapaneseExtended::try_new_with_buffer_provider(provider)?,
             ),
+            AnyCalendarKind::Julian => AnyCalendar::Julian(Julian),
+            AnyCalendarKind::JulianGregorian => {
+                AnyCalendar::JulianGregorian(JulianGregorian::new())
+            }
             AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
             AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::Vietnamese => AnyCalendar::Vietnamese(LunarChinese::new_vietnamese()),
         })
     }
 
@@ -649,6 +778,11 @@ impl AnyCalendar {
             }
             AnyCalendarKind::Gregorian => AnyCalendar::Gregorian(Gregorian),
             AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::HinduLunisolar => AnyCalendar::HinduLunisolar(HinduLunisolar::new()),
+            AnyCalendarKind::HinduLunisolarPurnimanta => {
+                AnyCalendar::HinduLunisolar(HinduLunisolar::new_purnimanta())
+            }
+            AnyCalendarKind::HinduSolar => AnyCalendar::HinduSolar(HinduSolar::new()),
             AnyCalendarKind::Indian => AnyCalendar::Indian(Indian),
             AnyCalendarKind::HijriTabularTypeIIFriday => {
                 AnyCalendar::HijriTabular(Hijri::new_tabular(
@@ -675,8 +809,13 @@ impl AnyCalendar {
             AnyCalendarKind::JapaneseExtended => {
                 AnyCalendar::JapaneseExtended(JapaneseExtended::try_new_unstable(provider)?)
             }
+            AnyCalendarKind::Julian => AnyCalendar::Julian(Julian),
+            AnyCalendarKind::JulianGregorian => {
+                AnyCalendar::JulianGregorian(JulianGregorian::new())
+            }
             AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
             AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::Vietnamese => AnyCalendar::Vietnamese(LunarChinese::new_vietnamese()),
         })
     }
 
@@ -690,6 +829,8 @@ impl AnyCalendar {
             Self::Ethiopian(ref e) => IntoAnyCalendar::kind(e),
             Self::Gregorian(_) => AnyCalendarKind::Gregorian,
             Self::Hebrew(_) => AnyCalendarKind::Hebrew,
+            Self::HinduLunisolar(ref h) => IntoAnyCalendar::kind(h),
+            Self::HinduSolar(_) => AnyCalendarKind::HinduSolar,
             Self::Indian(_) => AnyCalendarKind::Indian,
             Self::HijriTabular(ref h) => IntoAnyCalendar::kind(h),
             Self::HijriSimulated(ref h) => IntoAnyCalendar::kind(h),
@@ -697,10 +838,198 @@ impl AnyCalendar {
             Self::Iso(_) => AnyCalendarKind::Iso,
             Self::Japanese(_) => AnyCalendarKind::Japanese,
             Self::JapaneseExtended(_) => AnyCalendarKind::JapaneseExtended,
+            Self::Julian(_) => AnyCalendarKind::Julian,
+            Self::JulianGregorian(_) => AnyCalendarKind::JulianGregorian,
             Self::Persian(_) => AnyCalendarKind::Persian,
             Self::Roc(_) => AnyCalendarKind::Roc,
+            Self::Vietnamese(_) => AnyCalendarKind::Vietnamese,
         }
     }
+
+    /// Whether this calendar is solar, lunar, or lunisolar
+    pub fn basis(&self) -> types::CalendarBasis {
+        self.kind().basis()
+    }
+
+    /// The eras of this calendar, in chronological order.
+    ///
+    /// Calendars that use [cyclic years](types::CyclicYear) instead of eras, such as [`Chinese`], have no eras.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{AnyCalendar, AnyCalendarKind, Date};
+    ///
+    /// let roc = AnyCalendar::new(AnyCalendarKind::Roc);
+    /// let eras = roc.eras().collect::<Vec<_>>();
+    /// assert_eq!(eras.len(), 2);
+    ///
+    /// assert_eq!(eras[0].code, "broc");
+    /// assert!(eras[0].is_inverse);
+    /// assert_eq!(eras[0].start, None);
+    /// assert_eq!(eras[0].end, Some(Date::try_new_iso(1911, 12, 31).unwrap()));
+    ///
+    /// assert_eq!(eras[1].code, "roc");
+    /// assert!(!eras[1].is_inverse);
+    /// assert_eq!(eras[1].start, Some(Date::try_new_iso(1912, 1, 1).unwrap()));
+    /// assert_eq!(eras[1].end, None);
+    ///
+    /// let japanese = AnyCalendar::new(AnyCalendarKind::Japanese);
+    /// let heisei = japanese.eras().find(|e| e.code == "heisei").unwrap();
+    /// assert_eq!(heisei.start, Some(Date::try_new_iso(1989, 1, 8).unwrap()));
+    /// assert_eq!(heisei.end, Some(Date::try_new_iso(2019, 4, 30).unwrap()));
+    /// ```
+    pub fn eras(&self) -> impl Iterator<Item = types::EraInfo> + '_ {
+        // (code, index, is_inverse, has_start)
+        type Era = (TinyStr16, Option<u8>, bool, bool);
+        const BCE_CE: &[Era] = &[
+            (tinystr!(16, "bce"), Some(0), true, false),
+            (tinystr!(16, "ce"), Some(1), false, true),
+        ];
+
+        let (eras, japanese): (&[Era], Option<&Japanese>) = match self {
+            Self::Buddhist(_) => (&[(tinystr!(16, "be"), Some(0), false, false)], None),
+            Self::Chinese(_) | Self::Dangi(_) | Self::Vietnamese(_) => (&[], None),
+            Self::Coptic(_) | Self::Hebrew(_) => {
+                (&[(tinystr!(16, "am"), Some(0), false, false)], None)
+            }
+            Self::Ethiopian(e) if e.0 => (&[(tinystr!(16, "aa"), Some(0), false, false)], None),
+            Self::Ethiopian(_) => (
+                &[
+                    (tinystr!(16, "aa"), Some(0), false, false),
+                    (tinystr!(16, "am"), Some(1), false, true),
+                ],
+                None,
+            ),
+            Self::Gregorian(_) | Self::Julian(_) | Self::JulianGregorian(_) => (BCE_CE, None),
+            Self::HinduLunisolar(_) => (&[(tinystr!(16, "vikram"), Some(0), false, false)], None),
+            Self::HinduSolar(_) | Self::Indian(_) => {
+                (&[(tinystr!(16, "shaka"), Some(0), false, false)], None)
+            }
+            Self::HijriTabular(_) | Self::HijriSimulated(_) | Self::HijriUmmAlQura(_) => (
+                &[
+                    (tinystr!(16, "bh"), Some(1), true, false),
+                    (tinystr!(16, "ah"), Some(0), false, true),
+                ],
+                None,
+            ),
+            Self::Iso(_) => (&[(tinystr!(16, "default"), Some(0), false, false)], None),
+            Self::Japanese(_) | Self::JapaneseExtended(_) => (
+                &[
+                    (tinystr!(16, "bce"), None, true, false),
+                    (tinystr!(16, "ce"), None, false, true),
+                ],
+                match self {
+                    Self::Japanese(j) => Some(j),
+                    Self::JapaneseExtended(j) => Some(&j.0),
+                    _ => None,
+                },
+            ),
+            Self::Persian(_) => (&[(tinystr!(16, "ap"), Some(0), false, false)], None),
+            Self::Roc(_) => (
+                &[
+                    (tinystr!(16, "broc"), Some(0), true, false),
+                    (tinystr!(16, "roc"), Some(1), false, true),
+                ],
+                None,
+            ),
+        };
+
+        let mut eras = eras
+            .iter()
+            .map(move |&(code, index, is_inverse, has_start)| {
+                let start = if has_start {
+                    self.era_start(code)
+                } else {
+                    None
+                };
+                (code, index, is_inverse, start)
+            })
+            .chain(
+                japanese
+                    .into_iter()
+                    .flat_map(Japanese::era_starts)
+                    .map(|(start, code)| {
+                        let start = Date::try_new_iso(start.year, start.month, start.day).ok();
+                        (code, None, false, start)
+                    }),
+            )
+            .peekable();
+
+        core::iter::from_fn(move || {
+            let (code, index, is_inverse, start) = eras.next()?;
+            // An era ends the day before the next one starts
+            let end = eras
+                .peek()
+                .and_then(|next| next.3)
+                .map(|next_start| Date::from_rata_die(next_start.to_rata_die() - 1, Iso));
+            Some(types::EraInfo {
+                code,
+                index,
+                start,
+                end,
+                is_inverse,
+            })
+        })
+    }
+
+    /// The first day of year 1 of the given era
+    fn era_start(&self, era: TinyStr16) -> Option<Date<Iso>> {
+        let inner = self
+            .from_codes(Some(&era), 1, types::MonthCode::new_normal(1)?, 1)
+            .ok()?;
+        Some(Date::from_rata_die(self.to_rata_die(&inner), Iso))
+    }
+
+    /// The earliest date supported by this calendar.
+    ///
+    /// For most calendars, this is the earliest date supported by ECMA-262 Temporal, which is
+    /// -271821-04-19 in the ISO calendar. The astronomical [`HinduSolar`] and [`HinduLunisolar`]
+    /// calendars are supported from the start of the Kali Yuga, -3101-01-23 in the ISO calendar.
+    ///
+    /// Earlier dates can be constructed, but calendars are not guaranteed to handle them correctly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{AnyCalendar, AnyCalendarKind, Date};
+    ///
+    /// let hebrew = AnyCalendar::new(AnyCalendarKind::Hebrew);
+    /// assert_eq!(hebrew.min_date().to_iso(), Date::try_new_iso(-271821, 4, 19).unwrap());
+    /// assert_eq!(hebrew.min_date().extended_year(), -268058);
+    /// assert_eq!(hebrew.max_date().extended_year(), 279517);
+    ///
+    /// let hindu = AnyCalendar::new(AnyCalendarKind::HinduSolar);
+    /// assert_eq!(hindu.min_date().to_iso(), Date::try_new_iso(-3101, 1, 23).unwrap());
+    /// assert_eq!(hindu.max_date().to_iso(), Date::try_new_iso(9000, 12, 31).unwrap());
+    /// ```
+    pub fn min_date(&self) -> Date<Ref<'_, AnyCalendar>> {
+        let rd = match self {
+            Self::HinduLunisolar(_) | Self::HinduSolar(_) => {
+                calendrical_calculations::hindu::HINDU_EPOCH
+            }
+            _ => calendrical_calculations::iso::const_fixed_from_iso(-271821, 4, 19),
+        };
+        Date::from_rata_die(rd, Ref(self))
+    }
+
+    /// The latest date supported by this calendar.
+    ///
+    /// For most calendars, this is the latest date supported by ECMA-262 Temporal, which is
+    /// +275760-09-13 in the ISO calendar. The astronomical [`HinduSolar`] and [`HinduLunisolar`]
+    /// calendars are supported until 9000-12-31 in the ISO calendar, as the astronomical
+    /// calculations they are based on become unreliable after that.
+    ///
+    /// Later dates can be constructed, but calendars are not guaranteed to handle them correctly.
+    pub fn max_date(&self) -> Date<Ref<'_, AnyCalendar>> {
+        let rd = match self {
+            Self::HinduLunisolar(_) | Self::HinduSolar(_) => {
+                calendrical_calculations::iso::const_fixed_from_iso(9000, 12, 31)
+            }
+            _ => calendrical_calculations::iso::const_fixed_from_iso(275760, 9, 13),
+        };
+        Date::from_rata_die(rd, Ref(self))
+    }
 }
 
 impl<C: AsCalendar<Calendar = AnyCalendar>> Date<C> {
@@ -727,6 +1056,8 @@ impl AnyDateInner {
             AnyDateInner::Ethiopian(_) => AnyCalendarKind::Ethiopian,
             AnyDateInner::Gregorian(_) => AnyCalendarKind::Gregorian,
             AnyDateInner::Hebrew(_) => AnyCalendarKind::Hebrew,
+            AnyDateInner::HinduLunisolar(_) => AnyCalendarKind::HinduLunisolar,
+            AnyDateInner::HinduSolar(_) => AnyCalendarKind::HinduSolar,
             AnyDateInner::Indian(_) => AnyCalendarKind::Indian,
             AnyDateInner::HijriTabular(
                 _,
@@ -747,8 +1078,11 @@ impl AnyDateInner {
             AnyDateInner::Iso(_) => AnyCalendarKind::Iso,
             AnyDateInner::Japanese(_) => AnyCalendarKind::Japanese,
             AnyDateInner::JapaneseExtended(_) => AnyCalendarKind::JapaneseExtended,
+            AnyDateInner::Julian(_) => AnyCalendarKind::Julian,
+            AnyDateInner::JulianGregorian(_) => AnyCalendarKind::JulianGregorian,
             AnyDateInner::Persian(_) => AnyCalendarKind::Persian,
             AnyDateInner::Roc(_) => AnyCalendarKind::Roc,
+            AnyDateInner::Vietnamese(_) => AnyCalendarKind::Vietnamese,
         }
     }
 }
@@ -773,6 +1107,12 @@ pub enum AnyCalendarKind {
     Gregorian,
     /// The kind of a [`Hebrew`] calendar
     Hebrew,
+    /// The kind of a [`HinduLunisolar`] calendar, with amānta months
+    HinduLunisolar,
+    /// The kind of a [`HinduLunisolar`] calendar, with pūrṇimānta months
+    HinduLunisolarPurnimanta,
+    /// The kind of a [`HinduSolar`] calendar
+    HinduSolar,
     /// The kind of a [`Indian`] calendar
     Indian,
     /// The kind of an [`HijriTabular`] calendar using [`HijriTabularLeapYears::TypeII`] and [`HijriTabularEpoch::Friday`]
@@ -789,10 +1129,19 @@ pub enum AnyCalendarKind {
     Japanese,
     /// The kind of a [`JapaneseExtended`] calendar
     JapaneseExtended,
+    /// The kind of a [`Julian`] calendar
+    Julian,
+    /// The kind of a [`JulianGregorian`] calendar
+    JulianGregorian,
     /// The kind of a [`Persian`] calendar
     Persian,
     /// The kind of a [`Roc`] calendar
     Roc,
+    /// The kind of a [`Vietnamese`](chinese::Vietnamese) calendar
+    ///
+    /// This calendar is not yet a CLDR `ca` value, so it is never selected from a `-u-ca-`
+    /// keyword.
+    Vietnamese,
 }
 
 impl AnyCalendarKind {
@@ -824,6 +1173,10 @@ impl AnyCalendarKind {
             AnyCalendarKind::EthiopianAmeteAlem => Ethiopian(true).debug_name(),
             AnyCalendarKind::Gregorian => Gregorian.debug_name(),
             AnyCalendarKind::Hebrew => Hebrew.debug_name(),
+            AnyCalendarKind::HinduLunisolar | AnyCalendarKind::HinduLunisolarPurnimanta => {
+                HinduLunisolar::new().debug_name()
+            }
+            AnyCalendarKind::HinduSolar => HinduSolar::new().debug_name(),
             AnyCalendarKind::Indian => Indian.debug_name(),
             AnyCalendarKind::HijriTabularTypeIIFriday => Hijri::new_tabular(
                 hijri::TabularAlgorithmLeapYears::TypeII,
@@ -840,8 +1193,52 @@ impl AnyCalendarKind {
             AnyCalendarKind::Iso => Iso.debug_name(),
             AnyCalendarKind::Japanese => Japanese::DEBUG_NAME,
             AnyCalendarKind::JapaneseExtended => JapaneseExtended::DEBUG_NAME,
+            AnyCalendarKind::Julian => Julian.debug_name(),
+            AnyCalendarKind::JulianGregorian => JulianGregorian::new().debug_name(),
             AnyCalendarKind::Persian => Persian.debug_name(),
             AnyCalendarKind::Roc => Roc.debug_name(),
+            AnyCalendarKind::Vietnamese => LunarChinese::new_vietnamese().debug_name(),
+        }
+    }
+
+    /// Whether calendars of this kind are solar, lunar, or lunisolar
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::types::CalendarBasis;
+    /// use icu::calendar::AnyCalendarKind;
+    ///
+    /// assert_eq!(AnyCalendarKind::Gregorian.basis(), CalendarBasis::Solar);
+    /// assert_eq!(AnyCalendarKind::HijriUmmAlQura.basis(), CalendarBasis::Lunar);
+    /// assert_eq!(AnyCalendarKind::Hebrew.basis(), CalendarBasis::Lunisolar);
+    /// ```
+    pub fn basis(self) -> types::CalendarBasis {
+        match self {
+            AnyCalendarKind::Buddhist
+            | AnyCalendarKind::Coptic
+            | AnyCalendarKind::Ethiopian
+            | AnyCalendarKind::EthiopianAmeteAlem
+            | AnyCalendarKind::Gregorian
+            | AnyCalendarKind::HinduSolar
+            | AnyCalendarKind::Indian
+            | AnyCalendarKind::Iso
+            | AnyCalendarKind::Japanese
+            | AnyCalendarKind::JapaneseExtended
+            | AnyCalendarKind::Julian
+            | AnyCalendarKind::JulianGregorian
+            | AnyCalendarKind::Persian
+            | AnyCalendarKind::Roc => types::CalendarBasis::Solar,
+            AnyCalendarKind::HijriTabularTypeIIFriday
+            | AnyCalendarKind::HijriSimulatedMecca
+            | AnyCalendarKind::HijriTabularTypeIIThursday
+            | AnyCalendarKind::HijriUmmAlQura => types::CalendarBasis::Lunar,
+            AnyCalendarKind::Chinese
+            | AnyCalendarKind::Dangi
+            | AnyCalendarKind::Hebrew
+            | AnyCalendarKind::HinduLunisolar
+            | AnyCalendarKind::HinduLunisolarPurnimanta
+            | AnyCalendarKind::Vietnamese => types::CalendarBasis::Lunisolar,
         }
     }
 }
@@ -1201,6 +1598,83 @@ impl From<Hebrew> for AnyCalendar {
     }
 }
 
+impl IntoAnyCalendar for HinduLunisolar {
+    #[inline]
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::HinduLunisolar(self)
+    }
+    #[inline]
+    fn kind(&self) -> AnyCalendarKind {
+        match self.scheme() {
+            HinduMonthScheme::Purnimanta => AnyCalendarKind::HinduLunisolarPurnimanta,
+            _ => AnyCalendarKind::HinduLunisolar,
+        }
+    }
+    #[inline]
+    fn from_any(any: AnyCalendar) -> Result<Self, AnyCalendar> {
+        if let AnyCalendar::HinduLunisolar(cal) = any {
+            Ok(cal)
+        } else {
+            Err(any)
+        }
+    }
+    #[inline]
+    fn from_any_ref(any: &AnyCalendar) -> Option<&Self> {
+        if let AnyCalendar::HinduLunisolar(cal) = any {
+            Some(cal)
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::HinduLunisolar(*d)
+    }
+}
+
+impl From<HinduLunisolar> for AnyCalendar {
+    fn from(value: HinduLunisolar) -> AnyCalendar {
+        value.to_any()
+    }
+}
+
+impl IntoAnyCalendar for HinduSolar {
+    #[inline]
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::HinduSolar(self)
+    }
+    #[inline]
+    fn kind(&self) -> AnyCalendarKind {
+        AnyCalendarKind::HinduSolar
+    }
+    #[inline]
+    fn from_any(any: AnyCalendar) -> Result<Self, AnyCalendar> {
+        if let AnyCalendar::HinduSolar(cal) = any {
+            Ok(cal)
+        } else {
+            Err(any)
+        }
+    }
+    #[inline]
+    fn from_any_ref(any: &AnyCalendar) -> Option<&Self> {
+        if let AnyCalendar::HinduSolar(cal) = any {
+            Some(cal)
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::HinduSolar(*d)
+    }
+}
+
+impl From<HinduSolar> for AnyCalendar {
+    fn from(value: HinduSolar) -> AnyCalendar {
+        value.to_any()
+    }
+}
+
 impl IntoAnyCalendar for Indian {
     #[inline]
     fn to_any(self) -> AnyCalendar {
@@ -1473,6 +1947,80 @@ impl From<JapaneseExtended> for AnyCalendar {
     }
 }
 
+impl IntoAnyCalendar for Julian {
+    #[inline]
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Julian(Julian)
+    }
+    #[inline]
+    fn kind(&self) -> AnyCalendarKind {
+        AnyCalendarKind::Julian
+    }
+    #[inline]
+    fn from_any(any: AnyCalendar) -> Result<Self, AnyCalendar> {
+        if let AnyCalendar::Julian(cal) = any {
+            Ok(cal)
+        } else {
+            Err(any)
+        }
+    }
+    #[inline]
+    fn from_any_ref(any: &AnyCalendar) -> Option<&Self> {
+        if let AnyCalendar::Julian(cal) = any {
+            Some(cal)
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Julian(*d)
+    }
+}
+
+impl From<Julian> for AnyCalendar {
+    fn from(value: Julian) -> AnyCalendar {
+        value.to_any()
+    }
+}
+
+impl IntoAnyCalendar for JulianGregorian {
+    #[inline]
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::JulianGregorian(self)
+    }
+    #[inline]
+    fn kind(&self) -> AnyCalendarKind {
+        AnyCalendarKind::JulianGregorian
+    }
+    #[inline]
+    fn from_any(any: AnyCalendar) -> Result<Self, AnyCalendar> {
+        if let AnyCalendar::JulianGregorian(cal) = any {
+            Ok(cal)
+        } else {
+            Err(any)
+        }
+    }
+    #[inline]
+    fn from_any_ref(any: &AnyCalendar) -> Option<&Self> {
+        if let AnyCalendar::JulianGregorian(cal) = any {
+            Some(cal)
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::JulianGregorian(*d)
+    }
+}
+
+impl From<JulianGregorian> for AnyCalendar {
+    fn from(value: JulianGregorian) -> AnyCalendar {
+        value.to_any()
+    }
+}
+
 impl IntoAnyCalendar for Persian {
     #[inline]
     fn to_any(self) -> AnyCalendar {
@@ -1547,6 +2095,43 @@ impl From<Roc> for AnyCalendar {
     }
 }
 
+impl IntoAnyCalendar for LunarChinese<chinese::Vietnamese> {
+    #[inline]
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Vietnamese(self)
+    }
+    #[inline]
+    fn kind(&self) -> AnyCalendarKind {
+        AnyCalendarKind::Vietnamese
+    }
+    #[inline]
+    fn from_any(any: AnyCalendar) -> Result<Self, AnyCalendar> {
+        if let AnyCalendar::Vietnamese(cal) = any {
+            Ok(cal)
+        } else {
+            Err(any)
+        }
+    }
+    #[inline]
+    fn from_any_ref(any: &AnyCalendar) -> Option<&Self> {
+        if let AnyCalendar::Vietnamese(cal) = any {
+            Some(cal)
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Vietnamese(*d)
+    }
+}
+
+impl From<LunarChinese<chinese::Vietnamese>> for AnyCalendar {
+    fn from(value: LunarChinese<chinese::Vietnamese>) -> AnyCalendar {
+        value.to_any()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use tinystr::tinystr;
@@ -1644,6 +2229,8 @@ mod tests {
         let ethiopian = AnyCalendar::new(AnyCalendarKind::Ethiopian);
         let gregorian = AnyCalendar::new(AnyCalendarKind::Gregorian);
         let hebrew = AnyCalendar::new(AnyCalendarKind::Hebrew);
+        let hindu_lunisolar = AnyCalendar::new(AnyCalendarKind::HinduLunisolar);
+        let hindu_solar = AnyCalendar::new(AnyCalendarKind::HinduSolar);
         let indian = AnyCalendar::new(AnyCalendarKind::Indian);
         let hijri_civil: AnyCalendar = AnyCalendar::new(AnyCalendarKind::HijriTabularTypeIIFriday);
         let hijri_simulated: AnyCalendar = AnyCalendar::new(AnyCalendarKind::HijriSimulatedMecca);
@@ -1652,8 +2239,11 @@ mod tests {
         let hijri_umm_al_qura: AnyCalendar = AnyCalendar::new(AnyCalendarKind::HijriUmmAlQura);
         let japanese = AnyCalendar::new(AnyCalendarKind::Japanese);
         let japanext = AnyCalendar::new(AnyCalendarKind::JapaneseExtended);
+        let julian = AnyCalendar::new(AnyCalendarKind::Julian);
+        let julian_gregorian = AnyCalendar::new(AnyCalendarKind::JulianGregorian);
         let persian = AnyCalendar::new(AnyCalendarKind::Persian);
         let roc = AnyCalendar::new(AnyCalendarKind::Roc);
+        let vietnamese = AnyCalendar::new(AnyCalendarKind::Vietnamese);
         let buddhist = Ref(&buddhist);
         let chinese = Ref(&chinese);
         let coptic = Ref(&coptic);
@@ -1662,6 +2252,8 @@ mod tests {
         let ethiopian = Ref(&ethiopian);
         let gregorian = Ref(&gregorian);
         let hebrew = Ref(&hebrew);
+        let hindu_lunisolar = Ref(&hindu_lunisolar);
+        let hindu_solar = Ref(&hindu_solar);
         let indian = Ref(&indian);
         let hijri_civil = Ref(&hijri_civil);
         let hijri_simulated = Ref(&hijri_simulated);
@@ -1669,8 +2261,11 @@ mod tests {
         let hijri_umm_al_qura = Ref(&hijri_umm_al_qura);
         let japanese = Ref(&japanese);
         let japanext = Ref(&japanext);
+        let julian = Ref(&julian);
+        let julian_gregorian = Ref(&julian_gregorian);
         let persian = Ref(&persian);
         let roc = Ref(&roc);
+        let vietnamese = Ref(&vietnamese);
 
         single_test_roundtrip(buddhist, Some(("be", Some(0))), 100, "M03", 1);
         single_test_roundtrip(buddhist, None, 100, "M03", 1);
@@ -1829,6 +2424,18 @@ mod tests {
             DateError::UnknownMonthCode(MonthCode(tinystr!(4, "M00L"))),
         );
 
+        single_test_roundtrip(vietnamese, None, 1985, "M02L", 12);
+        single_test_roundtrip(vietnamese, None, 2025, "M06L", 1);
+        single_test_roundtrip(vietnamese, None, -1300, "M11", 12);
+        single_test_error(
+            vietnamese,
+            None,
+            1984,
+            "M10L",
+            1,
+            DateError::UnknownMonthCode(MonthCode(tinystr!(4, "M10L"))),
+        );
+
         single_test_roundtrip(japanese, Some(("reiwa", None)), 3, "M03", 1);
         single_test_roundtrip(japanese, Some(("heisei", None)), 6, "M12", 1);
         single_test_roundtrip(japanese, Some(("meiji", None)), 10, "M03", 1);
@@ -1941,6 +2548,62 @@ mod tests {
             DateError::UnknownMonthCode(MonthCode(tinystr!(4, "M9"))),
         );
 
+        single_test_roundtrip(julian, Some(("ce", Some(1))), 1582, "M10", 10);
+        single_test_roundtrip(julian, Some(("bce", Some(0))), 44, "M03", 15);
+        single_test_roundtrip(julian, None, -100, "M02", 29);
+        single_test_error(
+            julian,
+            Some(("ce", Some(1))),
+            1582,
+            "M13",
+            1,
+            DateError::UnknownMonthCode(MonthCode(tinystr!(4, "M13"))),
+        );
+
+        single_test_roundtrip(julian_gregorian, Some(("ce", Some(1))), 1582, "M10", 4);
+        single_test_roundtrip(julian_gregorian, Some(("ce", Some(1))), 1582, "M10", 15);
+        single_test_roundtrip(julian_gregorian, Some(("bce", Some(0))), 44, "M03", 15);
+        single_test_roundtrip(julian_gregorian, None, 1500, "M02", 29);
+        single_test_error(
+            julian_gregorian,
+            Some(("ce", Some(1))),
+            1582,
+            "M10",
+            10,
+            DateError::Range {
+                field: "day",
+                value: 10,
+                min: 15,
+                max: 31,
+            },
+        );
+        single_test_error(
+            julian_gregorian,
+            None,
+            1700,
+            "M02",
+            29,
+            DateError::Range {
+                field: "day",
+                value: 29,
+                min: 1,
+                max: 28,
+            },
+        );
+
+        single_test_roundtrip(hindu_solar, Some(("shaka", Some(0))), 1946, "M01", 1);
+        single_test_roundtrip(hindu_solar, None, 1945, "M04", 16);
+        single_test_roundtrip(hindu_lunisolar, Some(("vikram", Some(0))), 2080, "M05L", 15);
+        single_test_roundtrip(hindu_lunisolar, None, 2080, "M01", 1);
+        single_test_error(
+            hindu_lunisolar,
+            Some(("vikram", Some(0))),
+            2080,
+            "M13",
+            1,
+            DateError::UnknownMonthCode(MonthCode(tinystr!(4, "M13"))),
+        );
+
         single_test_roundtrip(roc, Some(("roc", Some(1))), 10, "M05", 3);
         single_test_roundtrip(roc, Some(("broc", Some(0))), 15, "M01", 10);
         single_test_roundtrip(roc, None, 100, "M10", 30);
@@ -1998,4 +2661,233 @@ mod tests {
             DateError::UnknownMonthCode(MonthCode(tinystr!(4, "M9"))),
         );
     }
+
+    const ALL_KINDS: [AnyCalendarKind; 24] = [
+        AnyCalendarKind::Buddhist,
+        AnyCalendarKind::Chinese,
+        AnyCalendarKind::Coptic,
+        AnyCalendarKind::Dangi,
+        AnyCalendarKind::Ethiopian,
+        AnyCalendarKind::EthiopianAmeteAlem,
+        AnyCalendarKind::Gregorian,
+        AnyCalendarKind::Hebrew,
+        AnyCalendarKind::HinduLunisolar,
+        AnyCalendarKind::HinduLunisolarPurnimanta,
+        AnyCalendarKind::HinduSolar,
+        AnyCalendarKind::Indian,
+        AnyCalendarKind::HijriTabularTypeIIFriday,
+        AnyCalendarKind::HijriSimulatedMecca,
+        AnyCalendarKind::HijriTabularTypeIIThursday,
+        AnyCalendarKind::HijriUmmAlQura,
+        AnyCalendarKind::Iso,
+        AnyCalendarKind::Japanese,
+        AnyCalendarKind::JapaneseExtended,
+        AnyCalendarKind::Julian,
+        AnyCalendarKind::JulianGregorian,
+        AnyCalendarKind::Persian,
+        AnyCalendarKind::Roc,
+        AnyCalendarKind::Vietnamese,
+    ];
+
+    #[test]
+    fn test_eras() {
+        for kind in ALL_KINDS {
+            let calendar = AnyCalendar::new(kind);
+            let eras = calendar.eras().collect::<Vec<_>>();
+
+            if matches!(
+                kind,
+                AnyCalendarKind::Chinese | AnyCalendarKind::Dangi | AnyCalendarKind::Vietnamese
+            ) {
+                assert!(eras.is_empty(), "{kind:?}");
+                continue;
+            }
+
+            assert_eq!(eras.first().unwrap().start, None, "{kind:?}");
+            assert_eq!(eras.last().unwrap().end, None, "{kind:?}");
+
+            for (era, next) in eras.iter().zip(eras.iter().skip(1)) {
+                let end = era.end.unwrap().to_rata_die();
+                assert_eq!(
+                    end + 1,
+                    next.start.unwrap().to_rata_die(),
+                    "{kind:?} {era:?}"
+                );
+            }
+
+            for era in &eras {
+                // The first year of an era starts on its first day, or ends on its last day for inverse eras
+                let boundary = if era.is_inverse { era.end } else { era.start };
+                let Some(boundary) = boundary else {
+                    continue;
+                };
+                let year = boundary.to_calendar(Ref(&calendar)).year().era().unwrap();
+                assert_eq!(year.era, era.code, "{kind:?} {era:?}");
+                assert_eq!(year.era_index, era.index, "{kind:?} {era:?}");
+                assert_eq!(year.year, 1, "{kind:?} {era:?}");
+            }
+        }
+
+        let gregorian = AnyCalendar::new(AnyCalendarKind::Gregorian);
+        let eras = gregorian.eras().collect::<Vec<_>>();
+        assert_eq!(eras.len(), 2);
+        assert_eq!(eras[0].code, "bce");
+        assert_eq!(eras[0].end, Some(Date::try_new_iso(0, 12, 31).unwrap()));
+        assert_eq!(eras[1].code, "ce");
+        assert_eq!(eras[1].start, Some(Date::try_new_iso(1, 1, 1).unwrap()));
+
+        let julian = AnyCalendar::new(AnyCalendarKind::Julian);
+        let eras = julian.eras().collect::<Vec<_>>();
+        assert_eq!(eras[1].start, Some(Date::try_new_iso(0, 12, 30).unwrap()));
+
+        let ethiopian = AnyCalendar::new(AnyCalendarKind::Ethiopian);
+        let eras = ethiopian.eras().collect::<Vec<_>>();
+        assert_eq!(eras[1].code, "am");
+        assert_eq!(eras[1].start, Some(Date::try_new_iso(8, 8, 27).unwrap()));
+
+        let japanese = AnyCalendar::new(AnyCalendarKind::Japanese);
+        let eras = japanese.eras().map(|e| e.code).collect::<Vec<_>>();
+        assert_eq!(
+            eras,
+            ["bce", "ce", "meiji", "taisho", "showa", "heisei", "reiwa"]
+        );
+        let meiji = japanese.eras().nth(2).unwrap();
+        assert_eq!(meiji.start, Some(Date::try_new_iso(1868, 10, 23).unwrap()));
+        assert_eq!(meiji.end, Some(Date::try_new_iso(1912, 7, 29).unwrap()));
+
+        let japanext = AnyCalendar::new(AnyCalendarKind::JapaneseExtended);
+        let taika = japanext.eras().nth(2).unwrap();
+        assert_eq!(taika.code, "taika-645");
+        assert!(japanext.eras().count() > 200);
+    }
+
+    #[test]
+    fn test_months_of_year() {
+        #[track_caller]
+        fn check(kind: AnyCalendarKind, era: &str, year: i32, expected: &[&str]) {
+            let calendar = AnyCalendar::new(kind);
+            let first_day = Date::try_new_from_codes(
+                Some(era),
+                year,
+                MonthCode::new_normal(1).unwrap(),
+                1,
+                Ref(&calendar),
+            )
+            .unwrap();
+            let date = Date::from_rata_die(first_day.to_rata_die() + 100, Ref(&calendar));
+            let months = date.months_of_year().collect::<Vec<_>>();
+            assert_eq!(
+                months.iter().map(|m| m.standard_code.0).collect::<Vec<_>>(),
+                expected,
+                "{kind:?}"
+            );
+            for (i, month) in months.iter().enumerate() {
+                assert_eq!(usize::from(month.ordinal), i + 1, "{kind:?}");
+            }
+        }
+
+        check(
+            AnyCalendarKind::Gregorian,
+            "ce",
+            2024,
+            &[
+                "M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12",
+            ],
+        );
+        check(
+            AnyCalendarKind::Coptic,
+            "am",
+            1740,
+            &[
+                "M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12",
+                "M13",
+            ],
+        );
+        check(
+            AnyCalendarKind::Hebrew,
+            "am",
+            5785,
+            &[
+                "M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12",
+            ],
+        );
+        check(
+            AnyCalendarKind::Hebrew,
+            "am",
+            5784,
+            &[
+                "M01", "M02", "M03", "M04", "M05", "M05L", "M06", "M07", "M08", "M09", "M10",
+                "M11", "M12",
+            ],
+        );
+        check(
+            AnyCalendarKind::HinduLunisolar,
+            "vikram",
+            2080,
+            &[
+                "M01", "M02", "M03", "M04", "M05L", "M05", "M06", "M07", "M08", "M09", "M10",
+                "M11", "M12",
+            ],
+        );
+        check(
+            AnyCalendarKind::Japanese,
+            "reiwa",
+            2,
+            &[
+                "M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12",
+            ],
+        );
+
+        let chinese = AnyCalendar::new(AnyCalendarKind::Chinese);
+        let date = Date::try_new_iso(2023, 6, 1)
+            .unwrap()
+            .to_calendar(Ref(&chinese));
+        assert_eq!(
+            date.months_of_year()
+                .map(|m| m.standard_code.0)
+                .collect::<Vec<_>>(),
+            [
+                "M01", "M02", "M02L", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10",
+                "M11", "M12"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_basis_and_extrema() {
+        for kind in ALL_KINDS {
+            let calendar = AnyCalendar::new(kind);
+            assert_eq!(calendar.basis(), kind.basis());
+
+            let (min_iso, max_iso) = match kind {
+                AnyCalendarKind::HinduSolar
+                | AnyCalendarKind::HinduLunisolar
+                | AnyCalendarKind::HinduLunisolarPurnimanta => ((-3101, 1, 23), (9000, 12, 31)),
+                _ => ((-271821, 4, 19), (275760, 9, 13)),
+            };
+            let min = calendar.min_date();
+            let max = calendar.max_date();
+            assert_eq!(
+                min.to_iso(),
+                Date::try_new_iso(min_iso.0, min_iso.1, min_iso.2).unwrap(),
+                "{kind:?}"
+            );
+            assert_eq!(
+                max.to_iso(),
+                Date::try_new_iso(max_iso.0, max_iso.1, max_iso.2).unwrap(),
+                "{kind:?}"
+            );
+
+            for date in [min, max] {
+                let months = date.months_of_year().count();
+                assert_eq!(months, usize::from(date.months_in_year()), "{kind:?}");
+                match kind.basis() {
+                    types::CalendarBasis::Lunar => assert_eq!(months, 12, "{kind:?}"),
+                    types::CalendarBasis::Solar | types::CalendarBasis::Lunisolar => {
+                        assert!(months >= 12, "{kind:?}")
+                    }
+                }
+            }
+        }
+    }
 }
@@ -37,6 +37,8 @@ use tinystr::tinystr;
 mod chinese_data;
 #[path = "chinese/dangi_data.rs"]
 mod dangi_data;
+#[path = "chinese/vietnamese_data.rs"]
+mod vietnamese_data;
 
 /// The [Chinese Calendar](https://en.wikipedia.org/wiki/Chinese_calendar)
 ///
@@ -92,7 +94,8 @@ pub struct LunarChinese<X>(pub X);
 /// time is determined differ between countries and have changed over time.
 ///
 /// For example, the [`China`] type implements the rules that are used in
-/// China, and the [`Dangi`] type implements the rules used in Korea.
+/// China, the [`Dangi`] type implements the rules used in Korea, and the
+/// [`Vietnamese`] type implements the rules used in Vietnam.
 pub trait Rules: Clone + core::fmt::Debug {
     /// Returns data about the given year.
     fn year_data(&self, related_iso: i32) -> LunarChineseYearData;
@@ -280,6 +283,81 @@ impl<A: AsCalendar<Calendar = LunarChinese<Dangi>>> Date<A> {
     }
 }
 
+/// The [Vietnamese lunar calendar](https://en.wikipedia.org/wiki/Vietnamese_calendar)
+///
+/// The Vietnamese lunar calendar (Âm lịch) is used in Vietnam to track important cultural
+/// events and holidays like Tết (Vietnamese lunar new year). It is similar to the Chinese lunar
+/// calendar (see [`China`](super::chinese::China)), except that since 1968 observations are
+/// based in Vietnam (UTC+7) rather than China (UTC+8). This can cause some differences; for
+/// example, in 1985 Tết was on January 21, a month before the Chinese New Year, and the
+/// Vietnamese year had a leap month after month 2.
+///
+/// This type includes precomputed data for the years 1901-2100.
+///
+/// This calendar is not yet a CLDR `ca` value, so it cannot be selected with a `-u-ca-`
+/// keyword; use [`LunarChinese::new_vietnamese`] or
+/// [`AnyCalendarKind::Vietnamese`](crate::AnyCalendarKind::Vietnamese).
+///
+/// ```rust
+/// use icu::calendar::cal::LunarChinese;
+/// use icu::calendar::Date;
+/// use tinystr::tinystr;
+///
+/// let iso = Date::try_new_iso(1985, 1, 21).unwrap();
+/// let vietnamese = iso.to_calendar(LunarChinese::new_vietnamese());
+/// let chinese = iso.to_calendar(LunarChinese::new_china());
+///
+/// assert_eq!(vietnamese.cyclic_year().related_iso, 1985);
+/// assert_eq!(vietnamese.month().standard_code.0, tinystr!(4, "M01"));
+/// assert_eq!(vietnamese.day_of_month().0, 1);
+/// assert_eq!(chinese.cyclic_year().related_iso, 1984);
+/// assert_eq!(chinese.month().standard_code.0, tinystr!(4, "M12"));
+///
+/// let iso = Date::try_new_iso(1985, 4, 1).unwrap();
+/// let vietnamese = iso.to_calendar(LunarChinese::new_vietnamese());
+///
+/// assert_eq!(vietnamese.month().standard_code.0, tinystr!(4, "M02L"));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub struct Vietnamese;
+
+impl LunarChinese<Vietnamese> {
+    /// Creates a new [`Vietnamese`] calendar.
+    pub const fn new_vietnamese() -> Self {
+        Self(Vietnamese)
+    }
+}
+
+impl Rules for Vietnamese {
+    fn year_data(&self, related_iso: i32) -> LunarChineseYearData {
+        if let Some(packed) = (ChineseBasedCache {
+            first_related_iso_year: vietnamese_data::STARTING_YEAR,
+            data: vietnamese_data::DATA,
+        }
+        .get(related_iso))
+        {
+            LunarChineseYearData {
+                packed,
+                related_iso,
+            }
+        } else {
+            fallback_approximation::<calendrical_calculations::chinese_based::Vietnamese>(
+                related_iso,
+            )
+        }
+    }
+
+    fn calendar_algorithm(&self) -> Option<CalendarAlgorithm> {
+        // Not yet a CLDR `ca` value
+        None
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Vietnamese"
+    }
+}
+
 /// The inner date type used for representing [`Date`]s of [`LunarChinese`].
 #[derive(Debug, Clone)]
 pub struct ChineseDateInner<X: Rules>(ArithmeticDate<LunarChinese<X>>);
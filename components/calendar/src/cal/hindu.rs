@@ -0,0 +1,1173 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Hindu calendars.
+//!
+//! ```rust
+//! use icu::calendar::cal::{HinduLunisolar, HinduSolar};
+//! use icu::calendar::types::MonthCode;
+//! use icu::calendar::Date;
+//!
+//! let date_iso = Date::try_new_iso(2023, 8, 1).unwrap();
+//!
+//! let date_solar = date_iso.to_calendar(HinduSolar::new());
+//! assert_eq!(date_solar.era_year().year, 1945);
+//! assert_eq!(date_solar.month().standard_code, MonthCode::new_normal(4).unwrap());
+//! assert_eq!(date_solar.day_of_month().0, 16);
+//!
+//! let date_lunisolar = date_iso.to_calendar(HinduLunisolar::new());
+//! assert_eq!(date_lunisolar.era_year().year, 2080);
+//! // Adhika Śrāvaṇa, the leap month preceding Śrāvaṇa
+//! assert_eq!(date_lunisolar.month().standard_code, MonthCode::new_leap(5).unwrap());
+//! assert_eq!(date_lunisolar.day_of_month().0, 15);
+//! ```
+
+use crate::cal::iso::{Iso, IsoDateInner};
+use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic, PrecomputedDataSource};
+use crate::error::{range_check, DateError};
+use crate::{types, AsCalendar, Calendar, Date, DateDuration, DateDurationUnit, RangeError};
+use calendrical_calculations::hindu::Location;
+use calendrical_calculations::rata_die::RataDie;
+use tinystr::tinystr;
+
+#[path = "hindu/lunisolar_data.rs"]
+mod lunisolar_data;
+#[path = "hindu/solar_data.rs"]
+mod solar_data;
+
+/// A reference location for the Hindu calendars.
+///
+/// The Hindu calendars depend on the times of sunrise and sunset, so dates can differ
+/// between locations. Data is precomputed for [`HinduLocation::Ujjain`]; calendars for
+/// other locations compute each year as needed, which is slower.
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum HinduLocation {
+    /// Ujjain, the traditional reference city of Hindu astronomy
+    #[default]
+    Ujjain,
+    /// New Delhi
+    NewDelhi,
+    /// Mumbai
+    Mumbai,
+    /// Chennai
+    Chennai,
+    /// Kolkata
+    Kolkata,
+}
+
+impl HinduLocation {
+    fn location(self) -> Location {
+        match self {
+            Self::Ujjain => calendrical_calculations::hindu::UJJAIN,
+            Self::NewDelhi => calendrical_calculations::hindu::NEW_DELHI,
+            Self::Mumbai => calendrical_calculations::hindu::MUMBAI,
+            Self::Chennai => calendrical_calculations::hindu::CHENNAI,
+            Self::Kolkata => calendrical_calculations::hindu::KOLKATA,
+        }
+    }
+}
+
+/// The month scheme of a [`HinduLunisolar`] calendar.
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum HinduMonthScheme {
+    /// Months end with the new moon, as in western and southern India.
+    #[default]
+    Amanta,
+    /// Months end with the full moon, as in northern India.
+    Purnimanta,
+}
+
+/// The astronomical [Hindu solar calendar](https://en.wikipedia.org/wiki/Hindu_calendar#Solar_calendar)
+///
+/// Each month begins when the sun enters a sidereal zodiac sign, with the first month starting with
+/// Mesha saṅkrānti in April. A month begins on the day of the saṅkrānti if it happens before sunset,
+/// and on the following day otherwise, so months have 29 to 32 days.
+///
+/// This type can be used with [`Date`] to represent dates in this calendar.
+///
+/// # Era codes
+///
+/// This calendar uses a single era code: `shaka`, with Śaka 0 beginning in 78 CE. Dates before this era use negative years.
+///
+/// # Month codes
+///
+/// This calendar supports 12 solar month codes (`"M01" - "M12"`), starting with Mesha.
+///
+/// # Supported range
+///
+/// The astronomical calculations behind this calendar are only reliable for dates between the
+/// start of the Kali Yuga in 3102 BCE and the end of 9000 CE.
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct HinduSolar {
+    location: HinduLocation,
+}
+
+/// The astronomical [Hindu lunisolar calendar](https://en.wikipedia.org/wiki/Hindu_calendar#Lunisolar_calendar)
+///
+/// Months run from new moon to new moon, and are named after the zodiac sign the sun is in at
+/// their start. When the sun stays in the same sign for two new moons, the first month is a leap
+/// (adhika) month; rarely, the sun passes through a whole sign between two new moons, and the
+/// month named after it is expunged (kṣaya). Years begin with Chaitra, in March or April.
+///
+/// Each day is labeled with the lunar day (tithi) current at sunrise, so a tithi can repeat on two
+/// consecutive days (see [`Date::is_leap_day()`]), or be skipped altogether. Month lengths,
+/// ordinals and arithmetic use civil days, while [`Date::day_of_month()`] returns the tithi.
+///
+/// This type can be used with [`Date`] to represent dates in this calendar.
+///
+/// # Era codes
+///
+/// This calendar uses a single era code: `vikram`, for the Vikrama Saṃvat, which is 57 years ahead of the Gregorian year for
+/// most of the year. Dates before this era use negative years.
+///
+/// # Month codes
+///
+/// This calendar is a lunisolar calendar. It supports regular month codes `"M01" - "M12"` starting with Chaitra,
+/// as well as leap month codes `"M01L" - "M12L"` for the adhika months preceding the regular month.
+///
+/// With [`HinduMonthScheme::Purnimanta`], the days after the full moon are named after the following month.
+/// This is reflected in [`MonthInfo::formatting_code`](types::MonthInfo::formatting_code); month ordinals
+/// and [`MonthInfo::standard_code`](types::MonthInfo::standard_code) follow the amānta months.
+///
+/// # Supported range
+///
+/// The astronomical calculations behind this calendar are only reliable for dates between the
+/// start of the Kali Yuga in 3102 BCE and the end of 9000 CE.
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct HinduLunisolar {
+    location: HinduLocation,
+    scheme: HinduMonthScheme,
+}
+
+impl HinduSolar {
+    /// Creates a new [`HinduSolar`] calendar for Ujjain.
+    pub const fn new() -> Self {
+        Self::new_with_location(HinduLocation::Ujjain)
+    }
+
+    /// Creates a new [`HinduSolar`] calendar for the given location.
+    pub const fn new_with_location(location: HinduLocation) -> Self {
+        Self { location }
+    }
+
+    /// The reference location of this calendar.
+    pub fn location(self) -> HinduLocation {
+        self.location
+    }
+
+    fn year_data(self, extended_year: i32) -> HinduSolarYear {
+        if self.location == HinduLocation::Ujjain {
+            if let Some(&packed) = usize::try_from(extended_year - solar_data::STARTING_YEAR)
+                .ok()
+                .and_then(|i| solar_data::DATA.get(i))
+            {
+                return HinduSolarYear {
+                    extended_year,
+                    new_year: packed.new_year(extended_year),
+                    packed,
+                };
+            }
+        }
+        Self::compute_year_data(self.location, extended_year)
+    }
+
+    fn compute_year_data(location: HinduLocation, extended_year: i32) -> HinduSolarYear {
+        let location = location.location();
+        let new_year =
+            calendrical_calculations::hindu::hindu_solar_month_start(extended_year, 1, location);
+        let mut start = new_year;
+        let month_lengths = core::array::from_fn(|month_idx| {
+            let next_start = if month_idx == 11 {
+                calendrical_calculations::hindu::hindu_solar_month_start(
+                    extended_year + 1,
+                    1,
+                    location,
+                )
+            } else {
+                calendrical_calculations::hindu::hindu_solar_month_start(
+                    extended_year,
+                    month_idx as u8 + 2,
+                    location,
+                )
+            };
+            let length = (next_start - start) as u8;
+            start = next_start;
+            length
+        });
+        HinduSolarYear {
+            extended_year,
+            new_year,
+            packed: PackedHinduSolarYearInfo::new_computed(extended_year, month_lengths, new_year),
+        }
+    }
+}
+
+impl HinduLunisolar {
+    /// Creates a new amānta [`HinduLunisolar`] calendar for Ujjain.
+    pub const fn new() -> Self {
+        Self::new_with_location(HinduLocation::Ujjain, HinduMonthScheme::Amanta)
+    }
+
+    /// Creates a new pūrṇimānta [`HinduLunisolar`] calendar for Ujjain.
+    pub const fn new_purnimanta() -> Self {
+        Self::new_with_location(HinduLocation::Ujjain, HinduMonthScheme::Purnimanta)
+    }
+
+    /// Creates a new [`HinduLunisolar`] calendar for the given location and month scheme.
+    pub const fn new_with_location(location: HinduLocation, scheme: HinduMonthScheme) -> Self {
+        Self { location, scheme }
+    }
+
+    /// The reference location of this calendar.
+    pub fn location(self) -> HinduLocation {
+        self.location
+    }
+
+    /// The month scheme of this calendar.
+    pub fn scheme(self) -> HinduMonthScheme {
+        self.scheme
+    }
+
+    fn year_data(self, extended_year: i32) -> HinduLunisolarYear {
+        if self.location == HinduLocation::Ujjain {
+            if let Some(&packed) = usize::try_from(extended_year - lunisolar_data::STARTING_YEAR)
+                .ok()
+                .and_then(|i| lunisolar_data::DATA.get(i))
+            {
+                return HinduLunisolarYear {
+                    extended_year,
+                    new_year: packed.new_year(extended_year),
+                    packed,
+                };
+            }
+        }
+        Self::compute_year_data(self.location, extended_year)
+    }
+
+    fn compute_year_data(location: HinduLocation, extended_year: i32) -> HinduLunisolarYear {
+        let (new_year, month_lengths, leap_months, expunged) =
+            calendrical_calculations::hindu::hindu_lunar_month_structure(
+                extended_year,
+                location.location(),
+            );
+        let mut leap_ordinals = [0; 2];
+        let mut leap_count = 0;
+        for (ordinal, _) in (1..).zip(leap_months).filter(|&(_, leap)| leap) {
+            if let Some(slot) = leap_ordinals.get_mut(leap_count) {
+                *slot = ordinal;
+                leap_count += 1;
+            }
+        }
+        HinduLunisolarYear {
+            extended_year,
+            new_year,
+            packed: PackedHinduLunisolarYearInfo::new_computed(
+                extended_year,
+                month_lengths,
+                leap_ordinals.get(..leap_count).unwrap_or_default(),
+                expunged,
+                new_year,
+            ),
+        }
+    }
+
+    /// The tithi at sunrise on the given day
+    fn tithi(self, rd: RataDie) -> u8 {
+        calendrical_calculations::hindu::hindu_lunar_day(rd, self.location.location())
+    }
+
+    /// Finds the day of the given month that is labeled with the given tithi
+    fn day_for_tithi(
+        self,
+        year: HinduLunisolarYear,
+        month: u8,
+        tithi: u8,
+    ) -> Result<u8, RangeError> {
+        let tithi = range_check(tithi, "day", 1..=30)?;
+        let month_start = year.new_year + year.days_before_month(month) as i64;
+        // Tithis never decrease within a month, so we can search for the first
+        // day whose tithi is at least the requested one. Month starts come from the
+        // new moon and tithis from the lunar phase, which far from the present can
+        // disagree by a few hours, so the first days of a month can still show the
+        // last tithi of the previous month, and the last days the first tithi of the
+        // next month.
+        let tithi_of_day = |day: u8| match self.tithi(month_start + (day - 1) as i64) {
+            t if day <= 3 && t >= 27 => 0,
+            t if day >= 27 && t <= 3 => 31,
+            t => t,
+        };
+        let (mut low, mut high) = (1, HinduLunisolar::days_in_provided_month(year, month) + 1);
+        while low < high {
+            let mid = (low + high) / 2;
+            if tithi_of_day(mid) < tithi {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        let found = if low <= HinduLunisolar::days_in_provided_month(year, month) {
+            tithi_of_day(low)
+        } else {
+            31
+        };
+        if found == tithi {
+            Ok(low)
+        } else {
+            // The tithi was expunged
+            Err(RangeError {
+                field: "day",
+                value: tithi as i32,
+                min: found as i32,
+                max: 30,
+            })
+        }
+    }
+}
+
+/// The packed month lengths and new year of a [`HinduSolar`] year.
+///
+/// Bit structure:
+///
+/// ```text
+/// Bit:             0   1   2   3   ... 22  23  24  25  26  27  28
+/// Value:           [month 1][month 2]  ... [month 12][  new year  ]
+/// ```
+///
+/// The lowest 24 bits store the length of each month minus 29, using two bits per month.
+/// The next five bits store the offset of the new year from April 1 of the related ISO year.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub(crate) struct PackedHinduSolarYearInfo(u32);
+
+impl PackedHinduSolarYearInfo {
+    const fn earliest_new_year(extended_year: i32) -> RataDie {
+        calendrical_calculations::iso::const_fixed_from_iso(extended_year + 78, 4, 1)
+    }
+
+    pub(crate) const fn new(
+        extended_year: i32,
+        month_lengths: [u8; 12],
+        new_year: RataDie,
+    ) -> Self {
+        let mut packed = 0;
+        let mut i = 0;
+        while i < 12 {
+            // Clamp to something we can represent, lengths are always 29 to 32 days
+            #[allow(clippy::indexing_slicing)] // const iteration
+            let length = month_lengths[i].saturating_sub(29);
+            let length = if length > 3 { 3 } else { length };
+            packed |= (length as u32) << (2 * i);
+            i += 1;
+        }
+        let new_year_offset = new_year.since(Self::earliest_new_year(extended_year));
+        debug_assert!(0 <= new_year_offset && new_year_offset < 32);
+        packed |= ((new_year_offset & 0x1F) as u32) << 24;
+        Self(packed)
+    }
+
+    /// Packs a computed year.
+    ///
+    /// Far from the present, the new year drifts away from the ISO calendar and no longer fits
+    /// into the packed offset. Only the precomputed data reads the new year back from the packed
+    /// value (see `test_precomputed_data`), computed years store it separately, so it is clamped.
+    fn new_computed(extended_year: i32, month_lengths: [u8; 12], new_year: RataDie) -> Self {
+        let earliest_new_year = Self::earliest_new_year(extended_year);
+        let new_year_offset = new_year.since(earliest_new_year).clamp(0, 0x1F);
+        Self::new(
+            extended_year,
+            month_lengths,
+            earliest_new_year + new_year_offset,
+        )
+    }
+
+    fn new_year(self, extended_year: i32) -> RataDie {
+        Self::earliest_new_year(extended_year) + ((self.0 >> 24) & 0x1F) as i64
+    }
+
+    fn days_in_month(self, month: u8) -> u8 {
+        29 + ((self.0 >> (2 * (month.saturating_sub(1) % 12))) & 0x3) as u8
+    }
+}
+
+/// The packed month structure and new year of a [`HinduLunisolar`] year.
+///
+/// Bit structure:
+///
+/// ```text
+/// Bit:             0  ...  12  13 ... 16  17 ... 20  21 ... 24  25 ... 31
+/// Value:           [ lengths ] [ leap 1 ] [ leap 2 ] [expunged] [new year]
+/// ```
+///
+/// The lowest 13 bits are set for months with 30 days. The next two four-bit values store the
+/// ordinals of the leap months, or zero, and the following four bits store the number of the
+/// expunged month, or zero. The highest seven bits store the offset of the new year from
+/// March 1 of the related ISO year.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub(crate) struct PackedHinduLunisolarYearInfo(u32);
+
+impl PackedHinduLunisolarYearInfo {
+    const fn earliest_new_year(extended_year: i32) -> RataDie {
+        calendrical_calculations::iso::const_fixed_from_iso(extended_year - 57, 3, 1)
+    }
+
+    pub(crate) const fn new(
+        extended_year: i32,
+        month_lengths: [bool; 13],
+        leap_months: &[u8],
+        expunged: Option<u8>,
+        new_year: RataDie,
+    ) -> Self {
+        debug_assert!(leap_months.len() <= 2);
+        let mut packed = 0;
+        let mut i = 0;
+        while i < 13 {
+            #[allow(clippy::indexing_slicing)] // const iteration
+            if month_lengths[i] {
+                packed |= 1 << i;
+            }
+            i += 1;
+        }
+        let mut i = 0;
+        while i < leap_months.len() && i < 2 {
+            #[allow(clippy::indexing_slicing)] // const iteration
+            let leap_month = leap_months[i];
+            debug_assert!(1 <= leap_month && leap_month <= 13);
+            packed |= ((leap_month & 0xF) as u32) << (13 + 4 * i);
+            i += 1;
+        }
+        if let Some(expunged) = expunged {
+            debug_assert!(1 <= expunged && expunged <= 12);
+            packed |= ((expunged & 0xF) as u32) << 21;
+        }
+        let new_year_offset = new_year.since(Self::earliest_new_year(extended_year));
+        debug_assert!(0 <= new_year_offset && new_year_offset < 128);
+        packed |= ((new_year_offset & 0x7F) as u32) << 25;
+        Self(packed)
+    }
+
+    /// Packs a computed year, clamping the new year like [`PackedHinduSolarYearInfo::new_computed`].
+    fn new_computed(
+        extended_year: i32,
+        month_lengths: [bool; 13],
+        leap_months: &[u8],
+        expunged: Option<u8>,
+        new_year: RataDie,
+    ) -> Self {
+        let earliest_new_year = Self::earliest_new_year(extended_year);
+        let new_year_offset = new_year.since(earliest_new_year).clamp(0, 0x7F);
+        Self::new(
+            extended_year,
+            month_lengths,
+            leap_months,
+            expunged,
+            earliest_new_year + new_year_offset,
+        )
+    }
+
+    fn new_year(self, extended_year: i32) -> RataDie {
+        Self::earliest_new_year(extended_year) + ((self.0 >> 25) & 0x7F) as i64
+    }
+
+    fn leap_ordinals(self) -> [u8; 2] {
+        [((self.0 >> 13) & 0xF) as u8, ((self.0 >> 17) & 0xF) as u8]
+    }
+
+    fn expunged(self) -> Option<u8> {
+        match ((self.0 >> 21) & 0xF) as u8 {
+            0 => None,
+            e => Some(e),
+        }
+    }
+
+    fn is_leap_ordinal(self, ordinal: u8) -> bool {
+        ordinal != 0 && self.leap_ordinals().contains(&ordinal)
+    }
+
+    fn months(self) -> u8 {
+        12 + self.leap_ordinals().iter().filter(|&&o| o != 0).count() as u8
+            - self.expunged().is_some() as u8
+    }
+}
+
+/// Information about a [`HinduSolar`] year.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub(crate) struct HinduSolarYear {
+    extended_year: i32,
+    new_year: RataDie,
+    packed: PackedHinduSolarYearInfo,
+}
+
+impl From<HinduSolarYear> for i32 {
+    fn from(value: HinduSolarYear) -> Self {
+        value.extended_year
+    }
+}
+
+/// Information about a [`HinduLunisolar`] year.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub(crate) struct HinduLunisolarYear {
+    extended_year: i32,
+    new_year: RataDie,
+    packed: PackedHinduLunisolarYearInfo,
+}
+
+impl From<HinduLunisolarYear> for i32 {
+    fn from(value: HinduLunisolarYear) -> Self {
+        value.extended_year
+    }
+}
+
+impl HinduLunisolarYear {
+    /// The month number and leap flag of the month with the given ordinal
+    fn month_number(self, ordinal: u8) -> (u8, bool) {
+        let mut number = 1;
+        for o in 1..ordinal {
+            if !self.packed.is_leap_ordinal(o) {
+                number += 1;
+                if Some(number) == self.packed.expunged() {
+                    number += 1;
+                }
+            }
+        }
+        if Some(number) == self.packed.expunged() {
+            number += 1;
+        }
+        (number, self.packed.is_leap_ordinal(ordinal))
+    }
+
+    fn ordinal(self, number: u8, leap: bool) -> Option<u8> {
+        (1..=self.packed.months()).find(|&o| self.month_number(o) == (number, leap))
+    }
+
+    fn days_before_month(self, month: u8) -> u16 {
+        HinduLunisolar::day_of_provided_year(self, month, 1) - 1
+    }
+}
+
+/// The inner date type used for representing [`Date`]s of [`HinduSolar`]. See [`Date`] and [`HinduSolar`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct HinduSolarDateInner(ArithmeticDate<HinduSolar>);
+
+/// The inner date type used for representing [`Date`]s of [`HinduLunisolar`]. See [`Date`] and [`HinduLunisolar`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct HinduLunisolarDateInner(ArithmeticDate<HinduLunisolar>);
+
+impl CalendarArithmetic for HinduSolar {
+    type YearInfo = HinduSolarYear;
+
+    fn days_in_provided_month(year: Self::YearInfo, month: u8) -> u8 {
+        year.packed.days_in_month(month)
+    }
+
+    fn months_in_provided_year(_year: Self::YearInfo) -> u8 {
+        12
+    }
+
+    fn provided_year_is_leap(year: Self::YearInfo) -> bool {
+        Self::days_in_provided_year(year) > 365
+    }
+
+    fn last_month_day_in_provided_year(year: Self::YearInfo) -> (u8, u8) {
+        (12, Self::days_in_provided_month(year, 12))
+    }
+}
+
+impl CalendarArithmetic for HinduLunisolar {
+    type YearInfo = HinduLunisolarYear;
+
+    fn days_in_provided_month(year: Self::YearInfo, month: u8) -> u8 {
+        29 + ((year.packed.0 >> (month.saturating_sub(1) % 13)) & 1) as u8
+    }
+
+    fn months_in_provided_year(year: Self::YearInfo) -> u8 {
+        year.packed.months()
+    }
+
+    fn provided_year_is_leap(year: Self::YearInfo) -> bool {
+        year.packed.leap_ordinals()[0] != 0
+    }
+
+    fn last_month_day_in_provided_year(year: Self::YearInfo) -> (u8, u8) {
+        let month = Self::months_in_provided_year(year);
+        (month, Self::days_in_provided_month(year, month))
+    }
+}
+
+impl PrecomputedDataSource<HinduSolarYear> for HinduSolar {
+    fn load_or_compute_info(&self, extended_year: i32) -> HinduSolarYear {
+        self.year_data(extended_year)
+    }
+}
+
+impl PrecomputedDataSource<HinduLunisolarYear> for HinduLunisolar {
+    fn load_or_compute_info(&self, extended_year: i32) -> HinduLunisolarYear {
+        self.year_data(extended_year)
+    }
+}
+
+impl crate::cal::scaffold::UnstableSealed for HinduSolar {}
+impl Calendar for HinduSolar {
+    type DateInner = HinduSolarDateInner;
+    type Year = types::EraYear;
+
+    fn from_codes(
+        &self,
+        era: Option<&str>,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, DateError> {
+        match era {
+            None | Some("shaka") => {}
+            Some(_) => return Err(DateError::UnknownEra),
+        }
+        let Some((month @ 1..=12, false)) = month_code.parsed() else {
+            return Err(DateError::UnknownMonthCode(month_code));
+        };
+        Ok(HinduSolarDateInner(ArithmeticDate::new_from_ordinals(
+            self.year_data(year),
+            month,
+            day,
+        )?))
+    }
+
+    fn from_rata_die(&self, rd: RataDie) -> Self::DateInner {
+        let iso_year = Iso.from_rata_die(rd).iso_year();
+        let year = self.year_data(iso_year - 78);
+        let year = if rd < year.new_year {
+            self.year_data(iso_year - 79)
+        } else {
+            let next_year = self.year_data(iso_year - 77);
+            if rd < next_year.new_year {
+                year
+            } else {
+                next_year
+            }
+        };
+        HinduSolarDateInner(ArithmeticDate::date_from_year_day(
+            year,
+            (rd - year.new_year) as u16 + 1,
+        ))
+    }
+
+    fn to_rata_die(&self, date: &Self::DateInner) -> RataDie {
+        date.0.year.new_year + date.0.day_of_year().0 as i64 - 1
+    }
+
+    fn from_iso(&self, iso: IsoDateInner) -> Self::DateInner {
+        self.from_rata_die(Iso.to_rata_die(&iso))
+    }
+
+    fn to_iso(&self, date: &Self::DateInner) -> IsoDateInner {
+        Iso.from_rata_die(self.to_rata_die(date))
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u16 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset, self)
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year_info(&self, date: &Self::DateInner) -> Self::Year {
+        let extended_year = date.0.extended_year();
+        types::EraYear {
+            era_index: Some(0),
+            era: tinystr!(16, "shaka"),
+            year: extended_year,
+            extended_year,
+            ambiguity: types::YearAmbiguity::CenturyRequired,
+        }
+    }
+
+    fn is_in_leap_year(&self, date: &Self::DateInner) -> bool {
+        Self::provided_year_is_leap(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::MonthInfo {
+        date.0.month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year(&self, date: &Self::DateInner) -> types::DayOfYear {
+        date.0.day_of_year()
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Hindu solar"
+    }
+
+    fn calendar_algorithm(&self) -> Option<crate::preferences::CalendarAlgorithm> {
+        None
+    }
+}
+
+impl crate::cal::scaffold::UnstableSealed for HinduLunisolar {}
+impl Calendar for HinduLunisolar {
+    type DateInner = HinduLunisolarDateInner;
+    type Year = types::EraYear;
+
+    fn from_codes(
+        &self,
+        era: Option<&str>,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, DateError> {
+        match era {
+            None | Some("vikram") => {}
+            Some(_) => return Err(DateError::UnknownEra),
+        }
+        let year = self.year_data(year);
+        let Some(month) = month_code
+            .parsed()
+            .and_then(|(number, leap)| year.ordinal(number, leap))
+        else {
+            return Err(DateError::UnknownMonthCode(month_code));
+        };
+        let day = self.day_for_tithi(year, month, day)?;
+        Ok(HinduLunisolarDateInner(ArithmeticDate::new_unchecked(
+            year, month, day,
+        )))
+    }
+
+    fn from_rata_die(&self, rd: RataDie) -> Self::DateInner {
+        let iso_year = Iso.from_rata_die(rd).iso_year();
+        let year = self.year_data(iso_year + 57);
+        let year = if rd < year.new_year {
+            self.year_data(iso_year + 56)
+        } else {
+            let next_year = self.year_data(iso_year + 58);
+            if rd < next_year.new_year {
+                year
+            } else {
+                next_year
+            }
+        };
+        HinduLunisolarDateInner(ArithmeticDate::date_from_year_day(
+            year,
+            (rd - year.new_year) as u16 + 1,
+        ))
+    }
+
+    fn to_rata_die(&self, date: &Self::DateInner) -> RataDie {
+        date.0.year.new_year + date.0.day_of_year().0 as i64 - 1
+    }
+
+    fn from_iso(&self, iso: IsoDateInner) -> Self::DateInner {
+        self.from_rata_die(Iso.to_rata_die(&iso))
+    }
+
+    fn to_iso(&self, date: &Self::DateInner) -> IsoDateInner {
+        Iso.from_rata_die(self.to_rata_die(date))
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u16 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset, self)
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year_info(&self, date: &Self::DateInner) -> Self::Year {
+        let extended_year = date.0.extended_year();
+        types::EraYear {
+            era_index: Some(0),
+            era: tinystr!(16, "vikram"),
+            year: extended_year,
+            extended_year,
+            ambiguity: types::YearAmbiguity::CenturyRequired,
+        }
+    }
+
+    fn is_in_leap_year(&self, date: &Self::DateInner) -> bool {
+        Self::provided_year_is_leap(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::MonthInfo {
+        let (number, leap) = date.0.year.month_number(date.0.month);
+        let code = |number, leap| {
+            if leap {
+                types::MonthCode::new_leap(number)
+            } else {
+                types::MonthCode::new_normal(number)
+            }
+            .unwrap_or(types::MonthCode(tinystr!(4, "und")))
+        };
+        let standard_code = code(number, leap);
+        let formatting_code = if self.scheme == HinduMonthScheme::Purnimanta
+            && !leap
+            && self.day_of_month(date).0 > 15
+        {
+            code(number % 12 + 1, false)
+        } else {
+            standard_code
+        };
+        types::MonthInfo {
+            ordinal: date.0.month,
+            standard_code,
+            formatting_code,
+        }
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        types::DayOfMonth(self.tithi(self.to_rata_die(date)))
+    }
+
+    fn day_of_year(&self, date: &Self::DateInner) -> types::DayOfYear {
+        date.0.day_of_year()
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Hindu lunisolar"
+    }
+
+    fn calendar_algorithm(&self) -> Option<crate::preferences::CalendarAlgorithm> {
+        None
+    }
+}
+
+impl<A: AsCalendar<Calendar = HinduSolar>> Date<A> {
+    /// Construct a new Hindu solar date, with the year provided in the Śaka era.
+    ///
+    /// ```rust
+    /// use icu::calendar::cal::HinduSolar;
+    /// use icu::calendar::Date;
+    ///
+    /// let date =
+    ///     Date::try_new_hindu_solar_with_calendar(1946, 1, 1, HinduSolar::new())
+    ///         .expect("Failed to initialize Hindu solar Date instance.");
+    ///
+    /// assert_eq!(date.to_iso(), Date::try_new_iso(2024, 4, 14).unwrap());
+    /// ```
+    pub fn try_new_hindu_solar_with_calendar(
+        year: i32,
+        month: u8,
+        day: u8,
+        calendar: A,
+    ) -> Result<Self, RangeError> {
+        let year = calendar.as_calendar().year_data(year);
+        Ok(Date::from_raw(
+            HinduSolarDateInner(ArithmeticDate::new_from_ordinals(year, month, day)?),
+            calendar,
+        ))
+    }
+}
+
+impl<A: AsCalendar<Calendar = HinduLunisolar>> Date<A> {
+    /// Construct a new Hindu lunisolar date, with the year provided in the Vikrama era.
+    ///
+    /// `month` is the ordinal of the month in the year (including leap months), and `day` is
+    /// the tithi. If the tithi repeats, this returns the first of the two days; if it was
+    /// expunged, this returns an error.
+    ///
+    /// ```rust
+    /// use icu::calendar::cal::HinduLunisolar;
+    /// use icu::calendar::Date;
+    ///
+    /// let date =
+    ///     Date::try_new_hindu_lunisolar_with_calendar(2080, 1, 1, HinduLunisolar::new())
+    ///         .expect("Failed to initialize Hindu lunisolar Date instance.");
+    ///
+    /// assert_eq!(date.to_iso(), Date::try_new_iso(2023, 3, 22).unwrap());
+    /// ```
+    pub fn try_new_hindu_lunisolar_with_calendar(
+        year: i32,
+        month: u8,
+        day: u8,
+        calendar: A,
+    ) -> Result<Self, RangeError> {
+        let cal = calendar.as_calendar();
+        let year = cal.year_data(year);
+        let month = range_check(month, "month", 1..=year.packed.months())?;
+        let day = cal.day_for_tithi(year, month, day)?;
+        Ok(Date::from_raw(
+            HinduLunisolarDateInner(ArithmeticDate::new_unchecked(year, month, day)),
+            calendar,
+        ))
+    }
+
+    /// Returns whether this date is a leap day, i.e. the second of two consecutive
+    /// days labeled with the same tithi.
+    ///
+    /// ```rust
+    /// use icu::calendar::cal::HinduLunisolar;
+    /// use icu::calendar::Date;
+    ///
+    /// let first = Date::try_new_iso(2023, 4, 2)
+    ///     .unwrap()
+    ///     .to_calendar(HinduLunisolar::new());
+    /// let second = Date::try_new_iso(2023, 4, 3)
+    ///     .unwrap()
+    ///     .to_calendar(HinduLunisolar::new());
+    ///
+    /// assert_eq!(first.day_of_month(), second.day_of_month());
+    /// assert!(!first.is_leap_day());
+    /// assert!(second.is_leap_day());
+    /// ```
+    pub fn is_leap_day(&self) -> bool {
+        let cal = self.calendar();
+        let rd = cal.to_rata_die(self.inner());
+        cal.tithi(rd) == cal.tithi(rd - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::MonthCode;
+
+    #[test]
+    fn test_precomputed_data() {
+        for (i, &packed) in (solar_data::STARTING_YEAR..)
+            .zip(solar_data::DATA)
+            .step_by(10)
+        {
+            let computed = HinduSolar::compute_year_data(HinduLocation::Ujjain, i);
+            assert_eq!(packed.new_year(i), computed.new_year, "{i}");
+            assert_eq!(packed, computed.packed, "{i}");
+        }
+        for (i, &packed) in (lunisolar_data::STARTING_YEAR..)
+            .zip(lunisolar_data::DATA)
+            .step_by(10)
+        {
+            let computed = HinduLunisolar::compute_year_data(HinduLocation::Ujjain, i);
+            assert_eq!(packed.new_year(i), computed.new_year, "{i}");
+            assert_eq!(packed, computed.packed, "{i}");
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let calendars = [HinduLocation::Ujjain, HinduLocation::Chennai];
+        for location in calendars {
+            let solar = HinduSolar::new_with_location(location);
+            let lunisolar = HinduLunisolar::new_with_location(location, HinduMonthScheme::Amanta);
+            for rd in (738_000..739_500).step_by(7) {
+                let rd = RataDie::new(rd);
+
+                let date = solar.from_rata_die(rd);
+                assert_eq!(solar.to_rata_die(&date), rd);
+                let (y, m, d) = calendrical_calculations::hindu::hindu_solar_from_fixed(
+                    rd,
+                    location.location(),
+                );
+                assert_eq!(
+                    (
+                        solar.year_info(&date).year,
+                        solar.month(&date).ordinal,
+                        solar.day_of_month(&date).0
+                    ),
+                    (y, m, d),
+                    "{rd:?}"
+                );
+
+                let date = lunisolar.from_rata_die(rd);
+                assert_eq!(lunisolar.to_rata_die(&date), rd);
+                let (y, m, leap, d, _) = calendrical_calculations::hindu::hindu_lunar_from_fixed(
+                    rd,
+                    location.location(),
+                );
+                let month = lunisolar.month(&date);
+                assert_eq!(
+                    (
+                        lunisolar.year_info(&date).year,
+                        month.standard_code.parsed(),
+                        lunisolar.day_of_month(&date).0
+                    ),
+                    (y, Some((m, leap)), d),
+                    "{rd:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_supported_range() {
+        fn check<C: Calendar + Copy>(calendar: C, rd: RataDie) {
+            let date = Date::from_rata_die(rd, calendar);
+            assert_eq!(date.to_rata_die(), rd, "{date:?}");
+
+            let year = date.year().era().unwrap();
+            let roundtrip = Date::try_new_from_codes(
+                Some(year.era.as_str()),
+                year.year,
+                date.month().standard_code,
+                date.day_of_month().0,
+                calendar,
+            )
+            .unwrap();
+            // A repeated tithi resolves to the first of the two days
+            assert!(matches!(rd - roundtrip.to_rata_die(), 0 | 1), "{date:?}");
+            assert_eq!(roundtrip.day_of_month(), date.day_of_month());
+
+            let first_day = rd - i64::from(date.day_of_year().0) + 1;
+            let last_day = first_day + i64::from(date.days_in_year()) - 1;
+            let last = Date::from_rata_die(last_day, calendar);
+            assert_eq!(last.year(), date.year(), "{date:?}");
+            assert_eq!(last.day_of_year().0, date.days_in_year(), "{date:?}");
+            assert_eq!(last.month().ordinal, date.months_in_year(), "{date:?}");
+        }
+
+        let min = calendrical_calculations::hindu::HINDU_EPOCH;
+        let max = calendrical_calculations::iso::fixed_from_iso(9000, 12, 31);
+        for rd in [min, max] {
+            check(HinduSolar::new(), rd);
+            check(HinduLunisolar::new(), rd);
+            check(HinduLunisolar::new_purnimanta(), rd);
+        }
+    }
+
+    #[test]
+    fn test_tithis() {
+        let calendar = HinduLunisolar::new();
+        // Chaitra 2080 has a repeated and an expunged tithi
+        let mut repeated = 0;
+        let mut expunged = 0;
+        let mut previous = 0;
+        for day in 0..29 {
+            let date = Date::try_new_iso(2023, 3, 22)
+                .unwrap()
+                .to_calendar(calendar)
+                .added(DateDuration::new(0, 0, 0, day));
+            let tithi = date.day_of_month().0;
+            if date.is_leap_day() {
+                repeated += 1;
+                assert_eq!(tithi, previous);
+            } else {
+                let constructed = Date::try_new_from_codes(
+                    None,
+                    2080,
+                    date.month().standard_code,
+                    tithi,
+                    calendar,
+                )
+                .unwrap();
+                assert_eq!(constructed, date);
+            }
+            for skipped in previous + 1..tithi {
+                expunged += 1;
+                assert!(matches!(
+                    Date::try_new_hindu_lunisolar_with_calendar(2080, 1, skipped, calendar),
+                    Err(RangeError { min, .. }) if min == tithi as i32
+                ));
+            }
+            previous = tithi;
+        }
+        assert!(repeated > 0);
+        assert!(expunged > 0);
+    }
+
+    #[test]
+    fn test_leap_and_expunged_months() {
+        let calendar = HinduLunisolar::new();
+
+        // 2023: Adhika Śrāvaṇa
+        let date = Date::try_new_hindu_lunisolar_with_calendar(2080, 5, 1, calendar).unwrap();
+        assert_eq!(date.month().standard_code, MonthCode::new_leap(5).unwrap());
+        assert_eq!(date.months_in_year(), 13);
+        assert!(date.is_in_leap_year());
+        let date = Date::try_new_hindu_lunisolar_with_calendar(2080, 6, 1, calendar).unwrap();
+        assert_eq!(
+            date.month().standard_code,
+            MonthCode::new_normal(5).unwrap()
+        );
+
+        // 1982-83: two leap months and an expunged month
+        let date = Date::try_new_hindu_lunisolar_with_calendar(2039, 1, 1, calendar).unwrap();
+        assert_eq!(date.months_in_year(), 13);
+        let codes = (1..=13)
+            .map(|m| {
+                date.added(DateDuration::new(0, m - 1, 0, 0))
+                    .month()
+                    .standard_code
+                    .0
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            [
+                "M01", "M02", "M03", "M04", "M05", "M06", "M07L", "M07", "M08", "M09", "M10",
+                "M12L", "M12"
+            ]
+        );
+        assert_eq!(
+            Date::try_new_from_codes(None, 2039, MonthCode::new_normal(11).unwrap(), 1, calendar),
+            Err(DateError::UnknownMonthCode(
+                MonthCode::new_normal(11).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_purnimanta() {
+        let amanta = HinduLunisolar::new();
+        let purnimanta = HinduLunisolar::new_purnimanta();
+        for day in [10, 20] {
+            let iso = Date::try_new_hindu_lunisolar_with_calendar(2080, 1, day, amanta)
+                .unwrap()
+                .to_iso();
+            let a = iso.to_calendar(amanta).month();
+            let p = iso.to_calendar(purnimanta).month();
+            assert_eq!(
+                a,
+                types::MonthInfo {
+                    ordinal: 1,
+                    standard_code: MonthCode::new_normal(1).unwrap(),
+                    formatting_code: MonthCode::new_normal(1).unwrap(),
+                }
+            );
+            assert_eq!(p.standard_code, a.standard_code);
+            assert_eq!(
+                p.formatting_code,
+                MonthCode::new_normal(if day > 15 { 2 } else { 1 }).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,219 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data obtained from [`calendrical_calculations`], for Ujjain.
+
+use super::PackedHinduLunisolarYearInfo;
+
+pub const STARTING_YEAR: i32 = 1957;
+
+#[rustfmt::skip]
+pub const DATA: &[PackedHinduLunisolarYearInfo] = {
+    use calendrical_calculations::iso::const_fixed_from_iso as iso;
+    let l = true; // long
+    let s = false; // short
+    &[
+        PackedHinduLunisolarYearInfo::new(1957, [l, s, l, s, l, s, l, l, s, l, l, s, s], &[], None, iso(1900, 3, 31)),
+        PackedHinduLunisolarYearInfo::new(1958, [s, l, s, s, l, s, l, l, l, s, l, l, s], &[5], None, iso(1901, 3, 21)),
+        PackedHinduLunisolarYearInfo::new(1959, [s, l, s, s, l, s, l, l, s, l, l, l, s], &[], None, iso(1902, 4, 9)),
+        PackedHinduLunisolarYearInfo::new(1960, [s, s, l, s, s, l, s, l, s, l, l, l, s], &[], None, iso(1903, 3, 30)),
+        PackedHinduLunisolarYearInfo::new(1961, [s, l, s, l, s, s, l, s, l, s, l, l, s], &[3], None, iso(1904, 3, 18)),
+        PackedHinduLunisolarYearInfo::new(1962, [l, l, s, l, s, s, l, s, l, s, l, s, s], &[], None, iso(1905, 4, 5)),
+        PackedHinduLunisolarYearInfo::new(1963, [l, l, s, l, l, s, s, l, s, l, s, l, s], &[12], None, iso(1906, 3, 25)),
+        PackedHinduLunisolarYearInfo::new(1964, [l, s, l, l, s, l, s, l, s, l, s, l, s], &[], None, iso(1907, 4, 13)),
+        PackedHinduLunisolarYearInfo::new(1965, [s, l, s, l, s, l, l, s, l, s, l, s, s], &[], None, iso(1908, 4, 2)),
+        PackedHinduLunisolarYearInfo::new(1966, [l, s, s, l, s, l, l, l, s, l, s, l, s], &[5], None, iso(1909, 3, 22)),
+        PackedHinduLunisolarYearInfo::new(1967, [l, s, s, l, s, l, l, s, l, l, s, l, s], &[], None, iso(1910, 4, 10)),
+        PackedHinduLunisolarYearInfo::new(1968, [s, l, s, s, l, s, l, s, l, l, l, s, s], &[], None, iso(1911, 3, 31)),
+        PackedHinduLunisolarYearInfo::new(1969, [l, s, l, s, s, l, s, l, s, l, l, s, l], &[4], None, iso(1912, 3, 19)),
+        PackedHinduLunisolarYearInfo::new(1970, [l, s, l, s, s, l, s, l, s, l, s, l, s], &[], None, iso(1913, 4, 7)),
+        PackedHinduLunisolarYearInfo::new(1971, [l, l, s, l, s, s, l, s, l, s, l, s, s], &[], None, iso(1914, 3, 27)),
+        PackedHinduLunisolarYearInfo::new(1972, [l, l, s, l, s, l, s, l, s, l, s, l, s], &[2], None, iso(1915, 3, 16)),
+        PackedHinduLunisolarYearInfo::new(1973, [l, s, l, l, s, l, s, l, s, l, s, l, s], &[], None, iso(1916, 4, 3)),
+        PackedHinduLunisolarYearInfo::new(1974, [s, l, s, l, s, l, l, s, l, s, l, s, l], &[7], None, iso(1917, 3, 24)),
+        PackedHinduLunisolarYearInfo::new(1975, [s, s, l, s, l, l, s, l, l, s, l, s, s], &[], None, iso(1918, 4, 12)),
+        PackedHinduLunisolarYearInfo::new(1976, [l, s, s, l, s, l, s, l, l, l, s, l, s], &[], None, iso(1919, 4, 1)),
+        PackedHinduLunisolarYearInfo::new(1977, [s, l, s, s, l, s, s, l, l, l, s, l, l], &[5], None, iso(1920, 3, 21)),
+        PackedHinduLunisolarYearInfo::new(1978, [s, l, s, s, l, s, s, l, l, s, l, l, s], &[], None, iso(1921, 4, 9)),
+        PackedHinduLunisolarYearInfo::new(1979, [l, s, l, s, s, l, s, s, l, l, s, l, s], &[], None, iso(1922, 3, 29)),
+        PackedHinduLunisolarYearInfo::new(1980, [l, s, l, l, s, s, l, s, l, s, l, s, l], &[3], None, iso(1923, 3, 18)),
+        PackedHinduLunisolarYearInfo::new(1981, [s, l, l, s, l, s, l, s, l, s, l, s, s], &[], None, iso(1924, 4, 5)),
+        PackedHinduLunisolarYearInfo::new(1982, [l, s, l, s, l, l, s, l, s, l, s, l, s], &[12], None, iso(1925, 3, 25)),
+        PackedHinduLunisolarYearInfo::new(1983, [s, l, s, l, l, s, l, l, s, l, s, l, s], &[], None, iso(1926, 4, 13)),
+        PackedHinduLunisolarYearInfo::new(1984, [s, s, l, s, l, s, l, l, l, s, l, s, s], &[], None, iso(1927, 4, 3)),
+        PackedHinduLunisolarYearInfo::new(1985, [l, s, s, l, s, l, s, l, l, s, l, l, s], &[5], None, iso(1928, 3, 22)),
+        PackedHinduLunisolarYearInfo::new(1986, [l, s, s, l, s, s, l, l, s, l, l, l, s], &[], None, iso(1929, 4, 10)),
+        PackedHinduLunisolarYearInfo::new(1987, [s, l, s, s, l, s, s, l, s, l, l, l, s], &[], None, iso(1930, 3, 31)),
+        PackedHinduLunisolarYearInfo::new(1988, [l, s, l, s, s, l, s, s, l, s, l, l, l], &[4], None, iso(1931, 3, 20)),
+        PackedHinduLunisolarYearInfo::new(1989, [s, l, s, l, s, l, s, s, l, s, l, l, s], &[], None, iso(1932, 4, 7)),
+        PackedHinduLunisolarYearInfo::new(1990, [s, l, l, s, l, s, l, s, l, s, s, l, s], &[], None, iso(1933, 3, 27)),
+        PackedHinduLunisolarYearInfo::new(1991, [s, l, l, s, l, s, l, l, s, l, s, l, s], &[2], None, iso(1934, 3, 16)),
+        PackedHinduLunisolarYearInfo::new(1992, [s, l, s, l, l, s, l, l, s, l, s, l, s], &[], None, iso(1935, 4, 4)),
+        PackedHinduLunisolarYearInfo::new(1993, [s, s, l, s, l, s, l, l, s, l, l, s, l], &[6], None, iso(1936, 3, 24)),
+        PackedHinduLunisolarYearInfo::new(1994, [s, s, l, s, s, l, l, s, l, l, l, s, s], &[], None, iso(1937, 4, 12)),
+        PackedHinduLunisolarYearInfo::new(1995, [l, s, s, l, s, s, l, s, l, l, l, l, s], &[], None, iso(1938, 4, 1)),
+        PackedHinduLunisolarYearInfo::new(1996, [s, l, s, s, l, s, s, l, s, l, l, l, s], &[5], None, iso(1939, 3, 22)),
+        PackedHinduLunisolarYearInfo::new(1997, [l, l, s, s, l, s, s, l, s, l, l, s, s], &[], None, iso(1940, 4, 8)),
+        PackedHinduLunisolarYearInfo::new(1998, [l, l, s, l, s, l, s, s, l, s, l, s, s], &[], None, iso(1941, 3, 28)),
+        PackedHinduLunisolarYearInfo::new(1999, [l, l, s, l, l, s, l, s, l, s, s, l, s], &[3], None, iso(1942, 3, 17)),
+        PackedHinduLunisolarYearInfo::new(2000, [l, s, l, l, s, l, l, s, l, s, l, s, s], &[], None, iso(1943, 4, 5)),
+        PackedHinduLunisolarYearInfo::new(2001, [s, l, s, l, s, l, l, s, l, l, s, l, s], &[], None, iso(1944, 3, 25)),
+        PackedHinduLunisolarYearInfo::new(2002, [s, s, l, s, l, s, l, s, l, l, l, s, l], &[1], None, iso(1945, 3, 15)),
+        PackedHinduLunisolarYearInfo::new(2003, [s, s, l, s, s, l, s, l, l, l, l, s, s], &[], None, iso(1946, 4, 3)),
+        PackedHinduLunisolarYearInfo::new(2004, [l, s, s, l, s, s, l, s, l, l, l, s, l], &[5], None, iso(1947, 3, 23)),
+        PackedHinduLunisolarYearInfo::new(2005, [l, s, s, l, s, s, l, s, l, l, s, l, s], &[], None, iso(1948, 4, 10)),
+        PackedHinduLunisolarYearInfo::new(2006, [l, s, l, s, l, s, s, l, s, l, s, l, s], &[], None, iso(1949, 3, 30)),
+        PackedHinduLunisolarYearInfo::new(2007, [l, l, s, l, s, l, s, s, l, s, l, s, l], &[4], None, iso(1950, 3, 19)),
+        PackedHinduLunisolarYearInfo::new(2008, [l, s, l, s, l, l, s, s, l, s, l, s, s], &[], None, iso(1951, 4, 7)),
+        PackedHinduLunisolarYearInfo::new(2009, [l, s, l, s, l, l, s, l, l, s, s, l, s], &[], None, iso(1952, 3, 26)),
+        PackedHinduLunisolarYearInfo::new(2010, [s, l, s, l, s, l, s, l, l, l, s, l, s], &[2], None, iso(1953, 3, 16)),
+        PackedHinduLunisolarYearInfo::new(2011, [s, l, s, s, l, l, s, l, l, s, l, l, s], &[], None, iso(1954, 4, 4)),
+        PackedHinduLunisolarYearInfo::new(2012, [s, s, l, s, s, l, s, l, l, l, s, l, l], &[6], None, iso(1955, 3, 25)),
+        PackedHinduLunisolarYearInfo::new(2013, [s, s, l, s, s, l, s, l, l, s, l, l, s], &[], None, iso(1956, 4, 12)),
+        PackedHinduLunisolarYearInfo::new(2014, [s, l, s, l, s, s, l, s, l, s, l, l, s], &[], None, iso(1957, 4, 1)),
+        PackedHinduLunisolarYearInfo::new(2015, [l, s, l, s, l, s, s, l, s, l, s, l, l], &[5], None, iso(1958, 3, 21)),
+        PackedHinduLunisolarYearInfo::new(2016, [s, l, l, s, l, s, s, l, s, l, s, l, s], &[], None, iso(1959, 4, 9)),
+        PackedHinduLunisolarYearInfo::new(2017, [s, l, l, s, l, s, l, s, l, s, l, s, s], &[], None, iso(1960, 3, 28)),
+        PackedHinduLunisolarYearInfo::new(2018, [l, s, l, s, l, l, s, l, s, l, s, l, s], &[3], None, iso(1961, 3, 17)),
+        PackedHinduLunisolarYearInfo::new(2019, [l, s, s, l, l, s, l, l, s, l, l, s, s], &[], None, iso(1962, 4, 5)),
+        PackedHinduLunisolarYearInfo::new(2020, [s, l, s, s, l, s, l, l, l, s, l, l, s], &[8], Some(9), iso(1963, 3, 26)),
+        PackedHinduLunisolarYearInfo::new(2021, [s, s, l, s, s, l, s, l, s, l, l, l, s], &[1], None, iso(1964, 3, 15)),
+        PackedHinduLunisolarYearInfo::new(2022, [l, s, l, s, s, l, s, l, s, l, l, l, s], &[], None, iso(1965, 4, 2)),
+        PackedHinduLunisolarYearInfo::new(2023, [s, l, s, l, s, s, l, s, l, s, l, l, s], &[5], None, iso(1966, 3, 23)),
+        PackedHinduLunisolarYearInfo::new(2024, [l, l, s, l, s, s, l, s, l, s, l, s, s], &[], None, iso(1967, 4, 10)),
+        PackedHinduLunisolarYearInfo::new(2025, [l, l, s, l, s, l, s, l, s, l, s, l, s], &[], None, iso(1968, 3, 29)),
+        PackedHinduLunisolarYearInfo::new(2026, [s, l, s, l, l, s, l, s, l, s, l, s, l], &[4], None, iso(1969, 3, 19)),
+        PackedHinduLunisolarYearInfo::new(2027, [s, l, s, l, s, l, l, s, l, s, l, s, s], &[], None, iso(1970, 4, 7)),
+        PackedHinduLunisolarYearInfo::new(2028, [l, s, s, l, s, l, l, l, s, l, s, l, s], &[], None, iso(1971, 3, 27)),
+        PackedHinduLunisolarYearInfo::new(2029, [s, l, s, s, l, s, l, l, s, l, l, s, l], &[2], None, iso(1972, 3, 16)),
+        PackedHinduLunisolarYearInfo::new(2030, [s, l, s, s, l, s, l, s, l, l, l, s, s], &[], None, iso(1973, 4, 4)),
+        PackedHinduLunisolarYearInfo::new(2031, [l, s, l, s, s, l, s, s, l, l, l, s, l], &[6], None, iso(1974, 3, 24)),
+        PackedHinduLunisolarYearInfo::new(2032, [l, s, l, s, s, l, s, s, l, l, s, l, s], &[], None, iso(1975, 4, 12)),
+        PackedHinduLunisolarYearInfo::new(2033, [l, l, s, l, s, s, l, s, l, s, l, s, s], &[], None, iso(1976, 3, 31)),
+        PackedHinduLunisolarYearInfo::new(2034, [l, l, s, l, s, l, s, l, s, l, s, l, s], &[5], None, iso(1977, 3, 20)),
+        PackedHinduLunisolarYearInfo::new(2035, [l, s, l, l, s, l, s, l, s, l, s, l, s], &[], None, iso(1978, 4, 8)),
+        PackedHinduLunisolarYearInfo::new(2036, [s, s, l, l, s, l, l, s, l, s, l, s, s], &[], None, iso(1979, 3, 29)),
+        PackedHinduLunisolarYearInfo::new(2037, [l, s, s, l, s, l, l, s, l, l, s, l, s], &[3], None, iso(1980, 3, 17)),
+        PackedHinduLunisolarYearInfo::new(2038, [l, s, s, l, s, l, s, l, l, l, s, l, s], &[], None, iso(1981, 4, 5)),
+        PackedHinduLunisolarYearInfo::new(2039, [s, l, s, s, l, s, s, l, l, l, s, l, l], &[7, 12], Some(11), iso(1982, 3, 26)),
+        PackedHinduLunisolarYearInfo::new(2040, [s, l, s, s, l, s, s, l, l, s, l, l, s], &[], None, iso(1983, 4, 14)),
+        PackedHinduLunisolarYearInfo::new(2041, [l, s, l, s, s, l, s, s, l, l, s, l, s], &[], None, iso(1984, 4, 2)),
+        PackedHinduLunisolarYearInfo::new(2042, [l, s, l, s, l, s, l, s, s, l, s, l, l], &[5], None, iso(1985, 3, 22)),
+        PackedHinduLunisolarYearInfo::new(2043, [s, l, l, s, l, s, l, s, l, s, s, l, s], &[], None, iso(1986, 4, 10)),
+        PackedHinduLunisolarYearInfo::new(2044, [l, s, l, s, l, l, s, l, s, l, s, l, s], &[], None, iso(1987, 3, 30)),
+        PackedHinduLunisolarYearInfo::new(2045, [s, s, l, s, l, l, s, l, l, s, l, s, l], &[3], None, iso(1988, 3, 19)),
+        PackedHinduLunisolarYearInfo::new(2046, [s, s, l, s, l, s, l, l, l, s, l, s, s], &[], None, iso(1989, 4, 7)),
+        PackedHinduLunisolarYearInfo::new(2047, [l, s, s, l, s, l, s, l, l, s, l, l, s], &[], None, iso(1990, 3, 27)),
+        PackedHinduLunisolarYearInfo::new(2048, [s, l, s, s, l, s, s, l, l, s, l, l, l], &[2], None, iso(1991, 3, 17)),
+        PackedHinduLunisolarYearInfo::new(2049, [s, l, s, s, l, s, s, l, s, l, l, l, s], &[], None, iso(1992, 4, 4)),
+        PackedHinduLunisolarYearInfo::new(2050, [s, l, l, s, s, l, s, s, l, s, l, l, s], &[6], None, iso(1993, 3, 24)),
+        PackedHinduLunisolarYearInfo::new(2051, [l, l, s, l, s, l, s, s, l, s, l, l, s], &[], None, iso(1994, 4, 11)),
+        PackedHinduLunisolarYearInfo::new(2052, [s, l, l, s, l, s, l, s, l, s, s, l, s], &[], None, iso(1995, 4, 1)),
+        PackedHinduLunisolarYearInfo::new(2053, [s, l, l, s, l, s, l, l, s, l, s, l, s], &[4], None, iso(1996, 3, 20)),
+        PackedHinduLunisolarYearInfo::new(2054, [s, l, s, l, s, l, l, l, s, l, s, l, s], &[], None, iso(1997, 4, 8)),
+        PackedHinduLunisolarYearInfo::new(2055, [s, s, l, s, l, s, l, l, s, l, l, s, s], &[], None, iso(1998, 3, 29)),
+        PackedHinduLunisolarYearInfo::new(2056, [l, s, s, l, s, s, l, l, s, l, l, l, s], &[3], None, iso(1999, 3, 18)),
+        PackedHinduLunisolarYearInfo::new(2057, [l, s, s, l, s, s, l, s, l, l, l, l, s], &[], None, iso(2000, 4, 5)),
+        PackedHinduLunisolarYearInfo::new(2058, [s, l, s, s, l, s, s, l, s, l, l, l, s], &[7], None, iso(2001, 3, 26)),
+        PackedHinduLunisolarYearInfo::new(2059, [l, s, l, s, l, s, s, l, s, l, l, s, s], &[], None, iso(2002, 4, 13)),
+        PackedHinduLunisolarYearInfo::new(2060, [l, l, s, l, s, l, s, s, l, s, l, s, s], &[], None, iso(2003, 4, 2)),
+        PackedHinduLunisolarYearInfo::new(2061, [l, l, s, l, l, s, l, s, l, s, s, l, s], &[5], None, iso(2004, 3, 21)),
+        PackedHinduLunisolarYearInfo::new(2062, [l, s, l, l, s, l, l, s, l, s, s, l, s], &[], None, iso(2005, 4, 9)),
+        PackedHinduLunisolarYearInfo::new(2063, [s, l, s, l, s, l, l, s, l, l, s, l, s], &[], None, iso(2006, 3, 30)),
+        PackedHinduLunisolarYearInfo::new(2064, [s, s, l, s, s, l, l, s, l, l, l, s, l], &[3], None, iso(2007, 3, 20)),
+        PackedHinduLunisolarYearInfo::new(2065, [s, s, l, s, s, l, s, l, l, l, l, s, s], &[], None, iso(2008, 4, 7)),
+        PackedHinduLunisolarYearInfo::new(2066, [l, s, s, l, s, s, l, s, l, l, l, s, s], &[], None, iso(2009, 3, 27)),
+        PackedHinduLunisolarYearInfo::new(2067, [l, l, s, s, l, s, s, l, s, l, l, s, l], &[2], None, iso(2010, 3, 16)),
+        PackedHinduLunisolarYearInfo::new(2068, [l, s, l, s, l, s, s, l, s, l, s, l, s], &[], None, iso(2011, 4, 4)),
+        PackedHinduLunisolarYearInfo::new(2069, [l, s, l, l, s, l, s, s, l, s, l, s, l], &[6], None, iso(2012, 3, 23)),
+        PackedHinduLunisolarYearInfo::new(2070, [l, s, l, s, l, s, l, s, l, s, l, s, s], &[], None, iso(2013, 4, 11)),
+        PackedHinduLunisolarYearInfo::new(2071, [l, s, l, s, l, l, s, l, l, s, s, l, s], &[], None, iso(2014, 3, 31)),
+        PackedHinduLunisolarYearInfo::new(2072, [s, l, s, l, s, l, s, l, l, s, l, l, s], &[4], None, iso(2015, 3, 21)),
+        PackedHinduLunisolarYearInfo::new(2073, [s, l, s, s, l, s, l, l, l, s, l, l, s], &[], None, iso(2016, 4, 8)),
+        PackedHinduLunisolarYearInfo::new(2074, [s, s, l, s, s, l, s, l, l, l, s, l, s], &[], None, iso(2017, 3, 29)),
+        PackedHinduLunisolarYearInfo::new(2075, [l, s, s, l, s, s, l, s, l, s, l, l, l], &[3], None, iso(2018, 3, 18)),
+        PackedHinduLunisolarYearInfo::new(2076, [s, l, s, l, s, s, l, s, l, s, l, l, s], &[], None, iso(2019, 4, 6)),
+        PackedHinduLunisolarYearInfo::new(2077, [l, s, l, s, l, s, s, l, s, l, s, l, l], &[7], None, iso(2020, 3, 25)),
+        PackedHinduLunisolarYearInfo::new(2078, [s, l, l, s, l, s, s, l, s, l, s, l, s], &[], None, iso(2021, 4, 13)),
+        PackedHinduLunisolarYearInfo::new(2079, [s, l, l, s, l, s, l, s, l, s, l, s, s], &[], None, iso(2022, 4, 2)),
+        PackedHinduLunisolarYearInfo::new(2080, [l, s, l, s, l, l, s, l, s, l, s, l, s], &[5], None, iso(2023, 3, 22)),
+        PackedHinduLunisolarYearInfo::new(2081, [l, s, s, l, l, s, l, l, s, l, s, l, s], &[], None, iso(2024, 4, 9)),
+        PackedHinduLunisolarYearInfo::new(2082, [s, l, s, s, l, s, l, l, l, s, l, l, s], &[], None, iso(2025, 3, 30)),
+        PackedHinduLunisolarYearInfo::new(2083, [s, s, l, s, s, l, s, l, s, l, l, l, s], &[3], None, iso(2026, 3, 20)),
+        PackedHinduLunisolarYearInfo::new(2084, [l, s, l, s, s, l, s, l, s, l, l, l, s], &[], None, iso(2027, 4, 7)),
+        PackedHinduLunisolarYearInfo::new(2085, [s, l, s, l, s, s, l, s, l, s, l, l, s], &[], None, iso(2028, 3, 27)),
+        PackedHinduLunisolarYearInfo::new(2086, [s, l, l, s, l, s, s, l, s, l, s, l, s], &[1], None, iso(2029, 3, 16)),
+        PackedHinduLunisolarYearInfo::new(2087, [l, l, s, l, s, l, s, l, s, l, s, l, s], &[], None, iso(2030, 4, 3)),
+        PackedHinduLunisolarYearInfo::new(2088, [s, l, s, l, l, s, l, s, l, s, l, s, l], &[6], None, iso(2031, 3, 24)),
+        PackedHinduLunisolarYearInfo::new(2089, [s, l, s, l, s, l, l, s, l, s, l, s, s], &[], None, iso(2032, 4, 11)),
+        PackedHinduLunisolarYearInfo::new(2090, [l, s, s, l, s, l, l, l, s, l, s, l, s], &[], None, iso(2033, 3, 31)),
+        PackedHinduLunisolarYearInfo::new(2091, [s, l, s, s, l, s, l, l, s, l, l, s, l], &[4], None, iso(2034, 3, 21)),
+        PackedHinduLunisolarYearInfo::new(2092, [s, l, s, s, l, s, l, s, l, l, l, s, s], &[], None, iso(2035, 4, 9)),
+        PackedHinduLunisolarYearInfo::new(2093, [l, s, l, s, s, l, s, s, l, l, l, s, s], &[], None, iso(2036, 3, 28)),
+        PackedHinduLunisolarYearInfo::new(2094, [l, l, s, l, s, s, l, s, s, l, l, s, l], &[3], None, iso(2037, 3, 17)),
+        PackedHinduLunisolarYearInfo::new(2095, [l, l, s, l, s, s, l, s, s, l, l, s, s], &[], None, iso(2038, 4, 5)),
+        PackedHinduLunisolarYearInfo::new(2096, [l, l, s, l, s, l, s, l, s, l, s, l, s], &[7], None, iso(2039, 3, 25)),
+        PackedHinduLunisolarYearInfo::new(2097, [l, s, l, s, l, l, s, l, s, l, s, l, s], &[], None, iso(2040, 4, 12)),
+        PackedHinduLunisolarYearInfo::new(2098, [s, s, l, l, s, l, l, s, l, s, l, s, s], &[], None, iso(2041, 4, 2)),
+        PackedHinduLunisolarYearInfo::new(2099, [l, s, s, l, s, l, l, s, l, l, s, l, s], &[5], None, iso(2042, 3, 22)),
+        PackedHinduLunisolarYearInfo::new(2100, [l, s, s, l, s, l, s, l, l, l, s, l, s], &[], None, iso(2043, 4, 10)),
+        PackedHinduLunisolarYearInfo::new(2101, [s, l, s, s, l, s, s, l, l, l, s, l, s], &[], None, iso(2044, 3, 30)),
+        PackedHinduLunisolarYearInfo::new(2102, [l, s, l, s, s, l, s, s, l, l, s, l, l], &[3], None, iso(2045, 3, 19)),
+        PackedHinduLunisolarYearInfo::new(2103, [l, s, l, s, s, l, s, s, l, l, s, l, s], &[], None, iso(2046, 4, 7)),
+        PackedHinduLunisolarYearInfo::new(2104, [l, s, l, s, l, s, l, s, s, l, s, l, s], &[], None, iso(2047, 3, 27)),
+        PackedHinduLunisolarYearInfo::new(2105, [l, s, l, l, s, l, s, l, s, l, s, s, l], &[1], None, iso(2048, 3, 15)),
+        PackedHinduLunisolarYearInfo::new(2106, [s, l, l, s, l, l, s, l, s, l, s, s, s], &[], None, iso(2049, 4, 3)),
+        PackedHinduLunisolarYearInfo::new(2107, [l, s, l, s, l, l, s, l, l, s, l, s, l], &[6], None, iso(2050, 3, 23)),
+        PackedHinduLunisolarYearInfo::new(2108, [s, s, l, s, l, s, l, l, l, s, l, s, s], &[], None, iso(2051, 4, 12)),
+        PackedHinduLunisolarYearInfo::new(2109, [l, s, s, l, s, s, l, l, l, s, l, l, s], &[], None, iso(2052, 3, 31)),
+        PackedHinduLunisolarYearInfo::new(2110, [s, l, s, s, l, s, s, l, l, s, l, l, l], &[4], None, iso(2053, 3, 21)),
+        PackedHinduLunisolarYearInfo::new(2111, [s, l, s, s, l, s, s, l, s, l, l, l, s], &[], None, iso(2054, 4, 9)),
+        PackedHinduLunisolarYearInfo::new(2112, [s, l, l, s, s, l, s, s, l, s, l, l, s], &[], None, iso(2055, 3, 29)),
+        PackedHinduLunisolarYearInfo::new(2113, [s, l, l, s, l, s, l, s, s, l, s, l, l], &[2], None, iso(2056, 3, 17)),
+        PackedHinduLunisolarYearInfo::new(2114, [s, l, s, l, l, s, l, s, s, l, s, l, s], &[], None, iso(2057, 4, 5)),
+        PackedHinduLunisolarYearInfo::new(2115, [s, l, l, s, l, s, l, l, s, l, s, s, l], &[7], None, iso(2058, 3, 25)),
+        PackedHinduLunisolarYearInfo::new(2116, [s, l, s, l, s, l, l, l, s, l, s, l, s], &[], None, iso(2059, 4, 13)),
+        PackedHinduLunisolarYearInfo::new(2117, [s, s, l, s, l, s, l, l, s, l, l, s, s], &[], None, iso(2060, 4, 2)),
+        PackedHinduLunisolarYearInfo::new(2118, [l, s, s, l, s, s, l, l, s, l, l, l, s], &[5], None, iso(2061, 3, 22)),
+        PackedHinduLunisolarYearInfo::new(2119, [l, s, s, l, s, s, l, s, l, l, l, s, s], &[], None, iso(2062, 4, 10)),
+        PackedHinduLunisolarYearInfo::new(2120, [l, l, s, s, l, s, s, l, s, l, l, l, s], &[], None, iso(2063, 3, 30)),
+        PackedHinduLunisolarYearInfo::new(2121, [s, l, s, l, s, l, s, s, l, s, l, l, s], &[3], None, iso(2064, 3, 19)),
+        PackedHinduLunisolarYearInfo::new(2122, [l, l, s, l, s, l, s, s, l, s, l, s, s], &[], None, iso(2065, 4, 6)),
+        PackedHinduLunisolarYearInfo::new(2123, [l, l, s, l, l, s, l, s, s, l, s, l, s], &[], None, iso(2066, 3, 26)),
+        PackedHinduLunisolarYearInfo::new(2124, [s, l, s, l, l, s, l, s, l, l, s, s, l], &[1], None, iso(2067, 3, 16)),
+        PackedHinduLunisolarYearInfo::new(2125, [s, l, s, l, s, l, l, s, l, l, s, l, s], &[], None, iso(2068, 4, 3)),
+        PackedHinduLunisolarYearInfo::new(2126, [s, s, l, s, s, l, l, s, l, l, l, s, l], &[5], None, iso(2069, 3, 24)),
+        PackedHinduLunisolarYearInfo::new(2127, [s, s, l, s, s, l, s, l, l, l, s, l, s], &[], None, iso(2070, 4, 12)),
+        PackedHinduLunisolarYearInfo::new(2128, [l, s, s, l, s, s, l, s, l, l, l, s, s], &[], None, iso(2071, 4, 1)),
+        PackedHinduLunisolarYearInfo::new(2129, [l, l, s, s, l, s, s, l, s, l, l, s, l], &[4], None, iso(2072, 3, 20)),
+        PackedHinduLunisolarYearInfo::new(2130, [l, s, l, s, l, s, s, l, s, l, s, l, s], &[], None, iso(2073, 4, 8)),
+        PackedHinduLunisolarYearInfo::new(2131, [l, s, l, l, s, l, s, s, l, s, l, s, s], &[], None, iso(2074, 3, 28)),
+        PackedHinduLunisolarYearInfo::new(2132, [l, s, l, l, s, l, s, l, s, l, s, l, s], &[2], None, iso(2075, 3, 17)),
+        PackedHinduLunisolarYearInfo::new(2133, [l, s, l, s, l, l, s, l, s, l, s, l, s], &[], None, iso(2076, 4, 4)),
+        PackedHinduLunisolarYearInfo::new(2134, [s, l, s, l, s, l, s, l, l, s, l, s, l], &[7], None, iso(2077, 3, 25)),
+        PackedHinduLunisolarYearInfo::new(2135, [s, l, s, s, l, s, l, l, l, s, l, l, s], &[], None, iso(2078, 4, 13)),
+        PackedHinduLunisolarYearInfo::new(2136, [s, s, l, s, s, l, s, l, l, l, s, l, s], &[], None, iso(2079, 4, 3)),
+        PackedHinduLunisolarYearInfo::new(2137, [l, s, s, l, s, s, l, s, l, s, l, l, l], &[5], None, iso(2080, 3, 22)),
+        PackedHinduLunisolarYearInfo::new(2138, [s, l, s, l, s, s, l, s, l, s, l, l, s], &[], None, iso(2081, 4, 10)),
+        PackedHinduLunisolarYearInfo::new(2139, [l, s, l, s, l, s, s, l, s, l, s, l, s], &[], None, iso(2082, 3, 30)),
+        PackedHinduLunisolarYearInfo::new(2140, [l, s, l, s, l, s, l, s, l, s, l, s, l], &[3], None, iso(2083, 3, 19)),
+        PackedHinduLunisolarYearInfo::new(2141, [s, l, l, s, l, s, l, s, l, s, l, s, s], &[], None, iso(2084, 4, 6)),
+        PackedHinduLunisolarYearInfo::new(2142, [l, s, l, s, l, l, s, l, s, l, s, l, s], &[], None, iso(2085, 3, 26)),
+        PackedHinduLunisolarYearInfo::new(2143, [s, l, s, s, l, l, s, l, l, s, l, s, l], &[1], None, iso(2086, 3, 16)),
+        PackedHinduLunisolarYearInfo::new(2144, [s, l, s, s, l, s, l, l, l, s, l, s, s], &[], None, iso(2087, 4, 4)),
+        PackedHinduLunisolarYearInfo::new(2145, [l, s, l, s, s, l, s, l, s, l, l, l, s], &[5], None, iso(2088, 3, 23)),
+        PackedHinduLunisolarYearInfo::new(2146, [l, s, l, s, s, l, s, l, s, l, l, l, s], &[], None, iso(2089, 4, 11)),
+        PackedHinduLunisolarYearInfo::new(2147, [s, l, s, l, s, s, l, s, s, l, l, l, s], &[], None, iso(2090, 4, 1)),
+        PackedHinduLunisolarYearInfo::new(2148, [s, l, l, s, s, l, s, l, s, s, l, l, s], &[4], None, iso(2091, 3, 21)),
+        PackedHinduLunisolarYearInfo::new(2149, [l, l, s, l, s, l, s, l, s, l, s, l, s], &[], None, iso(2092, 4, 7)),
+        PackedHinduLunisolarYearInfo::new(2150, [s, l, s, l, l, s, l, s, l, s, l, s, s], &[], None, iso(2093, 3, 28)),
+        PackedHinduLunisolarYearInfo::new(2151, [l, s, s, l, l, s, l, l, s, l, s, l, s], &[2], None, iso(2094, 3, 17)),
+        PackedHinduLunisolarYearInfo::new(2152, [l, s, s, l, s, l, l, s, l, l, s, l, s], &[], None, iso(2095, 4, 5)),
+        PackedHinduLunisolarYearInfo::new(2153, [s, l, s, s, l, s, l, s, l, l, l, s, l], &[6], None, iso(2096, 3, 25)),
+        PackedHinduLunisolarYearInfo::new(2154, [s, l, s, s, s, l, l, s, l, l, l, s, s], &[], None, iso(2097, 4, 13)),
+        PackedHinduLunisolarYearInfo::new(2155, [l, s, l, s, s, s, l, s, l, l, l, s, s], &[], None, iso(2098, 4, 2)),
+        PackedHinduLunisolarYearInfo::new(2156, [l, l, s, l, s, s, l, s, s, l, l, s, l], &[5], None, iso(2099, 3, 22)),
+        PackedHinduLunisolarYearInfo::new(2157, [l, s, l, s, l, s, l, s, s, l, l, s, s], &[], None, iso(2100, 4, 10)),
+    ]
+};
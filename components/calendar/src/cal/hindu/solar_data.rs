@@ -0,0 +1,217 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data obtained from [`calendrical_calculations`], for Ujjain.
+
+use super::PackedHinduSolarYearInfo;
+
+pub const STARTING_YEAR: i32 = 1822;
+
+#[rustfmt::skip]
+pub const DATA: &[PackedHinduSolarYearInfo] = {
+    use calendrical_calculations::iso::const_fixed_from_iso as iso;
+    &[
+        PackedHinduSolarYearInfo::new(1822, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1900, 4, 13)),
+        PackedHinduSolarYearInfo::new(1823, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1901, 4, 13)),
+        PackedHinduSolarYearInfo::new(1824, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1902, 4, 13)),
+        PackedHinduSolarYearInfo::new(1825, [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], iso(1903, 4, 14)),
+        PackedHinduSolarYearInfo::new(1826, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1904, 4, 13)),
+        PackedHinduSolarYearInfo::new(1827, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1905, 4, 13)),
+        PackedHinduSolarYearInfo::new(1828, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1906, 4, 13)),
+        PackedHinduSolarYearInfo::new(1829, [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1907, 4, 14)),
+        PackedHinduSolarYearInfo::new(1830, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1908, 4, 13)),
+        PackedHinduSolarYearInfo::new(1831, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1909, 4, 13)),
+        PackedHinduSolarYearInfo::new(1832, [31, 32, 31, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1910, 4, 13)),
+        PackedHinduSolarYearInfo::new(1833, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1911, 4, 14)),
+        PackedHinduSolarYearInfo::new(1834, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1912, 4, 13)),
+        PackedHinduSolarYearInfo::new(1835, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1913, 4, 13)),
+        PackedHinduSolarYearInfo::new(1836, [31, 32, 31, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1914, 4, 13)),
+        PackedHinduSolarYearInfo::new(1837, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1915, 4, 14)),
+        PackedHinduSolarYearInfo::new(1838, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1916, 4, 13)),
+        PackedHinduSolarYearInfo::new(1839, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1917, 4, 13)),
+        PackedHinduSolarYearInfo::new(1840, [31, 32, 31, 31, 31, 31, 30, 30, 29, 29, 30, 31], iso(1918, 4, 13)),
+        PackedHinduSolarYearInfo::new(1841, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1919, 4, 14)),
+        PackedHinduSolarYearInfo::new(1842, [31, 31, 32, 31, 31, 30, 30, 30, 29, 30, 30, 30], iso(1920, 4, 13)),
+        PackedHinduSolarYearInfo::new(1843, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1921, 4, 13)),
+        PackedHinduSolarYearInfo::new(1844, [31, 32, 31, 31, 31, 31, 30, 30, 29, 29, 30, 31], iso(1922, 4, 13)),
+        PackedHinduSolarYearInfo::new(1845, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1923, 4, 14)),
+        PackedHinduSolarYearInfo::new(1846, [31, 31, 32, 31, 31, 30, 30, 30, 29, 30, 30, 30], iso(1924, 4, 13)),
+        PackedHinduSolarYearInfo::new(1847, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1925, 4, 13)),
+        PackedHinduSolarYearInfo::new(1848, [31, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(1926, 4, 13)),
+        PackedHinduSolarYearInfo::new(1849, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1927, 4, 14)),
+        PackedHinduSolarYearInfo::new(1850, [31, 31, 32, 31, 31, 30, 30, 30, 30, 29, 30, 30], iso(1928, 4, 13)),
+        PackedHinduSolarYearInfo::new(1851, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1929, 4, 13)),
+        PackedHinduSolarYearInfo::new(1852, [30, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(1930, 4, 14)),
+        PackedHinduSolarYearInfo::new(1853, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1931, 4, 14)),
+        PackedHinduSolarYearInfo::new(1854, [31, 31, 32, 31, 31, 30, 30, 30, 30, 29, 30, 30], iso(1932, 4, 13)),
+        PackedHinduSolarYearInfo::new(1855, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1933, 4, 13)),
+        PackedHinduSolarYearInfo::new(1856, [30, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(1934, 4, 14)),
+        PackedHinduSolarYearInfo::new(1857, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1935, 4, 14)),
+        PackedHinduSolarYearInfo::new(1858, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1936, 4, 13)),
+        PackedHinduSolarYearInfo::new(1859, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1937, 4, 13)),
+        PackedHinduSolarYearInfo::new(1860, [30, 32, 31, 31, 32, 30, 30, 30, 29, 30, 29, 31], iso(1938, 4, 14)),
+        PackedHinduSolarYearInfo::new(1861, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1939, 4, 14)),
+        PackedHinduSolarYearInfo::new(1862, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1940, 4, 13)),
+        PackedHinduSolarYearInfo::new(1863, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1941, 4, 13)),
+        PackedHinduSolarYearInfo::new(1864, [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], iso(1942, 4, 14)),
+        PackedHinduSolarYearInfo::new(1865, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1943, 4, 14)),
+        PackedHinduSolarYearInfo::new(1866, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1944, 4, 13)),
+        PackedHinduSolarYearInfo::new(1867, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1945, 4, 13)),
+        PackedHinduSolarYearInfo::new(1868, [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1946, 4, 14)),
+        PackedHinduSolarYearInfo::new(1869, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1947, 4, 14)),
+        PackedHinduSolarYearInfo::new(1870, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1948, 4, 13)),
+        PackedHinduSolarYearInfo::new(1871, [31, 32, 31, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1949, 4, 13)),
+        PackedHinduSolarYearInfo::new(1872, [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1950, 4, 14)),
+        PackedHinduSolarYearInfo::new(1873, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1951, 4, 14)),
+        PackedHinduSolarYearInfo::new(1874, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1952, 4, 13)),
+        PackedHinduSolarYearInfo::new(1875, [31, 32, 31, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1953, 4, 13)),
+        PackedHinduSolarYearInfo::new(1876, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1954, 4, 14)),
+        PackedHinduSolarYearInfo::new(1877, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1955, 4, 14)),
+        PackedHinduSolarYearInfo::new(1878, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1956, 4, 13)),
+        PackedHinduSolarYearInfo::new(1879, [31, 32, 31, 31, 31, 31, 30, 30, 29, 29, 30, 31], iso(1957, 4, 13)),
+        PackedHinduSolarYearInfo::new(1880, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1958, 4, 14)),
+        PackedHinduSolarYearInfo::new(1881, [31, 31, 32, 31, 31, 30, 30, 30, 29, 30, 30, 30], iso(1959, 4, 14)),
+        PackedHinduSolarYearInfo::new(1882, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1960, 4, 13)),
+        PackedHinduSolarYearInfo::new(1883, [31, 32, 31, 31, 31, 31, 30, 30, 29, 29, 30, 31], iso(1961, 4, 13)),
+        PackedHinduSolarYearInfo::new(1884, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1962, 4, 14)),
+        PackedHinduSolarYearInfo::new(1885, [31, 31, 32, 31, 31, 30, 30, 30, 29, 30, 30, 30], iso(1963, 4, 14)),
+        PackedHinduSolarYearInfo::new(1886, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1964, 4, 13)),
+        PackedHinduSolarYearInfo::new(1887, [31, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(1965, 4, 13)),
+        PackedHinduSolarYearInfo::new(1888, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1966, 4, 14)),
+        PackedHinduSolarYearInfo::new(1889, [31, 31, 32, 31, 31, 30, 30, 30, 30, 29, 30, 30], iso(1967, 4, 14)),
+        PackedHinduSolarYearInfo::new(1890, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1968, 4, 13)),
+        PackedHinduSolarYearInfo::new(1891, [30, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(1969, 4, 14)),
+        PackedHinduSolarYearInfo::new(1892, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1970, 4, 14)),
+        PackedHinduSolarYearInfo::new(1893, [31, 31, 32, 31, 31, 30, 30, 30, 30, 29, 30, 30], iso(1971, 4, 14)),
+        PackedHinduSolarYearInfo::new(1894, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1972, 4, 13)),
+        PackedHinduSolarYearInfo::new(1895, [30, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(1973, 4, 14)),
+        PackedHinduSolarYearInfo::new(1896, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1974, 4, 14)),
+        PackedHinduSolarYearInfo::new(1897, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1975, 4, 14)),
+        PackedHinduSolarYearInfo::new(1898, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1976, 4, 13)),
+        PackedHinduSolarYearInfo::new(1899, [30, 32, 31, 31, 32, 30, 30, 30, 29, 30, 29, 31], iso(1977, 4, 14)),
+        PackedHinduSolarYearInfo::new(1900, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1978, 4, 14)),
+        PackedHinduSolarYearInfo::new(1901, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1979, 4, 14)),
+        PackedHinduSolarYearInfo::new(1902, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1980, 4, 13)),
+        PackedHinduSolarYearInfo::new(1903, [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], iso(1981, 4, 14)),
+        PackedHinduSolarYearInfo::new(1904, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1982, 4, 14)),
+        PackedHinduSolarYearInfo::new(1905, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1983, 4, 14)),
+        PackedHinduSolarYearInfo::new(1906, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1984, 4, 13)),
+        PackedHinduSolarYearInfo::new(1907, [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1985, 4, 14)),
+        PackedHinduSolarYearInfo::new(1908, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1986, 4, 14)),
+        PackedHinduSolarYearInfo::new(1909, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1987, 4, 14)),
+        PackedHinduSolarYearInfo::new(1910, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1988, 4, 13)),
+        PackedHinduSolarYearInfo::new(1911, [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1989, 4, 14)),
+        PackedHinduSolarYearInfo::new(1912, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1990, 4, 14)),
+        PackedHinduSolarYearInfo::new(1913, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1991, 4, 14)),
+        PackedHinduSolarYearInfo::new(1914, [31, 32, 31, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(1992, 4, 13)),
+        PackedHinduSolarYearInfo::new(1915, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1993, 4, 14)),
+        PackedHinduSolarYearInfo::new(1916, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1994, 4, 14)),
+        PackedHinduSolarYearInfo::new(1917, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1995, 4, 14)),
+        PackedHinduSolarYearInfo::new(1918, [31, 32, 31, 31, 31, 31, 30, 30, 29, 29, 30, 31], iso(1996, 4, 13)),
+        PackedHinduSolarYearInfo::new(1919, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(1997, 4, 14)),
+        PackedHinduSolarYearInfo::new(1920, [31, 31, 32, 31, 31, 30, 30, 30, 29, 30, 30, 30], iso(1998, 4, 14)),
+        PackedHinduSolarYearInfo::new(1921, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(1999, 4, 14)),
+        PackedHinduSolarYearInfo::new(1922, [31, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(2000, 4, 13)),
+        PackedHinduSolarYearInfo::new(1923, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2001, 4, 14)),
+        PackedHinduSolarYearInfo::new(1924, [31, 31, 32, 31, 31, 30, 30, 30, 29, 30, 30, 30], iso(2002, 4, 14)),
+        PackedHinduSolarYearInfo::new(1925, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2003, 4, 14)),
+        PackedHinduSolarYearInfo::new(1926, [31, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(2004, 4, 13)),
+        PackedHinduSolarYearInfo::new(1927, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2005, 4, 14)),
+        PackedHinduSolarYearInfo::new(1928, [31, 31, 32, 31, 31, 30, 30, 30, 30, 29, 30, 30], iso(2006, 4, 14)),
+        PackedHinduSolarYearInfo::new(1929, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2007, 4, 14)),
+        PackedHinduSolarYearInfo::new(1930, [31, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(2008, 4, 13)),
+        PackedHinduSolarYearInfo::new(1931, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2009, 4, 14)),
+        PackedHinduSolarYearInfo::new(1932, [31, 31, 32, 31, 31, 30, 30, 30, 30, 29, 30, 30], iso(2010, 4, 14)),
+        PackedHinduSolarYearInfo::new(1933, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(2011, 4, 14)),
+        PackedHinduSolarYearInfo::new(1934, [30, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(2012, 4, 14)),
+        PackedHinduSolarYearInfo::new(1935, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2013, 4, 14)),
+        PackedHinduSolarYearInfo::new(1936, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2014, 4, 14)),
+        PackedHinduSolarYearInfo::new(1937, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(2015, 4, 14)),
+        PackedHinduSolarYearInfo::new(1938, [30, 32, 31, 31, 32, 30, 30, 30, 29, 30, 29, 31], iso(2016, 4, 14)),
+        PackedHinduSolarYearInfo::new(1939, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2017, 4, 14)),
+        PackedHinduSolarYearInfo::new(1940, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2018, 4, 14)),
+        PackedHinduSolarYearInfo::new(1941, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(2019, 4, 14)),
+        PackedHinduSolarYearInfo::new(1942, [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], iso(2020, 4, 14)),
+        PackedHinduSolarYearInfo::new(1943, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2021, 4, 14)),
+        PackedHinduSolarYearInfo::new(1944, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2022, 4, 14)),
+        PackedHinduSolarYearInfo::new(1945, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(2023, 4, 14)),
+        PackedHinduSolarYearInfo::new(1946, [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2024, 4, 14)),
+        PackedHinduSolarYearInfo::new(1947, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2025, 4, 14)),
+        PackedHinduSolarYearInfo::new(1948, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2026, 4, 14)),
+        PackedHinduSolarYearInfo::new(1949, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(2027, 4, 14)),
+        PackedHinduSolarYearInfo::new(1950, [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2028, 4, 14)),
+        PackedHinduSolarYearInfo::new(1951, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2029, 4, 14)),
+        PackedHinduSolarYearInfo::new(1952, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2030, 4, 14)),
+        PackedHinduSolarYearInfo::new(1953, [31, 32, 31, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(2031, 4, 14)),
+        PackedHinduSolarYearInfo::new(1954, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2032, 4, 14)),
+        PackedHinduSolarYearInfo::new(1955, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2033, 4, 14)),
+        PackedHinduSolarYearInfo::new(1956, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2034, 4, 14)),
+        PackedHinduSolarYearInfo::new(1957, [31, 32, 31, 31, 31, 31, 30, 30, 29, 29, 30, 31], iso(2035, 4, 14)),
+        PackedHinduSolarYearInfo::new(1958, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2036, 4, 14)),
+        PackedHinduSolarYearInfo::new(1959, [31, 31, 32, 31, 31, 30, 30, 30, 29, 30, 30, 30], iso(2037, 4, 14)),
+        PackedHinduSolarYearInfo::new(1960, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2038, 4, 14)),
+        PackedHinduSolarYearInfo::new(1961, [31, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(2039, 4, 14)),
+        PackedHinduSolarYearInfo::new(1962, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2040, 4, 14)),
+        PackedHinduSolarYearInfo::new(1963, [31, 31, 32, 31, 31, 30, 30, 30, 29, 30, 30, 30], iso(2041, 4, 14)),
+        PackedHinduSolarYearInfo::new(1964, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2042, 4, 14)),
+        PackedHinduSolarYearInfo::new(1965, [31, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(2043, 4, 14)),
+        PackedHinduSolarYearInfo::new(1966, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2044, 4, 14)),
+        PackedHinduSolarYearInfo::new(1967, [31, 31, 32, 31, 31, 30, 30, 30, 30, 29, 30, 30], iso(2045, 4, 14)),
+        PackedHinduSolarYearInfo::new(1968, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2046, 4, 14)),
+        PackedHinduSolarYearInfo::new(1969, [31, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(2047, 4, 14)),
+        PackedHinduSolarYearInfo::new(1970, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2048, 4, 14)),
+        PackedHinduSolarYearInfo::new(1971, [31, 31, 32, 31, 31, 30, 30, 30, 30, 29, 30, 30], iso(2049, 4, 14)),
+        PackedHinduSolarYearInfo::new(1972, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(2050, 4, 14)),
+        PackedHinduSolarYearInfo::new(1973, [30, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(2051, 4, 15)),
+        PackedHinduSolarYearInfo::new(1974, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2052, 4, 14)),
+        PackedHinduSolarYearInfo::new(1975, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2053, 4, 14)),
+        PackedHinduSolarYearInfo::new(1976, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(2054, 4, 14)),
+        PackedHinduSolarYearInfo::new(1977, [30, 32, 31, 31, 32, 30, 30, 30, 29, 30, 29, 31], iso(2055, 4, 15)),
+        PackedHinduSolarYearInfo::new(1978, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2056, 4, 14)),
+        PackedHinduSolarYearInfo::new(1979, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2057, 4, 14)),
+        PackedHinduSolarYearInfo::new(1980, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(2058, 4, 14)),
+        PackedHinduSolarYearInfo::new(1981, [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], iso(2059, 4, 15)),
+        PackedHinduSolarYearInfo::new(1982, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2060, 4, 14)),
+        PackedHinduSolarYearInfo::new(1983, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2061, 4, 14)),
+        PackedHinduSolarYearInfo::new(1984, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(2062, 4, 14)),
+        PackedHinduSolarYearInfo::new(1985, [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2063, 4, 15)),
+        PackedHinduSolarYearInfo::new(1986, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2064, 4, 14)),
+        PackedHinduSolarYearInfo::new(1987, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2065, 4, 14)),
+        PackedHinduSolarYearInfo::new(1988, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(2066, 4, 14)),
+        PackedHinduSolarYearInfo::new(1989, [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2067, 4, 15)),
+        PackedHinduSolarYearInfo::new(1990, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2068, 4, 14)),
+        PackedHinduSolarYearInfo::new(1991, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2069, 4, 14)),
+        PackedHinduSolarYearInfo::new(1992, [31, 32, 31, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(2070, 4, 14)),
+        PackedHinduSolarYearInfo::new(1993, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2071, 4, 15)),
+        PackedHinduSolarYearInfo::new(1994, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2072, 4, 14)),
+        PackedHinduSolarYearInfo::new(1995, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2073, 4, 14)),
+        PackedHinduSolarYearInfo::new(1996, [31, 32, 31, 31, 31, 31, 30, 30, 29, 29, 30, 31], iso(2074, 4, 14)),
+        PackedHinduSolarYearInfo::new(1997, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2075, 4, 15)),
+        PackedHinduSolarYearInfo::new(1998, [31, 31, 32, 31, 31, 30, 30, 30, 29, 30, 30, 30], iso(2076, 4, 14)),
+        PackedHinduSolarYearInfo::new(1999, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2077, 4, 14)),
+        PackedHinduSolarYearInfo::new(2000, [31, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(2078, 4, 14)),
+        PackedHinduSolarYearInfo::new(2001, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2079, 4, 15)),
+        PackedHinduSolarYearInfo::new(2002, [31, 31, 32, 31, 31, 30, 30, 30, 29, 30, 30, 30], iso(2080, 4, 14)),
+        PackedHinduSolarYearInfo::new(2003, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2081, 4, 14)),
+        PackedHinduSolarYearInfo::new(2004, [31, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(2082, 4, 14)),
+        PackedHinduSolarYearInfo::new(2005, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2083, 4, 15)),
+        PackedHinduSolarYearInfo::new(2006, [31, 31, 32, 31, 31, 30, 30, 30, 30, 29, 30, 30], iso(2084, 4, 14)),
+        PackedHinduSolarYearInfo::new(2007, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2085, 4, 14)),
+        PackedHinduSolarYearInfo::new(2008, [31, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(2086, 4, 14)),
+        PackedHinduSolarYearInfo::new(2009, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2087, 4, 15)),
+        PackedHinduSolarYearInfo::new(2010, [31, 31, 32, 31, 31, 30, 30, 30, 30, 29, 30, 30], iso(2088, 4, 14)),
+        PackedHinduSolarYearInfo::new(2011, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(2089, 4, 14)),
+        PackedHinduSolarYearInfo::new(2012, [30, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(2090, 4, 15)),
+        PackedHinduSolarYearInfo::new(2013, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2091, 4, 15)),
+        PackedHinduSolarYearInfo::new(2014, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2092, 4, 14)),
+        PackedHinduSolarYearInfo::new(2015, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(2093, 4, 14)),
+        PackedHinduSolarYearInfo::new(2016, [30, 32, 31, 31, 31, 31, 30, 30, 29, 30, 29, 31], iso(2094, 4, 15)),
+        PackedHinduSolarYearInfo::new(2017, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2095, 4, 15)),
+        PackedHinduSolarYearInfo::new(2018, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2096, 4, 14)),
+        PackedHinduSolarYearInfo::new(2019, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 31], iso(2097, 4, 14)),
+        PackedHinduSolarYearInfo::new(2020, [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], iso(2098, 4, 15)),
+        PackedHinduSolarYearInfo::new(2021, [31, 31, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], iso(2099, 4, 15)),
+        PackedHinduSolarYearInfo::new(2022, [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], iso(2100, 4, 15)),
+    ]
+};
@@ -86,7 +86,7 @@ pub struct Japanese {
 ///
 /// This calendar supports 12 solar month codes (`M01` - `M12`)
 #[derive(Clone, Debug, Default)]
-pub struct JapaneseExtended(Japanese);
+pub struct JapaneseExtended(pub(crate) Japanese);
 
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
 /// The inner date type used for representing [`Date`]s of [`Japanese`]. See [`Date`] and [`Japanese`] for more details.
@@ -129,6 +129,11 @@ impl Japanese {
     }
 
     pub(crate) const DEBUG_NAME: &'static str = "Japanese";
+
+    /// The eras in the data, with their start dates, in chronological order
+    pub(crate) fn era_starts(&self) -> impl Iterator<Item = (EraStartDate, TinyStr16)> + '_ {
+        self.eras.get().dates_to_eras.iter()
+    }
 }
 
 impl JapaneseExtended {
@@ -0,0 +1,512 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the hybrid Julian-Gregorian calendar.
+//!
+//! ```rust
+//! use icu::calendar::{cal::JulianGregorian, Date};
+//!
+//! let calendar = JulianGregorian::new();
+//!
+//! // The last day of the Julian calendar
+//! let julian = Date::try_new_julian_gregorian_with_calendar(1582, 10, 4, calendar)
+//!     .expect("Failed to initialize Julian-Gregorian Date instance.");
+//! // The first day of the Gregorian calendar
+//! let gregorian = Date::try_new_julian_gregorian_with_calendar(1582, 10, 15, calendar)
+//!     .expect("Failed to initialize Julian-Gregorian Date instance.");
+//!
+//! assert_eq!(gregorian.to_rata_die() - julian.to_rata_die(), 1);
+//! assert_eq!(julian.to_iso(), Date::try_new_iso(1582, 10, 14).unwrap());
+//! assert_eq!(gregorian.to_iso(), Date::try_new_iso(1582, 10, 15).unwrap());
+//!
+//! // The days in between do not exist
+//! assert!(Date::try_new_julian_gregorian_with_calendar(1582, 10, 10, calendar).is_err());
+//! ```
+
+use crate::cal::iso::{Iso, IsoDateInner};
+use crate::cal::julian::Julian;
+use crate::calendar_arithmetic::CalendarArithmetic;
+use crate::error::{range_check, DateError};
+use crate::{types, Calendar, Date, DateDuration, DateDurationUnit, RangeError};
+use calendrical_calculations::helpers::I32CastError;
+use calendrical_calculations::rata_die::RataDie;
+use tinystr::tinystr;
+
+/// A hybrid calendar that switches from the [Julian calendar] to the [Gregorian calendar]
+/// at a configurable cutover date.
+///
+/// Dates before the cutover use the [`Julian`] calendar, and dates on or after the cutover
+/// use the (proleptic) [`Gregorian`](crate::cal::Gregorian) calendar. The days that were
+/// skipped during the switch do not exist in this calendar, so the month containing the
+/// cutover is shorter than usual.
+///
+/// This matches the behavior of Java's `GregorianCalendar`, and is useful for dealing with
+/// historical dates, such as in genealogy. By default, the cutover is on 1582-10-15, when the
+/// Gregorian calendar was first adopted.
+///
+/// This type can be used with [`Date`] to represent dates in this calendar.
+///
+/// [Julian calendar]: https://en.wikipedia.org/wiki/Julian_calendar
+/// [Gregorian calendar]: https://en.wikipedia.org/wiki/Gregorian_calendar
+///
+/// # Era codes
+///
+/// This calendar uses two era codes: `bce` (alias `bc`), and `ce` (alias `ad`), corresponding to the BCE and CE eras.
+///
+/// # Month codes
+///
+/// This calendar supports 12 solar month codes (`"M01" - "M12"`)
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct JulianGregorian {
+    /// The first day of the Gregorian calendar
+    cutover: RataDie,
+}
+
+/// The inner date type used for representing [`Date`]s of [`JulianGregorian`]. See [`Date`] and [`JulianGregorian`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct JulianGregorianDateInner(RataDie);
+
+impl Default for JulianGregorian {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JulianGregorian {
+    /// Construct a new [`JulianGregorian`] calendar that switches to the Gregorian calendar
+    /// on 1582-10-15, the day after Julian 1582-10-04.
+    pub const fn new() -> Self {
+        Self {
+            cutover: calendrical_calculations::julian::fixed_from_julian(1582, 10, 5),
+        }
+    }
+
+    /// Construct a new [`JulianGregorian`] calendar that switches to the Gregorian calendar
+    /// on 1752-09-14, the day after Julian 1752-09-02, as done by Great Britain and its colonies.
+    pub const fn new_great_britain() -> Self {
+        Self {
+            cutover: calendrical_calculations::julian::fixed_from_julian(1752, 9, 3),
+        }
+    }
+
+    /// Construct a new [`JulianGregorian`] calendar that switches to the Gregorian calendar
+    /// on the given date.
+    ///
+    /// ```rust
+    /// use icu::calendar::{cal::JulianGregorian, Date};
+    ///
+    /// // Russia switched to the Gregorian calendar in 1918
+    /// let calendar = JulianGregorian::new_with_cutover(
+    ///     Date::try_new_iso(1918, 2, 14).unwrap(),
+    /// );
+    ///
+    /// let date = Date::try_new_iso(1918, 2, 13).unwrap().to_calendar(calendar);
+    /// assert_eq!(date.month().ordinal, 1);
+    /// assert_eq!(date.day_of_month().0, 31);
+    ///
+    /// let date = Date::try_new_iso(1918, 2, 14).unwrap().to_calendar(calendar);
+    /// assert_eq!(date.month().ordinal, 2);
+    /// assert_eq!(date.day_of_month().0, 14);
+    /// assert_eq!(date.days_in_month(), 15);
+    /// ```
+    pub fn new_with_cutover(cutover: Date<Iso>) -> Self {
+        Self {
+            cutover: cutover.to_rata_die(),
+        }
+    }
+
+    /// Returns the first day of the Gregorian calendar.
+    pub fn cutover(self) -> Date<Iso> {
+        Date::from_rata_die(self.cutover, Iso)
+    }
+
+    /// The year, month, and day of `rd`.
+    fn fields(self, rd: RataDie) -> (i32, u8, u8) {
+        let fields = if rd >= self.cutover {
+            calendrical_calculations::iso::iso_from_fixed(rd)
+        } else {
+            calendrical_calculations::julian::julian_from_fixed(rd)
+        };
+        match fields {
+            Err(I32CastError::BelowMin) => (i32::MIN, 1, 1),
+            Err(I32CastError::AboveMax) => (i32::MAX, 12, 31),
+            Ok(fields) => fields,
+        }
+    }
+
+    /// The day labeled with the given fields, where `day` may overflow the month.
+    ///
+    /// Labels that were skipped during the switch resolve to the cutover.
+    fn rata_die_from_fields(self, year: i32, month: u8, day: i32) -> RataDie {
+        let offset = i64::from(day) - 1;
+        let gregorian = calendrical_calculations::iso::fixed_from_iso(year, month, 1) + offset;
+        if gregorian >= self.cutover {
+            return gregorian;
+        }
+        let julian = calendrical_calculations::julian::fixed_from_julian(year, month, 1) + offset;
+        if julian < self.cutover {
+            julian
+        } else {
+            self.cutover
+        }
+    }
+
+    /// The day labeled with the given fields, or an error if the day does not exist.
+    fn rata_die_from_valid_fields(
+        self,
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<RataDie, RangeError> {
+        // Julian months are never shorter than Gregorian months
+        let day = range_check(day, "day", 1..=Julian::days_in_provided_month(year, month))?;
+        let offset = i64::from(day) - 1;
+
+        let gregorian = calendrical_calculations::iso::fixed_from_iso(year, month, 1) + offset;
+        if gregorian >= self.cutover && day <= Iso::days_in_provided_month(year, month) {
+            return Ok(gregorian);
+        }
+        let julian = calendrical_calculations::julian::fixed_from_julian(year, month, 1) + offset;
+        if julian < self.cutover {
+            return Ok(julian);
+        }
+
+        // The day was skipped, or does not exist in the Gregorian month
+        let (cutover_year, cutover_month, cutover_day) = self.fields(self.cutover);
+        Err(RangeError {
+            field: "day",
+            value: day as i32,
+            min: if (cutover_year, cutover_month) == (year, month) {
+                cutover_day as i32
+            } else {
+                1
+            },
+            max: Iso::days_in_provided_month(year, month) as i32,
+        })
+    }
+}
+
+impl crate::cal::scaffold::UnstableSealed for JulianGregorian {}
+impl Calendar for JulianGregorian {
+    type DateInner = JulianGregorianDateInner;
+    type Year = types::EraYear;
+
+    fn from_codes(
+        &self,
+        era: Option<&str>,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, DateError> {
+        let year = match era {
+            Some("ce" | "ad") => range_check(year, "year", 1..)?,
+            None => year,
+            Some("bce" | "bc") => 1 - range_check(year, "year", 1..)?,
+            Some(_) => return Err(DateError::UnknownEra),
+        };
+
+        let Some((month @ 1..=12, false)) = month_code.parsed() else {
+            return Err(DateError::UnknownMonthCode(month_code));
+        };
+
+        Ok(JulianGregorianDateInner(
+            self.rata_die_from_valid_fields(year, month, day)?,
+        ))
+    }
+
+    fn from_rata_die(&self, rd: RataDie) -> Self::DateInner {
+        JulianGregorianDateInner(rd)
+    }
+
+    fn to_rata_die(&self, date: &Self::DateInner) -> RataDie {
+        date.0
+    }
+
+    fn from_iso(&self, iso: IsoDateInner) -> JulianGregorianDateInner {
+        JulianGregorianDateInner(Iso.to_rata_die(&iso))
+    }
+
+    fn to_iso(&self, date: &Self::DateInner) -> IsoDateInner {
+        Iso.from_rata_die(date.0)
+    }
+
+    fn months_in_year(&self, _date: &Self::DateInner) -> u8 {
+        12
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u16 {
+        let (year, _, _) = self.fields(date.0);
+        (self.rata_die_from_fields(year.saturating_add(1), 1, 1)
+            - self.rata_die_from_fields(year, 1, 1)) as u16
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        let (year, month, _) = self.fields(date.0);
+        let next_month = if month == 12 {
+            self.rata_die_from_fields(year.saturating_add(1), 1, 1)
+        } else {
+            self.rata_die_from_fields(year, month + 1, 1)
+        };
+        (next_month - self.rata_die_from_fields(year, month, 1)) as u8
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        let (year, month, day) = self.fields(date.0);
+
+        let months = i64::from(year) * 12
+            + i64::from(month - 1)
+            + i64::from(offset.years) * 12
+            + i64::from(offset.months);
+        let year = months
+            .div_euclid(12)
+            .clamp(i32::MIN.into(), i32::MAX.into()) as i32;
+        let month = months.rem_euclid(12) as u8 + 1;
+
+        date.0 = self.rata_die_from_fields(year, month, day.into())
+            + i64::from(offset.weeks) * 7
+            + i64::from(offset.days);
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        _smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        match largest_unit {
+            DateDurationUnit::Days => DateDuration::new(0, 0, 0, (date1.0 - date2.0) as i32),
+            DateDurationUnit::Weeks => {
+                let days = date1.0 - date2.0;
+                DateDuration::new(0, 0, (days / 7) as i32, (days % 7) as i32)
+            }
+            DateDurationUnit::Months | DateDurationUnit::Years => {
+                let (year1, month1, _) = self.fields(date1.0);
+                let (year2, month2, day2) = self.fields(date2.0);
+                // Counting the days from the same day in the target month makes sure
+                // that `date2 + duration == date1`, even across the cutover.
+                let days = date1.0 - self.rata_die_from_fields(year1, month1, day2.into());
+                let (years, months) = if largest_unit == DateDurationUnit::Years {
+                    (year1 - year2, month1 as i32 - month2 as i32)
+                } else {
+                    (0, (year1 - year2) * 12 + month1 as i32 - month2 as i32)
+                };
+                DateDuration::new(years, months, 0, days as i32)
+            }
+        }
+    }
+
+    /// The calendar-specific year represented by `date`
+    /// [`JulianGregorian`] has the same era scheme as Gregorian
+    fn year_info(&self, date: &Self::DateInner) -> Self::Year {
+        let (extended_year, _, _) = self.fields(date.0);
+        if extended_year > 0 {
+            types::EraYear {
+                era: tinystr!(16, "ce"),
+                era_index: Some(1),
+                year: extended_year,
+                extended_year,
+                ambiguity: match extended_year {
+                    ..=999 => types::YearAmbiguity::EraAndCenturyRequired,
+                    1000..=1949 => types::YearAmbiguity::CenturyRequired,
+                    1950..=2049 => types::YearAmbiguity::Unambiguous,
+                    2050.. => types::YearAmbiguity::CenturyRequired,
+                },
+            }
+        } else {
+            types::EraYear {
+                era: tinystr!(16, "bce"),
+                era_index: Some(0),
+                year: 1_i32.saturating_sub(extended_year),
+                extended_year,
+                ambiguity: types::YearAmbiguity::EraAndCenturyRequired,
+            }
+        }
+    }
+
+    /// Whether the year of `date` contains February 29th
+    fn is_in_leap_year(&self, date: &Self::DateInner) -> bool {
+        let (year, _, _) = self.fields(date.0);
+        self.rata_die_from_valid_fields(year, 2, 29).is_ok()
+    }
+
+    /// The calendar-specific month represented by `date`
+    fn month(&self, date: &Self::DateInner) -> types::MonthInfo {
+        let (year, month, day) = self.fields(date.0);
+        crate::calendar_arithmetic::ArithmeticDate::<Julian>::new_unchecked(year, month, day)
+            .month()
+    }
+
+    /// The calendar-specific day-of-month represented by `date`
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        let (_, _, day) = self.fields(date.0);
+        types::DayOfMonth(day)
+    }
+
+    fn day_of_year(&self, date: &Self::DateInner) -> types::DayOfYear {
+        let (year, _, _) = self.fields(date.0);
+        types::DayOfYear((date.0 - self.rata_die_from_fields(year, 1, 1) + 1) as u16)
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Julian-Gregorian"
+    }
+
+    fn calendar_algorithm(&self) -> Option<crate::preferences::CalendarAlgorithm> {
+        None
+    }
+}
+
+impl Date<JulianGregorian> {
+    /// Construct a new Julian-Gregorian Date.
+    ///
+    /// Years are arithmetic, meaning there is a year 0. Zero and negative years are in BC, with year 0 = 1 BC
+    ///
+    /// ```rust
+    /// use icu::calendar::{cal::JulianGregorian, Date};
+    ///
+    /// let calendar = JulianGregorian::new_great_britain();
+    ///
+    /// let date = Date::try_new_julian_gregorian_with_calendar(1752, 9, 2, calendar)
+    ///     .expect("Failed to initialize Julian-Gregorian Date instance.");
+    /// assert_eq!(date.to_iso(), Date::try_new_iso(1752, 9, 13).unwrap());
+    ///
+    /// // September 1752 only has 19 days
+    /// assert_eq!(date.days_in_month(), 19);
+    /// assert!(Date::try_new_julian_gregorian_with_calendar(1752, 9, 3, calendar).is_err());
+    /// ```
+    pub fn try_new_julian_gregorian_with_calendar(
+        year: i32,
+        month: u8,
+        day: u8,
+        calendar: JulianGregorian,
+    ) -> Result<Date<JulianGregorian>, RangeError> {
+        let month = range_check(month, "month", 1..=12)?;
+        calendar
+            .rata_die_from_valid_fields(year, month, day)
+            .map(|rd| Date::from_raw(JulianGregorianDateInner(rd), calendar))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cal::Gregorian;
+
+    fn date(year: i32, month: u8, day: u8) -> Date<JulianGregorian> {
+        Date::try_new_julian_gregorian_with_calendar(year, month, day, JulianGregorian::new())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_cutover() {
+        let julian = date(1582, 10, 4);
+        let gregorian = date(1582, 10, 15);
+
+        assert_eq!(
+            julian.to_calendar(Julian),
+            Date::try_new_julian(1582, 10, 4).unwrap()
+        );
+        assert_eq!(
+            gregorian.to_calendar(Gregorian),
+            Date::try_new_gregorian(1582, 10, 15).unwrap()
+        );
+        assert_eq!(gregorian.to_rata_die() - julian.to_rata_die(), 1);
+        assert_eq!(
+            julian.added(DateDuration::new(0, 0, 0, 1)),
+            gregorian.clone()
+        );
+
+        for day in 5..=14 {
+            assert_eq!(
+                Date::try_new_julian_gregorian_with_calendar(1582, 10, day, JulianGregorian::new()),
+                Err(RangeError {
+                    field: "day",
+                    value: day as i32,
+                    min: 15,
+                    max: 31
+                })
+            );
+        }
+
+        assert_eq!(julian.days_in_month(), 21);
+        assert_eq!(gregorian.days_in_month(), 21);
+        assert_eq!(julian.days_in_year(), 355);
+        assert_eq!(julian.day_of_year().0, 277);
+        assert_eq!(gregorian.day_of_year().0, 278);
+        assert_eq!(date(1582, 12, 31).day_of_year().0, 355);
+    }
+
+    #[test]
+    fn test_leap_years() {
+        // Julian leap year
+        assert!(date(1500, 2, 29).is_in_leap_year());
+        // Gregorian common year
+        assert!(!date(1700, 1, 1).is_in_leap_year());
+        assert!(
+            Date::try_new_julian_gregorian_with_calendar(1700, 2, 29, JulianGregorian::new())
+                .is_err()
+        );
+
+        // Britain still used the Julian calendar in 1700
+        let britain = JulianGregorian::new_great_britain();
+        assert!(
+            Date::try_new_julian_gregorian_with_calendar(1700, 2, 29, britain)
+                .unwrap()
+                .is_in_leap_year()
+        );
+    }
+
+    #[test]
+    fn test_eras() {
+        let bce = date(-43, 3, 15);
+        assert_eq!(bce.era_year().era, "bce");
+        assert_eq!(bce.era_year().year, 44);
+        assert_eq!(
+            bce.to_calendar(Julian),
+            Date::try_new_julian(-43, 3, 15).unwrap()
+        );
+
+        let bce = Date::try_new_from_codes(
+            Some("bc"),
+            44,
+            types::MonthCode::new_normal(3).unwrap(),
+            15,
+            JulianGregorian::new(),
+        )
+        .unwrap();
+        assert_eq!(bce, date(-43, 3, 15));
+    }
+
+    #[test]
+    fn test_arithmetic_across_cutover() {
+        let start = date(1582, 10, 1);
+        let end = date(1582, 11, 1);
+
+        let duration = end.until(&start, DateDurationUnit::Days, DateDurationUnit::Days);
+        assert_eq!(duration, DateDuration::new(0, 0, 0, 21));
+
+        let duration = end.until(&start, DateDurationUnit::Months, DateDurationUnit::Days);
+        assert_eq!(duration, DateDuration::new(0, 1, 0, 0));
+        assert_eq!(start.added(duration), end);
+
+        let start = date(1582, 9, 10);
+        let end = date(1582, 10, 20);
+        let duration = end.until(&start, DateDurationUnit::Years, DateDurationUnit::Days);
+        assert_eq!(duration, DateDuration::new(0, 1, 0, 5));
+        assert_eq!(start.added(duration), end);
+
+        // 1582-10-10 was skipped, so the days are counted from the cutover
+        let end = date(1582, 10, 16);
+        let duration = end.until(&start, DateDurationUnit::Months, DateDurationUnit::Days);
+        assert_eq!(duration, DateDuration::new(0, 1, 0, 1));
+        assert_eq!(start.added(duration), end);
+
+        assert_eq!(
+            start.added(DateDuration::new(0, 1, 0, 0)),
+            date(1582, 10, 15)
+        );
+    }
+}
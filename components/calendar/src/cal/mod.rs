@@ -12,10 +12,12 @@ pub(crate) mod gregorian;
 pub(crate) mod hebrew;
 #[path = "hijri.rs"]
 pub(crate) mod hijri_internal;
+pub(crate) mod hindu;
 pub(crate) mod indian;
 pub(crate) mod iso;
 pub(crate) mod japanese;
 pub(crate) mod julian;
+pub(crate) mod julian_gregorian;
 pub(crate) mod persian;
 pub(crate) mod roc;
 
@@ -23,13 +25,14 @@ pub use buddhist::Buddhist;
 pub use chinese_internal::LunarChinese;
 /// Customizations for the [`LunarChinese`] calendar.
 pub mod chinese {
-    pub use super::chinese_internal::{China, Dangi, LunarChineseYearData, Rules};
+    pub use super::chinese_internal::{China, Dangi, LunarChineseYearData, Rules, Vietnamese};
 }
 pub use coptic::Coptic;
 pub use ethiopian::{Ethiopian, EthiopianEraStyle};
 pub use gregorian::Gregorian;
 pub use hebrew::Hebrew;
 pub use hijri_internal::Hijri;
+pub use hindu::{HinduLocation, HinduLunisolar, HinduMonthScheme, HinduSolar};
 /// Customizations for the [`Hijri`] calendar.
 pub mod hijri {
     pub use super::hijri_internal::{
@@ -41,6 +44,7 @@ pub use indian::Indian;
 pub use iso::Iso;
 pub use japanese::{Japanese, JapaneseExtended};
 pub use julian::Julian;
+pub use julian_gregorian::JulianGregorian;
 pub use persian::Persian;
 pub use roc::Roc;
 
@@ -174,6 +174,33 @@ impl<A: AsCalendar> Date<A> {
         self.calendar.as_calendar().months_in_year(self.inner())
     }
 
+    /// The months in the year of this date, in order, including any leap months
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::cal::Hebrew;
+    /// use icu::calendar::types::MonthCode;
+    /// use icu::calendar::Date;
+    ///
+    /// // 5784 AM is a leap year, with the leap month Adar I
+    /// let date = Date::try_new_from_codes(Some("am"), 5784, MonthCode::new_normal(1).unwrap(), 1, Hebrew).unwrap();
+    /// let months = date.months_of_year().map(|m| m.standard_code.0).collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     months,
+    ///     ["M01", "M02", "M03", "M04", "M05", "M05L", "M06", "M07", "M08", "M09", "M10", "M11", "M12"]
+    /// );
+    /// ```
+    pub fn months_of_year(&self) -> impl Iterator<Item = types::MonthInfo> + '_ {
+        let calendar = self.calendar.as_calendar();
+        let mut first_day_of_month = self.to_rata_die() - i64::from(self.day_of_year().0) + 1;
+        (0..self.months_in_year()).map(move |_| {
+            let date = calendar.from_rata_die(first_day_of_month);
+            first_day_of_month += i64::from(calendar.days_in_month(&date));
+            calendar.month(&date)
+        })
+    }
+
     /// The number of days in the year of this date
     #[inline]
     pub fn days_in_year(&self) -> u16 {
@@ -277,6 +304,28 @@ impl<A: AsCalendar> Date<A> {
         self.calendar.as_calendar().day_of_year(&self.inner)
     }
 
+    /// The day of the year along with the lengths of this and the previous year,
+    /// and the previous and next years
+    ///
+    /// This is the input to week-of-year calculations, see
+    /// [`WeekInformation::week_of_year`](crate::week::WeekInformation::week_of_year).
+    pub fn day_of_year_info(&self) -> types::DayOfYearInfo {
+        let calendar = self.calendar.as_calendar();
+        let day_of_year = self.day_of_year();
+        let days_in_year = self.days_in_year();
+        let rata_die = self.to_rata_die();
+        let last_day_of_prev_year = calendar.from_rata_die(rata_die - i64::from(day_of_year.0));
+        let first_day_of_next_year =
+            calendar.from_rata_die(rata_die + i64::from(days_in_year - day_of_year.0) + 1);
+        types::DayOfYearInfo {
+            day_of_year,
+            days_in_year,
+            days_in_prev_year: calendar.days_in_year(&last_day_of_prev_year),
+            prev_year: calendar.year_info(&last_day_of_prev_year).into(),
+            next_year: calendar.year_info(&first_day_of_next_year).into(),
+        }
+    }
+
     /// Construct a date from raw values for a given calendar. This does not check any
     /// invariants for the date and calendar, and should only be called by calendar implementations.
     ///
@@ -47,6 +47,7 @@ const _: () = {
     impl_calendar_japanese_modern_v1!(Baked);
     impl_calendar_japanese_extended_v1!(Baked);
     impl_calendar_week_v1!(Baked);
+    impl_calendar_week_v2!(Baked);
 };
 
 icu_provider::data_marker!(
@@ -74,6 +75,17 @@ icu_provider::data_marker!(
         config
     },
 );
+icu_provider::data_marker!(
+    /// Week information, including the minimal days in the first week
+    CalendarWeekV2,
+    "calendar/week/v2",
+    WeekDataV2,
+    fallback_config = {
+        let mut config = LocaleFallbackConfig::default();
+        config.priority = LocaleFallbackPriority::Region;
+        config
+    },
+);
 
 #[cfg(feature = "datagen")]
 /// The latest minimum set of markers required by this component.
@@ -81,6 +93,7 @@ pub const MARKERS: &[DataMarkerInfo] = &[
     CalendarJapaneseModernV1::INFO,
     CalendarJapaneseExtendedV1::INFO,
     CalendarWeekV1::INFO,
+    CalendarWeekV2::INFO,
 ];
 
 /// The date at which an era started
@@ -157,6 +170,36 @@ icu_provider::data_struct!(
     #[cfg(feature = "datagen")]
 );
 
+/// An ICU4X mapping to a subset of CLDR weekData, including the data
+/// required for week numbering.
+/// See CLDR-JSON's weekData.json for more context.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Clone, Copy, Debug, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_calendar::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[allow(clippy::exhaustive_structs)] // used in data provider
+pub struct WeekDataV2 {
+    /// The first day of a week.
+    pub first_weekday: Weekday,
+    /// Bitset representing weekdays that are part of the 'weekend', for calendar purposes.
+    /// The number of days can be different between locales, and may not be contiguous.
+    pub weekend: WeekdaySet,
+    /// The minimal number of days of a year or month that need to fall into its first
+    /// week for that week to be counted as week 1.
+    pub min_week_days: u8,
+}
+
+icu_provider::data_struct!(
+    WeekDataV2,
+    #[cfg(feature = "datagen")]
+);
+
 /// Bitset representing weekdays.
 //
 // This Bitset uses an [u8] to represent the weekend, thus leaving one bit free.
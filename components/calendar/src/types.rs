@@ -4,6 +4,7 @@
 
 //! This module contains various types used by `icu_calendar` and `icu::datetime`
 
+use crate::{Date, Iso};
 #[doc(no_inline)]
 pub use calendrical_calculations::rata_die::RataDie;
 use core::fmt;
@@ -122,6 +123,34 @@ pub struct CyclicYear {
     pub related_iso: i32,
 }
 
+/// Information about an era of a calendar, as returned by [`AnyCalendar::eras()`](crate::AnyCalendar::eras).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EraInfo {
+    /// The era code, as in [`EraYear::era`]
+    pub code: TinyStr16,
+    /// The era index, as in [`EraYear::era_index`]
+    pub index: Option<u8>,
+    /// The first day of the era, or `None` if the era extends indefinitely into the past
+    pub start: Option<Date<Iso>>,
+    /// The last day of the era, or `None` if the era extends indefinitely into the future
+    pub end: Option<Date<Iso>>,
+    /// Whether years in this era are counted backwards, like in the `bce` era
+    pub is_inverse: bool,
+}
+
+/// Whether the months and years of a calendar follow the moon, the sun, or both.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::exhaustive_enums)] // logically complete
+pub enum CalendarBasis {
+    /// Years follow the seasons, and months do not follow the moon, like in the Gregorian calendar.
+    Solar,
+    /// Months follow the moon, and years of twelve months do not follow the seasons, like in the Hijri calendars.
+    Lunar,
+    /// Months follow the moon, and leap months keep years in line with the seasons, like in the Hebrew and Chinese calendars.
+    Lunisolar,
+}
+
 /// Representation of a month in a year
 ///
 /// Month codes typically look like `M01`, `M02`, etc, but can handle leap months
@@ -285,6 +314,25 @@ impl MonthInfo {
 #[allow(clippy::exhaustive_structs)] // this is a newtype
 pub struct DayOfYear(pub u16);
 
+/// The day of the year along with the surrounding years,
+/// as needed for week-of-year calculations.
+///
+/// See [`WeekInformation::week_of_year`](crate::week::WeekInformation::week_of_year).
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct DayOfYearInfo {
+    /// The current day of the year, 1-based.
+    pub day_of_year: DayOfYear,
+    /// The number of days in the current year.
+    pub days_in_year: u16,
+    /// The number of days in the previous year.
+    pub days_in_prev_year: u16,
+    /// The previous year, as needed for the week-based year of dates in its last week.
+    pub prev_year: YearInfo,
+    /// The next year, as needed for the week-based year of dates in its first week.
+    pub next_year: YearInfo,
+}
+
 /// A 1-based day number in a month.
 #[allow(clippy::exhaustive_structs)] // this is a newtype
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -300,6 +348,15 @@ pub struct IsoWeekOfYear {
     pub iso_year: i32,
 }
 
+/// A week number in a month.
+///
+/// The week containing the first day of the month is week 1 if it has at least
+/// [`WeekInformation::min_week_days`](crate::week::WeekInformation::min_week_days) days
+/// in the month, and week 0 otherwise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this is a newtype
+pub struct WeekOfMonth(pub u8);
+
 /// A day of week in month. 1-based.
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[allow(clippy::exhaustive_structs)] // this is a newtype
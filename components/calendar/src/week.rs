@@ -4,7 +4,11 @@
 
 //! Functions for region-specific weekday information.
 
-use crate::{error::RangeError, provider::*, types::Weekday};
+use crate::{
+    error::RangeError,
+    provider::*,
+    types::{DayOfMonth, DayOfYearInfo, WeekOfMonth, Weekday},
+};
 use icu_locale_core::preferences::{define_preferences, extensions::unicode::keywords::FirstDay};
 use icu_provider::prelude::*;
 
@@ -29,6 +33,9 @@ pub struct WeekInformation {
     pub first_weekday: Weekday,
     /// The set of weekend days
     pub weekend: WeekdaySet,
+    /// The minimal number of days of a year or month that need to fall into its first
+    /// week for that week to be counted as week 1.
+    pub min_week_days: u8,
 }
 
 impl WeekInformation {
@@ -40,9 +47,9 @@ impl WeekInformation {
     #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
     pub fn try_new_unstable<P>(provider: &P, prefs: WeekPreferences) -> Result<Self, DataError>
     where
-        P: DataProvider<crate::provider::CalendarWeekV1> + ?Sized,
+        P: DataProvider<crate::provider::CalendarWeekV2> + ?Sized,
     {
-        let locale = CalendarWeekV1::make_locale(prefs.locale_preferences);
+        let locale = CalendarWeekV2::make_locale(prefs.locale_preferences);
         provider
             .load(DataRequest {
                 id: DataIdentifierBorrowed::for_locale(&locale),
@@ -60,6 +67,7 @@ impl WeekInformation {
                     _ => response.payload.get().first_weekday,
                 },
                 weekend: response.payload.get().weekend,
+                min_week_days: response.payload.get().min_week_days.clamp(1, 7),
             })
     }
 
@@ -68,6 +76,100 @@ impl WeekInformation {
     pub fn weekend(self) -> WeekdaySetIterator {
         WeekdaySetIterator::new(self.first_weekday, self.weekend)
     }
+
+    /// Returns the week of year of a day, according to the rules of this locale.
+    ///
+    /// The first days of a year can be part of the last week of the previous year, and
+    /// the last days of a year can be part of the first week of the next year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::week::{RelativeUnit, WeekInformation, WeekOf};
+    /// use icu::calendar::Date;
+    /// use icu::locale::locale;
+    ///
+    /// let us = WeekInformation::try_new(locale!("und-US").into()).unwrap();
+    /// let de = WeekInformation::try_new(locale!("und-DE").into()).unwrap();
+    ///
+    /// // 2021 starts on a Friday
+    /// let date = Date::try_new_gregorian(2021, 1, 1).unwrap();
+    ///
+    /// assert_eq!(
+    ///     us.week_of_year(date.day_of_year_info(), date.day_of_week()),
+    ///     WeekOf {
+    ///         week: 1,
+    ///         unit: RelativeUnit::Current
+    ///     }
+    /// );
+    /// assert_eq!(
+    ///     de.week_of_year(date.day_of_year_info(), date.day_of_week()),
+    ///     WeekOf {
+    ///         week: 53,
+    ///         unit: RelativeUnit::Previous
+    ///     }
+    /// );
+    /// ```
+    pub fn week_of_year(self, day_of_year_info: DayOfYearInfo, weekday: Weekday) -> WeekOf {
+        self.calculator()
+            .week_of(
+                day_of_year_info.days_in_prev_year,
+                day_of_year_info.days_in_year,
+                day_of_year_info.day_of_year.0,
+                weekday,
+            )
+            .unwrap_or_else(|_| {
+                // Years have more than 14 days in all calendars
+                debug_assert!(false);
+                WeekOf {
+                    week: 1,
+                    unit: RelativeUnit::Current,
+                }
+            })
+    }
+
+    /// Returns the week of month of a day, according to the rules of this locale.
+    ///
+    /// Unlike [`Self::week_of_year`], weeks are never assigned to the previous or next month:
+    /// days before the first week of the month are in week 0, and days after the last full
+    /// week are in an additional week.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::types::WeekOfMonth;
+    /// use icu::calendar::week::WeekInformation;
+    /// use icu::calendar::Date;
+    /// use icu::locale::locale;
+    ///
+    /// let us = WeekInformation::try_new(locale!("und-US").into()).unwrap();
+    /// let de = WeekInformation::try_new(locale!("und-DE").into()).unwrap();
+    ///
+    /// // January 2021 starts on a Friday
+    /// let date = Date::try_new_gregorian(2021, 1, 2).unwrap();
+    ///
+    /// assert_eq!(
+    ///     us.week_of_month(date.day_of_month(), date.day_of_week()),
+    ///     WeekOfMonth(1)
+    /// );
+    /// assert_eq!(
+    ///     de.week_of_month(date.day_of_month(), date.day_of_week()),
+    ///     WeekOfMonth(0)
+    /// );
+    /// ```
+    pub fn week_of_month(self, day_of_month: DayOfMonth, weekday: Weekday) -> WeekOfMonth {
+        WeekOfMonth(
+            self.calculator()
+                .simple_week_of(u16::from(day_of_month.0), weekday),
+        )
+    }
+
+    fn calculator(self) -> WeekCalculator {
+        WeekCalculator {
+            first_weekday: self.first_weekday,
+            min_week_days: self.min_week_days,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -133,6 +235,39 @@ impl WeekCalculator {
             }),
         }
     }
+
+    /// Computes & returns the week of the given month/year, without assigning days
+    /// to the preceding or following month/year.
+    ///
+    /// Days before the first week are in week 0.
+    ///
+    /// # Arguments
+    ///  - day: 1-based day of month/year.
+    ///  - week_day: The weekday of `day`.
+    pub(crate) fn simple_week_of(self, day: u16, week_day: Weekday) -> u8 {
+        // The first day of this month/year is (day - 1) days from `day`.
+        let first_day = add_to_weekday(week_day, 1 - i32::from(day));
+        let days_since_first_week =
+            i32::from(day) - i32::from(self.first_week_offset(first_day)) - 1;
+        if days_since_first_week < 0 {
+            0
+        } else {
+            (1 + days_since_first_week / 7) as u8
+        }
+    }
+
+    /// Returns the start of the first week of a month/year starting on `first_day`.
+    ///
+    /// The returned value can be negative if the first week started during the previous
+    /// month/year.
+    fn first_week_offset(self, first_day: Weekday) -> i8 {
+        let first_day_index = self.weekday_index(first_day);
+        if 7 - first_day_index >= self.min_week_days as i8 {
+            -first_day_index
+        } else {
+            7 - first_day_index
+        }
+    }
 }
 
 /// Returns the weekday that's `num_days` after `weekday`.
@@ -185,12 +320,7 @@ impl UnitInfo {
     /// The returned value can be negative if this unit's first week started during the previous
     /// unit.
     fn first_week_offset(self, calendar: WeekCalculator) -> i8 {
-        let first_day_index = calendar.weekday_index(self.first_day);
-        if 7 - first_day_index >= calendar.min_week_days as i8 {
-            -first_day_index
-        } else {
-            7 - first_day_index
-        }
+        calendar.first_week_offset(self.first_day)
     }
 
     /// Returns the number of weeks in this unit according to `calendar`.
@@ -222,9 +352,9 @@ impl UnitInfo {
 }
 
 /// The year or month that a calendar assigns a week to relative to the year/month that it is in.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[allow(clippy::exhaustive_enums)] // this type is stable
-pub(crate) enum RelativeUnit {
+pub enum RelativeUnit {
     /// A week that is assigned to previous year/month. e.g. 2021-01-01 is week 54 of 2020 per the ISO calendar.
     Previous,
     /// A week that's assigned to the current year/month. e.g. 2021-01-11 is week 2 of 2021 per the ISO calendar.
@@ -234,9 +364,9 @@ pub(crate) enum RelativeUnit {
 }
 
 /// The week number assigned to a given week according to a calendar.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[allow(clippy::exhaustive_structs)] // this type is stable
-pub(crate) struct WeekOf {
+pub struct WeekOf {
     /// Week of month/year. 1 based.
     pub week: u8,
     /// The month/year that this week is in, relative to the month/year of the input date.
@@ -448,6 +578,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_simple_week_of() {
+        for min_week_days in 1..7 {
+            for start_of_week in 0..7 {
+                let calendar = WeekCalculator {
+                    first_weekday: Weekday::from_days_since_sunday(start_of_week),
+                    min_week_days,
+                };
+                for start_of_unit in 0..7 {
+                    let unit =
+                        UnitInfo::new(Weekday::from_days_since_sunday(start_of_unit), 31).unwrap();
+                    for day in 1..=31 {
+                        let expected = match unit.relative_week(calendar, day) {
+                            RelativeWeek::LastWeekOfPreviousUnit => 0,
+                            RelativeWeek::WeekOfCurrentUnit(w) => w,
+                            RelativeWeek::FirstWeekOfNextUnit => unit.num_weeks(calendar) + 1,
+                        };
+                        let week_day = super::add_to_weekday(unit.first_day, i32::from(day) - 1);
+                        assert_eq!(calendar.simple_week_of(day, week_day), expected);
+                    }
+                }
+            }
+        }
+    }
+
     fn week_of_month_from_iso_date(
         calendar: WeekCalculator,
         yyyymmdd: u32,
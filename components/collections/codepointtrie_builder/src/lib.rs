@@ -8,20 +8,20 @@
 //! Because it is normally expected for [`CodePointTrie`] data to be pre-compiled, this crate is not
 //! optimized for speed; it should be used during a build phase.
 //!
-//! Under the hood, this crate uses the CodePointTrie builder code from ICU4C, [`UMutableCPTrie`].
-//! For more context, see <https://github.com/unicode-org/icu4x/issues/1837>.
+//! The builder is a Rust port of the CodePointTrie builder code from ICU4C, [`UMutableCPTrie`],
+//! and produces the same output. For more context, see <https://github.com/unicode-org/icu4x/issues/1837>.
 //!
-//! Unlike most of ICU4X, due in large part to the native dependency, this crate is not guaranteed
-//! to be panic-free.
+//! Unlike most of ICU4X, this crate is not guaranteed to be panic-free.
 //!
 //! # Build configuration
 //!
-//! This crate has two primary modes it can be used in: `"wasm"` and `"icu4c"`, exposed as
-//! Cargo features. If both are enabled, the code will internally use the wasm codepath.
+//! The builder does not need any Cargo features. The `"wasm"` and `"icu4c"` features only exist
+//! to test the Rust builder against the ICU4C code it was ported from. If both are enabled, the
+//! tests use the wasm codepath.
 //!
 //! The `"wasm"` mode uses a Wasm module packaged into this Rust crate that contains
 //! pre-compiled ICU4C CodePointTrie builder code. It evaluates the Wasm module using
-//! the Wasmer runtime, which "just works", but it requires a large number of
+//! the Wasmi runtime, which "just works", but it requires a large number of
 //! Rust/Cargo dependencies.
 //!
 //! The `"icu4c"` mode reduces the number of Rust dependencies, but it requires having a local copy
@@ -88,13 +88,15 @@
 use icu_collections::codepointtrie::TrieType;
 use icu_collections::codepointtrie::TrieValue;
 
-#[cfg(any(feature = "wasm", feature = "icu4c"))]
+mod mutable;
+
+#[cfg(all(test, any(feature = "wasm", feature = "icu4c")))]
 mod common;
 
-#[cfg(feature = "wasm")]
+#[cfg(all(test, feature = "wasm"))]
 mod wasm;
 
-#[cfg(feature = "icu4c")]
+#[cfg(all(test, feature = "icu4c"))]
 mod native;
 
 /// Wrapper over the data to be encoded into a [`CodePointTrie`].
@@ -134,28 +136,20 @@ where
 {
     /// Build the [`CodePointTrie`].
     ///
-    /// Under the hood, this function runs ICU4C code compiled into WASM,
-    /// or links natively to ICU4C as specified by the `ICU4C_LIB_PATH` env var
+    /// The output is identical to that of ICU4C's `umutablecptrie_buildImmutable`.
+    ///
+    /// # Panics
     ///
-    /// ✨ *Enabled with either the `wasm` or the `icu4c` Cargo feature.*
+    /// Panics if the data has values for more than 0x110000 code points, or if the
+    /// data is too varied to fit into a [`CodePointTrie`].
     ///
     /// [`CodePointTrie`]: icu_collections::codepointtrie::CodePointTrie
-    #[cfg(any(feature = "wasm", feature = "icu4c"))]
     pub fn build(self) -> icu_collections::codepointtrie::CodePointTrie<'static, T> {
-        #[cfg(feature = "wasm")]
-        {
-            wasm::run_wasmi_ucptrie_wrap(&self)
-        }
-
-        #[cfg(all(feature = "icu4c", not(feature = "wasm")))]
-        {
-            native::run_native(&self)
-        }
+        mutable::build(&self)
     }
 }
 
 #[test]
-#[cfg(any(feature = "wasm", feature = "icu4c"))]
 fn test_cpt_builder() {
     // Buckets of ten characters for 0 to 100, and then some default values, and then heterogenous "last hex digit" for 0x100 to 0x200
     let values: Vec<u32> = (0..100)
@@ -187,3 +181,107 @@ fn test_cpt_builder() {
     // default value
     assert_eq!(cpt.get32(0x300), 100);
 }
+
+/// Checks that the Rust builder produces the same tries as ICU4C, for both trie types.
+#[cfg(all(test, any(feature = "wasm", feature = "icu4c")))]
+fn assert_same_as_icu4c<T>(values: &[T], default_value: T, error_value: T)
+where
+    T: TrieValue + core::fmt::Debug,
+{
+    for trie_type in [TrieType::Fast, TrieType::Small] {
+        let builder = CodePointTrieBuilder {
+            data: CodePointTrieBuilderData::ValuesByCodePoint(values),
+            default_value,
+            error_value,
+            trie_type,
+        };
+        #[cfg(feature = "wasm")]
+        let expected = wasm::run_wasmi_ucptrie_wrap(&builder);
+        #[cfg(all(feature = "icu4c", not(feature = "wasm")))]
+        let expected = native::run_native(&builder);
+        assert_eq!(builder.build(), expected, "{trie_type:?}");
+    }
+}
+
+#[test]
+#[cfg(any(feature = "wasm", feature = "icu4c"))]
+fn test_same_as_icu4c() {
+    assert_same_as_icu4c::<u8>(&[], 7, 0xFF);
+    assert_same_as_icu4c::<u16>(&[1, 2, 3, 0x1234], 0, 0xFFFF);
+
+    let runs = pseudo_random_runs(0x20000);
+    assert_same_as_icu4c(&runs, 0, 0xFFFF_FFFF);
+    let values = runs.iter().map(|&v| v as u16 * 0x101).collect::<Vec<_>>();
+    assert_same_as_icu4c(&values, 0, 0xFFFF);
+    let values = runs.iter().map(|&v| v as i8 - 50).collect::<Vec<_>>();
+    assert_same_as_icu4c(&values, 0, -1);
+    let values = runs
+        .iter()
+        .map(|&v| char::from_u32(0x1F600 + v).unwrap())
+        .collect::<Vec<_>>();
+    assert_same_as_icu4c(&values, '\0', char::REPLACEMENT_CHARACTER);
+
+    // Values up to U+10FFFF
+    let mut values = vec![0u16; 0x110000];
+    values[0x1F000..0x1F0FF].fill(1);
+    values[0xE0001] = 2;
+    values[0x10FFF0..].fill(3);
+    assert_same_as_icu4c(&values, 0, 0xFFFF);
+
+    // Noise, which needs data offsets beyond 16 bits
+    let mut next = xorshift();
+    let values = (0..0x14000).map(|_| next() % 0x1000).collect::<Vec<u32>>();
+    assert_same_as_icu4c(&values, 0, 0xFFFF_FFFF);
+}
+
+#[test]
+#[ignore] // slow
+#[cfg(any(feature = "wasm", feature = "icu4c"))]
+fn test_same_as_icu4c_full_range() {
+    use icu::properties::props::{GeneralCategory, Script};
+    use icu::properties::CodePointMapData;
+
+    let gc = CodePointMapData::<GeneralCategory>::new();
+    let values = (0..=0x10FFFF).map(|c| gc.get32(c)).collect::<Vec<_>>();
+    assert_same_as_icu4c(
+        &values,
+        GeneralCategory::Unassigned,
+        GeneralCategory::Unassigned,
+    );
+
+    let script = CodePointMapData::<Script>::new();
+    let values = (0..=0x10FFFF).map(|c| script.get32(c)).collect::<Vec<_>>();
+    assert_same_as_icu4c(&values, Script::Unknown, Script::Unknown);
+
+    let runs = pseudo_random_runs(0x110000);
+    assert_same_as_icu4c(&runs, 0, 0xFFFF_FFFF);
+    let values = runs.iter().map(|&v| v as u8).collect::<Vec<_>>();
+    assert_same_as_icu4c(&values, 0, 0xFF);
+}
+
+/// xorshift32, for reproducible pseudo-random data
+#[cfg(all(test, any(feature = "wasm", feature = "icu4c")))]
+fn xorshift() -> impl FnMut() -> u32 {
+    let mut state = 0x2545_F491_u32;
+    move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state
+    }
+}
+
+/// Runs of values, with more distinct values than fit into the builder's
+/// cache of same-value blocks
+#[cfg(all(test, any(feature = "wasm", feature = "icu4c")))]
+fn pseudo_random_runs(length: usize) -> Vec<u32> {
+    let mut next = xorshift();
+    let mut runs = Vec::new();
+    while runs.len() < length {
+        let value = next() % 100;
+        let run_length = next() % 4000 + 1;
+        runs.extend((0..run_length).map(|_| value));
+    }
+    runs.truncate(length);
+    runs
+}
@@ -0,0 +1,1255 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A Rust port of the ICU4C mutable code point trie, `umutablecptrie.cpp`.
+//!
+//! The compaction steps are reproduced exactly so that the output is identical
+//! to that of `umutablecptrie_buildImmutable`. Keep it that way: generated data
+//! must not change depending on which builder was used.
+
+use crate::CodePointTrieBuilder;
+use crate::CodePointTrieBuilderData;
+
+use icu_collections::codepointtrie::{CodePointTrie, CodePointTrieHeader, TrieType, TrieValue};
+use zerovec::ZeroVec;
+
+const MAX_UNICODE: u32 = 0x10ffff;
+const UNICODE_LIMIT: u32 = 0x110000;
+const ASCII_LIMIT: usize = 0x80;
+
+const SHIFT_3: u32 = 4;
+const SHIFT_2: u32 = 5 + SHIFT_3;
+const SHIFT_1: u32 = 5 + SHIFT_2;
+const SHIFT_2_3: u32 = SHIFT_2 - SHIFT_3;
+const SHIFT_1_2: u32 = SHIFT_1 - SHIFT_2;
+const FAST_SHIFT: u32 = 6;
+
+const INDEX_2_BLOCK_LENGTH: usize = 1 << SHIFT_1_2;
+const INDEX_2_MASK: usize = INDEX_2_BLOCK_LENGTH - 1;
+const CP_PER_INDEX_2_ENTRY: u32 = 1 << SHIFT_2;
+const INDEX_3_BLOCK_LENGTH: usize = 1 << SHIFT_2_3;
+const SMALL_DATA_BLOCK_LENGTH: usize = 1 << SHIFT_3;
+const SMALL_DATA_MASK: u32 = SMALL_DATA_BLOCK_LENGTH as u32 - 1;
+const FAST_DATA_BLOCK_LENGTH: usize = 1 << FAST_SHIFT;
+const BMP_INDEX_LENGTH: usize = 0x10000 >> FAST_SHIFT;
+const SMALL_LIMIT: u32 = 0x1000;
+
+const NO_INDEX3_NULL_OFFSET: u16 = 0x7fff;
+const NO_DATA_NULL_OFFSET: u32 = 0xfffff;
+
+const I_LIMIT: usize = (UNICODE_LIMIT >> SHIFT_3) as usize;
+const BMP_I_LIMIT: usize = 0x10000 >> SHIFT_3;
+const ASCII_I_LIMIT: usize = ASCII_LIMIT >> SHIFT_3;
+
+const SMALL_DATA_BLOCKS_PER_BMP_BLOCK: usize = 1 << (FAST_SHIFT - SHIFT_3);
+
+/// Index-3 blocks with 18-bit data offsets store the upper bits of each group
+/// of 8 offsets in an additional 16-bit unit.
+const INDEX_3_18BIT_BLOCK_LENGTH: usize = INDEX_3_BLOCK_LENGTH + INDEX_3_BLOCK_LENGTH / 8;
+
+// Flag values for data blocks.
+const ALL_SAME: u8 = 0;
+const MIXED: u8 = 1;
+const SAME_AS: u8 = 2;
+
+// Flag values for index-3 blocks while compacting/building.
+const I3_NULL: u8 = 0;
+const I3_BMP: u8 = 1;
+const I3_16: u8 = 2;
+const I3_18: u8 = 3;
+
+/// Builds a [`CodePointTrie`] from the builder's data.
+pub(crate) fn build<T>(builder: &CodePointTrieBuilder<T>) -> CodePointTrie<'static, T>
+where
+    T: TrieValue,
+{
+    let default_value = builder.default_value.to_u32();
+    let mut trie = MutableCodePointTrie::new(default_value, builder.error_value.to_u32());
+
+    let CodePointTrieBuilderData::ValuesByCodePoint(values) = builder.data;
+    assert!(
+        values.len() <= UNICODE_LIMIT as usize,
+        "Cannot set values beyond U+10FFFF"
+    );
+    let mut start = 0;
+    while start < values.len() {
+        let value = values[start].to_u32();
+        let mut end = start + 1;
+        while end < values.len() && values[end].to_u32() == value {
+            end += 1;
+        }
+        if value != default_value {
+            trie.set_range(start as u32, end as u32 - 1, value);
+        }
+        start = end;
+    }
+
+    let width = match core::mem::size_of::<T::ULE>() {
+        1 => ValueWidth::Bits8,
+        2 => ValueWidth::Bits16,
+        3 | 4 => ValueWidth::Bits32,
+        other => panic!("Don't know how to make trie with width {other}"),
+    };
+    let (header, index, data) = trie.build(builder.trie_type, width);
+
+    let bytes = match core::mem::size_of::<T::ULE>() {
+        1 => data.iter().map(|&v| v as u8).collect::<Vec<_>>(),
+        2 => data
+            .iter()
+            .flat_map(|&v| (v as u16).to_le_bytes())
+            .collect(),
+        // 24-bit values are built as a 32-bit trie and then truncated
+        n => data
+            .iter()
+            .flat_map(|&v| v.to_le_bytes().into_iter().take(n))
+            .collect(),
+    };
+    let data = ZeroVec::<T>::parse_bytes(&bytes)
+        .expect("Values were created from valid values of T")
+        .into_owned();
+
+    CodePointTrie::try_new(header, ZeroVec::alloc_from_slice(&index), data)
+        .expect("Failed to construct")
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum ValueWidth {
+    Bits16,
+    Bits32,
+    Bits8,
+}
+
+/// The build-time trie, which stores a 32-bit value for each code point
+/// in blocks of [`SMALL_DATA_BLOCK_LENGTH`] code points.
+struct MutableCodePointTrie {
+    /// For each data block, either its value (if [`ALL_SAME`]) or its offset in `data`.
+    index: Vec<u32>,
+    index3_null_offset: Option<u16>,
+    data: Vec<u32>,
+    data_null_offset: u32,
+
+    initial_value: u32,
+    error_value: u32,
+    high_start: u32,
+    high_value: u32,
+
+    /// Temporary array while building the final index.
+    index16: Vec<u16>,
+    flags: Vec<u8>,
+}
+
+impl MutableCodePointTrie {
+    fn new(initial_value: u32, error_value: u32) -> Self {
+        Self {
+            index: vec![0; I_LIMIT],
+            index3_null_offset: None,
+            data: Vec::new(),
+            data_null_offset: NO_DATA_NULL_OFFSET,
+            initial_value,
+            error_value,
+            high_start: 0,
+            high_value: initial_value,
+            index16: Vec::new(),
+            flags: vec![ALL_SAME; I_LIMIT],
+        }
+    }
+
+    fn get(&self, c: u32) -> u32 {
+        if c > MAX_UNICODE {
+            return self.error_value;
+        }
+        if c >= self.high_start {
+            return self.high_value;
+        }
+        let i = (c >> SHIFT_3) as usize;
+        if self.flags[i] == ALL_SAME {
+            self.index[i]
+        } else {
+            self.data[self.index[i] as usize + (c & SMALL_DATA_MASK) as usize]
+        }
+    }
+
+    fn ensure_high_start(&mut self, c: u32) {
+        if c >= self.high_start {
+            // Round up to a CP_PER_INDEX_2_ENTRY boundary to simplify compaction.
+            let c = (c + CP_PER_INDEX_2_ENTRY) & !(CP_PER_INDEX_2_ENTRY - 1);
+            let i_start = (self.high_start >> SHIFT_3) as usize;
+            let i_limit = (c >> SHIFT_3) as usize;
+            self.flags[i_start..i_limit].fill(ALL_SAME);
+            self.index[i_start..i_limit].fill(self.initial_value);
+            self.high_start = c;
+        }
+    }
+
+    fn alloc_data_block(&mut self, block_length: usize) -> usize {
+        let new_block = self.data.len();
+        self.data.resize(new_block + block_length, 0);
+        new_block
+    }
+
+    /// Returns the offset of the data block for index `i`, turning it into a
+    /// [`MIXED`] block if necessary.
+    fn get_data_block(&mut self, i: usize) -> usize {
+        if self.flags[i] == MIXED {
+            return self.index[i] as usize;
+        }
+        if i < BMP_I_LIMIT {
+            // Fast-range data blocks are allocated together.
+            let mut new_block = self.alloc_data_block(FAST_DATA_BLOCK_LENGTH);
+            let i_start = i & !(SMALL_DATA_BLOCKS_PER_BMP_BLOCK - 1);
+            for i_block in i_start..i_start + SMALL_DATA_BLOCKS_PER_BMP_BLOCK {
+                debug_assert_eq!(self.flags[i_block], ALL_SAME);
+                let value = self.index[i_block];
+                self.data[new_block..new_block + SMALL_DATA_BLOCK_LENGTH].fill(value);
+                self.flags[i_block] = MIXED;
+                self.index[i_block] = new_block as u32;
+                new_block += SMALL_DATA_BLOCK_LENGTH;
+            }
+            self.index[i] as usize
+        } else {
+            let new_block = self.alloc_data_block(SMALL_DATA_BLOCK_LENGTH);
+            let value = self.index[i];
+            self.data[new_block..new_block + SMALL_DATA_BLOCK_LENGTH].fill(value);
+            self.flags[i] = MIXED;
+            self.index[i] = new_block as u32;
+            new_block
+        }
+    }
+
+    /// Sets the value for the code points `start..=end`.
+    fn set_range(&mut self, mut start: u32, end: u32, value: u32) {
+        assert!(start <= end && end <= MAX_UNICODE);
+        self.ensure_high_start(end);
+
+        let mut limit = end + 1;
+        if start & SMALL_DATA_MASK != 0 {
+            // Set partial block at [start..following block boundary[.
+            let block = self.get_data_block((start >> SHIFT_3) as usize);
+            let next_start = (start + SMALL_DATA_MASK) & !SMALL_DATA_MASK;
+            let offset = (start & SMALL_DATA_MASK) as usize;
+            if next_start <= limit {
+                self.data[block + offset..block + SMALL_DATA_BLOCK_LENGTH].fill(value);
+                start = next_start;
+            } else {
+                let block_limit = (limit & SMALL_DATA_MASK) as usize;
+                self.data[block + offset..block + block_limit].fill(value);
+                return;
+            }
+        }
+
+        // Number of positions in the last, partial block.
+        let rest = (limit & SMALL_DATA_MASK) as usize;
+
+        // Round down limit to a block boundary.
+        limit &= !SMALL_DATA_MASK;
+
+        // Iterate over all-value blocks.
+        while start < limit {
+            let i = (start >> SHIFT_3) as usize;
+            if self.flags[i] == ALL_SAME {
+                self.index[i] = value;
+            } else {
+                let block = self.index[i] as usize;
+                self.data[block..block + SMALL_DATA_BLOCK_LENGTH].fill(value);
+            }
+            start += SMALL_DATA_BLOCK_LENGTH as u32;
+        }
+
+        if rest > 0 {
+            // Set partial block at [last block boundary..limit[.
+            let block = self.get_data_block((start >> SHIFT_3) as usize);
+            self.data[block..block + rest].fill(value);
+        }
+    }
+
+    fn mask_values(&mut self, mask: u32) {
+        self.initial_value &= mask;
+        self.error_value &= mask;
+        self.high_value &= mask;
+        let i_limit = (self.high_start >> SHIFT_3) as usize;
+        for i in 0..i_limit {
+            if self.flags[i] == ALL_SAME {
+                self.index[i] &= mask;
+            }
+        }
+        for value in &mut self.data {
+            *value &= mask;
+        }
+    }
+
+    /// Finds the start of the last range in the trie by enumerating backward.
+    /// Indexes for code points higher than this will be omitted.
+    fn find_high_start(&self) -> u32 {
+        let mut i = (self.high_start >> SHIFT_3) as usize;
+        while i > 0 {
+            i -= 1;
+            let matches = if self.flags[i] == ALL_SAME {
+                self.index[i] == self.high_value
+            } else {
+                let block = self.index[i] as usize;
+                all_values_same_as(
+                    &self.data[block..block + SMALL_DATA_BLOCK_LENGTH],
+                    self.high_value,
+                )
+            };
+            if !matches {
+                return ((i + 1) << SHIFT_3) as u32;
+            }
+        }
+        0
+    }
+
+    /// Marks data blocks that have the same value repeated over the whole block,
+    /// and deduplicates such blocks.
+    ///
+    /// Returns an upper bound for the length of the compacted data array.
+    fn compact_whole_data_blocks(
+        &mut self,
+        fast_i_limit: usize,
+        all_same_blocks: &mut AllSameBlocks,
+    ) -> usize {
+        // ASCII data will be stored as a linear table, even if the following code
+        // does not yet count it that way.
+        let mut new_data_capacity = ASCII_LIMIT;
+        // Add room for a small data null block in case it would match the start of
+        // a fast data block where dataNullOffset must not be set in that case.
+        new_data_capacity += SMALL_DATA_BLOCK_LENGTH;
+        // Add room for special values (errorValue, highValue) and padding.
+        new_data_capacity += 4;
+        let i_limit = (self.high_start >> SHIFT_3) as usize;
+        let mut block_length = FAST_DATA_BLOCK_LENGTH;
+        let mut inc = SMALL_DATA_BLOCKS_PER_BMP_BLOCK;
+        let mut i = 0;
+        while i < i_limit {
+            if i == fast_i_limit {
+                block_length = SMALL_DATA_BLOCK_LENGTH;
+                inc = 1;
+            }
+            let mut value = self.index[i];
+            if self.flags[i] == MIXED {
+                // Really mixed?
+                let block = value as usize;
+                value = self.data[block];
+                if all_values_same_as(&self.data[block + 1..block + block_length], value) {
+                    self.flags[i] = ALL_SAME;
+                    self.index[i] = value;
+                    // Fall through to ALL_SAME handling.
+                } else {
+                    new_data_capacity += block_length;
+                    i += inc;
+                    continue;
+                }
+            } else if inc > 1 {
+                // Do all of the fast-range data block's ALL_SAME parts have the same value?
+                if self.index[i + 1..i + inc].iter().any(|&v| v != value) {
+                    // Turn it into a MIXED block.
+                    self.get_data_block(i);
+                    new_data_capacity += block_length;
+                    i += inc;
+                    continue;
+                }
+            }
+            // Is there another ALL_SAME block with the same value?
+            let mut other = all_same_blocks.find_or_add(i, inc, value);
+            if other == AllSameBlock::Overflow {
+                // The fixed-size array overflowed. Slow check for a duplicate block.
+                let mut j_inc = SMALL_DATA_BLOCKS_PER_BMP_BLOCK;
+                let mut j = 0;
+                loop {
+                    if j == i {
+                        all_same_blocks.add(i, inc, value);
+                        break;
+                    }
+                    if j == fast_i_limit {
+                        j_inc = 1;
+                    }
+                    if self.flags[j] == ALL_SAME && self.index[j] == value {
+                        all_same_blocks.add(j, j_inc + inc, value);
+                        other = AllSameBlock::Found(j);
+                        break;
+                    }
+                    j += j_inc;
+                }
+            }
+            if let AllSameBlock::Found(other) = other {
+                self.flags[i] = SAME_AS;
+                self.index[i] = other as u32;
+            } else {
+                // New unique same-value block.
+                new_data_capacity += block_length;
+            }
+            i += inc;
+        }
+        new_data_capacity
+    }
+
+    /// Compacts the data blocks into `new_data`, which already contains the linear ASCII data.
+    ///
+    /// The compaction
+    /// - removes blocks that are identical with earlier ones
+    /// - overlaps each new non-duplicate block as much as possible with the previously-written one
+    /// - works with fast-range data blocks whose length is a multiple of that of
+    ///   higher-code-point data blocks
+    ///
+    /// It does not try to find an optimal order of writing, deduplicating, and overlapping blocks.
+    ///
+    /// Returns the length of the compacted data.
+    fn compact_data(
+        &mut self,
+        fast_i_limit: usize,
+        new_data: &mut [u32],
+        data_null_index: Option<usize>,
+        mixed_blocks: &mut MixedBlocks,
+    ) -> usize {
+        let mut new_data_length = 0;
+        let mut i = 0;
+        while new_data_length < ASCII_LIMIT {
+            self.index[i] = new_data_length as u32;
+            new_data_length += FAST_DATA_BLOCK_LENGTH;
+            i += SMALL_DATA_BLOCKS_PER_BMP_BLOCK;
+        }
+
+        let mut block_length = FAST_DATA_BLOCK_LENGTH;
+        mixed_blocks.init(new_data.len(), block_length);
+        mixed_blocks.extend(new_data, 0, 0, new_data_length);
+
+        let i_limit = (self.high_start >> SHIFT_3) as usize;
+        let mut inc = SMALL_DATA_BLOCKS_PER_BMP_BLOCK;
+        let mut fast_length = 0;
+        let mut i = ASCII_I_LIMIT;
+        while i < i_limit {
+            if i == fast_i_limit {
+                block_length = SMALL_DATA_BLOCK_LENGTH;
+                inc = 1;
+                fast_length = new_data_length;
+                mixed_blocks.init(new_data.len(), block_length);
+                mixed_blocks.extend(new_data, 0, 0, new_data_length);
+            }
+            match self.flags[i] {
+                ALL_SAME => {
+                    let value = self.index[i];
+                    // Find an earlier part of the data array of length block_length
+                    // that is filled with this value.
+                    let mut n = mixed_blocks.find_all_same_block(new_data, value);
+                    // If we find a match, and the current block is the data null block,
+                    // and it is not a fast block but matches the start of a fast block,
+                    // then we need to continue looking.
+                    // This is because this small block is shorter than the fast block,
+                    // and not all of the rest of the fast block is filled with this value.
+                    // Otherwise trie.getRange() would detect that the fast block starts at
+                    // dataNullOffset and assume incorrectly that it is filled with the null value.
+                    while let Some(m) = n {
+                        if Some(i) == data_null_index
+                            && i >= fast_i_limit
+                            && m < fast_length
+                            && is_start_of_some_fast_block(m as u32, &self.index, fast_i_limit)
+                        {
+                            n = find_all_same_block(
+                                new_data,
+                                m + 1,
+                                new_data_length,
+                                value,
+                                block_length,
+                            );
+                        } else {
+                            break;
+                        }
+                    }
+                    if let Some(n) = n {
+                        self.index[i] = n as u32;
+                    } else {
+                        let n =
+                            get_all_same_overlap(new_data, new_data_length, value, block_length);
+                        self.index[i] = (new_data_length - n) as u32;
+                        let prev_data_length = new_data_length;
+                        new_data_length += block_length - n;
+                        new_data[prev_data_length..new_data_length].fill(value);
+                        mixed_blocks.extend(new_data, 0, prev_data_length, new_data_length);
+                    }
+                }
+                MIXED => {
+                    let block_start = self.index[i] as usize;
+                    let block = &self.data[block_start..block_start + block_length];
+                    if let Some(n) = mixed_blocks.find_block(new_data, block, 0) {
+                        self.index[i] = n as u32;
+                    } else {
+                        let n = get_overlap(new_data, new_data_length, block, 0, block_length);
+                        self.index[i] = (new_data_length - n) as u32;
+                        let prev_data_length = new_data_length;
+                        new_data_length += block_length - n;
+                        new_data[prev_data_length..new_data_length].copy_from_slice(&block[n..]);
+                        mixed_blocks.extend(new_data, 0, prev_data_length, new_data_length);
+                    }
+                }
+                _ => {
+                    // SAME_AS
+                    let j = self.index[i] as usize;
+                    self.index[i] = self.index[j];
+                }
+            }
+            i += inc;
+        }
+        new_data_length
+    }
+
+    /// Builds the multi-stage index, if needed.
+    ///
+    /// Returns the length of the index.
+    fn compact_index(&mut self, fast_i_limit: usize, mixed_blocks: &mut MixedBlocks) -> usize {
+        let fast_index_length = fast_i_limit >> (FAST_SHIFT - SHIFT_3);
+        if (self.high_start >> FAST_SHIFT) as usize <= fast_index_length {
+            // Only the linear fast index, no multi-stage index tables.
+            self.index3_null_offset = Some(NO_INDEX3_NULL_OFFSET);
+            return fast_index_length;
+        }
+
+        // Condense the fast index table.
+        // Also, does it contain an index-3 block with all dataNullOffset?
+        let mut fast_index = [0u16; BMP_INDEX_LENGTH];
+        let mut i3_first_null = None;
+        let mut i = 0;
+        for (j, fast_index_entry) in fast_index[..fast_index_length].iter_mut().enumerate() {
+            let mut i3 = self.index[i];
+            *fast_index_entry = i3 as u16;
+            if i3 == self.data_null_offset {
+                match i3_first_null {
+                    None => i3_first_null = Some(j),
+                    Some(first)
+                        if self.index3_null_offset.is_none()
+                            && j - first + 1 == INDEX_3_BLOCK_LENGTH =>
+                    {
+                        self.index3_null_offset = Some(first as u16);
+                    }
+                    _ => {}
+                }
+            } else {
+                i3_first_null = None;
+            }
+            // Set the index entries that compact_data() skipped.
+            // Needed when the multi-stage index covers the fast index range as well.
+            let i_next = i + SMALL_DATA_BLOCKS_PER_BMP_BLOCK;
+            i += 1;
+            while i < i_next {
+                i3 += SMALL_DATA_BLOCK_LENGTH as u32;
+                self.index[i] = i3;
+                i += 1;
+            }
+        }
+
+        mixed_blocks.init(fast_index_length, INDEX_3_BLOCK_LENGTH);
+        mixed_blocks.extend(&fast_index, 0, 0, fast_index_length);
+
+        // Examine index-3 blocks. For each determine one of:
+        // - same as the index-3 null block
+        // - same as a fast-index block
+        // - 16-bit indexes
+        // - 18-bit indexes
+        // We store this in the first flags entry for the index-3 block.
+        //
+        // Also determine an upper limit for the index-3 table length.
+        let mut index3_capacity = 0;
+        let mut has_null_block = self.index3_null_offset.is_some();
+        let mut has_long_i3_blocks = false;
+        // If the fast index covers the whole BMP, then
+        // the multi-stage index is only for supplementary code points.
+        // Otherwise, the multi-stage index covers all of Unicode.
+        let i_start = if fast_i_limit < BMP_I_LIMIT {
+            0
+        } else {
+            BMP_I_LIMIT
+        };
+        let i_limit = (self.high_start >> SHIFT_3) as usize;
+        for i in (i_start..i_limit).step_by(INDEX_3_BLOCK_LENGTH) {
+            let block = &self.index[i..i + INDEX_3_BLOCK_LENGTH];
+            let ored_i3 = block.iter().fold(0, |acc, &i3| acc | i3);
+            let is_null = block.iter().all(|&i3| i3 == self.data_null_offset);
+            if is_null {
+                self.flags[i] = I3_NULL;
+                if !has_null_block {
+                    if ored_i3 <= 0xffff {
+                        index3_capacity += INDEX_3_BLOCK_LENGTH;
+                    } else {
+                        index3_capacity += INDEX_3_18BIT_BLOCK_LENGTH;
+                        has_long_i3_blocks = true;
+                    }
+                    has_null_block = true;
+                }
+            } else if ored_i3 <= 0xffff {
+                if let Some(n) = mixed_blocks.find_block(&fast_index, &self.index, i) {
+                    self.flags[i] = I3_BMP;
+                    self.index[i] = n as u32;
+                } else {
+                    self.flags[i] = I3_16;
+                    index3_capacity += INDEX_3_BLOCK_LENGTH;
+                }
+            } else {
+                self.flags[i] = I3_18;
+                index3_capacity += INDEX_3_18BIT_BLOCK_LENGTH;
+                has_long_i3_blocks = true;
+            }
+        }
+
+        let index2_capacity = (i_limit - i_start) >> SHIFT_2_3;
+
+        // Length of the index-1 table, rounded up.
+        let index1_length = (index2_capacity + INDEX_2_MASK) >> SHIFT_1_2;
+
+        // Index table: Fast index, index-1, index-3, index-2.
+        // +1 for possible index table padding.
+        let index16_capacity =
+            fast_index_length + index1_length + index3_capacity + index2_capacity + 1;
+        let mut index16 = vec![0u16; index16_capacity];
+        index16[..fast_index_length].copy_from_slice(&fast_index[..fast_index_length]);
+
+        mixed_blocks.init(index16_capacity, INDEX_3_BLOCK_LENGTH);
+        let mut long_i3_blocks = MixedBlocks::default();
+        if has_long_i3_blocks {
+            long_i3_blocks.init(index16_capacity, INDEX_3_18BIT_BLOCK_LENGTH);
+        }
+
+        // Compact the index-3 table and write an uncompacted version of the index-2 table.
+        let mut index2 = Vec::with_capacity(index2_capacity);
+        let mut has_null_block = self.index3_null_offset.is_some();
+        let index3_start = fast_index_length + index1_length;
+        let mut index_length = index3_start;
+        for i in (i_start..i_limit).step_by(INDEX_3_BLOCK_LENGTH) {
+            let mut f = self.flags[i];
+            if f == I3_NULL && !has_null_block {
+                // First index-3 null block. Write & overlap it like a normal block, then remember it.
+                f = if self.data_null_offset <= 0xffff {
+                    I3_16
+                } else {
+                    I3_18
+                };
+                has_null_block = true;
+            }
+            let i3 = match f {
+                I3_NULL => self.index3_null_offset.unwrap_or(NO_INDEX3_NULL_OFFSET) as usize,
+                I3_BMP => self.index[i] as usize,
+                I3_16 => {
+                    if let Some(n) = mixed_blocks.find_block(&index16, &self.index, i) {
+                        n
+                    } else {
+                        let mut n = if index_length == index3_start {
+                            // No overlap at the boundary between the index-1 and index-3 tables.
+                            0
+                        } else {
+                            get_overlap(
+                                &index16,
+                                index_length,
+                                &self.index,
+                                i,
+                                INDEX_3_BLOCK_LENGTH,
+                            )
+                        };
+                        let i3 = index_length - n;
+                        let prev_index_length = index_length;
+                        while n < INDEX_3_BLOCK_LENGTH {
+                            index16[index_length] = self.index[i + n] as u16;
+                            index_length += 1;
+                            n += 1;
+                        }
+                        mixed_blocks.extend(
+                            &index16,
+                            index3_start,
+                            prev_index_length,
+                            index_length,
+                        );
+                        if has_long_i3_blocks {
+                            long_i3_blocks.extend(
+                                &index16,
+                                index3_start,
+                                prev_index_length,
+                                index_length,
+                            );
+                        }
+                        i3
+                    }
+                }
+                _ => {
+                    debug_assert_eq!(f, I3_18);
+                    debug_assert!(has_long_i3_blocks);
+                    // Encode an index-3 block that contains one or more data indexes exceeding 16 bits.
+                    let mut k = index_length;
+                    for group in self.index[i..i + INDEX_3_BLOCK_LENGTH].chunks_exact(8) {
+                        let upper_bits_index = k;
+                        k += 1;
+                        let mut upper_bits = 0;
+                        for (shift, &v) in (2..=16).step_by(2).zip(group) {
+                            upper_bits |= (v & 0x30000) >> shift;
+                            index16[k] = v as u16;
+                            k += 1;
+                        }
+                        index16[upper_bits_index] = upper_bits as u16;
+                    }
+                    if let Some(n) = long_i3_blocks.find_block(&index16, &index16, index_length) {
+                        n | 0x8000
+                    } else {
+                        let mut n = if index_length == index3_start {
+                            // No overlap at the boundary between the index-1 and index-3 tables.
+                            0
+                        } else {
+                            get_overlap(
+                                &index16,
+                                index_length,
+                                &index16,
+                                index_length,
+                                INDEX_3_18BIT_BLOCK_LENGTH,
+                            )
+                        };
+                        let i3 = (index_length - n) | 0x8000;
+                        let prev_index_length = index_length;
+                        if n > 0 {
+                            let start = index_length;
+                            while n < INDEX_3_18BIT_BLOCK_LENGTH {
+                                index16[index_length] = index16[start + n];
+                                index_length += 1;
+                                n += 1;
+                            }
+                        } else {
+                            index_length += INDEX_3_18BIT_BLOCK_LENGTH;
+                        }
+                        mixed_blocks.extend(
+                            &index16,
+                            index3_start,
+                            prev_index_length,
+                            index_length,
+                        );
+                        if has_long_i3_blocks {
+                            long_i3_blocks.extend(
+                                &index16,
+                                index3_start,
+                                prev_index_length,
+                                index_length,
+                            );
+                        }
+                        i3
+                    }
+                }
+            };
+            if self.index3_null_offset.is_none() && has_null_block {
+                self.index3_null_offset = Some(i3 as u16);
+            }
+            // Set the index-2 table entry.
+            index2.push(i3 as u16);
+        }
+        debug_assert_eq!(index2.len(), index2_capacity);
+        debug_assert!(index_length <= index3_start + index3_capacity);
+
+        if self.index3_null_offset.is_none() {
+            self.index3_null_offset = Some(NO_INDEX3_NULL_OFFSET);
+        }
+        // The index-3 offsets must fit into 15 bits, and
+        // the last one must be distinguishable from the no-null-block value.
+        assert!(
+            index_length < NO_INDEX3_NULL_OFFSET as usize + INDEX_3_BLOCK_LENGTH,
+            "Index-3 table too long for a CodePointTrie"
+        );
+
+        // Compact the index-2 table and write the index-1 table.
+        const _: () = assert!(
+            INDEX_2_BLOCK_LENGTH == INDEX_3_BLOCK_LENGTH,
+            "must re-init mixed_blocks"
+        );
+        let mut block_length = INDEX_2_BLOCK_LENGTH;
+        let mut i1 = fast_index_length;
+        let mut i = 0;
+        while i < index2.len() {
+            let n = if index2.len() - i >= block_length {
+                // normal block
+                mixed_blocks.find_block(&index16, &index2, i)
+            } else {
+                // high_start is inside the last index-2 block. Shorten it.
+                block_length = index2.len() - i;
+                find_same_block(
+                    &index16,
+                    index3_start,
+                    index_length,
+                    &index2[i..i + block_length],
+                )
+            };
+            let i2 = if let Some(n) = n {
+                n
+            } else {
+                let n = if index_length == index3_start {
+                    // No overlap at the boundary between the index-1 and index-3/2 tables.
+                    0
+                } else {
+                    get_overlap(&index16, index_length, &index2, i, block_length)
+                };
+                let i2 = index_length - n;
+                let prev_index_length = index_length;
+                index_length += block_length - n;
+                index16[prev_index_length..index_length]
+                    .copy_from_slice(&index2[i + n..i + block_length]);
+                mixed_blocks.extend(&index16, index3_start, prev_index_length, index_length);
+                i2
+            };
+            // Set the index-1 table entry.
+            index16[i1] = i2 as u16;
+            i1 += 1;
+            i += block_length;
+        }
+        debug_assert_eq!(i1, index3_start);
+        debug_assert!(index_length <= index16_capacity);
+
+        self.index16 = index16;
+        index_length
+    }
+
+    /// Compacts the data and the index.
+    ///
+    /// Returns the length of the index.
+    fn compact_trie(&mut self, fast_i_limit: usize) -> usize {
+        // Find the real high_start and round it up.
+        debug_assert_eq!(self.high_start & (CP_PER_INDEX_2_ENTRY - 1), 0);
+        self.high_value = self.get(MAX_UNICODE);
+        let mut real_high_start = self.find_high_start();
+        real_high_start =
+            (real_high_start + (CP_PER_INDEX_2_ENTRY - 1)) & !(CP_PER_INDEX_2_ENTRY - 1);
+        if real_high_start == UNICODE_LIMIT {
+            self.high_value = self.initial_value;
+        }
+
+        // We always store indexes and data values for the fast range.
+        // Pin high_start to the top of that range while building.
+        let fast_limit = (fast_i_limit << SHIFT_3) as u32;
+        if real_high_start < fast_limit {
+            let i_start = (real_high_start >> SHIFT_3) as usize;
+            self.flags[i_start..fast_i_limit].fill(ALL_SAME);
+            self.index[i_start..fast_i_limit].fill(self.high_value);
+            self.high_start = fast_limit;
+        } else {
+            self.high_start = real_high_start;
+        }
+
+        let ascii_data: [u32; ASCII_LIMIT] = core::array::from_fn(|c| self.get(c as u32));
+
+        // First we look for which data blocks have the same value repeated over the whole block,
+        // deduplicate such blocks, find a good null data block (for faster enumeration),
+        // and get an upper bound for the necessary data array length.
+        let mut all_same_blocks = AllSameBlocks::default();
+        let new_data_capacity = self.compact_whole_data_blocks(fast_i_limit, &mut all_same_blocks);
+        let mut new_data = vec![0; new_data_capacity];
+        new_data[..ASCII_LIMIT].copy_from_slice(&ascii_data);
+
+        let data_null_index = all_same_blocks.find_most_used();
+
+        let mut mixed_blocks = MixedBlocks::default();
+        let new_data_length = self.compact_data(
+            fast_i_limit,
+            &mut new_data,
+            data_null_index,
+            &mut mixed_blocks,
+        );
+        new_data.truncate(new_data_length);
+        self.data = new_data;
+        // The offset of the last data block must fit into the index table.
+        assert!(
+            self.data.len() <= 0x3ffff + SMALL_DATA_BLOCK_LENGTH,
+            "Data table too long for a CodePointTrie"
+        );
+
+        if let Some(data_null_index) = data_null_index {
+            self.data_null_offset = self.index[data_null_index];
+            self.initial_value = self.data[self.data_null_offset as usize];
+        } else {
+            self.data_null_offset = NO_DATA_NULL_OFFSET;
+        }
+
+        let index_length = self.compact_index(fast_i_limit, &mut mixed_blocks);
+        self.high_start = real_high_start;
+        index_length
+    }
+
+    /// Builds the header, index and data of the immutable trie.
+    ///
+    /// The data values are masked to the value width but always returned as `u32`.
+    fn build(
+        mut self,
+        trie_type: TrieType,
+        value_width: ValueWidth,
+    ) -> (CodePointTrieHeader, Vec<u16>, Vec<u32>) {
+        // The mutable trie always stores 32-bit values.
+        // When we build a trie for a smaller value width, we first mask off unused bits
+        // before compacting the data.
+        match value_width {
+            ValueWidth::Bits32 => {}
+            ValueWidth::Bits16 => self.mask_values(0xffff),
+            ValueWidth::Bits8 => self.mask_values(0xff),
+        }
+
+        let fast_limit = match trie_type {
+            TrieType::Fast => 0x10000,
+            TrieType::Small => SMALL_LIMIT,
+        };
+        let index_length = self.compact_trie((fast_limit >> SHIFT_3) as usize);
+
+        let mut index: Vec<u16> = if self.high_start <= fast_limit {
+            // Condense only the fast index from the mutable-trie index.
+            self.index
+                .iter()
+                .step_by(SMALL_DATA_BLOCKS_PER_BMP_BLOCK)
+                .take(index_length)
+                .map(|&i| i as u16)
+                .collect()
+        } else {
+            self.index16.truncate(index_length);
+            self.index16
+        };
+
+        // Ensure data table alignment: The index length must be even for 32-bit data.
+        if value_width == ValueWidth::Bits32 && index.len() & 1 != 0 {
+            index.push(0xffee); // arbitrary value
+        }
+
+        // Make the total trie structure length a multiple of 4 bytes by padding the data table,
+        // and store special values as the last two data values.
+        let data = &mut self.data;
+        let (high_value, error_value) = (self.high_value, self.error_value);
+        let ends_with_special_values = |data: &[u32]| data.ends_with(&[high_value, error_value]);
+        match value_width {
+            ValueWidth::Bits16 => {
+                if (index.len() ^ data.len()) & 1 != 0 {
+                    // padding
+                    data.push(error_value);
+                }
+                if !ends_with_special_values(data) {
+                    data.extend([high_value, error_value]);
+                }
+            }
+            ValueWidth::Bits32 => {
+                // 32-bit data words never need padding to a multiple of 4 bytes.
+                if !ends_with_special_values(data) {
+                    if data.last() != Some(&high_value) {
+                        data.push(high_value);
+                    }
+                    data.push(error_value);
+                }
+            }
+            ValueWidth::Bits8 => {
+                let mut and3 = (index.len() * 2 + data.len()) & 3;
+                if and3 == 0 && ends_with_special_values(data) {
+                    // all set
+                } else if and3 == 3 && data.last() == Some(&high_value) {
+                    data.push(error_value);
+                } else {
+                    while and3 != 2 {
+                        data.push(high_value);
+                        and3 = (and3 + 1) & 3;
+                    }
+                    data.extend([high_value, error_value]);
+                }
+            }
+        }
+
+        let header = CodePointTrieHeader {
+            high_start: self.high_start,
+            // Round up shifted12_high_start to a multiple of 0x1000 for easy testing from UTF-8 lead bytes.
+            // Runtime code needs to then test for the real high_start as well.
+            shifted12_high_start: ((self.high_start + 0xfff) >> 12) as u16,
+            index3_null_offset: self.index3_null_offset.unwrap_or(NO_INDEX3_NULL_OFFSET),
+            data_null_offset: self.data_null_offset,
+            null_value: self.initial_value,
+            trie_type,
+        };
+        (header, index, self.data)
+    }
+}
+
+fn equal_blocks<A, B>(s: &[A], t: &[B]) -> bool
+where
+    A: Copy + Into<u32>,
+    B: Copy + Into<u32>,
+{
+    s.len() == t.len() && s.iter().zip(t).all(|(&a, &b)| a.into() == b.into())
+}
+
+fn all_values_same_as(p: &[u32], value: u32) -> bool {
+    p.iter().all(|&v| v == value)
+}
+
+/// Searches `p[start..length]` for a block identical to `q`.
+fn find_same_block(p: &[u16], start: usize, length: usize, q: &[u16]) -> Option<usize> {
+    // Ensure that we do not even partially get past length.
+    let last = length.checked_sub(q.len())?;
+    (start..=last).find(|&block| p[block..block + q.len()] == *q)
+}
+
+/// Searches `p[start..limit]` for a block of `block_length` values equal to `value`.
+fn find_all_same_block(
+    p: &[u32],
+    start: usize,
+    limit: usize,
+    value: u32,
+    block_length: usize,
+) -> Option<usize> {
+    // Ensure that we do not even partially get past limit.
+    let last = limit.checked_sub(block_length)?;
+    let mut block = start;
+    while block <= last {
+        if p[block] == value {
+            let mut i = 1;
+            loop {
+                if i == block_length {
+                    return Some(block);
+                }
+                if p[block + i] != value {
+                    block += i;
+                    break;
+                }
+                i += 1;
+            }
+        }
+        block += 1;
+    }
+    None
+}
+
+/// Looks for maximum overlap of the beginning of the block at `q[q_start..]`
+/// with the end of `p[..length]`.
+fn get_overlap<A, B>(p: &[A], length: usize, q: &[B], q_start: usize, block_length: usize) -> usize
+where
+    A: Copy + Into<u32>,
+    B: Copy + Into<u32>,
+{
+    let mut overlap = block_length - 1;
+    debug_assert!(overlap <= length);
+    while overlap > 0 && !equal_blocks(&p[length - overlap..length], &q[q_start..q_start + overlap])
+    {
+        overlap -= 1;
+    }
+    overlap
+}
+
+/// Returns how many values equal to `value` end `p[..length]`, up to `block_length - 1`.
+fn get_all_same_overlap(p: &[u32], length: usize, value: u32, block_length: usize) -> usize {
+    let min = length - (block_length - 1);
+    let mut i = length;
+    while min < i && p[i - 1] == value {
+        i -= 1;
+    }
+    length - i
+}
+
+fn is_start_of_some_fast_block(data_offset: u32, index: &[u32], fast_i_limit: usize) -> bool {
+    index[..fast_i_limit]
+        .iter()
+        .step_by(SMALL_DATA_BLOCKS_PER_BMP_BLOCK)
+        .any(|&i| i == data_offset)
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum AllSameBlock {
+    Found(usize),
+    NewUnique,
+    Overflow,
+}
+
+/// A small cache of [`ALL_SAME`] data blocks, with reference counts to
+/// find the best data null block.
+#[derive(Default)]
+struct AllSameBlocks {
+    length: usize,
+    most_recent: Option<usize>,
+    indexes: [usize; Self::CAPACITY],
+    values: [u32; Self::CAPACITY],
+    ref_counts: [usize; Self::CAPACITY],
+}
+
+impl AllSameBlocks {
+    const CAPACITY: usize = 32;
+
+    fn find_or_add(&mut self, index: usize, count: usize, value: u32) -> AllSameBlock {
+        if let Some(most_recent) = self.most_recent {
+            if self.values[most_recent] == value {
+                self.ref_counts[most_recent] += count;
+                return AllSameBlock::Found(self.indexes[most_recent]);
+            }
+        }
+        if let Some(i) = self.values[..self.length].iter().position(|&v| v == value) {
+            self.most_recent = Some(i);
+            self.ref_counts[i] += count;
+            return AllSameBlock::Found(self.indexes[i]);
+        }
+        if self.length == Self::CAPACITY {
+            return AllSameBlock::Overflow;
+        }
+        self.most_recent = Some(self.length);
+        self.indexes[self.length] = index;
+        self.values[self.length] = value;
+        self.ref_counts[self.length] = count;
+        self.length += 1;
+        AllSameBlock::NewUnique
+    }
+
+    /// Replaces the block which has the lowest reference count.
+    fn add(&mut self, index: usize, count: usize, value: u32) {
+        debug_assert_eq!(self.length, Self::CAPACITY);
+        let mut least = 0;
+        let mut least_count = I_LIMIT;
+        for (i, &ref_count) in self.ref_counts.iter().enumerate() {
+            debug_assert_ne!(self.values[i], value);
+            if ref_count < least_count {
+                least = i;
+                least_count = ref_count;
+            }
+        }
+        self.most_recent = Some(least);
+        self.indexes[least] = index;
+        self.values[least] = value;
+        self.ref_counts[least] = count;
+    }
+
+    fn find_most_used(&self) -> Option<usize> {
+        let mut max = None;
+        let mut max_count = 0;
+        for i in 0..self.length {
+            if self.ref_counts[i] > max_count {
+                max = Some(i);
+                max_count = self.ref_counts[i];
+            }
+        }
+        max.map(|i| self.indexes[i])
+    }
+}
+
+/// Hash table for mixed-value blocks to be found anywhere in the
+/// compacted data or index so far.
+#[derive(Default)]
+struct MixedBlocks {
+    /// The length is a prime number, larger than the maximum data length.
+    /// The `shift` lower bits store a data index + 1.
+    /// The remaining upper bits store a partial hash code of the block data values.
+    table: Vec<u32>,
+    shift: u32,
+    mask: u32,
+    block_length: usize,
+}
+
+impl MixedBlocks {
+    fn init(&mut self, max_length: usize, new_block_length: usize) {
+        // We store actual data indexes + 1 to reserve 0 for empty entries.
+        let max_data_index = (max_length + 1).saturating_sub(new_block_length);
+        let new_length;
+        if max_data_index <= 0xfff {
+            // 4k
+            new_length = 6007;
+            self.shift = 12;
+            self.mask = 0xfff;
+        } else if max_data_index <= 0x7fff {
+            // 32k
+            new_length = 50021;
+            self.shift = 15;
+            self.mask = 0x7fff;
+        } else if max_data_index <= 0x1ffff {
+            // 128k
+            new_length = 200003;
+            self.shift = 17;
+            self.mask = 0x1ffff;
+        } else {
+            // max_data_index up to around 1.1M
+            new_length = 1500007;
+            self.shift = 21;
+            self.mask = 0x1fffff;
+        }
+        self.table.clear();
+        self.table.resize(new_length, 0);
+        self.block_length = new_block_length;
+    }
+
+    fn extend<U: Copy + Into<u32>>(
+        &mut self,
+        data: &[U],
+        min_start: usize,
+        prev_data_length: usize,
+        new_data_length: usize,
+    ) {
+        let mut start = match prev_data_length.checked_sub(self.block_length) {
+            // Skip the last block that we added last time.
+            Some(start) if start >= min_start => start + 1,
+            // Begin with the first full block.
+            _ => min_start,
+        };
+        while start + self.block_length <= new_data_length {
+            let hash_code = self.make_hash_code(data, start);
+            if let Err(entry_index) = self.find_entry(data, data, start, hash_code) {
+                self.table[entry_index] = (hash_code << self.shift) | (start as u32 + 1);
+            }
+            start += 1;
+        }
+    }
+
+    fn find_block<A, B>(&self, data: &[A], block_data: &[B], block_start: usize) -> Option<usize>
+    where
+        A: Copy + Into<u32>,
+        B: Copy + Into<u32>,
+    {
+        let hash_code = self.make_hash_code(block_data, block_start);
+        let entry_index = self
+            .find_entry(data, block_data, block_start, hash_code)
+            .ok()?;
+        Some(((self.table[entry_index] & self.mask) - 1) as usize)
+    }
+
+    fn find_all_same_block(&self, data: &[u32], block_value: u32) -> Option<usize> {
+        let hash_code = (1..self.block_length).fold(block_value, |hash_code, _| {
+            hash_code.wrapping_mul(37).wrapping_add(block_value)
+        });
+        let entry_index = self
+            .probe(hash_code, |block| {
+                all_values_same_as(&data[block], block_value)
+            })
+            .ok()?;
+        Some(((self.table[entry_index] & self.mask) - 1) as usize)
+    }
+
+    fn make_hash_code<U: Copy + Into<u32>>(&self, block_data: &[U], block_start: usize) -> u32 {
+        let block = &block_data[block_start..block_start + self.block_length];
+        block.iter().skip(1).fold(block[0].into(), |hash_code, &v| {
+            hash_code.wrapping_mul(37).wrapping_add(v.into())
+        })
+    }
+
+    fn find_entry<A, B>(
+        &self,
+        data: &[A],
+        block_data: &[B],
+        block_start: usize,
+        hash_code: u32,
+    ) -> Result<usize, usize>
+    where
+        A: Copy + Into<u32>,
+        B: Copy + Into<u32>,
+    {
+        let block = &block_data[block_start..block_start + self.block_length];
+        self.probe(hash_code, |candidate| equal_blocks(&data[candidate], block))
+    }
+
+    /// Returns the index of the entry whose block matches, or
+    /// the index of the empty entry where such a block would be inserted.
+    fn probe(
+        &self,
+        hash_code: u32,
+        matches: impl Fn(core::ops::Range<usize>) -> bool,
+    ) -> Result<usize, usize> {
+        let length = self.table.len();
+        let shifted_hash_code = hash_code << self.shift;
+        let initial_entry_index = (hash_code as usize % (length - 1)) + 1; // 1..length-1
+        let mut entry_index = initial_entry_index;
+        loop {
+            let entry = self.table[entry_index];
+            if entry == 0 {
+                return Err(entry_index);
+            }
+            if (entry & !self.mask) == shifted_hash_code {
+                let data_index = ((entry & self.mask) - 1) as usize;
+                if matches(data_index..data_index + self.block_length) {
+                    return Ok(entry_index);
+                }
+            }
+            entry_index = (entry_index + initial_entry_index) % length;
+        }
+    }
+}
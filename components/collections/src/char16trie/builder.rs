@@ -0,0 +1,535 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A builder for [`Char16Trie`].
+//!
+//! This is a port of ICU4C's `StringTrieBuilder` and `UCharsTrieBuilder` using the
+//! `USTRINGTRIE_BUILD_SMALL` option: identical sub-tries are shared, and the output
+//! is the same as ICU4C's for the same input.
+
+use super::trie::{
+    Char16Trie, MAX_BRANCH_LINEAR_SUB_NODE_LENGTH, MAX_LINEAR_MATCH_LENGTH, MAX_ONE_UNIT_DELTA,
+    MAX_ONE_UNIT_NODE_VALUE, MAX_ONE_UNIT_VALUE, MIN_LINEAR_MATCH, MIN_TWO_UNIT_DELTA_LEAD,
+    MIN_TWO_UNIT_NODE_VALUE_LEAD, MIN_TWO_UNIT_VALUE_LEAD, THREE_UNIT_DELTA_LEAD,
+    THREE_UNIT_NODE_VALUE_LEAD, THREE_UNIT_VALUE_LEAD, VALUE_IS_FINAL,
+};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use displaydoc::Display;
+use zerovec::ZeroVec;
+
+const MAX_TWO_UNIT_VALUE: i32 =
+    (((THREE_UNIT_VALUE_LEAD - MIN_TWO_UNIT_VALUE_LEAD) as i32) << 16) - 1; // 0x3ffeffff
+const MAX_TWO_UNIT_NODE_VALUE: i32 =
+    (((THREE_UNIT_NODE_VALUE_LEAD - MIN_TWO_UNIT_NODE_VALUE_LEAD) as i32) << 10) - 1; // 0xfdffff
+const MAX_TWO_UNIT_DELTA: i32 =
+    (((THREE_UNIT_DELTA_LEAD - MIN_TWO_UNIT_DELTA_LEAD) as i32) << 16) - 1; // 0x3feffff
+
+/// An error returned when building a [`Char16Trie`].
+#[derive(Copy, Clone, Display, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Char16TrieBuildError {
+    /// [`Char16Trie`] must be built from at least one string
+    #[displaydoc("Char16Trie must be built from at least one string")]
+    Empty,
+    /// The strings must be sorted and must not contain duplicates
+    #[displaydoc("Strings must be sorted and unique, but the one at index {index} is not")]
+    NotSorted {
+        /// The index of the first string that is not greater than its predecessor.
+        index: usize,
+    },
+}
+
+impl core::error::Error for Char16TrieBuildError {}
+
+impl Char16Trie<'static> {
+    /// Builds a [`Char16Trie`] mapping each string to its value.
+    ///
+    /// The strings must be sorted and unique. The trie is built in the same way as
+    /// ICU4C's `UCharsTrieBuilder` with `USTRINGTRIE_BUILD_SMALL`.
+    ///
+    /// ✨ *Enabled with the `alloc` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::collections::char16trie::{Char16Trie, Char16TrieBuildError};
+    ///
+    /// let trie = Char16Trie::try_from_sorted_pairs([("a", 1), ("ab", 100)])
+    ///     .expect("strings are sorted");
+    /// assert_eq!(trie.data.to_vec(), [48, 97, 176, 98, 32868]);
+    /// assert_eq!(trie.get("ab"), Some(100));
+    /// assert_eq!(trie.get("b"), None);
+    ///
+    /// assert_eq!(
+    ///     Char16Trie::try_from_sorted_pairs([("b", 1), ("a", 2)]),
+    ///     Err(Char16TrieBuildError::NotSorted { index: 1 })
+    /// );
+    /// ```
+    pub fn try_from_sorted_pairs<S: AsRef<str>>(
+        pairs: impl IntoIterator<Item = (S, i32)>,
+    ) -> Result<Self, Char16TrieBuildError> {
+        let mut elements = Vec::new();
+        let mut previous: Option<S> = None;
+        for (index, (string, value)) in pairs.into_iter().enumerate() {
+            if previous
+                .as_ref()
+                .is_some_and(|previous| previous.as_ref() >= string.as_ref())
+            {
+                return Err(Char16TrieBuildError::NotSorted { index });
+            }
+            elements.push((string.as_ref().encode_utf16().collect::<Vec<_>>(), value));
+            previous = Some(string);
+        }
+        if elements.is_empty() {
+            return Err(Char16TrieBuildError::Empty);
+        }
+        // Code point order differs from code unit order for supplementary code points.
+        elements.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+        let mut builder = Builder {
+            elements: &elements,
+            nodes: Vec::new(),
+            registry: BTreeMap::new(),
+            units: Vec::new(),
+        };
+        let root = builder.make_node(0, elements.len(), 0);
+        builder.mark_right_edges_first(root, -1);
+        builder.write(root);
+        builder.units.reverse();
+        Ok(Self::new(ZeroVec::alloc_from_slice(&builder.units)))
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Edge {
+    /// The string ends after the branch unit, with this value.
+    Final(i32),
+    /// The strings continue with this node.
+    Node(usize),
+}
+
+/// The structure of a node, with sub-nodes referenced by their index.
+///
+/// Nodes are interned, so equal sub-tries are written only once.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum NodeKind {
+    FinalValue(i32),
+    LinearMatch {
+        units: Vec<u16>,
+        value: Option<i32>,
+        next: usize,
+    },
+    ListBranch(Vec<(u16, Edge)>),
+    SplitBranch {
+        unit: u16,
+        less_than: usize,
+        greater_or_equal: usize,
+    },
+    BranchHead {
+        length: usize,
+        value: Option<i32>,
+        next: usize,
+    },
+}
+
+struct Node {
+    kind: NodeKind,
+    /// 0 before marking, a negative edge number before writing,
+    /// and the written length of the trie when the node starts after writing.
+    offset: i32,
+    /// For branch nodes, the edge number of their right edge.
+    first_edge_number: i32,
+}
+
+struct Builder<'a> {
+    /// The sorted strings as UTF-16, and their values.
+    elements: &'a [(Vec<u16>, i32)],
+    nodes: Vec<Node>,
+    registry: BTreeMap<NodeKind, usize>,
+    /// The units written so far, in reverse order.
+    units: Vec<u16>,
+}
+
+impl Builder<'_> {
+    #[expect(clippy::indexing_slicing)] // element indices are in range by construction
+    fn string_length(&self, i: usize) -> usize {
+        self.elements[i].0.len()
+    }
+
+    #[expect(clippy::indexing_slicing)] // only called on strings longer than unit_index
+    fn unit(&self, i: usize, unit_index: usize) -> u16 {
+        self.elements[i].0[unit_index]
+    }
+
+    #[expect(clippy::indexing_slicing)] // only called on strings longer than the range
+    fn units_of(&self, i: usize, unit_index: usize, length: usize) -> Vec<u16> {
+        self.elements[i].0[unit_index..unit_index + length].to_vec()
+    }
+
+    #[expect(clippy::indexing_slicing)] // element indices are in range by construction
+    fn value(&self, i: usize) -> i32 {
+        self.elements[i].1
+    }
+
+    #[expect(clippy::indexing_slicing)] // node indices come from `register`
+    fn node(&mut self, i: usize) -> &mut Node {
+        &mut self.nodes[i]
+    }
+
+    fn register(&mut self, kind: NodeKind) -> usize {
+        if let Some(&i) = self.registry.get(&kind) {
+            return i;
+        }
+        let i = self.nodes.len();
+        self.nodes.push(Node {
+            kind: kind.clone(),
+            offset: 0,
+            first_edge_number: 0,
+        });
+        self.registry.insert(kind, i);
+        i
+    }
+
+    /// Returns the index after the last unit that `first` and `last` share,
+    /// starting after `unit_index`.
+    fn limit_of_linear_match(&self, first: usize, last: usize, unit_index: usize) -> usize {
+        let min_string_length = self.string_length(first);
+        let mut unit_index = unit_index + 1;
+        while unit_index < min_string_length
+            && self.unit(first, unit_index) == self.unit(last, unit_index)
+        {
+            unit_index += 1;
+        }
+        unit_index
+    }
+
+    /// Returns the number of different units at `unit_index`.
+    fn count_element_units(&self, start: usize, limit: usize, unit_index: usize) -> usize {
+        let mut length = 0;
+        let mut i = start;
+        while i < limit {
+            let unit = self.unit(i, unit_index);
+            i += 1;
+            while i < limit && unit == self.unit(i, unit_index) {
+                i += 1;
+            }
+            length += 1;
+        }
+        length
+    }
+
+    /// Returns the start of the elements after the first `count` different units at `unit_index`.
+    fn skip_elements_by_some_units(&self, i: usize, unit_index: usize, count: usize) -> usize {
+        let mut i = i;
+        for _ in 0..count {
+            let unit = self.unit(i, unit_index);
+            i = self.index_of_element_with_next_unit(i + 1, unit_index, unit);
+        }
+        i
+    }
+
+    fn index_of_element_with_next_unit(&self, i: usize, unit_index: usize, unit: u16) -> usize {
+        let mut i = i;
+        while unit == self.unit(i, unit_index) {
+            i += 1;
+        }
+        i
+    }
+
+    /// Builds the node for the elements in `start..limit`, which all share
+    /// their first `unit_index` units.
+    fn make_node(&mut self, start: usize, limit: usize, unit_index: usize) -> usize {
+        let mut start = start;
+        let mut value = None;
+        if unit_index == self.string_length(start) {
+            // An intermediate or final value.
+            let v = self.value(start);
+            start += 1;
+            if start == limit {
+                return self.register(NodeKind::FinalValue(v));
+            }
+            value = Some(v);
+        }
+        // Now all strings in start..limit are longer than unit_index.
+        let kind = if self.unit(start, unit_index) == self.unit(limit - 1, unit_index) {
+            // Linear-match node: all strings have the same unit at unit_index.
+            let mut last_unit_index = self.limit_of_linear_match(start, limit - 1, unit_index);
+            let mut next = self.make_node(start, limit, last_unit_index);
+            // Break the linear-match sequence into chunks of at most MAX_LINEAR_MATCH_LENGTH.
+            let max_length = MAX_LINEAR_MATCH_LENGTH as usize;
+            let mut length = last_unit_index - unit_index;
+            while length > max_length {
+                last_unit_index -= max_length;
+                length -= max_length;
+                let units = self.units_of(start, last_unit_index, max_length);
+                next = self.register(NodeKind::LinearMatch {
+                    units,
+                    value: None,
+                    next,
+                });
+            }
+            NodeKind::LinearMatch {
+                units: self.units_of(start, unit_index, length),
+                value,
+                next,
+            }
+        } else {
+            // Branch node; length >= 2 because the first and last units differ.
+            let length = self.count_element_units(start, limit, unit_index);
+            let next = self.make_branch_sub_node(start, limit, unit_index, length);
+            NodeKind::BranchHead {
+                length,
+                value,
+                next,
+            }
+        };
+        self.register(kind)
+    }
+
+    /// Builds the branch sub-node for the `length` different units at `unit_index`
+    /// of the elements in `start..limit`.
+    fn make_branch_sub_node(
+        &mut self,
+        start: usize,
+        limit: usize,
+        unit_index: usize,
+        length: usize,
+    ) -> usize {
+        let mut start = start;
+        let mut length = length;
+        let mut splits = Vec::new();
+        while length > MAX_BRANCH_LINEAR_SUB_NODE_LENGTH {
+            // Branch on the middle unit, with the less-than branch first.
+            let i = self.skip_elements_by_some_units(start, unit_index, length / 2);
+            let middle_unit = self.unit(i, unit_index);
+            let less_than = self.make_branch_sub_node(start, i, unit_index, length / 2);
+            splits.push((middle_unit, less_than));
+            // Continue for the greater-or-equal branch.
+            start = i;
+            length -= length / 2;
+        }
+        // For each unit, find its elements and whether it has a final value.
+        let mut list = Vec::with_capacity(length);
+        for unit_number in 0..length {
+            let unit = self.unit(start, unit_index);
+            let i = if unit_number + 1 == length {
+                limit
+            } else {
+                self.index_of_element_with_next_unit(start + 1, unit_index, unit)
+            };
+            let edge = if start == i - 1 && unit_index + 1 == self.string_length(start) {
+                Edge::Final(self.value(start))
+            } else {
+                Edge::Node(self.make_node(start, i, unit_index + 1))
+            };
+            list.push((unit, edge));
+            start = i;
+        }
+        let mut node = self.register(NodeKind::ListBranch(list));
+        while let Some((unit, less_than)) = splits.pop() {
+            node = self.register(NodeKind::SplitBranch {
+                unit,
+                less_than,
+                greater_or_equal: node,
+            });
+        }
+        node
+    }
+
+    /// Assigns negative edge numbers to the nodes on the right edges of branches,
+    /// which are written directly after their branch rather than jumped to.
+    fn mark_right_edges_first(&mut self, node: usize, edge_number: i32) -> i32 {
+        if self.node(node).offset != 0 {
+            return edge_number;
+        }
+        let mut edge_number = edge_number;
+        match self.node(node).kind.clone() {
+            NodeKind::FinalValue(_) => {}
+            NodeKind::LinearMatch { next, .. } | NodeKind::BranchHead { next, .. } => {
+                edge_number = self.mark_right_edges_first(next, edge_number);
+            }
+            NodeKind::ListBranch(list) => {
+                self.node(node).first_edge_number = edge_number;
+                let mut step = 0;
+                for (_, edge) in list.iter().rev() {
+                    if let Edge::Node(edge) = *edge {
+                        edge_number = self.mark_right_edges_first(edge, edge_number - step);
+                    }
+                    // For all but the rightmost edge, decrement the edge number.
+                    step = 1;
+                }
+            }
+            NodeKind::SplitBranch {
+                less_than,
+                greater_or_equal,
+                ..
+            } => {
+                self.node(node).first_edge_number = edge_number;
+                edge_number = self.mark_right_edges_first(greater_or_equal, edge_number);
+                edge_number = self.mark_right_edges_first(less_than, edge_number - 1);
+            }
+        }
+        self.node(node).offset = edge_number;
+        edge_number
+    }
+
+    fn write_unless_inside_right_edge(&mut self, node: usize, first_right: i32, last_right: i32) {
+        // Edge numbers are negative, last_right <= first_right.
+        // If the offset is positive, this node has been written already.
+        // If this node is part of the unwritten right branch edge,
+        // then we wait until that is written.
+        let offset = self.node(node).offset;
+        if offset < 0 && (offset < last_right || first_right < offset) {
+            self.write(node);
+        }
+    }
+
+    fn write(&mut self, node: usize) {
+        let offset = match self.node(node).kind.clone() {
+            NodeKind::FinalValue(value) => self.write_value_and_final(value, true),
+            NodeKind::LinearMatch { units, value, next } => {
+                self.write(next);
+                self.write_units(&units);
+                self.write_value_and_type(value, MIN_LINEAR_MATCH + units.len() as u16 - 1)
+            }
+            NodeKind::BranchHead {
+                length,
+                value,
+                next,
+            } => {
+                self.write(next);
+                if length <= MIN_LINEAR_MATCH as usize {
+                    self.write_value_and_type(value, length as u16 - 1)
+                } else {
+                    self.write_unit(length as u16 - 1);
+                    self.write_value_and_type(value, 0)
+                }
+            }
+            NodeKind::ListBranch(list) => {
+                let first_edge_number = self.node(node).first_edge_number;
+                let Some(((last_unit, right_edge), rest)) = list.split_last() else {
+                    debug_assert!(false, "branches have at least two units");
+                    return;
+                };
+                let right_edge_number = match *right_edge {
+                    Edge::Final(_) => first_edge_number,
+                    Edge::Node(right_edge) => self.node(right_edge).offset,
+                };
+                // Write the sub-nodes in reverse order: the jump deltas are relative to
+                // their own positions, so the minimum unit's sub-node is written last
+                // for a shorter delta.
+                for (_, edge) in rest.iter().rev() {
+                    if let Edge::Node(edge) = *edge {
+                        self.write_unless_inside_right_edge(
+                            edge,
+                            first_edge_number,
+                            right_edge_number,
+                        );
+                    }
+                }
+                // The maximum unit's sub-node is written last because we do not jump to it.
+                match *right_edge {
+                    Edge::Final(value) => {
+                        self.write_value_and_final(value, true);
+                    }
+                    Edge::Node(right_edge) => self.write(right_edge),
+                }
+                let mut offset = self.write_unit(*last_unit);
+                for &(unit, ref edge) in rest.iter().rev() {
+                    match *edge {
+                        Edge::Final(value) => self.write_value_and_final(value, true),
+                        Edge::Node(edge) => {
+                            let delta = offset - self.node(edge).offset;
+                            self.write_value_and_final(delta, false)
+                        }
+                    };
+                    offset = self.write_unit(unit);
+                }
+                offset
+            }
+            NodeKind::SplitBranch {
+                unit,
+                less_than,
+                greater_or_equal,
+            } => {
+                let first_edge_number = self.node(node).first_edge_number;
+                let greater_or_equal_offset = self.node(greater_or_equal).offset;
+                self.write_unless_inside_right_edge(
+                    less_than,
+                    first_edge_number,
+                    greater_or_equal_offset,
+                );
+                // The greater-or-equal branch is written last because we do not jump to it.
+                self.write(greater_or_equal);
+                let less_than_offset = self.node(less_than).offset;
+                self.write_delta_to(less_than_offset);
+                self.write_unit(unit)
+            }
+        };
+        self.node(node).offset = offset;
+    }
+
+    /// Prepends a unit, returning the new length.
+    fn write_unit(&mut self, unit: u16) -> i32 {
+        self.units.push(unit);
+        self.units.len() as i32
+    }
+
+    /// Prepends some units, returning the new length.
+    fn write_units(&mut self, units: &[u16]) -> i32 {
+        self.units.extend(units.iter().rev());
+        self.units.len() as i32
+    }
+
+    fn write_value_and_final(&mut self, value: i32, is_final: bool) -> i32 {
+        let final_bit = if is_final { VALUE_IS_FINAL } else { 0 };
+        if (0..=MAX_ONE_UNIT_VALUE as i32).contains(&value) {
+            self.write_unit(value as u16 | final_bit)
+        } else if !(0..=MAX_TWO_UNIT_VALUE).contains(&value) {
+            self.write_units(&[
+                THREE_UNIT_VALUE_LEAD | final_bit,
+                (value as u32 >> 16) as u16,
+                value as u16,
+            ])
+        } else {
+            self.write_units(&[
+                (MIN_TWO_UNIT_VALUE_LEAD + (value >> 16) as u16) | final_bit,
+                value as u16,
+            ])
+        }
+    }
+
+    fn write_value_and_type(&mut self, value: Option<i32>, node: u16) -> i32 {
+        let Some(value) = value else {
+            return self.write_unit(node);
+        };
+        if !(0..=MAX_TWO_UNIT_NODE_VALUE).contains(&value) {
+            self.write_units(&[
+                THREE_UNIT_NODE_VALUE_LEAD | node,
+                (value as u32 >> 16) as u16,
+                value as u16,
+            ])
+        } else if value <= MAX_ONE_UNIT_NODE_VALUE as i32 {
+            self.write_unit((((value + 1) << 6) as u16) | node)
+        } else {
+            self.write_units(&[
+                (MIN_TWO_UNIT_NODE_VALUE_LEAD + ((value >> 10) & 0x7fc0) as u16) | node,
+                value as u16,
+            ])
+        }
+    }
+
+    fn write_delta_to(&mut self, jump_target: i32) -> i32 {
+        let delta = self.units.len() as i32 - jump_target;
+        debug_assert!(delta >= 0);
+        if delta <= MAX_ONE_UNIT_DELTA as i32 {
+            self.write_unit(delta as u16)
+        } else if delta <= MAX_TWO_UNIT_DELTA {
+            self.write_units(&[MIN_TWO_UNIT_DELTA_LEAD + (delta >> 16) as u16, delta as u16])
+        } else {
+            self.write_units(&[THREE_UNIT_DELTA_LEAD, (delta >> 16) as u16, delta as u16])
+        }
+    }
+}
@@ -12,6 +12,8 @@
 //! ## Architecture
 //!
 //! ICU4X [`Char16Trie`] is designed to provide a read-only view of `UCharsTrie` data that is exported from ICU4C.
+//! With the `alloc` Cargo feature, [`Char16Trie::try_from_sorted_pairs`] builds the same data from
+//! `(string, value)` pairs, for example for custom dictionaries.
 //!
 //! ## Examples
 //!
@@ -35,10 +37,29 @@
 //! assert_eq!(res, TrieResult::NoMatch);
 //! ```
 //!
+//!
+//! ### Building a `Char16Trie`
+//!
+//! ```rust
+//! use icu::collections::char16trie::Char16Trie;
+//!
+//! let trie = Char16Trie::try_from_sorted_pairs([("a", 1), ("ab", 100)])
+//!     .expect("strings are sorted");
+//! assert_eq!(trie.data.to_vec(), [48, 97, 176, 98, 32868]);
+//!
+//! assert_eq!(trie.get("ab"), Some(100));
+//! assert_eq!(trie.get_longest_prefix("abc"), Some((2, 100)));
+//! ```
+//!
 //! [`ICU4X`]: ../icu/index.html
 
+#[cfg(feature = "alloc")]
+mod builder;
 mod trie;
 
+#[cfg(feature = "alloc")]
+pub use builder::Char16TrieBuildError;
+
 pub use trie::Char16Trie;
 pub use trie::Char16TrieIterator;
 pub use trie::TrieResult;
@@ -12,11 +12,11 @@ use zerovec::{ZeroSlice, ZeroVec};
 
 // For a branch sub-node with at most this many entries, we drop down
 // to a linear search.
-const MAX_BRANCH_LINEAR_SUB_NODE_LENGTH: usize = 5;
+pub(super) const MAX_BRANCH_LINEAR_SUB_NODE_LENGTH: usize = 5;
 
 // 0030..003f: Linear-match node, match 1..16 units and continue reading the next node.
-const MIN_LINEAR_MATCH: u16 = 0x30;
-const MAX_LINEAR_MATCH_LENGTH: u16 = 0x10;
+pub(super) const MIN_LINEAR_MATCH: u16 = 0x30;
+pub(super) const MAX_LINEAR_MATCH_LENGTH: u16 = 0x10;
 
 // Match-node lead unit bits 14..6 for the optional intermediate value.
 // If these bits are 0, then there is no intermediate value.
@@ -25,25 +25,26 @@ const MIN_VALUE_LEAD: u16 = MIN_LINEAR_MATCH + MAX_LINEAR_MATCH_LENGTH; // 0x40
 const NODE_TYPE_MASK: u16 = MIN_VALUE_LEAD - 1; // 0x003f
 
 // A final-value node has bit 15 set.
-const VALUE_IS_FINAL: u16 = 0x8000;
+pub(super) const VALUE_IS_FINAL: u16 = 0x8000;
 
 // Compact value: After testing bit 0, shift right by 15 and then use the following thresholds.
-const MAX_ONE_UNIT_VALUE: u16 = 0x3fff;
+pub(super) const MAX_ONE_UNIT_VALUE: u16 = 0x3fff;
 
-const MIN_TWO_UNIT_VALUE_LEAD: u16 = MAX_ONE_UNIT_VALUE + 1; // 0x4000
+pub(super) const MIN_TWO_UNIT_VALUE_LEAD: u16 = MAX_ONE_UNIT_VALUE + 1; // 0x4000
 
-const MAX_ONE_UNIT_NODE_VALUE: u16 = 0xff;
+pub(super) const MAX_ONE_UNIT_NODE_VALUE: u16 = 0xff;
 
-const MIN_TWO_UNIT_NODE_VALUE_LEAD: u16 = MIN_VALUE_LEAD + ((MAX_ONE_UNIT_NODE_VALUE + 1) << 6); // 0x4040
+pub(super) const MIN_TWO_UNIT_NODE_VALUE_LEAD: u16 =
+    MIN_VALUE_LEAD + ((MAX_ONE_UNIT_NODE_VALUE + 1) << 6); // 0x4040
 
-const THREE_UNIT_NODE_VALUE_LEAD: u16 = 0x7fc0;
+pub(super) const THREE_UNIT_NODE_VALUE_LEAD: u16 = 0x7fc0;
 
-const THREE_UNIT_VALUE_LEAD: u16 = 0x7fff;
+pub(super) const THREE_UNIT_VALUE_LEAD: u16 = 0x7fff;
 
 // Compact delta integers.
-const MAX_ONE_UNIT_DELTA: u16 = 0xfbff;
-const MIN_TWO_UNIT_DELTA_LEAD: u16 = MAX_ONE_UNIT_DELTA + 1; // 0xfc00
-const THREE_UNIT_DELTA_LEAD: u16 = 0xffff;
+pub(super) const MAX_ONE_UNIT_DELTA: u16 = 0xfbff;
+pub(super) const MIN_TWO_UNIT_DELTA_LEAD: u16 = MAX_ONE_UNIT_DELTA + 1; // 0xfc00
+pub(super) const THREE_UNIT_DELTA_LEAD: u16 = 0xffff;
 
 fn skip_value(pos: usize, lead: u16) -> usize {
     if lead < MIN_TWO_UNIT_VALUE_LEAD {
@@ -98,6 +99,98 @@ impl<'data> Char16Trie<'data> {
     pub fn iter(&self) -> Char16TrieIterator<'_> {
         Char16TrieIterator::new(&self.data)
     }
+
+    /// Returns the value for the string `key`, if it is in the trie.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::collections::char16trie::Char16Trie;
+    /// use zerovec::ZeroVec;
+    ///
+    /// // A Char16Trie mapping "a" to 1 and "ab" to 100.
+    /// let trie_data = [48, 97, 176, 98, 32868];
+    /// let trie = Char16Trie::new(ZeroVec::from_slice_or_alloc(&trie_data));
+    ///
+    /// assert_eq!(trie.get("a"), Some(1));
+    /// assert_eq!(trie.get("ab"), Some(100));
+    /// assert_eq!(trie.get("b"), None);
+    /// assert_eq!(trie.get("abc"), None);
+    /// ```
+    pub fn get(&self, key: &str) -> Option<i32> {
+        let mut iter = self.iter();
+        let mut result = self.empty_string_result();
+        for c in key.chars() {
+            result = iter.next(c);
+            if result == TrieResult::NoMatch {
+                return None;
+            }
+        }
+        result.value()
+    }
+
+    /// Returns an iterator over the prefixes of `input` that are in the trie, from the
+    /// shortest to the longest, as pairs of the prefix length in bytes and its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::collections::char16trie::Char16Trie;
+    /// use zerovec::ZeroVec;
+    ///
+    /// // A Char16Trie mapping "a" to 1 and "ab" to 100.
+    /// let trie_data = [48, 97, 176, 98, 32868];
+    /// let trie = Char16Trie::new(ZeroVec::from_slice_or_alloc(&trie_data));
+    ///
+    /// let prefixes: Vec<_> = trie.matching_prefixes("abc").collect();
+    /// assert_eq!(prefixes, [(1, 1), (2, 100)]);
+    /// assert_eq!(trie.matching_prefixes("ba").next(), None);
+    /// ```
+    pub fn matching_prefixes<'s>(
+        &'s self,
+        input: &'s str,
+    ) -> impl Iterator<Item = (usize, i32)> + 's {
+        let mut iter = self.iter();
+        core::iter::once((0, self.empty_string_result()))
+            .chain(
+                input
+                    .char_indices()
+                    .map_while(move |(i, c)| match iter.next(c) {
+                        TrieResult::NoMatch => None,
+                        result => Some((i + c.len_utf8(), result)),
+                    }),
+            )
+            .filter_map(|(length, result)| Some((length, result.value()?)))
+    }
+
+    /// Returns the longest prefix of `input` that is in the trie, as a pair of the
+    /// prefix length in bytes and its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::collections::char16trie::Char16Trie;
+    /// use zerovec::ZeroVec;
+    ///
+    /// // A Char16Trie mapping "a" to 1 and "ab" to 100.
+    /// let trie_data = [48, 97, 176, 98, 32868];
+    /// let trie = Char16Trie::new(ZeroVec::from_slice_or_alloc(&trie_data));
+    ///
+    /// assert_eq!(trie.get_longest_prefix("abc"), Some((2, 100)));
+    /// assert_eq!(trie.get_longest_prefix("ac"), Some((1, 1)));
+    /// assert_eq!(trie.get_longest_prefix("c"), None);
+    /// ```
+    pub fn get_longest_prefix(&self, input: &str) -> Option<(usize, i32)> {
+        self.matching_prefixes(input).last()
+    }
+
+    /// The result for the empty string, which is only stored at the start of the trie.
+    fn empty_string_result(&self) -> TrieResult {
+        match self.data.first() {
+            Some(node) if node >= MIN_VALUE_LEAD => self.iter().value_result(0),
+            _ => TrieResult::NoValue,
+        }
+    }
 }
 
 /// This struct represents an iterator over a [`Char16Trie`].
@@ -132,6 +225,16 @@ pub enum TrieResult {
     Intermediate(i32),
 }
 
+impl TrieResult {
+    /// Returns the value for the string so far, if there is one.
+    pub fn value(self) -> Option<i32> {
+        match self {
+            TrieResult::FinalValue(value) | TrieResult::Intermediate(value) => Some(value),
+            TrieResult::NoMatch | TrieResult::NoValue => None,
+        }
+    }
+}
+
 // Get the lead surrogate (0xd800..0xdbff) for a
 // supplementary code point (0x10000..0x10ffff).
 // @param supplementary 32-bit code point (U+10000..U+10ffff)
@@ -450,26 +450,17 @@ impl<'data> CodePointInversionList<'data> {
     /// ```
     pub fn iter_ranges_complemented(&self) -> impl Iterator<Item = RangeInclusive<u32>> + '_ {
         let inv_ule = self.inv_list.as_ule_slice();
-        let middle = inv_ule.get(1..inv_ule.len() - 1).unwrap_or(&[]);
-        let beginning = if let Some(first) = self.inv_list.first() {
-            let first = u32::from(first);
-            if first == 0 {
-                None
-            } else {
-                Some(0..=first - 1)
-            }
-        } else {
-            None
+        let middle = inv_ule
+            .get(1..inv_ule.len().saturating_sub(1))
+            .unwrap_or(&[]);
+        let beginning = match self.inv_list.first().map(u32::from) {
+            Some(0) => None,
+            Some(first) => Some(0..=first - 1),
+            None => Some(0..=char::MAX as u32),
         };
-        let end = if let Some(last) = self.inv_list.last() {
-            let last = u32::from(last);
-            if last == char::MAX as u32 {
-                None
-            } else {
-                Some(last..=char::MAX as u32)
-            }
-        } else {
-            None
+        let end = match self.inv_list.last().map(u32::from) {
+            Some(last) if last <= char::MAX as u32 => Some(last..=char::MAX as u32),
+            _ => None,
         };
         #[expect(clippy::indexing_slicing)] // chunks
         let chunks = middle.chunks(2).map(|pair| {
@@ -746,6 +737,7 @@ impl<'data> CodePointInversionList<'data> {
 #[cfg(test)]
 mod tests {
     use super::{CodePointInversionList, InvalidSetError};
+    use core::ops::RangeInclusive;
     use std::{char, vec::Vec};
     use zerovec::ZeroVec;
 
@@ -939,6 +931,26 @@ mod tests {
         assert_eq!(None, ranges.next());
     }
 
+    #[test]
+    fn test_codepointinversionlist_iter_ranges_complemented() {
+        let max = char::MAX as u32;
+        let cases: [(&[u32], &[RangeInclusive<u32>]); 5] = [
+            (&[], &[0..=max]),
+            (&[0, max + 1], &[]),
+            (
+                &[0x41, 0x44, 0x46, 0x4B],
+                &[0..=0x40, 0x44..=0x45, 0x4B..=max],
+            ),
+            (&[0x41, max + 1], &[0..=0x40]),
+            (&[0x41, max], &[0..=0x40, max..=max]),
+        ];
+        for (inv_list, expected) in cases {
+            let set = CodePointInversionList::try_from_u32_inversion_list_slice(inv_list).unwrap();
+            let actual: Vec<_> = set.iter_ranges_complemented().collect();
+            assert_eq!(actual, expected, "{inv_list:?}");
+        }
+    }
+
     #[test]
     fn test_codepointinversionlist_span_contains() {
         let ex = vec![0x41, 0x44, 0x46, 0x4B]; // A - D, F - K
@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use icu_collections::char16trie::{Char16Trie, TrieResult};
+use icu_collections::char16trie::{Char16Trie, Char16TrieBuildError, TrieResult};
 use zerovec::ZeroVec;
 
 #[test]
@@ -293,6 +293,190 @@ fn months() {
     assert_eq!(res, TrieResult::NoMatch);
 }
 
+#[test]
+fn build() {
+    let alphabet = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let long_sequence = alphabet.repeat(6);
+    let n = "n".repeat(54);
+    let o = "o".repeat(54);
+    let p = "p".repeat(54);
+    for (file, pairs) in [
+        (include_str!("data/char16trie/empty.toml"), vec![("", 0)]),
+        (include_str!("data/char16trie/test_a.toml"), vec![("a", 1)]),
+        (
+            include_str!("data/char16trie/test_a_ab.toml"),
+            vec![("a", 1), ("ab", 100)],
+        ),
+        (
+            include_str!("data/char16trie/test_shortest_branch.toml"),
+            vec![("a", 1000), ("b", 2000)],
+        ),
+        (
+            include_str!("data/char16trie/test_branches.toml"),
+            vec![
+                ("a", 0x10),
+                ("cc", 0x40),
+                ("e", 0x100),
+                ("ggg", 0x400),
+                ("i", 0x1000),
+                ("kkkk", 0x4000),
+                ("n", 0x10000),
+                ("ppppp", 0x40000),
+                ("r", 0x100000),
+                ("sss", 0x200000),
+                ("t", 0x400000),
+                ("uu", 0x800000),
+                ("vv", 0x7fffffff),
+                ("zz", -0x80000000),
+            ],
+        ),
+        (
+            include_str!("data/char16trie/test_long_sequence.toml"),
+            vec![("a", -1), (alphabet, -2), (&long_sequence, -3)],
+        ),
+        (
+            include_str!("data/char16trie/test_long_branch.toml"),
+            vec![
+                ("a", -2),
+                ("b", -1),
+                ("c", 0),
+                ("d2", 1),
+                ("f", 0x3f),
+                ("g", 0x40),
+                ("h", 0x41),
+                ("j23", 0x1900),
+                ("j24", 0x19ff),
+                ("j25", 0x1a00),
+                ("k2", 0x1a80),
+                ("k3", 0x1aff),
+                ("l234567890", 0x1b00),
+                ("l234567890123", 0x1b01),
+                (&n, 0x10ffff),
+                (&o, 0x110000),
+                (&p, 0x120000),
+                ("r", 0x333333),
+                ("s2345", 0x4444444),
+                ("t234567890", 0x77777777),
+                ("z", -0x7fffffff),
+            ],
+        ),
+        (
+            include_str!("data/char16trie/test_compact.toml"),
+            vec![
+                ("+", 0),
+                ("+august", 8),
+                ("+december", 12),
+                ("+july", 7),
+                ("+june", 6),
+                ("+november", 11),
+                ("+october", 10),
+                ("+september", 9),
+                ("-", 0),
+                ("-august", 8),
+                ("-december", 12),
+                ("-july", 7),
+                ("-june", 6),
+                ("-november", 11),
+                ("-october", 10),
+                ("-september", 9),
+                ("xjuly", 7),
+                ("xjune", 6),
+            ],
+        ),
+    ] {
+        let expected = toml::from_str::<TestFile>(file).unwrap().ucharstrie.data;
+        let trie = Char16Trie::try_from_sorted_pairs(pairs.iter().copied()).unwrap();
+        assert_eq!(trie.data.to_vec(), expected, "{pairs:?}");
+        for &(key, value) in &pairs {
+            assert_eq!(trie.get(key), Some(value), "{key}");
+        }
+    }
+}
+
+#[test]
+fn build_months() {
+    let trie_data = toml::from_str::<TestFile>(include_str!("data/char16trie/months.toml"))
+        .unwrap()
+        .ucharstrie
+        .data;
+    let pairs = [
+        ("august", 8),
+        ("jan", 1),
+        ("jan.", 1),
+        ("jana", 1),
+        ("janbb", 1),
+        ("janc", 1),
+        ("janddd", 1),
+        ("janee", 1),
+        ("janef", 1),
+        ("janf", 1),
+        ("jangg", 1),
+        ("janh", 1),
+        ("janiiii", 1),
+        ("janj", 1),
+        ("jankk", 1),
+        ("jankl", 1),
+        ("jankmm", 1),
+        ("janl", 1),
+        ("janm", 1),
+        ("jannnnnnnnnnnnnnnnnnnnnnnnnnnnn", 1),
+        ("jano", 1),
+        ("janpp", 1),
+        ("janqqq", 1),
+        ("janr", 1),
+        ("januar", 1),
+        ("january", 1),
+        ("july", 7),
+        ("jun", 6),
+        ("jun.", 6),
+        ("june", 6),
+    ];
+    let trie = Char16Trie::try_from_sorted_pairs(pairs).unwrap();
+    assert_eq!(trie.data.to_vec(), trie_data);
+
+    assert_eq!(
+        trie.matching_prefixes("january!").collect::<Vec<_>>(),
+        [(3, 1), (6, 1), (7, 1)]
+    );
+    assert_eq!(trie.get_longest_prefix("june."), Some((4, 6)));
+    assert_eq!(trie.get_longest_prefix("ju"), None);
+    assert_eq!(trie.get("janu"), None);
+}
+
+#[test]
+fn build_errors() {
+    assert_eq!(
+        Char16Trie::try_from_sorted_pairs::<&str>([]),
+        Err(Char16TrieBuildError::Empty)
+    );
+    assert_eq!(
+        Char16Trie::try_from_sorted_pairs([("a", 1), ("a", 2)]),
+        Err(Char16TrieBuildError::NotSorted { index: 1 })
+    );
+    assert_eq!(
+        Char16Trie::try_from_sorted_pairs([("a", 1), ("c", 2), ("b", 3)]),
+        Err(Char16TrieBuildError::NotSorted { index: 2 })
+    );
+}
+
+#[test]
+fn build_supplementary() {
+    // Code point order, which differs from UTF-16 order
+    let pairs = [
+        ("", -1),
+        ("\u{ffff}", 1),
+        ("\u{10000}", 2),
+        ("\u{10000}x", 3),
+    ];
+    let trie = Char16Trie::try_from_sorted_pairs(pairs).unwrap();
+    for (key, value) in pairs {
+        assert_eq!(trie.get(key), Some(value));
+        assert_eq!(trie.get_longest_prefix(key), Some((key.len(), value)));
+    }
+    assert_eq!(trie.get("\u{10001}"), None);
+    assert_eq!(trie.get_longest_prefix("\u{10001}"), Some((0, -1)));
+}
+
 #[derive(serde::Deserialize)]
 pub struct TestFile {
     ucharstrie: Char16TrieVec,
@@ -138,6 +138,12 @@ pub enum DateFields {
     /// A year, as in
     /// “2000”.
     Y,
+    /// The year and week of the year, as in
+    /// “week 52 of 1999”.
+    YW,
+    /// The month and week of the month, as in
+    /// “week 3 of January”.
+    MW,
 }
 
 impl DateFields {
@@ -153,6 +159,8 @@ impl DateFields {
         Self::M,
         Self::YM,
         Self::Y,
+        Self::YW,
+        Self::MW,
     ];
 
     /// Returns whether this [`DateFields`] variant represents a [`CalendarPeriodFieldSet`].
@@ -168,6 +176,8 @@ impl DateFields {
             DateFields::M => true,
             DateFields::YM => true,
             DateFields::Y => true,
+            DateFields::YW => true,
+            DateFields::MW => true,
         }
     }
 }
@@ -482,6 +492,12 @@ impl FieldSetBuilder {
             Some(DateFields::Y) => CalendarPeriod(CalendarPeriodFieldSet::Y(
                 fieldsets::Y::take_from_builder(self),
             )),
+            Some(DateFields::YW) => CalendarPeriod(CalendarPeriodFieldSet::YW(
+                fieldsets::YW::take_from_builder(self),
+            )),
+            Some(DateFields::MW) => CalendarPeriod(CalendarPeriodFieldSet::MW(
+                fieldsets::MW::take_from_builder(self),
+            )),
             Option::None => return Err(BuilderError::MissingDateFields),
         };
         Ok(field_set)
@@ -589,9 +605,12 @@ impl FieldSetBuilder {
             Some(DateFields::E) => {
                 DateAndTimeFieldSet::ET(fieldsets::ET::take_from_builder(&mut self))
             }
-            Some(DateFields::M) | Some(DateFields::YM) | Some(DateFields::Y) | Option::None => {
-                return Err(BuilderError::InvalidDateFields)
-            }
+            Some(DateFields::M)
+            | Some(DateFields::YM)
+            | Some(DateFields::Y)
+            | Some(DateFields::YW)
+            | Some(DateFields::MW)
+            | Option::None => return Err(BuilderError::InvalidDateFields),
         };
         self.check_options_consumed()?;
         Ok(date_and_time_field_set)
@@ -725,8 +744,13 @@ mod tests {
         DateFields::E,
     ];
 
-    static CALENDAR_PERIOD_FIELD_SETS: &[DateFields] =
-        &[DateFields::M, DateFields::YM, DateFields::Y];
+    static CALENDAR_PERIOD_FIELD_SETS: &[DateFields] = &[
+        DateFields::M,
+        DateFields::YM,
+        DateFields::Y,
+        DateFields::YW,
+        DateFields::MW,
+    ];
 
     static ZONE_STYLES: &[ZoneStyle] = &[
         ZoneStyle::SpecificLong,
@@ -127,10 +127,12 @@ pub enum CalendarPeriodFieldSet {
     /// A year, as in
     /// “2000”.
     Y(fieldsets::Y),
-    // TODO(#5643): Add support for week-of-year
-    // /// The year and week of the year, as in
-    // /// “52nd week of 1999”.
-    // YW(fieldsets::YW),
+    /// The year and week of the year, as in
+    /// “week 52 of 1999”.
+    YW(fieldsets::YW),
+    /// The month and week of the month, as in
+    /// “week 3 of January”.
+    MW(fieldsets::MW),
     // TODO(#501): Consider adding support for Quarter and YearQuarter.
 }
 
@@ -469,6 +471,8 @@ impl_attrs! {
         (M, ATTR_M, STR_M, "m0"),
         (YM, ATTR_YM, STR_YM, "ym0"),
         (Y, ATTR_Y, STR_Y, "y"),
+        (YW, ATTR_YW, STR_YW, "y0w"),
+        (MW, ATTR_MW, STR_MW, "m0w0"),
     ]
 }
 
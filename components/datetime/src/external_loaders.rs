@@ -4,8 +4,10 @@
 
 //! Internal traits and structs for loading data from other crates.
 
+use icu_calendar::week::{WeekInformation, WeekPreferences};
 use icu_decimal::options::DecimalFormatterOptions;
 use icu_decimal::{DecimalFormatter, DecimalFormatterPreferences};
+use icu_plurals::{PluralRules, PluralRulesPreferences};
 use icu_provider::prelude::*;
 
 use crate::scaffold::{FormattableAnyCalendar, FormattableAnyCalendarKind};
@@ -21,6 +23,20 @@ pub(crate) trait DecimalFormatterLoader {
     ) -> Result<DecimalFormatter, DataError>;
 }
 
+/// Trait for loading a WeekInformation.
+///
+/// Implemented on the provider-specific loader types in this module.
+pub(crate) trait WeekInformationLoader {
+    fn load(&self, prefs: WeekPreferences) -> Result<WeekInformation, DataError>;
+}
+
+/// Trait for loading ordinal PluralRules.
+///
+/// Implemented on the provider-specific loader types in this module.
+pub(crate) trait OrdinalRulesLoader {
+    fn load(&self, prefs: PluralRulesPreferences) -> Result<PluralRules, DataError>;
+}
+
 /// Trait for loading an AnyCalendar.
 ///
 /// Implemented on the provider-specific loader types in this module.
@@ -44,6 +60,22 @@ impl DecimalFormatterLoader for ExternalLoaderCompiledData {
     }
 }
 
+#[cfg(feature = "compiled_data")]
+impl WeekInformationLoader for ExternalLoaderCompiledData {
+    #[inline]
+    fn load(&self, prefs: WeekPreferences) -> Result<WeekInformation, DataError> {
+        WeekInformation::try_new(prefs)
+    }
+}
+
+#[cfg(feature = "compiled_data")]
+impl OrdinalRulesLoader for ExternalLoaderCompiledData {
+    #[inline]
+    fn load(&self, prefs: PluralRulesPreferences) -> Result<PluralRules, DataError> {
+        PluralRules::try_new_ordinal(prefs)
+    }
+}
+
 #[cfg(feature = "compiled_data")]
 impl FormattableAnyCalendarLoader for ExternalLoaderCompiledData {
     #[inline]
@@ -71,6 +103,28 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<P> WeekInformationLoader for ExternalLoaderBuffer<'_, P>
+where
+    P: ?Sized + BufferProvider,
+{
+    #[inline]
+    fn load(&self, prefs: WeekPreferences) -> Result<WeekInformation, DataError> {
+        WeekInformation::try_new_with_buffer_provider(self.0, prefs)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<P> OrdinalRulesLoader for ExternalLoaderBuffer<'_, P>
+where
+    P: ?Sized + BufferProvider,
+{
+    #[inline]
+    fn load(&self, prefs: PluralRulesPreferences) -> Result<PluralRules, DataError> {
+        PluralRules::try_new_ordinal_with_buffer_provider(self.0, prefs)
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<P> FormattableAnyCalendarLoader for ExternalLoaderBuffer<'_, P>
 where
@@ -101,6 +155,26 @@ where
     }
 }
 
+impl<P> WeekInformationLoader for ExternalLoaderUnstable<'_, P>
+where
+    P: DataProvider<icu_calendar::provider::CalendarWeekV2> + ?Sized,
+{
+    #[inline]
+    fn load(&self, prefs: WeekPreferences) -> Result<WeekInformation, DataError> {
+        WeekInformation::try_new_unstable(self.0, prefs)
+    }
+}
+
+impl<P> OrdinalRulesLoader for ExternalLoaderUnstable<'_, P>
+where
+    P: DataProvider<icu_plurals::provider::PluralsOrdinalV1> + ?Sized,
+{
+    #[inline]
+    fn load(&self, prefs: PluralRulesPreferences) -> Result<PluralRules, DataError> {
+        PluralRules::try_new_ordinal_unstable(self.0, prefs)
+    }
+}
+
 impl<P> FormattableAnyCalendarLoader for ExternalLoaderUnstable<'_, P>
 where
     P: DataProvider<icu_calendar::provider::CalendarJapaneseModernV1> + ?Sized,
@@ -63,7 +63,7 @@ use crate::{
     scaffold::*,
 };
 use enums::*;
-use icu_calendar::types::{DayOfMonth, MonthInfo, Weekday, YearInfo};
+use icu_calendar::types::{DayOfMonth, DayOfYearInfo, MonthInfo, Weekday, YearInfo};
 use icu_provider::marker::NeverMarker;
 use icu_time::{
     zone::{UtcOffset, ZoneNameTimestamp},
@@ -760,6 +760,9 @@ macro_rules! impl_calendar_period_marker {
         $(dates = $dates_yes:ident,)?
         $(input_year = $year_yes:ident,)?
         $(input_month = $month_yes:ident,)?
+        $(input_day_of_month = $day_of_month_yes:ident,)?
+        $(input_day_of_week = $day_of_week_yes:ident,)?
+        $(input_day_of_year = $day_of_year_yes:ident,)?
         $(input_any_calendar_kind = $any_calendar_kind_yes:ident,)?
         $(option_alignment = $option_alignment_yes:ident,)?
     ) => {
@@ -774,6 +777,9 @@ macro_rules! impl_calendar_period_marker {
             $(dates = $dates_yes,)?
             $(input_year = $year_yes,)?
             $(input_month = $month_yes,)?
+            $(input_day_of_month = $day_of_month_yes,)?
+            $(input_day_of_week = $day_of_week_yes,)?
+            $(input_day_of_year = $day_of_year_yes,)?
             $(input_any_calendar_kind = $any_calendar_kind_yes,)?
             $(option_alignment = $option_alignment_yes,)?
         );
@@ -1149,6 +1155,34 @@ impl_calendar_period_marker!(
     option_alignment = yes,
 );
 
+impl_calendar_period_marker!(
+    /// The week is computed according to the locale's week rules, which can
+    /// differ from ISO 8601. The year is the week-numbering year, which can
+    /// differ from the calendar year near the start and end of the year.
+    YW,
+    description = "year and week of the year",
+    sample_length = medium,
+    sample = "week 20 of 2024",
+    years = yes,
+    input_year = yes,
+    input_day_of_week = yes,
+    input_day_of_year = yes,
+    input_any_calendar_kind = yes,
+);
+
+impl_calendar_period_marker!(
+    /// The week is computed according to the locale's week rules.
+    MW,
+    description = "month and week of the month",
+    sample_length = long,
+    sample = "week 3 of May",
+    months = yes,
+    input_month = yes,
+    input_day_of_month = yes,
+    input_day_of_week = yes,
+    input_any_calendar_kind = yes,
+);
+
 impl_time_marker!(
     /// Hours can be switched between 12-hour and 24-hour time via the `u-hc` locale keyword
     /// or [`DateTimeFormatterPreferences`].
@@ -14,6 +14,7 @@ use crate::{parts, pattern::*};
 use core::fmt::{self, Write};
 use fixed_decimal::Decimal;
 use icu_calendar::types::{DayOfWeekInMonth, RataDie, Weekday};
+use icu_calendar::week::RelativeUnit;
 use icu_decimal::DecimalFormatter;
 use writeable::{Part, PartsWrite, Writeable};
 
@@ -221,6 +222,27 @@ where
             })?;
             Ok(())
         }
+        (FieldSymbol::Year(Year::WeekOf), l) => {
+            const PART: Part = parts::YEAR;
+            input!(PART, Year, year = input.year);
+            input!(PART, DayOfYear, day_of_year = input.day_of_year);
+            input!(PART, Weekday, iso_weekday = input.iso_weekday);
+            let Some(week_information) = datetime_names.week_information else {
+                w.with_part(PART, |w| write_value_missing(w, field))?;
+                return Ok(Err(FormattedDateTimePatternError::WeekInformationNotLoaded));
+            };
+            let week_year = match week_information.week_of_year(day_of_year, iso_weekday).unit {
+                RelativeUnit::Previous => day_of_year.prev_year,
+                RelativeUnit::Current => year,
+                RelativeUnit::Next => day_of_year.next_year,
+            };
+            let mut year = Decimal::from(week_year.era_year_or_related_iso());
+            if matches!(l, FieldLength::Two) {
+                // 'yy' and 'YY' truncate
+                year.set_max_position(2);
+            }
+            try_write_number(PART, w, decimal_formatter, year, l)?
+        }
         (FieldSymbol::Year(Year::Extended), l) => {
             const PART: Part = parts::EXTENDED_YEAR;
             input!(PART, Year, year = input.year);
@@ -288,7 +310,25 @@ where
                 }
             }
         }
-        (FieldSymbol::Week(w), _) => match w {},
+        (FieldSymbol::Week(week), l) => {
+            input!(_, Weekday, iso_weekday = input.iso_weekday);
+            let week_information = datetime_names.week_information;
+            let number = match week {
+                fields::Week::WeekOfYear => {
+                    input!(_, DayOfYear, day_of_year = input.day_of_year);
+                    week_information.map(|wi| wi.week_of_year(day_of_year, iso_weekday).week)
+                }
+                fields::Week::WeekOfMonth => {
+                    input!(_, DayOfMonth, day_of_month = input.day_of_month);
+                    week_information.map(|wi| wi.week_of_month(day_of_month, iso_weekday).0)
+                }
+            };
+            let Some(number) = number else {
+                write_value_missing(w, field)?;
+                return Ok(Err(FormattedDateTimePatternError::WeekInformationNotLoaded));
+            };
+            try_write_number_without_part(w, decimal_formatter, number.into(), l)?
+        }
         (FieldSymbol::Weekday(weekday), l) => {
             const PART: Part = parts::WEEKDAY;
             input!(PART, Weekday, iso_weekday = input.iso_weekday);
@@ -337,7 +377,12 @@ where
         }
         (FieldSymbol::Day(fields::Day::DayOfYear), l) => {
             input!(_, DayOfYear, day_of_year = input.day_of_year);
-            try_write_number_without_part(w, decimal_formatter, day_of_year.0.into(), l)?
+            try_write_number_without_part(
+                w,
+                decimal_formatter,
+                day_of_year.day_of_year.0.into(),
+                l,
+            )?
         }
         (FieldSymbol::Day(fields::Day::ModifiedJulianDay), l) => {
             const PART: Part = parts::JULIAN_DAY;
@@ -6,7 +6,7 @@
 //! formatting operations.
 
 use crate::scaffold::*;
-use icu_calendar::types::{DayOfYear, RataDie};
+use icu_calendar::types::{DayOfYearInfo, RataDie};
 use icu_calendar::{AsCalendar, Calendar};
 use icu_time::scaffold::IntoOption;
 use icu_time::zone::ZoneNameTimestamp;
@@ -37,7 +37,7 @@ pub struct DateTimeInputUnchecked {
     /// The weekday, required for field sets with weekdays (`E`).
     pub(crate) iso_weekday: Option<Weekday>,
     /// The day-of-year, required for field sets with weeks.
-    pub(crate) day_of_year: Option<DayOfYear>,
+    pub(crate) day_of_year: Option<DayOfYearInfo>,
     /// The RataDie of the day
     pub(crate) rata_die: Option<RataDie>,
     /// The hour, required for field sets with times (`T`).
@@ -72,7 +72,7 @@ impl DateTimeInputUnchecked {
         self.month = Some(date_in_calendar.month());
         self.day_of_month = Some(date_in_calendar.day_of_month());
         self.iso_weekday = Some(date_in_calendar.day_of_week());
-        self.day_of_year = Some(date_in_calendar.day_of_year());
+        self.day_of_year = Some(date_in_calendar.day_of_year_info());
     }
 
     /// Sets all fields from a [`Time`] input.
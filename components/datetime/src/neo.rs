@@ -22,9 +22,11 @@ use crate::size_test_macro::size_test;
 use crate::MismatchedCalendarError;
 use core::fmt;
 use core::marker::PhantomData;
+use icu_calendar::week::WeekPreferences;
 use icu_calendar::{preferences::CalendarPreferences, AnyCalendar, IntoAnyCalendar};
 use icu_decimal::DecimalFormatterPreferences;
 use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_plurals::PluralRulesPreferences;
 use icu_provider::prelude::*;
 use writeable::{impl_display_with_writeable, Writeable};
 
@@ -99,6 +101,9 @@ prefs_convert!(DateTimeFormatterPreferences, DecimalFormatterPreferences, {
     numbering_system
 });
 
+prefs_convert!(DateTimeFormatterPreferences, WeekPreferences);
+prefs_convert!(DateTimeFormatterPreferences, PluralRulesPreferences);
+
 prefs_convert!(DateTimeFormatterPreferences, CalendarPreferences, {
     calendar_algorithm
 });
@@ -122,6 +127,7 @@ macro_rules! gen_buffer_constructors_with_external_loader {
             Self::$internal_fn(
                 &compat_provider,
                 &ExternalLoaderBuffer(provider),
+                &ExternalLoaderBuffer(provider),
                 prefs,
                 field_set_with_options.get_field(),
             )
@@ -235,6 +241,7 @@ where
         Self::try_new_internal(
             &crate::provider::Baked,
             &ExternalLoaderCompiledData,
+            &ExternalLoaderCompiledData,
             prefs,
             field_set_with_options.get_field(),
         )
@@ -262,6 +269,7 @@ where
         Self::try_new_internal(
             provider,
             &ExternalLoaderUnstable(provider),
+            &ExternalLoaderUnstable(provider),
             prefs,
             field_set_with_options.get_field(),
         )
@@ -274,21 +282,24 @@ where
     FSet::T: TimeMarkers,
     FSet::Z: ZoneMarkers,
 {
-    fn try_new_internal<P, L>(
+    fn try_new_internal<P, L, W>(
         provider: &P,
         loader: &L,
+        week_information_loader: &W,
         prefs: DateTimeFormatterPreferences,
         field_set_with_options: CompositeFieldSet,
     ) -> Result<Self, DateTimeFormatterLoadError>
     where
         P: ?Sized + AllFixedCalendarFormattingDataMarkers<C, FSet>,
         L: DecimalFormatterLoader,
+        W: WeekInformationLoader + OrdinalRulesLoader,
     {
         let names = RawDateTimeNames::new_without_number_formatting();
         Self::try_new_internal_with_names(
             provider,
             provider,
             loader,
+            week_information_loader,
             prefs,
             field_set_with_options,
             names,
@@ -298,10 +309,12 @@ where
     }
 
     #[expect(clippy::result_large_err)] // returning ownership of an argument to the caller
-    pub(crate) fn try_new_internal_with_names<P0, P1, L>(
+    #[expect(clippy::too_many_arguments)] // internal function with lots of generics
+    pub(crate) fn try_new_internal_with_names<P0, P1, L, W>(
         provider_p: &P0,
         provider: &P1,
         loader: &L,
+        week_information_loader: &W,
         prefs: DateTimeFormatterPreferences,
         field_set_with_options: CompositeFieldSet,
         mut names: RawDateTimeNames<FSet>,
@@ -317,11 +330,13 @@ where
         P0: ?Sized + AllFixedCalendarPatternDataMarkers<C, FSet>,
         P1: ?Sized + AllFixedCalendarFormattingDataMarkers<C, FSet>,
         L: DecimalFormatterLoader,
+        W: WeekInformationLoader + OrdinalRulesLoader,
     {
         let selection = DateTimeZonePatternSelectionData::try_new_with_skeleton(
             &<FSet::D as TypedDateDataMarkers<C>>::DateSkeletonPatternsV1::bind(provider_p),
             &<FSet::T as TimeMarkers>::TimeSkeletonPatternsV1::bind(provider_p),
             &FSet::GluePatternV1::bind(provider_p),
+            week_information_loader,
             prefs,
             field_set_with_options,
         );
@@ -346,6 +361,7 @@ where
             &<FSet::Z as ZoneMarkers>::SpecificShortV1::bind(provider),
             &<FSet::Z as ZoneMarkers>::MetazonePeriodV1::bind(provider),
             loader, // fixed decimal formatter
+            week_information_loader,
             prefs,
             selection.pattern_items_for_data_loading(),
             &mut names_metadata,
@@ -380,10 +396,11 @@ where
     {
         let input =
             DateTimeInputUnchecked::extract_from_neo_input::<FSet::D, FSet::T, FSet::Z, I>(input);
+        let names = self.names.as_borrowed();
         FormattedDateTime {
-            pattern: self.selection.select(&input),
+            pattern: self.selection.select(&input, names.week_information),
             input,
-            names: self.names.as_borrowed(),
+            names,
         }
     }
 }
@@ -456,6 +473,7 @@ where
         Self::try_new_internal(
             &crate::provider::Baked,
             &ExternalLoaderCompiledData,
+            &ExternalLoaderCompiledData,
             prefs,
             field_set_with_options.get_field(),
         )
@@ -481,6 +499,7 @@ where
         Self::try_new_internal(
             provider,
             &ExternalLoaderUnstable(provider),
+            &ExternalLoaderUnstable(provider),
             prefs,
             field_set_with_options.get_field(),
         )
@@ -493,15 +512,17 @@ where
     FSet::T: TimeMarkers,
     FSet::Z: ZoneMarkers,
 {
-    fn try_new_internal<P, L>(
+    fn try_new_internal<P, L, W>(
         provider: &P,
         loader: &L,
+        week_information_loader: &W,
         prefs: DateTimeFormatterPreferences,
         field_set_with_options: CompositeFieldSet,
     ) -> Result<Self, DateTimeFormatterLoadError>
     where
         P: ?Sized + AllAnyCalendarFormattingDataMarkers<FSet>,
         L: DecimalFormatterLoader + FormattableAnyCalendarLoader,
+        W: WeekInformationLoader + OrdinalRulesLoader,
     {
         let kind = FormattableAnyCalendarKind::from_preferences(prefs);
         let calendar = FormattableAnyCalendarLoader::load(loader, kind)?;
@@ -510,6 +531,7 @@ where
             provider,
             provider,
             loader,
+            week_information_loader,
             prefs,
             field_set_with_options,
             calendar,
@@ -522,10 +544,11 @@ where
     #[expect(clippy::result_large_err)] // returning ownership of an argument to the caller
     #[expect(clippy::too_many_arguments)] // internal function with lots of generics
     #[expect(clippy::type_complexity)] // return type has all the parts inside
-    pub(crate) fn try_new_internal_with_calendar_and_names<P0, P1, L>(
+    pub(crate) fn try_new_internal_with_calendar_and_names<P0, P1, L, W>(
         provider_p: &P0,
         provider: &P1,
         loader: &L,
+        week_information_loader: &W,
         prefs: DateTimeFormatterPreferences,
         field_set_with_options: CompositeFieldSet,
         calendar: FormattableAnyCalendar,
@@ -546,6 +569,7 @@ where
         P0: ?Sized + AllAnyCalendarPatternDataMarkers<FSet>,
         P1: ?Sized + AllAnyCalendarFormattingDataMarkers<FSet>,
         L: DecimalFormatterLoader,
+        W: WeekInformationLoader + OrdinalRulesLoader,
     {
         let selection = DateTimeZonePatternSelectionData::try_new_with_skeleton(
             &FormattableAnyCalendarNamesLoader::<<FSet::D as DateDataMarkers>::Skel, _>::new(
@@ -554,6 +578,7 @@ where
             ),
             &<FSet::T as TimeMarkers>::TimeSkeletonPatternsV1::bind(provider_p),
             &FSet::GluePatternV1::bind(provider_p),
+            week_information_loader,
             prefs,
             field_set_with_options,
         );
@@ -589,6 +614,7 @@ where
             &<FSet::Z as ZoneMarkers>::SpecificShortV1::bind(provider),
             &<FSet::Z as ZoneMarkers>::MetazonePeriodV1::bind(provider),
             loader, // fixed decimal formatter
+            week_information_loader,
             prefs,
             selection.pattern_items_for_data_loading(),
             &mut names_metadata,
@@ -674,10 +700,11 @@ where
         let datetime = DateTimeInputUnchecked::extract_from_neo_input::<FSet::D, FSet::T, FSet::Z, I>(
             datetime,
         );
+        let names = self.names.as_borrowed();
         Ok(FormattedDateTime {
-            pattern: self.selection.select(&datetime),
+            pattern: self.selection.select(&datetime, names.week_information),
             input: datetime,
-            names: self.names.as_borrowed(),
+            names,
         })
     }
 
@@ -736,10 +763,11 @@ where
             FSet::Z,
             I::Converted<'a>,
         >(&datetime);
+        let names = self.names.as_borrowed();
         FormattedDateTime {
-            pattern: self.selection.select(&datetime),
+            pattern: self.selection.select(&datetime, names.week_information),
             input: datetime,
-            names: self.names.as_borrowed(),
+            names,
         }
     }
 }
@@ -527,7 +527,6 @@ mod tests {
     #[test]
     fn test_dayperiod_coverage() {
         // Thai has different values for different lengths of day periods
-        // TODO(#487): Support flexible day periods, too
         let locale = locale!("th").into();
         #[derive(Debug)]
         struct TestCase {
@@ -586,6 +585,31 @@ mod tests {
                 length: DayPeriodNameLength::Narrow,
                 expected: "<p>",
             },
+            TestCase {
+                pattern: "<B>",
+                length: DayPeriodNameLength::Abbreviated,
+                expected: "<บ่าย>",
+            },
+            TestCase {
+                pattern: "<BB>",
+                length: DayPeriodNameLength::Abbreviated,
+                expected: "<บ่าย>",
+            },
+            TestCase {
+                pattern: "<BBB>",
+                length: DayPeriodNameLength::Abbreviated,
+                expected: "<บ่าย>",
+            },
+            TestCase {
+                pattern: "<BBBB>",
+                length: DayPeriodNameLength::Wide,
+                expected: "<บ่าย>",
+            },
+            TestCase {
+                pattern: "<BBBBB>",
+                length: DayPeriodNameLength::Narrow,
+                expected: "<บ่าย>",
+            },
         ];
         for cas in cases {
             let TestCase {
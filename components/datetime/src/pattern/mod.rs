@@ -186,6 +186,15 @@ pub enum FormattedDateTimePatternError {
     /// The output will contain fallback values using Latin numerals.
     #[displaydoc("DecimalFormatter not loaded")]
     DecimalFormatterNotLoaded,
+    /// The [`WeekInformation`] has not been loaded.
+    ///
+    /// Same error conditions as [`FormattedDateTimePatternError::NamesNotLoaded`].
+    ///
+    /// The output will contain the string `{X}` instead, where `X` is the symbol of the week field.
+    ///
+    /// [`WeekInformation`]: icu_calendar::week::WeekInformation
+    #[displaydoc("WeekInformation not loaded")]
+    WeekInformationNotLoaded,
 
     /// An input field (such as "hour" or "month") is missing.
     ///
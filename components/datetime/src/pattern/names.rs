@@ -19,12 +19,15 @@ use crate::{external_loaders::*, DateTimeFormatterPreferences};
 use crate::{scaffold::*, DateTimeFormatter, DateTimeFormatterLoadError};
 use core::fmt;
 use core::marker::PhantomData;
+use icu_calendar::provider::CalendarWeekV2;
 use icu_calendar::types::{EraYear, MonthCode};
+use icu_calendar::week::WeekInformation;
 use icu_calendar::AnyCalendar;
 use icu_decimal::options::DecimalFormatterOptions;
 use icu_decimal::options::GroupingStrategy;
 use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV1};
 use icu_decimal::DecimalFormatter;
+use icu_plurals::provider::PluralsOrdinalV1;
 use icu_provider::prelude::*;
 
 /// Choices for loading year names.
@@ -320,12 +323,12 @@ impl DayPeriodNameLength {
         field_length: FieldLength,
     ) -> Option<Self> {
         use fields::DayPeriod;
-        // Names for 'a' and 'b' are stored in the same data marker
+        // Names for 'a', 'b' and 'B' are stored in the same data marker
         let field_symbol = match field_symbol {
-            DayPeriod::NoonMidnight => DayPeriod::AmPm,
+            DayPeriod::NoonMidnight | DayPeriod::Flexible => DayPeriod::AmPm,
             other => other,
         };
-        // UTS 35 says that "a..aaa" and "b..bbb" are all Abbreviated
+        // UTS 35 says that "a..aaa", "b..bbb" and "B..BBB" are all Abbreviated
         let field_length = field_length.numeric_to_abbr();
         match (field_symbol, field_length) {
             (DayPeriod::AmPm, FieldLength::Three) => Some(DayPeriodNameLength::Abbreviated),
@@ -337,7 +340,7 @@ impl DayPeriodNameLength {
 
     /// Returns an [`ErrorField`] sufficient for error reporting.
     pub(crate) fn to_approximate_error_field(self) -> ErrorField {
-        // Names for 'a' and 'b' are stored in the same data marker
+        // Names for 'a', 'b' and 'B' are stored in the same data marker
         let field_symbol = fields::DayPeriod::AmPm;
         let field_length = match self {
             DayPeriodNameLength::Abbreviated => FieldLength::Three,
@@ -681,6 +684,7 @@ pub(crate) struct RawDateTimeNames<FSet: DateTimeNamesMarker> {
     mz_periods: <FSet::MetazoneLookup as NamesContainer<tz::MzPeriodV1, ()>>::Container,
     // TODO(#4340): Make the DecimalFormatter optional
     decimal_formatter: Option<DecimalFormatter>,
+    week_information: Option<WeekInformation>,
     _marker: PhantomData<FSet>,
 }
 
@@ -704,6 +708,7 @@ impl<FSet: DateTimeNamesMarker> fmt::Debug for RawDateTimeNames<FSet> {
             .field("mz_specific_short", &self.mz_specific_short)
             .field("mz_periods", &self.mz_periods)
             .field("decimal_formatter", &self.decimal_formatter)
+            .field("week_information", &self.week_information)
             .finish()
     }
 }
@@ -727,6 +732,7 @@ impl<FSet: DateTimeNamesMarker> Clone for RawDateTimeNames<FSet> {
             mz_specific_short: self.mz_specific_short.clone(),
             mz_periods: self.mz_periods.clone(),
             decimal_formatter: self.decimal_formatter.clone(),
+            week_information: self.week_information,
             _marker: PhantomData,
         }
     }
@@ -751,6 +757,7 @@ impl<FSet: DateTimeNamesMarker> RawDateTimeNames<FSet> {
             mz_specific_short: FSet2::map_zone_specific_short(self.mz_specific_short),
             mz_periods: FSet2::map_metazone_lookup(self.mz_periods),
             decimal_formatter: self.decimal_formatter,
+            week_information: self.week_information,
             _marker: PhantomData,
         }
     }
@@ -774,6 +781,7 @@ pub(crate) struct RawDateTimeNamesBorrowed<'l> {
     mz_specific_short: OptionalNames<(), &'l tz::MzSpecific<'l>>,
     mz_periods: OptionalNames<(), &'l tz::MzPeriod<'l>>,
     pub(crate) decimal_formatter: Option<&'l DecimalFormatter>,
+    pub(crate) week_information: Option<&'l WeekInformation>,
 }
 
 impl<C, FSet: DateTimeNamesMarker> FixedCalendarDateTimeNames<C, FSet> {
@@ -1012,6 +1020,7 @@ where
             &crate::provider::Baked,
             &EmptyDataProvider,
             &ExternalLoaderUnstable(&EmptyDataProvider), // for decimals only
+            &ExternalLoaderCompiledData, // for week information and ordinal rules only
             self.prefs,
             field_set.get_field(),
             self.inner,
@@ -1028,12 +1037,16 @@ where
         field_set: FSet,
     ) -> Result<FixedCalendarDateTimeFormatter<C, FSet>, (DateTimeFormatterLoadError, Self)>
     where
-        P: AllFixedCalendarPatternDataMarkers<C, FSet> + ?Sized,
+        P: AllFixedCalendarPatternDataMarkers<C, FSet>
+            + DataProvider<CalendarWeekV2>
+            + DataProvider<PluralsOrdinalV1>
+            + ?Sized,
     {
         FixedCalendarDateTimeFormatter::try_new_internal_with_names(
             provider,
             &EmptyDataProvider,
             &ExternalLoaderUnstable(&EmptyDataProvider), // for decimals only
+            &ExternalLoaderUnstable(provider), // for week information and ordinal rules only
             self.prefs,
             field_set.get_field(),
             self.inner,
@@ -1057,6 +1070,7 @@ where
             &provider.as_deserializing(),
             &EmptyDataProvider,
             &ExternalLoaderUnstable(&EmptyDataProvider), // for decimals only
+            &ExternalLoaderBuffer(provider), // for week information and ordinal rules only
             self.prefs,
             field_set.get_field(),
             self.inner,
@@ -1222,6 +1236,7 @@ where
             &crate::provider::Baked,
             &EmptyDataProvider,
             &ExternalLoaderUnstable(&EmptyDataProvider), // for decimals only
+            &ExternalLoaderCompiledData, // for week information and ordinal rules only
             self.inner.prefs,
             field_set.get_field(),
             self.calendar,
@@ -1239,12 +1254,16 @@ where
         field_set: FSet,
     ) -> Result<DateTimeFormatter<FSet>, (DateTimeFormatterLoadError, Self)>
     where
-        P: AllAnyCalendarPatternDataMarkers<FSet> + ?Sized,
+        P: AllAnyCalendarPatternDataMarkers<FSet>
+            + DataProvider<CalendarWeekV2>
+            + DataProvider<PluralsOrdinalV1>
+            + ?Sized,
     {
         DateTimeFormatter::try_new_internal_with_calendar_and_names(
             provider,
             &EmptyDataProvider,
             &ExternalLoaderUnstable(&EmptyDataProvider), // for decimals only
+            &ExternalLoaderUnstable(provider), // for week information and ordinal rules only
             self.inner.prefs,
             field_set.get_field(),
             self.calendar,
@@ -1269,6 +1288,7 @@ where
             &provider.as_deserializing(),
             &EmptyDataProvider,
             &ExternalLoaderUnstable(&EmptyDataProvider), // for decimals only
+            &ExternalLoaderBuffer(provider), // for week information and ordinal rules only
             self.inner.prefs,
             field_set.get_field(),
             self.calendar,
@@ -2514,6 +2534,64 @@ impl<C, FSet: DateTimeNamesMarker> FixedCalendarDateTimeNames<C, FSet> {
             .load_decimal_formatter(&ExternalLoaderCompiledData, self.prefs)?;
         Ok(self)
     }
+
+    /// Loads a [`WeekInformation`] from a data provider.
+    ///
+    /// This is required for formatting week-of-year, week-of-month,
+    /// and week-based year fields (`w`, `W`, and `Y`).
+    ///
+    #[inline]
+    pub fn load_week_information<P>(&mut self, provider: &P) -> Result<&mut Self, DataError>
+    where
+        P: DataProvider<CalendarWeekV2> + ?Sized,
+    {
+        self.inner
+            .load_week_information(&ExternalLoaderUnstable(provider), self.prefs)?;
+        Ok(self)
+    }
+
+    /// Loads a [`WeekInformation`] with compiled data.
+    ///
+    /// This is required for formatting week-of-year, week-of-month,
+    /// and week-based year fields (`w`, `W`, and `Y`).
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::Gregorian;
+    /// use icu::datetime::fieldsets::enums::DateFieldSet;
+    /// use icu::datetime::input::Date;
+    /// use icu::datetime::pattern::DateTimePattern;
+    /// use icu::datetime::pattern::FixedCalendarDateTimeNames;
+    /// use icu::locale::locale;
+    /// use writeable::assert_try_writeable_eq;
+    ///
+    /// let mut names =
+    ///     FixedCalendarDateTimeNames::<Gregorian, DateFieldSet>::try_new(
+    ///         locale!("de").into(),
+    ///     )
+    ///     .unwrap();
+    /// names.include_week_information().unwrap();
+    ///
+    /// let pattern: DateTimePattern = "'week' w 'of' Y".parse().unwrap();
+    ///
+    /// // January 1, 2021 falls into the last week of 2020 in Germany
+    /// assert_try_writeable_eq!(
+    ///     names
+    ///         .with_pattern_unchecked(&pattern)
+    ///         .format(&Date::try_new_gregorian(2021, 1, 1).unwrap()),
+    ///     "week 53 of 2020",
+    /// );
+    /// ```
+    #[cfg(feature = "compiled_data")]
+    #[inline]
+    pub fn include_week_information(&mut self) -> Result<&mut Self, DataError> {
+        self.inner
+            .load_week_information(&ExternalLoaderCompiledData, self.prefs)?;
+        Ok(self)
+    }
 }
 
 impl<C: CldrCalendar, FSet: DateTimeNamesMarker> FixedCalendarDateTimeNames<C, FSet> {
@@ -2557,6 +2635,7 @@ impl<C: CldrCalendar, FSet: DateTimeNamesMarker> FixedCalendarDateTimeNames<C, F
             + DataProvider<tz::MzPeriodV1>
             + DataProvider<DecimalSymbolsV1>
             + DataProvider<DecimalDigitsV1>
+            + DataProvider<CalendarWeekV2>
             + ?Sized,
     {
         let locale = self.prefs;
@@ -2577,6 +2656,7 @@ impl<C: CldrCalendar, FSet: DateTimeNamesMarker> FixedCalendarDateTimeNames<C, F
             &tz::MzSpecificShortV1::bind(provider),
             &tz::MzPeriodV1::bind(provider),
             &ExternalLoaderUnstable(provider),
+            &ExternalLoaderUnstable(provider),
             locale,
             pattern.iter_items(),
             &mut self.metadata,
@@ -2653,6 +2733,7 @@ impl<C: CldrCalendar, FSet: DateTimeNamesMarker> FixedCalendarDateTimeNames<C, F
             &tz::MzSpecificShortV1::bind(&crate::provider::Baked),
             &tz::MzPeriodV1::bind(&crate::provider::Baked),
             &ExternalLoaderCompiledData,
+            &ExternalLoaderCompiledData,
             locale,
             pattern.iter_items(),
             &mut self.metadata,
@@ -2823,6 +2904,7 @@ impl<FSet: DateTimeNamesMarker> RawDateTimeNames<FSet> {
                 (),
             >>::Container::new_empty(),
             decimal_formatter: None,
+            week_information: None,
             _marker: PhantomData,
         }
     }
@@ -2845,6 +2927,7 @@ impl<FSet: DateTimeNamesMarker> RawDateTimeNames<FSet> {
             mz_specific_short: self.mz_specific_short.get().inner,
             mz_periods: self.mz_periods.get().inner,
             decimal_formatter: self.decimal_formatter.as_ref(),
+            week_information: self.week_information.as_ref(),
         }
     }
 
@@ -3413,6 +3496,18 @@ impl<FSet: DateTimeNamesMarker> RawDateTimeNames<FSet> {
         Ok(())
     }
 
+    pub(crate) fn load_week_information(
+        &mut self,
+        loader: &impl WeekInformationLoader,
+        prefs: DateTimeFormatterPreferences,
+    ) -> Result<(), DataError> {
+        if self.week_information.is_some() {
+            return Ok(());
+        }
+        self.week_information = Some(WeekInformationLoader::load(loader, (&prefs).into())?);
+        Ok(())
+    }
+
     /// Loads all data required for formatting the given [`PatternItem`]s.
     ///
     /// This function has a lot of arguments because many of the arguments are generic,
@@ -3436,11 +3531,13 @@ impl<FSet: DateTimeNamesMarker> RawDateTimeNames<FSet> {
         mz_specific_short_provider: &(impl BoundDataProvider<tz::MzSpecificShortV1> + ?Sized),
         mz_period_provider: &(impl BoundDataProvider<tz::MzPeriodV1> + ?Sized),
         decimal_formatter_loader: &impl DecimalFormatterLoader,
+        week_information_loader: &impl WeekInformationLoader,
         prefs: DateTimeFormatterPreferences,
         pattern_items: impl Iterator<Item = PatternItem>,
         names_metadata: &mut DateTimeNamesMetadata,
     ) -> Result<(), PatternLoadError> {
         let mut numeric_field = None;
+        let mut week_field = None;
 
         for item in pattern_items {
             let PatternItem::Field(field) = item else {
@@ -3509,7 +3606,7 @@ impl<FSet: DateTimeNamesMarker> RawDateTimeNames<FSet> {
                     )?;
                 }
 
-                // a..aaaaa, b..bbbbb
+                // a..aaaaa, b..bbbbb, B..BBBBB
                 (FS::DayPeriod(field_symbol), One | Two | Three | Four | Five) => {
                     self.load_day_period_names(
                         dayperiod_provider,
@@ -3616,6 +3713,11 @@ impl<FSet: DateTimeNamesMarker> RawDateTimeNames<FSet> {
 
                 // y+
                 (FS::Year(Year::Calendar), _) => numeric_field = Some(field),
+                // Y+
+                (FS::Year(Year::WeekOf), _) => {
+                    numeric_field = Some(field);
+                    week_field = Some(field);
+                }
                 // u+
                 (FS::Year(Year::Extended), _) => numeric_field = Some(field),
                 // r+
@@ -3626,6 +3728,17 @@ impl<FSet: DateTimeNamesMarker> RawDateTimeNames<FSet> {
                 // M..MM, L..LL
                 (FS::Month(_), One | Two) => numeric_field = Some(field),
 
+                // w..ww
+                (FS::Week(Week::WeekOfYear), One | Two) => {
+                    numeric_field = Some(field);
+                    week_field = Some(field);
+                }
+                // W
+                (FS::Week(Week::WeekOfMonth), One) => {
+                    numeric_field = Some(field);
+                    week_field = Some(field);
+                }
+
                 // d..dd
                 (FS::Day(Day::DayOfMonth), One | Two) => numeric_field = Some(field),
                 // D..DDD
@@ -3662,6 +3775,11 @@ impl<FSet: DateTimeNamesMarker> RawDateTimeNames<FSet> {
                 .map_err(|e| PatternLoadError::Data(e, ErrorField(field)))?;
         }
 
+        if let Some(field) = week_field {
+            self.load_week_information(week_information_loader, prefs)
+                .map_err(|e| PatternLoadError::Data(e, ErrorField(field)))?;
+        }
+
         Ok(())
     }
 }
@@ -3798,7 +3916,7 @@ impl RawDateTimeNamesBorrowed<'_> {
         hour: icu_time::Hour,
         is_top_of_hour: bool,
     ) -> Result<&str, GetNameForDayPeriodError> {
-        use fields::DayPeriod::NoonMidnight;
+        use fields::DayPeriod::{Flexible, NoonMidnight};
         let day_period_name_length = DayPeriodNameLength::from_field(field_symbol, field_length)
             .ok_or(GetNameForDayPeriodError::InvalidFieldLength)?;
         let dayperiod_names = self
@@ -3807,7 +3925,13 @@ impl RawDateTimeNamesBorrowed<'_> {
             .ok_or(GetNameForDayPeriodError::NotLoaded)?;
         let option_value: Option<&str> = match (field_symbol, u8::from(hour), is_top_of_hour) {
             (NoonMidnight, 00, true) => dayperiod_names.midnight().or_else(|| dayperiod_names.am()),
-            (NoonMidnight, 12, true) => dayperiod_names.noon().or_else(|| dayperiod_names.pm()),
+            (NoonMidnight | Flexible, 12, true) if dayperiod_names.noon().is_some() => {
+                dayperiod_names.noon()
+            }
+            // UTS 35: midnight is not used for flexible day periods
+            (Flexible, hour, _) if dayperiod_names.flexible(hour).is_some() => {
+                dayperiod_names.flexible(hour)
+            }
             (_, hour, _) if hour < 12 => dayperiod_names.am(),
             _ => dayperiod_names.pm(),
         };
@@ -140,9 +140,7 @@ impl Bag {
             fields.push(Field {
                 symbol: FieldSymbol::Year(match year {
                     Year::Numeric | Year::TwoDigit => fields::Year::Calendar,
-                    Year::NumericWeekOf | Year::TwoDigitWeekOf => {
-                        unimplemented!("#5643 fields::Year::WeekOf")
-                    }
+                    Year::NumericWeekOf | Year::TwoDigitWeekOf => fields::Year::WeekOf,
                 }),
                 length: match year {
                     // Calendar year (numeric).
@@ -182,13 +180,10 @@ impl Bag {
         }
 
         if let Some(week) = self.week {
-            #[allow(unreachable_code)]
             fields.push(Field {
                 symbol: FieldSymbol::Week(match week {
-                    Week::WeekOfMonth => unimplemented!("#5643 fields::Week::WeekOfMonth"),
-                    Week::NumericWeekOfYear | Week::TwoDigitWeekOfYear => {
-                        unimplemented!("#5643 fields::Week::WeekOfYear")
-                    }
+                    Week::WeekOfMonth => fields::Week::WeekOfMonth,
+                    Week::NumericWeekOfYear | Week::TwoDigitWeekOfYear => fields::Week::WeekOfYear,
                 }),
                 length: match week {
                     Week::WeekOfMonth | Week::NumericWeekOfYear => FieldLength::One,
@@ -623,10 +618,10 @@ impl Bag {
                             FieldLength::Two => Year::TwoDigit,
                             _ => Year::Numeric,
                         },
-                        // fields::Year::WeekOf => match field.length {
-                        //     FieldLength::TwoDigit => Year::TwoDigitWeekOf,
-                        //     _ => Year::NumericWeekOf,
-                        // },
+                        fields::Year::WeekOf => match field.length {
+                            FieldLength::Two => Year::TwoDigitWeekOf,
+                            _ => Year::NumericWeekOf,
+                        },
                         // TODO(#3762): Add support for U and r
                         _ => Year::Numeric,
                     });
@@ -643,15 +638,14 @@ impl Bag {
                         FieldLength::Five | FieldLength::Six => Month::Narrow,
                     });
                 }
-                FieldSymbol::Week(_week) => {
-                    // TODO(#5643): Add week fields back
-                    // bag.week = Some(match week {
-                    //     fields::Week::WeekOfYear => match field.length {
-                    //         FieldLength::TwoDigit => Week::TwoDigitWeekOfYear,
-                    //         _ => Week::NumericWeekOfYear,
-                    //     },
-                    //     fields::Week::WeekOfMonth => Week::WeekOfMonth,
-                    // });
+                FieldSymbol::Week(week) => {
+                    bag.week = Some(match week {
+                        fields::Week::WeekOfYear => match field.length {
+                            FieldLength::Two => Week::TwoDigitWeekOfYear,
+                            _ => Week::NumericWeekOfYear,
+                        },
+                        fields::Week::WeekOfMonth => Week::WeekOfMonth,
+                    });
                 }
                 FieldSymbol::Day(day) => {
                     bag.day = Some(match day {
@@ -791,8 +785,7 @@ mod test {
         let bag = Bag {
             year: Some(Year::Numeric),
             month: Some(Month::Long),
-            // TODO(#5643): Add week fields back
-            week: None,
+            week: Some(Week::NumericWeekOfYear),
             day: Some(Day::NumericDayOfMonth),
 
             hour: Some(Numeric::Numeric),
@@ -807,6 +800,7 @@ mod test {
             [
                 (Symbol::Year(fields::Year::Calendar), Length::One).into(),
                 (Symbol::Month(fields::Month::Format), Length::Four).into(),
+                (Symbol::Week(fields::Week::WeekOfYear), Length::One).into(),
                 (Symbol::Day(fields::Day::DayOfMonth), Length::One).into(),
                 (Symbol::Hour(fields::Hour::H23), Length::One).into(),
                 (Symbol::Minute, Length::One).into(),
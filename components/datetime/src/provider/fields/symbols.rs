@@ -276,31 +276,30 @@ impl FieldSymbol {
         match self {
             Self::Era => 0,
             Self::Year(Year::Calendar) => 1,
-            // Self::Year(Year::WeekOf) => 2,
-            Self::Year(Year::Extended) => 2,
-            Self::Year(Year::Cyclic) => 3,
-            Self::Year(Year::RelatedIso) => 4,
-            Self::Month(Month::Format) => 5,
-            Self::Month(Month::StandAlone) => 6,
-            // TODO(#5643): Add week fields back
-            // Self::Week(Week::WeekOfYear) => 7,
-            // Self::Week(Week::WeekOfMonth) => 8,
-            Self::Week(_) => unreachable!(), // ZST references aren't uninhabited
-            Self::Day(Day::DayOfMonth) => 9,
-            Self::Day(Day::DayOfYear) => 10,
-            Self::Day(Day::DayOfWeekInMonth) => 11,
-            Self::Day(Day::ModifiedJulianDay) => 12,
-            Self::Weekday(Weekday::Format) => 13,
-            Self::Weekday(Weekday::Local) => 14,
-            Self::Weekday(Weekday::StandAlone) => 15,
-            Self::DayPeriod(DayPeriod::AmPm) => 16,
-            Self::DayPeriod(DayPeriod::NoonMidnight) => 17,
-            Self::Hour(Hour::H11) => 18,
-            Self::Hour(Hour::H12) => 19,
-            Self::Hour(Hour::H23) => 20,
-            Self::Minute => 22,
-            Self::Second(Second::Second) => 23,
-            Self::Second(Second::MillisInDay) => 24,
+            Self::Year(Year::WeekOf) => 2,
+            Self::Year(Year::Extended) => 3,
+            Self::Year(Year::Cyclic) => 4,
+            Self::Year(Year::RelatedIso) => 5,
+            Self::Month(Month::Format) => 6,
+            Self::Month(Month::StandAlone) => 7,
+            Self::Week(Week::WeekOfYear) => 8,
+            Self::Week(Week::WeekOfMonth) => 9,
+            Self::Day(Day::DayOfMonth) => 10,
+            Self::Day(Day::DayOfYear) => 11,
+            Self::Day(Day::DayOfWeekInMonth) => 12,
+            Self::Day(Day::ModifiedJulianDay) => 13,
+            Self::Weekday(Weekday::Format) => 14,
+            Self::Weekday(Weekday::Local) => 15,
+            Self::Weekday(Weekday::StandAlone) => 16,
+            Self::DayPeriod(DayPeriod::AmPm) => 17,
+            Self::DayPeriod(DayPeriod::NoonMidnight) => 18,
+            Self::DayPeriod(DayPeriod::Flexible) => 19,
+            Self::Hour(Hour::H11) => 20,
+            Self::Hour(Hour::H12) => 21,
+            Self::Hour(Hour::H23) => 22,
+            Self::Minute => 23,
+            Self::Second(Second::Second) => 24,
+            Self::Second(Second::MillisInDay) => 25,
             Self::DecimalSecond(DecimalSecond::Subsecond1) => 31,
             Self::DecimalSecond(DecimalSecond::Subsecond2) => 32,
             Self::DecimalSecond(DecimalSecond::Subsecond3) => 33,
@@ -502,7 +501,7 @@ field_type! (
     Year; {
         /// Field symbol for calendar year (numeric).
         ///
-        /// In most cases the length of this field specifies the minimum number of digits to display, zero-padded as necessary. For most use cases, [`Year::Calendar`] or `Year::WeekOf` should be adequate.
+        /// In most cases the length of this field specifies the minimum number of digits to display, zero-padded as necessary. For most use cases, [`Year::Calendar`] or [`Year::WeekOf`] should be adequate.
         'y' => Calendar = 0,
         /// Field symbol for cyclic year; used in calendars where years are tracked in cycles, such as the Chinese or Dangi calendars.
         'U' => Cyclic = 1,
@@ -510,10 +509,10 @@ field_type! (
         'r' => RelatedIso = 2,
         /// Field symbol for extended year
         'u' => Extended = 3,
-        // /// Field symbol for year in "week of year".
-        // ///
-        // /// This works for “week of year” based calendars in which the year transition occurs on a week boundary; may differ from calendar year [`Year::Calendar`] near a year transition. This numeric year designation is used in conjunction with [`Week::WeekOfYear`], but can be used in non-Gregorian based calendar systems where week date processing is desired. The field length is interpreted in the same way as for [`Year::Calendar`].
-        // 'Y' => WeekOf = 4,
+        /// Field symbol for year in "week of year".
+        ///
+        /// This works for “week of year” based calendars in which the year transition occurs on a week boundary; may differ from calendar year [`Year::Calendar`] near a year transition. This numeric year designation is used in conjunction with [`Week::WeekOfYear`], but can be used in non-Gregorian based calendar systems where week date processing is desired. The field length is interpreted in the same way as for [`Year::Calendar`].
+        'Y' => WeekOf = 4,
     };
     YearULE
 );
@@ -620,60 +619,17 @@ field_type!(
 field_type!(
     /// An enum for the possible symbols of a week field in a date pattern.
     Week; {
-        // /// Field symbol for week of year (numeric).
-        // ///
-        // /// When used in a pattern with year, use [`Year::WeekOf`] for the year field instead of [`Year::Calendar`].
-        // 'w' => WeekOfYear = 0,
-        // /// Field symbol for week of month (numeric).
-        // 'W' => WeekOfMonth = 1,
+        /// Field symbol for week of year (numeric).
+        ///
+        /// When used in a pattern with year, use [`Year::WeekOf`] for the year field instead of [`Year::Calendar`].
+        'w' => WeekOfYear = 0,
+        /// Field symbol for week of month (numeric).
+        'W' => WeekOfMonth = 1,
     };
     Numeric;
-    // TODO(#5643): Recover ULE once the type is inhabited
-    // WeekULE
+    WeekULE
 );
 
-impl Week {
-    /// Retrieves an index of the field variant.
-    ///
-    /// # Examples
-    ///
-    /// ```ignore
-    /// use icu::datetime::fields::Month;
-    ///
-    /// assert_eq!(Month::StandAlone::idx(), 1);
-    /// ```
-    ///
-    /// # Stability
-    ///
-    /// This is mostly useful for serialization,
-    /// and does not guarantee index stability between ICU4X
-    /// versions.
-    #[inline]
-    pub(crate) fn idx(self) -> u8 {
-        0
-    }
-
-    /// Retrieves a field variant from an index.
-    ///
-    /// # Examples
-    ///
-    /// ```ignore
-    /// use icu::datetime::fields::Month;
-    ///
-    /// assert_eq!(Month::from_idx(0), Month::Format);
-    /// ```
-    ///
-    /// # Stability
-    ///
-    /// This is mostly useful for serialization,
-    /// and does not guarantee index stability between ICU4X
-    /// versions.
-    #[inline]
-    pub(crate) fn from_idx(idx: u8) -> Result<Self, SymbolError> {
-        Err(SymbolError::InvalidIndex(idx))
-    }
-}
-
 field_type!(
     /// An enum for the possible symbols of a weekday field in a date pattern.
     Weekday;  {
@@ -723,6 +679,10 @@ field_type!(
         'a' => AmPm = 0,
         /// Field symbol for the am, pm, noon, midnight day period.
         'b' => NoonMidnight = 1,
+        /// Field symbol for flexible day periods, such as "in the afternoon" or "at night".
+        ///
+        /// Falls back to the AM, PM day period when the locale has no flexible day period data.
+        'B' => Flexible = 2,
     };
     Text;
     DayPeriodULE
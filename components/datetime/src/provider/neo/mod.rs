@@ -634,6 +634,10 @@ size_test!(LinearNames, linear_names_v1_size, 24);
 /// - For weekdays, element 0 is Sunday
 /// - For dayperiods, the elements are in order: AM, PM, (noon), (midnight), where the latter two are optional.
 ///   In the case noon is missing but midnight is present, the noon value can be the empty string. This is unlikely.
+///   If the locale has flexible day periods, these are followed by an hour map and the flexible day period names
+///   in order: morning1, morning2, afternoon1, afternoon2, evening1, evening2, night1, night2. The hour map is a
+///   24-character string where the character at index `h` is the ASCII digit of the flexible day period in effect
+///   during hour `h`, or `-` if there is none. Flexible day periods missing in the locale are the empty string.
 /// - For day names element 0 is the first day of the month
 ///
 /// This uses a data marker attribute for length. See [`YearNames`] for more information on the scheme.
@@ -678,7 +682,20 @@ impl LinearNames<'_> {
     }
     /// Gets the 'midnight' name assuming this struct contains day period data.
     pub(crate) fn midnight(&self) -> Option<&str> {
-        self.names.get(3)
+        self.names
+            .get(3)
+            .and_then(|s| if s.is_empty() { None } else { Some(s) })
+    }
+    /// Gets the flexible day period name for the given hour of the day, assuming
+    /// this struct contains day period data.
+    pub(crate) fn flexible(&self, hour: u8) -> Option<&str> {
+        let index = match self.names.get(4)?.as_bytes().get(usize::from(hour))? {
+            d @ b'0'..=b'7' => usize::from(d - b'0'),
+            _ => return None,
+        };
+        self.names
+            .get(5 + index)
+            .and_then(|s| if s.is_empty() { None } else { Some(s) })
     }
 }
 
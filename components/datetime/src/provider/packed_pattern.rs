@@ -12,7 +12,7 @@ use crate::{options::Length, size_test_macro::size_test};
 use alloc::vec::Vec;
 use icu_plurals::{
     provider::{FourBitMetadata, PluralElementsPackedULE},
-    PluralElements,
+    PluralElements, PluralOperands, PluralRules,
 };
 use icu_provider::prelude::*;
 use zerovec::{VarZeroVec, ZeroSlice};
@@ -352,13 +352,9 @@ impl<'a> UnpackedPatterns<'a> {
             .elements
             .iter()
             .map(|plural_elements| {
-                plural_elements.decode().map(|(metadata, items)| {
-                    PatternBorrowed {
-                        metadata: PatternMetadata::from_u8(metadata.get()),
-                        items,
-                    }
-                    .as_pattern()
-                })
+                plural_elements
+                    .decode()
+                    .map(|value| to_pattern_borrowed(value).as_pattern())
             })
             .collect();
         Self {
@@ -370,6 +366,15 @@ impl<'a> UnpackedPatterns<'a> {
     }
 }
 
+fn to_pattern_borrowed(
+    (metadata, items): (FourBitMetadata, &ZeroSlice<PatternItem>),
+) -> PatternBorrowed<'_> {
+    PatternBorrowed {
+        metadata: PatternMetadata::from_u8(metadata.get()),
+        items,
+    }
+}
+
 impl PackedPatternsBuilder<'_> {
     /// Builds a packed pattern representation from the builder.
     pub fn build(mut self) -> PackedPatterns<'static> {
@@ -464,6 +469,7 @@ impl PackedPatternsBuilder<'_> {
 }
 
 /// Which pattern to select. For details, see [`PackedPatterns`].
+#[derive(Debug, Copy, Clone)]
 pub(crate) enum PackedSkeletonVariant {
     /// Default-precision year OR hours only
     Standard,
@@ -479,6 +485,38 @@ impl PackedPatterns<'_> {
         length: Length,
         variant: PackedSkeletonVariant,
     ) -> PatternBorrowed<'_> {
+        let Some(plural_elements) = self.get_plural_elements(length, variant) else {
+            return PatternBorrowed::DEFAULT;
+        };
+        to_pattern_borrowed(plural_elements.get_default())
+    }
+
+    /// Returns the plural variant of the pattern for the given [`PluralOperands`].
+    pub(crate) fn get_for_plural_operands(
+        &self,
+        length: Length,
+        variant: PackedSkeletonVariant,
+        operands: PluralOperands,
+        rules: &PluralRules,
+    ) -> PatternBorrowed<'_> {
+        let Some(plural_elements) = self.get_plural_elements(length, variant) else {
+            return PatternBorrowed::DEFAULT;
+        };
+        to_pattern_borrowed(plural_elements.get(operands, rules))
+    }
+
+    /// Whether any of the patterns have plural variants.
+    pub(crate) fn has_plural_variants(&self) -> bool {
+        self.elements
+            .iter()
+            .any(|plural_elements| plural_elements.decode().try_into_other().is_none())
+    }
+
+    fn get_plural_elements(
+        &self,
+        length: Length,
+        variant: PackedSkeletonVariant,
+    ) -> Option<&PluralElementsPackedULE<ZeroSlice<PatternItem>>> {
         use Length::*;
         use PackedSkeletonVariant::*;
         let lms = self.header & constants::LMS_MASK;
@@ -510,13 +548,13 @@ impl PackedPatterns<'_> {
                     (Short, Variant1) => self.header >> 18,
                     (_, Standard) => {
                         debug_assert!(false, "unreachable");
-                        return PatternBorrowed::DEFAULT;
+                        return None;
                     }
                 };
                 let chunk = chunk_in_low_bits & constants::CHUNK_MASK;
                 if chunk == 0 {
                     // Fall back to standard with the same length
-                    return self.get(length, Standard);
+                    return self.get_plural_elements(length, Standard);
                 }
                 chunk - 1
             } else {
@@ -530,7 +568,7 @@ impl PackedPatterns<'_> {
                     (Short, Variant1) => 6,
                     (_, Standard) => {
                         debug_assert!(false, "unreachable");
-                        return PatternBorrowed::DEFAULT;
+                        return None;
                     }
                 };
                 s_offset + additional_offset
@@ -538,13 +576,9 @@ impl PackedPatterns<'_> {
         };
         let Some(plural_elements) = self.elements.get(pattern_index as usize) else {
             debug_assert!(false, "unreachable");
-            return PatternBorrowed::DEFAULT;
+            return None;
         };
-        let (metadata, items) = plural_elements.get_default();
-        PatternBorrowed {
-            metadata: PatternMetadata::from_u8(metadata.get()),
-            items,
-        }
+        Some(plural_elements)
     }
 
     fn get_as_plural_elements(
@@ -552,7 +586,12 @@ impl PackedPatterns<'_> {
         length: Length,
         variant: PackedSkeletonVariant,
     ) -> PluralElements<Pattern<'_>> {
-        PluralElements::new(self.get(length, variant).as_pattern())
+        match self.get_plural_elements(length, variant) {
+            Some(plural_elements) => plural_elements
+                .decode()
+                .map(|value| to_pattern_borrowed(value).as_pattern()),
+            None => PluralElements::new(PatternBorrowed::DEFAULT.as_pattern()),
+        }
     }
 
     /// Converts this packed data to a builder that can be mutated.
@@ -619,7 +658,89 @@ mod _serde {
             serde(default, skip_serializing_if = "Option::is_none")
         )]
         pub(super) variant_pattern_indices: Option<[u32; 6]>,
-        pub(super) elements: Vec<reference::Pattern>,
+        pub(super) elements: Vec<PatternPluralsHuman>,
+    }
+
+    /// Either a single pattern or a map from plural category to pattern.
+    #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+    #[cfg_attr(feature = "datagen", derive(serde::Serialize))]
+    #[cfg_attr(feature = "serde", serde(untagged))]
+    enum PatternPluralsHuman {
+        SinglePattern(reference::Pattern),
+        MultipleVariants(PluralPatternsHuman),
+    }
+
+    #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+    #[cfg_attr(feature = "datagen", derive(serde::Serialize))]
+    struct PluralPatternsHuman {
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        zero: Option<reference::Pattern>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        one: Option<reference::Pattern>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        two: Option<reference::Pattern>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        few: Option<reference::Pattern>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        many: Option<reference::Pattern>,
+        other: reference::Pattern,
+    }
+
+    impl PatternPluralsHuman {
+        fn to_plural_elements(&self) -> PluralElements<Pattern<'static>> {
+            match self {
+                Self::SinglePattern(pattern) => PluralElements::new(pattern.to_runtime_pattern()),
+                Self::MultipleVariants(variants) => {
+                    PluralElements::new(variants.other.to_runtime_pattern())
+                        .with_zero_value(variants.zero.as_ref().map(|p| p.to_runtime_pattern()))
+                        .with_one_value(variants.one.as_ref().map(|p| p.to_runtime_pattern()))
+                        .with_two_value(variants.two.as_ref().map(|p| p.to_runtime_pattern()))
+                        .with_few_value(variants.few.as_ref().map(|p| p.to_runtime_pattern()))
+                        .with_many_value(variants.many.as_ref().map(|p| p.to_runtime_pattern()))
+                }
+            }
+        }
+
+        #[cfg(feature = "datagen")]
+        fn from_plural_elements(elements: &PluralElements<Pattern>) -> Self {
+            let other = elements.other();
+            let variant =
+                |pattern: &Pattern| (pattern != other).then(|| reference::Pattern::from(pattern));
+            let variants = PluralPatternsHuman {
+                zero: variant(elements.zero()),
+                one: variant(elements.one()),
+                two: variant(elements.two()),
+                few: variant(elements.few()),
+                many: variant(elements.many()),
+                other: reference::Pattern::from(other),
+            };
+            match variants {
+                PluralPatternsHuman {
+                    zero: None,
+                    one: None,
+                    two: None,
+                    few: None,
+                    many: None,
+                    other,
+                } => Self::SinglePattern(other),
+                variants => Self::MultipleVariants(variants),
+            }
+        }
     }
 
     impl<'de, 'data> serde::Deserialize<'de> for PackedPatterns<'data>
@@ -652,7 +773,7 @@ mod _serde {
                 let elements = human
                     .elements
                     .iter()
-                    .map(|pattern| PluralElements::new(pattern.to_runtime_pattern()))
+                    .map(PatternPluralsHuman::to_plural_elements)
                     .collect();
                 let unpacked = UnpackedPatterns {
                     has_explicit_medium: human.has_explicit_medium,
@@ -677,7 +798,6 @@ mod _serde {
         where
             S: serde::Serializer,
         {
-            use serde::ser::Error as _;
             if serializer.is_human_readable() {
                 let unpacked = UnpackedPatterns::from_packed(self);
                 let mut human = PackedPatternsHuman {
@@ -694,13 +814,11 @@ mod _serde {
                         human.variant_pattern_indices = Some(chunks);
                     }
                 }
-                human.elements = Vec::with_capacity(unpacked.elements.len());
-                for pattern_elements in unpacked.elements.into_iter() {
-                    let pattern = pattern_elements
-                        .try_into_other()
-                        .ok_or_else(|| S::Error::custom("cannot yet serialize plural patterns"))?;
-                    human.elements.push(reference::Pattern::from(&pattern));
-                }
+                human.elements = unpacked
+                    .elements
+                    .iter()
+                    .map(PatternPluralsHuman::from_plural_elements)
+                    .collect();
                 human.serialize(serializer)
             } else {
                 let machine = PackedPatternsMachine {
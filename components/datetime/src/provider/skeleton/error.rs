@@ -64,13 +64,8 @@ impl From<fields::SymbolError> for SkeletonError {
                 // NOTE: If you remove a symbol due to it now being supported,
                 //       make sure to regenerate data: cargo make bakeddata components/datetime.
                 match ch {
-                    // TODO(#487) - Flexible day periods
-                    'B'
                     // TODO(#501) - Quarters
-                    | 'Q' | 'q'
-                    // TODO(#5643) - Weeks
-                    | 'Y' | 'w' | 'W'
-                    => Self::SymbolUnimplemented(ch),
+                    'Q' | 'q' => Self::SymbolUnimplemented(ch),
                     _ => Self::SymbolUnknown(ch),
                 }
             }
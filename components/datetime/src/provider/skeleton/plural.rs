@@ -217,8 +217,6 @@ mod test {
     use super::*;
 
     #[test]
-    #[ignore] // TODO(#5643)
-    #[allow(unreachable_code, unused_variables, unused_mut)]
     fn build_plural_pattern() {
         let red_pattern: Pattern = "'red' w".parse().unwrap();
         let blue_pattern: Pattern = "'blue' w".parse().unwrap();
@@ -230,7 +228,7 @@ mod test {
         patterns.maybe_set_variant(PluralCategory::Few, red_pattern.clone());
         patterns.maybe_set_variant(PluralCategory::Many, blue_pattern.clone());
 
-        // assert_eq!(patterns.pivot_field, Week::WeekOfYear);
+        assert_eq!(patterns.pivot_field, Week::WeekOfYear);
         assert_eq!(patterns.zero, Some(red_pattern.clone()));
         assert_eq!(patterns.one, None); // duplicate `other
         assert_eq!(patterns.two, Some(red_pattern));
@@ -238,8 +236,6 @@ mod test {
     }
 
     #[test]
-    #[ignore] // TODO(#5643)
-    #[allow(unreachable_code, unused_variables)]
     fn normalize_pattern_plurals_switches_singletons_to_single_pattern() {
         let pattern: Pattern = "'red' w".parse().unwrap();
         let patterns = PluralPattern::new(pattern.clone()).expect("PluralPattern::new failed");
@@ -2,6 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use crate::external_loaders::OrdinalRulesLoader;
 use crate::fieldsets::builder;
 use crate::fieldsets::enums::{CompositeFieldSet, TimeFieldSet, ZoneFieldSet};
 use crate::format::DateTimeInputUnchecked;
@@ -15,6 +16,8 @@ use crate::provider::pattern::{
 use crate::provider::{neo::*, ErasedPackedPatterns, PackedSkeletonVariant};
 use crate::DateTimeFormatterPreferences;
 use icu_calendar::types::YearAmbiguity;
+use icu_calendar::week::WeekInformation;
+use icu_plurals::PluralRules;
 use icu_provider::prelude::*;
 use icu_provider::DataPayloadOr;
 use marker_attrs::GlueType;
@@ -57,6 +60,8 @@ impl RawPreferences {
 #[derive(Debug, Clone)]
 pub(crate) struct DatePatternSelectionData {
     payload: DataPayloadOr<ErasedPackedPatterns, ()>,
+    /// Ordinal rules for selecting plural variants of week patterns, if there are any.
+    ordinal_rules: Option<PluralRules>,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -135,11 +140,13 @@ impl DatePatternSelectionData {
     pub(crate) fn none() -> Self {
         Self {
             payload: DataPayloadOr::none(),
+            ordinal_rules: None,
         }
     }
 
     pub(crate) fn try_new_with_skeleton(
         provider: &(impl BoundDataProvider<ErasedPackedPatterns> + ?Sized),
+        ordinal_rules_loader: &impl OrdinalRulesLoader,
         prefs: DateTimeFormatterPreferences,
         attributes: &DataMarkerAttributes,
     ) -> Result<Self, DataError> {
@@ -152,8 +159,17 @@ impl DatePatternSelectionData {
                 ..Default::default()
             })?
             .payload;
+        let ordinal_rules = if payload.get().has_plural_variants() {
+            Some(OrdinalRulesLoader::load(
+                ordinal_rules_loader,
+                (&prefs).into(),
+            )?)
+        } else {
+            None
+        };
         Ok(Self {
             payload: DataPayloadOr::from_payload(payload),
+            ordinal_rules,
         })
     }
 
@@ -177,6 +193,7 @@ impl DatePatternSelectionData {
         &self,
         input: &DateTimeInputUnchecked,
         options: RawOptions,
+        week_information: Option<&WeekInformation>,
     ) -> Option<DatePatternDataBorrowed<'_>> {
         let payload = self.payload.get_option()?;
         let year_style = options.year_style.unwrap_or_default();
@@ -201,14 +218,55 @@ impl DatePatternSelectionData {
                 PackedSkeletonVariant::Standard
             }
         };
+        let mut pattern = payload.get(options.length(), variant);
+        if let (Some(ordinal_rules), Some(week_information)) =
+            (self.ordinal_rules.as_ref(), week_information)
+        {
+            if let Some(week) = input.week_number_for_plurals(pattern.items, *week_information) {
+                pattern = payload.get_for_plural_operands(
+                    options.length(),
+                    variant,
+                    week.into(),
+                    ordinal_rules,
+                );
+            }
+        }
         Some(DatePatternDataBorrowed::Resolved(
-            payload.get(options.length(), variant),
+            pattern,
             options.alignment,
         ))
     }
 }
 
 impl DateTimeInputUnchecked {
+    /// The number of the week field in the pattern, which selects its plural variant.
+    fn week_number_for_plurals(
+        &self,
+        items: &ZeroSlice<PatternItem>,
+        week_information: WeekInformation,
+    ) -> Option<u8> {
+        let week = items.iter().find_map(|item| match item {
+            PatternItem::Field(Field {
+                symbol: FieldSymbol::Week(week),
+                ..
+            }) => Some(week),
+            _ => None,
+        })?;
+        let iso_weekday = self.iso_weekday?;
+        Some(match week {
+            fields::Week::WeekOfYear => {
+                week_information
+                    .week_of_year(self.day_of_year?, iso_weekday)
+                    .week
+            }
+            fields::Week::WeekOfMonth => {
+                week_information
+                    .week_of_month(self.day_of_month?, iso_weekday)
+                    .0
+            }
+        })
+    }
+
     fn resolve_time_precision(
         &self,
         time_precision: TimePrecision,
@@ -404,6 +462,7 @@ impl DateTimeZonePatternSelectionData {
         date_provider: &(impl BoundDataProvider<ErasedPackedPatterns> + ?Sized),
         time_provider: &(impl BoundDataProvider<ErasedPackedPatterns> + ?Sized),
         glue_provider: &(impl BoundDataProvider<DatetimePatternsGlueV1> + ?Sized),
+        ordinal_rules_loader: &impl OrdinalRulesLoader,
         prefs: DateTimeFormatterPreferences,
         skeleton: CompositeFieldSet,
     ) -> Result<Self, DataError> {
@@ -412,6 +471,7 @@ impl DateTimeZonePatternSelectionData {
                 let options = field_set.to_raw_options();
                 let selection = DatePatternSelectionData::try_new_with_skeleton(
                     date_provider,
+                    ordinal_rules_loader,
                     prefs,
                     field_set.id_str(),
                 )?;
@@ -428,6 +488,7 @@ impl DateTimeZonePatternSelectionData {
                 let options = field_set.to_raw_options();
                 let selection = DatePatternSelectionData::try_new_with_skeleton(
                     date_provider,
+                    ordinal_rules_loader,
                     prefs,
                     field_set.id_str(),
                 )?;
@@ -502,6 +563,7 @@ impl DateTimeZonePatternSelectionData {
                 }
                 let date = DatePatternSelectionData::try_new_with_skeleton(
                     date_provider,
+                    ordinal_rules_loader,
                     prefs,
                     field_set.to_date_field_set().id_str(),
                 )?;
@@ -525,6 +587,7 @@ impl DateTimeZonePatternSelectionData {
                 let options = combo.dt().to_raw_options();
                 let date = DatePatternSelectionData::try_new_with_skeleton(
                     date_provider,
+                    ordinal_rules_loader,
                     prefs,
                     combo.dt().id_str(),
                 )?;
@@ -562,6 +625,7 @@ impl DateTimeZonePatternSelectionData {
                 let options = combo.dt().to_raw_options();
                 let date = DatePatternSelectionData::try_new_with_skeleton(
                     date_provider,
+                    ordinal_rules_loader,
                     prefs,
                     combo.dt().to_date_field_set().id_str(),
                 )?;
@@ -636,9 +700,10 @@ impl DateTimeZonePatternSelectionData {
     pub(crate) fn select(
         &self,
         input: &DateTimeInputUnchecked,
+        week_information: Option<&WeekInformation>,
     ) -> DateTimeZonePatternDataBorrowed<'_> {
         DateTimeZonePatternDataBorrowed {
-            date: self.date.select(input, self.options),
+            date: self.date.select(input, self.options, week_information),
             time: self.time.select(input, self.options, self.prefs),
             zone: self.zone.as_ref().map(|zone| zone.select(input)),
             glue: self.glue.as_ref().map(|glue| glue.get()),
@@ -751,7 +816,7 @@ impl<'a> ItemsAndOptions<'a> {
                             field.symbol,
                             FieldSymbol::Month(_)
                                 | FieldSymbol::Day(_)
-                                | FieldSymbol::Week(_)
+                                | FieldSymbol::Week(fields::Week::WeekOfYear)
                                 | FieldSymbol::Hour(_)
                         )
                     {
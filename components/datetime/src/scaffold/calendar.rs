@@ -109,6 +109,18 @@ impl CldrCalendar for JapaneseExtended {
     type SkeletaV1 = DatetimePatternsDateJapanextV1;
 }
 
+impl CldrCalendar for Julian {
+    type YearNamesV1 = DatetimeNamesYearGregorianV1;
+    type MonthNamesV1 = DatetimeNamesMonthGregorianV1;
+    type SkeletaV1 = DatetimePatternsDateGregorianV1;
+}
+
+impl CldrCalendar for JulianGregorian {
+    type YearNamesV1 = DatetimeNamesYearGregorianV1;
+    type MonthNamesV1 = DatetimeNamesMonthGregorianV1;
+    type SkeletaV1 = DatetimePatternsDateGregorianV1;
+}
+
 impl CldrCalendar for Persian {
     type YearNamesV1 = DatetimeNamesYearPersianV1;
     type MonthNamesV1 = DatetimeNamesMonthPersianV1;
@@ -121,6 +133,12 @@ impl CldrCalendar for Roc {
     type SkeletaV1 = DatetimePatternsDateRocV1;
 }
 
+impl CldrCalendar for LunarChinese<chinese::Vietnamese> {
+    type YearNamesV1 = DatetimeNamesYearChineseV1;
+    type MonthNamesV1 = DatetimeNamesMonthChineseV1;
+    type SkeletaV1 = DatetimePatternsDateChineseV1;
+}
+
 impl UnstableSealed for () {}
 impl UnstableSealed for Buddhist {}
 impl UnstableSealed for LunarChinese<chinese::China> {}
@@ -133,8 +151,11 @@ impl UnstableSealed for Indian {}
 impl<S: hijri::HijriSighting> UnstableSealed for Hijri<S> {}
 impl UnstableSealed for Japanese {}
 impl UnstableSealed for JapaneseExtended {}
+impl UnstableSealed for Julian {}
+impl UnstableSealed for JulianGregorian {}
 impl UnstableSealed for Persian {}
 impl UnstableSealed for Roc {}
+impl UnstableSealed for LunarChinese<chinese::Vietnamese> {}
 
 /// A collection of marker types associated with all formattable calendars.
 ///
@@ -222,6 +243,7 @@ impl IntoFormattableAnyCalendar for LunarChinese<chinese::Dangi> {}
 impl IntoFormattableAnyCalendar for Ethiopian {}
 impl IntoFormattableAnyCalendar for Gregorian {}
 impl IntoFormattableAnyCalendar for Hebrew {}
+// _NOT_ HinduLunisolar, HinduSolar
 impl IntoFormattableAnyCalendar for Indian {}
 impl IntoFormattableAnyCalendar for Hijri<hijri::TabularAlgorithm> {}
 impl IntoFormattableAnyCalendar for Hijri<hijri::AstronomicalSimulation> {}
@@ -229,8 +251,11 @@ impl IntoFormattableAnyCalendar for Hijri<hijri::UmmAlQura> {}
 // _NOT_ Hijri<S>
 impl IntoFormattableAnyCalendar for Japanese {}
 // _NOT_ JapaneseExtended
+impl IntoFormattableAnyCalendar for Julian {}
+impl IntoFormattableAnyCalendar for JulianGregorian {}
 impl IntoFormattableAnyCalendar for Persian {}
 impl IntoFormattableAnyCalendar for Roc {}
+impl IntoFormattableAnyCalendar for LunarChinese<chinese::Vietnamese> {}
 
 // keep in sync with IntoFormattableAnyCalendar
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -243,6 +268,7 @@ pub(crate) enum FormattableAnyCalendarKind {
     EthiopianAmeteAlem,
     Gregorian,
     Hebrew,
+    // _NOT_ HinduLunisolar, HinduSolar
     Indian,
     HijriTabularTypeIIFriday,
     // _NOT_ HijriSimulatedMecca
@@ -250,8 +276,11 @@ pub(crate) enum FormattableAnyCalendarKind {
     HijriUmmAlQura,
     Japanese,
     // _NOT_ JapaneseExtended
+    Julian,
+    JulianGregorian,
     Persian,
     Roc,
+    Vietnamese,
 }
 
 impl FormattableAnyCalendarKind {
@@ -266,6 +295,7 @@ impl FormattableAnyCalendarKind {
             EthiopianAmeteAlem => Self::EthiopianAmeteAlem,
             Gregorian => Self::Gregorian,
             Hebrew => Self::Hebrew,
+            HinduLunisolar | HinduLunisolarPurnimanta | HinduSolar => return None,
             Indian => Self::Indian,
             HijriTabularTypeIIFriday => Self::HijriTabularTypeIIFriday,
             HijriSimulatedMecca => return None,
@@ -274,8 +304,11 @@ impl FormattableAnyCalendarKind {
             Iso => return None,
             Japanese => Self::Japanese,
             JapaneseExtended => return None,
+            Julian => Self::Julian,
+            JulianGregorian => Self::JulianGregorian,
             Persian => Self::Persian,
             Roc => Self::Roc,
+            Vietnamese => Self::Vietnamese,
             _ => {
                 debug_assert!(false, "cross-crate exhaustive match");
                 return None;
@@ -377,8 +410,11 @@ impl FormattableAnyCalendar {
             )),
             HijriUmmAlQura => AnyCalendar::HijriUmmAlQura(cal::Hijri::new_umm_al_qura()),
             Japanese => AnyCalendar::Japanese(cal::Japanese::new()),
+            Julian => AnyCalendar::Julian(cal::Julian),
+            JulianGregorian => AnyCalendar::JulianGregorian(cal::JulianGregorian::new()),
             Persian => AnyCalendar::Persian(cal::Persian),
             Roc => AnyCalendar::Roc(cal::Roc),
+            Vietnamese => AnyCalendar::Vietnamese(cal::LunarChinese::new_vietnamese()),
         };
         Ok(Self { any_calendar, kind })
     }
@@ -416,8 +452,11 @@ impl FormattableAnyCalendar {
             Japanese => {
                 AnyCalendar::Japanese(cal::Japanese::try_new_with_buffer_provider(provider)?)
             }
+            Julian => AnyCalendar::Julian(cal::Julian),
+            JulianGregorian => AnyCalendar::JulianGregorian(cal::JulianGregorian::new()),
             Persian => AnyCalendar::Persian(cal::Persian),
             Roc => AnyCalendar::Roc(cal::Roc),
+            Vietnamese => AnyCalendar::Vietnamese(cal::LunarChinese::new_vietnamese()),
         };
         Ok(Self { any_calendar, kind })
     }
@@ -452,8 +491,11 @@ impl FormattableAnyCalendar {
             )),
             HijriUmmAlQura => AnyCalendar::HijriUmmAlQura(cal::Hijri::new_umm_al_qura()),
             Japanese => AnyCalendar::Japanese(cal::Japanese::try_new_unstable(provider)?),
+            Julian => AnyCalendar::Julian(cal::Julian),
+            JulianGregorian => AnyCalendar::JulianGregorian(cal::JulianGregorian::new()),
             Persian => AnyCalendar::Persian(cal::Persian),
             Roc => AnyCalendar::Roc(cal::Roc),
+            Vietnamese => AnyCalendar::Vietnamese(cal::LunarChinese::new_vietnamese()),
         };
         Ok(Self { any_calendar, kind })
     }
@@ -534,11 +576,13 @@ where
         let p = &self.provider;
         match self.kind {
             Buddhist => H::Buddhist::bind(p).load_bound(req),
-            Chinese => H::Chinese::bind(p).load_bound(req),
+            // The Vietnamese calendar uses the Chinese names and patterns
+            Chinese | Vietnamese => H::Chinese::bind(p).load_bound(req),
             Coptic => H::Coptic::bind(p).load_bound(req),
             Dangi => H::Dangi::bind(p).load_bound(req),
             Ethiopian | EthiopianAmeteAlem => H::Ethiopian::bind(p).load_bound(req),
-            Gregorian => H::Gregorian::bind(p).load_bound(req),
+            // The Julian calendars use the Gregorian names and patterns
+            Gregorian | Julian | JulianGregorian => H::Gregorian::bind(p).load_bound(req),
             Hebrew => H::Hebrew::bind(p).load_bound(req),
             Indian => H::Indian::bind(p).load_bound(req),
             HijriTabularTypeIIFriday | HijriTabularTypeIIThursday | HijriUmmAlQura => {
@@ -553,11 +597,11 @@ where
         use FormattableAnyCalendarKind::*;
         match self.kind {
             Buddhist => H::Buddhist::INFO,
-            Chinese => H::Chinese::INFO,
+            Chinese | Vietnamese => H::Chinese::INFO,
             Coptic => H::Coptic::INFO,
             Dangi => H::Dangi::INFO,
             Ethiopian | EthiopianAmeteAlem => H::Ethiopian::INFO,
-            Gregorian => H::Gregorian::INFO,
+            Gregorian | Julian | JulianGregorian => H::Gregorian::INFO,
             Hebrew => H::Hebrew::INFO,
             Indian => H::Indian::INFO,
             HijriTabularTypeIIFriday | HijriTabularTypeIIThursday | HijriUmmAlQura => {
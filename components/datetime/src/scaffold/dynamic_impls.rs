@@ -5,7 +5,7 @@
 use super::*;
 use crate::fieldsets::enums::*;
 use crate::provider::{neo::*, time_zones::tz, *};
-use icu_calendar::types::{DayOfMonth, DayOfYear, MonthInfo, RataDie, Weekday, YearInfo};
+use icu_calendar::types::{DayOfMonth, DayOfYearInfo, MonthInfo, RataDie, Weekday, YearInfo};
 use icu_provider::marker::NeverMarker;
 use icu_time::{
     zone::{UtcOffset, ZoneNameTimestamp},
@@ -85,9 +85,9 @@ impl DateTimeNamesMarker for CalendarPeriodFieldSet {
 impl DateInputMarkers for CalendarPeriodFieldSet {
     type YearInput = datetime_marker_helper!(@input/year, yes);
     type MonthInput = datetime_marker_helper!(@input/month, yes);
-    type DayOfMonthInput = datetime_marker_helper!(@input/day_of_month,);
-    type DayOfWeekInput = datetime_marker_helper!(@input/day_of_week,);
-    type DayOfYearInput = datetime_marker_helper!(@input/day_of_year,);
+    type DayOfMonthInput = datetime_marker_helper!(@input/day_of_month, yes);
+    type DayOfWeekInput = datetime_marker_helper!(@input/day_of_week, yes);
+    type DayOfYearInput = datetime_marker_helper!(@input/day_of_year, yes);
     type RataDieInput = datetime_marker_helper!(@input/rata_die,);
 }
 
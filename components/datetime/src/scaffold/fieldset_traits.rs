@@ -7,10 +7,11 @@ use crate::{
     scaffold::*,
 };
 use icu_calendar::{
-    provider::CalendarJapaneseModernV1,
-    types::{DayOfMonth, DayOfYear, MonthInfo, RataDie, Weekday, YearInfo},
+    provider::{CalendarJapaneseModernV1, CalendarWeekV2},
+    types::{DayOfMonth, DayOfYearInfo, MonthInfo, RataDie, Weekday, YearInfo},
 };
 use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV1};
+use icu_plurals::provider::PluralsOrdinalV1;
 use icu_provider::{marker::NeverMarker, prelude::*};
 use icu_time::{scaffold::IntoOption, zone::ZoneNameTimestamp};
 use icu_time::{zone::UtcOffset, Hour, Minute, Nanosecond, Second, TimeZone};
@@ -34,7 +35,7 @@ pub trait DateInputMarkers: UnstableSealed {
     /// Marker for resolving the day-of-month input field.
     type DayOfMonthInput: IntoOption<DayOfMonth>;
     /// Marker for resolving the day-of-year input field.
-    type DayOfYearInput: IntoOption<DayOfYear>;
+    type DayOfYearInput: IntoOption<DayOfYearInfo>;
     /// Marker for resolving the day-of-year input field.
     type RataDieInput: IntoOption<RataDie>;
     /// Marker for resolving the day-of-week input field.
@@ -487,12 +488,19 @@ where
 /// for datetime formatting with a fixed calendar.
 // This trait is implicitly sealed due to sealed supertraits
 pub trait AllFixedCalendarExternalDataMarkers:
-    DataProvider<DecimalSymbolsV1> + DataProvider<DecimalDigitsV1>
+    DataProvider<CalendarWeekV2>
+    + DataProvider<DecimalSymbolsV1>
+    + DataProvider<DecimalDigitsV1>
+    + DataProvider<PluralsOrdinalV1>
 {
 }
 
 impl<T> AllFixedCalendarExternalDataMarkers for T where
-    T: ?Sized + DataProvider<DecimalSymbolsV1> + DataProvider<DecimalDigitsV1>
+    T: ?Sized
+        + DataProvider<CalendarWeekV2>
+        + DataProvider<DecimalSymbolsV1>
+        + DataProvider<DecimalDigitsV1>
+        + DataProvider<PluralsOrdinalV1>
 {
 }
 
@@ -501,16 +509,20 @@ impl<T> AllFixedCalendarExternalDataMarkers for T where
 // This trait is implicitly sealed due to sealed supertraits
 pub trait AllAnyCalendarExternalDataMarkers:
     DataProvider<CalendarJapaneseModernV1>
+    + DataProvider<CalendarWeekV2>
     + DataProvider<DecimalSymbolsV1>
     + DataProvider<DecimalDigitsV1>
+    + DataProvider<PluralsOrdinalV1>
 {
 }
 
 impl<T> AllAnyCalendarExternalDataMarkers for T where
     T: ?Sized
         + DataProvider<CalendarJapaneseModernV1>
+        + DataProvider<CalendarWeekV2>
         + DataProvider<DecimalSymbolsV1>
         + DataProvider<DecimalDigitsV1>
+        + DataProvider<PluralsOrdinalV1>
 {
 }
 
@@ -649,7 +661,7 @@ macro_rules! datetime_marker_helper {
         Weekday
     };
     (@input/day_of_year, yes) => {
-        DayOfYear
+        DayOfYearInfo
     };
     (@input/rata_die, yes) => {
         RataDie
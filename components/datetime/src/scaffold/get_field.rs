@@ -3,7 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use icu_calendar::{
-    types::{DayOfMonth, DayOfYear, MonthInfo, RataDie, Weekday, YearInfo},
+    types::{DayOfMonth, DayOfYear, DayOfYearInfo, MonthInfo, RataDie, Weekday, YearInfo},
     AsCalendar, Calendar, Date,
 };
 use icu_time::{
@@ -73,6 +73,13 @@ impl<C: Calendar, A: AsCalendar<Calendar = C>> GetField<DayOfYear> for Date<A> {
     }
 }
 
+impl<C: Calendar, A: AsCalendar<Calendar = C>> GetField<DayOfYearInfo> for Date<A> {
+    #[inline]
+    fn get_field(&self) -> DayOfYearInfo {
+        self.day_of_year_info()
+    }
+}
+
 impl<C: Calendar, A: AsCalendar<Calendar = C>> GetField<RataDie> for Date<A> {
     #[inline]
     fn get_field(&self) -> RataDie {
@@ -147,6 +154,13 @@ impl<C: Calendar, A: AsCalendar<Calendar = C>> GetField<DayOfYear> for DateTime<
     }
 }
 
+impl<C: Calendar, A: AsCalendar<Calendar = C>> GetField<DayOfYearInfo> for DateTime<A> {
+    #[inline]
+    fn get_field(&self) -> DayOfYearInfo {
+        self.date.day_of_year_info()
+    }
+}
+
 impl<C: Calendar, A: AsCalendar<Calendar = C>> GetField<RataDie> for DateTime<A> {
     #[inline]
     fn get_field(&self) -> RataDie {
@@ -219,6 +233,13 @@ impl<C: Calendar, A: AsCalendar<Calendar = C>, Z> GetField<DayOfYear> for ZonedD
     }
 }
 
+impl<C: Calendar, A: AsCalendar<Calendar = C>, Z> GetField<DayOfYearInfo> for ZonedDateTime<A, Z> {
+    #[inline]
+    fn get_field(&self) -> DayOfYearInfo {
+        self.date.day_of_year_info()
+    }
+}
+
 impl<C: Calendar, A: AsCalendar<Calendar = C>, Z> GetField<RataDie> for ZonedDateTime<A, Z> {
     #[inline]
     fn get_field(&self) -> RataDie {
@@ -99,10 +99,11 @@ impl<C: CldrCalendar, FSet: DateTimeNamesMarker> FixedCalendarDateTimeFormatter<
         &self,
         datetime: DateTimeInputUnchecked,
     ) -> FormattedDateTimeUnchecked<'_> {
+        let names = self.names.as_borrowed();
         FormattedDateTimeUnchecked {
-            pattern: self.selection.select(&datetime),
+            pattern: self.selection.select(&datetime, names.week_information),
             input: datetime,
-            names: self.names.as_borrowed(),
+            names,
         }
     }
 }
@@ -180,10 +181,11 @@ impl<FSet: DateTimeNamesMarker> DateTimeFormatter<FSet> {
         &self,
         datetime: DateTimeInputUnchecked,
     ) -> FormattedDateTimeUnchecked<'_> {
+        let names = self.names.as_borrowed();
         FormattedDateTimeUnchecked {
-            pattern: self.selection.select(&datetime),
+            pattern: self.selection.select(&datetime, names.week_information),
             input: datetime,
-            names: self.names.as_borrowed(),
+            names,
         }
     }
 }
@@ -231,6 +233,9 @@ impl TryWriteable for FormattedDateTimeUnchecked<'_> {
             FormattedDateTimePatternError::DecimalFormatterNotLoaded => {
                 Self::Error::DecimalFormatterNotLoaded
             }
+            FormattedDateTimePatternError::WeekInformationNotLoaded => {
+                Self::Error::WeekInformationNotLoaded
+            }
             FormattedDateTimePatternError::NamesNotLoaded(error_field) => {
                 Self::Error::NamesNotLoaded(error_field)
             }
@@ -362,6 +367,15 @@ pub enum FormattedDateTimeUncheckedError {
     /// The output will contain fallback values using Latin numerals.
     #[displaydoc("DecimalFormatter not loaded")]
     DecimalFormatterNotLoaded,
+    /// The [`WeekInformation`] has not been loaded.
+    ///
+    /// Same error conditions as [`FormattedDateTimeUncheckedError::NamesNotLoaded`].
+    ///
+    /// The output will contain the string `{X}` instead, where `X` is the symbol of the week field.
+    ///
+    /// [`WeekInformation`]: icu_calendar::week::WeekInformation
+    #[displaydoc("WeekInformation not loaded")]
+    WeekInformationNotLoaded,
 
     /// An input field (such as "hour" or "month") is missing.
     ///
@@ -2,16 +2,20 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use icu_calendar::cal::Hebrew;
+use icu_calendar::cal::{Gregorian, Hebrew, Japanese, JulianGregorian, LunarChinese};
 use icu_calendar::Date;
 use icu_datetime::fieldsets::enums::{
     CompositeDateTimeFieldSet, DateAndTimeFieldSet, DateFieldSet,
 };
 use icu_datetime::fieldsets::{self, YMD};
-use icu_datetime::{DateTimeFormatterPreferences, FixedCalendarDateTimeFormatter};
+use icu_datetime::pattern::{DateTimePattern, FixedCalendarDateTimeNames};
+use icu_datetime::{
+    DateTimeFormatter, DateTimeFormatterPreferences, FixedCalendarDateTimeFormatter,
+};
 use icu_locale_core::{locale, Locale};
+use icu_provider::prelude::*;
 use icu_time::{DateTime, Time};
-use writeable::assert_writeable_eq;
+use writeable::{assert_try_writeable_eq, assert_writeable_eq};
 
 const EXPECTED_DATETIME: &[&str] = &[
     "Friday, December 22, 2023, 9:22:53 PM",
@@ -189,6 +193,48 @@ fn hebrew_months() {
     assert_writeable_eq!(formatted_datetime, "28 Adar II 5771");
 }
 
+#[test]
+fn julian_gregorian_cutover() {
+    let calendar = JulianGregorian::new_great_britain();
+    let formatter =
+        FixedCalendarDateTimeFormatter::try_new(locale!("en-GB").into(), YMD::long()).unwrap();
+
+    let before = Date::try_new_iso(1752, 9, 13)
+        .unwrap()
+        .to_calendar(calendar);
+    let after = Date::try_new_iso(1752, 9, 14)
+        .unwrap()
+        .to_calendar(calendar);
+
+    assert_writeable_eq!(formatter.format(&before), "2 September 1752");
+    assert_writeable_eq!(formatter.format(&after), "14 September 1752");
+
+    // Dates in other calendars are converted to the Julian-Gregorian calendar
+    let formatter = formatter.into_formatter(calendar);
+    assert_writeable_eq!(
+        formatter.format(&Date::try_new_iso(1752, 9, 13).unwrap()),
+        "2 September 1752"
+    );
+}
+
+#[test]
+fn vietnamese_cyclic_year() {
+    // There is no `-u-ca-` value for the Vietnamese calendar
+    let formatter = FixedCalendarDateTimeFormatter::try_new(locale!("en").into(), YMD::long())
+        .unwrap()
+        .into_formatter(LunarChinese::new_vietnamese());
+    let chinese_formatter =
+        DateTimeFormatter::try_new(locale!("en-u-ca-chinese").into(), YMD::long()).unwrap();
+
+    // Tết 1985 was a month before the Chinese New Year
+    let date = Date::try_new_iso(1985, 1, 21).unwrap();
+    assert_writeable_eq!(formatter.format(&date), "First Month 1, 1985(yi-chou)");
+    assert_writeable_eq!(
+        chinese_formatter.format(&date),
+        "Twelfth Month 1, 1984(jia-zi)"
+    );
+}
+
 #[test]
 fn test_5387() {
     let datetime = DateTime {
@@ -216,3 +262,94 @@ fn test_5387() {
     assert_writeable_eq!(formatter_h12.format(&datetime), "Fri, 2:15:16\u{202f}PM");
     assert_writeable_eq!(formatter_h24.format(&datetime), "Fri, 14:15:16");
 }
+
+/// A custom provider with the data for formatting `YW` in the Gregorian calendar.
+struct WeekProvider;
+
+icu_provider::marker::impl_data_provider_never_marker!(WeekProvider);
+
+macro_rules! forward_to_baked {
+    ($($baked:path => $marker:path),* $(,)?) => {
+        $(
+            impl DataProvider<$marker> for WeekProvider {
+                fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                    $baked.load(req)
+                }
+            }
+        )*
+    };
+}
+
+forward_to_baked!(
+    icu_calendar::provider::Baked => icu_calendar::provider::CalendarWeekV2,
+    icu_datetime::provider::Baked => icu_datetime::provider::DatetimePatternsDateGregorianV1,
+    icu_datetime::provider::Baked => icu_datetime::provider::neo::DatetimeNamesYearGregorianV1,
+    icu_decimal::provider::Baked => icu_decimal::provider::DecimalSymbolsV1,
+    icu_decimal::provider::Baked => icu_decimal::provider::DecimalDigitsV1,
+    icu_plurals::provider::Baked => icu_plurals::provider::PluralsOrdinalV1,
+);
+
+#[test]
+fn week_information_from_custom_provider() {
+    // January 1, 2021 falls into the last week of 2020 in Germany
+    let date = Date::try_new_gregorian(2021, 1, 1).unwrap();
+
+    let formatter = FixedCalendarDateTimeFormatter::<Gregorian, _>::try_new_unstable(
+        &WeekProvider,
+        locale!("de").into(),
+        fieldsets::YW::medium(),
+    )
+    .unwrap();
+    assert_writeable_eq!(formatter.format(&date), "week 53 of 2020");
+
+    let mut names =
+        FixedCalendarDateTimeNames::<Gregorian, fieldsets::YW>::try_new(locale!("de").into())
+            .unwrap();
+    names.include_decimal_formatter().unwrap();
+    let formatter = names
+        .try_into_formatter_unstable(&WeekProvider, fieldsets::YW::medium())
+        .unwrap();
+    assert_writeable_eq!(formatter.format(&date), "week 53 of 2020");
+}
+
+#[test]
+fn week_based_year_across_eras() {
+    let formatter =
+        FixedCalendarDateTimeFormatter::try_new(locale!("de").into(), fieldsets::YW::medium())
+            .unwrap();
+    // January 1, 1 BCE falls into the last week of 2 BCE
+    let date = Date::try_new_gregorian(0, 1, 1).unwrap();
+    assert_writeable_eq!(formatter.format(&date), "week 52 of 2");
+    // December 31, 1 BCE falls into the last week of 1 BCE
+    let date = Date::try_new_gregorian(0, 12, 31).unwrap();
+    assert_writeable_eq!(formatter.format(&date), "week 52 of 1");
+
+    // CLDR has no week-of patterns for the Japanese calendar
+    let pattern = DateTimePattern::try_from_pattern_str("'week' w 'of' Y").unwrap();
+    let mut names =
+        FixedCalendarDateTimeNames::<Japanese, fieldsets::YW>::try_new(locale!("de").into())
+            .unwrap();
+    let formatter = names.include_for_pattern(&pattern).unwrap();
+    // January 1, 1989 (Shōwa 64) falls into the last week of Shōwa 63
+    let date = Date::try_new_gregorian(1989, 1, 1)
+        .unwrap()
+        .to_calendar(Japanese::new());
+    assert_try_writeable_eq!(formatter.format(&date), "week 52 of 63");
+    // December 30, 2019 (Reiwa 1) falls into the first week of Reiwa 2
+    let date = Date::try_new_gregorian(2019, 12, 30)
+        .unwrap()
+        .to_calendar(Japanese::new());
+    assert_try_writeable_eq!(formatter.format(&date), "week 1 of 2");
+}
+
+#[test]
+fn week_pattern_plural_variants() {
+    let formatter =
+        FixedCalendarDateTimeFormatter::try_new(locale!("fil").into(), fieldsets::YW::medium())
+            .unwrap();
+    // Filipino uses a different pattern for the first week
+    let date = Date::try_new_gregorian(2024, 1, 3).unwrap();
+    assert_writeable_eq!(formatter.format(&date), "ika-1 linggo ng 2024");
+    let date = Date::try_new_gregorian(2024, 1, 10).unwrap();
+    assert_writeable_eq!(formatter.format(&date), "linggo 2 ng 2024");
+}
@@ -4,6 +4,12 @@
 
 //! Duration formatting
 
+use core::str::FromStr;
+use icu_calendar::{Calendar, DateDuration};
+use ixdtf::encoding::Utf8;
+use ixdtf::parsers::IsoDurationParser;
+use ixdtf::records::{DurationParseRecord, Sign, TimeDurationRecord};
+
 /// Represents a duration of time (intuitively, how long something took / will take).
 /// Can be constructed ergonomically using the [`Default`] trait like so:
 ///
@@ -97,3 +103,319 @@ impl Duration {
         Self::default()
     }
 }
+
+impl Duration {
+    /// Parse a [`Duration`] from an ISO 8601 duration string, such as `P1Y2M3DT4H`.
+    ///
+    /// Fractional hours and minutes are balanced into the smaller units, and fractional
+    /// seconds into milliseconds, microseconds, and nanoseconds, as in Temporal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::experimental::duration::{
+    ///     Duration, DurationFormatter, DurationSign, ValidatedDurationFormatterOptions,
+    /// };
+    /// use icu::locale::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let formatter = DurationFormatter::try_new(
+    ///     locale!("en").into(),
+    ///     ValidatedDurationFormatterOptions::validate(Default::default()).unwrap(),
+    /// )
+    /// .unwrap();
+    /// let duration = Duration::try_from_str("P1Y2M3DT4H").unwrap();
+    /// assert_writeable_eq!(
+    ///     formatter.format(&duration),
+    ///     "1 yr, 2 mths, 3 days, 4 hr"
+    /// );
+    ///
+    /// let duration = Duration::try_from_str("-P1Y2M3DT4H30.5M").unwrap();
+    /// assert_eq!(
+    ///     duration,
+    ///     Duration {
+    ///         sign: DurationSign::Negative,
+    ///         years: 1,
+    ///         months: 2,
+    ///         days: 3,
+    ///         hours: 4,
+    ///         minutes: 30,
+    ///         seconds: 30,
+    ///         ..Default::default()
+    ///     }
+    /// );
+    ///
+    /// assert!(Duration::try_from_str("P1H").is_err());
+    /// ```
+    pub fn try_from_str(s: &str) -> Result<Self, DurationParseError> {
+        Self::try_from_utf8(s.as_bytes())
+    }
+
+    /// Parse a [`Duration`] from an ISO 8601 duration string, such as `P1Y2M3DT4H`.
+    ///
+    /// See [`Self::try_from_str()`].
+    pub fn try_from_utf8(code_units: &[u8]) -> Result<Self, DurationParseError> {
+        IsoDurationParser::<Utf8>::from_utf8(code_units)
+            .parse()?
+            .try_into()
+    }
+}
+
+impl FromStr for Duration {
+    type Err = DurationParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+/// The error type for [`Duration::try_from_str()`].
+#[derive(Debug, Clone, Copy, PartialEq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum DurationParseError {
+    /// Syntax error.
+    #[displaydoc("Syntax error in the ISO 8601 duration string: {0}")]
+    Syntax(ixdtf::ParseError),
+    /// Parsed fractional digits had excessive precision beyond nanosecond.
+    ExcessivePrecision,
+}
+
+impl core::error::Error for DurationParseError {}
+
+impl From<ixdtf::ParseError> for DurationParseError {
+    fn from(value: ixdtf::ParseError) -> Self {
+        Self::Syntax(value)
+    }
+}
+
+impl TryFrom<DurationParseRecord> for Duration {
+    type Error = DurationParseError;
+
+    fn try_from(record: DurationParseRecord) -> Result<Self, Self::Error> {
+        let mut duration = Duration {
+            sign: match record.sign {
+                Sign::Negative => DurationSign::Negative,
+                Sign::Positive => DurationSign::Positive,
+            },
+            ..Default::default()
+        };
+
+        if let Some(date) = record.date {
+            duration.years = date.years.into();
+            duration.months = date.months.into();
+            duration.weeks = date.weeks.into();
+            duration.days = date.days;
+        }
+
+        // The fraction of the last time unit, in nanoseconds of that unit, and the number
+        // of nanoseconds in that unit
+        let (fraction, unit) = match record.time {
+            None => (None, 0),
+            Some(TimeDurationRecord::Hours { hours, fraction }) => {
+                duration.hours = hours;
+                (fraction, 3600)
+            }
+            Some(TimeDurationRecord::Minutes {
+                hours,
+                minutes,
+                fraction,
+            }) => {
+                duration.hours = hours;
+                duration.minutes = minutes;
+                (fraction, 60)
+            }
+            Some(TimeDurationRecord::Seconds {
+                hours,
+                minutes,
+                seconds,
+                fraction,
+            }) => {
+                duration.hours = hours;
+                duration.minutes = minutes;
+                duration.seconds = seconds;
+                (fraction, 1)
+            }
+        };
+
+        if let Some(fraction) = fraction {
+            let nanoseconds = u64::from(
+                fraction
+                    .to_nanoseconds()
+                    .ok_or(DurationParseError::ExcessivePrecision)?,
+            ) * unit;
+            if unit == 3600 {
+                duration.minutes = nanoseconds / 60_000_000_000;
+            }
+            if unit >= 60 {
+                duration.seconds = nanoseconds / 1_000_000_000 % 60;
+            }
+            duration.milliseconds = nanoseconds / 1_000_000 % 1_000;
+            duration.microseconds = nanoseconds / 1_000 % 1_000;
+            duration.nanoseconds = nanoseconds % 1_000;
+        }
+
+        Ok(duration)
+    }
+}
+
+/// The error type for conversions between [`Duration`] and `DateDuration`,
+/// and for [`Duration::try_round()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum DurationRangeError {
+    /// The units of the duration have different signs.
+    #[displaydoc("The units of the duration have different signs")]
+    MixedSign,
+    /// A unit is too large for the target type, or a date is out of range.
+    #[displaydoc("A unit is too large for the target type, or a date is out of range")]
+    OutOfRange,
+    /// The duration has time units, which a date duration cannot represent.
+    #[displaydoc("The duration has time units, which a date duration cannot represent")]
+    TimeUnits,
+}
+
+impl core::error::Error for DurationRangeError {}
+
+impl<C: Calendar + ?Sized> TryFrom<DateDuration<C>> for Duration {
+    type Error = DurationRangeError;
+
+    /// Converts a `DateDuration` into a [`Duration`].
+    ///
+    /// Fails with [`DurationRangeError::MixedSign`] if some units are positive and others negative.
+    fn try_from(value: DateDuration<C>) -> Result<Self, Self::Error> {
+        let units = [value.years, value.months, value.weeks, value.days];
+        let sign = if units.iter().all(|&u| u >= 0) {
+            DurationSign::Positive
+        } else if units.iter().all(|&u| u <= 0) {
+            DurationSign::Negative
+        } else {
+            return Err(DurationRangeError::MixedSign);
+        };
+        Ok(Duration {
+            sign,
+            years: value.years.unsigned_abs().into(),
+            months: value.months.unsigned_abs().into(),
+            weeks: value.weeks.unsigned_abs().into(),
+            days: value.days.unsigned_abs().into(),
+            ..Default::default()
+        })
+    }
+}
+
+impl<C: Calendar + ?Sized> TryFrom<&Duration> for DateDuration<C> {
+    type Error = DurationRangeError;
+
+    /// Converts a [`Duration`] into a `DateDuration`.
+    ///
+    /// Fails with [`DurationRangeError::TimeUnits`] if any unit smaller than days is nonzero,
+    /// and with [`DurationRangeError::OutOfRange`] if a unit does not fit into an `i32`.
+    fn try_from(value: &Duration) -> Result<Self, Self::Error> {
+        if value.iter_units()[4..].iter().any(|&u| u != 0) {
+            return Err(DurationRangeError::TimeUnits);
+        }
+        let signed = |unit: u64| {
+            let unit = i32::try_from(unit).map_err(|_| DurationRangeError::OutOfRange)?;
+            Ok(match value.sign {
+                DurationSign::Positive => unit,
+                DurationSign::Negative => -unit,
+            })
+        };
+        Ok(DateDuration::new(
+            signed(value.years)?,
+            signed(value.months)?,
+            signed(value.weeks)?,
+            signed(value.days)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_calendar::Iso;
+
+    #[test]
+    fn test_parse_fractions() {
+        for (input, expected) in [
+            (
+                "PT1.5H",
+                Duration {
+                    hours: 1,
+                    minutes: 30,
+                    ..Default::default()
+                },
+            ),
+            (
+                "PT0.000001H",
+                Duration {
+                    milliseconds: 3,
+                    microseconds: 600,
+                    ..Default::default()
+                },
+            ),
+            (
+                "PT2M0.25S",
+                Duration {
+                    minutes: 2,
+                    milliseconds: 250,
+                    ..Default::default()
+                },
+            ),
+            (
+                "-PT1.123456789S",
+                Duration {
+                    sign: DurationSign::Negative,
+                    seconds: 1,
+                    milliseconds: 123,
+                    microseconds: 456,
+                    nanoseconds: 789,
+                    ..Default::default()
+                },
+            ),
+        ] {
+            assert_eq!(Duration::try_from_str(input), Ok(expected), "{input}");
+        }
+
+        assert!(matches!(
+            Duration::try_from_str("P1Y2"),
+            Err(DurationParseError::Syntax(_))
+        ));
+    }
+
+    #[test]
+    fn test_date_duration() {
+        let duration = Duration::try_from(DateDuration::<Iso>::new(-1, 0, -2, 0)).unwrap();
+        assert_eq!(
+            duration,
+            Duration {
+                sign: DurationSign::Negative,
+                years: 1,
+                weeks: 2,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            DateDuration::<Iso>::try_from(&duration),
+            Ok(DateDuration::new(-1, 0, -2, 0))
+        );
+
+        assert_eq!(
+            Duration::try_from(DateDuration::<Iso>::new(1, -1, 0, 0)),
+            Err(DurationRangeError::MixedSign)
+        );
+        assert_eq!(
+            DateDuration::<Iso>::try_from(&Duration {
+                days: 1,
+                hours: 1,
+                ..Default::default()
+            }),
+            Err(DurationRangeError::TimeUnits)
+        );
+        assert_eq!(
+            DateDuration::<Iso>::try_from(&Duration {
+                days: u64::MAX,
+                ..Default::default()
+            }),
+            Err(DurationRangeError::OutOfRange)
+        );
+    }
+}
@@ -12,9 +12,12 @@ mod formatter;
 pub mod provider;
 
 pub mod options;
+mod round;
 mod validated_options;
 
-pub use duration::{Duration, DurationSign};
+pub use duration::{Duration, DurationParseError, DurationRangeError, DurationSign};
 pub use format::FormattedDuration;
 pub use formatter::DurationFormatter;
 pub use formatter::DurationFormatterPreferences;
+pub use round::{DurationRoundingOptions, DurationUnit};
+pub use validated_options::{DurationFormatterOptionsError, ValidatedDurationFormatterOptions};
@@ -0,0 +1,572 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Balancing and rounding of durations relative to a date
+
+use super::duration::{Duration, DurationRangeError, DurationSign};
+use fixed_decimal::{SignedRoundingMode, UnsignedRoundingMode};
+use icu_calendar::{AsCalendar, Date, DateDuration, Ref};
+
+/// A unit of a [`Duration`].
+///
+/// Units are ordered from largest to smallest, so `DurationUnit::Year < DurationUnit::Day`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum DurationUnit {
+    /// Calendar years
+    Year,
+    /// Calendar months
+    Month,
+    /// Weeks of seven days
+    Week,
+    /// Days
+    Day,
+    /// Hours
+    Hour,
+    /// Minutes
+    Minute,
+    /// Seconds
+    Second,
+    /// Milliseconds
+    Millisecond,
+    /// Microseconds
+    Microsecond,
+    /// Nanoseconds
+    Nanosecond,
+}
+
+impl DurationUnit {
+    /// The number of nanoseconds in this unit, if it has a fixed length.
+    fn nanoseconds(self) -> Option<i128> {
+        Some(match self {
+            Self::Year | Self::Month | Self::Week => return None,
+            Self::Day => NANOSECONDS_PER_DAY,
+            Self::Hour => 3_600_000_000_000,
+            Self::Minute => 60_000_000_000,
+            Self::Second => 1_000_000_000,
+            Self::Millisecond => 1_000_000,
+            Self::Microsecond => 1_000,
+            Self::Nanosecond => 1,
+        })
+    }
+}
+
+/// Options for [`Duration::try_round()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DurationRoundingOptions {
+    /// The largest unit of the result.
+    ///
+    /// Defaults to `None`, which uses the largest nonzero unit of the duration,
+    /// or the smallest unit if that is larger.
+    pub largest_unit: Option<DurationUnit>,
+    /// The smallest unit of the result. Defaults to [`DurationUnit::Nanosecond`],
+    /// which balances the duration without rounding it.
+    pub smallest_unit: DurationUnit,
+    /// How to round the remainder below the smallest unit.
+    ///
+    /// Defaults to [`UnsignedRoundingMode::HalfExpand`], which rounds halves away from zero.
+    pub rounding_mode: SignedRoundingMode,
+}
+
+impl Default for DurationRoundingOptions {
+    fn default() -> Self {
+        Self {
+            largest_unit: None,
+            smallest_unit: DurationUnit::Nanosecond,
+            rounding_mode: SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfExpand),
+        }
+    }
+}
+
+impl From<DurationUnit> for DurationRoundingOptions {
+    fn from(smallest_unit: DurationUnit) -> Self {
+        Self {
+            smallest_unit,
+            ..Default::default()
+        }
+    }
+}
+
+const NANOSECONDS_PER_DAY: i128 = 86_400_000_000_000;
+
+/// The largest number of days a duration may span, as in Temporal.
+const MAX_DAYS: i64 = 100_000_000;
+
+impl Duration {
+    /// Balances and rounds this duration, counting calendar units from the date `relative_to`.
+    ///
+    /// The result only uses units between [`DurationRoundingOptions::largest_unit`] and
+    /// [`DurationRoundingOptions::smallest_unit`], and has a single sign. Years and months
+    /// have the lengths they have in the calendar of `relative_to`, and days have 24 hours.
+    /// Weeks are only used if one of the two units is [`DurationUnit::Week`].
+    ///
+    /// Adding months to a date keeps its day of the month, clamped to the length of the month,
+    /// so that January 31 plus 1 month is the last day of February.
+    ///
+    /// Returns [`DurationRangeError::OutOfRange`] if the duration spans more than
+    /// 100,000,000 days.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::calendar::Date;
+    /// use icu::experimental::duration::{
+    ///     Duration, DurationFormatter, DurationRoundingOptions, DurationUnit,
+    ///     ValidatedDurationFormatterOptions,
+    /// };
+    /// use icu::locale::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let formatter = DurationFormatter::try_new(
+    ///     locale!("en").into(),
+    ///     ValidatedDurationFormatterOptions::validate(Default::default()).unwrap(),
+    /// )
+    /// .unwrap();
+    /// let relative_to = Date::try_new_iso(2025, 1, 31).unwrap();
+    ///
+    /// // 401.5 days from 2025-01-31 end on 2026-03-08 at noon,
+    /// // which is 1 year, 1 month and 8 days later since February 2026 has 28 days
+    /// let duration = Duration::try_from_str("P400DT36H").unwrap();
+    /// let mut options = DurationRoundingOptions::from(DurationUnit::Hour);
+    /// options.largest_unit = Some(DurationUnit::Year);
+    /// let balanced = duration.try_round(&relative_to, options).unwrap();
+    /// assert_writeable_eq!(
+    ///     formatter.format(&balanced),
+    ///     "1 yr, 1 mth, 8 days, 12 hr"
+    /// );
+    ///
+    /// // Rounded to months
+    /// options.smallest_unit = DurationUnit::Month;
+    /// let rounded = duration.try_round(&relative_to, options).unwrap();
+    /// assert_writeable_eq!(formatter.format(&rounded), "1 yr, 1 mth");
+    ///
+    /// // 90 minutes as hours and minutes
+    /// let duration = Duration::try_from_str("PT90M").unwrap();
+    /// let mut options = DurationRoundingOptions::default();
+    /// options.largest_unit = Some(DurationUnit::Hour);
+    /// let balanced = duration.try_round(&relative_to, options).unwrap();
+    /// assert_writeable_eq!(formatter.format(&balanced), "1 hr, 30 min");
+    /// ```
+    pub fn try_round<A: AsCalendar>(
+        &self,
+        relative_to: &Date<A>,
+        options: DurationRoundingOptions,
+    ) -> Result<Duration, DurationRangeError> {
+        let relative_to = relative_to.as_borrowed();
+        let smallest_unit = options.smallest_unit;
+        let largest_unit = options
+            .largest_unit
+            .unwrap_or_else(|| self.largest_unit())
+            .min(smallest_unit);
+
+        let target = self.nanoseconds_from(&relative_to)?;
+
+        let rounded = if let Some(unit) = smallest_unit.nanoseconds() {
+            round(target, unit, options.rounding_mode) * unit
+        } else {
+            let sign = target.signum();
+            let (counts, rest) =
+                count_calendar_units(&relative_to, target, sign, largest_unit, smallest_unit);
+            let start =
+                nanoseconds_between(&relative_to, &add_calendar_units(&relative_to, counts)?);
+            let mut end_counts = counts;
+            match smallest_unit {
+                DurationUnit::Year => end_counts.0 += sign as i64,
+                DurationUnit::Month => end_counts.1 += sign as i64,
+                _ => end_counts.2 += 7 * sign as i64,
+            }
+            let end =
+                nanoseconds_between(&relative_to, &add_calendar_units(&relative_to, end_counts)?);
+            let increment = round(rest, (end - start).abs().max(1), options.rounding_mode);
+            start + increment.abs() * (end - start)
+        };
+
+        if rounded.abs() > i128::from(MAX_DAYS) * NANOSECONDS_PER_DAY {
+            return Err(DurationRangeError::OutOfRange);
+        }
+
+        Ok(balance(&relative_to, rounded, largest_unit, smallest_unit))
+    }
+
+    /// The largest nonzero unit of this duration, or nanoseconds if it is zero.
+    fn largest_unit(&self) -> DurationUnit {
+        const UNITS: [DurationUnit; 10] = [
+            DurationUnit::Year,
+            DurationUnit::Month,
+            DurationUnit::Week,
+            DurationUnit::Day,
+            DurationUnit::Hour,
+            DurationUnit::Minute,
+            DurationUnit::Second,
+            DurationUnit::Millisecond,
+            DurationUnit::Microsecond,
+            DurationUnit::Nanosecond,
+        ];
+        self.iter_units()
+            .iter()
+            .zip(UNITS)
+            .find(|(&value, _)| value != 0)
+            .map(|(_, unit)| unit)
+            .unwrap_or(DurationUnit::Nanosecond)
+    }
+
+    /// The signed number of nanoseconds from the start of `relative_to` to the end of this duration.
+    fn nanoseconds_from<A: AsCalendar>(
+        &self,
+        relative_to: &Date<Ref<A>>,
+    ) -> Result<i128, DurationRangeError> {
+        let sign: i64 = match self.sign {
+            DurationSign::Positive => 1,
+            DurationSign::Negative => -1,
+        };
+        let signed = |value: u64| {
+            i64::try_from(value)
+                .ok()
+                .filter(|&v| v <= MAX_DAYS)
+                .map(|v| v * sign)
+                .ok_or(DurationRangeError::OutOfRange)
+        };
+        let end = add_calendar_units(
+            relative_to,
+            (
+                signed(self.years)?,
+                signed(self.months)?,
+                signed(self.weeks)? * 7 + signed(self.days)?,
+            ),
+        )?;
+
+        let time = i128::from(self.hours) * 3_600_000_000_000
+            + i128::from(self.minutes) * 60_000_000_000
+            + i128::from(self.seconds) * 1_000_000_000
+            + i128::from(self.milliseconds) * 1_000_000
+            + i128::from(self.microseconds) * 1_000
+            + i128::from(self.nanoseconds);
+
+        let total = nanoseconds_between(relative_to, &end) + i128::from(sign) * time;
+        if total.abs() > i128::from(MAX_DAYS) * NANOSECONDS_PER_DAY {
+            return Err(DurationRangeError::OutOfRange);
+        }
+        Ok(total)
+    }
+}
+
+/// Rounds `numerator / denominator` to an integer, where `denominator` is positive.
+fn round(numerator: i128, denominator: i128, mode: SignedRoundingMode) -> i128 {
+    let quotient = numerator / denominator;
+    let remainder = (numerator % denominator).abs();
+    if remainder == 0 {
+        return quotient;
+    }
+    let is_negative = numerator < 0;
+    let half = (2 * remainder).cmp(&denominator);
+    let away_from_zero = match mode {
+        SignedRoundingMode::Ceil => !is_negative,
+        SignedRoundingMode::Floor => is_negative,
+        SignedRoundingMode::Unsigned(UnsignedRoundingMode::Expand) => true,
+        SignedRoundingMode::Unsigned(UnsignedRoundingMode::Trunc) => false,
+        _ if half.is_ne() => half.is_gt(),
+        SignedRoundingMode::HalfCeil => !is_negative,
+        SignedRoundingMode::HalfFloor => is_negative,
+        SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfTrunc) => false,
+        SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfEven) => quotient % 2 != 0,
+        // HalfExpand
+        _ => true,
+    };
+    if !away_from_zero {
+        quotient
+    } else if is_negative {
+        quotient - 1
+    } else {
+        quotient + 1
+    }
+}
+
+/// The number of nanoseconds between the starts of two dates.
+fn nanoseconds_between<A: AsCalendar>(from: &Date<Ref<A>>, to: &Date<Ref<A>>) -> i128 {
+    i128::from(to.to_rata_die() - from.to_rata_die()) * NANOSECONDS_PER_DAY
+}
+
+/// Adds years, then months, then days to a date, keeping the day of the month
+/// where possible.
+fn add_calendar_units<'a, A: AsCalendar>(
+    date: &Date<Ref<'a, A>>,
+    (years, months, days): (i64, i64, i64),
+) -> Result<Date<Ref<'a, A>>, DurationRangeError> {
+    let out_of_range = |_| DurationRangeError::OutOfRange;
+    if years.abs() > MAX_DAYS / 365 * 2
+        || months.abs() > MAX_DAYS / 28 * 2
+        || days.abs() > MAX_DAYS * 2
+    {
+        return Err(DurationRangeError::OutOfRange);
+    }
+    let day = date.day_of_month().0;
+
+    let mut first_of_month = Date::from_rata_die(
+        date.to_rata_die() - i64::from(day - 1),
+        Ref(date.calendar_wrapper().0),
+    );
+    if years != 0 {
+        let year = i32::try_from(i64::from(first_of_month.extended_year()) + years)
+            .map_err(out_of_range)?;
+        let month = first_of_month.month().standard_code;
+        first_of_month =
+            Date::try_new_from_codes(None, year, month, 1, Ref(date.calendar_wrapper().0))
+                .or_else(|_| {
+                    // A leap month that does not exist in the target year
+                    Date::try_new_from_codes(
+                        None,
+                        year,
+                        month.get_normal_if_leap().unwrap_or(month),
+                        1,
+                        Ref(date.calendar_wrapper().0),
+                    )
+                })
+                .map_err(|_| DurationRangeError::OutOfRange)?;
+    }
+    if months != 0 {
+        first_of_month.add(DateDuration::new(
+            0,
+            i32::try_from(months).map_err(out_of_range)?,
+            0,
+            0,
+        ));
+    }
+    let day = day.min(first_of_month.days_in_month());
+
+    Ok(Date::from_rata_die(
+        first_of_month.to_rata_die() + i64::from(day - 1) + days,
+        Ref(date.calendar_wrapper().0),
+    ))
+}
+
+/// Counts the calendar units from `relative_to` towards `target`, returning the counts of
+/// years, months, and days, and the remaining nanoseconds.
+///
+/// Weeks are counted as multiples of seven days if `smallest_unit` is [`DurationUnit::Week`].
+fn count_calendar_units<A: AsCalendar>(
+    relative_to: &Date<Ref<A>>,
+    target: i128,
+    sign: i128,
+    largest_unit: DurationUnit,
+    smallest_unit: DurationUnit,
+) -> ((i64, i64, i64), i128) {
+    if sign == 0 {
+        return ((0, 0, 0), 0);
+    }
+    let sign = sign as i64;
+    // Whether the date at the given counts does not go past the target
+    let fits = |counts: (i64, i64, i64)| {
+        add_calendar_units(relative_to, counts).is_ok_and(|date| {
+            let nanoseconds = nanoseconds_between(relative_to, &date);
+            (nanoseconds - target) * i128::from(sign) <= 0
+        })
+    };
+
+    let mut counts = (0, 0, 0);
+    if largest_unit <= DurationUnit::Year && smallest_unit >= DurationUnit::Year {
+        counts.0 = sign * largest_fitting(|n| fits((sign * n, 0, 0)));
+    }
+    if largest_unit <= DurationUnit::Month && smallest_unit >= DurationUnit::Month {
+        counts.1 = sign * largest_fitting(|n| fits((counts.0, sign * n, 0)));
+    }
+    if smallest_unit >= DurationUnit::Week {
+        let step = if smallest_unit == DurationUnit::Week {
+            7
+        } else {
+            1
+        };
+        counts.2 = sign * step * largest_fitting(|n| fits((counts.0, counts.1, sign * step * n)));
+    }
+
+    let rest = match add_calendar_units(relative_to, counts) {
+        Ok(date) => target - nanoseconds_between(relative_to, &date),
+        Err(_) => 0,
+    };
+    (counts, rest)
+}
+
+/// Finds the largest `n >= 0` for which `fits(n)` holds, given that `fits` is monotonic
+/// and `fits(0)` holds.
+fn largest_fitting(fits: impl Fn(i64) -> bool) -> i64 {
+    let mut low = 0;
+    let mut high = 1;
+    while fits(high) {
+        low = high;
+        high *= 2;
+    }
+    while high - low > 1 {
+        let middle = low + (high - low) / 2;
+        if fits(middle) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    low
+}
+
+/// Converts the signed nanoseconds from `relative_to` into a [`Duration`] that uses
+/// units between `largest_unit` and `smallest_unit`.
+fn balance<A: AsCalendar>(
+    relative_to: &Date<Ref<A>>,
+    target: i128,
+    largest_unit: DurationUnit,
+    smallest_unit: DurationUnit,
+) -> Duration {
+    let mut duration = Duration {
+        sign: if target < 0 {
+            DurationSign::Negative
+        } else {
+            DurationSign::Positive
+        },
+        ..Default::default()
+    };
+
+    let ((years, months, days), rest) = if largest_unit <= DurationUnit::Day {
+        count_calendar_units(
+            relative_to,
+            target,
+            target.signum(),
+            largest_unit,
+            smallest_unit,
+        )
+    } else {
+        ((0, 0, 0), target)
+    };
+    duration.years = years.unsigned_abs();
+    duration.months = months.unsigned_abs();
+    if largest_unit == DurationUnit::Week || smallest_unit == DurationUnit::Week {
+        duration.weeks = days.unsigned_abs() / 7;
+        duration.days = days.unsigned_abs() % 7;
+    } else {
+        duration.days = days.unsigned_abs();
+    }
+
+    // The remaining time is less than a day if days were counted
+    let mut rest = rest.unsigned_abs();
+    for (unit, field) in [
+        (DurationUnit::Hour, &mut duration.hours),
+        (DurationUnit::Minute, &mut duration.minutes),
+        (DurationUnit::Second, &mut duration.seconds),
+        (DurationUnit::Millisecond, &mut duration.milliseconds),
+        (DurationUnit::Microsecond, &mut duration.microseconds),
+        (DurationUnit::Nanosecond, &mut duration.nanoseconds),
+    ] {
+        if unit < largest_unit || unit > smallest_unit {
+            continue;
+        }
+        #[expect(clippy::unwrap_used)] // time units have a fixed length
+        let length = unit.nanoseconds().unwrap() as u128;
+        *field = (rest / length) as u64;
+        rest %= length;
+    }
+
+    duration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_duration(
+        duration: &str,
+        largest_unit: DurationUnit,
+        smallest_unit: DurationUnit,
+        rounding_mode: SignedRoundingMode,
+    ) -> Duration {
+        let relative_to = Date::try_new_iso(2024, 1, 31).unwrap();
+        let mut options = DurationRoundingOptions::from(smallest_unit);
+        options.largest_unit = Some(largest_unit);
+        options.rounding_mode = rounding_mode;
+        Duration::try_from_str(duration)
+            .unwrap()
+            .try_round(&relative_to, options)
+            .unwrap()
+    }
+
+    const HALF_EXPAND: SignedRoundingMode =
+        SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfExpand);
+
+    #[test]
+    fn test_balance() {
+        use DurationUnit::*;
+        for (input, largest_unit, expected) in [
+            ("PT36H", Day, "P1DT12H"),
+            ("P1DT12H", Hour, "PT36H"),
+            ("PT3661.5S", Hour, "PT1H1M1.5S"),
+            ("P28D", Month, "P28D"),
+            // 2024 is a leap year, and January 31 plus 1 month is February 29
+            ("P29D", Month, "P1M"),
+            ("P30D", Month, "P1M1D"),
+            ("P366D", Year, "P1Y"),
+            ("P367D", Year, "P1Y1D"),
+            ("P1Y", Day, "P366D"),
+            ("P20D", Week, "P2W6D"),
+            ("P2W", Day, "P14D"),
+            ("-P1M", Day, "-P31D"),
+            ("-P400D", Year, "-P1Y1M4D"),
+            ("-PT25H", Day, "-P1DT1H"),
+        ] {
+            let expected = Duration::try_from_str(expected).unwrap();
+            assert_eq!(
+                round_duration(input, largest_unit, Nanosecond, HALF_EXPAND),
+                expected,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_round() {
+        use DurationUnit::*;
+        let half_even = SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfEven);
+        let trunc = SignedRoundingMode::Unsigned(UnsignedRoundingMode::Trunc);
+        for (input, largest_unit, smallest_unit, mode, expected) in [
+            ("PT1H29M59S", Hour, Hour, HALF_EXPAND, "PT1H"),
+            ("PT1H30M", Hour, Hour, HALF_EXPAND, "PT2H"),
+            ("PT2H30M", Hour, Hour, half_even, "PT2H"),
+            ("-PT1H30M", Hour, Hour, HALF_EXPAND, "-PT2H"),
+            ("-PT1H30M", Hour, Hour, SignedRoundingMode::Ceil, "-PT1H"),
+            ("PT1H1S", Hour, Minute, SignedRoundingMode::Ceil, "PT1H1M"),
+            ("PT23H59M59.9S", Day, Second, HALF_EXPAND, "P1D"),
+            // From 2024-02-29, the next month ends 31 days later
+            ("P1M16D", Year, Month, HALF_EXPAND, "P2M"),
+            ("P1M15D", Year, Month, HALF_EXPAND, "P1M"),
+            ("P11M20D", Year, Month, HALF_EXPAND, "P1Y"),
+            ("P11M20D", Year, Month, trunc, "P11M"),
+            ("P10D", Week, Week, HALF_EXPAND, "P1W"),
+            ("P10D", Week, Week, SignedRoundingMode::Ceil, "P2W"),
+            ("-P1Y6M", Year, Year, HALF_EXPAND, "-P2Y"),
+            ("-P1Y6M", Year, Year, SignedRoundingMode::Ceil, "-P1Y"),
+        ] {
+            let expected = Duration::try_from_str(expected).unwrap();
+            assert_eq!(
+                round_duration(input, largest_unit, smallest_unit, mode),
+                expected,
+                "{input} {smallest_unit:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_units() {
+        let relative_to = Date::try_new_iso(2024, 1, 31).unwrap();
+        let duration = Duration::try_from_str("P1DT36H").unwrap();
+        assert_eq!(
+            duration.try_round(&relative_to, Default::default()),
+            Ok(Duration::try_from_str("P2DT12H").unwrap())
+        );
+        assert_eq!(
+            duration.try_round(&relative_to, DurationUnit::Week.into()),
+            Ok(Duration::default())
+        );
+        assert_eq!(
+            Duration::try_from_str("P300000Y")
+                .unwrap()
+                .try_round(&relative_to, Default::default()),
+            Err(DurationRangeError::OutOfRange)
+        );
+    }
+}
@@ -86,6 +86,8 @@ pub enum DurationFormatterOptionsError {
 impl core::error::Error for DurationFormatterOptionsError {}
 
 impl ValidatedDurationFormatterOptions {
+    /// Validates a [`DurationFormatterOptions`] bag, resolving the default styles and
+    /// display settings for each unit.
     pub fn validate(
         value: DurationFormatterOptions,
     ) -> Result<Self, DurationFormatterOptionsError> {
@@ -0,0 +1,55 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Recognition of emoji in text, following [Unicode Technical Standard #51](https://www.unicode.org/reports/tr51/).
+//!
+//! [`EmojiScanner`] finds emoji characters and sequences in a string, classifies them, and
+//! determines whether they are recommended for general interchange (RGI).
+//!
+//! # Example
+//!
+//! ```
+//! use icu::experimental::emoji::{EmojiKind, EmojiScanner};
+//!
+//! let scanner = EmojiScanner::new();
+//!
+//! let text = "Hi 👋🏽! 🏳️‍🌈 ❤ 3️⃣";
+//! let emoji = scanner
+//!     .scan(text)
+//!     .map(|m| (&text[m.range.clone()], m.kind, m.is_fully_qualified))
+//!     .collect::<Vec<_>>();
+//!
+//! assert_eq!(
+//!     emoji,
+//!     [
+//!         ("👋🏽", EmojiKind::Modifier, true),
+//!         ("🏳️‍🌈", EmojiKind::Zwj, true),
+//!         ("3️⃣", EmojiKind::Keycap, true),
+//!     ]
+//! );
+//!
+//! // U+2764 HEAVY BLACK HEART has text presentation by default, unless followed
+//! // by U+FE0F VARIATION SELECTOR-16.
+//! assert_eq!(scanner.to_fully_qualified("❤").as_deref(), Some("❤️"));
+//! ```
+
+// https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        clippy::trivially_copy_pass_by_ref,
+        missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+mod scanner;
+
+pub use scanner::*;
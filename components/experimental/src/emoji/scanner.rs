@@ -0,0 +1,445 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::iter::Peekable;
+use core::ops::Range;
+use core::str::CharIndices;
+use icu_properties::props::{
+    Emoji, EmojiModifier, EmojiModifierBase, EmojiPresentation, RegionalIndicator, RgiEmoji,
+};
+use icu_properties::provider::{
+    PropertyBinaryEmojiModifierBaseV1, PropertyBinaryEmojiModifierV1,
+    PropertyBinaryEmojiPresentationV1, PropertyBinaryEmojiV1, PropertyBinaryRegionalIndicatorV1,
+    PropertyBinaryRgiEmojiV1,
+};
+use icu_properties::{
+    CodePointSetData, CodePointSetDataBorrowed, EmojiSetData, EmojiSetDataBorrowed,
+};
+use icu_provider::prelude::*;
+use icu_segmenter::iterators::GraphemeClusterBreakIterator;
+use icu_segmenter::provider::SegmenterBreakGraphemeClusterV1;
+use icu_segmenter::scaffold::Utf8;
+use icu_segmenter::{GraphemeClusterSegmenter, GraphemeClusterSegmenterBorrowed};
+
+const TEXT_PRESENTATION_SELECTOR: char = '\u{FE0E}';
+const EMOJI_PRESENTATION_SELECTOR: char = '\u{FE0F}';
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+const COMBINING_ENCLOSING_KEYCAP: char = '\u{20E3}';
+const TAG_SPECS: core::ops::RangeInclusive<char> = '\u{E0020}'..='\u{E007E}';
+const CANCEL_TAG: char = '\u{E007F}';
+
+/// The structure of an emoji, as defined in [UTS #51](https://www.unicode.org/reports/tr51/#Definitions).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EmojiKind {
+    /// A single emoji character, optionally followed by U+FE0F VARIATION SELECTOR-16, such as "😀" or "❤️".
+    Basic,
+    /// A digit, `#`, or `*`, followed by U+20E3 COMBINING ENCLOSING KEYCAP, such as "3️⃣".
+    Keycap,
+    /// A pair of regional indicator symbols, such as "🇺🇦".
+    Flag,
+    /// An emoji modifier base followed by a skin tone modifier, such as "👋🏽".
+    Modifier,
+    /// An emoji followed by tag characters, such as the flag of Scotland "🏴󠁧󠁢󠁳󠁣󠁴󠁿".
+    Tag,
+    /// Emoji joined by U+200D ZERO WIDTH JOINER, such as "🏳️‍🌈".
+    Zwj,
+}
+
+/// An emoji found by [`EmojiScannerBorrowed::scan()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EmojiMatch {
+    /// The byte range of the emoji in the scanned text.
+    pub range: Range<usize>,
+    /// The structure of the emoji.
+    pub kind: EmojiKind,
+    /// Whether the emoji is recommended for general interchange (RGI), possibly after
+    /// adding variation selectors, see [`EmojiScannerBorrowed::to_fully_qualified()`].
+    pub is_rgi: bool,
+    /// Whether the emoji is exactly an RGI emoji, including all of its variation selectors.
+    pub is_fully_qualified: bool,
+}
+
+/// A scanner that finds emoji in text.
+///
+/// Emoji are recognized at the start of extended grapheme clusters, using the syntax of
+/// possible emoji from [UTS #51](https://www.unicode.org/reports/tr51/#EBNF_and_Regex). Single
+/// characters with a default text presentation, such as "©" or digits, are only recognized
+/// when followed by U+FE0F VARIATION SELECTOR-16.
+///
+/// See the [module documentation](crate::emoji) for an example.
+#[derive(Debug)]
+pub struct EmojiScanner {
+    segmenter: GraphemeClusterSegmenter,
+    rgi_emoji: EmojiSetData,
+    emoji: CodePointSetData,
+    emoji_presentation: CodePointSetData,
+    emoji_modifier: CodePointSetData,
+    emoji_modifier_base: CodePointSetData,
+    regional_indicator: CodePointSetData,
+}
+
+/// A borrowed version of [`EmojiScanner`], returned by [`EmojiScanner::as_borrowed()`].
+#[derive(Debug, Clone, Copy)]
+pub struct EmojiScannerBorrowed<'a> {
+    segmenter: GraphemeClusterSegmenterBorrowed<'a>,
+    rgi_emoji: EmojiSetDataBorrowed<'a>,
+    emoji: CodePointSetDataBorrowed<'a>,
+    emoji_presentation: CodePointSetDataBorrowed<'a>,
+    emoji_modifier: CodePointSetDataBorrowed<'a>,
+    emoji_modifier_base: CodePointSetDataBorrowed<'a>,
+    regional_indicator: CodePointSetDataBorrowed<'a>,
+}
+
+impl EmojiScanner {
+    /// Creates an [`EmojiScannerBorrowed`] using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[expect(clippy::new_ret_no_self)]
+    pub const fn new() -> EmojiScannerBorrowed<'static> {
+        EmojiScannerBorrowed {
+            segmenter: GraphemeClusterSegmenter::new(),
+            rgi_emoji: EmojiSetData::new::<RgiEmoji>(),
+            emoji: CodePointSetData::new::<Emoji>(),
+            emoji_presentation: CodePointSetData::new::<EmojiPresentation>(),
+            emoji_modifier: CodePointSetData::new::<EmojiModifier>(),
+            emoji_modifier_base: CodePointSetData::new::<EmojiModifierBase>(),
+            regional_indicator: CodePointSetData::new::<RegionalIndicator>(),
+        }
+    }
+
+    icu_provider::gen_buffer_data_constructors!(() -> error: DataError,
+        functions: [
+            new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+    ]);
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<SegmenterBreakGraphemeClusterV1>
+            + DataProvider<PropertyBinaryRgiEmojiV1>
+            + DataProvider<PropertyBinaryEmojiV1>
+            + DataProvider<PropertyBinaryEmojiPresentationV1>
+            + DataProvider<PropertyBinaryEmojiModifierV1>
+            + DataProvider<PropertyBinaryEmojiModifierBaseV1>
+            + DataProvider<PropertyBinaryRegionalIndicatorV1>
+            + ?Sized,
+    {
+        Ok(Self {
+            segmenter: GraphemeClusterSegmenter::try_new_unstable(provider)?,
+            rgi_emoji: EmojiSetData::try_new_unstable::<RgiEmoji>(provider)?,
+            emoji: CodePointSetData::try_new_unstable::<Emoji>(provider)?,
+            emoji_presentation: CodePointSetData::try_new_unstable::<EmojiPresentation>(provider)?,
+            emoji_modifier: CodePointSetData::try_new_unstable::<EmojiModifier>(provider)?,
+            emoji_modifier_base: CodePointSetData::try_new_unstable::<EmojiModifierBase>(provider)?,
+            regional_indicator: CodePointSetData::try_new_unstable::<RegionalIndicator>(provider)?,
+        })
+    }
+
+    /// Constructs a borrowed version of this type for more efficient querying.
+    pub fn as_borrowed(&self) -> EmojiScannerBorrowed<'_> {
+        EmojiScannerBorrowed {
+            segmenter: self.segmenter.as_borrowed(),
+            rgi_emoji: self.rgi_emoji.as_borrowed(),
+            emoji: self.emoji.as_borrowed(),
+            emoji_presentation: self.emoji_presentation.as_borrowed(),
+            emoji_modifier: self.emoji_modifier.as_borrowed(),
+            emoji_modifier_base: self.emoji_modifier_base.as_borrowed(),
+            regional_indicator: self.regional_indicator.as_borrowed(),
+        }
+    }
+}
+
+impl<'a> EmojiScannerBorrowed<'a> {
+    /// Returns an iterator over the emoji in `text`.
+    pub fn scan<'s>(self, text: &'s str) -> EmojiIter<'a, 's> {
+        EmojiIter {
+            scanner: self,
+            text,
+            breaks: self.segmenter.segment_str(text),
+            start: 0,
+        }
+    }
+
+    /// Returns the emoji at the start of `text`, if any.
+    ///
+    /// Unlike [`Self::scan()`], this does not check that the emoji ends at a grapheme
+    /// cluster boundary.
+    pub fn match_at_start(self, text: &str) -> Option<EmojiMatch> {
+        let mut chars = text.char_indices().peekable();
+        let (mut kind, presentation) = self.element(&mut chars)?;
+        loop {
+            let mut lookahead = chars.clone();
+            if lookahead
+                .next_if(|&(_, c)| c == ZERO_WIDTH_JOINER)
+                .is_none()
+            {
+                break;
+            }
+            if self.element(&mut lookahead).is_none() {
+                break;
+            }
+            chars = lookahead;
+            kind = EmojiKind::Zwj;
+        }
+        if kind == EmojiKind::Basic && !presentation {
+            return None;
+        }
+
+        let end = chars.peek().map(|&(i, _)| i).unwrap_or(text.len());
+        let emoji = text.get(..end)?;
+        let is_fully_qualified = self.rgi_emoji.contains_str(emoji);
+        Some(EmojiMatch {
+            range: 0..end,
+            kind,
+            is_rgi: is_fully_qualified
+                || self
+                    .rgi_emoji
+                    .contains_str(&self.fully_qualified_form(emoji)),
+            is_fully_qualified,
+        })
+    }
+
+    /// Returns the fully-qualified form of an emoji, if it is an RGI emoji.
+    ///
+    /// Fully-qualified emoji contain U+FE0F VARIATION SELECTOR-16 after each character that
+    /// has a default text presentation. This maps unqualified and minimally-qualified emoji,
+    /// such as "❤" and "🏳‍🌈", to their fully-qualified forms "❤️" and "🏳️‍🌈".
+    pub fn to_fully_qualified<'s>(self, emoji: &'s str) -> Option<Cow<'s, str>> {
+        if self.rgi_emoji.contains_str(emoji) {
+            return Some(Cow::Borrowed(emoji));
+        }
+        let fully_qualified = self.fully_qualified_form(emoji);
+        self.rgi_emoji
+            .contains_str(&fully_qualified)
+            .then_some(Cow::Owned(fully_qualified))
+    }
+
+    fn fully_qualified_form(self, emoji: &str) -> String {
+        let mut result = String::with_capacity(emoji.len() + 3);
+        let mut chars = emoji
+            .chars()
+            .filter(|&c| c != EMOJI_PRESENTATION_SELECTOR)
+            .peekable();
+        while let Some(c) = chars.next() {
+            result.push(c);
+            if self.emoji.contains(c)
+                && !self.emoji_presentation.contains(c)
+                && !chars
+                    .peek()
+                    .is_some_and(|&m| self.emoji_modifier.contains(m))
+            {
+                result.push(EMOJI_PRESENTATION_SELECTOR);
+            }
+        }
+        result
+    }
+
+    /// Consumes a single element of a possible emoji, returning its kind and whether it has
+    /// an emoji presentation.
+    fn element(self, chars: &mut Peekable<CharIndices>) -> Option<(EmojiKind, bool)> {
+        let (_, c) = chars.next()?;
+        if self.regional_indicator.contains(c) {
+            if chars
+                .next_if(|&(_, c)| self.regional_indicator.contains(c))
+                .is_some()
+            {
+                return Some((EmojiKind::Flag, true));
+            }
+            return Some((EmojiKind::Basic, true));
+        }
+        if !self.emoji.contains(c) {
+            return None;
+        }
+
+        if chars
+            .next_if(|&(_, c)| c == TEXT_PRESENTATION_SELECTOR)
+            .is_some()
+        {
+            return Some((EmojiKind::Basic, false));
+        }
+        let has_selector = chars
+            .next_if(|&(_, c)| c == EMOJI_PRESENTATION_SELECTOR)
+            .is_some();
+
+        if matches!(c, '0'..='9' | '#' | '*') {
+            if chars
+                .next_if(|&(_, c)| c == COMBINING_ENCLOSING_KEYCAP)
+                .is_some()
+            {
+                return Some((EmojiKind::Keycap, true));
+            }
+        } else if !has_selector
+            && self.emoji_modifier_base.contains(c)
+            && chars
+                .next_if(|&(_, c)| self.emoji_modifier.contains(c))
+                .is_some()
+        {
+            return Some((EmojiKind::Modifier, true));
+        } else if chars.peek().is_some_and(|(_, c)| TAG_SPECS.contains(c)) {
+            let mut lookahead = chars.clone();
+            while lookahead.next_if(|(_, c)| TAG_SPECS.contains(c)).is_some() {}
+            if lookahead.next_if(|&(_, c)| c == CANCEL_TAG).is_some() {
+                *chars = lookahead;
+                return Some((EmojiKind::Tag, true));
+            }
+        }
+
+        Some((
+            EmojiKind::Basic,
+            has_selector || self.emoji_presentation.contains(c),
+        ))
+    }
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for EmojiScannerBorrowed<'static> {
+    fn default() -> Self {
+        EmojiScanner::new()
+    }
+}
+
+/// An iterator over the emoji in a string, returned by [`EmojiScannerBorrowed::scan()`].
+#[derive(Debug)]
+pub struct EmojiIter<'a, 's> {
+    scanner: EmojiScannerBorrowed<'a>,
+    text: &'s str,
+    breaks: GraphemeClusterBreakIterator<'a, 's, Utf8>,
+    start: usize,
+}
+
+impl Iterator for EmojiIter<'_, '_> {
+    type Item = EmojiMatch;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let end = self.breaks.next()?;
+            let start = core::mem::replace(&mut self.start, end);
+            let Some(cluster) = self.text.get(start..end).filter(|c| !c.is_empty()) else {
+                continue;
+            };
+            if let Some(mut m) = self.scanner.match_at_start(cluster) {
+                m.range = start + m.range.start..start + m.range.end;
+                return Some(m);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn scan(text: &str) -> Vec<(&str, EmojiKind, bool, bool)> {
+        EmojiScanner::new()
+            .scan(text)
+            .map(|m| {
+                (
+                    &text[m.range.clone()],
+                    m.kind,
+                    m.is_rgi,
+                    m.is_fully_qualified,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_kinds() {
+        use EmojiKind::*;
+        assert_eq!(
+            scan("a😀b©️c©d3️⃣e3⃣f3g🇺🇦🇩h👋🏽i🏴󠁧󠁢󠁳󠁣󠁴󠁿j👨‍👩‍👧k"),
+            [
+                ("😀", Basic, true, true),
+                ("©️", Basic, true, true),
+                ("3️⃣", Keycap, true, true),
+                ("3⃣", Keycap, true, false),
+                ("🇺🇦", Flag, true, true),
+                ("🇩", Basic, false, false),
+                ("👋🏽", Modifier, true, true),
+                ("🏴󠁧󠁢󠁳󠁣󠁴󠁿", Tag, true, true),
+                ("👨‍👩‍👧", Zwj, true, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_qualification() {
+        use EmojiKind::*;
+        assert_eq!(
+            scan("❤️ ❤ ❤︎ 🏳️‍🌈 🏳‍🌈 👁‍🗨 ☝🏻 ☕🏻 🇺🇺 😀‍🍕"),
+            [
+                ("❤️", Basic, true, true),
+                ("🏳️‍🌈", Zwj, true, true),
+                ("🏳‍🌈", Zwj, true, false),
+                ("👁‍🗨", Zwj, true, false),
+                ("☝🏻", Modifier, true, true),
+                ("☕", Basic, true, true),
+                ("🇺🇺", Flag, false, false),
+                ("😀‍🍕", Zwj, false, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_fully_qualified() {
+        let scanner = EmojiScanner::new();
+        for (input, expected) in [
+            ("😀", Some("😀")),
+            ("❤", Some("❤️")),
+            ("❤️", Some("❤️")),
+            ("3⃣", Some("3️⃣")),
+            ("🏳‍🌈", Some("🏳️‍🌈")),
+            ("👁‍🗨", Some("👁️‍🗨️")),
+            ("☝🏻", Some("☝🏻")),
+            ("☝️🏻", Some("☝🏻")),
+            ("❤︎", None),
+            ("3", None),
+            ("🇺🇺", None),
+            ("a", None),
+        ] {
+            assert_eq!(
+                scanner.to_fully_qualified(input).as_deref(),
+                expected,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_all_rgi_emoji() {
+        let scanner = EmojiScanner::new();
+        let rgi = EmojiSetData::new::<RgiEmoji>().static_to_owned();
+        let rgi = rgi.as_code_point_inversion_list_string_list().unwrap();
+
+        let emoji = rgi
+            .code_points()
+            .iter_chars()
+            .map(|c| c.to_string())
+            .chain(rgi.strings().iter().map(String::from));
+        for emoji in emoji {
+            let m = scanner.scan(&emoji).collect::<Vec<_>>();
+            assert_eq!(m.len(), 1, "{emoji:?}");
+            assert_eq!(m[0].range, 0..emoji.len(), "{emoji:?}");
+            assert!(m[0].is_fully_qualified, "{emoji:?}");
+
+            let unqualified = emoji.replace(EMOJI_PRESENTATION_SELECTOR, "");
+            assert_eq!(
+                scanner.to_fully_qualified(&unqualified).as_deref(),
+                Some(emoji.as_str()),
+                "{emoji:?}"
+            );
+        }
+    }
+}
@@ -21,9 +21,12 @@ pub mod compactdecimal;
 pub mod dimension;
 pub mod displaynames;
 pub mod duration;
+pub mod emoji;
 pub mod measure;
+pub mod messageformat;
 pub mod personnames;
 pub mod relativetime;
+pub mod timezonelist;
 pub mod transliterate;
 pub mod unicodeset_parse;
 pub mod units;
@@ -78,6 +81,7 @@ pub mod provider {
         impl_narrow_second_relative_v1!(Baked);
         impl_narrow_week_relative_v1!(Baked);
         impl_narrow_year_relative_v1!(Baked);
+        impl_relative_at_time_v1!(Baked);
         impl_short_day_relative_v1!(Baked);
         impl_short_hour_relative_v1!(Baked);
         impl_short_minute_relative_v1!(Baked);
@@ -133,6 +137,7 @@ pub mod provider {
         super::relativetime::provider::NarrowSecondRelativeV1::INFO,
         super::relativetime::provider::NarrowWeekRelativeV1::INFO,
         super::relativetime::provider::NarrowYearRelativeV1::INFO,
+        super::relativetime::provider::RelativeAtTimeV1::INFO,
         super::relativetime::provider::ShortDayRelativeV1::INFO,
         super::relativetime::provider::ShortHourRelativeV1::INFO,
         super::relativetime::provider::ShortMinuteRelativeV1::INFO,
@@ -0,0 +1,154 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+use fixed_decimal::Decimal;
+use icu_calendar::{Date, Iso};
+use icu_time::{DateTime, Time};
+
+/// A value passed to a message, or to a custom function.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum MessageArgument<'a> {
+    /// A string.
+    String(Cow<'a, str>),
+    /// A number.
+    Number(Decimal),
+    /// A date and time in the ISO calendar.
+    DateTime(DateTime<Iso>),
+}
+
+impl MessageArgument<'_> {
+    /// Returns a copy of this argument that borrows its string, if any.
+    pub(crate) fn reborrow(&self) -> MessageArgument<'_> {
+        match self {
+            Self::String(s) => MessageArgument::String(Cow::Borrowed(s)),
+            Self::Number(n) => MessageArgument::Number(n.clone()),
+            Self::DateTime(dt) => MessageArgument::DateTime(*dt),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MessageArgument<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::String(Cow::Borrowed(value))
+    }
+}
+
+impl From<String> for MessageArgument<'_> {
+    fn from(value: String) -> Self {
+        Self::String(Cow::Owned(value))
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for MessageArgument<'a> {
+    fn from(value: Cow<'a, str>) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<Decimal> for MessageArgument<'_> {
+    fn from(value: Decimal) -> Self {
+        Self::Number(value)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),+) => {
+        $(
+            impl From<$t> for MessageArgument<'_> {
+                fn from(value: $t) -> Self {
+                    Self::Number(Decimal::from(value))
+                }
+            }
+        )+
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl From<DateTime<Iso>> for MessageArgument<'_> {
+    fn from(value: DateTime<Iso>) -> Self {
+        Self::DateTime(value)
+    }
+}
+
+impl From<Date<Iso>> for MessageArgument<'_> {
+    /// Converts a date to a date and time at the start of the day.
+    fn from(date: Date<Iso>) -> Self {
+        Self::DateTime(DateTime {
+            date,
+            time: Time::start_of_day(),
+        })
+    }
+}
+
+/// The named arguments of a message.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::messageformat::{MessageArgument, MessageArguments};
+///
+/// let arguments = MessageArguments::new()
+///     .with("name", "Alice")
+///     .with("count", 3);
+///
+/// assert_eq!(arguments.get("name"), Some(&MessageArgument::from("Alice")));
+/// assert_eq!(arguments.get("other"), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MessageArguments<'a> {
+    arguments: BTreeMap<Cow<'a, str>, MessageArgument<'a>>,
+}
+
+impl<'a> MessageArguments<'a> {
+    /// Creates an empty set of arguments.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an argument, replacing any previous argument with the same name.
+    pub fn insert(
+        &mut self,
+        name: impl Into<Cow<'a, str>>,
+        value: impl Into<MessageArgument<'a>>,
+    ) -> &mut Self {
+        self.arguments.insert(name.into(), value.into());
+        self
+    }
+
+    /// Adds an argument, replacing any previous argument with the same name.
+    pub fn with(
+        mut self,
+        name: impl Into<Cow<'a, str>>,
+        value: impl Into<MessageArgument<'a>>,
+    ) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Returns the argument with the given name.
+    pub fn get(&self, name: &str) -> Option<&MessageArgument<'a>> {
+        self.arguments.get(name)
+    }
+}
+
+impl<'a, K, V> FromIterator<(K, V)> for MessageArguments<'a>
+where
+    K: Into<Cow<'a, str>>,
+    V: Into<MessageArgument<'a>>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self {
+            arguments: iter
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        }
+    }
+}
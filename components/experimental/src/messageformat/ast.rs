@@ -0,0 +1,217 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The data model of a MessageFormat 2.0 message.
+//!
+//! The types in this module mirror the
+//! [data model](https://www.unicode.org/reports/tr35/tr35-messageFormat.html#interchange-data-model)
+//! defined by the specification. Use [`parse`](super::parse) to obtain a [`Message`] from the
+//! message syntax.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A parsed message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)] // the two message kinds of the specification
+pub enum Message {
+    /// A message consisting of a single pattern, such as `Hello, {$name}!`.
+    Pattern(PatternMessage),
+    /// A message that selects one of several variants, starting with `.match`.
+    Select(SelectMessage),
+}
+
+impl Message {
+    /// Returns the declarations at the start of this message.
+    pub fn declarations(&self) -> &[Declaration] {
+        match self {
+            Self::Pattern(message) => &message.declarations,
+            Self::Select(message) => &message.declarations,
+        }
+    }
+}
+
+/// A message consisting of a single [`Pattern`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // data model type
+pub struct PatternMessage {
+    /// The `.input` and `.local` declarations of the message.
+    pub declarations: Vec<Declaration>,
+    /// The pattern to format.
+    pub pattern: Pattern,
+}
+
+/// A message that selects one of its variants based on the values of its selectors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // data model type
+pub struct SelectMessage {
+    /// The `.input` and `.local` declarations of the message.
+    pub declarations: Vec<Declaration>,
+    /// The names of the variables in the `.match` statement, without the `$` sigil.
+    pub selectors: Vec<String>,
+    /// The variants, in source order.
+    pub variants: Vec<Variant>,
+}
+
+/// An `.input` or `.local` declaration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)] // the two declaration kinds of the specification
+pub enum Declaration {
+    /// An `.input` declaration, such as `.input {$count :number}`.
+    ///
+    /// The operand of the expression is always the variable `name`.
+    Input {
+        /// The name of the declared variable, without the `$` sigil.
+        name: String,
+        /// The expression annotating the variable.
+        value: Expression,
+    },
+    /// A `.local` declaration, such as `.local $x = {$y :string}`.
+    Local {
+        /// The name of the declared variable, without the `$` sigil.
+        name: String,
+        /// The expression bound to the variable.
+        value: Expression,
+    },
+}
+
+impl Declaration {
+    /// Returns the name of the declared variable.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Input { name, .. } | Self::Local { name, .. } => name,
+        }
+    }
+
+    /// Returns the expression bound to the declared variable.
+    pub fn value(&self) -> &Expression {
+        match self {
+            Self::Input { value, .. } | Self::Local { value, .. } => value,
+        }
+    }
+}
+
+/// One variant of a [`SelectMessage`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // data model type
+pub struct Variant {
+    /// The keys of this variant, one per selector.
+    pub keys: Vec<Key>,
+    /// The pattern to format if this variant is selected.
+    pub value: Pattern,
+}
+
+/// A variant key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)] // the two key kinds of the specification
+pub enum Key {
+    /// A literal key, such as `one` or `|1|`.
+    Literal(String),
+    /// The catch-all key `*`.
+    CatchAll,
+}
+
+/// A sequence of text and placeholders.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[allow(clippy::exhaustive_structs)] // data model type
+pub struct Pattern {
+    /// The parts of the pattern, in order.
+    ///
+    /// Adjacent text is always merged into a single [`PatternPart::Text`].
+    pub parts: Vec<PatternPart>,
+}
+
+/// A part of a [`Pattern`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)] // the three pattern part kinds of the specification
+pub enum PatternPart {
+    /// Literal text, with escape sequences resolved.
+    Text(String),
+    /// An expression placeholder, such as `{$count :number}`.
+    Expression(Expression),
+    /// A markup placeholder, such as `{#b}` or `{/b}`.
+    Markup(Markup),
+}
+
+/// An expression, consisting of an optional operand and an optional function.
+///
+/// At least one of `operand` and `function` is present.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // data model type
+pub struct Expression {
+    /// The operand of the expression.
+    pub operand: Option<Operand>,
+    /// The function annotating the expression.
+    pub function: Option<Function>,
+    /// The attributes of the expression, such as `@translate=no`.
+    pub attributes: Vec<Attribute>,
+}
+
+/// A literal or variable operand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)] // the two operand kinds of the specification
+pub enum Operand {
+    /// A literal, such as `hello`, `1.5` or `|hello world|`, with escape sequences resolved.
+    Literal(String),
+    /// A variable reference, such as `$name`, without the `$` sigil.
+    Variable(String),
+}
+
+/// A function annotation, such as `:number minimumFractionDigits=2`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // data model type
+pub struct Function {
+    /// The name of the function, without the `:` sigil, such as `number` or `ns:name`.
+    pub name: String,
+    /// The options of the function, in source order.
+    pub options: Vec<FunctionOption>,
+}
+
+/// An option of a [`Function`] or [`Markup`], such as `minimumFractionDigits=2`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // data model type
+pub struct FunctionOption {
+    /// The name of the option.
+    pub name: String,
+    /// The value of the option.
+    pub value: Operand,
+}
+
+/// A markup placeholder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // data model type
+pub struct Markup {
+    /// Whether this opens, closes, or is a standalone markup element.
+    pub kind: MarkupKind,
+    /// The name of the markup element, such as `b` in `{#b}`.
+    pub name: String,
+    /// The options of the markup element.
+    pub options: Vec<FunctionOption>,
+    /// The attributes of the markup element.
+    pub attributes: Vec<Attribute>,
+}
+
+/// The kind of a [`Markup`] placeholder.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)] // the three markup kinds of the specification
+pub enum MarkupKind {
+    /// An opening element, such as `{#b}`.
+    Open,
+    /// A standalone element, such as `{#img/}`.
+    Standalone,
+    /// A closing element, such as `{/b}`.
+    Close,
+}
+
+/// An attribute of an [`Expression`] or [`Markup`], such as `@translate=no`.
+///
+/// Attributes do not affect formatting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // data model type
+pub struct Attribute {
+    /// The name of the attribute.
+    pub name: String,
+    /// The literal value of the attribute, if any.
+    pub value: Option<String>,
+}
@@ -0,0 +1,281 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options of the built-in `:number`, `:integer`, `:datetime`, `:date` and `:time` functions.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use fixed_decimal::{Decimal, Sign, SignDisplay};
+use icu_datetime::fieldsets::{self, enums::*};
+use icu_datetime::options::TimePrecision;
+use icu_decimal::options::GroupingStrategy;
+use writeable::Writeable;
+
+use super::{FunctionOptions, MessageArgument, MessageError};
+
+/// Returns the value of an option as a string, if it has a string representation.
+pub(crate) fn option_str<'a>(value: &'a MessageArgument) -> Option<Cow<'a, str>> {
+    match value {
+        MessageArgument::String(s) => Some(Cow::Borrowed(s)),
+        MessageArgument::Number(n) => Some(n.write_to_string()),
+        MessageArgument::DateTime(_) => None,
+    }
+}
+
+fn bad_option(function: &str, option: &str) -> MessageError {
+    MessageError::BadOption {
+        function: function.into(),
+        option: option.into(),
+    }
+}
+
+/// How a `:number` or `:integer` selector matches keys.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub(crate) enum NumberSelect {
+    #[default]
+    Plural,
+    Ordinal,
+    Exact,
+}
+
+impl NumberSelect {
+    pub(crate) fn from_option(value: &str) -> Option<Self> {
+        match value {
+            "plural" => Some(Self::Plural),
+            "ordinal" => Some(Self::Ordinal),
+            "exact" => Some(Self::Exact),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn grouping_from_option(value: &str) -> Option<GroupingStrategy> {
+    match value {
+        "auto" => Some(GroupingStrategy::Auto),
+        "always" => Some(GroupingStrategy::Always),
+        "never" => Some(GroupingStrategy::Never),
+        "min2" => Some(GroupingStrategy::Min2),
+        _ => None,
+    }
+}
+
+/// The resolved options of a `:number` or `:integer` function.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct NumberOptions {
+    pub(crate) integer: bool,
+    pub(crate) select: NumberSelect,
+    pub(crate) grouping: GroupingStrategy,
+    minimum_integer_digits: Option<i16>,
+    minimum_fraction_digits: Option<i16>,
+    maximum_fraction_digits: Option<i16>,
+    sign_display: Option<SignDisplay>,
+}
+
+impl NumberOptions {
+    /// Updates these options, which may have been inherited from a previous
+    /// annotation, with the options of the function `function`.
+    ///
+    /// Options with unsupported values are skipped, and the first error is returned.
+    pub(crate) fn update(
+        &mut self,
+        function: &str,
+        options: &FunctionOptions,
+    ) -> Result<(), MessageError> {
+        let mut error = None;
+        for (name, value) in options.iter() {
+            let Some(value) = option_str(value) else {
+                error.get_or_insert(bad_option(function, name));
+                continue;
+            };
+            let digits = || value.parse::<u8>().ok().map(i16::from);
+            let valid = match name {
+                "select" => NumberSelect::from_option(&value).map(|s| self.select = s),
+                "useGrouping" => grouping_from_option(&value).map(|g| self.grouping = g),
+                "minimumIntegerDigits" => digits().map(|d| self.minimum_integer_digits = Some(d)),
+                "minimumFractionDigits" if !self.integer => {
+                    digits().map(|d| self.minimum_fraction_digits = Some(d))
+                }
+                "maximumFractionDigits" if !self.integer => {
+                    digits().map(|d| self.maximum_fraction_digits = Some(d))
+                }
+                "signDisplay" => match &*value {
+                    "auto" => Some(SignDisplay::Auto),
+                    "always" => Some(SignDisplay::Always),
+                    "never" => Some(SignDisplay::Never),
+                    "exceptZero" => Some(SignDisplay::ExceptZero),
+                    "negative" => Some(SignDisplay::Negative),
+                    _ => None,
+                }
+                .map(|s| self.sign_display = Some(s)),
+                // Unknown options are ignored
+                _ => Some(()),
+            };
+            if valid.is_none() {
+                error.get_or_insert(bad_option(function, name));
+            }
+        }
+        match error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Applies the rounding, padding and sign options to `value`.
+    pub(crate) fn apply(&self, mut value: Decimal) -> Decimal {
+        if self.integer {
+            value.trunc(0);
+        } else {
+            if let Some(max) = self.maximum_fraction_digits {
+                value.round(-max);
+            }
+            if let Some(min) = self.minimum_fraction_digits {
+                value.absolute.pad_end(-min);
+            }
+        }
+        if let Some(min) = self.minimum_integer_digits {
+            value.absolute.pad_start(min);
+        }
+        if let Some(sign_display) = self.sign_display {
+            value.apply_sign_display(sign_display);
+        }
+        value
+    }
+}
+
+/// Whether two numbers are numerically equal, ignoring trailing zeros and the sign of zero.
+pub(crate) fn numeric_eq(a: &Decimal, b: &Decimal) -> bool {
+    fn normalize(d: &Decimal) -> Decimal {
+        let mut d = d.clone();
+        d.absolute.trim_start();
+        d.absolute.trim_end();
+        if d.absolute.is_zero() || d.sign == Sign::Positive {
+            d.sign = Sign::None;
+        }
+        d
+    }
+    normalize(a) == normalize(b)
+}
+
+/// The `dateStyle`, `timeStyle` and `style` option values.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Style {
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+impl Style {
+    fn from_option(value: &str) -> Option<Self> {
+        match value {
+            "full" => Some(Self::Full),
+            "long" => Some(Self::Long),
+            "medium" => Some(Self::Medium),
+            "short" => Some(Self::Short),
+            _ => None,
+        }
+    }
+}
+
+/// The resolved options of a `:datetime`, `:date` or `:time` function.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct DateTimeStyle {
+    date: Option<Style>,
+    time: Option<Style>,
+}
+
+impl DateTimeStyle {
+    /// Resolves the style of the function `function`, looking up options with `get`.
+    ///
+    /// Unsupported option values are replaced by the defaults, and the first error is returned
+    /// alongside the style.
+    pub(crate) fn new<'a>(
+        function: &str,
+        get: impl Fn(&str) -> Option<Cow<'a, str>>,
+    ) -> (Self, Option<MessageError>) {
+        let mut error = None;
+        let mut style = |option: &str| {
+            let value = get(option)?;
+            let style = Style::from_option(&value);
+            if style.is_none() {
+                error.get_or_insert(bad_option(function, option));
+            }
+            style
+        };
+        let style = match function {
+            "date" => Self {
+                date: Some(style("style").unwrap_or(Style::Medium)),
+                time: None,
+            },
+            "time" => Self {
+                date: None,
+                time: Some(style("style").unwrap_or(Style::Short)),
+            },
+            _ => match (style("dateStyle"), style("timeStyle")) {
+                (None, None) => Self {
+                    date: Some(Style::Medium),
+                    time: Some(Style::Short),
+                },
+                (date, time) => Self { date, time },
+            },
+        };
+        (style, error)
+    }
+
+    /// Returns the field set used to format this style.
+    ///
+    /// The time style only determines the precision of the time, since time zones
+    /// are not supported.
+    pub(crate) fn field_set(self) -> CompositeDateTimeFieldSet {
+        let precision = match self.time {
+            Some(Style::Short) => TimePrecision::Minute,
+            _ => TimePrecision::Second,
+        };
+        match (self.date, self.time) {
+            (Some(date), None) => CompositeDateTimeFieldSet::Date(match date {
+                Style::Full => DateFieldSet::YMDE(fieldsets::YMDE::long()),
+                Style::Long => DateFieldSet::YMD(fieldsets::YMD::long()),
+                Style::Medium => DateFieldSet::YMD(fieldsets::YMD::medium()),
+                Style::Short => DateFieldSet::YMD(fieldsets::YMD::short()),
+            }),
+            (None, Some(time)) => CompositeDateTimeFieldSet::Time(TimeFieldSet::T(
+                match time {
+                    Style::Full | Style::Long => fieldsets::T::long(),
+                    Style::Medium => fieldsets::T::medium(),
+                    Style::Short => fieldsets::T::short(),
+                }
+                .with_time_precision(precision),
+            )),
+            (date, _) => CompositeDateTimeFieldSet::DateTime(match date {
+                Some(Style::Full) => {
+                    DateAndTimeFieldSet::YMDET(fieldsets::YMDE::long().with_time(precision))
+                }
+                Some(Style::Long) => {
+                    DateAndTimeFieldSet::YMDT(fieldsets::YMD::long().with_time(precision))
+                }
+                Some(Style::Short) => {
+                    DateAndTimeFieldSet::YMDT(fieldsets::YMD::short().with_time(precision))
+                }
+                Some(Style::Medium) | None => {
+                    DateAndTimeFieldSet::YMDT(fieldsets::YMD::medium().with_time(precision))
+                }
+            }),
+        }
+    }
+}
+
+/// Escapes a literal for use in a fallback representation.
+pub(crate) fn quote_literal(literal: &str) -> String {
+    let mut quoted = String::with_capacity(literal.len() + 2);
+    quoted.push('|');
+    for c in literal.chars() {
+        if matches!(c, '\\' | '|') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('|');
+    quoted
+}
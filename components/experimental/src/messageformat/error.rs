@@ -0,0 +1,88 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+
+use displaydoc::Display;
+use icu_datetime::DateTimeFormatterLoadError;
+use icu_provider::DataError;
+
+use super::ParseError;
+
+/// An error from constructing a [`MessageFormatter`](super::MessageFormatter).
+#[derive(Display, Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum MessageFormatterLoadError {
+    /// The message has a syntax or data model error.
+    #[displaydoc("{0}")]
+    Parse(ParseError),
+    /// An error while loading data for the functions used by the message.
+    #[displaydoc("{0}")]
+    Data(DataError),
+    /// An error while loading data for a `:datetime`, `:date` or `:time` function.
+    #[displaydoc("{0}")]
+    DateTime(DateTimeFormatterLoadError),
+}
+
+impl core::error::Error for MessageFormatterLoadError {}
+
+impl From<ParseError> for MessageFormatterLoadError {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl From<DataError> for MessageFormatterLoadError {
+    fn from(e: DataError) -> Self {
+        Self::Data(e)
+    }
+}
+
+impl From<DateTimeFormatterLoadError> for MessageFormatterLoadError {
+    fn from(e: DateTimeFormatterLoadError) -> Self {
+        Self::DateTime(e)
+    }
+}
+
+/// An error that occurred while formatting a message.
+///
+/// As required by the specification, formatting continues after an error: the
+/// expression that failed is replaced by a fallback representation such as `{$name}`,
+/// and the first error is returned alongside the output.
+///
+/// Custom functions report their errors with this type, too.
+#[derive(Display, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MessageError {
+    /// A variable was neither declared in the message nor passed as an argument.
+    #[displaydoc("unresolved variable ${0}")]
+    UnresolvedVariable(String),
+    /// A function is neither built in nor registered.
+    #[displaydoc("unknown function :{0}")]
+    UnknownFunction(String),
+    /// The operand of a function is missing or not supported by the function.
+    #[displaydoc("bad operand for :{0}")]
+    BadOperand(String),
+    /// An option of a function has an unsupported value.
+    #[displaydoc("bad option {option} for :{function}")]
+    BadOption {
+        /// The name of the function.
+        function: String,
+        /// The name of the option.
+        option: String,
+    },
+    /// A selector of a `.match` statement does not support selection.
+    #[displaydoc("bad selector ${0}")]
+    BadSelector(String),
+    /// A variant key is not valid for its selector, such as a key that is
+    /// neither a number nor a plural category for a `:number` selector.
+    #[displaydoc("bad variant key {0}")]
+    BadVariantKey(String),
+    /// A variable holds a value that can only be formatted with a function,
+    /// such as a date without `:datetime`.
+    #[displaydoc("missing annotation for ${0}")]
+    MissingAnnotation(String),
+}
+
+impl core::error::Error for MessageError {}
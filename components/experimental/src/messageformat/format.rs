@@ -0,0 +1,688 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt::{self, Write};
+use core::marker::PhantomData;
+
+use fixed_decimal::Decimal;
+use icu_calendar::{Date, Iso};
+use icu_decimal::options::GroupingStrategy;
+use icu_pattern::{
+    MissingNamedPlaceholderError, MultiNamedPlaceholderKey, PlaceholderValueProvider,
+};
+use icu_plurals::PluralCategory;
+use icu_time::{DateTime, Time};
+use writeable::adapters::WithPart;
+use writeable::{PartsWrite, TryWriteable, Writeable};
+
+use super::ast::{Expression, FunctionOption, Key, Operand};
+use super::builtins::{
+    numeric_eq, option_str, quote_literal, DateTimeStyle, NumberOptions, NumberSelect,
+};
+use super::formatter::{Body, CompiledPattern, Placeholder};
+use super::{
+    BidiIsolation, FunctionOptions, MessageArgument, MessageArguments, MessageError,
+    MessageFormatter, MessageFunction,
+};
+
+/// Part identifiers for the output of [`MessageFormatter`].
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used by [`FormattedMessage`](super::FormattedMessage) to mark the
+    /// text of the message.
+    pub const LITERAL: Part = Part {
+        category: "messageformat",
+        value: "literal",
+    };
+
+    /// The [`Part`] used by [`FormattedMessage`](super::FormattedMessage) to mark a
+    /// placeholder formatted as a number.
+    ///
+    /// The parts of the number, such as [`icu_decimal::parts::INTEGER`], are nested within it.
+    pub const NUMBER: Part = Part {
+        category: "messageformat",
+        value: "number",
+    };
+
+    /// The [`Part`] used by [`FormattedMessage`](super::FormattedMessage) to mark a
+    /// placeholder formatted as a date or time.
+    ///
+    /// The parts of the date, such as [`icu_datetime::parts::YEAR`], are nested within it.
+    pub const DATETIME: Part = Part {
+        category: "messageformat",
+        value: "datetime",
+    };
+
+    /// The [`Part`] used by [`FormattedMessage`](super::FormattedMessage) to mark a
+    /// placeholder formatted as a string, including the output of custom functions.
+    pub const STRING: Part = Part {
+        category: "messageformat",
+        value: "string",
+    };
+
+    /// The [`Part`] used by [`FormattedMessage`](super::FormattedMessage) to mark the
+    /// fallback representation of an expression that failed to format, such as `{$name}`.
+    pub const FALLBACK: Part = Part {
+        category: "messageformat",
+        value: "fallback",
+    };
+
+    /// The [`Part`] used by [`FormattedMessage`](super::FormattedMessage) to mark the
+    /// bidi isolation controls around a placeholder.
+    pub const BIDI_ISOLATION: Part = Part {
+        category: "messageformat",
+        value: "bidiIsolation",
+    };
+}
+
+/// A message with its arguments, returned by [`MessageFormatter::format`].
+///
+/// This is a [`TryWriteable`]: formatting never stops at an error, but the first
+/// error is returned alongside the output.
+#[derive(Debug)]
+pub struct FormattedMessage<'a> {
+    pub(crate) formatter: &'a MessageFormatter,
+    pub(crate) arguments: &'a MessageArguments<'a>,
+}
+
+impl TryWriteable for FormattedMessage<'_> {
+    type Error = MessageError;
+
+    fn try_write_to_parts<S: PartsWrite + ?Sized>(
+        &self,
+        sink: &mut S,
+    ) -> Result<Result<(), Self::Error>, fmt::Error> {
+        let mut context = Context {
+            formatter: self.formatter,
+            arguments: self.arguments,
+            locals: Vec::new(),
+            error: RefCell::new(None),
+        };
+        for declaration in &self.formatter.message.declarations {
+            let value = context.resolve_expression(declaration.value());
+            context.locals.push((declaration.name(), value));
+        }
+        if let Some(pattern) = context.select_pattern() {
+            // The interpolator records its errors in the context
+            let _infallible = pattern
+                .pattern
+                .try_interpolate(Interpolator {
+                    context: &context,
+                    placeholders: &pattern.placeholders,
+                })
+                .try_write_to_parts(sink)?;
+        }
+        Ok(match context.error.into_inner() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        })
+    }
+}
+
+/// The value of an expression or variable after function resolution.
+#[derive(Clone)]
+enum Resolved<'a> {
+    /// A value that has not been annotated with a function.
+    Unannotated(MessageArgument<'a>),
+    Number {
+        value: Decimal,
+        options: NumberOptions,
+    },
+    DateTime {
+        value: DateTime<Iso>,
+        style: DateTimeStyle,
+    },
+    String(Cow<'a, str>),
+    Custom {
+        function: &'a dyn MessageFunction,
+        operand: Option<MessageArgument<'a>>,
+        options: FunctionOptions<'a>,
+    },
+    /// The fallback representation of an expression that failed to resolve, without braces.
+    Fallback(String),
+}
+
+/// The default direction of a placeholder, or the value of its `u:dir` option.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Direction {
+    LeftToRight,
+    RightToLeft,
+    Auto,
+}
+
+struct Context<'a> {
+    formatter: &'a MessageFormatter,
+    arguments: &'a MessageArguments<'a>,
+    /// The values of the declarations, in source order.
+    locals: Vec<(&'a str, Resolved<'a>)>,
+    /// The first error that occurred.
+    error: RefCell<Option<MessageError>>,
+}
+
+impl<'a> Context<'a> {
+    fn report(&self, error: MessageError) {
+        self.error.borrow_mut().get_or_insert(error);
+    }
+
+    fn resolve_variable(&self, name: &'a str) -> Result<Resolved<'a>, MessageError> {
+        if let Some((_, value)) = self.locals.iter().rev().find(|(n, _)| *n == name) {
+            Ok(value.clone())
+        } else if let Some(argument) = self.arguments.get(name) {
+            Ok(Resolved::Unannotated(argument.reborrow()))
+        } else {
+            Err(MessageError::UnresolvedVariable(name.into()))
+        }
+    }
+
+    fn resolve_operand(&self, operand: &'a Operand) -> Result<Resolved<'a>, MessageError> {
+        match operand {
+            Operand::Literal(literal) => Ok(Resolved::Unannotated(MessageArgument::String(
+                Cow::Borrowed(literal),
+            ))),
+            Operand::Variable(name) => self.resolve_variable(name),
+        }
+    }
+
+    /// Converts a resolved value to a function argument, or `None` if it is a fallback.
+    fn to_argument(&self, value: &Resolved<'a>) -> Option<MessageArgument<'a>> {
+        match value {
+            Resolved::Unannotated(argument) => Some(argument.clone()),
+            Resolved::Number { value, options } => {
+                Some(MessageArgument::Number(options.apply(value.clone())))
+            }
+            Resolved::DateTime { value, .. } => Some(MessageArgument::DateTime(*value)),
+            Resolved::String(s) => Some(MessageArgument::String(s.clone())),
+            Resolved::Custom {
+                function,
+                operand,
+                options,
+            } => match function.format(operand.as_ref(), options) {
+                Ok(s) => Some(MessageArgument::String(Cow::Owned(s))),
+                Err(e) => {
+                    self.report(e);
+                    None
+                }
+            },
+            Resolved::Fallback(_) => None,
+        }
+    }
+
+    /// Resolves the values of options, skipping the options that fail to resolve.
+    fn resolve_options(&self, options: &'a [FunctionOption]) -> FunctionOptions<'a> {
+        FunctionOptions {
+            options: options
+                .iter()
+                .filter_map(|option| {
+                    let value = match self.resolve_operand(&option.value) {
+                        Ok(value) => self.to_argument(&value)?,
+                        Err(e) => {
+                            self.report(e);
+                            return None;
+                        }
+                    };
+                    Some((option.name.as_str(), value))
+                })
+                .collect(),
+        }
+    }
+
+    fn resolve_expression(&self, expression: &'a Expression) -> Resolved<'a> {
+        let operand = match &expression.operand {
+            Some(operand) => match self.resolve_operand(operand) {
+                // A failed declaration keeps its own fallback representation
+                Ok(Resolved::Fallback(fallback)) => return Resolved::Fallback(fallback),
+                Ok(value) => Some(value),
+                Err(e) => {
+                    self.report(e);
+                    return Resolved::Fallback(fallback(expression));
+                }
+            },
+            None => None,
+        };
+        let Some(function) = &expression.function else {
+            return operand.unwrap_or_else(|| Resolved::Fallback(fallback(expression)));
+        };
+        let options = self.resolve_options(&function.options);
+        match self.call_function(&function.name, operand, options, &function.options) {
+            Ok(value) => value,
+            Err(e) => {
+                self.report(e);
+                Resolved::Fallback(fallback(expression))
+            }
+        }
+    }
+
+    fn call_function(
+        &self,
+        name: &'a str,
+        operand: Option<Resolved<'a>>,
+        options: FunctionOptions<'a>,
+        option_sources: &'a [FunctionOption],
+    ) -> Result<Resolved<'a>, MessageError> {
+        let bad_operand = || MessageError::BadOperand(name.into());
+        match name {
+            "number" | "integer" => {
+                let (value, mut number_options) = match operand {
+                    // Options are inherited from a previous annotation
+                    Some(Resolved::Number { value, options }) => (value, options),
+                    Some(Resolved::Unannotated(MessageArgument::Number(value))) => {
+                        (value, NumberOptions::default())
+                    }
+                    Some(
+                        Resolved::Unannotated(MessageArgument::String(s)) | Resolved::String(s),
+                    ) => (
+                        Decimal::try_from_str(&s).map_err(|_| bad_operand())?,
+                        NumberOptions::default(),
+                    ),
+                    _ => return Err(bad_operand()),
+                };
+                number_options.integer = name == "integer";
+                if let Err(e) = number_options.update(name, &options) {
+                    self.report(e);
+                }
+                Ok(Resolved::Number {
+                    value,
+                    options: number_options,
+                })
+            }
+            "string" => match operand {
+                None => Ok(Resolved::String(Cow::Borrowed(""))),
+                Some(Resolved::DateTime { .. })
+                | Some(Resolved::Unannotated(MessageArgument::DateTime(_))) => Err(bad_operand()),
+                Some(operand) => match self.to_argument(&operand) {
+                    Some(MessageArgument::String(s)) => Ok(Resolved::String(s)),
+                    Some(MessageArgument::Number(n)) => {
+                        Ok(Resolved::String(n.write_to_string().into_owned().into()))
+                    }
+                    _ => Err(bad_operand()),
+                },
+            },
+            "datetime" | "date" | "time" => {
+                let value = match operand {
+                    Some(
+                        Resolved::DateTime { value, .. }
+                        | Resolved::Unannotated(MessageArgument::DateTime(value)),
+                    ) => value,
+                    Some(
+                        Resolved::Unannotated(MessageArgument::String(s)) | Resolved::String(s),
+                    ) => parse_datetime(&s).ok_or_else(bad_operand)?,
+                    _ => return Err(bad_operand()),
+                };
+                // The styles are chosen when the formatter is created, so they must be literals
+                for option in option_sources {
+                    if matches!(option.value, Operand::Variable(_))
+                        && matches!(option.name.as_str(), "style" | "dateStyle" | "timeStyle")
+                    {
+                        self.report(MessageError::BadOption {
+                            function: name.into(),
+                            option: option.name.clone(),
+                        });
+                    }
+                }
+                let (style, error) = DateTimeStyle::new(name, |option| {
+                    option_sources
+                        .iter()
+                        .find(|o| o.name == option)
+                        .and_then(|o| match &o.value {
+                            Operand::Literal(value) => Some(Cow::Borrowed(value.as_str())),
+                            Operand::Variable(_) => None,
+                        })
+                });
+                if let Some(e) = error {
+                    self.report(e);
+                }
+                Ok(Resolved::DateTime { value, style })
+            }
+            _ => {
+                let function = self
+                    .formatter
+                    .functions
+                    .get(name)
+                    .ok_or_else(|| MessageError::UnknownFunction(name.into()))?;
+                Ok(Resolved::Custom {
+                    function,
+                    operand: operand.and_then(|o| self.to_argument(&o)),
+                    options,
+                })
+            }
+        }
+    }
+
+    fn select_pattern(&self) -> Option<&'a CompiledPattern> {
+        let (selectors, variants) = match &self.formatter.message.body {
+            Body::Pattern(pattern) => return Some(pattern),
+            Body::Select {
+                selectors,
+                variants,
+            } => (selectors, variants),
+        };
+        // For each selector, the indices of the matching keys, best match first
+        let matches: Vec<(Vec<&str>, Vec<usize>)> = selectors
+            .iter()
+            .enumerate()
+            .map(|(i, selector)| {
+                let keys: Vec<&str> = variants
+                    .iter()
+                    .filter_map(|(keys, _)| match keys.get(i) {
+                        Some(Key::Literal(key)) => Some(key.as_str()),
+                        _ => None,
+                    })
+                    .collect();
+                let matching = self.match_keys(selector, &keys);
+                (keys, matching)
+            })
+            .collect();
+
+        let mut best: Option<(Vec<usize>, &CompiledPattern)> = None;
+        'variants: for (keys, pattern) in variants {
+            let mut rank = Vec::with_capacity(keys.len());
+            for (key, (selector_keys, matching)) in keys.iter().zip(&matches) {
+                rank.push(match key {
+                    Key::CatchAll => matching.len(),
+                    Key::Literal(key) => {
+                        match matching
+                            .iter()
+                            .position(|&m| selector_keys.get(m) == Some(&key.as_str()))
+                        {
+                            Some(position) => position,
+                            None => continue 'variants,
+                        }
+                    }
+                });
+            }
+            // Ties are won by the first variant in source order
+            if best.as_ref().is_none_or(|(best_rank, _)| rank < *best_rank) {
+                best = Some((rank, pattern));
+            }
+        }
+        best.map(|(_, pattern)| pattern)
+    }
+
+    /// Returns the indices of the `keys` that match the selector, best match first.
+    fn match_keys(&self, selector: &'a str, keys: &[&str]) -> Vec<usize> {
+        let value = match self.resolve_variable(selector) {
+            // The error was reported when resolving the declaration
+            Ok(Resolved::Fallback(_)) => return Vec::new(),
+            Ok(value) => value,
+            Err(e) => {
+                self.report(e);
+                return Vec::new();
+            }
+        };
+        match value {
+            Resolved::Number { value, options } => {
+                let value = options.apply(value);
+                let rules = match options.select {
+                    NumberSelect::Plural => self.formatter.cardinal_rules.as_ref(),
+                    NumberSelect::Ordinal => self.formatter.ordinal_rules.as_ref(),
+                    NumberSelect::Exact => None,
+                };
+                let category = rules.map(|rules| rules.category_for(&value));
+                let mut exact = Vec::new();
+                let mut plural = Vec::new();
+                for (i, key) in keys.iter().enumerate() {
+                    if let Ok(number) = Decimal::try_from_str(key) {
+                        if numeric_eq(&number, &value) {
+                            exact.push(i);
+                        }
+                    } else if let Some(key_category) = PluralCategory::get_for_cldr_string(key) {
+                        if Some(key_category) == category {
+                            plural.push(i);
+                        }
+                    } else {
+                        self.report(MessageError::BadVariantKey((*key).into()));
+                    }
+                }
+                exact.extend(plural);
+                exact
+            }
+            Resolved::String(s) => keys
+                .iter()
+                .enumerate()
+                .filter_map(|(i, key)| (*key == s).then_some(i))
+                .collect(),
+            Resolved::Custom {
+                function,
+                operand,
+                options,
+            } => match function.select(operand.as_ref(), &options, keys) {
+                Ok(matching) => matching,
+                Err(e) => {
+                    self.report(e);
+                    Vec::new()
+                }
+            },
+            _ => {
+                self.report(MessageError::BadSelector(selector.into()));
+                Vec::new()
+            }
+        }
+    }
+
+    fn write_expression<S: PartsWrite + ?Sized>(
+        &self,
+        expression: &'a Expression,
+        sink: &mut S,
+    ) -> fmt::Result {
+        let value = self.resolve_expression(expression);
+        if self.formatter.options.bidi_isolation == BidiIsolation::None {
+            return self.write_value(expression, &value, sink);
+        }
+        let message_direction = if self.formatter.right_to_left {
+            Direction::RightToLeft
+        } else {
+            Direction::LeftToRight
+        };
+        let direction = self.direction_option(expression).unwrap_or(match value {
+            Resolved::Number { .. }
+            | Resolved::DateTime { .. }
+            | Resolved::Unannotated(MessageArgument::Number(_)) => message_direction,
+            _ => Direction::Auto,
+        });
+        let isolate = match (direction, message_direction) {
+            (Direction::LeftToRight, Direction::LeftToRight) => None,
+            (Direction::LeftToRight, _) => Some('\u{2066}'),
+            (Direction::RightToLeft, _) => Some('\u{2067}'),
+            (Direction::Auto, _) => Some('\u{2068}'),
+        };
+        if let Some(isolate) = isolate {
+            sink.with_part(parts::BIDI_ISOLATION, |w| w.write_char(isolate))?;
+        }
+        self.write_value(expression, &value, sink)?;
+        if isolate.is_some() {
+            sink.with_part(parts::BIDI_ISOLATION, |w| w.write_char('\u{2069}'))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the direction set by the `u:dir` option, if any.
+    fn direction_option(&self, expression: &'a Expression) -> Option<Direction> {
+        let function = expression.function.as_ref()?;
+        let option = function.options.iter().find(|o| o.name == "u:dir")?;
+        let value = self.to_argument(&self.resolve_operand(&option.value).ok()?)?;
+        match option_str(&value).as_deref() {
+            Some("ltr") => Some(Direction::LeftToRight),
+            Some("rtl") => Some(Direction::RightToLeft),
+            Some("auto") => Some(Direction::Auto),
+            Some("inherit") => Some(if self.formatter.right_to_left {
+                Direction::RightToLeft
+            } else {
+                Direction::LeftToRight
+            }),
+            _ => {
+                self.report(MessageError::BadOption {
+                    function: function.name.clone(),
+                    option: option.name.clone(),
+                });
+                None
+            }
+        }
+    }
+
+    fn write_value<S: PartsWrite + ?Sized>(
+        &self,
+        expression: &Expression,
+        value: &Resolved<'a>,
+        sink: &mut S,
+    ) -> fmt::Result {
+        match value {
+            Resolved::Unannotated(MessageArgument::Number(value)) => {
+                self.write_number(value.clone(), GroupingStrategy::Auto, sink)
+            }
+            Resolved::Number { value, options } => {
+                self.write_number(options.apply(value.clone()), options.grouping, sink)
+            }
+            Resolved::Unannotated(MessageArgument::String(s)) | Resolved::String(s) => {
+                sink.with_part(parts::STRING, |w| w.write_str(s))
+            }
+            Resolved::Unannotated(MessageArgument::DateTime(_)) => {
+                let name = match &expression.operand {
+                    Some(Operand::Variable(name)) => name.as_str(),
+                    _ => "",
+                };
+                self.report(MessageError::MissingAnnotation(name.into()));
+                write_fallback(&fallback(expression), sink)
+            }
+            Resolved::DateTime { value, style } => {
+                match self
+                    .formatter
+                    .datetime_formatters
+                    .iter()
+                    .find(|(s, _)| s == style)
+                {
+                    Some((_, formatter)) => sink.with_part(parts::DATETIME, |w| {
+                        formatter.format(value).write_to_parts(w)
+                    }),
+                    None => write_fallback(&fallback(expression), sink),
+                }
+            }
+            Resolved::Custom {
+                function,
+                operand,
+                options,
+            } => match function.format(operand.as_ref(), options) {
+                Ok(s) => sink.with_part(parts::STRING, |w| w.write_str(&s)),
+                Err(e) => {
+                    self.report(e);
+                    write_fallback(&fallback(expression), sink)
+                }
+            },
+            Resolved::Fallback(fallback) => write_fallback(fallback, sink),
+        }
+    }
+
+    fn write_number<S: PartsWrite + ?Sized>(
+        &self,
+        value: Decimal,
+        grouping: GroupingStrategy,
+        sink: &mut S,
+    ) -> fmt::Result {
+        let formatters = &self.formatter.decimal_formatters;
+        let formatter = formatters
+            .iter()
+            .find(|(g, _)| *g == grouping)
+            .or_else(|| formatters.first());
+        sink.with_part(parts::NUMBER, |w| match formatter {
+            Some((_, formatter)) => formatter.format(&value).write_to_parts(w),
+            None => value.write_to_parts(w),
+        })
+    }
+}
+
+/// Parses an RFC 9557 date or date-time string, such as `2025-03-14` or `2025-03-14T15:09`.
+fn parse_datetime(s: &str) -> Option<DateTime<Iso>> {
+    DateTime::try_from_str(s, Iso).ok().or_else(|| {
+        Some(DateTime {
+            date: Date::try_from_str(s, Iso).ok()?,
+            time: Time::start_of_day(),
+        })
+    })
+}
+
+/// Returns the fallback representation of an expression, without braces.
+fn fallback(expression: &Expression) -> String {
+    match (&expression.operand, &expression.function) {
+        (Some(Operand::Variable(name)), _) => alloc::format!("${name}"),
+        (Some(Operand::Literal(literal)), _) => quote_literal(literal),
+        (None, Some(function)) => alloc::format!(":{}", function.name),
+        (None, None) => String::new(),
+    }
+}
+
+fn write_fallback<S: PartsWrite + ?Sized>(fallback: &str, sink: &mut S) -> fmt::Result {
+    sink.with_part(parts::FALLBACK, |w| {
+        w.write_char('{')?;
+        w.write_str(fallback)?;
+        w.write_char('}')
+    })
+}
+
+/// Provides the values of the placeholders of a [`CompiledPattern`].
+struct Interpolator<'c, 'a> {
+    context: &'c Context<'a>,
+    placeholders: &'a [Placeholder],
+}
+
+impl<'c, 'a, 'k> PlaceholderValueProvider<MultiNamedPlaceholderKey<'k>> for Interpolator<'c, 'a> {
+    type Error = MissingNamedPlaceholderError<'k>;
+
+    type W<'b>
+        = PlaceholderValue<'c, 'a, 'k>
+    where
+        Self: 'b;
+
+    type L<'b, 'l>
+        = WithPart<&'l str>
+    where
+        Self: 'b;
+
+    fn value_for(&self, key: MultiNamedPlaceholderKey<'k>) -> Self::W<'_> {
+        PlaceholderValue {
+            context: self.context,
+            placeholder: key
+                .0
+                .parse::<usize>()
+                .ok()
+                .and_then(|i| self.placeholders.get(i)),
+            key: PhantomData,
+        }
+    }
+
+    fn map_literal<'b, 'l>(&'b self, literal: &'l str) -> Self::L<'b, 'l> {
+        WithPart {
+            part: parts::LITERAL,
+            writeable: literal,
+        }
+    }
+}
+
+struct PlaceholderValue<'c, 'a, 'k> {
+    context: &'c Context<'a>,
+    placeholder: Option<&'a Placeholder>,
+    key: PhantomData<MultiNamedPlaceholderKey<'k>>,
+}
+
+impl<'k> TryWriteable for PlaceholderValue<'_, '_, 'k> {
+    // Errors are recorded in the context instead
+    type Error = MissingNamedPlaceholderError<'k>;
+
+    fn try_write_to_parts<S: PartsWrite + ?Sized>(
+        &self,
+        sink: &mut S,
+    ) -> Result<Result<(), Self::Error>, fmt::Error> {
+        match self.placeholder {
+            Some(Placeholder::Text(text)) => sink.with_part(parts::LITERAL, |w| w.write_str(text)),
+            Some(Placeholder::Expression(expression)) => {
+                self.context.write_expression(expression, sink)
+            }
+            Some(Placeholder::Markup) | None => Ok(()),
+        }
+        .map(Ok)
+    }
+}
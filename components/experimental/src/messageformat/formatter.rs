@@ -0,0 +1,462 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use icu_datetime::fieldsets::enums::CompositeDateTimeFieldSet;
+use icu_datetime::scaffold::{
+    AllAnyCalendarExternalDataMarkers, AllAnyCalendarFormattingDataMarkers,
+};
+use icu_datetime::{DateTimeFormatter, DateTimeFormatterLoadError, DateTimeFormatterPreferences};
+use icu_decimal::options::{DecimalFormatterOptions, GroupingStrategy};
+use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV1};
+use icu_decimal::{DecimalFormatter, DecimalFormatterPreferences};
+use icu_locale::provider::{
+    LocaleLikelySubtagsLanguageV1, LocaleLikelySubtagsScriptRegionV1, LocaleScriptDirectionV1,
+};
+use icu_locale::LocaleDirectionality;
+use icu_locale_core::preferences::extensions::unicode::keywords::{
+    CalendarAlgorithm, HourCycle, NumberingSystem,
+};
+use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_pattern::{MultiNamedPlaceholder, MultiNamedPlaceholderKeyCow, Pattern, PatternItemCow};
+use icu_plurals::provider::{PluralsCardinalV1, PluralsOrdinalV1};
+use icu_plurals::{PluralRuleType, PluralRules, PluralRulesPreferences};
+use icu_provider::prelude::*;
+
+use super::ast::{self, Expression, Key, Message, Operand, PatternPart};
+use super::builtins::{grouping_from_option, DateTimeStyle, NumberSelect};
+use super::{
+    parse, FormattedMessage, FunctionRegistry, MessageArguments, MessageFormatterLoadError,
+};
+
+define_preferences!(
+    /// The preferences for message formatting.
+    [Copy]
+    MessageFormatterPreferences,
+    {
+        /// The user's preferred numbering system, used by `:number`, `:integer`
+        /// and the date and time functions.
+        ///
+        /// Corresponds to the `-u-nu` in Unicode Locale Identifier.
+        numbering_system: NumberingSystem,
+        /// The user's preferred hour cycle, used by `:datetime` and `:time`.
+        ///
+        /// Corresponds to the `-u-hc` in Unicode Locale Identifier.
+        hour_cycle: HourCycle,
+        /// The user's preferred calendar system, used by `:datetime` and `:date`.
+        ///
+        /// Corresponds to the `-u-ca` in Unicode Locale Identifier.
+        calendar_algorithm: CalendarAlgorithm
+    }
+);
+
+prefs_convert!(MessageFormatterPreferences, DecimalFormatterPreferences, {
+    numbering_system
+});
+prefs_convert!(MessageFormatterPreferences, PluralRulesPreferences);
+prefs_convert!(MessageFormatterPreferences, DateTimeFormatterPreferences, {
+    numbering_system,
+    hour_cycle,
+    calendar_algorithm
+});
+
+/// The strategy for isolating the formatted placeholders from the surrounding text.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BidiIsolation {
+    /// Isolates placeholders using Unicode bidi isolation controls, following the
+    /// [default bidi strategy](https://www.unicode.org/reports/tr35/tr35-messageFormat.html#handling-bidirectional-text)
+    /// of the specification.
+    ///
+    /// The direction of a placeholder can be set with the `u:dir` option.
+    #[default]
+    Default,
+    /// Does not isolate placeholders.
+    None,
+}
+
+/// A bag of options for [`MessageFormatter`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MessageFormatterOptions {
+    /// The strategy for isolating placeholders.
+    pub bidi_isolation: BidiIsolation,
+}
+
+impl From<BidiIsolation> for MessageFormatterOptions {
+    fn from(bidi_isolation: BidiIsolation) -> Self {
+        Self { bidi_isolation }
+    }
+}
+
+/// A placeholder of a [`CompiledPattern`].
+#[derive(Debug)]
+pub(crate) enum Placeholder {
+    /// Text that cannot be stored as a pattern literal.
+    Text(String),
+    Expression(Expression),
+    Markup,
+}
+
+/// A pattern of a message, stored as an [`icu_pattern`] pattern whose placeholders
+/// are the indices of its expressions.
+#[derive(Debug)]
+pub(crate) struct CompiledPattern {
+    pub(crate) pattern: Box<Pattern<MultiNamedPlaceholder>>,
+    pub(crate) placeholders: Vec<Placeholder>,
+}
+
+impl CompiledPattern {
+    fn new(pattern: ast::Pattern) -> Self {
+        let mut items = Vec::new();
+        let mut placeholders = Vec::new();
+        for part in pattern.parts {
+            let placeholder = match part {
+                // Code points up to U+0007 are reserved by the pattern store
+                PatternPart::Text(text) if text.contains(|c| c <= '\u{7}') => {
+                    Placeholder::Text(text)
+                }
+                PatternPart::Text(text) => {
+                    items.push(PatternItemCow::Literal(Cow::Owned(text)));
+                    continue;
+                }
+                PatternPart::Expression(expression) => Placeholder::Expression(expression),
+                PatternPart::Markup(_) => Placeholder::Markup,
+            };
+            items.push(PatternItemCow::Placeholder(MultiNamedPlaceholderKeyCow(
+                Cow::Owned(placeholders.len().to_string()),
+            )));
+            placeholders.push(placeholder);
+        }
+        Self {
+            // Placeholder names are short and literals are free of reserved code points
+            pattern: Pattern::try_from_items(items.into_iter()).unwrap_or_default(),
+            placeholders,
+        }
+    }
+
+    fn expressions(&self) -> impl Iterator<Item = &Expression> + '_ {
+        self.placeholders.iter().filter_map(|p| match p {
+            Placeholder::Expression(e) => Some(e),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum Body {
+    Pattern(CompiledPattern),
+    Select {
+        selectors: Vec<String>,
+        variants: Vec<(Vec<Key>, CompiledPattern)>,
+    },
+}
+
+/// The parts of a message that are needed at formatting time.
+#[derive(Debug)]
+pub(crate) struct CompiledMessage {
+    pub(crate) declarations: Vec<ast::Declaration>,
+    pub(crate) body: Body,
+}
+
+impl CompiledMessage {
+    fn new(message: Message) -> Self {
+        match message {
+            Message::Pattern(message) => Self {
+                declarations: message.declarations,
+                body: Body::Pattern(CompiledPattern::new(message.pattern)),
+            },
+            Message::Select(message) => Self {
+                declarations: message.declarations,
+                body: Body::Select {
+                    selectors: message.selectors,
+                    variants: message
+                        .variants
+                        .into_iter()
+                        .map(|v| (v.keys, CompiledPattern::new(v.value)))
+                        .collect(),
+                },
+            },
+        }
+    }
+
+    fn expressions(&self) -> impl Iterator<Item = &Expression> + '_ {
+        let patterns: Vec<&CompiledPattern> = match &self.body {
+            Body::Pattern(pattern) => alloc::vec![pattern],
+            Body::Select { variants, .. } => variants.iter().map(|(_, p)| p).collect(),
+        };
+        self.declarations
+            .iter()
+            .map(|d| d.value())
+            .chain(patterns.into_iter().flat_map(|p| p.expressions()))
+    }
+}
+
+/// The formatters and rules needed by a message.
+#[derive(Debug, Default)]
+struct Requirements {
+    groupings: Vec<GroupingStrategy>,
+    cardinal: bool,
+    ordinal: bool,
+    datetime_styles: Vec<DateTimeStyle>,
+}
+
+impl Requirements {
+    fn new(message: &CompiledMessage) -> Self {
+        let mut requirements = Self::default();
+        let is_select = matches!(message.body, Body::Select { .. });
+        for expression in message.expressions() {
+            let Some(function) = &expression.function else {
+                // Unannotated numbers are formatted with the default options
+                if matches!(expression.operand, Some(Operand::Variable(_))) {
+                    requirements.add_grouping(GroupingStrategy::Auto);
+                }
+                continue;
+            };
+            let literal_option = |name: &str| {
+                function
+                    .options
+                    .iter()
+                    .find(|o| o.name == name)
+                    .map(|o| &o.value)
+            };
+            match function.name.as_str() {
+                "number" | "integer" => {
+                    requirements.add_grouping(GroupingStrategy::Auto);
+                    match literal_option("useGrouping") {
+                        Some(Operand::Literal(value)) => {
+                            if let Some(grouping) = grouping_from_option(value) {
+                                requirements.add_grouping(grouping);
+                            }
+                        }
+                        Some(Operand::Variable(_)) => {
+                            for grouping in [
+                                GroupingStrategy::Always,
+                                GroupingStrategy::Never,
+                                GroupingStrategy::Min2,
+                            ] {
+                                requirements.add_grouping(grouping);
+                            }
+                        }
+                        None => (),
+                    }
+                    if is_select {
+                        match literal_option("select") {
+                            Some(Operand::Literal(value)) => {
+                                match NumberSelect::from_option(value) {
+                                    Some(NumberSelect::Ordinal) => requirements.ordinal = true,
+                                    Some(NumberSelect::Exact) => (),
+                                    _ => requirements.cardinal = true,
+                                }
+                            }
+                            Some(Operand::Variable(_)) => {
+                                requirements.cardinal = true;
+                                requirements.ordinal = true;
+                            }
+                            None => requirements.cardinal = true,
+                        }
+                    }
+                }
+                name @ ("datetime" | "date" | "time") => {
+                    let (style, _) =
+                        DateTimeStyle::new(name, |option| match literal_option(option) {
+                            Some(Operand::Literal(value)) => Some(Cow::Borrowed(value.as_str())),
+                            _ => None,
+                        });
+                    if !requirements.datetime_styles.contains(&style) {
+                        requirements.datetime_styles.push(style);
+                    }
+                }
+                _ => (),
+            }
+        }
+        requirements
+    }
+
+    fn add_grouping(&mut self, grouping: GroupingStrategy) {
+        if !self.groupings.contains(&grouping) {
+            self.groupings.push(grouping);
+        }
+    }
+}
+
+/// A formatter for messages in the [Unicode MessageFormat 2.0] syntax.
+///
+/// The message is parsed and the data for the functions it uses is loaded at construction.
+/// The built-in `:number`, `:integer`, `:string`, `:datetime`, `:date` and `:time`
+/// functions are supported, and custom functions can be added with [`Self::with_functions`].
+///
+/// See the [module-level documentation](super) for examples.
+///
+/// [Unicode MessageFormat 2.0]: https://www.unicode.org/reports/tr35/tr35-messageFormat.html
+#[derive(Debug)]
+pub struct MessageFormatter {
+    pub(crate) message: CompiledMessage,
+    pub(crate) right_to_left: bool,
+    pub(crate) options: MessageFormatterOptions,
+    pub(crate) decimal_formatters: Vec<(GroupingStrategy, DecimalFormatter)>,
+    pub(crate) cardinal_rules: Option<PluralRules>,
+    pub(crate) ordinal_rules: Option<PluralRules>,
+    pub(crate) datetime_formatters:
+        Vec<(DateTimeStyle, DateTimeFormatter<CompositeDateTimeFieldSet>)>,
+    pub(crate) functions: FunctionRegistry,
+}
+
+impl MessageFormatter {
+    /// Creates a new [`MessageFormatter`] for the given message from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: MessageFormatterPreferences,
+        message: &str,
+        options: MessageFormatterOptions,
+    ) -> Result<Self, MessageFormatterLoadError> {
+        Self::try_new_internal(
+            prefs,
+            message,
+            options,
+            || Ok(LocaleDirectionality::new_common()),
+            |grouping_strategy| {
+                DecimalFormatter::try_new((&prefs).into(), grouping_options(grouping_strategy))
+            },
+            |rule_type| PluralRules::try_new((&prefs).into(), rule_type.into()),
+            |style| DateTimeFormatter::try_new((&prefs).into(), style.field_set()),
+        )
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: MessageFormatterPreferences, message: &str, options: MessageFormatterOptions) -> error: MessageFormatterLoadError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: MessageFormatterPreferences,
+        message: &str,
+        options: MessageFormatterOptions,
+    ) -> Result<Self, MessageFormatterLoadError>
+    where
+        D: DataProvider<PluralsCardinalV1>
+            + DataProvider<PluralsOrdinalV1>
+            + DataProvider<DecimalSymbolsV1>
+            + DataProvider<DecimalDigitsV1>
+            + DataProvider<LocaleScriptDirectionV1>
+            + DataProvider<LocaleLikelySubtagsLanguageV1>
+            + DataProvider<LocaleLikelySubtagsScriptRegionV1>
+            + AllAnyCalendarFormattingDataMarkers<CompositeDateTimeFieldSet>
+            + AllAnyCalendarExternalDataMarkers
+            + ?Sized,
+    {
+        Self::try_new_internal(
+            prefs,
+            message,
+            options,
+            || LocaleDirectionality::try_new_common_unstable(provider),
+            |grouping_strategy| {
+                DecimalFormatter::try_new_unstable(
+                    provider,
+                    (&prefs).into(),
+                    grouping_options(grouping_strategy),
+                )
+            },
+            |rule_type| PluralRules::try_new_unstable(provider, (&prefs).into(), rule_type.into()),
+            |style| {
+                DateTimeFormatter::try_new_unstable(provider, (&prefs).into(), style.field_set())
+            },
+        )
+    }
+
+    fn try_new_internal(
+        prefs: MessageFormatterPreferences,
+        message: &str,
+        options: MessageFormatterOptions,
+        load_directionality: impl FnOnce() -> Result<LocaleDirectionality, DataError>,
+        load_decimal: impl Fn(GroupingStrategy) -> Result<DecimalFormatter, DataError>,
+        load_plural_rules: impl Fn(PluralRuleType) -> Result<PluralRules, DataError>,
+        load_datetime: impl Fn(
+            DateTimeStyle,
+        ) -> Result<
+            DateTimeFormatter<CompositeDateTimeFieldSet>,
+            DateTimeFormatterLoadError,
+        >,
+    ) -> Result<Self, MessageFormatterLoadError> {
+        let message = CompiledMessage::new(parse(message)?);
+        let requirements = Requirements::new(&message);
+
+        let right_to_left = if options.bidi_isolation == BidiIsolation::None {
+            false
+        } else {
+            let langid = prefs
+                .locale_preferences
+                .to_data_locale_language_priority()
+                .into_locale()
+                .id;
+            load_directionality()?.is_right_to_left(&langid)
+        };
+
+        Ok(Self {
+            message,
+            right_to_left,
+            options,
+            decimal_formatters: requirements
+                .groupings
+                .into_iter()
+                .map(|g| Ok((g, load_decimal(g)?)))
+                .collect::<Result<_, DataError>>()?,
+            cardinal_rules: requirements
+                .cardinal
+                .then(|| load_plural_rules(PluralRuleType::Cardinal))
+                .transpose()?,
+            ordinal_rules: requirements
+                .ordinal
+                .then(|| load_plural_rules(PluralRuleType::Ordinal))
+                .transpose()?,
+            datetime_formatters: requirements
+                .datetime_styles
+                .into_iter()
+                .map(|s| Ok((s, load_datetime(s)?)))
+                .collect::<Result<_, DateTimeFormatterLoadError>>()?,
+            functions: FunctionRegistry::new(),
+        })
+    }
+
+    /// Sets the custom functions that can be called from the message.
+    ///
+    /// See [`MessageFunction`](super::MessageFunction) for an example.
+    pub fn with_functions(mut self, functions: FunctionRegistry) -> Self {
+        self.functions = functions;
+        self
+    }
+
+    /// Formats the message with the given arguments.
+    ///
+    /// The result is a [`TryWriteable`](writeable::TryWriteable): if an error occurs, the
+    /// output still contains a fallback representation of the failed expression, and the
+    /// first error is returned alongside it.
+    pub fn format<'a>(&'a self, arguments: &'a MessageArguments<'a>) -> FormattedMessage<'a> {
+        FormattedMessage {
+            formatter: self,
+            arguments,
+        }
+    }
+}
+
+fn grouping_options(grouping_strategy: GroupingStrategy) -> DecimalFormatterOptions {
+    let mut options = DecimalFormatterOptions::default();
+    options.grouping_strategy = Some(grouping_strategy);
+    options
+}
@@ -0,0 +1,156 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::{MessageArgument, MessageError};
+
+/// The names of the functions built into [`MessageFormatter`](super::MessageFormatter).
+///
+/// These cannot be replaced by custom functions.
+pub const BUILTIN_FUNCTIONS: &[&str] = &["number", "integer", "string", "datetime", "date", "time"];
+
+/// A custom function that can be called from a message.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::messageformat::*;
+/// use icu::locale::locale;
+/// use writeable::assert_try_writeable_eq;
+///
+/// struct Uppercase;
+///
+/// impl MessageFunction for Uppercase {
+///     fn format(
+///         &self,
+///         operand: Option<&MessageArgument>,
+///         _options: &FunctionOptions,
+///     ) -> Result<String, MessageError> {
+///         match operand {
+///             Some(MessageArgument::String(s)) => Ok(s.to_uppercase()),
+///             _ => Err(MessageError::BadOperand("x:upper".into())),
+///         }
+///     }
+/// }
+///
+/// let formatter = MessageFormatter::try_new(
+///     locale!("en").into(),
+///     "Hello, {$name :x:upper}!",
+///     Default::default(),
+/// )
+/// .unwrap()
+/// .with_functions(FunctionRegistry::new().with("x:upper", Uppercase));
+///
+/// let arguments = MessageArguments::new().with("name", "Alice");
+///
+/// assert_try_writeable_eq!(
+///     formatter.format(&arguments),
+///     "Hello, \u{2068}ALICE\u{2069}!"
+/// );
+///
+/// // Errors are reported, and the expression is replaced by a fallback.
+/// let arguments = MessageArguments::new().with("name", 5);
+/// assert_try_writeable_eq!(
+///     formatter.format(&arguments),
+///     "Hello, \u{2068}{$name}\u{2069}!",
+///     Err(MessageError::BadOperand("x:upper".into()))
+/// );
+/// ```
+pub trait MessageFunction {
+    /// Formats the operand.
+    ///
+    /// Option values that are variables have already been resolved.
+    fn format(
+        &self,
+        operand: Option<&MessageArgument>,
+        options: &FunctionOptions,
+    ) -> Result<String, MessageError>;
+
+    /// Returns the indices of the `keys` that match the operand, best match first.
+    ///
+    /// By default, the keys that are equal to the formatted operand match.
+    fn select(
+        &self,
+        operand: Option<&MessageArgument>,
+        options: &FunctionOptions,
+        keys: &[&str],
+    ) -> Result<Vec<usize>, MessageError> {
+        let value = self.format(operand, options)?;
+        Ok(keys.iter().position(|&k| k == value).into_iter().collect())
+    }
+}
+
+/// The resolved options of a function call.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FunctionOptions<'a> {
+    pub(crate) options: Vec<(&'a str, MessageArgument<'a>)>,
+}
+
+impl<'a> FunctionOptions<'a> {
+    /// Returns the value of the option with the given name.
+    pub fn get(&self, name: &str) -> Option<&MessageArgument<'a>> {
+        self.options
+            .iter()
+            .find_map(|(n, v)| (*n == name).then_some(v))
+    }
+
+    /// Returns an iterator over the names and values of the options, in source order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &MessageArgument<'a>)> + '_ {
+        self.options.iter().map(|(n, v)| (*n, v))
+    }
+}
+
+/// A set of custom functions, by name.
+///
+/// Names can have a namespace, such as `x:upper`. Names of
+/// [built-in functions](BUILTIN_FUNCTIONS) are ignored.
+///
+/// See [`MessageFunction`] for an example.
+#[derive(Default)]
+pub struct FunctionRegistry {
+    functions: BTreeMap<String, Box<dyn MessageFunction>>,
+}
+
+impl fmt::Debug for FunctionRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.functions.keys()).finish()
+    }
+}
+
+impl FunctionRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a function, replacing any previous function with the same name.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        function: impl MessageFunction + 'static,
+    ) -> &mut Self {
+        self.functions.insert(name.into(), Box::new(function));
+        self
+    }
+
+    /// Registers a function, replacing any previous function with the same name.
+    pub fn with(
+        mut self,
+        name: impl Into<String>,
+        function: impl MessageFunction + 'static,
+    ) -> Self {
+        self.insert(name, function);
+        self
+    }
+
+    /// Returns the function with the given name.
+    pub fn get(&self, name: &str) -> Option<&dyn MessageFunction> {
+        self.functions.get(name).map(|f| &**f)
+    }
+}
@@ -0,0 +1,107 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting of messages in the [Unicode MessageFormat 2.0] syntax.
+//!
+//! A [`MessageFormatter`] parses a message, loads the data for the functions it uses, and
+//! formats it with a set of [`MessageArguments`]. Messages can select a variant based on
+//! the plural category or the exact value of a number, and format numbers, dates and times
+//! with the built-in functions. Custom functions can be registered with a [`FunctionRegistry`].
+//!
+//! Placeholders are isolated from the surrounding text with Unicode bidi controls by default;
+//! see [`BidiIsolation`].
+//!
+//! [Unicode MessageFormat 2.0]: https://www.unicode.org/reports/tr35/tr35-messageFormat.html
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::experimental::messageformat::*;
+//! use icu::locale::locale;
+//! use writeable::assert_try_writeable_eq;
+//!
+//! let formatter = MessageFormatter::try_new(
+//!     locale!("en").into(),
+//!     ".input {$count :number}
+//!      .match $count
+//!      0   {{You have no new messages.}}
+//!      one {{You have {$count} new message.}}
+//!      *   {{You have {$count} new messages.}}",
+//!     BidiIsolation::None.into(),
+//! )
+//! .unwrap();
+//!
+//! for (count, expected) in [
+//!     (0, "You have no new messages."),
+//!     (1, "You have 1 new message."),
+//!     (1234, "You have 1,234 new messages."),
+//! ] {
+//!     let arguments = MessageArguments::new().with("count", count);
+//!     assert_try_writeable_eq!(formatter.format(&arguments), expected);
+//! }
+//! ```
+//!
+//! Ordinal selection and dates:
+//!
+//! ```
+//! use icu::calendar::Date;
+//! use icu::experimental::messageformat::*;
+//! use icu::locale::locale;
+//! use writeable::assert_try_writeable_eq;
+//!
+//! let formatter = MessageFormatter::try_new(
+//!     locale!("en").into(),
+//!     ".input {$place :integer select=ordinal}
+//!      .match $place
+//!      one {{{$place}st place on {$date :date style=long}}}
+//!      two {{{$place}nd place on {$date :date style=long}}}
+//!      few {{{$place}rd place on {$date :date style=long}}}
+//!      *   {{{$place}th place on {$date :date style=long}}}",
+//!     BidiIsolation::None.into(),
+//! )
+//! .unwrap();
+//!
+//! let arguments = MessageArguments::new()
+//!     .with("place", 22)
+//!     .with("date", Date::try_new_iso(2025, 3, 14).unwrap());
+//!
+//! assert_try_writeable_eq!(
+//!     formatter.format(&arguments),
+//!     "22nd place on March 14, 2025"
+//! );
+//! ```
+
+// https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        clippy::trivially_copy_pass_by_ref,
+        missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+mod arguments;
+pub mod ast;
+mod builtins;
+mod error;
+mod format;
+mod formatter;
+mod functions;
+mod parser;
+
+pub use arguments::{MessageArgument, MessageArguments};
+pub use error::{MessageError, MessageFormatterLoadError};
+pub use format::{parts, FormattedMessage};
+pub use formatter::{
+    BidiIsolation, MessageFormatter, MessageFormatterOptions, MessageFormatterPreferences,
+};
+pub use functions::{FunctionOptions, FunctionRegistry, MessageFunction, BUILTIN_FUNCTIONS};
+pub use parser::{parse, ParseError, ParseErrorKind};
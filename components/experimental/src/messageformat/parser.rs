@@ -0,0 +1,963 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::ast::*;
+
+/// The kind of error that occurred while parsing a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// An unexpected character was encountered.
+    #[displaydoc("unexpected character {0:?}")]
+    UnexpectedChar(char),
+    /// The message ended unexpectedly.
+    #[displaydoc("unexpected end of message")]
+    Eof,
+    /// A variant has a different number of keys than there are selectors.
+    #[displaydoc("variant key mismatch")]
+    VariantKeyMismatch,
+    /// No variant has only catch-all (`*`) keys.
+    #[displaydoc("missing fallback variant")]
+    MissingFallbackVariant,
+    /// A selector does not reference a declaration with a function.
+    #[displaydoc("missing selector annotation")]
+    MissingSelectorAnnotation,
+    /// A variable is declared more than once, or declared after it was used.
+    #[displaydoc("duplicate declaration")]
+    DuplicateDeclaration,
+    /// A function or markup element has two options with the same name.
+    #[displaydoc("duplicate option name")]
+    DuplicateOptionName,
+    /// Two variants have the same keys.
+    #[displaydoc("duplicate variant")]
+    DuplicateVariant,
+}
+
+impl ParseErrorKind {
+    fn with_offset(self, offset: usize) -> ParseError {
+        ParseError {
+            offset: Some(offset),
+            kind: self,
+        }
+    }
+}
+
+impl From<ParseErrorKind> for ParseError {
+    fn from(kind: ParseErrorKind) -> Self {
+        ParseError { offset: None, kind }
+    }
+}
+
+/// The error type returned by [`parse`].
+///
+/// Syntax errors carry the byte offset at which they occurred. Errors in the
+/// [data model](https://www.unicode.org/reports/tr35/tr35-messageFormat.html#data-model-errors),
+/// such as a missing fallback variant, are detected after parsing and carry no offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    offset: Option<usize>,
+    kind: ParseErrorKind,
+}
+
+impl ParseError {
+    /// Returns the kind of this error.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset in the source at which this error occurred, if known.
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.offset {
+            Some(offset) => write!(f, "{} at offset {offset}", self.kind),
+            None => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+type Result<T, E = ParseError> = core::result::Result<T, E>;
+
+/// Parses a message in the [MessageFormat 2.0 syntax](https://www.unicode.org/reports/tr35/tr35-messageFormat.html#syntax).
+///
+/// Besides syntax errors, this also reports the data model errors of the specification.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::messageformat::ast::*;
+/// use icu::experimental::messageformat::{parse, ParseErrorKind};
+///
+/// let message = parse("Hello, {$name}!").unwrap();
+/// assert_eq!(
+///     message,
+///     Message::Pattern(PatternMessage {
+///         declarations: vec![],
+///         pattern: Pattern {
+///             parts: vec![
+///                 PatternPart::Text("Hello, ".into()),
+///                 PatternPart::Expression(Expression {
+///                     operand: Some(Operand::Variable("name".into())),
+///                     function: None,
+///                     attributes: vec![],
+///                 }),
+///                 PatternPart::Text("!".into()),
+///             ]
+///         }
+///     })
+/// );
+///
+/// assert_eq!(
+///     parse(".input {$n :number} .match $n one {{one}}")
+///         .unwrap_err()
+///         .kind(),
+///     ParseErrorKind::MissingFallbackVariant
+/// );
+/// ```
+pub fn parse(source: &str) -> Result<Message> {
+    let message = Parser { source, pos: 0 }.parse_message()?;
+    validate(&message)?;
+    Ok(message)
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+fn is_ws(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\r' | '\n' | '\u{3000}')
+}
+
+fn is_bidi(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+fn is_name_start(c: char) -> bool {
+    matches!(c,
+        'a'..='z'
+        | 'A'..='Z'
+        | '_'
+        | '\u{C0}'..='\u{D6}'
+        | '\u{D8}'..='\u{F6}'
+        | '\u{F8}'..='\u{2FF}'
+        | '\u{370}'..='\u{37D}'
+        | '\u{37F}'..='\u{61B}'
+        | '\u{61D}'..='\u{1FFF}'
+        | '\u{200C}'..='\u{200D}'
+        | '\u{2070}'..='\u{218F}'
+        | '\u{2C00}'..='\u{2FEF}'
+        | '\u{3001}'..='\u{D7FF}'
+        | '\u{F900}'..='\u{FDCF}'
+        | '\u{FDF0}'..='\u{FFFC}'
+        | '\u{10000}'..='\u{EFFFF}'
+    )
+}
+
+fn is_name_char(c: char) -> bool {
+    is_name_start(c)
+        || matches!(c,
+            '0'..='9' | '-' | '.' | '\u{B7}' | '\u{300}'..='\u{36F}' | '\u{203F}'..='\u{2040}'
+        )
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str {
+        self.source.get(self.pos..).unwrap_or_default()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            Some(c) => ParseErrorKind::UnexpectedChar(c).with_offset(self.pos),
+            None => ParseErrorKind::Eof.with_offset(self.pos),
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        if self.peek() == Some(expected) {
+            self.pos += expected.len_utf8();
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    /// Skips optional whitespace and bidi marks (`o` in the grammar).
+    fn skip_o(&mut self) {
+        while let Some(c) = self.peek().filter(|&c| is_ws(c) || is_bidi(c)) {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    /// Skips whitespace and bidi marks, returning whether the skipped
+    /// sequence is required whitespace (`s` in the grammar).
+    fn skip_s(&mut self) -> bool {
+        let mut has_ws = false;
+        while let Some(c) = self.peek().filter(|&c| is_ws(c) || is_bidi(c)) {
+            has_ws |= is_ws(c);
+            self.pos += c.len_utf8();
+        }
+        has_ws
+    }
+
+    fn parse_message(&mut self) -> Result<Message> {
+        self.skip_o();
+        if self.peek() != Some('.') && !self.rest().starts_with("{{") {
+            // A simple message, in which leading whitespace is significant
+            self.pos = 0;
+            let pattern = self.parse_pattern(false)?;
+            return Ok(Message::Pattern(PatternMessage {
+                declarations: Vec::new(),
+                pattern,
+            }));
+        }
+
+        let mut declarations = Vec::new();
+        loop {
+            self.skip_o();
+            if self.rest().starts_with("{{") {
+                let pattern = self.parse_quoted_pattern()?;
+                self.skip_o();
+                if self.peek().is_some() {
+                    return Err(self.unexpected());
+                }
+                return Ok(Message::Pattern(PatternMessage {
+                    declarations,
+                    pattern,
+                }));
+            }
+            let start = self.pos;
+            self.expect('.')?;
+            match self.parse_name_at_keyword() {
+                "input" => {
+                    self.skip_o();
+                    self.expect('{')?;
+                    let value = self.parse_expression_body()?;
+                    let Some(Operand::Variable(name)) = value.operand.clone() else {
+                        return Err(ParseErrorKind::UnexpectedChar('{').with_offset(start));
+                    };
+                    declarations.push(Declaration::Input { name, value });
+                }
+                "local" => {
+                    if !self.skip_s() {
+                        return Err(self.unexpected());
+                    }
+                    let name = self.parse_variable()?;
+                    self.skip_o();
+                    self.expect('=')?;
+                    self.skip_o();
+                    self.expect('{')?;
+                    let value = self.parse_expression_body()?;
+                    declarations.push(Declaration::Local { name, value });
+                }
+                "match" => return self.parse_matcher(declarations),
+                _ => return Err(ParseErrorKind::UnexpectedChar('.').with_offset(start)),
+            }
+        }
+    }
+
+    /// Consumes the keyword following a `.` and returns it.
+    fn parse_name_at_keyword(&mut self) -> &str {
+        let start = self.pos;
+        while self.peek().is_some_and(is_name_char) {
+            self.bump();
+        }
+        self.source.get(start..self.pos).unwrap_or_default()
+    }
+
+    fn parse_matcher(&mut self, declarations: Vec<Declaration>) -> Result<Message> {
+        let mut selectors = Vec::new();
+        loop {
+            let has_s = self.skip_s();
+            if self.peek() != Some('$') {
+                if !has_s || selectors.is_empty() {
+                    return Err(self.unexpected());
+                }
+                break;
+            }
+            if !has_s {
+                return Err(self.unexpected());
+            }
+            selectors.push(self.parse_variable()?);
+        }
+
+        let mut variants = Vec::new();
+        loop {
+            self.skip_o();
+            if self.peek().is_none() {
+                break;
+            }
+            let mut keys = Vec::new();
+            loop {
+                keys.push(if self.peek() == Some('*') {
+                    self.bump();
+                    Key::CatchAll
+                } else {
+                    Key::Literal(self.parse_literal()?)
+                });
+                let has_s = self.skip_s();
+                if self.rest().starts_with("{{") {
+                    break;
+                }
+                if !has_s {
+                    return Err(self.unexpected());
+                }
+            }
+            let value = self.parse_quoted_pattern()?;
+            variants.push(Variant { keys, value });
+        }
+        if variants.is_empty() {
+            return Err(self.unexpected());
+        }
+
+        Ok(Message::Select(SelectMessage {
+            declarations,
+            selectors,
+            variants,
+        }))
+    }
+
+    fn parse_quoted_pattern(&mut self) -> Result<Pattern> {
+        self.expect('{')?;
+        self.expect('{')?;
+        let pattern = self.parse_pattern(true)?;
+        self.expect('}')?;
+        self.expect('}')?;
+        Ok(pattern)
+    }
+
+    /// Parses a pattern up to the end of the message, or up to the closing `}}` if `quoted`.
+    fn parse_pattern(&mut self, quoted: bool) -> Result<Pattern> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        loop {
+            match self.peek() {
+                None if quoted => return Err(self.unexpected()),
+                None => break,
+                Some('}') if quoted && self.rest().starts_with("}}") => break,
+                Some('}' | '\0') => return Err(self.unexpected()),
+                Some('\\') => {
+                    self.bump();
+                    match self.peek() {
+                        Some(c @ ('\\' | '{' | '|' | '}')) => {
+                            self.bump();
+                            text.push(c);
+                        }
+                        _ => return Err(self.unexpected()),
+                    }
+                }
+                Some('{') => {
+                    self.bump();
+                    if !text.is_empty() {
+                        parts.push(PatternPart::Text(core::mem::take(&mut text)));
+                    }
+                    self.skip_o();
+                    parts.push(if matches!(self.peek(), Some('#' | '/')) {
+                        PatternPart::Markup(self.parse_markup_body()?)
+                    } else {
+                        PatternPart::Expression(self.parse_expression_body()?)
+                    });
+                }
+                Some(c) => {
+                    self.bump();
+                    text.push(c);
+                }
+            }
+        }
+        if !text.is_empty() {
+            parts.push(PatternPart::Text(text));
+        }
+        Ok(Pattern { parts })
+    }
+
+    /// Parses an expression following its opening `{`, up to and including the closing `}`.
+    fn parse_expression_body(&mut self) -> Result<Expression> {
+        self.skip_o();
+        let operand = match self.peek() {
+            Some('$') => Some(Operand::Variable(self.parse_variable()?)),
+            Some(':') => None,
+            _ => Some(Operand::Literal(self.parse_literal()?)),
+        };
+        let mut function = None;
+        let mut attributes = Vec::new();
+        loop {
+            let has_s = self.skip_s();
+            match self.peek() {
+                Some('}') => {
+                    self.bump();
+                    break;
+                }
+                Some(':') if function.is_none() && attributes.is_empty() => {
+                    if operand.is_some() && !has_s {
+                        return Err(self.unexpected());
+                    }
+                    self.bump();
+                    let name = self.parse_identifier()?;
+                    function = Some(Function {
+                        name,
+                        options: Vec::new(),
+                    });
+                }
+                Some('@') if has_s => attributes.push(self.parse_attribute()?),
+                Some(c) if has_s && attributes.is_empty() && is_name_start(c) => {
+                    let Some(function) = function.as_mut() else {
+                        return Err(self.unexpected());
+                    };
+                    self.parse_option(&mut function.options)?;
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+        Ok(Expression {
+            operand,
+            function,
+            attributes,
+        })
+    }
+
+    /// Parses a markup placeholder following its opening `{`, up to and including the closing `}`.
+    fn parse_markup_body(&mut self) -> Result<Markup> {
+        let mut kind = match self.bump() {
+            Some('#') => MarkupKind::Open,
+            _ => MarkupKind::Close,
+        };
+        let name = self.parse_identifier()?;
+        let mut options = Vec::new();
+        let mut attributes = Vec::new();
+        loop {
+            let has_s = self.skip_s();
+            match self.peek() {
+                Some('}') => {
+                    self.bump();
+                    break;
+                }
+                Some('/') if kind == MarkupKind::Open => {
+                    self.bump();
+                    kind = MarkupKind::Standalone;
+                    self.expect('}')?;
+                    break;
+                }
+                Some('@') if has_s => attributes.push(self.parse_attribute()?),
+                Some(c) if has_s && attributes.is_empty() && is_name_start(c) => {
+                    self.parse_option(&mut options)?;
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+        Ok(Markup {
+            kind,
+            name,
+            options,
+            attributes,
+        })
+    }
+
+    fn parse_option(&mut self, options: &mut Vec<FunctionOption>) -> Result<()> {
+        let start = self.pos;
+        let name = self.parse_identifier()?;
+        self.skip_o();
+        self.expect('=')?;
+        self.skip_o();
+        let value = if self.peek() == Some('$') {
+            Operand::Variable(self.parse_variable()?)
+        } else {
+            Operand::Literal(self.parse_literal()?)
+        };
+        if options.iter().any(|o| o.name == name) {
+            return Err(ParseErrorKind::DuplicateOptionName.with_offset(start));
+        }
+        options.push(FunctionOption { name, value });
+        Ok(())
+    }
+
+    fn parse_attribute(&mut self) -> Result<Attribute> {
+        self.expect('@')?;
+        let name = self.parse_identifier()?;
+        let checkpoint = self.pos;
+        self.skip_o();
+        let value = if self.peek() == Some('=') {
+            self.bump();
+            self.skip_o();
+            Some(self.parse_literal()?)
+        } else {
+            self.pos = checkpoint;
+            None
+        };
+        Ok(Attribute { name, value })
+    }
+
+    fn parse_variable(&mut self) -> Result<String> {
+        self.expect('$')?;
+        self.parse_name()
+    }
+
+    /// Parses an identifier, which is a name with an optional namespace.
+    fn parse_identifier(&mut self) -> Result<String> {
+        let mut identifier = self.parse_name()?;
+        if self.peek() == Some(':') {
+            self.bump();
+            identifier.push(':');
+            identifier.push_str(&self.parse_name()?);
+        }
+        Ok(identifier)
+    }
+
+    fn parse_name(&mut self) -> Result<String> {
+        if self.peek().is_some_and(is_bidi) {
+            self.bump();
+        }
+        if !self.peek().is_some_and(is_name_start) {
+            return Err(self.unexpected());
+        }
+        let start = self.pos;
+        while self.peek().is_some_and(is_name_char) {
+            self.bump();
+        }
+        let name = self.source.get(start..self.pos).unwrap_or_default().into();
+        if self.peek().is_some_and(is_bidi) {
+            self.bump();
+        }
+        Ok(name)
+    }
+
+    fn parse_literal(&mut self) -> Result<String> {
+        match self.peek() {
+            Some('|') => self.parse_quoted_literal(),
+            Some('-' | '0'..='9') => self.parse_number_literal(),
+            _ => self.parse_name(),
+        }
+    }
+
+    fn parse_quoted_literal(&mut self) -> Result<String> {
+        self.expect('|')?;
+        let mut literal = String::new();
+        loop {
+            match self.peek() {
+                Some('|') => {
+                    self.bump();
+                    return Ok(literal);
+                }
+                Some('\\') => {
+                    self.bump();
+                    match self.peek() {
+                        Some(c @ ('\\' | '{' | '|' | '}')) => {
+                            self.bump();
+                            literal.push(c);
+                        }
+                        _ => return Err(self.unexpected()),
+                    }
+                }
+                None | Some('\0') => return Err(self.unexpected()),
+                Some(c) => {
+                    self.bump();
+                    literal.push(c);
+                }
+            }
+        }
+    }
+
+    fn parse_number_literal(&mut self) -> Result<String> {
+        let start = self.pos;
+        if self.peek() == Some('-') {
+            self.bump();
+        }
+        match self.peek() {
+            Some('0') => {
+                self.bump();
+            }
+            Some('1'..='9') => self.skip_digits(),
+            _ => return Err(self.unexpected()),
+        }
+        if self.peek() == Some('.') {
+            self.bump();
+            self.expect_digits()?;
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            self.bump();
+            if matches!(self.peek(), Some('-' | '+')) {
+                self.bump();
+            }
+            self.expect_digits()?;
+        }
+        if self.peek().is_some_and(is_name_char) {
+            return Err(self.unexpected());
+        }
+        Ok(self.source.get(start..self.pos).unwrap_or_default().into())
+    }
+
+    fn skip_digits(&mut self) {
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.bump();
+        }
+    }
+
+    fn expect_digits(&mut self) -> Result<()> {
+        if !self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            return Err(self.unexpected());
+        }
+        self.skip_digits();
+        Ok(())
+    }
+}
+
+/// Returns the variables referenced by the operand and the options of the expression.
+fn referenced_variables(expression: &Expression) -> impl Iterator<Item = &str> {
+    let operand = expression.operand.iter();
+    let options = expression
+        .function
+        .iter()
+        .flat_map(|f| f.options.iter().map(|o| &o.value));
+    operand.chain(options).filter_map(|o| match o {
+        Operand::Variable(name) => Some(name.as_str()),
+        Operand::Literal(_) => None,
+    })
+}
+
+fn validate(message: &Message) -> Result<()> {
+    let declarations = message.declarations();
+
+    let mut declared = BTreeSet::new();
+    for declaration in declarations {
+        match declaration {
+            Declaration::Input { name, value } => {
+                if !declared.insert(name.as_str()) {
+                    return Err(ParseErrorKind::DuplicateDeclaration.into());
+                }
+                declared.extend(referenced_variables(value));
+            }
+            Declaration::Local { name, value } => {
+                declared.extend(referenced_variables(value));
+                if !declared.insert(name.as_str()) {
+                    return Err(ParseErrorKind::DuplicateDeclaration.into());
+                }
+            }
+        }
+    }
+
+    let Message::Select(message) = message else {
+        return Ok(());
+    };
+
+    for selector in &message.selectors {
+        if !is_annotated(declarations, selector) {
+            return Err(ParseErrorKind::MissingSelectorAnnotation.into());
+        }
+    }
+
+    let mut has_fallback = false;
+    for (i, variant) in message.variants.iter().enumerate() {
+        if variant.keys.len() != message.selectors.len() {
+            return Err(ParseErrorKind::VariantKeyMismatch.into());
+        }
+        has_fallback |= variant.keys.iter().all(|k| *k == Key::CatchAll);
+        if message
+            .variants
+            .iter()
+            .take(i)
+            .any(|other| other.keys == variant.keys)
+        {
+            return Err(ParseErrorKind::DuplicateVariant.into());
+        }
+    }
+    if !has_fallback {
+        return Err(ParseErrorKind::MissingFallbackVariant.into());
+    }
+
+    Ok(())
+}
+
+/// Whether the variable is bound, directly or through other local variables,
+/// to an expression with a function.
+fn is_annotated(declarations: &[Declaration], name: &str) -> bool {
+    // Declarations are unique and can only reference earlier declarations
+    let mut name = name;
+    for declaration in declarations.iter().rev() {
+        if declaration.name() != name {
+            continue;
+        }
+        let value = declaration.value();
+        if value.function.is_some() {
+            return true;
+        }
+        match (declaration, &value.operand) {
+            (Declaration::Local { .. }, Some(Operand::Variable(other))) => name = other,
+            _ => return false,
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    fn variable(name: &str) -> Expression {
+        Expression {
+            operand: Some(Operand::Variable(name.into())),
+            function: None,
+            attributes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_simple_messages() {
+        let cases = [
+            ("", vec![]),
+            ("  hello  ", vec![PatternPart::Text("  hello  ".into())]),
+            (
+                r"\{braces\} and .dots @at |pipe|",
+                vec![PatternPart::Text("{braces} and .dots @at |pipe|".into())],
+            ),
+            (
+                "{$x}{ $y }",
+                vec![
+                    PatternPart::Expression(variable("x")),
+                    PatternPart::Expression(variable("y")),
+                ],
+            ),
+            (
+                "{|a \\| b|}",
+                vec![PatternPart::Expression(Expression {
+                    operand: Some(Operand::Literal("a | b".into())),
+                    function: None,
+                    attributes: vec![],
+                })],
+            ),
+            (
+                "{-1.5e+3 :number minimumFractionDigits=2 u:dir=$dir @translate=no}",
+                vec![PatternPart::Expression(Expression {
+                    operand: Some(Operand::Literal("-1.5e+3".into())),
+                    function: Some(Function {
+                        name: "number".into(),
+                        options: vec![
+                            FunctionOption {
+                                name: "minimumFractionDigits".into(),
+                                value: Operand::Literal("2".into()),
+                            },
+                            FunctionOption {
+                                name: "u:dir".into(),
+                                value: Operand::Variable("dir".into()),
+                            },
+                        ],
+                    }),
+                    attributes: vec![Attribute {
+                        name: "translate".into(),
+                        value: Some("no".into()),
+                    }],
+                })],
+            ),
+            (
+                "{#b}bold{/b}{#img src=|x.png| /}",
+                vec![
+                    PatternPart::Markup(Markup {
+                        kind: MarkupKind::Open,
+                        name: "b".into(),
+                        options: vec![],
+                        attributes: vec![],
+                    }),
+                    PatternPart::Text("bold".into()),
+                    PatternPart::Markup(Markup {
+                        kind: MarkupKind::Close,
+                        name: "b".into(),
+                        options: vec![],
+                        attributes: vec![],
+                    }),
+                    PatternPart::Markup(Markup {
+                        kind: MarkupKind::Standalone,
+                        name: "img".into(),
+                        options: vec![FunctionOption {
+                            name: "src".into(),
+                            value: Operand::Literal("x.png".into()),
+                        }],
+                        attributes: vec![],
+                    }),
+                ],
+            ),
+        ];
+        for (source, parts) in cases {
+            assert_eq!(
+                parse(source),
+                Ok(Message::Pattern(PatternMessage {
+                    declarations: vec![],
+                    pattern: Pattern { parts }
+                })),
+                "{source}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_complex_messages() {
+        assert_eq!(
+            parse(".local $x = {$y :string} {{{$x}}}"),
+            Ok(Message::Pattern(PatternMessage {
+                declarations: vec![Declaration::Local {
+                    name: "x".into(),
+                    value: Expression {
+                        operand: Some(Operand::Variable("y".into())),
+                        function: Some(Function {
+                            name: "string".into(),
+                            options: vec![],
+                        }),
+                        attributes: vec![],
+                    },
+                }],
+                pattern: Pattern {
+                    parts: vec![PatternPart::Expression(variable("x"))]
+                },
+            }))
+        );
+
+        assert_eq!(
+            parse(".input {$n :number}\n.match $n\n1 {{one item}}\n* {{{$n} items}}"),
+            Ok(Message::Select(SelectMessage {
+                declarations: vec![Declaration::Input {
+                    name: "n".into(),
+                    value: Expression {
+                        operand: Some(Operand::Variable("n".into())),
+                        function: Some(Function {
+                            name: "number".into(),
+                            options: vec![],
+                        }),
+                        attributes: vec![],
+                    },
+                }],
+                selectors: vec!["n".into()],
+                variants: vec![
+                    Variant {
+                        keys: vec![Key::Literal("1".into())],
+                        value: Pattern {
+                            parts: vec![PatternPart::Text("one item".into())]
+                        },
+                    },
+                    Variant {
+                        keys: vec![Key::CatchAll],
+                        value: Pattern {
+                            parts: vec![
+                                PatternPart::Expression(variable("n")),
+                                PatternPart::Text(" items".into())
+                            ]
+                        },
+                    },
+                ],
+            }))
+        );
+
+        // Whitespace inside quoted patterns is significant
+        assert_eq!(
+            parse("{{ hi }}"),
+            Ok(Message::Pattern(PatternMessage {
+                declarations: vec![],
+                pattern: Pattern {
+                    parts: vec![PatternPart::Text(" hi ".into())]
+                },
+            }))
+        );
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        for (source, offset) in [
+            ("hello }", 6),
+            ("{$x", 3),
+            ("{$x:number}", 3),
+            ("{ :number 1}", 10),
+            ("{01}", 2),
+            ("{{hello}", 7),
+            ("{{hello}} world", 10),
+            (".input {1} {{}}", 0),
+            (".local$x = {1} {{}}", 6),
+            (".foo {{}}", 0),
+            (r"\a", 1),
+            (".input {$n :number} .match $n {{}}", 30),
+        ] {
+            let error = parse(source).unwrap_err();
+            assert_eq!(error.offset(), Some(offset), "{source}: {error}");
+        }
+    }
+
+    #[test]
+    fn test_data_model_errors() {
+        for (source, kind) in [
+            (
+                ".input {$n :number} .match $n 1 2 {{}} * {{}}",
+                ParseErrorKind::VariantKeyMismatch,
+            ),
+            (
+                ".input {$n :number} .match $n 1 {{}}",
+                ParseErrorKind::MissingFallbackVariant,
+            ),
+            (
+                ".input {$n} .match $n * {{}}",
+                ParseErrorKind::MissingSelectorAnnotation,
+            ),
+            (
+                ".match $n * {{}}",
+                ParseErrorKind::MissingSelectorAnnotation,
+            ),
+            (
+                ".input {$x} .input {$x} {{}}",
+                ParseErrorKind::DuplicateDeclaration,
+            ),
+            (
+                ".local $x = {$x :string} {{}}",
+                ParseErrorKind::DuplicateDeclaration,
+            ),
+            (
+                ".local $y = {$x :string} .input {$x} {{}}",
+                ParseErrorKind::DuplicateDeclaration,
+            ),
+            ("{1 :number a=1 a=2}", ParseErrorKind::DuplicateOptionName),
+            (
+                ".input {$n :number} .match $n 1 {{}} |1| {{}} * {{}}",
+                ParseErrorKind::DuplicateVariant,
+            ),
+        ] {
+            assert_eq!(parse(source).map_err(|e| e.kind()), Err(kind), "{source}");
+        }
+
+        // Annotations can be inherited from other local variables
+        assert!(parse(".input {$n :number} .local $m = {$n} .match $m * {{}}").is_ok());
+    }
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(
+            parse("{$x").unwrap_err().to_string(),
+            "unexpected end of message at offset 3"
+        );
+        assert_eq!(
+            parse(".input {$n :number} .match $n 1 {{}}")
+                .unwrap_err()
+                .to_string(),
+            "missing fallback variant"
+        );
+    }
+}
@@ -0,0 +1,553 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::Decimal;
+use icu_calendar::types::RataDie;
+use icu_calendar::{AsCalendar, Date, Iso};
+use icu_datetime::fieldsets::T;
+use icu_datetime::options::Length;
+use icu_datetime::scaffold::{
+    AllFixedCalendarExternalDataMarkers, AllFixedCalendarFormattingDataMarkers, GetField,
+};
+use icu_datetime::{
+    DateTimeFormatterLoadError, DateTimeFormatterPreferences, FormattedDateTime,
+    NoCalendarFormatter,
+};
+use icu_decimal::options::DecimalFormatterOptions;
+use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV1};
+use icu_decimal::{DecimalFormatter, DecimalFormatterPreferences};
+use icu_locale_core::preferences::extensions::unicode::keywords::{HourCycle, NumberingSystem};
+use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_pattern::DoublePlaceholderPattern;
+use icu_plurals::provider::PluralsCardinalV1;
+use icu_plurals::{PluralRules, PluralRulesPreferences};
+use icu_provider::marker::ErasedMarker;
+use icu_provider::prelude::*;
+use icu_time::zone::UtcOffset;
+use icu_time::{Time, ZonedDateTime};
+use writeable::Writeable;
+
+use crate::relativetime::format::FormattedRelativeTime;
+use crate::relativetime::options::{RelativeDateTimeFormatterOptions, Width};
+use crate::relativetime::provider::*;
+
+define_preferences!(
+    /// The preferences for relative date and time formatting.
+    [Copy]
+    RelativeDateTimeFormatterPreferences,
+    {
+        /// The user's preferred numbering system.
+        ///
+        /// Corresponds to the `-u-nu` in Unicode Locale Identifier.
+        numbering_system: NumberingSystem,
+        /// The user's preferred hour cycle, used for the time of relative days.
+        ///
+        /// Corresponds to the `-u-hc` in Unicode Locale Identifier.
+        hour_cycle: HourCycle
+    }
+);
+prefs_convert!(
+    RelativeDateTimeFormatterPreferences,
+    DecimalFormatterPreferences,
+    { numbering_system }
+);
+prefs_convert!(RelativeDateTimeFormatterPreferences, PluralRulesPreferences);
+prefs_convert!(RelativeDateTimeFormatterPreferences, DateTimeFormatterPreferences, {
+    numbering_system,
+    hour_cycle
+});
+
+/// A unit selected by [`RelativeDateTimeFormatter`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum RelativeTimeUnit {
+    /// Seconds, such as "in 30 seconds".
+    Second,
+    /// Minutes, such as "5 minutes ago".
+    Minute,
+    /// Hours, such as "in 3 hours".
+    Hour,
+    /// Calendar days, such as "yesterday".
+    Day,
+    /// Weeks of seven days, such as "in 2 weeks".
+    Week,
+    /// Calendar months, such as "last month".
+    Month,
+    /// Calendar years, such as "in 5 years".
+    Year,
+}
+
+type ErasedRelativeTimePatternData = ErasedMarker<RelativeTimePatternData<'static>>;
+
+/// A formatter for the difference between two date-times, such as "yesterday at 5:30 PM"
+/// or "in 3 days".
+///
+/// Unlike [`RelativeTimeFormatter`](super::RelativeTimeFormatter), which formats a number
+/// in a unit chosen by the caller, this formatter selects the unit from the difference
+/// between two [`ZonedDateTime`]s, using the [`RelativeTimeThresholds`](super::options::RelativeTimeThresholds)
+/// in its options.
+///
+/// Seconds, minutes, and hours are counted from the elapsed time. Days, weeks, months, and
+/// years are counted in calendar days, months and years in the time zone of the reference
+/// date-time, so that a date-time 10 minutes before midnight is "yesterday" if days are used.
+///
+/// # Example
+///
+/// ```
+/// use icu::calendar::Iso;
+/// use icu::datetime::fieldsets::T;
+/// use icu::datetime::options::TimePrecision;
+/// use icu::experimental::relativetime::{
+///     RelativeDateTimeFormatter, RelativeDateTimeFormatterOptions,
+/// };
+/// use icu::locale::locale;
+/// use icu::time::ZonedDateTime;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter = RelativeDateTimeFormatter::try_new(
+///     locale!("en").into(),
+///     RelativeDateTimeFormatterOptions::from(
+///         T::long().with_time_precision(TimePrecision::Minute),
+///     ),
+/// )
+/// .unwrap();
+///
+/// let now =
+///     ZonedDateTime::try_offset_only_from_str("2025-06-12T10:00-07:00", Iso)
+///         .unwrap();
+/// let parse = |s| ZonedDateTime::try_offset_only_from_str(s, Iso).unwrap();
+///
+/// assert_writeable_eq!(
+///     formatter.format_relative_to(&parse("2025-06-12T09:59:30-07:00"), &now),
+///     "30 seconds ago"
+/// );
+/// assert_writeable_eq!(
+///     formatter.format_relative_to(&parse("2025-06-12T13:00-07:00"), &now),
+///     "in 3 hours"
+/// );
+/// assert_writeable_eq!(
+///     formatter.format_relative_to(&parse("2025-06-11T05:30-07:00"), &now),
+///     "yesterday at 5:30\u{202f}AM"
+/// );
+/// // The same instant in a different time zone
+/// assert_writeable_eq!(
+///     formatter.format_relative_to(&parse("2025-06-11T14:30+02:00"), &now),
+///     "yesterday at 5:30\u{202f}AM"
+/// );
+/// assert_writeable_eq!(
+///     formatter.format_relative_to(&parse("2025-06-15T08:00-07:00"), &now),
+///     "in 3 days at 8:00\u{202f}AM"
+/// );
+/// assert_writeable_eq!(
+///     formatter.format_relative_to(&parse("2025-05-20T08:00-07:00"), &now),
+///     "3 weeks ago"
+/// );
+/// assert_writeable_eq!(
+///     formatter.format_relative_to(&parse("2025-05-01T08:00-07:00"), &now),
+///     "last month"
+/// );
+/// assert_writeable_eq!(
+///     formatter.format_relative_to(&parse("2027-01-01T08:00-07:00"), &now),
+///     "next year"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct RelativeDateTimeFormatter {
+    plural_rules: PluralRules,
+    decimal_formatter: DecimalFormatter,
+    units: [DataPayload<ErasedRelativeTimePatternData>; 7],
+    at_time: Option<(DataPayload<RelativeAtTimeV1>, NoCalendarFormatter<T>)>,
+    options: RelativeDateTimeFormatterOptions,
+}
+
+macro_rules! load_units {
+    ($provider:expr, $locale:expr, [$($marker:ty),+]) => {
+        [$({
+            let response: DataResponse<$marker> = $provider.load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale($locale),
+                ..Default::default()
+            })?;
+            response.payload.cast()
+        }),+]
+    };
+}
+
+fn load_units<D>(
+    provider: &D,
+    prefs: RelativeDateTimeFormatterPreferences,
+    width: Width,
+) -> Result<[DataPayload<ErasedRelativeTimePatternData>; 7], DataError>
+where
+    D: DataProvider<LongSecondRelativeV1>
+        + DataProvider<LongMinuteRelativeV1>
+        + DataProvider<LongHourRelativeV1>
+        + DataProvider<LongDayRelativeV1>
+        + DataProvider<LongWeekRelativeV1>
+        + DataProvider<LongMonthRelativeV1>
+        + DataProvider<LongYearRelativeV1>
+        + DataProvider<ShortSecondRelativeV1>
+        + DataProvider<ShortMinuteRelativeV1>
+        + DataProvider<ShortHourRelativeV1>
+        + DataProvider<ShortDayRelativeV1>
+        + DataProvider<ShortWeekRelativeV1>
+        + DataProvider<ShortMonthRelativeV1>
+        + DataProvider<ShortYearRelativeV1>
+        + DataProvider<NarrowSecondRelativeV1>
+        + DataProvider<NarrowMinuteRelativeV1>
+        + DataProvider<NarrowHourRelativeV1>
+        + DataProvider<NarrowDayRelativeV1>
+        + DataProvider<NarrowWeekRelativeV1>
+        + DataProvider<NarrowMonthRelativeV1>
+        + DataProvider<NarrowYearRelativeV1>
+        + ?Sized,
+{
+    let locale = LongDayRelativeV1::make_locale(prefs.locale_preferences);
+    Ok(match width {
+        Width::Long => load_units!(
+            provider,
+            &locale,
+            [
+                LongSecondRelativeV1,
+                LongMinuteRelativeV1,
+                LongHourRelativeV1,
+                LongDayRelativeV1,
+                LongWeekRelativeV1,
+                LongMonthRelativeV1,
+                LongYearRelativeV1
+            ]
+        ),
+        Width::Short => load_units!(
+            provider,
+            &locale,
+            [
+                ShortSecondRelativeV1,
+                ShortMinuteRelativeV1,
+                ShortHourRelativeV1,
+                ShortDayRelativeV1,
+                ShortWeekRelativeV1,
+                ShortMonthRelativeV1,
+                ShortYearRelativeV1
+            ]
+        ),
+        Width::Narrow => load_units!(
+            provider,
+            &locale,
+            [
+                NarrowSecondRelativeV1,
+                NarrowMinuteRelativeV1,
+                NarrowHourRelativeV1,
+                NarrowDayRelativeV1,
+                NarrowWeekRelativeV1,
+                NarrowMonthRelativeV1,
+                NarrowYearRelativeV1
+            ]
+        ),
+    })
+}
+
+impl RelativeDateTimeFormatter {
+    /// Creates a new [`RelativeDateTimeFormatter`] from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: RelativeDateTimeFormatterPreferences,
+        options: RelativeDateTimeFormatterOptions,
+    ) -> Result<Self, DateTimeFormatterLoadError> {
+        Self::try_new_internal(
+            &crate::provider::Baked,
+            prefs,
+            options,
+            || PluralRules::try_new_cardinal((&prefs).into()),
+            || DecimalFormatter::try_new((&prefs).into(), DecimalFormatterOptions::default()),
+            |time| NoCalendarFormatter::try_new((&prefs).into(), time),
+        )
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: RelativeDateTimeFormatterPreferences, options: RelativeDateTimeFormatterOptions) -> error: DateTimeFormatterLoadError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: RelativeDateTimeFormatterPreferences,
+        options: RelativeDateTimeFormatterOptions,
+    ) -> Result<Self, DateTimeFormatterLoadError>
+    where
+        D: DataProvider<LongSecondRelativeV1>
+            + DataProvider<LongMinuteRelativeV1>
+            + DataProvider<LongHourRelativeV1>
+            + DataProvider<LongDayRelativeV1>
+            + DataProvider<LongWeekRelativeV1>
+            + DataProvider<LongMonthRelativeV1>
+            + DataProvider<LongYearRelativeV1>
+            + DataProvider<ShortSecondRelativeV1>
+            + DataProvider<ShortMinuteRelativeV1>
+            + DataProvider<ShortHourRelativeV1>
+            + DataProvider<ShortDayRelativeV1>
+            + DataProvider<ShortWeekRelativeV1>
+            + DataProvider<ShortMonthRelativeV1>
+            + DataProvider<ShortYearRelativeV1>
+            + DataProvider<NarrowSecondRelativeV1>
+            + DataProvider<NarrowMinuteRelativeV1>
+            + DataProvider<NarrowHourRelativeV1>
+            + DataProvider<NarrowDayRelativeV1>
+            + DataProvider<NarrowWeekRelativeV1>
+            + DataProvider<NarrowMonthRelativeV1>
+            + DataProvider<NarrowYearRelativeV1>
+            + DataProvider<RelativeAtTimeV1>
+            + DataProvider<PluralsCardinalV1>
+            + DataProvider<DecimalSymbolsV1>
+            + DataProvider<DecimalDigitsV1>
+            + AllFixedCalendarFormattingDataMarkers<(), T>
+            + AllFixedCalendarExternalDataMarkers
+            + ?Sized,
+    {
+        Self::try_new_internal(
+            provider,
+            prefs,
+            options,
+            || PluralRules::try_new_cardinal_unstable(provider, (&prefs).into()),
+            || {
+                DecimalFormatter::try_new_unstable(
+                    provider,
+                    (&prefs).into(),
+                    DecimalFormatterOptions::default(),
+                )
+            },
+            |time| NoCalendarFormatter::try_new_unstable(provider, (&prefs).into(), time),
+        )
+    }
+
+    fn try_new_internal<D>(
+        provider: &D,
+        prefs: RelativeDateTimeFormatterPreferences,
+        options: RelativeDateTimeFormatterOptions,
+        load_plural_rules: impl FnOnce() -> Result<PluralRules, DataError>,
+        load_decimal: impl FnOnce() -> Result<DecimalFormatter, DataError>,
+        load_time: impl FnOnce(T) -> Result<NoCalendarFormatter<T>, DateTimeFormatterLoadError>,
+    ) -> Result<Self, DateTimeFormatterLoadError>
+    where
+        D: DataProvider<LongSecondRelativeV1>
+            + DataProvider<LongMinuteRelativeV1>
+            + DataProvider<LongHourRelativeV1>
+            + DataProvider<LongDayRelativeV1>
+            + DataProvider<LongWeekRelativeV1>
+            + DataProvider<LongMonthRelativeV1>
+            + DataProvider<LongYearRelativeV1>
+            + DataProvider<ShortSecondRelativeV1>
+            + DataProvider<ShortMinuteRelativeV1>
+            + DataProvider<ShortHourRelativeV1>
+            + DataProvider<ShortDayRelativeV1>
+            + DataProvider<ShortWeekRelativeV1>
+            + DataProvider<ShortMonthRelativeV1>
+            + DataProvider<ShortYearRelativeV1>
+            + DataProvider<NarrowSecondRelativeV1>
+            + DataProvider<NarrowMinuteRelativeV1>
+            + DataProvider<NarrowHourRelativeV1>
+            + DataProvider<NarrowDayRelativeV1>
+            + DataProvider<NarrowWeekRelativeV1>
+            + DataProvider<NarrowMonthRelativeV1>
+            + DataProvider<NarrowYearRelativeV1>
+            + DataProvider<RelativeAtTimeV1>
+            + ?Sized,
+    {
+        let at_time = match options.time {
+            Some(time) => {
+                let locale = RelativeAtTimeV1::make_locale(prefs.locale_preferences);
+                let patterns = provider
+                    .load(DataRequest {
+                        id: DataIdentifierBorrowed::for_locale(&locale),
+                        ..Default::default()
+                    })?
+                    .payload;
+                Some((patterns, load_time(time)?))
+            }
+            None => None,
+        };
+        Ok(Self {
+            plural_rules: load_plural_rules()?,
+            decimal_formatter: load_decimal()?,
+            units: load_units(provider, prefs, options.width)?,
+            at_time,
+            options,
+        })
+    }
+
+    /// Formats `value` relative to the reference date-time `now`.
+    ///
+    /// If the UTC offset of either date-time is unknown, both are treated as being in the
+    /// same time zone.
+    pub fn format_relative_to<A1, Z1, A2, Z2>(
+        &self,
+        value: &ZonedDateTime<A1, Z1>,
+        now: &ZonedDateTime<A2, Z2>,
+    ) -> FormattedRelativeDateTime<'_>
+    where
+        A1: AsCalendar,
+        Z1: GetField<Option<UtcOffset>>,
+        A2: AsCalendar,
+        Z2: GetField<Option<UtcOffset>>,
+    {
+        let (value_offset, now_offset) = match (value.zone.get_field(), now.zone.get_field()) {
+            (Some(v), Some(n)) => (v.to_seconds() as i64, n.to_seconds() as i64),
+            _ => (0, 0),
+        };
+        let now_date = now.date.to_iso();
+        let now_seconds = local_seconds(now_date.to_rata_die(), now.time) - now_offset;
+        let value_seconds = local_seconds(value.date.to_rata_die(), value.time) - value_offset;
+
+        // The value as a local date and time in the time zone of `now`
+        let value_local_seconds = value_seconds + now_offset;
+        let value_date =
+            Date::from_rata_die(RataDie::new(value_local_seconds.div_euclid(86400)), Iso);
+        let value_time = time_of_day(value_local_seconds.rem_euclid(86400), value.time);
+
+        let (unit, count) = self.select_unit(
+            value_seconds - now_seconds,
+            &value_date,
+            value_time,
+            &now_date,
+            now.time,
+        );
+
+        #[expect(clippy::indexing_slicing)] // the units are in index order
+        let patterns = self.units[unit as usize].get();
+        let relative = FormattedRelativeTime {
+            patterns,
+            plural_rules: &self.plural_rules,
+            decimal_formatter: &self.decimal_formatter,
+            numeric: self.options.numeric,
+            value: Decimal::from(count.unsigned_abs()),
+            is_negative: count < 0,
+        };
+        let time = match (&self.at_time, unit) {
+            (Some((at_time, time_formatter)), RelativeTimeUnit::Day) => {
+                let at_time = at_time.get();
+                let pattern = match self.options.time.map(|t| t.length) {
+                    Some(Length::Long) => &at_time.long,
+                    Some(Length::Short) => &at_time.short,
+                    _ => &at_time.medium,
+                };
+                Some((&**pattern, time_formatter.format(&value_time)))
+            }
+            _ => None,
+        };
+        FormattedRelativeDateTime {
+            relative,
+            time,
+            unit,
+        }
+    }
+
+    fn select_unit(
+        &self,
+        seconds: i64,
+        value_date: &Date<Iso>,
+        value_time: Time,
+        now_date: &Date<Iso>,
+        now_time: Time,
+    ) -> (RelativeTimeUnit, i64) {
+        let thresholds = &self.options.thresholds;
+        let count = |magnitude: i64| if seconds < 0 { -magnitude } else { magnitude };
+        let seconds_abs = seconds.abs();
+        if seconds_abs < thresholds.minute as i64 {
+            return (RelativeTimeUnit::Second, count(seconds_abs));
+        }
+        if seconds_abs / 60 < thresholds.hour as i64 {
+            return (RelativeTimeUnit::Minute, count(seconds_abs / 60));
+        }
+        if seconds_abs / 3600 < thresholds.day as i64 {
+            return (RelativeTimeUnit::Hour, count(seconds_abs / 3600));
+        }
+
+        let days = value_date.to_rata_die() - now_date.to_rata_die();
+        if days.abs() < thresholds.week as i64 {
+            return (RelativeTimeUnit::Day, days);
+        }
+        if days.abs() / 7 < thresholds.month as i64 {
+            return (RelativeTimeUnit::Week, days / 7);
+        }
+
+        // The number of complete calendar months between the two date-times
+        let month_index =
+            |date: &Date<Iso>| date.extended_year() as i64 * 12 + date.month().ordinal as i64;
+        let value_day = (value_date.day_of_month().0, value_time);
+        let now_day = (now_date.day_of_month().0, now_time);
+        let mut months = month_index(value_date) - month_index(now_date);
+        if months > 0 && value_day < now_day {
+            months -= 1;
+        } else if months < 0 && value_day > now_day {
+            months += 1;
+        }
+        let months = if months == 0 { count(1) } else { months };
+        if months.abs() < thresholds.year as i64 {
+            return (RelativeTimeUnit::Month, months);
+        }
+        let years = months / 12;
+        (
+            RelativeTimeUnit::Year,
+            if years == 0 { count(1) } else { years },
+        )
+    }
+}
+
+/// The number of seconds from the start of a day to a date and time.
+fn local_seconds(date: RataDie, time: Time) -> i64 {
+    date.to_i64_date() * 86400
+        + time.hour.number() as i64 * 3600
+        + time.minute.number() as i64 * 60
+        + time.second.number() as i64
+}
+
+/// Converts the number of seconds since the start of a day back to a [`Time`], keeping the
+/// subsecond of `time`.
+fn time_of_day(seconds: i64, time: Time) -> Time {
+    Time {
+        hour: ((seconds / 3600) as u8).try_into().unwrap_or(time.hour),
+        minute: ((seconds / 60 % 60) as u8)
+            .try_into()
+            .unwrap_or(time.minute),
+        second: ((seconds % 60) as u8).try_into().unwrap_or(time.second),
+        subsecond: time.subsecond,
+    }
+}
+
+/// An intermediate structure returned by [`RelativeDateTimeFormatter`].
+/// This structure can be consumed via the [`Writeable`] trait to a string or buffer.
+#[derive(Debug)]
+pub struct FormattedRelativeDateTime<'a> {
+    relative: FormattedRelativeTime<'a>,
+    time: Option<(&'a DoublePlaceholderPattern, FormattedDateTime<'a>)>,
+    unit: RelativeTimeUnit,
+}
+
+impl FormattedRelativeDateTime<'_> {
+    /// The unit in which the difference is formatted.
+    pub fn unit(&self) -> RelativeTimeUnit {
+        self.unit
+    }
+}
+
+impl Writeable for FormattedRelativeDateTime<'_> {
+    fn write_to_parts<S: writeable::PartsWrite + ?Sized>(&self, sink: &mut S) -> core::fmt::Result {
+        match &self.time {
+            Some((pattern, time)) => pattern
+                .interpolate((time, &self.relative))
+                .write_to_parts(sink),
+            None => self.relative.write_to_parts(sink),
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedRelativeDateTime<'_>);
@@ -5,12 +5,12 @@
 use alloc::fmt::Write;
 
 use fixed_decimal::Decimal;
+use icu_decimal::DecimalFormatter;
+use icu_plurals::PluralRules;
 use writeable::Writeable;
 
-use crate::relativetime::{
-    options::{Numeric, RelativeTimeFormatterOptions},
-    relativetime::RelativeTimeFormatter,
-};
+use crate::relativetime::options::Numeric;
+use crate::relativetime::provider::RelativeTimePatternData;
 
 pub mod parts {
     use writeable::Part;
@@ -25,17 +25,20 @@ pub mod parts {
 
 /// An intermediate structure returned by [`RelativeTimeFormatter`](crate::relativetime::RelativeTimeFormatter).
 /// This structure can be consumed via [`Writeable`](Writeable) trait to a string or buffer.
+#[derive(Debug)]
 pub struct FormattedRelativeTime<'a> {
-    pub(crate) formatter: &'a RelativeTimeFormatter,
-    pub(crate) options: &'a RelativeTimeFormatterOptions,
+    pub(crate) patterns: &'a RelativeTimePatternData<'a>,
+    pub(crate) plural_rules: &'a PluralRules,
+    pub(crate) decimal_formatter: &'a DecimalFormatter,
+    pub(crate) numeric: Numeric,
     pub(crate) value: Decimal,
     pub(crate) is_negative: bool,
 }
 
 impl Writeable for FormattedRelativeTime<'_> {
     fn write_to_parts<S: writeable::PartsWrite + ?Sized>(&self, sink: &mut S) -> core::fmt::Result {
-        if self.options.numeric == Numeric::Auto {
-            let relatives = &self.formatter.rt.get().relatives;
+        if self.numeric == Numeric::Auto {
+            let relatives = &self.patterns.relatives;
             if self.value.absolute.magnitude_range() == (0..=0) {
                 // Can be cast without overflow as it is a single digit.
                 let i8_value = if self.is_negative {
@@ -51,12 +54,12 @@ impl Writeable for FormattedRelativeTime<'_> {
         }
 
         if self.is_negative {
-            &self.formatter.rt.get().past
+            &self.patterns.past
         } else {
-            &self.formatter.rt.get().future
+            &self.patterns.future
         }
-        .get((&self.value).into(), &self.formatter.plural_rules)
-        .interpolate((self.formatter.decimal_formatter.format(&self.value),))
+        .get((&self.value).into(), self.plural_rules)
+        .interpolate((self.decimal_formatter.format(&self.value),))
         .write_to(sink)
     }
 }
@@ -6,12 +6,18 @@
 
 #![warn(missing_docs)]
 
+mod datetime;
 mod format;
 pub mod options;
 pub mod provider;
 mod relativetime;
 
+pub use datetime::FormattedRelativeDateTime;
+pub use datetime::RelativeDateTimeFormatter;
+pub use datetime::RelativeDateTimeFormatterPreferences;
+pub use datetime::RelativeTimeUnit;
 pub use format::FormattedRelativeTime;
+pub use options::RelativeDateTimeFormatterOptions;
 pub use options::RelativeTimeFormatterOptions;
 pub use relativetime::RelativeTimeFormatter;
 pub use relativetime::RelativeTimeFormatterPreferences;
@@ -2,7 +2,10 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! Options for configuring [`RelativeTimeFormatter`](crate::relativetime::RelativeTimeFormatter).
+//! Options for configuring [`RelativeTimeFormatter`](crate::relativetime::RelativeTimeFormatter)
+//! and [`RelativeDateTimeFormatter`](crate::relativetime::RelativeDateTimeFormatter).
+
+use icu_datetime::fieldsets::T;
 
 /// A bag of options for defining how to format time using
 /// [`RelativeTimeFormatter`](crate::relativetime::RelativeTimeFormatter).
@@ -22,3 +25,96 @@ pub enum Numeric {
     /// Automatically select special formatting if available else fallback to numeric formatting.
     Auto,
 }
+
+/// The width of the unit names used by
+/// [`RelativeDateTimeFormatter`](crate::relativetime::RelativeDateTimeFormatter).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Width {
+    /// Long unit names, such as "in 3 days".
+    #[default]
+    Long,
+    /// Short unit names, such as "in 3 hr.".
+    Short,
+    /// Narrow unit names, such as "in 3h".
+    Narrow,
+}
+
+/// The thresholds at which [`RelativeDateTimeFormatter`](crate::relativetime::RelativeDateTimeFormatter)
+/// switches to the next larger unit.
+///
+/// Each threshold is the number of the smaller unit from which the larger unit is used.
+/// For example, with the default thresholds, 59 seconds are formatted as "59 seconds ago" and
+/// 60 seconds as "1 minute ago". A threshold of zero skips the smaller unit.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RelativeTimeThresholds {
+    /// The number of seconds from which minutes are used. Defaults to 60.
+    pub minute: u32,
+    /// The number of minutes from which hours are used. Defaults to 60.
+    pub hour: u32,
+    /// The number of hours from which days are used. Defaults to 24.
+    ///
+    /// Days are counted as calendar days, so a time 2 hours before midnight is "yesterday"
+    /// if this is set to 2 or less.
+    pub day: u32,
+    /// The number of days from which weeks are used. Defaults to 7.
+    pub week: u32,
+    /// The number of weeks from which months are used. Defaults to 4.
+    pub month: u32,
+    /// The number of months from which years are used. Defaults to 12.
+    pub year: u32,
+}
+
+impl Default for RelativeTimeThresholds {
+    fn default() -> Self {
+        Self {
+            minute: 60,
+            hour: 60,
+            day: 24,
+            week: 7,
+            month: 4,
+            year: 12,
+        }
+    }
+}
+
+/// A bag of options for [`RelativeDateTimeFormatter`](crate::relativetime::RelativeDateTimeFormatter).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RelativeDateTimeFormatterOptions {
+    /// The width of the unit names.
+    pub width: Width,
+    /// Whether to always use numeric formatting.
+    ///
+    /// Unlike in [`RelativeTimeFormatterOptions`], this defaults to [`Numeric::Auto`], which
+    /// formats "yesterday" instead of "1 day ago".
+    pub numeric: Numeric,
+    /// When to switch to larger units.
+    pub thresholds: RelativeTimeThresholds,
+    /// The time to add to relative days, such as "yesterday at 5:30 PM".
+    ///
+    /// The time is only added when the difference is formatted in days. Its length also selects
+    /// the pattern that combines it with the relative day.
+    pub time: Option<T>,
+}
+
+impl Default for RelativeDateTimeFormatterOptions {
+    fn default() -> Self {
+        Self {
+            width: Width::default(),
+            numeric: Numeric::Auto,
+            thresholds: RelativeTimeThresholds::default(),
+            time: None,
+        }
+    }
+}
+
+impl From<T> for RelativeDateTimeFormatterOptions {
+    fn from(time: T) -> Self {
+        Self {
+            time: Some(time),
+            ..Default::default()
+        }
+    }
+}
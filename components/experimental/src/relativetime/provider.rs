@@ -9,9 +9,12 @@
 //!
 //! Read more about data providers: [`icu_provider`]
 
+use alloc::borrow::Cow;
 #[cfg(feature = "datagen")]
 use core::fmt::Debug;
-use icu_pattern::SinglePlaceholderPattern;
+#[cfg(feature = "serde")]
+use icu_pattern::DoublePlaceholder;
+use icu_pattern::{DoublePlaceholderPattern, SinglePlaceholderPattern};
 use icu_plurals::provider::PluralElementsPackedCow;
 use icu_provider::prelude::*;
 use zerovec::ZeroMap;
@@ -146,6 +149,11 @@ icu_provider::data_marker!(
     NarrowYearRelativeV1,
     RelativeTimePatternData<'static>,
 );
+icu_provider::data_marker!(
+    /// `RelativeAtTimeV1`
+    RelativeAtTimeV1,
+    RelativeAtTimePatterns<'static>,
+);
 
 /// Relative time format  data struct.
 #[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
@@ -168,3 +176,45 @@ pub struct RelativeTimePatternData<'data> {
 }
 
 icu_provider::data_struct!(RelativeTimePatternData<'_>, #[cfg(feature = "datagen")]);
+
+/// Patterns for combining a relative date, such as "yesterday", with a time.
+///
+/// These are the CLDR `dateTimeFormats` of the `relative` type, or of the `atTime`
+/// type for locales that do not have relative patterns. The placeholder `{0}` is
+/// the time and the placeholder `{1}` is the relative date.
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::relativetime::provider))]
+#[yoke(prove_covariance_manually)]
+pub struct RelativeAtTimePatterns<'data> {
+    /// The pattern used with long times.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            deserialize_with = "icu_pattern::deserialize_borrowed_cow::<DoublePlaceholder, _>"
+        )
+    )]
+    pub long: Cow<'data, DoublePlaceholderPattern>,
+    /// The pattern used with medium times.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            deserialize_with = "icu_pattern::deserialize_borrowed_cow::<DoublePlaceholder, _>"
+        )
+    )]
+    pub medium: Cow<'data, DoublePlaceholderPattern>,
+    /// The pattern used with short times.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            deserialize_with = "icu_pattern::deserialize_borrowed_cow::<DoublePlaceholder, _>"
+        )
+    )]
+    pub short: Cow<'data, DoublePlaceholderPattern>,
+}
+
+icu_provider::data_struct!(RelativeAtTimePatterns<'_>, #[cfg(feature = "datagen")]);
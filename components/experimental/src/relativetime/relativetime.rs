@@ -357,8 +357,10 @@ impl RelativeTimeFormatter {
     pub fn format(&self, value: Decimal) -> FormattedRelativeTime<'_> {
         let is_negative = value.sign() == Sign::Negative;
         FormattedRelativeTime {
-            options: &self.options,
-            formatter: self,
+            patterns: self.rt.get(),
+            plural_rules: &self.plural_rules,
+            decimal_formatter: &self.decimal_formatter,
+            numeric: self.options.numeric,
             value: value.with_sign(Sign::None),
             is_negative,
         }
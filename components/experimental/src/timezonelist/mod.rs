@@ -0,0 +1,300 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Localized lists of time zones, such as for a time zone picker.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::calendar::Iso;
+//! use icu::experimental::timezonelist::TimeZoneListFormatter;
+//! use icu::locale::{locale, subtags::region};
+//! use icu::time::zone::ZoneNameTimestamp;
+//! use icu::time::ZonedDateTime;
+//! use writeable::assert_writeable_eq;
+//!
+//! let formatter =
+//!     TimeZoneListFormatter::try_new(locale!("en").into()).unwrap();
+//!
+//! let now = ZoneNameTimestamp::from_zoned_date_time_iso(
+//!     ZonedDateTime::try_offset_only_from_str("2025-01-01T00:00Z", Iso)
+//!         .unwrap(),
+//! );
+//!
+//! let zones = formatter.list_for_region(region!("US"), now);
+//!
+//! let los_angeles = zones
+//!     .iter()
+//!     .find(|zone| zone.exemplar_city == "Los Angeles")
+//!     .unwrap();
+//! assert_writeable_eq!(los_angeles, "(UTC-08:00) Pacific Time – Los Angeles");
+//!
+//! // Zones are sorted by offset
+//! assert!(zones
+//!     .windows(2)
+//!     .all(|w| w[0].standard_offset <= w[1].standard_offset));
+//! ```
+
+#![warn(missing_docs)]
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use icu_collator::options::CollatorOptions;
+use icu_collator::provider::{
+    CollationDiacriticsV1, CollationJamoV1, CollationMetadataV1, CollationReorderingV1,
+    CollationRootV1, CollationSpecialPrimariesV1, CollationTailoringV1,
+};
+use icu_collator::{Collator, CollatorPreferences};
+use icu_datetime::fieldsets::zone::{ExemplarCity, GenericLong};
+use icu_datetime::scaffold::{
+    AllFixedCalendarExternalDataMarkers, AllFixedCalendarFormattingDataMarkers,
+};
+use icu_datetime::{DateTimeFormatterLoadError, DateTimeFormatterPreferences, NoCalendarFormatter};
+use icu_locale_core::preferences::extensions::unicode::keywords::CollationType;
+use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_locale_core::subtags::Region;
+use icu_normalizer::provider::{NormalizerNfdDataV1, NormalizerNfdTablesV1};
+use icu_provider::prelude::*;
+use icu_time::provider::iana::TimezoneIdentifiersIanaCoreV1;
+use icu_time::provider::TimezonePeriodsV1;
+#[allow(deprecated)]
+use icu_time::zone::VariantOffsetsCalculator;
+use icu_time::zone::{IanaParser, UtcOffset, ZoneNameTimestamp};
+use icu_time::TimeZone;
+use writeable::{PartsWrite, Writeable};
+
+define_preferences!(
+    /// The preferences for time zone lists.
+    [Copy]
+    TimeZoneListFormatterPreferences,
+    {
+        /// The user's preferred collation type, used for sorting zones with the same offset.
+        ///
+        /// Corresponds to the `-u-co` in Unicode Locale Identifier.
+        collation_type: CollationType
+    }
+);
+prefs_convert!(TimeZoneListFormatterPreferences, CollatorPreferences, {
+    collation_type
+});
+prefs_convert!(
+    TimeZoneListFormatterPreferences,
+    DateTimeFormatterPreferences
+);
+
+/// A time zone in a [`TimeZoneListFormatter`] list.
+///
+/// The [`Writeable`] implementation produces a label such as
+/// "(UTC-08:00) Pacific Time – Los Angeles", using the standard offset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TimeZoneListEntry {
+    /// The time zone.
+    pub time_zone: TimeZone,
+    /// The standard offset of the time zone at the requested time.
+    pub standard_offset: UtcOffset,
+    /// The daylight offset of the time zone at the requested time, if it observes daylight time.
+    pub daylight_offset: Option<UtcOffset>,
+    /// The localized generic name, such as "Pacific Time".
+    pub generic_name: String,
+    /// The localized exemplar city, such as "Los Angeles".
+    pub exemplar_city: String,
+}
+
+impl Writeable for TimeZoneListEntry {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let seconds = self.standard_offset.to_seconds();
+        let sign = if seconds < 0 { '-' } else { '+' };
+        let minutes = seconds.unsigned_abs() / 60;
+        write!(sink, "(UTC{sign}{:02}:{:02}) ", minutes / 60, minutes % 60)?;
+        sink.write_str(&self.generic_name)?;
+        sink.write_str(" – ")?;
+        sink.write_str(&self.exemplar_city)
+    }
+}
+
+writeable::impl_display_with_writeable!(TimeZoneListEntry);
+
+/// Builds sorted, localized lists of time zones.
+///
+/// Each zone is listed with its standard offset, its generic name and its exemplar city.
+/// Zones are ordered by standard offset, and then by their collated names.
+///
+/// The offsets are computed with [`VariantOffsetsCalculator`], which approximates the
+/// offsets a time zone uses around a given time.
+///
+/// See the [module-level documentation](self) for an example.
+#[derive(Debug)]
+pub struct TimeZoneListFormatter {
+    generic: NoCalendarFormatter<GenericLong>,
+    exemplar_city: NoCalendarFormatter<ExemplarCity>,
+    iana: IanaParser,
+    #[allow(deprecated)]
+    offsets: VariantOffsetsCalculator,
+    collator: Collator,
+}
+
+impl TimeZoneListFormatter {
+    /// Creates a new [`TimeZoneListFormatter`] from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[allow(deprecated)]
+    pub fn try_new(
+        prefs: TimeZoneListFormatterPreferences,
+    ) -> Result<Self, DateTimeFormatterLoadError> {
+        Ok(Self {
+            generic: NoCalendarFormatter::try_new((&prefs).into(), GenericLong)?,
+            exemplar_city: NoCalendarFormatter::try_new((&prefs).into(), ExemplarCity)?,
+            iana: IanaParser::new().static_to_owned(),
+            offsets: VariantOffsetsCalculator::new().static_to_owned(),
+            collator: Collator::try_new((&prefs).into(), CollatorOptions::default())?
+                .static_to_owned(),
+        })
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: TimeZoneListFormatterPreferences) -> error: DateTimeFormatterLoadError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    #[allow(deprecated)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: TimeZoneListFormatterPreferences,
+    ) -> Result<Self, DateTimeFormatterLoadError>
+    where
+        D: AllFixedCalendarFormattingDataMarkers<(), GenericLong>
+            + AllFixedCalendarFormattingDataMarkers<(), ExemplarCity>
+            + AllFixedCalendarExternalDataMarkers
+            + DataProvider<TimezoneIdentifiersIanaCoreV1>
+            + DataProvider<TimezonePeriodsV1>
+            + DataProvider<CollationSpecialPrimariesV1>
+            + DataProvider<CollationRootV1>
+            + DataProvider<CollationTailoringV1>
+            + DataProvider<CollationDiacriticsV1>
+            + DataProvider<CollationJamoV1>
+            + DataProvider<CollationMetadataV1>
+            + DataProvider<CollationReorderingV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + ?Sized,
+    {
+        Ok(Self {
+            generic: NoCalendarFormatter::try_new_unstable(provider, (&prefs).into(), GenericLong)?,
+            exemplar_city: NoCalendarFormatter::try_new_unstable(
+                provider,
+                (&prefs).into(),
+                ExemplarCity,
+            )?,
+            iana: IanaParser::try_new_unstable(provider)?,
+            offsets: VariantOffsetsCalculator::try_new_unstable(provider)?,
+            collator: Collator::try_new_unstable(
+                provider,
+                (&prefs).into(),
+                CollatorOptions::default(),
+            )?,
+        })
+    }
+
+    /// Lists all known time zones, with names and offsets as of `timestamp`.
+    pub fn list(&self, timestamp: ZoneNameTimestamp) -> Vec<TimeZoneListEntry> {
+        self.list_filtered(timestamp, |_| true)
+    }
+
+    /// Lists the time zones of a region, with names and offsets as of `timestamp`.
+    ///
+    /// The region of a time zone is the region that its BCP-47 ID starts with,
+    /// such as `US` for `uslax` (America/Los_Angeles).
+    pub fn list_for_region(
+        &self,
+        region: Region,
+        timestamp: ZoneNameTimestamp,
+    ) -> Vec<TimeZoneListEntry> {
+        self.list_filtered(timestamp, |time_zone| {
+            time_zone
+                .as_str()
+                .get(..2)
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(region.as_str()))
+        })
+    }
+
+    #[allow(deprecated)]
+    fn list_filtered(
+        &self,
+        timestamp: ZoneNameTimestamp,
+        filter: impl Fn(TimeZone) -> bool,
+    ) -> Vec<TimeZoneListEntry> {
+        let offsets = self.offsets.as_borrowed();
+        let mut entries = self
+            .iana
+            .as_borrowed()
+            .iter()
+            .filter(|&time_zone| time_zone != TimeZone::UNKNOWN && filter(time_zone))
+            .filter_map(|time_zone| {
+                let variants = offsets
+                    .compute_offsets_from_time_zone_and_name_timestamp(time_zone, timestamp)?;
+                let info = time_zone
+                    .without_offset()
+                    .with_zone_name_timestamp(timestamp);
+                Some(TimeZoneListEntry {
+                    time_zone,
+                    standard_offset: variants.standard,
+                    daylight_offset: variants.daylight,
+                    generic_name: self.generic.format(&info).write_to_string().into_owned(),
+                    exemplar_city: self
+                        .exemplar_city
+                        .format(&info)
+                        .write_to_string()
+                        .into_owned(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let collator = self.collator.as_borrowed();
+        entries.sort_by(|a, b| {
+            a.standard_offset
+                .cmp(&b.standard_offset)
+                .then_with(|| collator.compare(&a.generic_name, &b.generic_name))
+                .then_with(|| collator.compare(&a.exemplar_city, &b.exemplar_city))
+                .then_with(|| a.time_zone.as_str().cmp(b.time_zone.as_str()))
+        });
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locale_core::locale;
+    use icu_locale_core::subtags::{region, subtag};
+
+    #[test]
+    fn test_region_list() {
+        let formatter = TimeZoneListFormatter::try_new(locale!("en").into()).unwrap();
+        let zones = formatter.list_for_region(region!("AU"), ZoneNameTimestamp::far_in_future());
+
+        assert!(zones
+            .iter()
+            .all(|zone| zone.time_zone.as_str().starts_with("au")));
+        let sydney = zones
+            .iter()
+            .find(|zone| zone.time_zone == TimeZone(subtag!("ausyd")))
+            .unwrap();
+        assert_eq!(
+            sydney.to_string(),
+            "(UTC+10:00) Australian Eastern Time – Sydney"
+        );
+        assert!(sydney.daylight_offset.is_some());
+    }
+}
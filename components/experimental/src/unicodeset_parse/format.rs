@@ -0,0 +1,416 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use core::ops::RangeInclusive;
+
+use icu_collections::codepointinvlist::CodePointInversionList;
+use icu_collections::codepointinvliststringlist::CodePointInversionListAndStringList;
+use icu_properties::props::{GeneralCategory, GeneralCategoryGroup, Script};
+use icu_properties::provider::{
+    PropertyEnumGeneralCategoryV1, PropertyEnumScriptV1, PropertyNameShortScriptV1,
+};
+use icu_properties::{
+    CodePointMapData, CodePointMapDataBorrowed, PropertyNamesShort, PropertyNamesShortBorrowed,
+};
+use icu_provider::prelude::*;
+use writeable::{LengthHint, Writeable};
+use zerovec::VarZeroSlice;
+
+/// The General_Category values and groups that [`UnicodeSetFormatter`] recognizes, by short name.
+const GENERAL_CATEGORIES: &[(&str, GeneralCategoryGroup)] = &[
+    ("L", GeneralCategoryGroup::Letter),
+    ("LC", GeneralCategoryGroup::CasedLetter),
+    ("Lu", GeneralCategoryGroup::UppercaseLetter),
+    ("Ll", GeneralCategoryGroup::LowercaseLetter),
+    ("Lt", GeneralCategoryGroup::TitlecaseLetter),
+    ("Lm", GeneralCategoryGroup::ModifierLetter),
+    ("Lo", GeneralCategoryGroup::OtherLetter),
+    ("M", GeneralCategoryGroup::Mark),
+    ("Mn", GeneralCategoryGroup::NonspacingMark),
+    ("Mc", GeneralCategoryGroup::SpacingMark),
+    ("Me", GeneralCategoryGroup::EnclosingMark),
+    ("N", GeneralCategoryGroup::Number),
+    ("Nd", GeneralCategoryGroup::DecimalNumber),
+    ("Nl", GeneralCategoryGroup::LetterNumber),
+    ("No", GeneralCategoryGroup::OtherNumber),
+    ("P", GeneralCategoryGroup::Punctuation),
+    ("Pc", GeneralCategoryGroup::ConnectorPunctuation),
+    ("Pd", GeneralCategoryGroup::DashPunctuation),
+    ("Ps", GeneralCategoryGroup::OpenPunctuation),
+    ("Pe", GeneralCategoryGroup::ClosePunctuation),
+    ("Pi", GeneralCategoryGroup::InitialPunctuation),
+    ("Pf", GeneralCategoryGroup::FinalPunctuation),
+    ("Po", GeneralCategoryGroup::OtherPunctuation),
+    ("S", GeneralCategoryGroup::Symbol),
+    ("Sm", GeneralCategoryGroup::MathSymbol),
+    ("Sc", GeneralCategoryGroup::CurrencySymbol),
+    ("Sk", GeneralCategoryGroup::ModifierSymbol),
+    ("So", GeneralCategoryGroup::OtherSymbol),
+    ("Z", GeneralCategoryGroup::Separator),
+    ("Zs", GeneralCategoryGroup::SpaceSeparator),
+    ("Zl", GeneralCategoryGroup::LineSeparator),
+    ("Zp", GeneralCategoryGroup::ParagraphSeparator),
+    ("C", GeneralCategoryGroup::Other),
+    ("Cc", GeneralCategoryGroup::Control),
+    ("Cf", GeneralCategoryGroup::Format),
+    ("Cs", GeneralCategoryGroup::Surrogate),
+    ("Co", GeneralCategoryGroup::PrivateUse),
+    ("Cn", GeneralCategoryGroup::Unassigned),
+];
+
+const SURROGATES: RangeInclusive<u32> = 0xD800..=0xDFFF;
+
+/// A formatter that writes sets as UnicodeSet patterns, the inverse of [`parse`](super::parse).
+///
+/// The output is a compact pattern that [`parse`](super::parse) reads back into the same set:
+/// * Code points are written as ranges, or as the complement `[^...]` of the ranges that are not
+///   in the set if it contains both U+0000 and U+10FFFF.
+/// * Strings are written as `{string}`.
+/// * Pattern syntax characters are escaped with a backslash; whitespace, controls, and other
+///   characters that would be invisible are written as `\uXXXX` or `\UXXXXXXXX`.
+/// * The surrogate code points are written as `[:Cs:]`. Sets that contain only some of them
+///   cannot be represented by a pattern, and those code points are written as escapes that
+///   [`parse`](super::parse) rejects.
+///
+/// Formatters created with [`UnicodeSetFormatter::new_with_property_names`] additionally write sets
+/// whose code points are exactly those of a General_Category or Script value, or its complement,
+/// using the property name, such as `[:Lu:]` or `[:^Latn:]`.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::unicodeset_parse::*;
+/// use writeable::assert_writeable_eq;
+///
+/// let (set, _) = parse("[[:Lu:]-[A-Z]{ab} \\- \\u0009]").unwrap();
+///
+/// let formatter = UnicodeSetFormatter::new();
+/// let pattern = formatter.format(&set).to_string();
+/// assert!(pattern.starts_with(r"[\u0009\-À-Ö"));
+/// assert!(pattern.ends_with("{ab}]"));
+/// assert_eq!(parse(&pattern).unwrap().0, set);
+///
+/// let (set, _) = parse("[:^L:]").unwrap();
+/// let formatter = UnicodeSetFormatter::new_with_property_names();
+/// assert_writeable_eq!(formatter.format(&set), "[:^L:]");
+/// ```
+#[derive(Debug, Default)]
+pub struct UnicodeSetFormatter {
+    /// Sets that are written by name, with their short names.
+    properties: Vec<(String, CodePointInversionList<'static>)>,
+}
+
+impl UnicodeSetFormatter {
+    /// Creates a formatter that writes code points and strings, without recognizing properties.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a formatter that also writes General_Category and Script sets by name.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn new_with_property_names() -> Self {
+        Self::new_with_property_names_internal(
+            CodePointMapData::<GeneralCategory>::new(),
+            CodePointMapData::<Script>::new(),
+            PropertyNamesShort::<Script>::new(),
+        )
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new_with_property_names)]
+    pub fn try_new_with_property_names_unstable<P>(provider: &P) -> Result<Self, DataError>
+    where
+        P: ?Sized
+            + DataProvider<PropertyEnumGeneralCategoryV1>
+            + DataProvider<PropertyEnumScriptV1>
+            + DataProvider<PropertyNameShortScriptV1>,
+    {
+        Ok(Self::new_with_property_names_internal(
+            CodePointMapData::<GeneralCategory>::try_new_unstable(provider)?.as_borrowed(),
+            CodePointMapData::<Script>::try_new_unstable(provider)?.as_borrowed(),
+            PropertyNamesShort::<Script>::try_new_unstable(provider)?.as_borrowed(),
+        ))
+    }
+
+    fn new_with_property_names_internal(
+        gc: CodePointMapDataBorrowed<GeneralCategory>,
+        sc: CodePointMapDataBorrowed<Script>,
+        script_names: PropertyNamesShortBorrowed<Script>,
+    ) -> Self {
+        let mut properties = Vec::new();
+        for &(name, group) in GENERAL_CATEGORIES {
+            let set = gc
+                .iter_ranges()
+                .filter(|range| group.contains(range.value))
+                .map(|range| range.range)
+                .collect();
+            properties.push((name.into(), set));
+        }
+        for value in 0..=u16::MAX {
+            let script = Script::from_icu4c_value(value);
+            let Some(name) = script_names.get(script) else {
+                continue;
+            };
+            let set: CodePointInversionList = sc.iter_ranges_for_value(script).collect();
+            if !set.is_empty() {
+                properties.push((name.into(), set));
+            }
+        }
+        Self { properties }
+    }
+
+    /// Formats a set of code points and strings as a UnicodeSet pattern.
+    pub fn format<'a>(
+        &'a self,
+        set: &'a CodePointInversionListAndStringList<'_>,
+    ) -> FormattedUnicodeSet<'a> {
+        FormattedUnicodeSet {
+            formatter: self,
+            code_points: set.code_points(),
+            strings: set.strings(),
+        }
+    }
+
+    /// Formats a set of code points as a UnicodeSet pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::collections::codepointinvlist::CodePointInversionList;
+    /// use icu::experimental::unicodeset_parse::UnicodeSetFormatter;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let set = CodePointInversionList::try_from_u32_inversion_list_slice(&[
+    ///     0x24, 0x25, 0x41, 0x43, 0x61, 0x7B, 0x1F600, 0x1F601,
+    /// ])
+    /// .unwrap();
+    /// let formatter = UnicodeSetFormatter::new();
+    /// assert_writeable_eq!(formatter.format_code_points(&set), r"[\$ABa-z😀]");
+    /// ```
+    pub fn format_code_points<'a>(
+        &'a self,
+        set: &'a CodePointInversionList<'_>,
+    ) -> FormattedUnicodeSet<'a> {
+        FormattedUnicodeSet {
+            formatter: self,
+            code_points: set,
+            strings: VarZeroSlice::new_empty(),
+        }
+    }
+}
+
+/// A set formatted as a UnicodeSet pattern, returned by [`UnicodeSetFormatter`].
+#[derive(Debug)]
+pub struct FormattedUnicodeSet<'a> {
+    formatter: &'a UnicodeSetFormatter,
+    code_points: &'a CodePointInversionList<'a>,
+    strings: &'a VarZeroSlice<str>,
+}
+
+impl Writeable for FormattedUnicodeSet<'_> {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        let named = self.formatter.properties.iter().find_map(|(name, set)| {
+            if set == self.code_points {
+                Some((name, false))
+            } else if self
+                .code_points
+                .iter_ranges_complemented()
+                .eq(set.iter_ranges())
+            {
+                Some((name, true))
+            } else {
+                None
+            }
+        });
+
+        if let Some((name, inverted)) = named {
+            let has_strings = !self.strings.is_empty();
+            if has_strings {
+                sink.write_char('[')?;
+            }
+            sink.write_str(if inverted { "[:^" } else { "[:" })?;
+            sink.write_str(name)?;
+            sink.write_str(":]")?;
+            if has_strings {
+                write_strings(self.strings, sink)?;
+                sink.write_char(']')?;
+            }
+            return Ok(());
+        }
+
+        sink.write_char('[')?;
+        // Inverting a set removes its strings, so only sets without strings can be inverted.
+        if self.strings.is_empty()
+            && self.code_points.contains32(0)
+            && self.code_points.contains32(char::MAX as u32)
+        {
+            sink.write_char('^')?;
+            write_ranges(self.code_points.iter_ranges_complemented(), sink)?;
+        } else {
+            write_ranges(self.code_points.iter_ranges(), sink)?;
+            write_strings(self.strings, sink)?;
+        }
+        sink.write_char(']')
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        LengthHint::at_least(2)
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedUnicodeSet<'_>);
+
+fn write_ranges<W: Write + ?Sized>(
+    ranges: impl Iterator<Item = RangeInclusive<u32>>,
+    sink: &mut W,
+) -> fmt::Result {
+    let ranges = ranges.collect::<Vec<_>>();
+    // Surrogates cannot be escaped, but all of them together are written as a property.
+    let all_surrogates = ranges
+        .iter()
+        .any(|range| range.start() <= SURROGATES.start() && range.end() >= SURROGATES.end());
+    for range in ranges {
+        let (start, end) = range.into_inner();
+        if all_surrogates && start <= *SURROGATES.end() && end >= *SURROGATES.start() {
+            if start < *SURROGATES.start() {
+                write_range(start, SURROGATES.start() - 1, sink)?;
+            }
+            if end > *SURROGATES.end() {
+                write_range(SURROGATES.end() + 1, end, sink)?;
+            }
+        } else {
+            write_range(start, end, sink)?;
+        }
+    }
+    if all_surrogates {
+        sink.write_str("[:Cs:]")?;
+    }
+    Ok(())
+}
+
+fn write_range<W: Write + ?Sized>(start: u32, end: u32, sink: &mut W) -> fmt::Result {
+    write_code_point(start, sink)?;
+    if end != start {
+        // Two adjacent code points are shorter without the '-'
+        if end != start + 1 {
+            sink.write_char('-')?;
+        }
+        write_code_point(end, sink)?;
+    }
+    Ok(())
+}
+
+fn write_strings<W: Write + ?Sized>(strings: &VarZeroSlice<str>, sink: &mut W) -> fmt::Result {
+    for string in strings.iter() {
+        sink.write_char('{')?;
+        for c in string.chars() {
+            write_code_point(c as u32, sink)?;
+        }
+        sink.write_char('}')?;
+    }
+    Ok(())
+}
+
+fn write_code_point<W: Write + ?Sized>(code_point: u32, sink: &mut W) -> fmt::Result {
+    match char::from_u32(code_point) {
+        Some(c @ ('[' | ']' | '-' | '^' | '&' | '\\' | '{' | '}' | ':' | '$')) => {
+            sink.write_char('\\')?;
+            sink.write_char(c)
+        }
+        Some(c) if !is_invisible(c) => sink.write_char(c),
+        _ if code_point <= 0xFFFF => write!(sink, "\\u{code_point:04X}"),
+        _ => write!(sink, "\\U{code_point:08X}"),
+    }
+}
+
+/// Whether a character would be invisible or confusing in a pattern, including all whitespace,
+/// which the parser skips.
+fn is_invisible(c: char) -> bool {
+    c.is_control()
+        || c.is_whitespace()
+        || matches!(
+            c,
+            // Soft hyphen, Arabic letter mark, Mongolian vowel separator
+            '\u{AD}' | '\u{61C}' | '\u{180E}'
+            // Zero-width and directional formatting characters
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{206F}'
+            // Variation selectors, byte order mark, and specials
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FEFF}'
+            | '\u{FFF0}'..='\u{FFFF}'
+            | '\u{E0000}'..='\u{E0FFF}'
+            // Noncharacters and private use
+            | '\u{FDD0}'..='\u{FDEF}'
+            | '\u{E000}'..='\u{F8FF}'
+            | '\u{F0000}'..
+        )
+        || (c as u32) & 0xFFFE == 0xFFFE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unicodeset_parse::parse;
+    use writeable::assert_writeable_eq;
+
+    #[test]
+    fn test_format() {
+        let formatter = UnicodeSetFormatter::new();
+        for (source, expected) in [
+            ("[]", "[]"),
+            ("[a]", "[a]"),
+            ("[ab]", "[ab]"),
+            ("[a-c]", "[a-c]"),
+            ("[a-cx-z{ab}{}]", "[a-cx-z{}{ab}]"),
+            (r"[\[\]\-\^\&\\\{\}\:\$]", r"[\$\&\-\:\[-\^\{\}]"),
+            (r"[\ \u0009\u200B é]", r"[\u0009\u0020é\u200B]"),
+            (r"[{a\ b\}}]", r"[{a\u0020b\}}]"),
+            (r"[\U0001F600-\U0001F64F]", "[😀-🙏]"),
+            ("[^a]", "[^a]"),
+            ("[^]", "[^]"),
+            (r"[\u0000-\U0010FFFF]", "[^]"),
+            (r"[\u0000\U0010FFFF {ab}]", r"[\u0000\U0010FFFF{ab}]"),
+            ("[[:Cs:]a]", "[a[:Cs:]]"),
+            (r"[\uD7FF-\uE000]", "[\u{D7FF}\\uE000[:Cs:]]"),
+            ("[^[:Cs:]a]", "[^a[:Cs:]]"),
+        ] {
+            let (set, _) = parse(source).unwrap();
+            let formatted = formatter.format(&set);
+            assert_writeable_eq!(formatted, expected, "{source}");
+            assert_eq!(parse(expected).unwrap().0, set, "{expected}");
+        }
+    }
+
+    #[test]
+    fn test_format_property_names() {
+        let formatter = UnicodeSetFormatter::new_with_property_names();
+        for (source, expected) in [
+            ("[:L:]", "[:L:]"),
+            (r"\p{Lowercase_Letter}", "[:Ll:]"),
+            ("[[:Lu:][:Ll:][:Lt:]]", "[:LC:]"),
+            ("[:^Nd:]", "[:^Nd:]"),
+            ("[[:Cs:]]", "[:Cs:]"),
+            ("[[:Greek:]{αβ}]", "[[:Grek:]{αβ}]"),
+            ("[:^sc=Latin:]", "[:^Latn:]"),
+        ] {
+            let (set, _) = parse(source).unwrap();
+            assert_writeable_eq!(formatter.format(&set), expected, "{source}");
+            assert_eq!(parse(expected).unwrap().0, set, "{expected}");
+        }
+
+        // Other sets are written as ranges
+        let (set, _) = parse("[[:Latn:]-[a]]").unwrap();
+        let pattern = formatter.format(&set).to_string();
+        assert!(pattern.starts_with("[A-Zb-z"), "{pattern}");
+        assert_eq!(parse(&pattern).unwrap().0, set);
+    }
+}
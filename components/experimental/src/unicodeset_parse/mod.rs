@@ -8,6 +8,8 @@
 //!
 //! See [`parse`](parse()) for more information.
 //!
+//! [`UnicodeSetFormatter`] writes sets back as patterns that [`parse`](parse()) reads into the same set.
+//!
 //! [`ICU4X`]: ../icu/index.html
 
 // https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
@@ -26,6 +28,8 @@
 )]
 #![warn(missing_docs)]
 
+mod format;
 mod parse;
 
+pub use format::*;
 pub use parse::*;
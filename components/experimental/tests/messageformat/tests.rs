@@ -0,0 +1,338 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_calendar::{Date, Iso};
+use icu_experimental::messageformat::*;
+use icu_locale_core::locale;
+use icu_time::{DateTime, Time};
+use writeable::{assert_try_writeable_eq, assert_try_writeable_parts_eq, TryWriteable};
+
+fn en_formatter(message: &str) -> MessageFormatter {
+    MessageFormatter::try_new(locale!("en").into(), message, BidiIsolation::None.into())
+        .expect("message should be valid")
+}
+
+#[test]
+fn test_plural_selection() {
+    let formatter = en_formatter(
+        ".input {$count :number}
+         .match $count
+         0   {{no files}}
+         one {{{$count} file}}
+         *   {{{$count} files}}",
+    );
+    for (count, expected) in [
+        (0, "no files"),
+        (1, "1 file"),
+        (2, "2 files"),
+        (1000, "1,000 files"),
+    ] {
+        let arguments = MessageArguments::new().with("count", count);
+        assert_try_writeable_eq!(formatter.format(&arguments), expected);
+    }
+
+    // Numbers given as strings are parsed, and fraction digits affect the plural category
+    let arguments = MessageArguments::new().with("count", "1.0");
+    assert_try_writeable_eq!(formatter.format(&arguments), "1.0 files");
+}
+
+#[test]
+fn test_exact_and_ordinal_selection() {
+    let formatter = en_formatter(
+        ".input {$n :integer select=exact}
+         .match $n
+         1 {{exactly one}}
+         one {{never chosen}}
+         * {{other}}",
+    );
+    let arguments = MessageArguments::new().with("n", 1);
+    assert_try_writeable_eq!(formatter.format(&arguments), "exactly one");
+    // Selecting on an integer truncates the fraction first
+    let arguments = MessageArguments::new().with("n", "1.7");
+    assert_try_writeable_eq!(formatter.format(&arguments), "exactly one");
+
+    let formatter = en_formatter(
+        ".input {$n :integer select=ordinal}
+         .match $n
+         one {{{$n}st}}
+         two {{{$n}nd}}
+         few {{{$n}rd}}
+         *   {{{$n}th}}",
+    );
+    for (n, expected) in [
+        (1, "1st"),
+        (2, "2nd"),
+        (3, "3rd"),
+        (11, "11th"),
+        (23, "23rd"),
+    ] {
+        let arguments = MessageArguments::new().with("n", n);
+        assert_try_writeable_eq!(formatter.format(&arguments), expected);
+    }
+}
+
+#[test]
+fn test_multiple_selectors() {
+    let formatter = en_formatter(
+        ".input {$photos :number}
+         .input {$gender :string}
+         .match $photos $gender
+         one female {{She added a photo.}}
+         one *      {{They added a photo.}}
+         * female   {{She added {$photos} photos.}}
+         * *        {{They added {$photos} photos.}}",
+    );
+    let format = |photos: i32, gender: &'static str| {
+        let arguments = MessageArguments::new()
+            .with("photos", photos)
+            .with("gender", gender);
+        formatter
+            .format(&arguments)
+            .try_write_to_string()
+            .map(|s| s.into_owned())
+            .map_err(|(e, _)| e)
+    };
+    assert_eq!(format(1, "female").as_deref(), Ok("She added a photo."));
+    assert_eq!(format(1, "male").as_deref(), Ok("They added a photo."));
+    assert_eq!(format(3, "female").as_deref(), Ok("She added 3 photos."));
+    assert_eq!(format(3, "other").as_deref(), Ok("They added 3 photos."));
+}
+
+#[test]
+fn test_number_options() {
+    let formatter = en_formatter(
+        ".local $price = {$amount :number minimumFractionDigits=2 maximumFractionDigits=2}
+         {{{$price} | {$price :number signDisplay=always} | {$amount :integer useGrouping=never}}}",
+    );
+    let arguments = MessageArguments::new().with("amount", "12345.678");
+    assert_try_writeable_eq!(
+        formatter.format(&arguments),
+        "12,345.68 | +12,345.68 | 12345"
+    );
+    let arguments = MessageArguments::new().with("amount", 3);
+    assert_try_writeable_eq!(formatter.format(&arguments), "3.00 | +3.00 | 3");
+
+    // Bad options are reported, and the number is still formatted
+    let arguments = MessageArguments::new().with("n", 5);
+    let formatter = en_formatter("{$n :number signDisplay=sometimes}");
+    assert_try_writeable_eq!(
+        formatter.format(&arguments),
+        "5",
+        Err(MessageError::BadOption {
+            function: "number".into(),
+            option: "signDisplay".into()
+        })
+    );
+}
+
+#[test]
+fn test_datetime() {
+    let datetime = DateTime {
+        date: Date::try_new_iso(2025, 3, 14).unwrap(),
+        time: Time::try_new(15, 9, 26, 0).unwrap(),
+    };
+    let arguments = MessageArguments::new().with("d", datetime);
+    for (message, expected) in [
+        ("{$d :datetime}", "Mar 14, 2025, 3:09\u{202f}PM"),
+        ("{$d :date}", "Mar 14, 2025"),
+        ("{$d :date style=full}", "Friday, March 14, 2025"),
+        ("{$d :date style=short}", "3/14/25"),
+        ("{$d :time}", "3:09\u{202f}PM"),
+        ("{$d :time style=medium}", "3:09:26\u{202f}PM"),
+        (
+            "{$d :datetime dateStyle=long timeStyle=medium}",
+            "March 14, 2025, 3:09:26\u{202f}PM",
+        ),
+        ("{|2025-03-14| :date style=long}", "March 14, 2025"),
+        ("{|2025-03-14T08:30| :time}", "8:30\u{202f}AM"),
+    ] {
+        let formatter = en_formatter(message);
+        assert_try_writeable_eq!(formatter.format(&arguments), expected, Ok(()), "{message}");
+    }
+
+    let arguments =
+        MessageArguments::new().with("d", Date::<Iso>::try_new_iso(2025, 3, 14).unwrap());
+    let formatter = en_formatter("{$d :datetime}");
+    assert_try_writeable_eq!(
+        formatter.format(&arguments),
+        "Mar 14, 2025, 12:00\u{202f}AM"
+    );
+
+    // Dates need an annotation
+    let formatter = en_formatter("on {$d}");
+    assert_try_writeable_eq!(
+        formatter.format(&arguments),
+        "on {$d}",
+        Err(MessageError::MissingAnnotation("d".into()))
+    );
+    let formatter = en_formatter("{|yesterday| :date}");
+    assert_try_writeable_eq!(
+        formatter.format(&arguments),
+        "{|yesterday|}",
+        Err(MessageError::BadOperand("date".into()))
+    );
+}
+
+#[test]
+fn test_fallbacks() {
+    let arguments = MessageArguments::new().with("n", 1);
+    for (message, expected, error) in [
+        (
+            "Hello, {$name}!",
+            "Hello, {$name}!",
+            MessageError::UnresolvedVariable("name".into()),
+        ),
+        (
+            "{$n :uppercase}",
+            "{$n}",
+            MessageError::UnknownFunction("uppercase".into()),
+        ),
+        (
+            "{:uppercase} {|a\\|b| :uppercase}",
+            "{:uppercase} {|a\\|b|}",
+            MessageError::UnknownFunction("uppercase".into()),
+        ),
+        (
+            "{|horse| :number}",
+            "{|horse|}",
+            MessageError::BadOperand("number".into()),
+        ),
+        (
+            ".local $x = {$missing :number} {{{$x} and {$n}}}",
+            "{$missing} and 1",
+            MessageError::UnresolvedVariable("missing".into()),
+        ),
+        (
+            ".input {$n :number} .match $n one {{one}} horse {{horse}} * {{other}}",
+            "one",
+            MessageError::BadVariantKey("horse".into()),
+        ),
+    ] {
+        let formatter = en_formatter(message);
+        assert_try_writeable_eq!(
+            formatter.format(&arguments),
+            expected,
+            Err(error.clone()),
+            "{message}"
+        );
+    }
+}
+
+#[test]
+fn test_syntax_errors() {
+    let error = MessageFormatter::try_new(
+        locale!("en").into(),
+        ".match $n * {{other}}",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert!(
+        matches!(error, MessageFormatterLoadError::Parse(_)),
+        "{error:?}"
+    );
+}
+
+#[test]
+fn test_bidi_isolation() {
+    let arguments = MessageArguments::new()
+        .with("name", "Alice")
+        .with("count", 5);
+
+    let formatter = MessageFormatter::try_new(
+        locale!("en").into(),
+        "{$name} has {$count} messages, {$count :number u:dir=rtl}, {$name :string u:dir=ltr}",
+        Default::default(),
+    )
+    .unwrap();
+    assert_try_writeable_eq!(
+        formatter.format(&arguments),
+        "\u{2068}Alice\u{2069} has 5 messages, \u{2067}5\u{2069}, Alice"
+    );
+
+    // In a right-to-left message, left-to-right values are isolated, too
+    let formatter = MessageFormatter::try_new(
+        locale!("he").into(),
+        "{$name} {$count :number u:dir=ltr} {$count :number}",
+        Default::default(),
+    )
+    .unwrap();
+    assert_try_writeable_eq!(
+        formatter.format(&arguments),
+        "\u{2068}Alice\u{2069} \u{2066}5\u{2069} \u{2067}5\u{2069}"
+    );
+}
+
+#[test]
+fn test_parts() {
+    use icu_decimal::parts as decimal;
+    use icu_experimental::messageformat::parts;
+
+    let formatter = MessageFormatter::try_new(
+        locale!("en").into(),
+        "{$name} has {$count} {:x:oops}",
+        Default::default(),
+    )
+    .unwrap();
+    let arguments = MessageArguments::new()
+        .with("name", "Bob")
+        .with("count", 1234);
+    assert_try_writeable_parts_eq!(
+        formatter.format(&arguments),
+        "\u{2068}Bob\u{2069} has 1,234 \u{2068}{:x:oops}\u{2069}",
+        Err(MessageError::UnknownFunction("x:oops".into())),
+        [
+            (0, 3, parts::BIDI_ISOLATION),
+            (3, 6, parts::STRING),
+            (6, 9, parts::BIDI_ISOLATION),
+            (9, 14, parts::LITERAL),
+            (14, 19, decimal::INTEGER),
+            (14, 19, parts::NUMBER),
+            (15, 16, decimal::GROUP),
+            (19, 20, parts::LITERAL),
+            (20, 23, parts::BIDI_ISOLATION),
+            (23, 32, parts::FALLBACK),
+            (32, 35, parts::BIDI_ISOLATION),
+        ]
+    );
+}
+
+#[test]
+fn test_custom_selector() {
+    struct Parity;
+
+    impl MessageFunction for Parity {
+        fn format(
+            &self,
+            operand: Option<&MessageArgument>,
+            _options: &FunctionOptions,
+        ) -> Result<String, MessageError> {
+            match operand {
+                Some(MessageArgument::Number(n)) => Ok(if n.absolute.digit_at(0) % 2 == 0 {
+                    "even".into()
+                } else {
+                    "odd".into()
+                }),
+                _ => Err(MessageError::BadOperand("test:parity".into())),
+            }
+        }
+    }
+
+    let formatter = en_formatter(
+        ".input {$n :test:parity}
+         .match $n
+         even {{even}}
+         * {{odd}}",
+    )
+    .with_functions(FunctionRegistry::new().with("test:parity", Parity));
+    let arguments = MessageArguments::new().with("n", 4);
+    assert_try_writeable_eq!(formatter.format(&arguments), "even");
+    let arguments = MessageArguments::new().with("n", 7);
+    assert_try_writeable_eq!(formatter.format(&arguments), "odd");
+    let arguments = MessageArguments::new().with("n", "four");
+    assert_try_writeable_eq!(
+        formatter.format(&arguments),
+        "odd",
+        Err(MessageError::BadOperand("test:parity".into()))
+    );
+}
@@ -3,10 +3,14 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use fixed_decimal::Decimal;
+use icu_calendar::Iso;
 use icu_experimental::relativetime::{
-    options::Numeric, RelativeTimeFormatter, RelativeTimeFormatterOptions,
+    options::{Numeric, RelativeDateTimeFormatterOptions, Width},
+    RelativeDateTimeFormatter, RelativeTimeFormatter, RelativeTimeFormatterOptions,
+    RelativeTimeUnit,
 };
 use icu_locale_core::locale;
+use icu_time::{zone::UtcOffset, ZonedDateTime};
 use writeable::assert_writeable_eq;
 
 macro_rules! generate_test {
@@ -1243,3 +1247,81 @@ generate_test!(
         (10, "خلال ١٠ سنوات")
     ]
 );
+
+fn zdt(s: &str) -> ZonedDateTime<Iso, UtcOffset> {
+    ZonedDateTime::try_offset_only_from_str(s, Iso).unwrap()
+}
+
+#[test]
+fn test_relative_date_time_thresholds() {
+    let now = zdt("2025-01-31T12:00Z");
+
+    let formatter =
+        RelativeDateTimeFormatter::try_new(locale!("en").into(), Default::default()).unwrap();
+    for (value, expected, unit) in [
+        ("2025-01-31T12:00Z", "now", RelativeTimeUnit::Second),
+        (
+            "2025-01-31T12:00:59Z",
+            "in 59 seconds",
+            RelativeTimeUnit::Second,
+        ),
+        (
+            "2025-01-31T11:01Z",
+            "59 minutes ago",
+            RelativeTimeUnit::Minute,
+        ),
+        ("2025-02-01T11:59Z", "in 23 hours", RelativeTimeUnit::Hour),
+        ("2025-02-01T12:00Z", "tomorrow", RelativeTimeUnit::Day),
+        ("2025-01-25T00:00Z", "6 days ago", RelativeTimeUnit::Day),
+        ("2025-01-24T00:00Z", "last week", RelativeTimeUnit::Week),
+        ("2025-02-27T12:00Z", "in 3 weeks", RelativeTimeUnit::Week),
+        // Four weeks, but not a full month since January 31
+        ("2025-02-28T12:00Z", "next month", RelativeTimeUnit::Month),
+        ("2024-12-31T12:00Z", "last month", RelativeTimeUnit::Month),
+        ("2024-01-31T12:00Z", "last year", RelativeTimeUnit::Year),
+        ("2035-06-01T12:00Z", "in 10 years", RelativeTimeUnit::Year),
+    ] {
+        let formatted = formatter.format_relative_to(&zdt(value), &now);
+        assert_writeable_eq!(formatted, expected, "{value}");
+        assert_eq!(formatted.unit(), unit, "{value}");
+    }
+
+    let mut options = RelativeDateTimeFormatterOptions::default();
+    // Skip hours and weeks
+    options.thresholds.day = 0;
+    options.thresholds.month = 0;
+    let formatter = RelativeDateTimeFormatter::try_new(locale!("en").into(), options).unwrap();
+    for (value, expected) in [
+        ("2025-01-31T11:01Z", "59 minutes ago"),
+        ("2025-01-31T10:00Z", "today"),
+        ("2025-01-27T12:00Z", "4 days ago"),
+        ("2025-01-10T12:00Z", "last month"),
+    ] {
+        assert_writeable_eq!(formatter.format_relative_to(&zdt(value), &now), expected);
+    }
+}
+
+#[test]
+fn test_relative_date_time_options() {
+    let now = zdt("2025-01-31T12:00Z");
+
+    let mut options = RelativeDateTimeFormatterOptions::default();
+    options.width = Width::Short;
+    options.numeric = Numeric::Always;
+    let formatter = RelativeDateTimeFormatter::try_new(locale!("en").into(), options).unwrap();
+    assert_writeable_eq!(
+        formatter.format_relative_to(&zdt("2025-01-30T08:00Z"), &now),
+        "1 day ago"
+    );
+    assert_writeable_eq!(
+        formatter.format_relative_to(&zdt("2025-04-30T08:00Z"), &now),
+        "in 2 mo."
+    );
+
+    let formatter =
+        RelativeDateTimeFormatter::try_new(locale!("ar-EG").into(), Default::default()).unwrap();
+    assert_writeable_eq!(
+        formatter.format_relative_to(&zdt("2025-01-29T08:00Z"), &now),
+        "أول أمس"
+    );
+}
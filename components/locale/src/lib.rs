@@ -93,6 +93,7 @@ pub mod exemplar_chars;
 mod expander;
 pub mod fallback;
 pub mod provider;
+mod validator;
 
 pub use icu_locale_core::*;
 
@@ -101,6 +102,7 @@ pub use directionality::{Direction, LocaleDirectionality};
 pub use expander::LocaleExpander;
 #[doc(inline)]
 pub use fallback::LocaleFallbacker;
+pub use validator::{InvalidSubtag, LocaleValidator, ValidityError, ValidityErrorKind};
 
 /// Used to track the result of a transformation operation that potentially modifies its argument in place.
 #[derive(Debug, PartialEq)]
@@ -93,6 +93,14 @@ icu_provider::data_marker!(
     is_singleton = true
 );
 
+icu_provider::data_marker!(
+    /// Marker for subtag validity data.
+    LocaleValidityV1,
+    "locale/validity/v1",
+    LocaleValidity<'static>,
+    is_singleton = true
+);
+
 icu_provider::data_marker!(
     /// Marker for auxiliary exemplar characters data.
     LocaleExemplarCharactersAuxiliaryV1,
@@ -126,6 +134,8 @@ icu_provider::data_marker!(
 
 #[cfg(feature = "datagen")]
 /// The latest minimum set of markers required by this component.
+///
+/// Subtag validity data ([`LocaleValidityV1`]) is not included, as it is not part of compiled data.
 pub const MARKERS: &[DataMarkerInfo] = &[
     LocaleAliasesV1::INFO,
     LocaleExemplarCharactersAuxiliaryV1::INFO,
@@ -146,7 +156,7 @@ use icu_locale_core::subtags::{Language, Region, Script, Variant};
 use icu_provider::prelude::*;
 use potential_utf::PotentialUtf8;
 use tinystr::{TinyAsciiStr, UnvalidatedTinyAsciiStr};
-use zerovec::{VarZeroVec, ZeroMap, ZeroSlice, ZeroVec};
+use zerovec::{VarZeroVec, ZeroMap, ZeroMap2d, ZeroSlice, ZeroVec};
 
 // We use raw TinyAsciiStrs for map keys, as we then don't have to
 // validate them as subtags on deserialization. Map lookup can be
@@ -484,3 +494,87 @@ icu_provider::data_struct!(
     ExemplarCharactersData<'_>,
     #[cfg(feature = "datagen")]
 );
+
+/// The validity status of a subtag, extension key, or extension value, as defined
+/// in CLDR's `validity` and `bcp47` data.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(IdStatusULE)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_locale::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
+#[repr(u8)]
+pub enum IdStatus {
+    /// A regular code, such as `en` or `Latn`.
+    Regular = 0,
+    /// A code with a special meaning, such as `mul` or `Zyyy`.
+    Special = 1,
+    /// A region code that represents a grouping of regions, such as `419`.
+    Macroregion = 2,
+    /// A code that is valid, but deprecated in favor of another code.
+    Deprecated = 3,
+    /// A code reserved for private use, such as `qaa` or `XA`.
+    PrivateUse = 4,
+    /// A code that represents an unknown value, such as `und` or `ZZ`.
+    Unknown = 5,
+}
+
+/// Subtag validity data, used to check whether the subtags of a locale
+/// are valid according to <https://www.unicode.org/reports/tr35/#Validity_Data>.
+///
+/// Codes that are not present in a map are not valid.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_locale::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct LocaleValidity<'data> {
+    /// Language subtags.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub languages: ZeroMap<'data, UnvalidatedLanguage, IdStatus>,
+    /// Script subtags.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub scripts: ZeroMap<'data, UnvalidatedScript, IdStatus>,
+    /// Region subtags.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub regions: ZeroMap<'data, UnvalidatedRegion, IdStatus>,
+    /// Variant subtags.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub variants: ZeroMap<'data, UnvalidatedVariant, IdStatus>,
+    /// Subdivision codes, as used in the `-u-sd` and `-u-rg` keywords.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub subdivisions: ZeroMap<'data, UnvalidatedSubdivision, IdStatus>,
+    /// Unicode extension (`-u-`) keys.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub unicode_keys: ZeroMap<'data, UnvalidatedTinyAsciiStr<2>, IdStatus>,
+    /// Unicode extension (`-u-`) values, by key.
+    ///
+    /// Besides literal values, this contains the CLDR placeholders `CODEPOINTS`,
+    /// `REORDER_CODE`, `RG_KEY_VALUE`, `SCRIPT_CODE`, `SUBDIVISION_CODE`, and
+    /// `PRIVATE_USE`, which stand for whole classes of values.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub unicode_values: ZeroMap2d<'data, UnvalidatedTinyAsciiStr<2>, str, IdStatus>,
+    /// Transform extension (`-t-`) keys.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub transform_keys: ZeroMap<'data, UnvalidatedTinyAsciiStr<2>, IdStatus>,
+    /// Transform extension (`-t-`) values, by key, including placeholders as in
+    /// [`Self::unicode_values`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub transform_values: ZeroMap2d<'data, UnvalidatedTinyAsciiStr<2>, str, IdStatus>,
+}
+
+icu_provider::data_struct!(
+    LocaleValidity<'_>,
+    #[cfg(feature = "datagen")]
+);
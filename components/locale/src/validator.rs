@@ -0,0 +1,540 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::{IdStatus, LocaleValidity, LocaleValidityV1};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::ControlFlow;
+use icu_locale_core::extensions::{transform, unicode};
+use icu_locale_core::subtags::{Language, Region, Script, Variant};
+use icu_locale_core::{LanguageIdentifier, Locale};
+use icu_provider::prelude::*;
+use tinystr::{TinyAsciiStr, UnvalidatedTinyAsciiStr};
+use zerovec::maps::ZeroMap2dCursor;
+
+/// Placeholders in the extension value data that stand for whole classes of values.
+const PLACEHOLDERS: [&str; 6] = [
+    "CODEPOINTS",
+    "PRIVATE_USE",
+    "REORDER_CODE",
+    "RG_KEY_VALUE",
+    "SCRIPT_CODE",
+    "SUBDIVISION_CODE",
+];
+
+/// A tool for checking whether the subtags of a [`Locale`] are valid, following
+/// the definition of validity in [UTS #35: Validity].
+///
+/// The language, script, region, and variant subtags (including those of the
+/// `-t-` language), as well as the keys and values of the `-u-` and `-t-`
+/// extensions, are checked against CLDR's validity and BCP 47 data. Subdivision
+/// codes are checked in the `-u-sd` and `-u-rg` keywords. Other extensions and
+/// private use subtags are not checked.
+///
+/// Codes with a special meaning, such as `und` or `ZZ`, are valid. Deprecated
+/// and private use codes are reported as errors, as callers usually want to
+/// reject or canonicalize them.
+///
+/// Validity data is not included in compiled data. Use `icu4x-datagen` to generate
+/// the [`LocaleValidityV1`] marker.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locale::provider::LocaleValidityV1;
+/// use icu::locale::{locale, subtags::region, InvalidSubtag, Locale, LocaleValidator, ValidityErrorKind};
+/// use icu_provider::prelude::*;
+///
+/// fn check(provider: &impl DataProvider<LocaleValidityV1>) {
+///     let validator = LocaleValidator::try_new_unstable(provider).unwrap();
+///
+///     assert_eq!(validator.validate(&locale!("de-CH-u-ca-buddhist")), Ok(()));
+///
+///     let error = validator.validate(&locale!("de-QZ")).unwrap_err();
+///     assert_eq!(error.subtag, InvalidSubtag::Region(region!("QZ")));
+///     assert_eq!(error.kind, ValidityErrorKind::PrivateUse);
+///
+///     let locale = "en-XY-u-ca-julian".parse::<Locale>().unwrap();
+///     assert_eq!(validator.errors(&locale).len(), 2);
+/// }
+/// ```
+///
+/// [UTS #35: Validity]: https://www.unicode.org/reports/tr35/#Validity_Data
+#[derive(Debug)]
+pub struct LocaleValidator {
+    data: DataPayload<LocaleValidityV1>,
+}
+
+/// An error returned by [`LocaleValidator`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ValidityError {
+    /// The subtag that is not valid.
+    pub subtag: InvalidSubtag,
+    /// Why the subtag is not valid.
+    pub kind: ValidityErrorKind,
+}
+
+impl fmt::Display for ValidityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.kind, self.subtag)
+    }
+}
+
+impl core::error::Error for ValidityError {}
+
+/// The subtag of a [`ValidityError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidSubtag {
+    /// A language subtag.
+    Language(Language),
+    /// A script subtag.
+    Script(Script),
+    /// A region subtag.
+    Region(Region),
+    /// A variant subtag.
+    Variant(Variant),
+    /// The key of a `-u-` keyword.
+    UnicodeKey(unicode::Key),
+    /// The value of a `-u-` keyword, with its key.
+    UnicodeValue(unicode::Key, unicode::Value),
+    /// The key of a `-t-` field.
+    TransformKey(transform::Key),
+    /// The value of a `-t-` field, with its key.
+    TransformValue(transform::Key, transform::Value),
+}
+
+impl fmt::Display for InvalidSubtag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Language(l) => write!(f, "language subtag `{l}`"),
+            Self::Script(s) => write!(f, "script subtag `{s}`"),
+            Self::Region(r) => write!(f, "region subtag `{r}`"),
+            Self::Variant(v) => write!(f, "variant subtag `{v}`"),
+            Self::UnicodeKey(k) => write!(f, "unicode extension key `{k}`"),
+            Self::UnicodeValue(k, v) => write!(f, "unicode extension value `{k}-{v}`"),
+            Self::TransformKey(k) => write!(f, "transform extension key `{k}`"),
+            Self::TransformValue(k, v) => write!(f, "transform extension value `{k}-{v}`"),
+        }
+    }
+}
+
+/// The reason for a [`ValidityError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum ValidityErrorKind {
+    /// The code is not defined by CLDR.
+    #[displaydoc("unknown")]
+    Unknown,
+    /// The code is deprecated, and should be replaced by its preferred value.
+    #[displaydoc("deprecated")]
+    Deprecated,
+    /// The code is reserved for private use.
+    #[displaydoc("private use")]
+    PrivateUse,
+}
+
+impl ValidityErrorKind {
+    fn from_status(status: Option<IdStatus>) -> Option<Self> {
+        match status {
+            None => Some(Self::Unknown),
+            Some(IdStatus::Deprecated) => Some(Self::Deprecated),
+            Some(IdStatus::PrivateUse) => Some(Self::PrivateUse),
+            Some(_) => None,
+        }
+    }
+}
+
+impl LocaleValidator {
+    /// Creates a new [`LocaleValidator`] from validity data provided by a [`BufferProvider`].
+    ///
+    /// ✨ *Enabled with the `serde` feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+    ) -> Result<Self, DataError> {
+        use icu_provider::buf::AsDeserializingBufferProvider;
+        Self::try_new_unstable(&provider.as_deserializing())
+    }
+
+    /// Creates a new [`LocaleValidator`] from validity data provided by a [`DataProvider`].
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// <div class="stab unstable">⚠️ The bounds on <tt>provider</tt> may change over time, including in SemVer minor releases.</div>
+    pub fn try_new_unstable(
+        provider: &(impl DataProvider<LocaleValidityV1> + ?Sized),
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            data: provider.load(Default::default())?.payload,
+        })
+    }
+
+    /// Checks whether all subtags of the locale are valid, returning the first error otherwise.
+    pub fn validate(&self, locale: &Locale) -> Result<(), ValidityError> {
+        match self.for_each_error(locale, &mut ControlFlow::Break) {
+            ControlFlow::Break(e) => Err(e),
+            ControlFlow::Continue(()) => Ok(()),
+        }
+    }
+
+    /// Returns all validity errors in the locale, in the order in which the subtags appear.
+    pub fn errors(&self, locale: &Locale) -> Vec<ValidityError> {
+        let mut errors = Vec::new();
+        let _ = self.for_each_error(locale, &mut |e| {
+            errors.push(e);
+            ControlFlow::<()>::Continue(())
+        });
+        errors
+    }
+
+    fn for_each_error<B>(
+        &self,
+        locale: &Locale,
+        f: &mut impl FnMut(ValidityError) -> ControlFlow<B>,
+    ) -> ControlFlow<B> {
+        let data = self.data.get();
+
+        check_language_identifier(data, &locale.id, f)?;
+
+        for (key, value) in locale.extensions.unicode.keywords.iter() {
+            let key_str = key.to_tinystr().to_unvalidated();
+            if let Some(kind) =
+                ValidityErrorKind::from_status(data.unicode_keys.get_copied(&key_str))
+            {
+                f(ValidityError {
+                    subtag: InvalidSubtag::UnicodeKey(*key),
+                    kind,
+                })?;
+                if kind == ValidityErrorKind::Unknown {
+                    continue;
+                }
+            }
+            let value_str = if value.is_empty() {
+                String::from("true")
+            } else {
+                format!("{value}")
+            };
+            if let Some(kind) = check_value(data, data.unicode_values.get0(&key_str), &value_str) {
+                f(ValidityError {
+                    subtag: InvalidSubtag::UnicodeValue(*key, value.clone()),
+                    kind,
+                })?;
+            }
+        }
+
+        if let Some(tlang) = &locale.extensions.transform.lang {
+            check_language_identifier(data, tlang, f)?;
+        }
+        for (key, value) in locale.extensions.transform.fields.iter() {
+            let key_str = key.to_tinystr().to_unvalidated();
+            if let Some(kind) =
+                ValidityErrorKind::from_status(data.transform_keys.get_copied(&key_str))
+            {
+                f(ValidityError {
+                    subtag: InvalidSubtag::TransformKey(*key),
+                    kind,
+                })?;
+                if kind == ValidityErrorKind::Unknown {
+                    continue;
+                }
+            }
+            if let Some(kind) = check_value(
+                data,
+                data.transform_values.get0(&key_str),
+                &format!("{value}"),
+            ) {
+                f(ValidityError {
+                    subtag: InvalidSubtag::TransformValue(*key, value.clone()),
+                    kind,
+                })?;
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+fn check_language_identifier<B>(
+    data: &LocaleValidity<'_>,
+    id: &LanguageIdentifier,
+    f: &mut impl FnMut(ValidityError) -> ControlFlow<B>,
+) -> ControlFlow<B> {
+    if let Some(kind) = ValidityErrorKind::from_status(
+        data.languages
+            .get_copied(&id.language.to_tinystr().to_unvalidated()),
+    ) {
+        f(ValidityError {
+            subtag: InvalidSubtag::Language(id.language),
+            kind,
+        })?;
+    }
+    if let Some(script) = id.script {
+        if let Some(kind) = ValidityErrorKind::from_status(
+            data.scripts
+                .get_copied(&script.to_tinystr().to_unvalidated()),
+        ) {
+            f(ValidityError {
+                subtag: InvalidSubtag::Script(script),
+                kind,
+            })?;
+        }
+    }
+    if let Some(region) = id.region {
+        if let Some(kind) = ValidityErrorKind::from_status(
+            data.regions
+                .get_copied(&region.to_tinystr().to_unvalidated()),
+        ) {
+            f(ValidityError {
+                subtag: InvalidSubtag::Region(region),
+                kind,
+            })?;
+        }
+    }
+    for variant in id.variants.iter() {
+        if let Some(kind) = ValidityErrorKind::from_status(
+            data.variants
+                .get_copied(&variant.to_tinystr().to_unvalidated()),
+        ) {
+            f(ValidityError {
+                subtag: InvalidSubtag::Variant(*variant),
+                kind,
+            })?;
+        }
+    }
+    ControlFlow::Continue(())
+}
+
+/// Checks an extension value against the values of its key.
+///
+/// The value is first looked up as a whole. If the key accepts a class of values, such
+/// as script codes for `-u-kr`, each of its subtags is checked individually instead.
+fn check_value(
+    data: &LocaleValidity<'_>,
+    values: Option<ZeroMap2dCursor<'_, '_, UnvalidatedTinyAsciiStr<2>, str, IdStatus>>,
+    value: &str,
+) -> Option<ValidityErrorKind> {
+    let values = values?;
+    if let Some(status) = values.get1_copied(value) {
+        return ValidityErrorKind::from_status(Some(status));
+    }
+    if !PLACEHOLDERS.iter().any(|p| values.get1(p).is_some()) {
+        return Some(ValidityErrorKind::Unknown);
+    }
+    let mut result = None;
+    for subtag in value.split('-') {
+        let status = values
+            .get1_copied(subtag)
+            .or_else(|| placeholder_status(data, &values, subtag));
+        match ValidityErrorKind::from_status(status) {
+            Some(ValidityErrorKind::Unknown) => return Some(ValidityErrorKind::Unknown),
+            Some(kind) => result = result.or(Some(kind)),
+            None => {}
+        }
+    }
+    result
+}
+
+/// Returns the status of a single subtag matched through one of the key's placeholders.
+fn placeholder_status(
+    data: &LocaleValidity<'_>,
+    values: &ZeroMap2dCursor<'_, '_, UnvalidatedTinyAsciiStr<2>, str, IdStatus>,
+    subtag: &str,
+) -> Option<IdStatus> {
+    let has = |placeholder: &str| values.get1_copied(placeholder);
+
+    if let Some(status) = has("CODEPOINTS") {
+        if (4..=6).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Some(status);
+        }
+    }
+    if let Some(status) = has("PRIVATE_USE") {
+        if (3..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return Some(status);
+        }
+    }
+    if has("REORDER_CODE").is_some() || has("SCRIPT_CODE").is_some() {
+        if let Ok(script) = TinyAsciiStr::<4>::try_from_str(subtag) {
+            if let Some(status) = data
+                .scripts
+                .get_copied(&script.to_ascii_titlecase().to_unvalidated())
+            {
+                return Some(status);
+            }
+        }
+    }
+    if has("SUBDIVISION_CODE").is_some() || has("RG_KEY_VALUE").is_some() {
+        if let Ok(subdivision) = TinyAsciiStr::<7>::try_from_str(subtag) {
+            if let Some(status) = data.subdivisions.get_copied(&subdivision.to_unvalidated()) {
+                return Some(status);
+            }
+        }
+    }
+    if has("RG_KEY_VALUE").is_some() {
+        // A region followed by `zzzz` stands for the whole region
+        if let Some(region) = subtag
+            .strip_suffix("zzzz")
+            .and_then(|r| TinyAsciiStr::<3>::try_from_str(r).ok())
+        {
+            return data
+                .regions
+                .get_copied(&region.to_ascii_uppercase().to_unvalidated());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locale_core::subtags::language;
+    use zerovec::{ZeroMap, ZeroMap2d};
+
+    fn map<const N: usize>(
+        entries: &[(&str, IdStatus)],
+    ) -> ZeroMap<'static, UnvalidatedTinyAsciiStr<N>, IdStatus> {
+        entries
+            .iter()
+            .map(|&(k, v)| (TinyAsciiStr::try_from_str(k).unwrap().to_unvalidated(), v))
+            .collect()
+    }
+
+    fn map2d(
+        entries: &[(&str, &str, IdStatus)],
+    ) -> ZeroMap2d<'static, UnvalidatedTinyAsciiStr<2>, str, IdStatus> {
+        let mut map = ZeroMap2d::new();
+        for &(k0, k1, v) in entries {
+            map.insert(
+                &TinyAsciiStr::try_from_str(k0).unwrap().to_unvalidated(),
+                k1,
+                &v,
+            );
+        }
+        map
+    }
+
+    fn validator() -> LocaleValidator {
+        use IdStatus::*;
+        LocaleValidator {
+            data: DataPayload::from_owned(LocaleValidity {
+                languages: map(&[
+                    ("de", Regular),
+                    ("en", Regular),
+                    ("iw", Deprecated),
+                    ("qaa", PrivateUse),
+                    ("und", Unknown),
+                ]),
+                scripts: map(&[("Latn", Regular), ("Zzzz", Unknown)]),
+                regions: map(&[
+                    ("001", Macroregion),
+                    ("CH", Regular),
+                    ("QZ", PrivateUse),
+                    ("US", Regular),
+                ]),
+                variants: map(&[("1996", Regular), ("heploc", Deprecated)]),
+                subdivisions: map(&[("chzh", Regular), ("usca", Regular)]),
+                unicode_keys: map(&[
+                    ("ca", Regular),
+                    ("kr", Regular),
+                    ("rg", Regular),
+                    ("vt", Deprecated),
+                ]),
+                unicode_values: map2d(&[
+                    ("ca", "buddhist", Regular),
+                    ("ca", "ethiopic-amete-alem", Deprecated),
+                    ("ca", "islamic-civil", Regular),
+                    ("kr", "digit", Regular),
+                    ("kr", "REORDER_CODE", Regular),
+                    ("rg", "RG_KEY_VALUE", Regular),
+                    ("vt", "CODEPOINTS", Regular),
+                ]),
+                transform_keys: map(&[("m0", Regular), ("x0", Regular)]),
+                transform_values: map2d(&[
+                    ("m0", "ungegn", Regular),
+                    ("x0", "PRIVATE_USE", Regular),
+                ]),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_valid() {
+        let validator = validator();
+        for s in [
+            "und",
+            "en-Latn-US-1996",
+            "de-001",
+            "und-Zzzz",
+            "de-CH-u-ca-buddhist-rg-chzh",
+            "en-u-ca-islamic-civil",
+            "en-u-kr-latn-digit",
+            "en-u-rg-uszzzz",
+            "de-t-en-m0-ungegn-x0-foo-barbaz",
+        ] {
+            let locale = s.parse::<Locale>().unwrap();
+            assert_eq!(validator.validate(&locale), Ok(()), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let validator = validator();
+        let locale = "iw-QZ-heploc-u-ca-julian-vt-0041-xx-yyy"
+            .parse::<Locale>()
+            .unwrap();
+        let errors = validator
+            .errors(&locale)
+            .into_iter()
+            .map(|e| (e.subtag.to_string(), e.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                ("language subtag `iw`", ValidityErrorKind::Deprecated),
+                ("region subtag `QZ`", ValidityErrorKind::PrivateUse),
+                ("variant subtag `heploc`", ValidityErrorKind::Deprecated),
+                (
+                    "unicode extension value `ca-julian`",
+                    ValidityErrorKind::Unknown
+                ),
+                ("unicode extension key `vt`", ValidityErrorKind::Deprecated),
+                ("unicode extension key `xx`", ValidityErrorKind::Unknown),
+            ]
+            .map(|(s, k)| (s.to_string(), k))
+        );
+        assert_eq!(
+            validator.validate(&locale).unwrap_err().subtag,
+            InvalidSubtag::Language(language!("iw"))
+        );
+
+        for (s, kind) in [
+            ("en-u-ca-ethiopic-amete-alem", ValidityErrorKind::Deprecated),
+            ("en-u-ca-buddhist-gregory", ValidityErrorKind::Unknown),
+            ("en-u-ca", ValidityErrorKind::Unknown),
+            ("en-u-kr-latn-xxxx", ValidityErrorKind::Unknown),
+            ("en-u-rg-usxx", ValidityErrorKind::Unknown),
+            ("en-u-rg-qzzzzz", ValidityErrorKind::PrivateUse),
+            ("en-t-qaa", ValidityErrorKind::PrivateUse),
+            ("en-t-m0-bgn", ValidityErrorKind::Unknown),
+        ] {
+            let locale = s.parse::<Locale>().unwrap();
+            assert_eq!(
+                validator.validate(&locale).map_err(|e| e.kind),
+                Err(kind),
+                "{s}"
+            );
+        }
+
+        assert_eq!(
+            validator.validate(&"en-x-foo".parse().unwrap()),
+            Ok(()),
+            "private use subtags are not checked"
+        );
+    }
+}
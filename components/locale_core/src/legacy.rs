@@ -0,0 +1,625 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Conversion between ICU legacy locale IDs and BCP 47 [`Locale`]s.
+//!
+//! ICU4C and ICU4J identify locales with IDs such as `de_DE@collation=phonebook;currency=EUR`,
+//! which use `_` as a separator, put keywords after an `@`, and use long keyword names and
+//! values. These are converted to and from BCP 47 following the key and type aliases of
+//! [UTS #35](https://unicode.org/reports/tr35/#Unicode_Locale_Extension_Data_Files).
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::locale::{locale, Locale};
+//!
+//! let conversion =
+//!     Locale::try_from_legacy_id("de_DE@collation=phonebook;currency=EUR")
+//!         .unwrap();
+//! assert_eq!(
+//!     conversion.locale,
+//!     Locale::try_from_str("de-DE-u-co-phonebk-cu-eur").unwrap()
+//! );
+//! assert!(conversion.is_lossless());
+//!
+//! assert_eq!(
+//!     locale!("ja-JP-u-ca-japanese").to_legacy_id(),
+//!     "ja_JP@calendar=japanese"
+//! );
+//! ```
+
+use crate::extensions::other::Other;
+use crate::extensions::private::Private;
+use crate::extensions::transform::Transform;
+use crate::extensions::unicode::{Attributes, Key, Value};
+use crate::subtags::{Language, Region, Script, Variant, Variants};
+use crate::{Locale, ParseError};
+use alloc::string::String;
+use alloc::vec::Vec;
+use writeable::Writeable;
+
+/// Legacy keyword names of Unicode extension keys, as `(key, legacy name)`.
+const KEYS: &[(&str, &str)] = &[
+    ("ca", "calendar"),
+    ("co", "collation"),
+    ("cu", "currency"),
+    ("hc", "hours"),
+    ("ka", "colalternate"),
+    ("kb", "colbackwards"),
+    ("kc", "colcaselevel"),
+    ("kf", "colcasefirst"),
+    ("kh", "colhiraganaquaternary"),
+    ("kk", "colnormalization"),
+    ("kn", "colnumeric"),
+    ("kr", "colreorder"),
+    ("ks", "colstrength"),
+    ("ms", "measure"),
+    ("nu", "numbers"),
+    ("tz", "timezone"),
+    ("vt", "variabletop"),
+];
+
+/// Legacy names of Unicode extension types, as `(key, type, legacy name)`.
+///
+/// Where several legacy names map to the same type, the first one is used for output.
+const TYPES: &[(&str, &str, &str)] = &[
+    ("ca", "ethioaa", "ethiopic-amete-alem"),
+    ("ca", "gregory", "gregorian"),
+    ("co", "dict", "dictionary"),
+    ("co", "gb2312", "gb2312han"),
+    ("co", "phonebk", "phonebook"),
+    ("co", "trad", "traditional"),
+    ("ka", "noignore", "non-ignorable"),
+    ("kb", "false", "no"),
+    ("kb", "true", "yes"),
+    ("kc", "false", "no"),
+    ("kc", "true", "yes"),
+    ("kf", "false", "no"),
+    ("kh", "false", "no"),
+    ("kh", "true", "yes"),
+    ("kk", "false", "no"),
+    ("kk", "true", "yes"),
+    ("kn", "false", "no"),
+    ("kn", "true", "yes"),
+    ("ks", "identic", "identical"),
+    ("ks", "level1", "primary"),
+    ("ks", "level2", "secondary"),
+    ("ks", "level3", "tertiary"),
+    ("ks", "level4", "quaternary"),
+    ("ks", "level4", "quarternary"),
+    ("ms", "uksystem", "imperial"),
+    ("nu", "traditio", "traditional"),
+];
+
+/// The result of [`Locale::try_from_legacy_id`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LegacyIdConversion {
+    /// The converted locale.
+    pub locale: Locale,
+    /// The parts of the legacy ID that could not be represented in [`Self::locale`].
+    pub dropped: Vec<LegacyIdPart>,
+}
+
+impl LegacyIdConversion {
+    /// Whether the legacy ID was converted without dropping any parts.
+    pub fn is_lossless(&self) -> bool {
+        self.dropped.is_empty()
+    }
+}
+
+/// A part of a legacy locale ID that has no BCP 47 representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LegacyIdPart {
+    /// A variant that is not a valid BCP 47 variant, such as `WIN`, or a variant whose
+    /// meaning is overridden by a keyword.
+    Variant(String),
+    /// A keyword whose name or value cannot be expressed in BCP 47, such as
+    /// `timezone=America/Los_Angeles`.
+    Keyword {
+        /// The keyword name, in lowercase.
+        key: String,
+        /// The keyword value.
+        value: String,
+    },
+}
+
+impl Locale {
+    /// Converts an ICU legacy locale ID, such as `sr_Latn_RS` or
+    /// `de_DE@collation=phonebook;currency=EUR`, into a [`Locale`].
+    ///
+    /// Subtags may be separated by `_` or `-`, the language may be empty or `root`, and the
+    /// region may be empty, as in `es__TRADITIONAL`. Keywords are mapped to Unicode extension
+    /// keywords, the `attribute` keyword to Unicode extension attributes, and single-letter
+    /// keywords such as `t` and `x` to other extensions.
+    ///
+    /// The deprecated ICU variants `POSIX`, `TRADITIONAL`, `PHONEBOOK`, `STROKE`, `PINYIN` and
+    /// `DIRECT` are mapped to the corresponding keywords, such as `-u-va-posix` or
+    /// `-u-co-phonebk`. If that keyword is also given explicitly with a different value, the
+    /// explicit value is used and the variant is dropped.
+    ///
+    /// Variants and keywords that cannot be represented in BCP 47 are dropped and reported in
+    /// [`LegacyIdConversion::dropped`]. An error is returned if the language, script or region
+    /// is invalid.
+    ///
+    /// ✨ *Enabled with the `alloc` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locale::legacy::LegacyIdPart;
+    /// use icu::locale::{locale, Locale};
+    ///
+    /// let conversion = Locale::try_from_legacy_id("en_US_POSIX").unwrap();
+    /// assert_eq!(conversion.locale, locale!("en-US-u-va-posix"));
+    ///
+    /// let conversion = Locale::try_from_legacy_id("ja_JP_TRADITIONAL").unwrap();
+    /// assert_eq!(conversion.locale, locale!("ja-JP-u-ca-japanese"));
+    ///
+    /// let conversion =
+    ///     Locale::try_from_legacy_id("en_US@timezone=America/Los_Angeles;numbers=latn")
+    ///         .unwrap();
+    /// assert_eq!(conversion.locale, locale!("en-US-u-nu-latn"));
+    /// assert_eq!(
+    ///     conversion.dropped,
+    ///     [LegacyIdPart::Keyword {
+    ///         key: "timezone".into(),
+    ///         value: "America/Los_Angeles".into()
+    ///     }]
+    /// );
+    /// ```
+    pub fn try_from_legacy_id(s: &str) -> Result<LegacyIdConversion, ParseError> {
+        Self::try_from_legacy_id_utf8(s.as_bytes())
+    }
+
+    /// See [`Self::try_from_legacy_id`]
+    pub fn try_from_legacy_id_utf8(code_units: &[u8]) -> Result<LegacyIdConversion, ParseError> {
+        let mut parts = code_units.splitn(2, |&b| b == b'@');
+        let base = parts.next().unwrap_or_default();
+        let keywords = parts.next().unwrap_or_default();
+
+        let mut locale = Locale::UNKNOWN;
+        let mut dropped = Vec::new();
+
+        let mut subtags = base.split(|&b| b == b'_' || b == b'-').peekable();
+        let language = subtags.next().unwrap_or_default();
+        if !language.is_empty() && !language.eq_ignore_ascii_case(b"root") {
+            locale.id.language = Language::try_from_utf8(language)?;
+        }
+        if let Some(script) =
+            subtags.next_if(|s| s.len() == 4 && s.iter().all(u8::is_ascii_alphabetic))
+        {
+            locale.id.script = Some(Script::try_from_utf8(script)?);
+        }
+        if let Some(region) = subtags.next_if(|s| matches!(s.len(), 0 | 2 | 3)) {
+            if !region.is_empty() {
+                locale.id.region = Some(Region::try_from_utf8(region)?);
+            }
+        }
+
+        let mut variants = Vec::new();
+        let mut variant_keywords = Vec::new();
+        for variant in subtags.filter(|s| !s.is_empty()) {
+            if let Some((key, value)) = variant_keyword(locale.id.language, variant) {
+                variant_keywords.push((key, value, variant));
+            } else if let Ok(variant) = Variant::try_from_utf8(variant) {
+                variants.push(variant);
+            } else {
+                dropped.push(LegacyIdPart::Variant(
+                    String::from_utf8_lossy(variant).into_owned(),
+                ));
+            }
+        }
+        variants.sort();
+        variants.dedup();
+        locale.id.variants = Variants::from_vec_unchecked(variants);
+
+        for keyword in keywords.split(|&b| b == b';') {
+            let keyword = keyword.trim_ascii();
+            if keyword.is_empty() {
+                continue;
+            }
+            let mut parts = keyword.splitn(2, |&b| b == b'=');
+            let key = parts.next().unwrap_or_default().trim_ascii();
+            let value = parts.next().unwrap_or_default().trim_ascii();
+            if !insert_keyword(&mut locale, key, value) {
+                dropped.push(LegacyIdPart::Keyword {
+                    key: String::from_utf8_lossy(key).to_ascii_lowercase(),
+                    value: String::from_utf8_lossy(value).into_owned(),
+                });
+            }
+        }
+
+        for (key, value, variant) in variant_keywords {
+            match locale.extensions.unicode.keywords.get(&key) {
+                None => {
+                    locale.extensions.unicode.keywords.set(key, value);
+                }
+                Some(existing) if *existing == value => {}
+                Some(_) => dropped.push(LegacyIdPart::Variant(
+                    String::from_utf8_lossy(variant).into_owned(),
+                )),
+            }
+        }
+
+        Ok(LegacyIdConversion { locale, dropped })
+    }
+
+    /// Converts this [`Locale`] into an ICU legacy locale ID, such as
+    /// `de_DE@collation=phonebook;currency=eur`.
+    ///
+    /// This is the inverse of [`Self::try_from_legacy_id`]: the language `und` is written as
+    /// the empty string, Unicode extension keywords use their legacy names, `-u-va-posix` is
+    /// written as the `POSIX` variant, and other extensions are written as single-letter
+    /// keywords. Keywords are sorted by name.
+    ///
+    /// Every [`Locale`] can be represented as a legacy ID, so this conversion is lossless.
+    ///
+    /// ✨ *Enabled with the `alloc` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locale::{locale, Locale};
+    ///
+    /// assert_eq!(locale!("sr-Latn-RS").to_legacy_id(), "sr_Latn_RS");
+    /// assert_eq!(locale!("en-US-u-va-posix").to_legacy_id(), "en_US_POSIX");
+    /// assert_eq!(locale!("de-1996").to_legacy_id(), "de__1996");
+    /// assert_eq!(
+    ///     Locale::try_from_str("th-u-nu-thai-ca-buddhist-x-foo")
+    ///         .unwrap()
+    ///         .to_legacy_id(),
+    ///     "th@calendar=buddhist;numbers=thai;x=foo"
+    /// );
+    /// assert_eq!(
+    ///     locale!("und-u-kn").to_legacy_id(),
+    ///     "@colnumeric=yes"
+    /// );
+    /// ```
+    pub fn to_legacy_id(&self) -> String {
+        let mut output = String::new();
+        if !self.id.language.is_unknown() {
+            output.push_str(self.id.language.as_str());
+        }
+        if let Some(script) = self.id.script {
+            output.push('_');
+            output.push_str(script.as_str());
+        }
+
+        let posix = self
+            .extensions
+            .unicode
+            .keywords
+            .get(&crate::extensions::unicode::key!("va"))
+            .and_then(Value::as_single_subtag)
+            .is_some_and(|v| v.as_str() == "posix");
+        if self.id.region.is_some() || !self.id.variants.is_empty() || posix {
+            output.push('_');
+            if let Some(region) = self.id.region {
+                output.push_str(region.as_str());
+            }
+        }
+        for variant in self.id.variants.iter() {
+            output.push('_');
+            output.push_str(&variant.as_str().to_ascii_uppercase());
+        }
+        if posix {
+            output.push_str("_POSIX");
+        }
+
+        let mut keywords: Vec<(&str, String)> = Vec::new();
+        if !self.extensions.unicode.attributes.is_empty() {
+            keywords.push((
+                "attribute",
+                self.extensions
+                    .unicode
+                    .attributes
+                    .write_to_string()
+                    .into_owned(),
+            ));
+        }
+        for (key, value) in self.extensions.unicode.keywords.iter() {
+            if posix && key.as_str() == "va" {
+                continue;
+            }
+            let legacy_key = KEYS
+                .iter()
+                .find(|(k, _)| *k == key.as_str())
+                .map_or(key.as_str(), |(_, legacy)| legacy);
+            let value = if value.is_empty() {
+                String::from("yes")
+            } else {
+                let value = value.write_to_string();
+                TYPES
+                    .iter()
+                    .find(|(k, t, _)| *k == key.as_str() && *t == value)
+                    .map_or_else(
+                        || value.into_owned(),
+                        |(_, _, legacy)| String::from(*legacy),
+                    )
+            };
+            keywords.push((legacy_key, value));
+        }
+        if !self.extensions.transform.is_empty() {
+            keywords.push(("t", strip_singleton(&self.extensions.transform)));
+        }
+        for other in self.extensions.other.iter() {
+            keywords.push((other.get_ext_str(), strip_singleton(other)));
+        }
+        if !self.extensions.private.is_empty() {
+            keywords.push(("x", strip_singleton(&self.extensions.private)));
+        }
+        keywords.sort();
+
+        for (i, (key, value)) in keywords.iter().enumerate() {
+            output.push(if i == 0 { '@' } else { ';' });
+            output.push_str(key);
+            output.push('=');
+            output.push_str(value);
+        }
+        output
+    }
+}
+
+/// Returns the keyword that replaces a deprecated ICU variant.
+fn variant_keyword(language: Language, variant: &[u8]) -> Option<(Key, Value)> {
+    use crate::extensions::unicode::{key, value};
+    let upper = |s: &str| variant.eq_ignore_ascii_case(s.as_bytes());
+    if upper("POSIX") {
+        Some((key!("va"), value!("posix")))
+    } else if upper("PHONEBOOK") {
+        Some((key!("co"), value!("phonebk")))
+    } else if upper("STROKE") {
+        Some((key!("co"), value!("stroke")))
+    } else if upper("PINYIN") {
+        Some((key!("co"), value!("pinyin")))
+    } else if upper("DIRECT") {
+        Some((key!("co"), value!("direct")))
+    } else if upper("TRADITIONAL") {
+        Some(match language.as_str() {
+            "ja" => (key!("ca"), value!("japanese")),
+            "th" => (key!("ca"), value!("buddhist")),
+            _ => (key!("co"), value!("trad")),
+        })
+    } else {
+        None
+    }
+}
+
+/// Adds a legacy keyword to the locale, returning `false` if it cannot be represented.
+fn insert_keyword(locale: &mut Locale, key: &[u8], value: &[u8]) -> bool {
+    if let [singleton] = key {
+        let mut extension = Vec::with_capacity(value.len() + 2);
+        extension.push(singleton.to_ascii_lowercase());
+        extension.push(b'-');
+        extension.extend_from_slice(value);
+        return match singleton.to_ascii_lowercase() {
+            b'x' => Private::try_from_utf8(&extension)
+                .map(|private| locale.extensions.private = private)
+                .is_ok(),
+            b't' => Transform::try_from_utf8(&extension)
+                .map(|transform| locale.extensions.transform = transform)
+                .is_ok(),
+            _ => match Other::try_from_utf8(&extension) {
+                Ok(other) => {
+                    match locale
+                        .extensions
+                        .other
+                        .binary_search_by_key(&other.get_ext(), Other::get_ext)
+                    {
+                        Ok(i) => {
+                            if let Some(existing) = locale.extensions.other.get_mut(i) {
+                                *existing = other;
+                            }
+                        }
+                        Err(i) => locale.extensions.other.insert(i, other),
+                    }
+                    true
+                }
+                Err(_) => false,
+            },
+        };
+    }
+
+    if key.eq_ignore_ascii_case(b"attribute") {
+        return Attributes::try_from_utf8(value)
+            .map(|attributes| locale.extensions.unicode.attributes = attributes)
+            .is_ok();
+    }
+
+    let Some(key) = KEYS
+        .iter()
+        .find(|(_, legacy)| key.eq_ignore_ascii_case(legacy.as_bytes()))
+        .map(|(k, _)| k.as_bytes())
+        .or(Some(key))
+        .and_then(|k| Key::try_from_utf8(k).ok())
+    else {
+        return false;
+    };
+    if value.is_empty() {
+        return false;
+    }
+    let value = TYPES
+        .iter()
+        .find(|(k, _, legacy)| *k == key.as_str() && value.eq_ignore_ascii_case(legacy.as_bytes()))
+        .map_or(value, |(_, t, _)| t.as_bytes());
+    Value::try_from_utf8(value)
+        .map(|value| locale.extensions.unicode.keywords.set(key, value))
+        .is_ok()
+}
+
+/// Writes an extension without its singleton.
+fn strip_singleton(extension: &impl Writeable) -> String {
+    let mut s = extension.write_to_string().into_owned();
+    s.drain(..2);
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_legacy_id() {
+        for (input, expected) in [
+            ("", "und".parse::<Locale>().unwrap()),
+            ("root", "und".parse::<Locale>().unwrap()),
+            ("en", "en".parse::<Locale>().unwrap()),
+            ("sr_Latn_RS", "sr-Latn-RS".parse::<Locale>().unwrap()),
+            ("_US", "und-US".parse::<Locale>().unwrap()),
+            ("zh-Hant", "zh-Hant".parse::<Locale>().unwrap()),
+            ("de_DE_1901", "de-DE-1901".parse::<Locale>().unwrap()),
+            (
+                "sl__ROZAJ_BISKE",
+                "sl-biske-rozaj".parse::<Locale>().unwrap(),
+            ),
+            ("es__TRADITIONAL", "es-u-co-trad".parse::<Locale>().unwrap()),
+            (
+                "th_TH_TRADITIONAL",
+                "th-TH-u-ca-buddhist".parse::<Locale>().unwrap(),
+            ),
+            (
+                "zh_TW_STROKE",
+                "zh-TW-u-co-stroke".parse::<Locale>().unwrap(),
+            ),
+            (
+                "de__PHONEBOOK",
+                "de-u-co-phonebk".parse::<Locale>().unwrap(),
+            ),
+            (
+                "en@calendar=gregorian",
+                "en-u-ca-gregory".parse::<Locale>().unwrap(),
+            ),
+            (
+                "ar@calendar=islamic-civil;numbers=arab",
+                "ar-u-ca-islamic-civil-nu-arab".parse::<Locale>().unwrap(),
+            ),
+            (
+                "en@ colStrength = primary ; colNumeric=yes",
+                "en-u-kn-ks-level1".parse::<Locale>().unwrap(),
+            ),
+            (
+                "en@colcasefirst=no;kv=space",
+                "en-u-kf-false-kv-space".parse::<Locale>().unwrap(),
+            ),
+            (
+                "en@attribute=foo-bar",
+                "en-u-bar-foo".parse::<Locale>().unwrap(),
+            ),
+            (
+                "en@t=es-m0-ungegn;x=private;a=other",
+                "en-a-other-t-es-m0-ungegn-x-private"
+                    .parse::<Locale>()
+                    .unwrap(),
+            ),
+            (
+                "ja_JP_TRADITIONAL@calendar=japanese",
+                "ja-JP-u-ca-japanese".parse::<Locale>().unwrap(),
+            ),
+        ] {
+            let conversion = Locale::try_from_legacy_id(input).unwrap();
+            assert_eq!(conversion.locale, expected, "{input}");
+            assert!(conversion.is_lossless(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_from_legacy_id_lossy() {
+        let conversion = Locale::try_from_legacy_id("en_US_WIN").unwrap();
+        assert_eq!(conversion.locale, "en-US".parse::<Locale>().unwrap());
+        assert_eq!(conversion.dropped, [LegacyIdPart::Variant("WIN".into())]);
+
+        let conversion =
+            Locale::try_from_legacy_id("ja_JP_TRADITIONAL@calendar=gregorian").unwrap();
+        assert_eq!(
+            conversion.locale,
+            "ja-JP-u-ca-gregory".parse::<Locale>().unwrap()
+        );
+        assert_eq!(
+            conversion.dropped,
+            [LegacyIdPart::Variant("TRADITIONAL".into())]
+        );
+
+        let conversion =
+            Locale::try_from_legacy_id("de@Collation=phonebook;foo=bar;numbers=;u=xyz").unwrap();
+        assert_eq!(
+            conversion.locale,
+            "de-u-co-phonebk".parse::<Locale>().unwrap()
+        );
+        assert_eq!(
+            conversion.dropped,
+            [
+                LegacyIdPart::Keyword {
+                    key: "foo".into(),
+                    value: "bar".into()
+                },
+                LegacyIdPart::Keyword {
+                    key: "numbers".into(),
+                    value: "".into()
+                },
+                LegacyIdPart::Keyword {
+                    key: "u".into(),
+                    value: "xyz".into()
+                },
+            ]
+        );
+
+        for input in ["english", "e", "en_USA", "de_12"] {
+            assert!(Locale::try_from_legacy_id(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_to_legacy_id() {
+        for (input, expected) in [
+            ("und".parse::<Locale>().unwrap(), ""),
+            ("und-US".parse::<Locale>().unwrap(), "_US"),
+            ("zh-Hant-TW".parse::<Locale>().unwrap(), "zh_Hant_TW"),
+            (
+                "sl-biske-rozaj".parse::<Locale>().unwrap(),
+                "sl__BISKE_ROZAJ",
+            ),
+            ("en-u-va-posix".parse::<Locale>().unwrap(), "en__POSIX"),
+            ("en-u-va-xposix".parse::<Locale>().unwrap(), "en@va=xposix"),
+            (
+                "de-DE-u-co-phonebk-cu-eur".parse::<Locale>().unwrap(),
+                "de_DE@collation=phonebook;currency=eur",
+            ),
+            (
+                "en-u-attr-ks-level4-kf-false-kb-fw-mon"
+                    .parse::<Locale>()
+                    .unwrap(),
+                "en@attribute=attr;colbackwards=yes;colcasefirst=no;colstrength=quaternary;fw=mon",
+            ),
+            (
+                "en-a-other-t-es-m0-ungegn-x-private"
+                    .parse::<Locale>()
+                    .unwrap(),
+                "en@a=other;t=es-m0-ungegn;x=private",
+            ),
+        ] {
+            assert_eq!(input.to_legacy_id(), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for id in [
+            "en_US_POSIX",
+            "sr_Latn_RS",
+            "de_DE@collation=phonebook;currency=eur",
+            "ar@calendar=islamic-umalqura;numbers=traditional",
+            "und@t=und-latn-m0-ungegn",
+            "en@attribute=abc;colnumeric=yes;x=foo-bar",
+        ] {
+            let conversion = Locale::try_from_legacy_id(id).unwrap();
+            assert!(conversion.is_lossless(), "{id}");
+            assert_eq!(
+                conversion.locale.to_legacy_id(),
+                id.trim_start_matches("und")
+            );
+        }
+    }
+}
@@ -84,9 +84,13 @@ pub use locale::Locale;
 pub use parser::ParseError;
 
 pub mod extensions;
+#[cfg(feature = "alloc")]
+pub mod legacy;
 #[macro_use]
 pub mod subtags;
 pub mod preferences;
+#[cfg(feature = "alloc")]
+pub mod priority_list;
 pub mod zerovec;
 
 #[cfg(feature = "serde")]
@@ -0,0 +1,567 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing of language priority lists, such as HTTP `Accept-Language` headers
+//! and the `LANGUAGE` environment variable.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::locale::priority_list::{LanguagePriorityList, LanguageRange, Weight};
+//! use icu::locale::{locale, Locale};
+//!
+//! let list = LanguagePriorityList::<Locale>::try_from_accept_language(
+//!     "fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5",
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(
+//!     list.languages().collect::<Vec<_>>(),
+//!     [
+//!         &locale!("fr-CH"),
+//!         &locale!("fr"),
+//!         &locale!("en"),
+//!         &locale!("de")
+//!     ]
+//! );
+//!
+//! let last = list.iter().last().unwrap();
+//! assert_eq!(last.range, LanguageRange::Wildcard);
+//! assert_eq!(last.weight, Weight::try_from_thousandths(500).unwrap());
+//! ```
+
+use crate::ParseError;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+/// A weight of a language range, as an HTTP quality value between 0 and 1 with
+/// at most three decimal places.
+///
+/// A weight of 0 means that the language is not acceptable.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Weight(u16);
+
+impl Weight {
+    /// The weight 1, which is the default.
+    pub const MAX: Self = Self(1000);
+
+    /// The weight 0, which marks a language as not acceptable.
+    pub const ZERO: Self = Self(0);
+
+    /// Creates a [`Weight`] from a number of thousandths, returning `None` if it is
+    /// greater than 1000.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locale::priority_list::Weight;
+    ///
+    /// assert_eq!(Weight::try_from_thousandths(1000), Some(Weight::MAX));
+    /// assert_eq!(Weight::try_from_thousandths(1001), None);
+    /// ```
+    pub const fn try_from_thousandths(thousandths: u16) -> Option<Self> {
+        if thousandths <= 1000 {
+            Some(Self(thousandths))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the weight as a number of thousandths, between 0 and 1000.
+    pub const fn to_thousandths(self) -> u16 {
+        self.0
+    }
+
+    /// Parses an RFC 9110 `qvalue`, such as `0.8`.
+    fn try_from_utf8(code_units: &[u8]) -> Option<Self> {
+        let (integer, fraction) = match code_units {
+            [i, b'.', fraction @ ..] if fraction.len() <= 3 => (*i, fraction),
+            [i] => (*i, &[][..]),
+            _ => return None,
+        };
+        let mut thousandths = match integer {
+            b'0' => 0,
+            b'1' => 1000,
+            _ => return None,
+        };
+        let mut scale = 100;
+        for &digit in fraction {
+            if !digit.is_ascii_digit() {
+                return None;
+            }
+            thousandths += u16::from(digit - b'0') * scale;
+            scale /= 10;
+        }
+        Self::try_from_thousandths(thousandths)
+    }
+}
+
+impl Default for Weight {
+    fn default() -> Self {
+        Self::MAX
+    }
+}
+
+/// A language range in a [`LanguagePriorityList`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LanguageRange<T> {
+    /// The wildcard `*`, which matches any language not otherwise listed.
+    Wildcard,
+    /// A language identifier or locale.
+    Language(T),
+}
+
+/// An entry of a [`LanguagePriorityList`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct WeightedLanguageRange<T> {
+    /// The language range.
+    pub range: LanguageRange<T>,
+    /// The weight of the language range.
+    pub weight: Weight,
+}
+
+/// The error type for parsing a [`LanguagePriorityList`].
+#[derive(displaydoc::Display, Debug, PartialEq, Copy, Clone)]
+#[non_exhaustive]
+pub enum PriorityListParseError {
+    /// A language range is not a valid language identifier or locale.
+    #[displaydoc("Invalid language range: {0}")]
+    Language(ParseError),
+    /// A weight is not a valid quality value between 0 and 1.
+    #[displaydoc("Invalid weight")]
+    Weight,
+    /// A list element has an unexpected form, such as a parameter other than `q`.
+    #[displaydoc("Invalid list syntax")]
+    Syntax,
+}
+
+impl core::error::Error for PriorityListParseError {}
+
+impl From<ParseError> for PriorityListParseError {
+    fn from(e: ParseError) -> Self {
+        Self::Language(e)
+    }
+}
+
+/// A list of language ranges ordered by preference, such as an HTTP `Accept-Language` header.
+///
+/// The entries are sorted by descending [`Weight`], keeping the original order of entries with
+/// the same weight. If a language range appears more than once, only its first occurrence is kept.
+///
+/// `T` is either [`LanguageIdentifier`](crate::LanguageIdentifier) or [`Locale`](crate::Locale).
+///
+/// See the [module-level documentation](self) for an example.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LanguagePriorityList<T> {
+    entries: Vec<WeightedLanguageRange<T>>,
+}
+
+impl<T> Default for LanguagePriorityList<T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<T: FromStr<Err = ParseError> + PartialEq> LanguagePriorityList<T> {
+    /// Parses an RFC 9110 `Accept-Language` header value, such as `"fr-CH, fr;q=0.9, *;q=0.5"`.
+    ///
+    /// Whitespace around elements and weights, and empty elements are allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locale::priority_list::{
+    ///     LanguagePriorityList, PriorityListParseError,
+    /// };
+    /// use icu::locale::{langid, LanguageIdentifier};
+    ///
+    /// let list =
+    ///     LanguagePriorityList::<LanguageIdentifier>::try_from_accept_language(
+    ///         " en;q=0.5 ,, de-AT ,de;Q=0.9 , en;q=1",
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     list.languages().collect::<Vec<_>>(),
+    ///     [&langid!("de-AT"), &langid!("de"), &langid!("en")]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     LanguagePriorityList::<LanguageIdentifier>::try_from_accept_language(
+    ///         "en;q=2"
+    ///     ),
+    ///     Err(PriorityListParseError::Weight)
+    /// );
+    /// ```
+    pub fn try_from_accept_language(s: &str) -> Result<Self, PriorityListParseError> {
+        Self::try_from_accept_language_utf8(s.as_bytes())
+    }
+
+    /// See [`Self::try_from_accept_language`]
+    pub fn try_from_accept_language_utf8(
+        code_units: &[u8],
+    ) -> Result<Self, PriorityListParseError> {
+        let mut list = Self::default();
+        for element in code_units.split(|&b| b == b',') {
+            if let Some((range, weight)) = parse_accept_language_element(element)? {
+                list.push(parse_range(range)?, weight);
+            }
+        }
+        list.sort();
+        Ok(list)
+    }
+
+    /// Parses an `Accept-Language` header value like [`Self::try_from_accept_language`],
+    /// but skips or repairs malformed entries instead of failing.
+    ///
+    /// Entries with invalid weights or unexpected parameters are skipped. Language ranges
+    /// are repaired as described in [`Self::from_colon_separated_lossy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locale::priority_list::LanguagePriorityList;
+    /// use icu::locale::{locale, Locale};
+    ///
+    /// let list = LanguagePriorityList::<Locale>::from_accept_language_lossy(
+    ///     "en_US, fr;q=abc, de-DE-$$$;q=0.5, !!",
+    /// );
+    /// assert_eq!(
+    ///     list.languages().collect::<Vec<_>>(),
+    ///     [&locale!("en-US"), &locale!("de-DE")]
+    /// );
+    /// ```
+    pub fn from_accept_language_lossy(s: &str) -> Self {
+        Self::from_accept_language_lossy_utf8(s.as_bytes())
+    }
+
+    /// See [`Self::from_accept_language_lossy`]
+    pub fn from_accept_language_lossy_utf8(code_units: &[u8]) -> Self {
+        let mut list = Self::default();
+        for element in code_units.split(|&b| b == b',') {
+            if let Ok(Some((range, weight))) = parse_accept_language_element(element) {
+                if let Some(range) = parse_range_lossy(range) {
+                    list.push(range, weight);
+                }
+            }
+        }
+        list.sort();
+        list
+    }
+
+    /// Parses a colon-separated list of language identifiers, such as the `LANGUAGE`
+    /// environment variable `"de:fr:en"`.
+    ///
+    /// All entries have the weight 1 and keep their order. Empty elements are allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locale::priority_list::LanguagePriorityList;
+    /// use icu::locale::{langid, LanguageIdentifier};
+    ///
+    /// let list =
+    ///     LanguagePriorityList::<LanguageIdentifier>::try_from_colon_separated(
+    ///         "de-CH:de::en",
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     list.languages().collect::<Vec<_>>(),
+    ///     [&langid!("de-CH"), &langid!("de"), &langid!("en")]
+    /// );
+    /// ```
+    pub fn try_from_colon_separated(s: &str) -> Result<Self, PriorityListParseError> {
+        Self::try_from_colon_separated_utf8(s.as_bytes())
+    }
+
+    /// See [`Self::try_from_colon_separated`]
+    pub fn try_from_colon_separated_utf8(
+        code_units: &[u8],
+    ) -> Result<Self, PriorityListParseError> {
+        let mut list = Self::default();
+        for element in code_units.split(|&b| b == b':') {
+            if !element.is_empty() {
+                list.push(parse_range(element)?, Weight::MAX);
+            }
+        }
+        Ok(list)
+    }
+
+    /// Parses a colon-separated list like [`Self::try_from_colon_separated`], but skips
+    /// or repairs malformed entries instead of failing.
+    ///
+    /// Language ranges are repaired by
+    /// * trimming whitespace,
+    /// * replacing `_` with `-`,
+    /// * removing a POSIX codeset or modifier, such as `.UTF-8` or `@euro`,
+    /// * removing trailing subtags until the rest is valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locale::priority_list::LanguagePriorityList;
+    /// use icu::locale::{langid, LanguageIdentifier};
+    ///
+    /// let list =
+    ///     LanguagePriorityList::<LanguageIdentifier>::from_colon_separated_lossy(
+    ///         "de_DE.UTF-8:fr_FR@euro:123:en",
+    ///     );
+    /// assert_eq!(
+    ///     list.languages().collect::<Vec<_>>(),
+    ///     [&langid!("de-DE"), &langid!("fr-FR"), &langid!("en")]
+    /// );
+    /// ```
+    pub fn from_colon_separated_lossy(s: &str) -> Self {
+        Self::from_colon_separated_lossy_utf8(s.as_bytes())
+    }
+
+    /// See [`Self::from_colon_separated_lossy`]
+    pub fn from_colon_separated_lossy_utf8(code_units: &[u8]) -> Self {
+        let mut list = Self::default();
+        for element in code_units.split(|&b| b == b':') {
+            if let Some(range) = parse_range_lossy(element) {
+                list.push(range, Weight::MAX);
+            }
+        }
+        list
+    }
+
+    fn push(&mut self, range: LanguageRange<T>, weight: Weight) {
+        if !self.entries.iter().any(|e| e.range == range) {
+            self.entries.push(WeightedLanguageRange { range, weight });
+        }
+    }
+}
+
+impl<T> LanguagePriorityList<T> {
+    /// Returns the entries of the list, including the wildcard and entries with weight 0,
+    /// in order of preference.
+    pub fn iter(&self) -> impl Iterator<Item = &WeightedLanguageRange<T>> {
+        self.entries.iter()
+    }
+
+    /// Returns the acceptable languages of the list in order of preference, skipping
+    /// the wildcard and entries with weight 0.
+    pub fn languages(&self) -> impl Iterator<Item = &T> {
+        self.entries.iter().filter_map(|e| match &e.range {
+            LanguageRange::Language(language) if e.weight != Weight::ZERO => Some(language),
+            _ => None,
+        })
+    }
+
+    /// Returns the number of entries in the list.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the list has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn sort(&mut self) {
+        // Stable, so entries with the same weight keep their order
+        self.entries.sort_by(|a, b| b.weight.cmp(&a.weight));
+    }
+}
+
+impl<T> IntoIterator for LanguagePriorityList<T> {
+    type Item = WeightedLanguageRange<T>;
+    type IntoIter = alloc::vec::IntoIter<WeightedLanguageRange<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+fn is_whitespace(b: u8) -> bool {
+    matches!(b, b' ' | b'\t')
+}
+
+fn trim(mut code_units: &[u8]) -> &[u8] {
+    while let [first, rest @ ..] = code_units {
+        if !is_whitespace(*first) {
+            break;
+        }
+        code_units = rest;
+    }
+    while let [rest @ .., last] = code_units {
+        if !is_whitespace(*last) {
+            break;
+        }
+        code_units = rest;
+    }
+    code_units
+}
+
+/// Splits an `Accept-Language` element into its language range and weight,
+/// returning `None` for an empty element.
+fn parse_accept_language_element(
+    element: &[u8],
+) -> Result<Option<(&[u8], Weight)>, PriorityListParseError> {
+    let mut parts = element.split(|&b| b == b';');
+    let range = parts.next().map(trim).unwrap_or_default();
+    let weight = match parts.next().map(trim) {
+        None => Weight::MAX,
+        Some([b'q' | b'Q', b'=', value @ ..]) => {
+            Weight::try_from_utf8(value).ok_or(PriorityListParseError::Weight)?
+        }
+        Some(_) => return Err(PriorityListParseError::Syntax),
+    };
+    if parts.next().is_some() {
+        return Err(PriorityListParseError::Syntax);
+    }
+    if range.is_empty() {
+        return if weight == Weight::MAX {
+            Ok(None)
+        } else {
+            Err(PriorityListParseError::Syntax)
+        };
+    }
+    Ok(Some((range, weight)))
+}
+
+fn parse_range<T: FromStr<Err = ParseError>>(
+    range: &[u8],
+) -> Result<LanguageRange<T>, PriorityListParseError> {
+    if range == b"*" {
+        return Ok(LanguageRange::Wildcard);
+    }
+    let range = core::str::from_utf8(range).map_err(|_| ParseError::InvalidLanguage)?;
+    Ok(LanguageRange::Language(range.parse()?))
+}
+
+fn parse_range_lossy<T: FromStr<Err = ParseError>>(range: &[u8]) -> Option<LanguageRange<T>> {
+    let range = trim(range);
+    if let Ok(range) = parse_range(range) {
+        return Some(range);
+    }
+    let range = range
+        .split(|&b| b == b'.' || b == b'@')
+        .next()
+        .unwrap_or_default();
+    let mut range: String = String::from_utf8_lossy(range).replace('_', "-");
+    loop {
+        if let Ok(range) = range.parse() {
+            return Some(LanguageRange::Language(range));
+        }
+        range.truncate(range.rfind('-')?);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{langid, LanguageIdentifier};
+
+    fn weights(list: &LanguagePriorityList<LanguageIdentifier>) -> Vec<u16> {
+        list.iter().map(|e| e.weight.to_thousandths()).collect()
+    }
+
+    #[test]
+    fn test_weights() {
+        for (input, expected) in [
+            ("en", Some(1000)),
+            ("en;q=1", Some(1000)),
+            ("en;q=1.000", Some(1000)),
+            ("en;q=0", Some(0)),
+            ("en;q=0.", Some(0)),
+            ("en;q=0.5", Some(500)),
+            ("en;q=0.123", Some(123)),
+            ("en ; q=0.07", Some(70)),
+            ("en;Q=0.1", Some(100)),
+            ("en;q=1.001", None),
+            ("en;q=0.1234", None),
+            ("en;q=2", None),
+            ("en;q=", None),
+            ("en;q=.5", None),
+            ("en;q = 0.5", None),
+        ] {
+            let list = LanguagePriorityList::try_from_accept_language(input);
+            assert_eq!(
+                list.as_ref().ok().map(weights),
+                expected.map(|w| vec![w]),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_syntax() {
+        for input in [
+            "en;level=1",
+            "en;q=0.5;q=0.5",
+            ";q=0.5",
+            "en fr",
+            "e\u{301}",
+        ] {
+            assert!(
+                LanguagePriorityList::<LanguageIdentifier>::try_from_accept_language(input)
+                    .is_err(),
+                "{input}"
+            );
+        }
+        for input in ["", " ", ",,", " , "] {
+            assert_eq!(
+                LanguagePriorityList::<LanguageIdentifier>::try_from_accept_language(input),
+                Ok(Default::default()),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_order_and_duplicates() {
+        let list = LanguagePriorityList::try_from_accept_language(
+            "*;q=0.1, en;q=0.5, fr;q=0, de, en-us;q=0.8, en-US;q=0.9, de",
+        )
+        .unwrap();
+        assert_eq!(
+            list.iter().map(|e| e.range.clone()).collect::<Vec<_>>(),
+            [
+                LanguageRange::Language(langid!("de")),
+                LanguageRange::Language(langid!("en-US")),
+                LanguageRange::Language(langid!("en")),
+                LanguageRange::Wildcard,
+                LanguageRange::Language(langid!("fr")),
+            ]
+        );
+        assert_eq!(weights(&list), [1000, 800, 500, 100, 0]);
+        assert_eq!(
+            list.languages().collect::<Vec<_>>(),
+            [&langid!("de"), &langid!("en-US"), &langid!("en")]
+        );
+    }
+
+    #[test]
+    fn test_lossy() {
+        let list = LanguagePriorityList::<LanguageIdentifier>::from_accept_language_lossy(
+            "en;level=1, fr_ca;q=0.9, *;q=x, sr-Latn-RS-@@;q=0.3, 12;q=0.5, de",
+        );
+        assert_eq!(
+            list.iter().map(|e| e.range.clone()).collect::<Vec<_>>(),
+            [
+                LanguageRange::Language(langid!("de")),
+                LanguageRange::Language(langid!("fr-CA")),
+                LanguageRange::Language(langid!("sr-Latn-RS")),
+            ]
+        );
+
+        let list = LanguagePriorityList::<LanguageIdentifier>::from_colon_separated_lossy(
+            " pt_BR.UTF-8 :C.UTF-8:pt:*",
+        );
+        assert_eq!(
+            list.iter().map(|e| e.range.clone()).collect::<Vec<_>>(),
+            [
+                LanguageRange::Language(langid!("pt-BR")),
+                LanguageRange::Language(langid!("pt")),
+                LanguageRange::Wildcard,
+            ]
+        );
+    }
+}
@@ -75,6 +75,8 @@ pub use multi_named::MissingNamedPlaceholderError;
 pub use multi_named::MultiNamedPlaceholder;
 pub use multi_named::MultiNamedPlaceholderKey;
 #[cfg(feature = "alloc")]
+pub use multi_named::MultiNamedPlaceholderKeyCow;
+#[cfg(feature = "alloc")]
 pub use parser::ParsedPatternItem;
 #[cfg(feature = "alloc")]
 pub use parser::Parser;
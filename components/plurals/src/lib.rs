@@ -265,7 +265,7 @@ define_preferences!(
 /// [`ICU4X`]: ../icu/index.html
 /// [`Plural Type`]: PluralRuleType
 /// [`Plural Category`]: PluralCategory
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PluralRules(DataPayload<ErasedMarker<PluralRulesData<'static>>>);
 
 impl AsRef<PluralRules> for PluralRules {
@@ -562,7 +562,6 @@ where
     }
 
     /// Recovers the [`PluralElements`] corresponding to this packed structure.
-    #[cfg(feature = "datagen")]
     pub fn decode(&self) -> PluralElements<(FourBitMetadata, &V)> {
         PluralElements(PluralElementsInner::from_packed(self))
     }
@@ -881,7 +880,6 @@ where
     }
 }
 
-#[cfg(feature = "datagen")]
 impl<'a, V> PluralElementsInner<(FourBitMetadata, &'a V)>
 where
     V: VarULE + ?Sized,
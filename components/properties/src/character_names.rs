@@ -0,0 +1,439 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::names::{PropertyCharacterNames, PropertyCharacterNamesV1};
+use core::cmp::Ordering;
+use core::fmt::{self, Write};
+use icu_provider::prelude::*;
+use potential_utf::PotentialCodePoint;
+use zerovec::vecs::Index32;
+use zerovec::{VarZeroSlice, ZeroSlice};
+
+/// A struct capable of looking up the names of characters, and characters by their names.
+/// Access its data by calling [`Self::as_borrowed()`] and using the methods on
+/// [`CharacterNamesBorrowed`].
+///
+/// This covers the `Name` property, including the names of Hangul syllables and CJK
+/// ideographs that are derived from their code points, as well as the `Name_Alias` property.
+/// Characters are looked up using the loose matching rule [UAX44-LM2].
+///
+/// Character names are not included in compiled data because of their size. Use
+/// `icu4x-datagen` to generate the [`PropertyCharacterNamesV1`] marker.
+///
+/// # Example
+///
+/// ```no_run
+/// use icu::properties::provider::PropertyCharacterNamesV1;
+/// use icu::properties::CharacterNames;
+/// use icu_provider::prelude::*;
+///
+/// // A provider with data generated by `icu4x-datagen`
+/// let provider: &dyn DataProvider<PropertyCharacterNamesV1> = todo!();
+///
+/// let names = CharacterNames::try_new_unstable(provider).unwrap();
+/// let names = names.as_borrowed();
+///
+/// assert_eq!(
+///     names.name('á').unwrap().to_string(),
+///     "LATIN SMALL LETTER A WITH ACUTE"
+/// );
+/// assert_eq!(
+///     names.name('한').unwrap().to_string(),
+///     "HANGUL SYLLABLE HAN"
+/// );
+///
+/// assert_eq!(names.get("Latin small letter A with acute"), Some('á'));
+/// assert_eq!(names.get("cjk-unified-ideograph-4e00"), Some('一'));
+/// ```
+///
+/// [UAX44-LM2]: https://www.unicode.org/reports/tr44/#UAX44-LM2
+#[derive(Debug)]
+pub struct CharacterNames {
+    data: DataPayload<PropertyCharacterNamesV1>,
+}
+
+/// A borrowed wrapper around character name data, returned by
+/// [`CharacterNames::as_borrowed()`]. More efficient to query.
+#[derive(Debug, Clone, Copy)]
+pub struct CharacterNamesBorrowed<'a> {
+    data: &'a PropertyCharacterNames<'a>,
+}
+
+impl CharacterNames {
+    /// Creates a new [`CharacterNames`] from character name data provided by a
+    /// [`BufferProvider`].
+    ///
+    /// ✨ *Enabled with the `serde` feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+    ) -> Result<Self, DataError> {
+        use icu_provider::buf::AsDeserializingBufferProvider;
+        Self::try_new_unstable(&provider.as_deserializing())
+    }
+
+    /// Creates a new [`CharacterNames`] from character name data provided by a
+    /// [`DataProvider`].
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// <div class="stab unstable">⚠️ The bounds on <tt>provider</tt> may change over time, including in SemVer minor releases.</div>
+    pub fn try_new_unstable(
+        provider: &(impl DataProvider<PropertyCharacterNamesV1> + ?Sized),
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            data: provider.load(Default::default())?.payload,
+        })
+    }
+
+    /// Construct a borrowed version of this type that can be queried.
+    ///
+    /// This avoids a potential small underlying cost per API call by consolidating it
+    /// up front.
+    #[inline]
+    pub fn as_borrowed(&self) -> CharacterNamesBorrowed<'_> {
+        CharacterNamesBorrowed {
+            data: self.data.get(),
+        }
+    }
+}
+
+impl<'a> CharacterNamesBorrowed<'a> {
+    /// Returns the `Name` property of a character.
+    ///
+    /// Control characters, private use characters, surrogates, noncharacters, and unassigned
+    /// code points have no name. Control characters have name aliases instead, see
+    /// [`Self::aliases()`].
+    pub fn name(self, c: char) -> Option<CharacterName<'a>> {
+        let cp = u32::from(c);
+        if (HANGUL_SYLLABLE_BASE..HANGUL_SYLLABLE_BASE + HANGUL_SYLLABLE_COUNT).contains(&cp) {
+            return Some(CharacterName(CharacterNameInner::Hangul(
+                cp - HANGUL_SYLLABLE_BASE,
+            )));
+        }
+        if let Ok(index) = self
+            .data
+            .code_points
+            .binary_search(&PotentialCodePoint::from_char(c))
+        {
+            return Some(CharacterName(CharacterNameInner::Words(
+                self.data.names.get(index)?,
+                &self.data.words,
+            )));
+        }
+        let (_, _, prefix) = self
+            .data
+            .hex_name_ranges
+            .iter()
+            .find(|&(start, end, _)| u32::from(start) <= cp && cp <= u32::from(end))?;
+        Some(CharacterName(CharacterNameInner::Hex(
+            self.data.hex_name_prefixes.get(prefix as usize)?,
+            cp,
+        )))
+    }
+
+    /// Returns the `Name_Alias` property of a character, as pairs of alias and alias type.
+    ///
+    /// Most characters have no aliases, some have several.
+    pub fn aliases(self, c: char) -> impl Iterator<Item = (&'a str, NameAliasType)> + 'a {
+        let data = self.data;
+        let cp = PotentialCodePoint::from_char(c);
+        let start = data
+            .alias_code_points
+            .binary_search_by(|probe| {
+                if probe < cp {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }
+            })
+            .unwrap_or_else(|index| index);
+        (start..data.alias_code_points.len())
+            .take_while(move |&index| data.alias_code_points.get(index) == Some(cp))
+            .filter_map(move |index| {
+                Some((
+                    data.aliases.get(index)?,
+                    NameAliasType::from_u8(data.alias_types.get(index)?)?,
+                ))
+            })
+    }
+
+    /// Returns the character with the given name or name alias.
+    ///
+    /// Names are matched using the loose matching rule [UAX44-LM2], which ignores case,
+    /// whitespace, underscores, and hyphens between letters or digits.
+    ///
+    /// [UAX44-LM2]: https://www.unicode.org/reports/tr44/#UAX44-LM2
+    pub fn get(self, name: &str) -> Option<char> {
+        let mut buffer = [0; MAX_KEY_LEN];
+        let mut len = 0;
+        for b in Loose::new(name.bytes()) {
+            if !b.is_ascii() {
+                return None;
+            }
+            *buffer.get_mut(len)? = b;
+            len += 1;
+        }
+        let key = buffer.get(..len)?;
+
+        // The only exception in UAX44-LM2: the hyphen in U+1180 HANGUL JUNGSEONG O-E is
+        // significant, as it otherwise matches U+116C HANGUL JUNGSEONG OE.
+        if key == b"HANGULJUNGSEONGOE" {
+            let mut last = [0; 3];
+            for b in name.bytes().filter(|&b| !is_ignored(b)) {
+                last = [last[1], last[2], b.to_ascii_uppercase()];
+            }
+            return Some(if &last == b"O-E" {
+                '\u{1180}'
+            } else {
+                '\u{116C}'
+            });
+        }
+
+        if let Some(c) = key
+            .strip_prefix(b"HANGULSYLLABLE")
+            .and_then(hangul_syllable_from_name)
+        {
+            return Some(c);
+        }
+
+        for (index, prefix) in self.data.hex_name_prefixes.iter().enumerate() {
+            let Some(cp) = strip_loose_prefix(key, prefix.trim_end_matches('-'))
+                .and_then(parse_hex_code_point)
+            else {
+                continue;
+            };
+            if self
+                .data
+                .hex_name_ranges
+                .iter()
+                .any(|(start, end, prefix)| {
+                    prefix as usize == index && u32::from(start) <= cp && cp <= u32::from(end)
+                })
+            {
+                return char::from_u32(cp);
+            }
+        }
+
+        let names_len = self.data.names.len();
+        let index = self
+            .data
+            .loose_order
+            .binary_search_by(|index| {
+                let index = index as usize;
+                let (name, alias) = if index < names_len {
+                    (self.data.names.get(index), None)
+                } else {
+                    (None, self.data.aliases.get(index - names_len))
+                };
+                Loose::new(
+                    name_bytes(name.unwrap_or(ZeroSlice::new_empty()), &self.data.words)
+                        .chain(alias.unwrap_or_default().bytes()),
+                )
+                .cmp(key.iter().copied())
+            })
+            .ok()?;
+        let index = self.data.loose_order.get(index)? as usize;
+        if index < names_len {
+            self.data.code_points.get(index)
+        } else {
+            self.data.alias_code_points.get(index - names_len)
+        }?
+        .try_to_char()
+        .ok()
+    }
+}
+
+/// The name of a character, returned by [`CharacterNamesBorrowed::name()`].
+///
+/// Use its [`Display`](fmt::Display) implementation to write the name.
+#[derive(Debug, Clone, Copy)]
+pub struct CharacterName<'a>(CharacterNameInner<'a>);
+
+#[derive(Debug, Clone, Copy)]
+enum CharacterNameInner<'a> {
+    Words(&'a ZeroSlice<u16>, &'a VarZeroSlice<str, Index32>),
+    Hex(&'a str, u32),
+    Hangul(u32),
+}
+
+impl fmt::Display for CharacterName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            CharacterNameInner::Words(name, words) => {
+                for (i, word) in name.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(' ')?;
+                    }
+                    f.write_str(words.get(word as usize).unwrap_or_default())?;
+                }
+                Ok(())
+            }
+            CharacterNameInner::Hex(prefix, cp) => write!(f, "{prefix}{cp:04X}"),
+            CharacterNameInner::Hangul(index) => {
+                let (l, v, t) = (
+                    index / (JAMO_V.len() * JAMO_T.len()) as u32,
+                    index / JAMO_T.len() as u32 % JAMO_V.len() as u32,
+                    index % JAMO_T.len() as u32,
+                );
+                f.write_str("HANGUL SYLLABLE ")?;
+                for part in [
+                    JAMO_L.get(l as usize),
+                    JAMO_V.get(v as usize),
+                    JAMO_T.get(t as usize),
+                ] {
+                    f.write_str(part.copied().unwrap_or_default())?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The type of a character name alias, returned by [`CharacterNamesBorrowed::aliases()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NameAliasType {
+    /// A correction of a serious problem in a character name.
+    Correction,
+    /// An ISO 6429 name of a control function, or another common name of a control code.
+    Control,
+    /// A widely used alternate name of a format character.
+    Alternate,
+    /// A documented label of a C1 control code that was never approved in any standard.
+    Figment,
+    /// A common abbreviation of the name of a control code, format character, space, or
+    /// variation selector.
+    Abbreviation,
+}
+
+impl NameAliasType {
+    fn from_u8(value: u8) -> Option<Self> {
+        Some(match value {
+            0 => Self::Correction,
+            1 => Self::Control,
+            2 => Self::Alternate,
+            3 => Self::Figment,
+            4 => Self::Abbreviation,
+            _ => return None,
+        })
+    }
+}
+
+/// An upper bound on the length of loose matching keys, longer than any character name.
+const MAX_KEY_LEN: usize = 128;
+
+const HANGUL_SYLLABLE_BASE: u32 = 0xAC00;
+const HANGUL_SYLLABLE_COUNT: u32 = (JAMO_L.len() * JAMO_V.len() * JAMO_T.len()) as u32;
+
+// Jamo short names, from Jamo.txt
+const JAMO_L: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P",
+    "H",
+];
+const JAMO_V: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE",
+    "WI", "YU", "EU", "YI", "I",
+];
+const JAMO_T: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M",
+    "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+
+fn hangul_syllable_from_name(jamo: &[u8]) -> Option<char> {
+    for (l, l_name) in JAMO_L.iter().enumerate() {
+        let Some(jamo) = jamo.strip_prefix(l_name.as_bytes()) else {
+            continue;
+        };
+        for (v, v_name) in JAMO_V.iter().enumerate() {
+            let Some(jamo) = jamo.strip_prefix(v_name.as_bytes()) else {
+                continue;
+            };
+            if let Some(t) = JAMO_T.iter().position(|t_name| t_name.as_bytes() == jamo) {
+                return char::from_u32(
+                    HANGUL_SYLLABLE_BASE + ((l * JAMO_V.len() + v) * JAMO_T.len() + t) as u32,
+                );
+            }
+        }
+    }
+    None
+}
+
+/// Strips the loose matching key of `prefix` from `key`.
+fn strip_loose_prefix<'k>(key: &'k [u8], prefix: &str) -> Option<&'k [u8]> {
+    let mut key = key;
+    for b in Loose::new(prefix.bytes()) {
+        key = key.strip_prefix(&[b])?;
+    }
+    Some(key)
+}
+
+/// Parses a code point written as in names like `CJK UNIFIED IDEOGRAPH-4E00`, that is, as
+/// uppercase hexadecimal with at least four digits and no further leading zeros.
+fn parse_hex_code_point(hex: &[u8]) -> Option<u32> {
+    if !(4..=6).contains(&hex.len())
+        || (hex.len() > 4 && hex.first() == Some(&b'0'))
+        || !hex
+            .iter()
+            .all(|b| b.is_ascii_digit() || (b'A'..=b'F').contains(b))
+    {
+        return None;
+    }
+    u32::from_str_radix(core::str::from_utf8(hex).ok()?, 16).ok()
+}
+
+fn name_bytes<'a>(
+    name: &'a ZeroSlice<u16>,
+    words: &'a VarZeroSlice<str, Index32>,
+) -> impl Iterator<Item = u8> + 'a {
+    name.iter().enumerate().flat_map(move |(i, word)| {
+        (i > 0)
+            .then_some(b' ')
+            .into_iter()
+            .chain(words.get(word as usize).unwrap_or_default().bytes())
+    })
+}
+
+fn is_ignored(b: u8) -> bool {
+    b.is_ascii_whitespace() || b == b'_'
+}
+
+/// Transforms a name into its loose matching key, following UAX44-LM2: ASCII letters are
+/// uppercased, and whitespace, underscores, and medial hyphens are removed.
+///
+/// A hyphen is medial if it is between two letters or digits.
+struct Loose<I: Iterator<Item = u8>> {
+    iter: core::iter::Peekable<I>,
+    prev: u8,
+}
+
+impl<I: Iterator<Item = u8>> Loose<I> {
+    fn new(iter: I) -> Self {
+        Self {
+            iter: iter.peekable(),
+            prev: b' ',
+        }
+    }
+}
+
+impl<I: Iterator<Item = u8>> Iterator for Loose<I> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        loop {
+            let b = self.iter.next()?;
+            let prev = core::mem::replace(&mut self.prev, b);
+            if is_ignored(b)
+                || (b == b'-'
+                    && prev.is_ascii_alphanumeric()
+                    && self.iter.peek().is_some_and(u8::is_ascii_alphanumeric))
+            {
+                continue;
+            }
+            return Some(b.to_ascii_uppercase());
+        }
+    }
+}
@@ -71,6 +71,8 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+mod character_names;
+pub use character_names::{CharacterName, CharacterNames, CharacterNamesBorrowed, NameAliasType};
 mod code_point_set;
 pub use code_point_set::{CodePointSetData, CodePointSetDataBorrowed};
 mod code_point_map;
@@ -3155,6 +3155,150 @@ make_emoji_set! {
     /// ```
 }
 
+make_emoji_set! {
+    ident: EmojiKeycapSequence;
+    data_marker: crate::provider::PropertyBinaryEmojiKeycapSequenceV1;
+    singleton: SINGLETON_PROPERTY_BINARY_EMOJI_KEYCAP_SEQUENCE_V1;
+    /// Keycap sequences: a digit, `#`, or `*`, followed by U+FE0F VARIATION SELECTOR-16
+    /// and U+20E3 COMBINING ENCLOSING KEYCAP.
+    ///
+    /// See [`Unicode Technical Standard #51`](https://unicode.org/reports/tr51/) for more
+    /// details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::EmojiSetData;
+    /// use icu::properties::props::EmojiKeycapSequence;
+    ///
+    /// let keycaps = EmojiSetData::new::<EmojiKeycapSequence>();
+    ///
+    /// assert!(keycaps.contains_str("\u{0033}\u{FE0F}\u{20E3}")); // keycap 3
+    /// assert!(!keycaps.contains_str("\u{0033}\u{20E3}"));
+    /// assert!(!keycaps.contains('3'));
+    /// ```
+}
+
+make_emoji_set! {
+    ident: RgiEmojiFlagSequence;
+    data_marker: crate::provider::PropertyBinaryRgiEmojiFlagSequenceV1;
+    singleton: SINGLETON_PROPERTY_BINARY_RGI_EMOJI_FLAG_SEQUENCE_V1;
+    /// Pairs of regional indicator symbols that are recommended for general interchange (RGI)
+    /// as flags.
+    ///
+    /// See [`Unicode Technical Standard #51`](https://unicode.org/reports/tr51/) for more
+    /// details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::EmojiSetData;
+    /// use icu::properties::props::RgiEmojiFlagSequence;
+    ///
+    /// let flags = EmojiSetData::new::<RgiEmojiFlagSequence>();
+    ///
+    /// assert!(flags.contains_str("🇺🇦")); // U+1F1FA U+1F1E6
+    /// assert!(!flags.contains_str("🇺🇺")); // U+1F1FA U+1F1FA
+    /// ```
+}
+
+make_emoji_set! {
+    ident: RgiEmojiModifierSequence;
+    data_marker: crate::provider::PropertyBinaryRgiEmojiModifierSequenceV1;
+    singleton: SINGLETON_PROPERTY_BINARY_RGI_EMOJI_MODIFIER_SEQUENCE_V1;
+    /// Emoji modifier bases followed by a skin tone modifier, that are recommended for general
+    /// interchange (RGI).
+    ///
+    /// See [`Unicode Technical Standard #51`](https://unicode.org/reports/tr51/) for more
+    /// details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::EmojiSetData;
+    /// use icu::properties::props::RgiEmojiModifierSequence;
+    ///
+    /// let modifier_sequences = EmojiSetData::new::<RgiEmojiModifierSequence>();
+    ///
+    /// assert!(modifier_sequences.contains_str("\u{1F44B}\u{1F3FD}")); // waving hand: medium skin tone
+    /// assert!(!modifier_sequences.contains_str("\u{1F44B}"));
+    /// ```
+}
+
+make_emoji_set! {
+    ident: RgiEmojiTagSequence;
+    data_marker: crate::provider::PropertyBinaryRgiEmojiTagSequenceV1;
+    singleton: SINGLETON_PROPERTY_BINARY_RGI_EMOJI_TAG_SEQUENCE_V1;
+    /// Emoji tag sequences that are recommended for general interchange (RGI), such as
+    /// subdivision flags.
+    ///
+    /// See [`Unicode Technical Standard #51`](https://unicode.org/reports/tr51/) for more
+    /// details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::EmojiSetData;
+    /// use icu::properties::props::RgiEmojiTagSequence;
+    ///
+    /// let tag_sequences = EmojiSetData::new::<RgiEmojiTagSequence>();
+    ///
+    /// // flag: Scotland
+    /// assert!(tag_sequences.contains_str(
+    ///     "\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}"
+    /// ));
+    /// ```
+}
+
+make_emoji_set! {
+    ident: RgiEmojiZwjSequence;
+    data_marker: crate::provider::PropertyBinaryRgiEmojiZwjSequenceV1;
+    singleton: SINGLETON_PROPERTY_BINARY_RGI_EMOJI_ZWJ_SEQUENCE_V1;
+    /// Emoji sequences joined by U+200D ZERO WIDTH JOINER that are recommended for general
+    /// interchange (RGI).
+    ///
+    /// See [`Unicode Technical Standard #51`](https://unicode.org/reports/tr51/) for more
+    /// details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::EmojiSetData;
+    /// use icu::properties::props::RgiEmojiZwjSequence;
+    ///
+    /// let zwj_sequences = EmojiSetData::new::<RgiEmojiZwjSequence>();
+    ///
+    /// assert!(zwj_sequences.contains_str("\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}")); // rainbow flag
+    /// assert!(!zwj_sequences.contains_str("\u{1F3F3}\u{200D}\u{1F308}"));
+    /// ```
+}
+
+make_emoji_set! {
+    ident: RgiEmoji;
+    data_marker: crate::provider::PropertyBinaryRgiEmojiV1;
+    singleton: SINGLETON_PROPERTY_BINARY_RGI_EMOJI_V1;
+    /// All emoji that are recommended for general interchange (RGI), which is the union of
+    /// [`BasicEmoji`], [`EmojiKeycapSequence`], [`RgiEmojiFlagSequence`],
+    /// [`RgiEmojiModifierSequence`], [`RgiEmojiTagSequence`], and [`RgiEmojiZwjSequence`].
+    ///
+    /// See [`Unicode Technical Standard #51`](https://unicode.org/reports/tr51/) for more
+    /// details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::EmojiSetData;
+    /// use icu::properties::props::RgiEmoji;
+    ///
+    /// let rgi_emoji = EmojiSetData::new::<RgiEmoji>();
+    ///
+    /// assert!(rgi_emoji.contains('🦃')); // U+1F983 TURKEY
+    /// assert!(rgi_emoji.contains_str("\u{0033}\u{FE0F}\u{20E3}")); // keycap 3
+    /// assert!(rgi_emoji.contains_str("🇺🇦"));
+    /// assert!(!rgi_emoji.contains('A'));
+    /// ```
+}
+
 #[cfg(test)]
 mod test_enumerated_property_completeness {
     use super::*;
@@ -18,25 +18,25 @@
 pub mod names;
 
 pub use names::{
-    PropertyNameLongBidiClassV1, PropertyNameLongCanonicalCombiningClassV1,
-    PropertyNameLongEastAsianWidthV1, PropertyNameLongGeneralCategoryV1,
-    PropertyNameLongGraphemeClusterBreakV1, PropertyNameLongHangulSyllableTypeV1,
-    PropertyNameLongIndicSyllabicCategoryV1, PropertyNameLongJoiningTypeV1,
-    PropertyNameLongLineBreakV1, PropertyNameLongScriptV1, PropertyNameLongSentenceBreakV1,
-    PropertyNameLongVerticalOrientationV1, PropertyNameLongWordBreakV1,
-    PropertyNameParseBidiClassV1, PropertyNameParseCanonicalCombiningClassV1,
-    PropertyNameParseEastAsianWidthV1, PropertyNameParseGeneralCategoryMaskV1,
-    PropertyNameParseGeneralCategoryV1, PropertyNameParseGraphemeClusterBreakV1,
-    PropertyNameParseHangulSyllableTypeV1, PropertyNameParseIndicSyllabicCategoryV1,
-    PropertyNameParseJoiningTypeV1, PropertyNameParseLineBreakV1, PropertyNameParseScriptV1,
-    PropertyNameParseSentenceBreakV1, PropertyNameParseVerticalOrientationV1,
-    PropertyNameParseWordBreakV1, PropertyNameShortBidiClassV1,
-    PropertyNameShortCanonicalCombiningClassV1, PropertyNameShortEastAsianWidthV1,
-    PropertyNameShortGeneralCategoryV1, PropertyNameShortGraphemeClusterBreakV1,
-    PropertyNameShortHangulSyllableTypeV1, PropertyNameShortIndicSyllabicCategoryV1,
-    PropertyNameShortJoiningTypeV1, PropertyNameShortLineBreakV1, PropertyNameShortScriptV1,
-    PropertyNameShortSentenceBreakV1, PropertyNameShortVerticalOrientationV1,
-    PropertyNameShortWordBreakV1,
+    PropertyCharacterNamesV1, PropertyNameLongBidiClassV1,
+    PropertyNameLongCanonicalCombiningClassV1, PropertyNameLongEastAsianWidthV1,
+    PropertyNameLongGeneralCategoryV1, PropertyNameLongGraphemeClusterBreakV1,
+    PropertyNameLongHangulSyllableTypeV1, PropertyNameLongIndicSyllabicCategoryV1,
+    PropertyNameLongJoiningTypeV1, PropertyNameLongLineBreakV1, PropertyNameLongScriptV1,
+    PropertyNameLongSentenceBreakV1, PropertyNameLongVerticalOrientationV1,
+    PropertyNameLongWordBreakV1, PropertyNameParseBidiClassV1,
+    PropertyNameParseCanonicalCombiningClassV1, PropertyNameParseEastAsianWidthV1,
+    PropertyNameParseGeneralCategoryMaskV1, PropertyNameParseGeneralCategoryV1,
+    PropertyNameParseGraphemeClusterBreakV1, PropertyNameParseHangulSyllableTypeV1,
+    PropertyNameParseIndicSyllabicCategoryV1, PropertyNameParseJoiningTypeV1,
+    PropertyNameParseLineBreakV1, PropertyNameParseScriptV1, PropertyNameParseSentenceBreakV1,
+    PropertyNameParseVerticalOrientationV1, PropertyNameParseWordBreakV1,
+    PropertyNameShortBidiClassV1, PropertyNameShortCanonicalCombiningClassV1,
+    PropertyNameShortEastAsianWidthV1, PropertyNameShortGeneralCategoryV1,
+    PropertyNameShortGraphemeClusterBreakV1, PropertyNameShortHangulSyllableTypeV1,
+    PropertyNameShortIndicSyllabicCategoryV1, PropertyNameShortJoiningTypeV1,
+    PropertyNameShortLineBreakV1, PropertyNameShortScriptV1, PropertyNameShortSentenceBreakV1,
+    PropertyNameShortVerticalOrientationV1, PropertyNameShortWordBreakV1,
 };
 
 pub use crate::props::gc::GeneralCategoryULE;
@@ -91,6 +91,7 @@ const _: () = {
     impl_property_binary_deprecated_v1!(Baked);
     impl_property_binary_diacritic_v1!(Baked);
     impl_property_binary_emoji_component_v1!(Baked);
+    impl_property_binary_emoji_keycap_sequence_v1!(Baked);
     impl_property_binary_emoji_modifier_base_v1!(Baked);
     impl_property_binary_emoji_modifier_v1!(Baked);
     impl_property_binary_emoji_presentation_v1!(Baked);
@@ -125,6 +126,11 @@ const _: () = {
     impl_property_binary_quotation_mark_v1!(Baked);
     impl_property_binary_radical_v1!(Baked);
     impl_property_binary_regional_indicator_v1!(Baked);
+    impl_property_binary_rgi_emoji_flag_sequence_v1!(Baked);
+    impl_property_binary_rgi_emoji_modifier_sequence_v1!(Baked);
+    impl_property_binary_rgi_emoji_tag_sequence_v1!(Baked);
+    impl_property_binary_rgi_emoji_v1!(Baked);
+    impl_property_binary_rgi_emoji_zwj_sequence_v1!(Baked);
     impl_property_binary_segment_starter_v1!(Baked);
     impl_property_binary_sentence_terminal_v1!(Baked);
     impl_property_binary_soft_dotted_v1!(Baked);
@@ -680,6 +686,42 @@ icu_provider::data_marker!(
     PropertyUnicodeSet<'static>,
     is_singleton = true
 );
+icu_provider::data_marker!(
+    /// `PropertyBinaryEmojiKeycapSequenceV1`
+    PropertyBinaryEmojiKeycapSequenceV1,
+    PropertyUnicodeSet<'static>,
+    is_singleton = true
+);
+icu_provider::data_marker!(
+    /// `PropertyBinaryRgiEmojiV1`
+    PropertyBinaryRgiEmojiV1,
+    PropertyUnicodeSet<'static>,
+    is_singleton = true
+);
+icu_provider::data_marker!(
+    /// `PropertyBinaryRgiEmojiFlagSequenceV1`
+    PropertyBinaryRgiEmojiFlagSequenceV1,
+    PropertyUnicodeSet<'static>,
+    is_singleton = true
+);
+icu_provider::data_marker!(
+    /// `PropertyBinaryRgiEmojiModifierSequenceV1`
+    PropertyBinaryRgiEmojiModifierSequenceV1,
+    PropertyUnicodeSet<'static>,
+    is_singleton = true
+);
+icu_provider::data_marker!(
+    /// `PropertyBinaryRgiEmojiTagSequenceV1`
+    PropertyBinaryRgiEmojiTagSequenceV1,
+    PropertyUnicodeSet<'static>,
+    is_singleton = true
+);
+icu_provider::data_marker!(
+    /// `PropertyBinaryRgiEmojiZwjSequenceV1`
+    PropertyBinaryRgiEmojiZwjSequenceV1,
+    PropertyUnicodeSet<'static>,
+    is_singleton = true
+);
 icu_provider::data_marker!(
     /// `PropertyScriptWithExtensionsV1`
     PropertyScriptWithExtensionsV1,
@@ -687,7 +729,9 @@ icu_provider::data_marker!(
     is_singleton = true
 );
 
-/// All data keys in this module.
+/// All data keys in this module that are included in compiled data.
+///
+/// Character names ([`PropertyCharacterNamesV1`]) are not included because of their size.
 pub const MARKERS: &[DataMarkerInfo] = &[
     PropertyNameLongBidiClassV1::INFO,
     PropertyNameLongCanonicalCombiningClassV1::INFO,
@@ -810,6 +854,12 @@ pub const MARKERS: &[DataMarkerInfo] = &[
     PropertyEnumWordBreakV1::INFO,
     PropertyEnumBidiMirroringGlyphV1::INFO,
     PropertyBinaryBasicEmojiV1::INFO,
+    PropertyBinaryEmojiKeycapSequenceV1::INFO,
+    PropertyBinaryRgiEmojiV1::INFO,
+    PropertyBinaryRgiEmojiFlagSequenceV1::INFO,
+    PropertyBinaryRgiEmojiModifierSequenceV1::INFO,
+    PropertyBinaryRgiEmojiTagSequenceV1::INFO,
+    PropertyBinaryRgiEmojiZwjSequenceV1::INFO,
     PropertyScriptWithExtensionsV1::INFO,
 ];
 
@@ -14,10 +14,12 @@
 
 use icu_locale_core::subtags::Script;
 use icu_provider::prelude::*;
+use potential_utf::PotentialCodePoint;
 
 use zerotrie::ZeroTrieSimpleAscii;
 use zerovec::ule::NichedOption;
-use zerovec::{VarZeroVec, ZeroMap, ZeroVec};
+use zerovec::vecs::Index32;
+use zerovec::{VarZeroVec, ZeroMap, ZeroSlice, ZeroVec};
 
 icu_provider::data_marker!(
     /// `PropertyNameParseBidiClassV1`
@@ -259,6 +261,12 @@ icu_provider::data_marker!(
     PropertyScriptToIcuScriptMap<'static>,
     is_singleton = true,
 );
+icu_provider::data_marker!(
+    /// `PropertyCharacterNamesV1`
+    PropertyCharacterNamesV1,
+    PropertyCharacterNames<'static>,
+    is_singleton = true,
+);
 
 /// A set of characters and strings which share a particular property value.
 ///
@@ -355,3 +363,59 @@ icu_provider::data_struct!(
     PropertyScriptToIcuScriptMap<'_>,
     #[cfg(feature = "datagen")]
 );
+
+/// The `Name` and `Name_Alias` properties of all code points.
+///
+/// Names that are derived algorithmically from the code point, like those of Hangul syllables
+/// and CJK ideographs, are not stored individually.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_properties::provider::names))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct PropertyCharacterNames<'data> {
+    /// The space-separated words that names consist of.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub words: VarZeroVec<'data, str, Index32>,
+    /// The code points that have a name listed in `names`, in ascending order.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub code_points: ZeroVec<'data, PotentialCodePoint>,
+    /// The name of each code point in `code_points`, as indices into `words`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: VarZeroVec<'data, ZeroSlice<u16>, Index32>,
+    /// Ranges of code points whose name is a prefix followed by the code point in
+    /// hexadecimal, such as `CJK UNIFIED IDEOGRAPH-4E00`, as inclusive start, inclusive
+    /// end, and index into `hex_name_prefixes`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub hex_name_ranges: ZeroVec<'data, (PotentialCodePoint, PotentialCodePoint, u8)>,
+    /// The prefixes of the names in `hex_name_ranges`, including the trailing hyphen.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub hex_name_prefixes: VarZeroVec<'data, str>,
+    /// The code points that have a name alias, in ascending order. Code points
+    /// with multiple aliases are repeated.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub alias_code_points: ZeroVec<'data, PotentialCodePoint>,
+    /// The name alias of each entry in `alias_code_points`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub aliases: VarZeroVec<'data, str>,
+    /// The type of each alias in `aliases`: 0 for correction, 1 for control, 2 for
+    /// alternate, 3 for figment, and 4 for abbreviation.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub alias_types: ZeroVec<'data, u8>,
+    /// Indices into `names`, followed by indices into `aliases` offset by the length of
+    /// `names`, ordered by their loose matching key (see [UAX44-LM2]).
+    ///
+    /// [UAX44-LM2]: https://www.unicode.org/reports/tr44/#UAX44-LM2
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub loose_order: ZeroVec<'data, u32>,
+}
+
+icu_provider::data_struct!(
+    PropertyCharacterNames<'_>,
+    #[cfg(feature = "datagen")]
+);
@@ -0,0 +1,665 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Times of astronomical events, such as sunrise, sunset, moon phases, and solar terms.
+//!
+//! The times are computed using the algorithms from _Calendrical Calculations_ by
+//! Reingold & Dershowitz, which are also used to compute the astronomical calendars in
+//! [`icu_calendar`]. They are generally accurate to within a few minutes for dates in the
+//! recent past and near future.
+//!
+//! All functions take a [`Date`] and an optional [`UtcOffset`]. The date is interpreted as the
+//! local date at that offset (or in UTC if no offset is given), so the functions return the
+//! events that happen during that local date, and the results are returned as
+//! [`ZonedDateTime`]s at that offset.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::calendar::Date;
+//! use icu::time::astronomy::{GeographicLocation, MoonPhase, SolarTerm};
+//! use icu::time::zone::UtcOffset;
+//!
+//! let london = GeographicLocation::try_new(51.5074, -0.1278, 0.0).unwrap();
+//! let bst = UtcOffset::try_from_seconds(3600).unwrap();
+//!
+//! let sunrise = london
+//!     .sunrise(Date::try_new_iso(2024, 6, 20).unwrap(), Some(bst))
+//!     .unwrap();
+//! // 04:42:59, which published tables round to 04:43
+//! assert_eq!(sunrise.time.hour.number(), 4);
+//! assert_eq!(sunrise.time.minute.number(), 42);
+//! assert_eq!(sunrise.time.second.number(), 59);
+//!
+//! let full_moon =
+//!     MoonPhase::FullMoon.on_or_after(Date::try_new_iso(2024, 9, 1).unwrap(), None);
+//! assert_eq!(full_moon.date, Date::try_new_iso(2024, 9, 18).unwrap());
+//!
+//! assert_eq!(
+//!     SolarTerm::for_date(Date::try_new_iso(2024, 3, 20).unwrap(), None),
+//!     Some(SolarTerm::Chunfen)
+//! );
+//! ```
+
+use calendrical_calculations::astronomical_events;
+use calendrical_calculations::rata_die::RataDie;
+use displaydoc::Display;
+use icu_calendar::{AsCalendar, Date, Iso};
+
+use crate::zone::UtcOffset;
+use crate::ZonedDateTime;
+
+const UNIX_EPOCH: RataDie = calendrical_calculations::iso::const_fixed_from_iso(1970, 1, 1);
+
+/// The geographic location was invalid. The latitude must be within ±90°, the longitude within ±180°, and the elevation finite.
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[allow(clippy::exhaustive_structs)]
+pub struct InvalidLocationError;
+
+/// A location on the surface of the Earth.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GeographicLocation {
+    latitude: f64,
+    longitude: f64,
+    elevation: f64,
+}
+
+/// The depression of the sun below the horizon used to define dawn and dusk.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Twilight {
+    /// The sun is 6° below the horizon.
+    Civil,
+    /// The sun is 12° below the horizon.
+    Nautical,
+    /// The sun is 18° below the horizon.
+    Astronomical,
+}
+
+impl Twilight {
+    fn depression(self) -> f64 {
+        match self {
+            Self::Civil => astronomical_events::CIVIL_TWILIGHT,
+            Self::Nautical => astronomical_events::NAUTICAL_TWILIGHT,
+            Self::Astronomical => astronomical_events::ASTRONOMICAL_TWILIGHT,
+        }
+    }
+}
+
+impl GeographicLocation {
+    /// Creates a [`GeographicLocation`] from a latitude in degrees (positive is north),
+    /// a longitude in degrees (positive is east), and an elevation in meters.
+    pub fn try_new(
+        latitude: f64,
+        longitude: f64,
+        elevation: f64,
+    ) -> Result<Self, InvalidLocationError> {
+        if !(-90.0..=90.0).contains(&latitude)
+            || !(-180.0..=180.0).contains(&longitude)
+            || !elevation.is_finite()
+        {
+            return Err(InvalidLocationError);
+        }
+        Ok(Self {
+            latitude,
+            longitude,
+            elevation,
+        })
+    }
+
+    /// The latitude in degrees, from -90 to 90.
+    pub fn latitude(self) -> f64 {
+        self.latitude
+    }
+
+    /// The longitude in degrees, from -180 to 180.
+    pub fn longitude(self) -> f64 {
+        self.longitude
+    }
+
+    /// The elevation in meters.
+    pub fn elevation(self) -> f64 {
+        self.elevation
+    }
+
+    /// The time of sunrise on the given date, or `None` if the sun does not rise on that date.
+    ///
+    /// See the [module documentation](self) for how the date and offset are interpreted.
+    pub fn sunrise<A: AsCalendar>(
+        self,
+        date: Date<A>,
+        offset: Option<UtcOffset>,
+    ) -> Option<ZonedDateTime<Iso, UtcOffset>> {
+        on_local_date(date.to_rata_die(), offset, |day| {
+            astronomical_events::sunrise(day, self.latitude, self.longitude, self.elevation)
+        })
+    }
+
+    /// The time of sunset on the given date, or `None` if the sun does not set on that date.
+    ///
+    /// See the [module documentation](self) for how the date and offset are interpreted.
+    pub fn sunset<A: AsCalendar>(
+        self,
+        date: Date<A>,
+        offset: Option<UtcOffset>,
+    ) -> Option<ZonedDateTime<Iso, UtcOffset>> {
+        on_local_date(date.to_rata_die(), offset, |day| {
+            astronomical_events::sunset(day, self.latitude, self.longitude, self.elevation)
+        })
+    }
+
+    /// The time of dawn on the given date, or `None` if the sun does not reach
+    /// the depression of the given [`Twilight`] in the morning of that date.
+    ///
+    /// See the [module documentation](self) for how the date and offset are interpreted.
+    pub fn dawn<A: AsCalendar>(
+        self,
+        date: Date<A>,
+        twilight: Twilight,
+        offset: Option<UtcOffset>,
+    ) -> Option<ZonedDateTime<Iso, UtcOffset>> {
+        on_local_date(date.to_rata_die(), offset, |day| {
+            astronomical_events::dawn(day, self.latitude, self.longitude, twilight.depression())
+        })
+    }
+
+    /// The time of dusk on the given date, or `None` if the sun does not reach
+    /// the depression of the given [`Twilight`] in the evening of that date.
+    ///
+    /// See the [module documentation](self) for how the date and offset are interpreted.
+    pub fn dusk<A: AsCalendar>(
+        self,
+        date: Date<A>,
+        twilight: Twilight,
+        offset: Option<UtcOffset>,
+    ) -> Option<ZonedDateTime<Iso, UtcOffset>> {
+        on_local_date(date.to_rata_die(), offset, |day| {
+            astronomical_events::dusk(day, self.latitude, self.longitude, twilight.depression())
+        })
+    }
+}
+
+/// The principal phases of the moon.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MoonPhase {
+    /// The moon and the sun have the same longitude.
+    NewMoon,
+    /// The moon is 90° east of the sun.
+    FirstQuarter,
+    /// The moon and the sun have opposite longitudes.
+    FullMoon,
+    /// The moon is 270° east of the sun.
+    LastQuarter,
+}
+
+impl MoonPhase {
+    const ALL: [Self; 4] = [
+        Self::NewMoon,
+        Self::FirstQuarter,
+        Self::FullMoon,
+        Self::LastQuarter,
+    ];
+
+    /// The difference between the longitudes of the moon and the sun at this phase, in degrees.
+    pub fn angle(self) -> f64 {
+        match self {
+            Self::NewMoon => 0.0,
+            Self::FirstQuarter => 90.0,
+            Self::FullMoon => 180.0,
+            Self::LastQuarter => 270.0,
+        }
+    }
+
+    /// The time of the first occurrence of this phase on or after the start of the given date.
+    ///
+    /// See the [module documentation](self) for how the date and offset are interpreted.
+    pub fn on_or_after<A: AsCalendar>(
+        self,
+        date: Date<A>,
+        offset: Option<UtcOffset>,
+    ) -> ZonedDateTime<Iso, UtcOffset> {
+        from_moment(
+            astronomical_events::lunar_phase_at_or_after(
+                self.angle(),
+                start_of_day(date.to_rata_die(), offset),
+            ),
+            offset,
+        )
+    }
+
+    /// The phase that occurs on the given date, if any.
+    ///
+    /// See the [module documentation](self) for how the date and offset are interpreted.
+    pub fn for_date<A: AsCalendar>(date: Date<A>, offset: Option<UtcOffset>) -> Option<Self> {
+        let start = start_of_day(date.to_rata_die(), offset);
+        // Principal phases are about a week apart, so at most one of them falls on any day.
+        let next = Self::ALL
+            .into_iter()
+            .cycle()
+            .nth((astronomical_events::lunar_phase(start) / 90.0) as usize + 1)?;
+        (astronomical_events::lunar_phase_at_or_after(next.angle(), start) < start + 1.0)
+            .then_some(next)
+    }
+}
+
+/// The 24 solar terms (节气) of the Chinese calendar.
+///
+/// Each term begins when the longitude of the sun reaches a multiple of 15°.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SolarTerm {
+    /// Start of Spring (立春), 315°
+    Lichun,
+    /// Rain Water (雨水), 330°
+    Yushui,
+    /// Awakening of Insects (惊蛰), 345°
+    Jingzhe,
+    /// Spring Equinox (春分), 0°
+    Chunfen,
+    /// Pure Brightness (清明), 15°
+    Qingming,
+    /// Grain Rain (谷雨), 30°
+    Guyu,
+    /// Start of Summer (立夏), 45°
+    Lixia,
+    /// Grain Buds (小满), 60°
+    Xiaoman,
+    /// Grain in Ear (芒种), 75°
+    Mangzhong,
+    /// Summer Solstice (夏至), 90°
+    Xiazhi,
+    /// Minor Heat (小暑), 105°
+    Xiaoshu,
+    /// Major Heat (大暑), 120°
+    Dashu,
+    /// Start of Autumn (立秋), 135°
+    Liqiu,
+    /// End of Heat (处暑), 150°
+    Chushu,
+    /// White Dew (白露), 165°
+    Bailu,
+    /// Autumn Equinox (秋分), 180°
+    Qiufen,
+    /// Cold Dew (寒露), 195°
+    Hanlu,
+    /// Frost's Descent (霜降), 210°
+    Shuangjiang,
+    /// Start of Winter (立冬), 225°
+    Lidong,
+    /// Minor Snow (小雪), 240°
+    Xiaoxue,
+    /// Major Snow (大雪), 255°
+    Daxue,
+    /// Winter Solstice (冬至), 270°
+    Dongzhi,
+    /// Minor Cold (小寒), 285°
+    Xiaohan,
+    /// Major Cold (大寒), 300°
+    Dahan,
+}
+
+impl SolarTerm {
+    /// The terms in order of solar longitude, starting at 0°.
+    const BY_LONGITUDE: [Self; 24] = [
+        Self::Chunfen,
+        Self::Qingming,
+        Self::Guyu,
+        Self::Lixia,
+        Self::Xiaoman,
+        Self::Mangzhong,
+        Self::Xiazhi,
+        Self::Xiaoshu,
+        Self::Dashu,
+        Self::Liqiu,
+        Self::Chushu,
+        Self::Bailu,
+        Self::Qiufen,
+        Self::Hanlu,
+        Self::Shuangjiang,
+        Self::Lidong,
+        Self::Xiaoxue,
+        Self::Daxue,
+        Self::Dongzhi,
+        Self::Xiaohan,
+        Self::Dahan,
+        Self::Lichun,
+        Self::Yushui,
+        Self::Jingzhe,
+    ];
+
+    /// The longitude of the sun at the start of this term, in degrees.
+    pub fn solar_longitude(self) -> f64 {
+        let index = match self {
+            Self::Chunfen => 0,
+            Self::Qingming => 1,
+            Self::Guyu => 2,
+            Self::Lixia => 3,
+            Self::Xiaoman => 4,
+            Self::Mangzhong => 5,
+            Self::Xiazhi => 6,
+            Self::Xiaoshu => 7,
+            Self::Dashu => 8,
+            Self::Liqiu => 9,
+            Self::Chushu => 10,
+            Self::Bailu => 11,
+            Self::Qiufen => 12,
+            Self::Hanlu => 13,
+            Self::Shuangjiang => 14,
+            Self::Lidong => 15,
+            Self::Xiaoxue => 16,
+            Self::Daxue => 17,
+            Self::Dongzhi => 18,
+            Self::Xiaohan => 19,
+            Self::Dahan => 20,
+            Self::Lichun => 21,
+            Self::Yushui => 22,
+            Self::Jingzhe => 23,
+        };
+        f64::from(index) * 15.0
+    }
+
+    /// The time of the first start of this term on or after the start of the given date.
+    ///
+    /// See the [module documentation](self) for how the date and offset are interpreted.
+    pub fn on_or_after<A: AsCalendar>(
+        self,
+        date: Date<A>,
+        offset: Option<UtcOffset>,
+    ) -> ZonedDateTime<Iso, UtcOffset> {
+        from_moment(
+            astronomical_events::solar_longitude_after(
+                self.solar_longitude(),
+                start_of_day(date.to_rata_die(), offset),
+            ),
+            offset,
+        )
+    }
+
+    /// The term that starts on the given date, if any.
+    ///
+    /// See the [module documentation](self) for how the date and offset are interpreted.
+    pub fn for_date<A: AsCalendar>(date: Date<A>, offset: Option<UtcOffset>) -> Option<Self> {
+        let start = start_of_day(date.to_rata_die(), offset);
+        // Terms are about 15 days apart, so at most one of them starts on any day.
+        let next = Self::BY_LONGITUDE
+            .into_iter()
+            .cycle()
+            .nth((astronomical_events::solar_longitude(start) / 15.0) as usize + 1)?;
+        (astronomical_events::solar_longitude_after(next.solar_longitude(), start) < start + 1.0)
+            .then_some(next)
+    }
+}
+
+/// The moment in universal time at which the given local date starts.
+fn start_of_day(date: RataDie, offset: Option<UtcOffset>) -> f64 {
+    let offset = offset.unwrap_or(UtcOffset::zero());
+    date.to_i64_date() as f64 - f64::from(offset.to_seconds()) / 86400.0
+}
+
+/// Finds the event that happens during the given local date.
+///
+/// `event` interprets its argument as a date in local mean time at the location, which can
+/// differ by up to a day from the date at the offset, so the neighboring days are checked too.
+fn on_local_date(
+    date: RataDie,
+    offset: Option<UtcOffset>,
+    event: impl Fn(RataDie) -> Option<f64>,
+) -> Option<ZonedDateTime<Iso, UtcOffset>> {
+    let start = start_of_day(date, offset);
+    [date, date - 1, date + 1]
+        .into_iter()
+        .filter_map(event)
+        .find(|moment| (start..start + 1.0).contains(moment))
+        .map(|moment| from_moment(moment, offset))
+}
+
+/// Converts a moment in universal time to a [`ZonedDateTime`], rounded to the nearest second.
+fn from_moment(moment: f64, offset: Option<UtcOffset>) -> ZonedDateTime<Iso, UtcOffset> {
+    let seconds = (moment - UNIX_EPOCH.to_i64_date() as f64) * 86400.0;
+    // Round to the nearest second without `f64::round`, which needs `std`
+    let seconds = if seconds < 0.0 {
+        seconds - 0.5
+    } else {
+        seconds + 0.5
+    } as i64;
+    ZonedDateTime::from_epoch_milliseconds_and_utc_offset(
+        seconds * 1000,
+        offset.unwrap_or(UtcOffset::zero()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Asserts that `actual` is within `tolerance` minutes of the given UTC time.
+    #[track_caller]
+    fn assert_near(
+        actual: ZonedDateTime<Iso, UtcOffset>,
+        (year, month, day, hour, minute): (i32, u8, u8, u8, u8),
+        tolerance: i64,
+    ) {
+        let expected = Date::try_new_iso(year, month, day).unwrap().to_rata_die() - UNIX_EPOCH;
+        let expected = (expected * 1440 + i64::from(hour) * 60 + i64::from(minute)) * 60000;
+        let difference = actual.to_epoch_milliseconds_utc() - expected;
+        assert!(
+            difference.abs() <= tolerance * 60000,
+            "{actual:?} is {} minutes away from {year}-{month:02}-{day:02}T{hour:02}:{minute:02}Z",
+            difference / 60000
+        );
+    }
+
+    fn date(year: i32, month: u8, day: u8) -> Date<Iso> {
+        Date::try_new_iso(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_location_validation() {
+        assert!(GeographicLocation::try_new(90.0, 180.0, 0.0).is_ok());
+        assert!(GeographicLocation::try_new(-90.0, -180.0, -400.0).is_ok());
+        assert_eq!(
+            GeographicLocation::try_new(90.5, 0.0, 0.0),
+            Err(InvalidLocationError)
+        );
+        assert_eq!(
+            GeographicLocation::try_new(0.0, -180.5, 0.0),
+            Err(InvalidLocationError)
+        );
+        assert_eq!(
+            GeographicLocation::try_new(f64::NAN, 0.0, 0.0),
+            Err(InvalidLocationError)
+        );
+        assert_eq!(
+            GeographicLocation::try_new(0.0, 0.0, f64::INFINITY),
+            Err(InvalidLocationError)
+        );
+    }
+
+    #[test]
+    fn test_sunrise_sunset() {
+        // Times from the U.S. Naval Observatory's "Sun and Moon Data for One Day"
+        let london = GeographicLocation::try_new(51.5074, -0.1278, 0.0).unwrap();
+        let new_york = GeographicLocation::try_new(40.7128, -74.0060, 0.0).unwrap();
+        let tokyo = GeographicLocation::try_new(35.6762, 139.6503, 0.0).unwrap();
+        let sydney = GeographicLocation::try_new(-33.8688, 151.2093, 0.0).unwrap();
+
+        let jst = Some(UtcOffset::try_from_seconds(9 * 3600).unwrap());
+        let aedt = Some(UtcOffset::try_from_seconds(11 * 3600).unwrap());
+
+        #[rustfmt::skip]
+        let cases = [
+            (london, date(2024, 6, 20), None, (2024, 6, 20, 3, 43), (2024, 6, 20, 20, 21)),
+            (london, date(2024, 12, 21), None, (2024, 12, 21, 8, 4), (2024, 12, 21, 15, 54)),
+            (new_york, date(2024, 12, 21), None, (2024, 12, 21, 12, 17), (2024, 12, 21, 21, 32)),
+            (tokyo, date(2024, 3, 20), jst, (2024, 3, 19, 20, 45), (2024, 3, 20, 8, 54)),
+            (sydney, date(2024, 1, 1), aedt, (2023, 12, 31, 18, 48), (2024, 1, 1, 9, 10)),
+        ];
+
+        for (location, date, offset, sunrise, sunset) in cases {
+            let actual_sunrise = location.sunrise(date, offset).unwrap();
+            let actual_sunset = location.sunset(date, offset).unwrap();
+            assert_eq!(actual_sunrise.date, date);
+            assert_eq!(actual_sunset.date, date);
+            assert_near(actual_sunrise, sunrise, 2);
+            assert_near(actual_sunset, sunset, 2);
+        }
+    }
+
+    #[test]
+    fn test_offset() {
+        let new_york = GeographicLocation::try_new(40.7128, -74.0060, 0.0).unwrap();
+        let est = UtcOffset::try_from_seconds(-5 * 3600).unwrap();
+
+        let sunset = new_york.sunset(date(2024, 12, 21), Some(est)).unwrap();
+        assert_eq!(sunset.zone, est);
+        assert_eq!(sunset.date, date(2024, 12, 21));
+        assert_eq!(sunset.time.hour.number(), 16);
+        assert_near(sunset, (2024, 12, 21, 21, 32), 2);
+
+        // Sunrise in Tokyo on 2024-03-20 in UTC is on the morning of 2024-03-21 in Tokyo
+        let tokyo = GeographicLocation::try_new(35.6762, 139.6503, 0.0).unwrap();
+        let sunrise = tokyo.sunrise(date(2024, 3, 20), None).unwrap();
+        assert_eq!(sunrise.date, date(2024, 3, 20));
+        assert_near(sunrise, (2024, 3, 20, 20, 44), 2);
+
+        // The new moon of 2024-01-11 11:57 UTC is on 2024-01-12 in Auckland
+        let nzdt = UtcOffset::try_from_seconds(13 * 3600).unwrap();
+        assert_eq!(
+            MoonPhase::for_date(date(2024, 1, 11), None),
+            Some(MoonPhase::NewMoon)
+        );
+        assert_eq!(MoonPhase::for_date(date(2024, 1, 11), Some(nzdt)), None);
+        assert_eq!(
+            MoonPhase::for_date(date(2024, 1, 12), Some(nzdt)),
+            Some(MoonPhase::NewMoon)
+        );
+        assert_eq!(
+            MoonPhase::NewMoon
+                .on_or_after(date(2024, 1, 12), Some(nzdt))
+                .date,
+            date(2024, 1, 12)
+        );
+    }
+
+    #[test]
+    fn test_twilight() {
+        // Times from the U.S. Naval Observatory's "Sun and Moon Data for One Day"
+        let washington = GeographicLocation::try_new(38.8951, -77.0364, 0.0).unwrap();
+        let edt = Some(UtcOffset::try_from_seconds(-4 * 3600).unwrap());
+        let date = date(2024, 3, 20);
+
+        #[rustfmt::skip]
+        let cases = [
+            (Twilight::Civil, (2024, 3, 20, 10, 44), (2024, 3, 20, 23, 46)),
+            (Twilight::Nautical, (2024, 3, 20, 10, 13), (2024, 3, 21, 0, 17)),
+            (Twilight::Astronomical, (2024, 3, 20, 9, 41), (2024, 3, 21, 0, 49)),
+        ];
+
+        for (twilight, dawn, dusk) in cases {
+            assert_near(washington.dawn(date, twilight, edt).unwrap(), dawn, 2);
+            assert_near(washington.dusk(date, twilight, edt).unwrap(), dusk, 2);
+        }
+    }
+
+    #[test]
+    fn test_polar() {
+        let tromso = GeographicLocation::try_new(69.6492, 18.9553, 0.0).unwrap();
+        let london = GeographicLocation::try_new(51.5074, -0.1278, 0.0).unwrap();
+
+        // Midnight sun
+        assert_eq!(tromso.sunrise(date(2024, 6, 21), None), None);
+        assert_eq!(tromso.sunset(date(2024, 6, 21), None), None);
+        // Polar night
+        assert_eq!(tromso.sunrise(date(2024, 12, 21), None), None);
+        assert!(tromso
+            .dawn(date(2024, 12, 21), Twilight::Civil, None)
+            .is_some());
+        // No astronomical night in London around midsummer
+        assert_eq!(
+            london.dusk(date(2024, 6, 21), Twilight::Astronomical, None),
+            None
+        );
+        assert!(london
+            .dusk(date(2024, 6, 21), Twilight::Nautical, None)
+            .is_some());
+    }
+
+    #[test]
+    fn test_moon_phases() {
+        // Times from the U.S. Naval Observatory's "Phases of the Moon"
+        #[rustfmt::skip]
+        let cases = [
+            (MoonPhase::NewMoon, (2024, 1, 11, 11, 57)),
+            (MoonPhase::FirstQuarter, (2024, 1, 18, 3, 53)),
+            (MoonPhase::FullMoon, (2024, 1, 25, 17, 54)),
+            (MoonPhase::LastQuarter, (2024, 2, 2, 23, 18)),
+            (MoonPhase::NewMoon, (2024, 4, 8, 18, 21)),
+            (MoonPhase::FullMoon, (2024, 9, 18, 2, 34)),
+            (MoonPhase::NewMoon, (2024, 10, 2, 18, 49)),
+            (MoonPhase::FullMoon, (2000, 1, 21, 4, 40)),
+            (MoonPhase::NewMoon, (1990, 1, 26, 19, 20)),
+        ];
+
+        for (phase, (year, month, day, hour, minute)) in cases {
+            let rd = date(year, month, day).to_rata_die();
+            assert_near(
+                phase.on_or_after(Date::from_rata_die(rd - 3, Iso), None),
+                (year, month, day, hour, minute),
+                2,
+            );
+            assert_eq!(
+                MoonPhase::for_date(Date::from_rata_die(rd, Iso), None),
+                Some(phase)
+            );
+            assert_eq!(
+                MoonPhase::for_date(Date::from_rata_die(rd + 1, Iso), None),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn test_solar_terms() {
+        // Times from the Hong Kong Observatory's "24 Solar Terms" tables, converted from UTC+8
+        #[rustfmt::skip]
+        let cases = [
+            (SolarTerm::Xiaohan, (2024, 1, 5, 20, 49)),
+            (SolarTerm::Lichun, (2024, 2, 4, 8, 27)),
+            (SolarTerm::Chunfen, (2024, 3, 20, 3, 6)),
+            (SolarTerm::Qingming, (2024, 4, 4, 7, 2)),
+            (SolarTerm::Xiazhi, (2024, 6, 20, 20, 51)),
+            (SolarTerm::Qiufen, (2024, 9, 22, 12, 44)),
+            (SolarTerm::Dongzhi, (2024, 12, 21, 9, 21)),
+        ];
+
+        for (term, (year, month, day, hour, minute)) in cases {
+            let rd = date(year, month, day).to_rata_die();
+            assert_near(
+                term.on_or_after(Date::from_rata_die(rd - 3, Iso), None),
+                (year, month, day, hour, minute),
+                2,
+            );
+            assert_eq!(
+                SolarTerm::for_date(Date::from_rata_die(rd, Iso), None),
+                Some(term)
+            );
+            assert_eq!(
+                SolarTerm::for_date(Date::from_rata_die(rd + 1, Iso), None),
+                None
+            );
+        }
+
+        // Every term starts exactly once a year
+        let mut count = 0;
+        let start = date(2024, 1, 1).to_rata_die();
+        for day in 0..366 {
+            if let Some(term) = SolarTerm::for_date(Date::from_rata_die(start + day, Iso), None) {
+                assert_eq!(term, SolarTerm::BY_LONGITUDE[(count + 19) % 24]);
+                count += 1;
+            }
+        }
+        assert_eq!(count, 24);
+    }
+}
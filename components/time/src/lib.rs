@@ -27,6 +27,7 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+pub mod astronomy;
 pub mod provider;
 pub mod scaffold;
 
@@ -63,6 +63,13 @@ impl IntoOption<DayOfYear> for DayOfYear {
     }
 }
 
+impl IntoOption<DayOfYearInfo> for DayOfYearInfo {
+    #[inline]
+    fn into_option(self) -> Option<Self> {
+        Some(self)
+    }
+}
+
 impl IntoOption<RataDie> for RataDie {
     #[inline]
     fn into_option(self) -> Option<Self> {
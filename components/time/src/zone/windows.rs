@@ -15,8 +15,8 @@ use crate::{
 
 /// A mapper between Windows time zone identifier and a BCP-47 ID.
 ///
-/// This mapper currently only supports mapping from windows time zone identifiers
-/// to BCP-47 identifiers.
+/// This mapper supports mapping from windows time zone identifiers to BCP-47 identifiers
+/// and, with the `alloc` Cargo feature, from BCP-47 identifiers back to windows time zone identifiers.
 ///
 /// A windows time zone may vary depending on an associated territory/region. This is represented
 /// by the internal data mapping by delimiting the windows time zone and territory/region
@@ -150,6 +150,51 @@ impl WindowsParserBorrowed<'_> {
             .ok()?;
         self.data.bcp47_ids.get(cursor.take_value()?)
     }
+
+    /// Returns the Windows time zone identifier for a provided BCP-47 ID.
+    ///
+    /// If the BCP-47 ID is the main time zone of a Windows time zone (territory `001`),
+    /// that Windows time zone is returned. Otherwise, the Windows time zone that lists
+    /// the BCP-47 ID for some other territory is returned.
+    ///
+    /// Returns `None` for BCP-47 IDs that are not listed in the Windows time zone data.
+    ///
+    /// ✨ *Enabled with the `alloc` Cargo feature.*
+    ///
+    /// ```rust
+    /// use icu::locale::subtags::subtag;
+    /// use icu::time::{zone::WindowsParser, TimeZone};
+    ///
+    /// let win_tz_mapper = WindowsParser::new();
+    ///
+    /// let windows_tz = win_tz_mapper.to_windows(TimeZone(subtag!("uschi")));
+    /// assert_eq!(windows_tz.as_deref(), Some("Central Standard Time"));
+    ///
+    /// let windows_tz = win_tz_mapper.to_windows(TimeZone(subtag!("cawnp")));
+    /// assert_eq!(windows_tz.as_deref(), Some("Central Standard Time"));
+    ///
+    /// let windows_tz = win_tz_mapper.to_windows(TimeZone::UNKNOWN);
+    /// assert_eq!(windows_tz, None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_windows(self, time_zone: TimeZone) -> Option<alloc::string::String> {
+        let mut fallback = None;
+        for (key, index) in self.data.map.iter() {
+            if self.data.bcp47_ids.get(index) != Some(time_zone) {
+                continue;
+            }
+            let Some((windows_tz, territory)) = key.rsplit_once('/') else {
+                continue;
+            };
+            if territory == "001" {
+                return Some(windows_tz.into());
+            }
+            if fallback.is_none() {
+                fallback = Some(windows_tz.into());
+            }
+        }
+        fallback
+    }
 }
 
 #[cfg(test)]
@@ -173,4 +218,25 @@ mod tests {
         let result = win_map.parse("GMT Standard Time", None);
         assert_eq!(result, Some(TimeZone(subtag!("gblon"))));
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn windows_tz_round_trip() {
+        let win_map = WindowsParser::new();
+
+        for windows_tz in [
+            "Central Standard Time",
+            "Eastern Standard Time",
+            "GMT Standard Time",
+            "Pacific Standard Time",
+            "Tokyo Standard Time",
+        ] {
+            let bcp47_id = win_map.parse(windows_tz, None).unwrap();
+            assert_eq!(win_map.to_windows(bcp47_id).as_deref(), Some(windows_tz));
+        }
+
+        // Region-specific zones map back to their Windows zone
+        let result = win_map.to_windows(TimeZone(subtag!("cator")));
+        assert_eq!(result.as_deref(), Some("Eastern Standard Time"));
+    }
 }
@@ -0,0 +1,197 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#include <icu4x/CompactDecimalFormatter.hpp>
+#include <icu4x/CurrencyFormatter.hpp>
+#include <icu4x/DurationFormatter.hpp>
+#include <icu4x/LongCurrencyFormatter.hpp>
+#include <icu4x/MeasureUnit.hpp>
+#include <icu4x/PersonName.hpp>
+#include <icu4x/PersonNamesFormatter.hpp>
+#include <icu4x/RelativeTimeFormatter.hpp>
+#include <icu4x/Transliterator.hpp>
+#include <icu4x/UnitsConverter.hpp>
+#include <icu4x/UnitsConverterFactory.hpp>
+#include <icu4x/UnitsFormatter.hpp>
+#include <icu4x/Logger.hpp>
+
+#include <cmath>
+#include <iostream>
+
+using namespace icu4x;
+
+int main() {
+    // For basic logging
+    Logger::init_simple_logger();
+
+    std::unique_ptr<Locale> locale = Locale::from_string("en-US").ok().value();
+
+    // Compact decimals
+    std::unique_ptr<CompactDecimalFormatter> compact = CompactDecimalFormatter::create_short(*locale.get(), std::nullopt).ok().value();
+    std::string out = compact->format(*Decimal::from(12345).get());
+    std::cout << "Compact short 12345: " << out << std::endl;
+    if (out != "12K") {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    compact = CompactDecimalFormatter::create_long(*locale.get(), std::nullopt).ok().value();
+    out = compact->format(*Decimal::from(12345).get());
+    std::cout << "Compact long 12345: " << out << std::endl;
+    if (out != "12 thousand") {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    // Relative time
+    std::unique_ptr<RelativeTimeFormatter> relative = RelativeTimeFormatter::create(*locale.get(), RelativeTimeUnit::Second, RelativeTimeLength::Long, RelativeTimeNumeric::Always).ok().value();
+    out = relative->format(*Decimal::from(5).get());
+    std::cout << "Relative time +5 seconds: " << out << std::endl;
+    if (out != "in 5 seconds") {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    relative = RelativeTimeFormatter::create(*locale.get(), RelativeTimeUnit::Day, RelativeTimeLength::Long, RelativeTimeNumeric::Auto).ok().value();
+    out = relative->format(*Decimal::from(-1).get());
+    std::cout << "Relative time -1 day: " << out << std::endl;
+    if (out != "yesterday") {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    // Currencies
+    std::unique_ptr<Decimal> amount = Decimal::from_string("12345.67").ok().value();
+
+    std::unique_ptr<CurrencyFormatter> currency = CurrencyFormatter::create(*locale.get(), "USD", CurrencyFormatterWidth::Short).ok().value();
+    out = currency->format(*amount.get());
+    std::cout << "Currency: " << out << std::endl;
+    if (out != "$12,345.67") {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    std::unique_ptr<LongCurrencyFormatter> long_currency = LongCurrencyFormatter::create(*locale.get(), "USD").ok().value();
+    out = long_currency->format(*amount.get());
+    std::cout << "Long currency: " << out << std::endl;
+    if (out != "12,345.67 US dollars") {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    if (!CurrencyFormatter::create(*locale.get(), "US", CurrencyFormatterWidth::Short).is_err()) {
+        std::cout << "Invalid currency code should fail" << std::endl;
+        return 1;
+    }
+
+    // Units
+    std::unique_ptr<UnitsFormatter> units = UnitsFormatter::create(*locale.get(), "meter", UnitsFormatterWidth::Long).ok().value();
+    out = units->format(*Decimal::from(1).get());
+    std::cout << "Units: " << out << std::endl;
+    if (out != "1 meter") {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    units = UnitsFormatter::create(*locale.get(), "meter", UnitsFormatterWidth::Short).ok().value();
+    out = units->format(*amount.get());
+    std::cout << "Units short: " << out << std::endl;
+    if (out != "12,345.67 m") {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    // Unit conversion
+    std::unique_ptr<UnitsConverterFactory> factory = UnitsConverterFactory::create();
+    std::unique_ptr<MeasureUnit> meter = MeasureUnit::from_string("meter");
+    std::unique_ptr<MeasureUnit> foot = MeasureUnit::from_string("foot");
+    std::unique_ptr<MeasureUnit> second = MeasureUnit::from_string("second");
+    if (!meter || !foot || !second || MeasureUnit::from_string("metre")) {
+        std::cout << "Unexpected unit parsing result" << std::endl;
+        return 1;
+    }
+
+    std::unique_ptr<UnitsConverter> converter = factory->converter(*meter.get(), *foot.get());
+    double converted = converter->convert(1000.0);
+    std::cout << "1000 meters in feet: " << converted << std::endl;
+    if (std::fabs(converted - 3280.839895013123) > 1e-9) {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    converter = factory->converter(*MeasureUnit::from_string("celsius").get(), *MeasureUnit::from_string("fahrenheit").get());
+    converted = converter->convert(100.0);
+    std::cout << "100 celsius in fahrenheit: " << converted << std::endl;
+    if (std::fabs(converted - 212.0) > 1e-9) {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    if (factory->converter(*meter.get(), *second.get())) {
+        std::cout << "Meters should not be convertible to seconds" << std::endl;
+        return 1;
+    }
+
+    // Durations
+    std::unique_ptr<Locale> und = Locale::from_string("und").ok().value();
+    std::unique_ptr<DurationFormatter> duration_formatter = DurationFormatter::create(*und.get(), DurationFormatterStyle::Digital, std::nullopt).ok().value();
+    Duration duration = {};
+    duration.sign = DurationSign::Positive;
+    duration.hours = 12;
+    duration.minutes = 1;
+    duration.seconds = 32;
+    duration.milliseconds = 130;
+    out = duration_formatter->format(duration);
+    std::cout << "Duration: " << out << std::endl;
+    if (out != "12:01:32.13") {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    auto invalid_duration_formatter = DurationFormatter::create(*und.get(), DurationFormatterStyle::Digital, 10);
+    if (!invalid_duration_formatter.is_err() || std::move(invalid_duration_formatter).err().value() != DurationFormatterLoadError::FractionalDigitsOutOfRange) {
+        std::cout << "Out of range fractional digits should fail" << std::endl;
+        return 1;
+    }
+
+    // Transliteration
+    std::unique_ptr<Locale> bengali_to_arabic = Locale::from_string("und-Arab-t-und-beng").ok().value();
+    std::unique_ptr<Transliterator> transliterator = Transliterator::create(*bengali_to_arabic.get()).ok().value();
+    out = transliterator->transliterate("অকার্যতানাযা");
+    std::cout << "Transliterated: " << out << std::endl;
+    if (out != "اكاريتانايا") {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    // Person names
+    std::unique_ptr<Locale> en = Locale::from_string("en").ok().value();
+    std::unique_ptr<PersonName> name = PersonName::create();
+    name->set_field(PersonNameFieldKind::Given, "Henry");
+    name->set_field(PersonNameFieldKind::Surname, "Wells");
+    name->set_locale(*en.get());
+
+    std::unique_ptr<PersonNamesFormatter> names = PersonNamesFormatter::create(*en.get(), PersonNamesFormatterOrder::Sorting, PersonNamesFormatterLength::Medium, PersonNamesFormatterUsage::Referring, PersonNamesFormatterFormality::Formal).ok().value();
+    out = names->format(*name.get()).ok().value();
+    std::cout << "Person name (sorting): " << out << std::endl;
+    if (out != "Wells, Henry") {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    names = PersonNamesFormatter::create(*en.get(), PersonNamesFormatterOrder::GivenFirst, PersonNamesFormatterLength::Short, PersonNamesFormatterUsage::Referring, PersonNamesFormatterFormality::Formal).ok().value();
+    out = names->format(*name.get()).ok().value();
+    std::cout << "Person name (short): " << out << std::endl;
+    if (out != "H. Wells") {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    if (!names->format(*PersonName::create().get()).is_err()) {
+        std::cout << "Empty person name should fail" << std::endl;
+        return 1;
+    }
+
+    return 0;
+}
@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 #include <icu4x/Locale.hpp>
+#include <icu4x/LocalePriorityList.hpp>
 #include <icu4x/Logger.hpp>
 
 #include <iostream>
@@ -105,5 +106,30 @@ int main() {
     return 1;
   }
 
+  std::unique_ptr<LocalePriorityList> list =
+      LocalePriorityList::from_accept_language("fr-CH, fr;q=0.9, *;q=0.5, de;q=0.7")
+          .ok()
+          .value();
+  if (list->length() != 4) {
+    std::cout << "Priority list has the wrong length: " << list->length()
+              << std::endl;
+    return 1;
+  }
+  if (!test_locale(*list->locale_at(2), "de",
+                   "The third entry of the priority list")) {
+    return 1;
+  }
+  if (!list->is_wildcard_at(3) || list->weight_at(3) != 500) {
+    std::cout << "The last entry should be a wildcard with weight 0.5"
+              << std::endl;
+    return 1;
+  }
+
+  list = LocalePriorityList::from_colon_separated_lossy("de_DE.UTF-8:en");
+  if (!test_locale(*list->locale_at(0), "de-DE",
+                   "Repaired a POSIX locale name")) {
+    return 1;
+  }
+
   return 0;
 }
@@ -0,0 +1,19 @@
+#ifndef CompactDecimalFormatter_D_H
+#define CompactDecimalFormatter_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef struct CompactDecimalFormatter CompactDecimalFormatter;
+
+
+
+
+#endif // CompactDecimalFormatter_D_H
@@ -0,0 +1,19 @@
+#ifndef CurrencyFormatter_D_H
+#define CurrencyFormatter_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef struct CurrencyFormatter CurrencyFormatter;
+
+
+
+
+#endif // CurrencyFormatter_D_H
@@ -0,0 +1,37 @@
+#ifndef CurrencyFormatter_H
+#define CurrencyFormatter_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#include "CurrencyFormatterWidth.d.h"
+#include "DataError.d.h"
+#include "DataProvider.d.h"
+#include "Decimal.d.h"
+#include "Locale.d.h"
+
+#include "CurrencyFormatter.d.h"
+
+
+
+
+
+
+typedef struct icu4x_CurrencyFormatter_create_mv1_result {union {CurrencyFormatter* ok; DataError err;}; bool is_ok;} icu4x_CurrencyFormatter_create_mv1_result;
+icu4x_CurrencyFormatter_create_mv1_result icu4x_CurrencyFormatter_create_mv1(const Locale* locale, DiplomatStringView currency_code, CurrencyFormatterWidth width);
+
+typedef struct icu4x_CurrencyFormatter_create_with_provider_mv1_result {union {CurrencyFormatter* ok; DataError err;}; bool is_ok;} icu4x_CurrencyFormatter_create_with_provider_mv1_result;
+icu4x_CurrencyFormatter_create_with_provider_mv1_result icu4x_CurrencyFormatter_create_with_provider_mv1(const DataProvider* provider, const Locale* locale, DiplomatStringView currency_code, CurrencyFormatterWidth width);
+
+void icu4x_CurrencyFormatter_format_mv1(const CurrencyFormatter* self, const Decimal* value, DiplomatWrite* write);
+
+void icu4x_CurrencyFormatter_destroy_mv1(CurrencyFormatter* self);
+
+
+
+
+
+#endif // CurrencyFormatter_H
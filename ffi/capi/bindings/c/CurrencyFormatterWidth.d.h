@@ -0,0 +1,23 @@
+#ifndef CurrencyFormatterWidth_D_H
+#define CurrencyFormatterWidth_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef enum CurrencyFormatterWidth {
+  CurrencyFormatterWidth_Short = 0,
+  CurrencyFormatterWidth_Narrow = 1,
+} CurrencyFormatterWidth;
+
+typedef struct CurrencyFormatterWidth_option {union { CurrencyFormatterWidth ok; }; bool is_ok; } CurrencyFormatterWidth_option;
+
+
+
+#endif // CurrencyFormatterWidth_D_H
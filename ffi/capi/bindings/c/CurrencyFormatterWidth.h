@@ -0,0 +1,22 @@
+#ifndef CurrencyFormatterWidth_H
+#define CurrencyFormatterWidth_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "CurrencyFormatterWidth.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // CurrencyFormatterWidth_H
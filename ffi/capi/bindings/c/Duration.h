@@ -0,0 +1,22 @@
+#ifndef Duration_H
+#define Duration_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "Duration.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // Duration_H
@@ -0,0 +1,19 @@
+#ifndef DurationFormatter_D_H
+#define DurationFormatter_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef struct DurationFormatter DurationFormatter;
+
+
+
+
+#endif // DurationFormatter_D_H
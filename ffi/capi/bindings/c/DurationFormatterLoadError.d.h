@@ -0,0 +1,34 @@
+#ifndef DurationFormatterLoadError_D_H
+#define DurationFormatterLoadError_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef enum DurationFormatterLoadError {
+  DurationFormatterLoadError_Unknown = 0,
+  DurationFormatterLoadError_DisplayAlwaysFractional = 2049,
+  DurationFormatterLoadError_PreviousFractional = 2050,
+  DurationFormatterLoadError_PreviousNumeric = 2051,
+  DurationFormatterLoadError_FractionalDigitsOutOfRange = 2052,
+  DurationFormatterLoadError_DataMarkerNotFound = 1,
+  DurationFormatterLoadError_DataIdentifierNotFound = 2,
+  DurationFormatterLoadError_DataInvalidRequest = 3,
+  DurationFormatterLoadError_DataInconsistentData = 4,
+  DurationFormatterLoadError_DataDowncast = 5,
+  DurationFormatterLoadError_DataDeserialize = 6,
+  DurationFormatterLoadError_DataCustom = 7,
+  DurationFormatterLoadError_DataIo = 8,
+} DurationFormatterLoadError;
+
+typedef struct DurationFormatterLoadError_option {union { DurationFormatterLoadError ok; }; bool is_ok; } DurationFormatterLoadError_option;
+
+
+
+#endif // DurationFormatterLoadError_D_H
@@ -0,0 +1,22 @@
+#ifndef DurationFormatterLoadError_H
+#define DurationFormatterLoadError_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "DurationFormatterLoadError.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // DurationFormatterLoadError_H
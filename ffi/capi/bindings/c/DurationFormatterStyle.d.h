@@ -0,0 +1,25 @@
+#ifndef DurationFormatterStyle_D_H
+#define DurationFormatterStyle_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef enum DurationFormatterStyle {
+  DurationFormatterStyle_Narrow = 0,
+  DurationFormatterStyle_Short = 1,
+  DurationFormatterStyle_Long = 2,
+  DurationFormatterStyle_Digital = 3,
+} DurationFormatterStyle;
+
+typedef struct DurationFormatterStyle_option {union { DurationFormatterStyle ok; }; bool is_ok; } DurationFormatterStyle_option;
+
+
+
+#endif // DurationFormatterStyle_D_H
@@ -0,0 +1,22 @@
+#ifndef DurationFormatterStyle_H
+#define DurationFormatterStyle_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "DurationFormatterStyle.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // DurationFormatterStyle_H
@@ -0,0 +1,22 @@
+#ifndef DurationSign_H
+#define DurationSign_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "DurationSign.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // DurationSign_H
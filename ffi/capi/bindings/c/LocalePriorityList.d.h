@@ -0,0 +1,19 @@
+#ifndef LocalePriorityList_D_H
+#define LocalePriorityList_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef struct LocalePriorityList LocalePriorityList;
+
+
+
+
+#endif // LocalePriorityList_D_H
@@ -0,0 +1,45 @@
+#ifndef LocalePriorityList_H
+#define LocalePriorityList_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#include "Locale.d.h"
+#include "LocaleParseError.d.h"
+
+#include "LocalePriorityList.d.h"
+
+
+
+
+
+
+typedef struct icu4x_LocalePriorityList_from_accept_language_mv1_result {union {LocalePriorityList* ok; LocaleParseError err;}; bool is_ok;} icu4x_LocalePriorityList_from_accept_language_mv1_result;
+icu4x_LocalePriorityList_from_accept_language_mv1_result icu4x_LocalePriorityList_from_accept_language_mv1(DiplomatStringView s);
+
+LocalePriorityList* icu4x_LocalePriorityList_from_accept_language_lossy_mv1(DiplomatStringView s);
+
+typedef struct icu4x_LocalePriorityList_from_colon_separated_mv1_result {union {LocalePriorityList* ok; LocaleParseError err;}; bool is_ok;} icu4x_LocalePriorityList_from_colon_separated_mv1_result;
+icu4x_LocalePriorityList_from_colon_separated_mv1_result icu4x_LocalePriorityList_from_colon_separated_mv1(DiplomatStringView s);
+
+LocalePriorityList* icu4x_LocalePriorityList_from_colon_separated_lossy_mv1(DiplomatStringView s);
+
+size_t icu4x_LocalePriorityList_length_mv1(const LocalePriorityList* self);
+
+Locale* icu4x_LocalePriorityList_locale_at_mv1(const LocalePriorityList* self, size_t index);
+
+bool icu4x_LocalePriorityList_is_wildcard_at_mv1(const LocalePriorityList* self, size_t index);
+
+typedef struct icu4x_LocalePriorityList_weight_at_mv1_result {union {uint16_t ok; }; bool is_ok;} icu4x_LocalePriorityList_weight_at_mv1_result;
+icu4x_LocalePriorityList_weight_at_mv1_result icu4x_LocalePriorityList_weight_at_mv1(const LocalePriorityList* self, size_t index);
+
+void icu4x_LocalePriorityList_destroy_mv1(LocalePriorityList* self);
+
+
+
+
+
+#endif // LocalePriorityList_H
@@ -0,0 +1,19 @@
+#ifndef LongCurrencyFormatter_D_H
+#define LongCurrencyFormatter_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef struct LongCurrencyFormatter LongCurrencyFormatter;
+
+
+
+
+#endif // LongCurrencyFormatter_D_H
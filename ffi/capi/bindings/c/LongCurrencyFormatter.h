@@ -0,0 +1,36 @@
+#ifndef LongCurrencyFormatter_H
+#define LongCurrencyFormatter_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#include "DataError.d.h"
+#include "DataProvider.d.h"
+#include "Decimal.d.h"
+#include "Locale.d.h"
+
+#include "LongCurrencyFormatter.d.h"
+
+
+
+
+
+
+typedef struct icu4x_LongCurrencyFormatter_create_mv1_result {union {LongCurrencyFormatter* ok; DataError err;}; bool is_ok;} icu4x_LongCurrencyFormatter_create_mv1_result;
+icu4x_LongCurrencyFormatter_create_mv1_result icu4x_LongCurrencyFormatter_create_mv1(const Locale* locale, DiplomatStringView currency_code);
+
+typedef struct icu4x_LongCurrencyFormatter_create_with_provider_mv1_result {union {LongCurrencyFormatter* ok; DataError err;}; bool is_ok;} icu4x_LongCurrencyFormatter_create_with_provider_mv1_result;
+icu4x_LongCurrencyFormatter_create_with_provider_mv1_result icu4x_LongCurrencyFormatter_create_with_provider_mv1(const DataProvider* provider, const Locale* locale, DiplomatStringView currency_code);
+
+void icu4x_LongCurrencyFormatter_format_mv1(const LongCurrencyFormatter* self, const Decimal* value, DiplomatWrite* write);
+
+void icu4x_LongCurrencyFormatter_destroy_mv1(LongCurrencyFormatter* self);
+
+
+
+
+
+#endif // LongCurrencyFormatter_H
@@ -0,0 +1,19 @@
+#ifndef MeasureUnit_D_H
+#define MeasureUnit_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef struct MeasureUnit MeasureUnit;
+
+
+
+
+#endif // MeasureUnit_D_H
@@ -0,0 +1,26 @@
+#ifndef MeasureUnit_H
+#define MeasureUnit_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "MeasureUnit.d.h"
+
+
+
+
+
+
+MeasureUnit* icu4x_MeasureUnit_from_string_mv1(DiplomatStringView unit);
+
+void icu4x_MeasureUnit_destroy_mv1(MeasureUnit* self);
+
+
+
+
+
+#endif // MeasureUnit_H
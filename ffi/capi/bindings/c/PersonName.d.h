@@ -0,0 +1,19 @@
+#ifndef PersonName_D_H
+#define PersonName_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef struct PersonName PersonName;
+
+
+
+
+#endif // PersonName_D_H
@@ -0,0 +1,35 @@
+#ifndef PersonName_H
+#define PersonName_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#include "Locale.d.h"
+#include "PersonNameFieldKind.d.h"
+#include "PersonNamePreferredOrder.d.h"
+
+#include "PersonName.d.h"
+
+
+
+
+
+
+PersonName* icu4x_PersonName_create_mv1(void);
+
+void icu4x_PersonName_set_field_mv1(PersonName* self, PersonNameFieldKind kind, DiplomatStringView value);
+
+void icu4x_PersonName_set_locale_mv1(PersonName* self, const Locale* locale);
+
+void icu4x_PersonName_set_preferred_order_mv1(PersonName* self, PersonNamePreferredOrder order);
+
+void icu4x_PersonName_destroy_mv1(PersonName* self);
+
+
+
+
+
+#endif // PersonName_H
@@ -0,0 +1,28 @@
+#ifndef PersonNameFieldKind_D_H
+#define PersonNameFieldKind_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef enum PersonNameFieldKind {
+  PersonNameFieldKind_Title = 0,
+  PersonNameFieldKind_Given = 1,
+  PersonNameFieldKind_Given2 = 2,
+  PersonNameFieldKind_Surname = 3,
+  PersonNameFieldKind_Surname2 = 4,
+  PersonNameFieldKind_Generation = 5,
+  PersonNameFieldKind_Credentials = 6,
+} PersonNameFieldKind;
+
+typedef struct PersonNameFieldKind_option {union { PersonNameFieldKind ok; }; bool is_ok; } PersonNameFieldKind_option;
+
+
+
+#endif // PersonNameFieldKind_D_H
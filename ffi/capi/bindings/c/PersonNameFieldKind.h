@@ -0,0 +1,22 @@
+#ifndef PersonNameFieldKind_H
+#define PersonNameFieldKind_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "PersonNameFieldKind.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // PersonNameFieldKind_H
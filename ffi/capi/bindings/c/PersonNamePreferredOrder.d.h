@@ -0,0 +1,24 @@
+#ifndef PersonNamePreferredOrder_D_H
+#define PersonNamePreferredOrder_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef enum PersonNamePreferredOrder {
+  PersonNamePreferredOrder_Default = 0,
+  PersonNamePreferredOrder_GivenFirst = 1,
+  PersonNamePreferredOrder_SurnameFirst = 2,
+} PersonNamePreferredOrder;
+
+typedef struct PersonNamePreferredOrder_option {union { PersonNamePreferredOrder ok; }; bool is_ok; } PersonNamePreferredOrder_option;
+
+
+
+#endif // PersonNamePreferredOrder_D_H
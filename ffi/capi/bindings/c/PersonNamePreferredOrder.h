@@ -0,0 +1,22 @@
+#ifndef PersonNamePreferredOrder_H
+#define PersonNamePreferredOrder_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "PersonNamePreferredOrder.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // PersonNamePreferredOrder_H
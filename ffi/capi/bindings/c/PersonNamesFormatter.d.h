@@ -0,0 +1,19 @@
+#ifndef PersonNamesFormatter_D_H
+#define PersonNamesFormatter_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef struct PersonNamesFormatter PersonNamesFormatter;
+
+
+
+
+#endif // PersonNamesFormatter_D_H
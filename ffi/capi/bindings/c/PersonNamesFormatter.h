@@ -0,0 +1,44 @@
+#ifndef PersonNamesFormatter_H
+#define PersonNamesFormatter_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#include "DataProvider.d.h"
+#include "Locale.d.h"
+#include "PersonName.d.h"
+#include "PersonNamesFormatterError.d.h"
+#include "PersonNamesFormatterFormality.d.h"
+#include "PersonNamesFormatterLength.d.h"
+#include "PersonNamesFormatterOrder.d.h"
+#include "PersonNamesFormatterUsage.d.h"
+
+#include "PersonNamesFormatter.d.h"
+
+
+
+
+
+
+typedef struct icu4x_PersonNamesFormatter_create_mv1_result {union {PersonNamesFormatter* ok; PersonNamesFormatterError err;}; bool is_ok;} icu4x_PersonNamesFormatter_create_mv1_result;
+icu4x_PersonNamesFormatter_create_mv1_result icu4x_PersonNamesFormatter_create_mv1(const Locale* locale, PersonNamesFormatterOrder order, PersonNamesFormatterLength length, PersonNamesFormatterUsage usage, PersonNamesFormatterFormality formality);
+
+typedef struct icu4x_PersonNamesFormatter_create_with_provider_mv1_result {union {PersonNamesFormatter* ok; PersonNamesFormatterError err;}; bool is_ok;} icu4x_PersonNamesFormatter_create_with_provider_mv1_result;
+icu4x_PersonNamesFormatter_create_with_provider_mv1_result icu4x_PersonNamesFormatter_create_with_provider_mv1(const DataProvider* provider, const Locale* locale, PersonNamesFormatterOrder order, PersonNamesFormatterLength length, PersonNamesFormatterUsage usage, PersonNamesFormatterFormality formality);
+
+typedef struct icu4x_PersonNamesFormatter_format_mv1_result {union { PersonNamesFormatterError err;}; bool is_ok;} icu4x_PersonNamesFormatter_format_mv1_result;
+icu4x_PersonNamesFormatter_format_mv1_result icu4x_PersonNamesFormatter_format_mv1(const PersonNamesFormatter* self, const PersonName* name, DiplomatWrite* write);
+
+typedef struct icu4x_PersonNamesFormatter_format_with_provider_mv1_result {union { PersonNamesFormatterError err;}; bool is_ok;} icu4x_PersonNamesFormatter_format_with_provider_mv1_result;
+icu4x_PersonNamesFormatter_format_with_provider_mv1_result icu4x_PersonNamesFormatter_format_with_provider_mv1(const PersonNamesFormatter* self, const DataProvider* provider, const PersonName* name, DiplomatWrite* write);
+
+void icu4x_PersonNamesFormatter_destroy_mv1(PersonNamesFormatter* self);
+
+
+
+
+
+#endif // PersonNamesFormatter_H
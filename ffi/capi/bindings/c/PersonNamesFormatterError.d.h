@@ -0,0 +1,35 @@
+#ifndef PersonNamesFormatterError_D_H
+#define PersonNamesFormatterError_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef enum PersonNamesFormatterError {
+  PersonNamesFormatterError_Unknown = 0,
+  PersonNamesFormatterError_Parse = 2049,
+  PersonNamesFormatterError_InvalidPersonName = 2050,
+  PersonNamesFormatterError_InvalidLocale = 2051,
+  PersonNamesFormatterError_InvalidCldrData = 2052,
+  PersonNamesFormatterError_Pattern = 2053,
+  PersonNamesFormatterError_DataMarkerNotFound = 1,
+  PersonNamesFormatterError_DataIdentifierNotFound = 2,
+  PersonNamesFormatterError_DataInvalidRequest = 3,
+  PersonNamesFormatterError_DataInconsistentData = 4,
+  PersonNamesFormatterError_DataDowncast = 5,
+  PersonNamesFormatterError_DataDeserialize = 6,
+  PersonNamesFormatterError_DataCustom = 7,
+  PersonNamesFormatterError_DataIo = 8,
+} PersonNamesFormatterError;
+
+typedef struct PersonNamesFormatterError_option {union { PersonNamesFormatterError ok; }; bool is_ok; } PersonNamesFormatterError_option;
+
+
+
+#endif // PersonNamesFormatterError_D_H
@@ -0,0 +1,22 @@
+#ifndef PersonNamesFormatterError_H
+#define PersonNamesFormatterError_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "PersonNamesFormatterError.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // PersonNamesFormatterError_H
@@ -0,0 +1,23 @@
+#ifndef PersonNamesFormatterFormality_D_H
+#define PersonNamesFormatterFormality_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef enum PersonNamesFormatterFormality {
+  PersonNamesFormatterFormality_Formal = 0,
+  PersonNamesFormatterFormality_Informal = 1,
+} PersonNamesFormatterFormality;
+
+typedef struct PersonNamesFormatterFormality_option {union { PersonNamesFormatterFormality ok; }; bool is_ok; } PersonNamesFormatterFormality_option;
+
+
+
+#endif // PersonNamesFormatterFormality_D_H
@@ -0,0 +1,22 @@
+#ifndef PersonNamesFormatterFormality_H
+#define PersonNamesFormatterFormality_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "PersonNamesFormatterFormality.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // PersonNamesFormatterFormality_H
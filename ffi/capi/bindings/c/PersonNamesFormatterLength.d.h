@@ -0,0 +1,24 @@
+#ifndef PersonNamesFormatterLength_D_H
+#define PersonNamesFormatterLength_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef enum PersonNamesFormatterLength {
+  PersonNamesFormatterLength_Short = 0,
+  PersonNamesFormatterLength_Medium = 1,
+  PersonNamesFormatterLength_Long = 2,
+} PersonNamesFormatterLength;
+
+typedef struct PersonNamesFormatterLength_option {union { PersonNamesFormatterLength ok; }; bool is_ok; } PersonNamesFormatterLength_option;
+
+
+
+#endif // PersonNamesFormatterLength_D_H
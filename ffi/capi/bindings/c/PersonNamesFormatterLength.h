@@ -0,0 +1,22 @@
+#ifndef PersonNamesFormatterLength_H
+#define PersonNamesFormatterLength_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "PersonNamesFormatterLength.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // PersonNamesFormatterLength_H
@@ -0,0 +1,24 @@
+#ifndef PersonNamesFormatterOrder_D_H
+#define PersonNamesFormatterOrder_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef enum PersonNamesFormatterOrder {
+  PersonNamesFormatterOrder_GivenFirst = 0,
+  PersonNamesFormatterOrder_SurnameFirst = 1,
+  PersonNamesFormatterOrder_Sorting = 2,
+} PersonNamesFormatterOrder;
+
+typedef struct PersonNamesFormatterOrder_option {union { PersonNamesFormatterOrder ok; }; bool is_ok; } PersonNamesFormatterOrder_option;
+
+
+
+#endif // PersonNamesFormatterOrder_D_H
@@ -0,0 +1,22 @@
+#ifndef PersonNamesFormatterOrder_H
+#define PersonNamesFormatterOrder_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "PersonNamesFormatterOrder.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // PersonNamesFormatterOrder_H
@@ -0,0 +1,24 @@
+#ifndef PersonNamesFormatterUsage_D_H
+#define PersonNamesFormatterUsage_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef enum PersonNamesFormatterUsage {
+  PersonNamesFormatterUsage_Addressing = 0,
+  PersonNamesFormatterUsage_Referring = 1,
+  PersonNamesFormatterUsage_Monogram = 2,
+} PersonNamesFormatterUsage;
+
+typedef struct PersonNamesFormatterUsage_option {union { PersonNamesFormatterUsage ok; }; bool is_ok; } PersonNamesFormatterUsage_option;
+
+
+
+#endif // PersonNamesFormatterUsage_D_H
@@ -0,0 +1,22 @@
+#ifndef PersonNamesFormatterUsage_H
+#define PersonNamesFormatterUsage_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "PersonNamesFormatterUsage.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // PersonNamesFormatterUsage_H
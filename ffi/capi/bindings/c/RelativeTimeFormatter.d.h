@@ -0,0 +1,19 @@
+#ifndef RelativeTimeFormatter_D_H
+#define RelativeTimeFormatter_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef struct RelativeTimeFormatter RelativeTimeFormatter;
+
+
+
+
+#endif // RelativeTimeFormatter_D_H
@@ -0,0 +1,39 @@
+#ifndef RelativeTimeFormatter_H
+#define RelativeTimeFormatter_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#include "DataError.d.h"
+#include "DataProvider.d.h"
+#include "Decimal.d.h"
+#include "Locale.d.h"
+#include "RelativeTimeLength.d.h"
+#include "RelativeTimeNumeric.d.h"
+#include "RelativeTimeUnit.d.h"
+
+#include "RelativeTimeFormatter.d.h"
+
+
+
+
+
+
+typedef struct icu4x_RelativeTimeFormatter_create_mv1_result {union {RelativeTimeFormatter* ok; DataError err;}; bool is_ok;} icu4x_RelativeTimeFormatter_create_mv1_result;
+icu4x_RelativeTimeFormatter_create_mv1_result icu4x_RelativeTimeFormatter_create_mv1(const Locale* locale, RelativeTimeUnit unit, RelativeTimeLength length, RelativeTimeNumeric numeric);
+
+typedef struct icu4x_RelativeTimeFormatter_create_with_provider_mv1_result {union {RelativeTimeFormatter* ok; DataError err;}; bool is_ok;} icu4x_RelativeTimeFormatter_create_with_provider_mv1_result;
+icu4x_RelativeTimeFormatter_create_with_provider_mv1_result icu4x_RelativeTimeFormatter_create_with_provider_mv1(const DataProvider* provider, const Locale* locale, RelativeTimeUnit unit, RelativeTimeLength length, RelativeTimeNumeric numeric);
+
+void icu4x_RelativeTimeFormatter_format_mv1(const RelativeTimeFormatter* self, const Decimal* value, DiplomatWrite* write);
+
+void icu4x_RelativeTimeFormatter_destroy_mv1(RelativeTimeFormatter* self);
+
+
+
+
+
+#endif // RelativeTimeFormatter_H
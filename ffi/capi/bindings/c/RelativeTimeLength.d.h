@@ -0,0 +1,24 @@
+#ifndef RelativeTimeLength_D_H
+#define RelativeTimeLength_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef enum RelativeTimeLength {
+  RelativeTimeLength_Long = 0,
+  RelativeTimeLength_Short = 1,
+  RelativeTimeLength_Narrow = 2,
+} RelativeTimeLength;
+
+typedef struct RelativeTimeLength_option {union { RelativeTimeLength ok; }; bool is_ok; } RelativeTimeLength_option;
+
+
+
+#endif // RelativeTimeLength_D_H
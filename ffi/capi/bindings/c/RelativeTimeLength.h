@@ -0,0 +1,22 @@
+#ifndef RelativeTimeLength_H
+#define RelativeTimeLength_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "RelativeTimeLength.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // RelativeTimeLength_H
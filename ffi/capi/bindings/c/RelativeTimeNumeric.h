@@ -0,0 +1,22 @@
+#ifndef RelativeTimeNumeric_H
+#define RelativeTimeNumeric_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "RelativeTimeNumeric.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // RelativeTimeNumeric_H
@@ -0,0 +1,29 @@
+#ifndef RelativeTimeUnit_D_H
+#define RelativeTimeUnit_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef enum RelativeTimeUnit {
+  RelativeTimeUnit_Second = 0,
+  RelativeTimeUnit_Minute = 1,
+  RelativeTimeUnit_Hour = 2,
+  RelativeTimeUnit_Day = 3,
+  RelativeTimeUnit_Week = 4,
+  RelativeTimeUnit_Month = 5,
+  RelativeTimeUnit_Quarter = 6,
+  RelativeTimeUnit_Year = 7,
+} RelativeTimeUnit;
+
+typedef struct RelativeTimeUnit_option {union { RelativeTimeUnit ok; }; bool is_ok; } RelativeTimeUnit_option;
+
+
+
+#endif // RelativeTimeUnit_D_H
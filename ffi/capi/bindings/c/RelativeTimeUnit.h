@@ -0,0 +1,22 @@
+#ifndef RelativeTimeUnit_H
+#define RelativeTimeUnit_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "RelativeTimeUnit.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // RelativeTimeUnit_H
@@ -0,0 +1,19 @@
+#ifndef Transliterator_D_H
+#define Transliterator_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef struct Transliterator Transliterator;
+
+
+
+
+#endif // Transliterator_D_H
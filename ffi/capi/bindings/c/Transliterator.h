@@ -0,0 +1,35 @@
+#ifndef Transliterator_H
+#define Transliterator_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#include "DataError.d.h"
+#include "DataProvider.d.h"
+#include "Locale.d.h"
+
+#include "Transliterator.d.h"
+
+
+
+
+
+
+typedef struct icu4x_Transliterator_create_mv1_result {union {Transliterator* ok; DataError err;}; bool is_ok;} icu4x_Transliterator_create_mv1_result;
+icu4x_Transliterator_create_mv1_result icu4x_Transliterator_create_mv1(const Locale* locale);
+
+typedef struct icu4x_Transliterator_create_with_provider_mv1_result {union {Transliterator* ok; DataError err;}; bool is_ok;} icu4x_Transliterator_create_with_provider_mv1_result;
+icu4x_Transliterator_create_with_provider_mv1_result icu4x_Transliterator_create_with_provider_mv1(const DataProvider* provider, const Locale* locale);
+
+void icu4x_Transliterator_transliterate_mv1(const Transliterator* self, DiplomatStringView input, DiplomatWrite* write);
+
+void icu4x_Transliterator_destroy_mv1(Transliterator* self);
+
+
+
+
+
+#endif // Transliterator_H
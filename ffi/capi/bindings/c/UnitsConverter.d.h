@@ -0,0 +1,19 @@
+#ifndef UnitsConverter_D_H
+#define UnitsConverter_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef struct UnitsConverter UnitsConverter;
+
+
+
+
+#endif // UnitsConverter_D_H
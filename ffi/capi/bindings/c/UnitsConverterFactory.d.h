@@ -0,0 +1,19 @@
+#ifndef UnitsConverterFactory_D_H
+#define UnitsConverterFactory_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef struct UnitsConverterFactory UnitsConverterFactory;
+
+
+
+
+#endif // UnitsConverterFactory_D_H
@@ -0,0 +1,19 @@
+#ifndef UnitsFormatter_D_H
+#define UnitsFormatter_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef struct UnitsFormatter UnitsFormatter;
+
+
+
+
+#endif // UnitsFormatter_D_H
@@ -0,0 +1,37 @@
+#ifndef UnitsFormatter_H
+#define UnitsFormatter_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#include "DataError.d.h"
+#include "DataProvider.d.h"
+#include "Decimal.d.h"
+#include "Locale.d.h"
+#include "UnitsFormatterWidth.d.h"
+
+#include "UnitsFormatter.d.h"
+
+
+
+
+
+
+typedef struct icu4x_UnitsFormatter_create_mv1_result {union {UnitsFormatter* ok; DataError err;}; bool is_ok;} icu4x_UnitsFormatter_create_mv1_result;
+icu4x_UnitsFormatter_create_mv1_result icu4x_UnitsFormatter_create_mv1(const Locale* locale, DiplomatStringView unit, UnitsFormatterWidth width);
+
+typedef struct icu4x_UnitsFormatter_create_with_provider_mv1_result {union {UnitsFormatter* ok; DataError err;}; bool is_ok;} icu4x_UnitsFormatter_create_with_provider_mv1_result;
+icu4x_UnitsFormatter_create_with_provider_mv1_result icu4x_UnitsFormatter_create_with_provider_mv1(const DataProvider* provider, const Locale* locale, DiplomatStringView unit, UnitsFormatterWidth width);
+
+void icu4x_UnitsFormatter_format_mv1(const UnitsFormatter* self, const Decimal* value, DiplomatWrite* write);
+
+void icu4x_UnitsFormatter_destroy_mv1(UnitsFormatter* self);
+
+
+
+
+
+#endif // UnitsFormatter_H
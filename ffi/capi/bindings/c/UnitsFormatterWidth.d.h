@@ -0,0 +1,24 @@
+#ifndef UnitsFormatterWidth_D_H
+#define UnitsFormatterWidth_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef enum UnitsFormatterWidth {
+  UnitsFormatterWidth_Long = 0,
+  UnitsFormatterWidth_Short = 1,
+  UnitsFormatterWidth_Narrow = 2,
+} UnitsFormatterWidth;
+
+typedef struct UnitsFormatterWidth_option {union { UnitsFormatterWidth ok; }; bool is_ok; } UnitsFormatterWidth_option;
+
+
+
+#endif // UnitsFormatterWidth_D_H
@@ -0,0 +1,22 @@
+#ifndef UnitsFormatterWidth_H
+#define UnitsFormatterWidth_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "UnitsFormatterWidth.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // UnitsFormatterWidth_H
@@ -0,0 +1,108 @@
+#ifndef icu4x_CompactDecimalFormatter_D_HPP
+#define icu4x_CompactDecimalFormatter_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+namespace icu4x {
+namespace capi { struct CompactDecimalFormatter; }
+class CompactDecimalFormatter;
+namespace capi { struct DataProvider; }
+class DataProvider;
+namespace capi { struct Decimal; }
+class Decimal;
+namespace capi { struct Locale; }
+class Locale;
+class DataError;
+class DecimalGroupingStrategy;
+}
+
+
+namespace icu4x {
+namespace capi {
+    struct CompactDecimalFormatter;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Compact Decimal Format object, capable of formatting a {@link Decimal} in a compact form,
+ * such as "1.2K" or "1.2 thousand".
+ *
+ * See the [Rust documentation for `CompactDecimalFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html) for more information.
+ */
+class CompactDecimalFormatter {
+public:
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link CompactDecimalFormatter} for the short compact style (e.g. "1.2K"), using compiled data.
+     *
+     * See the [Rust documentation for `try_new_short`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html#method.try_new_short) for more information.
+   */
+  inline static diplomat::result<std::unique_ptr<icu4x::CompactDecimalFormatter>, icu4x::DataError> create_short(const icu4x::Locale& locale, std::optional<icu4x::DecimalGroupingStrategy> grouping_strategy);
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link CompactDecimalFormatter} for the short compact style (e.g. "1.2K"), using a particular data source.
+     *
+     * See the [Rust documentation for `try_new_short`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html#method.try_new_short) for more information.
+   */
+  inline static diplomat::result<std::unique_ptr<icu4x::CompactDecimalFormatter>, icu4x::DataError> create_short_with_provider(const icu4x::DataProvider& provider, const icu4x::Locale& locale, std::optional<icu4x::DecimalGroupingStrategy> grouping_strategy);
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link CompactDecimalFormatter} for the long compact style (e.g. "1.2 thousand"), using compiled data.
+     *
+     * See the [Rust documentation for `try_new_long`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html#method.try_new_long) for more information.
+   */
+  inline static diplomat::result<std::unique_ptr<icu4x::CompactDecimalFormatter>, icu4x::DataError> create_long(const icu4x::Locale& locale, std::optional<icu4x::DecimalGroupingStrategy> grouping_strategy);
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link CompactDecimalFormatter} for the long compact style (e.g. "1.2 thousand"), using a particular data source.
+     *
+     * See the [Rust documentation for `try_new_long`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html#method.try_new_long) for more information.
+   */
+  inline static diplomat::result<std::unique_ptr<icu4x::CompactDecimalFormatter>, icu4x::DataError> create_long_with_provider(const icu4x::DataProvider& provider, const icu4x::Locale& locale, std::optional<icu4x::DecimalGroupingStrategy> grouping_strategy);
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link Decimal} to a string, rounding it to the precision of the compact pattern.
+     *
+     * See the [Rust documentation for `format_fixed_decimal`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html#method.format_fixed_decimal) for more information.
+   */
+  inline std::string format(const icu4x::Decimal& value) const;
+  template<typename W>
+  inline void format_write(const icu4x::Decimal& value, W& writeable_output) const;
+
+    inline const icu4x::capi::CompactDecimalFormatter* AsFFI() const;
+    inline icu4x::capi::CompactDecimalFormatter* AsFFI();
+    inline static const icu4x::CompactDecimalFormatter* FromFFI(const icu4x::capi::CompactDecimalFormatter* ptr);
+    inline static icu4x::CompactDecimalFormatter* FromFFI(icu4x::capi::CompactDecimalFormatter* ptr);
+    inline static void operator delete(void* ptr);
+private:
+    CompactDecimalFormatter() = delete;
+    CompactDecimalFormatter(const icu4x::CompactDecimalFormatter&) = delete;
+    CompactDecimalFormatter(icu4x::CompactDecimalFormatter&&) noexcept = delete;
+    CompactDecimalFormatter operator=(const icu4x::CompactDecimalFormatter&) = delete;
+    CompactDecimalFormatter operator=(icu4x::CompactDecimalFormatter&&) noexcept = delete;
+    static void operator delete[](void*, size_t) = delete;
+};
+
+} // namespace
+#endif // icu4x_CompactDecimalFormatter_D_HPP
@@ -0,0 +1,94 @@
+#ifndef icu4x_CurrencyFormatter_D_HPP
+#define icu4x_CurrencyFormatter_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+namespace icu4x {
+namespace capi { struct CurrencyFormatter; }
+class CurrencyFormatter;
+namespace capi { struct DataProvider; }
+class DataProvider;
+namespace capi { struct Decimal; }
+class Decimal;
+namespace capi { struct Locale; }
+class Locale;
+class CurrencyFormatterWidth;
+class DataError;
+}
+
+
+namespace icu4x {
+namespace capi {
+    struct CurrencyFormatter;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Currency Formatter object, capable of formatting a {@link Decimal} as an amount
+ * of a particular currency, such as "$12,345.67".
+ *
+ * See the [Rust documentation for `CurrencyFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/formatter/struct.CurrencyFormatter.html) for more information.
+ */
+class CurrencyFormatter {
+public:
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link CurrencyFormatter} for the given ISO 4217 currency code, using compiled data.
+     *
+     * Returns {@link DataError::IdentifierNotFound} if the currency code is not well-formed.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/formatter/struct.CurrencyFormatter.html#method.try_new) for more information.
+   */
+  inline static diplomat::result<std::unique_ptr<icu4x::CurrencyFormatter>, icu4x::DataError> create(const icu4x::Locale& locale, std::string_view currency_code, icu4x::CurrencyFormatterWidth width);
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link CurrencyFormatter} for the given ISO 4217 currency code, using a particular data source.
+     *
+     * Returns {@link DataError::IdentifierNotFound} if the currency code is not well-formed.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/formatter/struct.CurrencyFormatter.html#method.try_new) for more information.
+   */
+  inline static diplomat::result<std::unique_ptr<icu4x::CurrencyFormatter>, icu4x::DataError> create_with_provider(const icu4x::DataProvider& provider, const icu4x::Locale& locale, std::string_view currency_code, icu4x::CurrencyFormatterWidth width);
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link Decimal} as an amount of this formatter's currency.
+     *
+     * See the [Rust documentation for `format_fixed_decimal`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/formatter/struct.CurrencyFormatter.html#method.format_fixed_decimal) for more information.
+   */
+  inline std::string format(const icu4x::Decimal& value) const;
+  template<typename W>
+  inline void format_write(const icu4x::Decimal& value, W& writeable_output) const;
+
+    inline const icu4x::capi::CurrencyFormatter* AsFFI() const;
+    inline icu4x::capi::CurrencyFormatter* AsFFI();
+    inline static const icu4x::CurrencyFormatter* FromFFI(const icu4x::capi::CurrencyFormatter* ptr);
+    inline static icu4x::CurrencyFormatter* FromFFI(icu4x::capi::CurrencyFormatter* ptr);
+    inline static void operator delete(void* ptr);
+private:
+    CurrencyFormatter() = delete;
+    CurrencyFormatter(const icu4x::CurrencyFormatter&) = delete;
+    CurrencyFormatter(icu4x::CurrencyFormatter&&) noexcept = delete;
+    CurrencyFormatter operator=(const icu4x::CurrencyFormatter&) = delete;
+    CurrencyFormatter operator=(icu4x::CurrencyFormatter&&) noexcept = delete;
+    static void operator delete[](void*, size_t) = delete;
+};
+
+} // namespace
+#endif // icu4x_CurrencyFormatter_D_HPP
@@ -0,0 +1,92 @@
+#ifndef icu4x_CurrencyFormatter_HPP
+#define icu4x_CurrencyFormatter_HPP
+
+#include "CurrencyFormatter.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+#include "CurrencyFormatterWidth.hpp"
+#include "DataError.hpp"
+#include "DataProvider.hpp"
+#include "Decimal.hpp"
+#include "Locale.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    typedef struct icu4x_CurrencyFormatter_create_mv1_result {union {icu4x::capi::CurrencyFormatter* ok; icu4x::capi::DataError err;}; bool is_ok;} icu4x_CurrencyFormatter_create_mv1_result;
+    icu4x_CurrencyFormatter_create_mv1_result icu4x_CurrencyFormatter_create_mv1(const icu4x::capi::Locale* locale, diplomat::capi::DiplomatStringView currency_code, icu4x::capi::CurrencyFormatterWidth width);
+
+    typedef struct icu4x_CurrencyFormatter_create_with_provider_mv1_result {union {icu4x::capi::CurrencyFormatter* ok; icu4x::capi::DataError err;}; bool is_ok;} icu4x_CurrencyFormatter_create_with_provider_mv1_result;
+    icu4x_CurrencyFormatter_create_with_provider_mv1_result icu4x_CurrencyFormatter_create_with_provider_mv1(const icu4x::capi::DataProvider* provider, const icu4x::capi::Locale* locale, diplomat::capi::DiplomatStringView currency_code, icu4x::capi::CurrencyFormatterWidth width);
+
+    void icu4x_CurrencyFormatter_format_mv1(const icu4x::capi::CurrencyFormatter* self, const icu4x::capi::Decimal* value, diplomat::capi::DiplomatWrite* write);
+
+    void icu4x_CurrencyFormatter_destroy_mv1(CurrencyFormatter* self);
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline diplomat::result<std::unique_ptr<icu4x::CurrencyFormatter>, icu4x::DataError> icu4x::CurrencyFormatter::create(const icu4x::Locale& locale, std::string_view currency_code, icu4x::CurrencyFormatterWidth width) {
+    auto result = icu4x::capi::icu4x_CurrencyFormatter_create_mv1(locale.AsFFI(),
+        {currency_code.data(), currency_code.size()},
+        width.AsFFI());
+    return result.is_ok ? diplomat::result<std::unique_ptr<icu4x::CurrencyFormatter>, icu4x::DataError>(diplomat::Ok<std::unique_ptr<icu4x::CurrencyFormatter>>(std::unique_ptr<icu4x::CurrencyFormatter>(icu4x::CurrencyFormatter::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<icu4x::CurrencyFormatter>, icu4x::DataError>(diplomat::Err<icu4x::DataError>(icu4x::DataError::FromFFI(result.err)));
+}
+
+inline diplomat::result<std::unique_ptr<icu4x::CurrencyFormatter>, icu4x::DataError> icu4x::CurrencyFormatter::create_with_provider(const icu4x::DataProvider& provider, const icu4x::Locale& locale, std::string_view currency_code, icu4x::CurrencyFormatterWidth width) {
+    auto result = icu4x::capi::icu4x_CurrencyFormatter_create_with_provider_mv1(provider.AsFFI(),
+        locale.AsFFI(),
+        {currency_code.data(), currency_code.size()},
+        width.AsFFI());
+    return result.is_ok ? diplomat::result<std::unique_ptr<icu4x::CurrencyFormatter>, icu4x::DataError>(diplomat::Ok<std::unique_ptr<icu4x::CurrencyFormatter>>(std::unique_ptr<icu4x::CurrencyFormatter>(icu4x::CurrencyFormatter::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<icu4x::CurrencyFormatter>, icu4x::DataError>(diplomat::Err<icu4x::DataError>(icu4x::DataError::FromFFI(result.err)));
+}
+
+inline std::string icu4x::CurrencyFormatter::format(const icu4x::Decimal& value) const {
+    std::string output;
+    diplomat::capi::DiplomatWrite write = diplomat::WriteFromString(output);
+    icu4x::capi::icu4x_CurrencyFormatter_format_mv1(this->AsFFI(),
+        value.AsFFI(),
+        &write);
+    return output;
+}
+template<typename W>
+inline void icu4x::CurrencyFormatter::format_write(const icu4x::Decimal& value, W& writeable) const {
+    diplomat::capi::DiplomatWrite write = diplomat::WriteTrait<W>::Construct(writeable);
+    icu4x::capi::icu4x_CurrencyFormatter_format_mv1(this->AsFFI(),
+        value.AsFFI(),
+        &write);
+}
+
+inline const icu4x::capi::CurrencyFormatter* icu4x::CurrencyFormatter::AsFFI() const {
+    return reinterpret_cast<const icu4x::capi::CurrencyFormatter*>(this);
+}
+
+inline icu4x::capi::CurrencyFormatter* icu4x::CurrencyFormatter::AsFFI() {
+    return reinterpret_cast<icu4x::capi::CurrencyFormatter*>(this);
+}
+
+inline const icu4x::CurrencyFormatter* icu4x::CurrencyFormatter::FromFFI(const icu4x::capi::CurrencyFormatter* ptr) {
+    return reinterpret_cast<const icu4x::CurrencyFormatter*>(ptr);
+}
+
+inline icu4x::CurrencyFormatter* icu4x::CurrencyFormatter::FromFFI(icu4x::capi::CurrencyFormatter* ptr) {
+    return reinterpret_cast<icu4x::CurrencyFormatter*>(ptr);
+}
+
+inline void icu4x::CurrencyFormatter::operator delete(void* ptr) {
+    icu4x::capi::icu4x_CurrencyFormatter_destroy_mv1(reinterpret_cast<icu4x::capi::CurrencyFormatter*>(ptr));
+}
+
+
+#endif // icu4x_CurrencyFormatter_HPP
@@ -0,0 +1,54 @@
+#ifndef icu4x_CurrencyFormatterWidth_D_HPP
+#define icu4x_CurrencyFormatterWidth_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    enum CurrencyFormatterWidth {
+      CurrencyFormatterWidth_Short = 0,
+      CurrencyFormatterWidth_Narrow = 1,
+    };
+
+    typedef struct CurrencyFormatterWidth_option {union { CurrencyFormatterWidth ok; }; bool is_ok; } CurrencyFormatterWidth_option;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `Width`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/options/enum.Width.html) for more information.
+ */
+class CurrencyFormatterWidth {
+public:
+    enum Value {
+        Short = 0,
+        Narrow = 1,
+    };
+
+    CurrencyFormatterWidth(): value(Value::Short) {}
+
+    // Implicit conversions between enum and ::Value
+    constexpr CurrencyFormatterWidth(Value v) : value(v) {}
+    constexpr operator Value() const { return value; }
+    // Prevent usage as boolean value
+    explicit operator bool() const = delete;
+
+    inline icu4x::capi::CurrencyFormatterWidth AsFFI() const;
+    inline static icu4x::CurrencyFormatterWidth FromFFI(icu4x::capi::CurrencyFormatterWidth c_enum);
+private:
+    Value value;
+};
+
+} // namespace
+#endif // icu4x_CurrencyFormatterWidth_D_HPP
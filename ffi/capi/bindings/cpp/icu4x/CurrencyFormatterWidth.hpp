@@ -0,0 +1,38 @@
+#ifndef icu4x_CurrencyFormatterWidth_HPP
+#define icu4x_CurrencyFormatterWidth_HPP
+
+#include "CurrencyFormatterWidth.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline icu4x::capi::CurrencyFormatterWidth icu4x::CurrencyFormatterWidth::AsFFI() const {
+    return static_cast<icu4x::capi::CurrencyFormatterWidth>(value);
+}
+
+inline icu4x::CurrencyFormatterWidth icu4x::CurrencyFormatterWidth::FromFFI(icu4x::capi::CurrencyFormatterWidth c_enum) {
+    switch (c_enum) {
+        case icu4x::capi::CurrencyFormatterWidth_Short:
+        case icu4x::capi::CurrencyFormatterWidth_Narrow:
+            return static_cast<icu4x::CurrencyFormatterWidth::Value>(c_enum);
+        default:
+            std::abort();
+    }
+}
+#endif // icu4x_CurrencyFormatterWidth_HPP
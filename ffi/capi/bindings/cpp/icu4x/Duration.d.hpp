@@ -0,0 +1,67 @@
+#ifndef icu4x_Duration_D_HPP
+#define icu4x_Duration_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+#include "DurationSign.d.hpp"
+
+namespace icu4x {
+class DurationSign;
+}
+
+
+namespace icu4x {
+namespace capi {
+    struct Duration {
+      icu4x::capi::DurationSign sign;
+      uint64_t years;
+      uint64_t months;
+      uint64_t weeks;
+      uint64_t days;
+      uint64_t hours;
+      uint64_t minutes;
+      uint64_t seconds;
+      uint64_t milliseconds;
+      uint64_t microseconds;
+      uint64_t nanoseconds;
+    };
+
+    typedef struct Duration_option {union { Duration ok; }; bool is_ok; } Duration_option;
+} // namespace capi
+} // namespace
+
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * A duration of time, such as "1 hour, 2 minutes".
+ *
+ * See the [Rust documentation for `Duration`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.Duration.html) for more information.
+ */
+struct Duration {
+    icu4x::DurationSign sign;
+    uint64_t years;
+    uint64_t months;
+    uint64_t weeks;
+    uint64_t days;
+    uint64_t hours;
+    uint64_t minutes;
+    uint64_t seconds;
+    uint64_t milliseconds;
+    uint64_t microseconds;
+    uint64_t nanoseconds;
+
+    inline icu4x::capi::Duration AsFFI() const;
+    inline static icu4x::Duration FromFFI(icu4x::capi::Duration c_struct);
+};
+
+} // namespace
+#endif // icu4x_Duration_D_HPP
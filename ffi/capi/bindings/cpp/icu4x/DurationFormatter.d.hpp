@@ -0,0 +1,95 @@
+#ifndef icu4x_DurationFormatter_D_HPP
+#define icu4x_DurationFormatter_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+namespace icu4x {
+namespace capi { struct DataProvider; }
+class DataProvider;
+namespace capi { struct DurationFormatter; }
+class DurationFormatter;
+namespace capi { struct Locale; }
+class Locale;
+struct Duration;
+class DurationFormatterLoadError;
+class DurationFormatterStyle;
+}
+
+
+namespace icu4x {
+namespace capi {
+    struct DurationFormatter;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Duration Formatter object, capable of formatting a {@link Duration}, such as
+ * "1 hr, 2 min" or "1:02:03".
+ *
+ * See the [Rust documentation for `DurationFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html) for more information.
+ */
+class DurationFormatter {
+public:
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link DurationFormatter} using compiled data.
+     *
+     * `fractional_digits` is the number of fractional digits (0 to 9) used for sub-second
+     * units in the digital style; if absent, as many digits as necessary are shown.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html#method.try_new) for more information.
+   */
+  inline static diplomat::result<std::unique_ptr<icu4x::DurationFormatter>, icu4x::DurationFormatterLoadError> create(const icu4x::Locale& locale, icu4x::DurationFormatterStyle style, std::optional<uint8_t> fractional_digits);
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link DurationFormatter} using a particular data source.
+     *
+     * `fractional_digits` is the number of fractional digits (0 to 9) used for sub-second
+     * units in the digital style; if absent, as many digits as necessary are shown.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html#method.try_new) for more information.
+   */
+  inline static diplomat::result<std::unique_ptr<icu4x::DurationFormatter>, icu4x::DurationFormatterLoadError> create_with_provider(const icu4x::DataProvider& provider, const icu4x::Locale& locale, icu4x::DurationFormatterStyle style, std::optional<uint8_t> fractional_digits);
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link Duration} to a string.
+     *
+     * See the [Rust documentation for `format`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html#method.format) for more information.
+   */
+  inline std::string format(icu4x::Duration duration) const;
+  template<typename W>
+  inline void format_write(icu4x::Duration duration, W& writeable_output) const;
+
+    inline const icu4x::capi::DurationFormatter* AsFFI() const;
+    inline icu4x::capi::DurationFormatter* AsFFI();
+    inline static const icu4x::DurationFormatter* FromFFI(const icu4x::capi::DurationFormatter* ptr);
+    inline static icu4x::DurationFormatter* FromFFI(icu4x::capi::DurationFormatter* ptr);
+    inline static void operator delete(void* ptr);
+private:
+    DurationFormatter() = delete;
+    DurationFormatter(const icu4x::DurationFormatter&) = delete;
+    DurationFormatter(icu4x::DurationFormatter&&) noexcept = delete;
+    DurationFormatter operator=(const icu4x::DurationFormatter&) = delete;
+    DurationFormatter operator=(icu4x::DurationFormatter&&) noexcept = delete;
+    static void operator delete[](void*, size_t) = delete;
+};
+
+} // namespace
+#endif // icu4x_DurationFormatter_D_HPP
@@ -0,0 +1,92 @@
+#ifndef icu4x_DurationFormatter_HPP
+#define icu4x_DurationFormatter_HPP
+
+#include "DurationFormatter.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+#include "DataProvider.hpp"
+#include "Duration.hpp"
+#include "DurationFormatterLoadError.hpp"
+#include "DurationFormatterStyle.hpp"
+#include "Locale.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    typedef struct icu4x_DurationFormatter_create_mv1_result {union {icu4x::capi::DurationFormatter* ok; icu4x::capi::DurationFormatterLoadError err;}; bool is_ok;} icu4x_DurationFormatter_create_mv1_result;
+    icu4x_DurationFormatter_create_mv1_result icu4x_DurationFormatter_create_mv1(const icu4x::capi::Locale* locale, icu4x::capi::DurationFormatterStyle style, diplomat::capi::OptionU8 fractional_digits);
+
+    typedef struct icu4x_DurationFormatter_create_with_provider_mv1_result {union {icu4x::capi::DurationFormatter* ok; icu4x::capi::DurationFormatterLoadError err;}; bool is_ok;} icu4x_DurationFormatter_create_with_provider_mv1_result;
+    icu4x_DurationFormatter_create_with_provider_mv1_result icu4x_DurationFormatter_create_with_provider_mv1(const icu4x::capi::DataProvider* provider, const icu4x::capi::Locale* locale, icu4x::capi::DurationFormatterStyle style, diplomat::capi::OptionU8 fractional_digits);
+
+    void icu4x_DurationFormatter_format_mv1(const icu4x::capi::DurationFormatter* self, icu4x::capi::Duration duration, diplomat::capi::DiplomatWrite* write);
+
+    void icu4x_DurationFormatter_destroy_mv1(DurationFormatter* self);
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline diplomat::result<std::unique_ptr<icu4x::DurationFormatter>, icu4x::DurationFormatterLoadError> icu4x::DurationFormatter::create(const icu4x::Locale& locale, icu4x::DurationFormatterStyle style, std::optional<uint8_t> fractional_digits) {
+    auto result = icu4x::capi::icu4x_DurationFormatter_create_mv1(locale.AsFFI(),
+        style.AsFFI(),
+        fractional_digits.has_value() ? (diplomat::capi::OptionU8{ { fractional_digits.value() }, true }) : (diplomat::capi::OptionU8{ {}, false }));
+    return result.is_ok ? diplomat::result<std::unique_ptr<icu4x::DurationFormatter>, icu4x::DurationFormatterLoadError>(diplomat::Ok<std::unique_ptr<icu4x::DurationFormatter>>(std::unique_ptr<icu4x::DurationFormatter>(icu4x::DurationFormatter::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<icu4x::DurationFormatter>, icu4x::DurationFormatterLoadError>(diplomat::Err<icu4x::DurationFormatterLoadError>(icu4x::DurationFormatterLoadError::FromFFI(result.err)));
+}
+
+inline diplomat::result<std::unique_ptr<icu4x::DurationFormatter>, icu4x::DurationFormatterLoadError> icu4x::DurationFormatter::create_with_provider(const icu4x::DataProvider& provider, const icu4x::Locale& locale, icu4x::DurationFormatterStyle style, std::optional<uint8_t> fractional_digits) {
+    auto result = icu4x::capi::icu4x_DurationFormatter_create_with_provider_mv1(provider.AsFFI(),
+        locale.AsFFI(),
+        style.AsFFI(),
+        fractional_digits.has_value() ? (diplomat::capi::OptionU8{ { fractional_digits.value() }, true }) : (diplomat::capi::OptionU8{ {}, false }));
+    return result.is_ok ? diplomat::result<std::unique_ptr<icu4x::DurationFormatter>, icu4x::DurationFormatterLoadError>(diplomat::Ok<std::unique_ptr<icu4x::DurationFormatter>>(std::unique_ptr<icu4x::DurationFormatter>(icu4x::DurationFormatter::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<icu4x::DurationFormatter>, icu4x::DurationFormatterLoadError>(diplomat::Err<icu4x::DurationFormatterLoadError>(icu4x::DurationFormatterLoadError::FromFFI(result.err)));
+}
+
+inline std::string icu4x::DurationFormatter::format(icu4x::Duration duration) const {
+    std::string output;
+    diplomat::capi::DiplomatWrite write = diplomat::WriteFromString(output);
+    icu4x::capi::icu4x_DurationFormatter_format_mv1(this->AsFFI(),
+        duration.AsFFI(),
+        &write);
+    return output;
+}
+template<typename W>
+inline void icu4x::DurationFormatter::format_write(icu4x::Duration duration, W& writeable) const {
+    diplomat::capi::DiplomatWrite write = diplomat::WriteTrait<W>::Construct(writeable);
+    icu4x::capi::icu4x_DurationFormatter_format_mv1(this->AsFFI(),
+        duration.AsFFI(),
+        &write);
+}
+
+inline const icu4x::capi::DurationFormatter* icu4x::DurationFormatter::AsFFI() const {
+    return reinterpret_cast<const icu4x::capi::DurationFormatter*>(this);
+}
+
+inline icu4x::capi::DurationFormatter* icu4x::DurationFormatter::AsFFI() {
+    return reinterpret_cast<icu4x::capi::DurationFormatter*>(this);
+}
+
+inline const icu4x::DurationFormatter* icu4x::DurationFormatter::FromFFI(const icu4x::capi::DurationFormatter* ptr) {
+    return reinterpret_cast<const icu4x::DurationFormatter*>(ptr);
+}
+
+inline icu4x::DurationFormatter* icu4x::DurationFormatter::FromFFI(icu4x::capi::DurationFormatter* ptr) {
+    return reinterpret_cast<icu4x::DurationFormatter*>(ptr);
+}
+
+inline void icu4x::DurationFormatter::operator delete(void* ptr) {
+    icu4x::capi::icu4x_DurationFormatter_destroy_mv1(reinterpret_cast<icu4x::capi::DurationFormatter*>(ptr));
+}
+
+
+#endif // icu4x_DurationFormatter_HPP
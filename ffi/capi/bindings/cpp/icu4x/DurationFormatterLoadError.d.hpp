@@ -0,0 +1,76 @@
+#ifndef icu4x_DurationFormatterLoadError_D_HPP
+#define icu4x_DurationFormatterLoadError_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    enum DurationFormatterLoadError {
+      DurationFormatterLoadError_Unknown = 0,
+      DurationFormatterLoadError_DisplayAlwaysFractional = 2049,
+      DurationFormatterLoadError_PreviousFractional = 2050,
+      DurationFormatterLoadError_PreviousNumeric = 2051,
+      DurationFormatterLoadError_FractionalDigitsOutOfRange = 2052,
+      DurationFormatterLoadError_DataMarkerNotFound = 1,
+      DurationFormatterLoadError_DataIdentifierNotFound = 2,
+      DurationFormatterLoadError_DataInvalidRequest = 3,
+      DurationFormatterLoadError_DataInconsistentData = 4,
+      DurationFormatterLoadError_DataDowncast = 5,
+      DurationFormatterLoadError_DataDeserialize = 6,
+      DurationFormatterLoadError_DataCustom = 7,
+      DurationFormatterLoadError_DataIo = 8,
+    };
+
+    typedef struct DurationFormatterLoadError_option {union { DurationFormatterLoadError ok; }; bool is_ok; } DurationFormatterLoadError_option;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * Additional information: [1](https://docs.rs/icu/2.0.0/icu/experimental/duration/enum.DurationFormatterOptionsError.html), [2](https://docs.rs/icu_provider/2.0.0/icu_provider/struct.DataError.html), [3](https://docs.rs/icu_provider/2.0.0/icu_provider/enum.DataErrorKind.html)
+ */
+class DurationFormatterLoadError {
+public:
+    enum Value {
+        Unknown = 0,
+        DisplayAlwaysFractional = 2049,
+        PreviousFractional = 2050,
+        PreviousNumeric = 2051,
+        FractionalDigitsOutOfRange = 2052,
+        DataMarkerNotFound = 1,
+        DataIdentifierNotFound = 2,
+        DataInvalidRequest = 3,
+        DataInconsistentData = 4,
+        DataDowncast = 5,
+        DataDeserialize = 6,
+        DataCustom = 7,
+        DataIo = 8,
+    };
+
+    DurationFormatterLoadError(): value(Value::Unknown) {}
+
+    // Implicit conversions between enum and ::Value
+    constexpr DurationFormatterLoadError(Value v) : value(v) {}
+    constexpr operator Value() const { return value; }
+    // Prevent usage as boolean value
+    explicit operator bool() const = delete;
+
+    inline icu4x::capi::DurationFormatterLoadError AsFFI() const;
+    inline static icu4x::DurationFormatterLoadError FromFFI(icu4x::capi::DurationFormatterLoadError c_enum);
+private:
+    Value value;
+};
+
+} // namespace
+#endif // icu4x_DurationFormatterLoadError_D_HPP
@@ -0,0 +1,49 @@
+#ifndef icu4x_DurationFormatterLoadError_HPP
+#define icu4x_DurationFormatterLoadError_HPP
+
+#include "DurationFormatterLoadError.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline icu4x::capi::DurationFormatterLoadError icu4x::DurationFormatterLoadError::AsFFI() const {
+    return static_cast<icu4x::capi::DurationFormatterLoadError>(value);
+}
+
+inline icu4x::DurationFormatterLoadError icu4x::DurationFormatterLoadError::FromFFI(icu4x::capi::DurationFormatterLoadError c_enum) {
+    switch (c_enum) {
+        case icu4x::capi::DurationFormatterLoadError_Unknown:
+        case icu4x::capi::DurationFormatterLoadError_DisplayAlwaysFractional:
+        case icu4x::capi::DurationFormatterLoadError_PreviousFractional:
+        case icu4x::capi::DurationFormatterLoadError_PreviousNumeric:
+        case icu4x::capi::DurationFormatterLoadError_FractionalDigitsOutOfRange:
+        case icu4x::capi::DurationFormatterLoadError_DataMarkerNotFound:
+        case icu4x::capi::DurationFormatterLoadError_DataIdentifierNotFound:
+        case icu4x::capi::DurationFormatterLoadError_DataInvalidRequest:
+        case icu4x::capi::DurationFormatterLoadError_DataInconsistentData:
+        case icu4x::capi::DurationFormatterLoadError_DataDowncast:
+        case icu4x::capi::DurationFormatterLoadError_DataDeserialize:
+        case icu4x::capi::DurationFormatterLoadError_DataCustom:
+        case icu4x::capi::DurationFormatterLoadError_DataIo:
+            return static_cast<icu4x::DurationFormatterLoadError::Value>(c_enum);
+        default:
+            std::abort();
+    }
+}
+#endif // icu4x_DurationFormatterLoadError_HPP
@@ -0,0 +1,58 @@
+#ifndef icu4x_DurationFormatterStyle_D_HPP
+#define icu4x_DurationFormatterStyle_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    enum DurationFormatterStyle {
+      DurationFormatterStyle_Narrow = 0,
+      DurationFormatterStyle_Short = 1,
+      DurationFormatterStyle_Long = 2,
+      DurationFormatterStyle_Digital = 3,
+    };
+
+    typedef struct DurationFormatterStyle_option {union { DurationFormatterStyle ok; }; bool is_ok; } DurationFormatterStyle_option;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `BaseStyle`](https://docs.rs/icu/2.0.0/icu/experimental/duration/options/enum.BaseStyle.html) for more information.
+ */
+class DurationFormatterStyle {
+public:
+    enum Value {
+        Narrow = 0,
+        Short = 1,
+        Long = 2,
+        Digital = 3,
+    };
+
+    DurationFormatterStyle(): value(Value::Short) {}
+
+    // Implicit conversions between enum and ::Value
+    constexpr DurationFormatterStyle(Value v) : value(v) {}
+    constexpr operator Value() const { return value; }
+    // Prevent usage as boolean value
+    explicit operator bool() const = delete;
+
+    inline icu4x::capi::DurationFormatterStyle AsFFI() const;
+    inline static icu4x::DurationFormatterStyle FromFFI(icu4x::capi::DurationFormatterStyle c_enum);
+private:
+    Value value;
+};
+
+} // namespace
+#endif // icu4x_DurationFormatterStyle_D_HPP
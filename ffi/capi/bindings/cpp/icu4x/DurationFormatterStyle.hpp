@@ -0,0 +1,40 @@
+#ifndef icu4x_DurationFormatterStyle_HPP
+#define icu4x_DurationFormatterStyle_HPP
+
+#include "DurationFormatterStyle.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline icu4x::capi::DurationFormatterStyle icu4x::DurationFormatterStyle::AsFFI() const {
+    return static_cast<icu4x::capi::DurationFormatterStyle>(value);
+}
+
+inline icu4x::DurationFormatterStyle icu4x::DurationFormatterStyle::FromFFI(icu4x::capi::DurationFormatterStyle c_enum) {
+    switch (c_enum) {
+        case icu4x::capi::DurationFormatterStyle_Narrow:
+        case icu4x::capi::DurationFormatterStyle_Short:
+        case icu4x::capi::DurationFormatterStyle_Long:
+        case icu4x::capi::DurationFormatterStyle_Digital:
+            return static_cast<icu4x::DurationFormatterStyle::Value>(c_enum);
+        default:
+            std::abort();
+    }
+}
+#endif // icu4x_DurationFormatterStyle_HPP
@@ -0,0 +1,115 @@
+#ifndef icu4x_LocalePriorityList_D_HPP
+#define icu4x_LocalePriorityList_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+namespace icu4x {
+namespace capi { struct Locale; }
+class Locale;
+namespace capi { struct LocalePriorityList; }
+class LocalePriorityList;
+class LocaleParseError;
+}
+
+
+namespace icu4x {
+namespace capi {
+    struct LocalePriorityList;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * A list of locales ordered by preference, such as one parsed from an
+ * HTTP `Accept-Language` header or the `LANGUAGE` environment variable.
+ *
+ * See the [Rust documentation for `LanguagePriorityList`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html) for more information.
+ */
+class LocalePriorityList {
+public:
+
+  /**
+     * Parses an HTTP `Accept-Language` header value, such as `"fr-CH, fr;q=0.9, *;q=0.5"`.
+     *
+     * See the [Rust documentation for `try_from_accept_language`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.try_from_accept_language) for more information.
+   */
+  inline static diplomat::result<std::unique_ptr<icu4x::LocalePriorityList>, icu4x::LocaleParseError> from_accept_language(std::string_view s);
+
+  /**
+     * Parses an HTTP `Accept-Language` header value, skipping or repairing malformed entries.
+     *
+     * See the [Rust documentation for `from_accept_language_lossy`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.from_accept_language_lossy) for more information.
+   */
+  inline static std::unique_ptr<icu4x::LocalePriorityList> from_accept_language_lossy(std::string_view s);
+
+  /**
+     * Parses a colon-separated list of locales, such as the `LANGUAGE` environment variable.
+     *
+     * See the [Rust documentation for `try_from_colon_separated`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.try_from_colon_separated) for more information.
+   */
+  inline static diplomat::result<std::unique_ptr<icu4x::LocalePriorityList>, icu4x::LocaleParseError> from_colon_separated(std::string_view s);
+
+  /**
+     * Parses a colon-separated list of locales, skipping or repairing malformed entries,
+     * such as POSIX locale names like `de_DE.UTF-8`.
+     *
+     * See the [Rust documentation for `from_colon_separated_lossy`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.from_colon_separated_lossy) for more information.
+   */
+  inline static std::unique_ptr<icu4x::LocalePriorityList> from_colon_separated_lossy(std::string_view s);
+
+  /**
+     * The number of entries in this list, including wildcards.
+     *
+     * See the [Rust documentation for `len`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.len) for more information.
+   */
+  inline size_t length() const;
+
+  /**
+     * Returns the locale of the entry at the given index.
+     *
+     * Returns `None` if the index is out of bounds or the entry is the wildcard `*`.
+     *
+     * See the [Rust documentation for `iter`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.iter) for more information.
+   */
+  inline std::unique_ptr<icu4x::Locale> locale_at(size_t index) const;
+
+  /**
+     * Returns whether the entry at the given index is the wildcard `*`.
+     *
+     * See the [Rust documentation for `iter`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.iter) for more information.
+   */
+  inline bool is_wildcard_at(size_t index) const;
+
+  /**
+     * Returns the weight of the entry at the given index, in thousandths between 0 and 1000.
+     *
+     * Returns `None` if the index is out of bounds.
+     *
+     * See the [Rust documentation for `Weight`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.Weight.html) for more information.
+   */
+  inline std::optional<uint16_t> weight_at(size_t index) const;
+
+    inline const icu4x::capi::LocalePriorityList* AsFFI() const;
+    inline icu4x::capi::LocalePriorityList* AsFFI();
+    inline static const icu4x::LocalePriorityList* FromFFI(const icu4x::capi::LocalePriorityList* ptr);
+    inline static icu4x::LocalePriorityList* FromFFI(icu4x::capi::LocalePriorityList* ptr);
+    inline static void operator delete(void* ptr);
+private:
+    LocalePriorityList() = delete;
+    LocalePriorityList(const icu4x::LocalePriorityList&) = delete;
+    LocalePriorityList(icu4x::LocalePriorityList&&) noexcept = delete;
+    LocalePriorityList operator=(const icu4x::LocalePriorityList&) = delete;
+    LocalePriorityList operator=(icu4x::LocalePriorityList&&) noexcept = delete;
+    static void operator delete[](void*, size_t) = delete;
+};
+
+} // namespace
+#endif // icu4x_LocalePriorityList_D_HPP
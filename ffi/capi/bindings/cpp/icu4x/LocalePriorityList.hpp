@@ -0,0 +1,112 @@
+#ifndef icu4x_LocalePriorityList_HPP
+#define icu4x_LocalePriorityList_HPP
+
+#include "LocalePriorityList.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+#include "Locale.hpp"
+#include "LocaleParseError.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    typedef struct icu4x_LocalePriorityList_from_accept_language_mv1_result {union {icu4x::capi::LocalePriorityList* ok; icu4x::capi::LocaleParseError err;}; bool is_ok;} icu4x_LocalePriorityList_from_accept_language_mv1_result;
+    icu4x_LocalePriorityList_from_accept_language_mv1_result icu4x_LocalePriorityList_from_accept_language_mv1(diplomat::capi::DiplomatStringView s);
+
+    icu4x::capi::LocalePriorityList* icu4x_LocalePriorityList_from_accept_language_lossy_mv1(diplomat::capi::DiplomatStringView s);
+
+    typedef struct icu4x_LocalePriorityList_from_colon_separated_mv1_result {union {icu4x::capi::LocalePriorityList* ok; icu4x::capi::LocaleParseError err;}; bool is_ok;} icu4x_LocalePriorityList_from_colon_separated_mv1_result;
+    icu4x_LocalePriorityList_from_colon_separated_mv1_result icu4x_LocalePriorityList_from_colon_separated_mv1(diplomat::capi::DiplomatStringView s);
+
+    icu4x::capi::LocalePriorityList* icu4x_LocalePriorityList_from_colon_separated_lossy_mv1(diplomat::capi::DiplomatStringView s);
+
+    size_t icu4x_LocalePriorityList_length_mv1(const icu4x::capi::LocalePriorityList* self);
+
+    icu4x::capi::Locale* icu4x_LocalePriorityList_locale_at_mv1(const icu4x::capi::LocalePriorityList* self, size_t index);
+
+    bool icu4x_LocalePriorityList_is_wildcard_at_mv1(const icu4x::capi::LocalePriorityList* self, size_t index);
+
+    typedef struct icu4x_LocalePriorityList_weight_at_mv1_result {union {uint16_t ok; }; bool is_ok;} icu4x_LocalePriorityList_weight_at_mv1_result;
+    icu4x_LocalePriorityList_weight_at_mv1_result icu4x_LocalePriorityList_weight_at_mv1(const icu4x::capi::LocalePriorityList* self, size_t index);
+
+    void icu4x_LocalePriorityList_destroy_mv1(LocalePriorityList* self);
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline diplomat::result<std::unique_ptr<icu4x::LocalePriorityList>, icu4x::LocaleParseError> icu4x::LocalePriorityList::from_accept_language(std::string_view s) {
+    auto result = icu4x::capi::icu4x_LocalePriorityList_from_accept_language_mv1({s.data(), s.size()});
+    return result.is_ok ? diplomat::result<std::unique_ptr<icu4x::LocalePriorityList>, icu4x::LocaleParseError>(diplomat::Ok<std::unique_ptr<icu4x::LocalePriorityList>>(std::unique_ptr<icu4x::LocalePriorityList>(icu4x::LocalePriorityList::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<icu4x::LocalePriorityList>, icu4x::LocaleParseError>(diplomat::Err<icu4x::LocaleParseError>(icu4x::LocaleParseError::FromFFI(result.err)));
+}
+
+inline std::unique_ptr<icu4x::LocalePriorityList> icu4x::LocalePriorityList::from_accept_language_lossy(std::string_view s) {
+    auto result = icu4x::capi::icu4x_LocalePriorityList_from_accept_language_lossy_mv1({s.data(), s.size()});
+    return std::unique_ptr<icu4x::LocalePriorityList>(icu4x::LocalePriorityList::FromFFI(result));
+}
+
+inline diplomat::result<std::unique_ptr<icu4x::LocalePriorityList>, icu4x::LocaleParseError> icu4x::LocalePriorityList::from_colon_separated(std::string_view s) {
+    auto result = icu4x::capi::icu4x_LocalePriorityList_from_colon_separated_mv1({s.data(), s.size()});
+    return result.is_ok ? diplomat::result<std::unique_ptr<icu4x::LocalePriorityList>, icu4x::LocaleParseError>(diplomat::Ok<std::unique_ptr<icu4x::LocalePriorityList>>(std::unique_ptr<icu4x::LocalePriorityList>(icu4x::LocalePriorityList::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<icu4x::LocalePriorityList>, icu4x::LocaleParseError>(diplomat::Err<icu4x::LocaleParseError>(icu4x::LocaleParseError::FromFFI(result.err)));
+}
+
+inline std::unique_ptr<icu4x::LocalePriorityList> icu4x::LocalePriorityList::from_colon_separated_lossy(std::string_view s) {
+    auto result = icu4x::capi::icu4x_LocalePriorityList_from_colon_separated_lossy_mv1({s.data(), s.size()});
+    return std::unique_ptr<icu4x::LocalePriorityList>(icu4x::LocalePriorityList::FromFFI(result));
+}
+
+inline size_t icu4x::LocalePriorityList::length() const {
+    auto result = icu4x::capi::icu4x_LocalePriorityList_length_mv1(this->AsFFI());
+    return result;
+}
+
+inline std::unique_ptr<icu4x::Locale> icu4x::LocalePriorityList::locale_at(size_t index) const {
+    auto result = icu4x::capi::icu4x_LocalePriorityList_locale_at_mv1(this->AsFFI(),
+        index);
+    return std::unique_ptr<icu4x::Locale>(icu4x::Locale::FromFFI(result));
+}
+
+inline bool icu4x::LocalePriorityList::is_wildcard_at(size_t index) const {
+    auto result = icu4x::capi::icu4x_LocalePriorityList_is_wildcard_at_mv1(this->AsFFI(),
+        index);
+    return result;
+}
+
+inline std::optional<uint16_t> icu4x::LocalePriorityList::weight_at(size_t index) const {
+    auto result = icu4x::capi::icu4x_LocalePriorityList_weight_at_mv1(this->AsFFI(),
+        index);
+    return result.is_ok ? std::optional<uint16_t>(result.ok) : std::nullopt;
+}
+
+inline const icu4x::capi::LocalePriorityList* icu4x::LocalePriorityList::AsFFI() const {
+    return reinterpret_cast<const icu4x::capi::LocalePriorityList*>(this);
+}
+
+inline icu4x::capi::LocalePriorityList* icu4x::LocalePriorityList::AsFFI() {
+    return reinterpret_cast<icu4x::capi::LocalePriorityList*>(this);
+}
+
+inline const icu4x::LocalePriorityList* icu4x::LocalePriorityList::FromFFI(const icu4x::capi::LocalePriorityList* ptr) {
+    return reinterpret_cast<const icu4x::LocalePriorityList*>(ptr);
+}
+
+inline icu4x::LocalePriorityList* icu4x::LocalePriorityList::FromFFI(icu4x::capi::LocalePriorityList* ptr) {
+    return reinterpret_cast<icu4x::LocalePriorityList*>(ptr);
+}
+
+inline void icu4x::LocalePriorityList::operator delete(void* ptr) {
+    icu4x::capi::icu4x_LocalePriorityList_destroy_mv1(reinterpret_cast<icu4x::capi::LocalePriorityList*>(ptr));
+}
+
+
+#endif // icu4x_LocalePriorityList_HPP
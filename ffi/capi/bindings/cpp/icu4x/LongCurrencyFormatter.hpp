@@ -0,0 +1,89 @@
+#ifndef icu4x_LongCurrencyFormatter_HPP
+#define icu4x_LongCurrencyFormatter_HPP
+
+#include "LongCurrencyFormatter.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+#include "DataError.hpp"
+#include "DataProvider.hpp"
+#include "Decimal.hpp"
+#include "Locale.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    typedef struct icu4x_LongCurrencyFormatter_create_mv1_result {union {icu4x::capi::LongCurrencyFormatter* ok; icu4x::capi::DataError err;}; bool is_ok;} icu4x_LongCurrencyFormatter_create_mv1_result;
+    icu4x_LongCurrencyFormatter_create_mv1_result icu4x_LongCurrencyFormatter_create_mv1(const icu4x::capi::Locale* locale, diplomat::capi::DiplomatStringView currency_code);
+
+    typedef struct icu4x_LongCurrencyFormatter_create_with_provider_mv1_result {union {icu4x::capi::LongCurrencyFormatter* ok; icu4x::capi::DataError err;}; bool is_ok;} icu4x_LongCurrencyFormatter_create_with_provider_mv1_result;
+    icu4x_LongCurrencyFormatter_create_with_provider_mv1_result icu4x_LongCurrencyFormatter_create_with_provider_mv1(const icu4x::capi::DataProvider* provider, const icu4x::capi::Locale* locale, diplomat::capi::DiplomatStringView currency_code);
+
+    void icu4x_LongCurrencyFormatter_format_mv1(const icu4x::capi::LongCurrencyFormatter* self, const icu4x::capi::Decimal* value, diplomat::capi::DiplomatWrite* write);
+
+    void icu4x_LongCurrencyFormatter_destroy_mv1(LongCurrencyFormatter* self);
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline diplomat::result<std::unique_ptr<icu4x::LongCurrencyFormatter>, icu4x::DataError> icu4x::LongCurrencyFormatter::create(const icu4x::Locale& locale, std::string_view currency_code) {
+    auto result = icu4x::capi::icu4x_LongCurrencyFormatter_create_mv1(locale.AsFFI(),
+        {currency_code.data(), currency_code.size()});
+    return result.is_ok ? diplomat::result<std::unique_ptr<icu4x::LongCurrencyFormatter>, icu4x::DataError>(diplomat::Ok<std::unique_ptr<icu4x::LongCurrencyFormatter>>(std::unique_ptr<icu4x::LongCurrencyFormatter>(icu4x::LongCurrencyFormatter::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<icu4x::LongCurrencyFormatter>, icu4x::DataError>(diplomat::Err<icu4x::DataError>(icu4x::DataError::FromFFI(result.err)));
+}
+
+inline diplomat::result<std::unique_ptr<icu4x::LongCurrencyFormatter>, icu4x::DataError> icu4x::LongCurrencyFormatter::create_with_provider(const icu4x::DataProvider& provider, const icu4x::Locale& locale, std::string_view currency_code) {
+    auto result = icu4x::capi::icu4x_LongCurrencyFormatter_create_with_provider_mv1(provider.AsFFI(),
+        locale.AsFFI(),
+        {currency_code.data(), currency_code.size()});
+    return result.is_ok ? diplomat::result<std::unique_ptr<icu4x::LongCurrencyFormatter>, icu4x::DataError>(diplomat::Ok<std::unique_ptr<icu4x::LongCurrencyFormatter>>(std::unique_ptr<icu4x::LongCurrencyFormatter>(icu4x::LongCurrencyFormatter::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<icu4x::LongCurrencyFormatter>, icu4x::DataError>(diplomat::Err<icu4x::DataError>(icu4x::DataError::FromFFI(result.err)));
+}
+
+inline std::string icu4x::LongCurrencyFormatter::format(const icu4x::Decimal& value) const {
+    std::string output;
+    diplomat::capi::DiplomatWrite write = diplomat::WriteFromString(output);
+    icu4x::capi::icu4x_LongCurrencyFormatter_format_mv1(this->AsFFI(),
+        value.AsFFI(),
+        &write);
+    return output;
+}
+template<typename W>
+inline void icu4x::LongCurrencyFormatter::format_write(const icu4x::Decimal& value, W& writeable) const {
+    diplomat::capi::DiplomatWrite write = diplomat::WriteTrait<W>::Construct(writeable);
+    icu4x::capi::icu4x_LongCurrencyFormatter_format_mv1(this->AsFFI(),
+        value.AsFFI(),
+        &write);
+}
+
+inline const icu4x::capi::LongCurrencyFormatter* icu4x::LongCurrencyFormatter::AsFFI() const {
+    return reinterpret_cast<const icu4x::capi::LongCurrencyFormatter*>(this);
+}
+
+inline icu4x::capi::LongCurrencyFormatter* icu4x::LongCurrencyFormatter::AsFFI() {
+    return reinterpret_cast<icu4x::capi::LongCurrencyFormatter*>(this);
+}
+
+inline const icu4x::LongCurrencyFormatter* icu4x::LongCurrencyFormatter::FromFFI(const icu4x::capi::LongCurrencyFormatter* ptr) {
+    return reinterpret_cast<const icu4x::LongCurrencyFormatter*>(ptr);
+}
+
+inline icu4x::LongCurrencyFormatter* icu4x::LongCurrencyFormatter::FromFFI(icu4x::capi::LongCurrencyFormatter* ptr) {
+    return reinterpret_cast<icu4x::LongCurrencyFormatter*>(ptr);
+}
+
+inline void icu4x::LongCurrencyFormatter::operator delete(void* ptr) {
+    icu4x::capi::icu4x_LongCurrencyFormatter_destroy_mv1(reinterpret_cast<icu4x::capi::LongCurrencyFormatter*>(ptr));
+}
+
+
+#endif // icu4x_LongCurrencyFormatter_HPP
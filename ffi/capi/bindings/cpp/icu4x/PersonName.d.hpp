@@ -0,0 +1,87 @@
+#ifndef icu4x_PersonName_D_HPP
+#define icu4x_PersonName_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+namespace icu4x {
+namespace capi { struct Locale; }
+class Locale;
+namespace capi { struct PersonName; }
+class PersonName;
+class PersonNameFieldKind;
+class PersonNamePreferredOrder;
+}
+
+
+namespace icu4x {
+namespace capi {
+    struct PersonName;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * A person name to be formatted with a {@link PersonNamesFormatter}, built up field by field.
+ *
+ * See the [Rust documentation for `DefaultPersonName`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/provided_struct/struct.DefaultPersonName.html) for more information.
+ */
+class PersonName {
+public:
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates an empty {@link PersonName}.
+   */
+  inline static std::unique_ptr<icu4x::PersonName> create();
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Sets the value of a name field, replacing any previous value.
+     *
+     * Ill-formed input is treated as if errors had been replaced with REPLACEMENT CHARACTERs according
+     * to the WHATWG Encoding Standard.
+   */
+  inline void set_field(icu4x::PersonNameFieldKind kind, std::string_view value);
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Sets the locale of the name itself, which may differ from the formatting locale.
+   */
+  inline void set_locale(const icu4x::Locale& locale);
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Sets the order in which the name prefers its fields to be displayed.
+   */
+  inline void set_preferred_order(icu4x::PersonNamePreferredOrder order);
+
+    inline const icu4x::capi::PersonName* AsFFI() const;
+    inline icu4x::capi::PersonName* AsFFI();
+    inline static const icu4x::PersonName* FromFFI(const icu4x::capi::PersonName* ptr);
+    inline static icu4x::PersonName* FromFFI(icu4x::capi::PersonName* ptr);
+    inline static void operator delete(void* ptr);
+private:
+    PersonName() = delete;
+    PersonName(const icu4x::PersonName&) = delete;
+    PersonName(icu4x::PersonName&&) noexcept = delete;
+    PersonName operator=(const icu4x::PersonName&) = delete;
+    PersonName operator=(icu4x::PersonName&&) noexcept = delete;
+    static void operator delete[](void*, size_t) = delete;
+};
+
+} // namespace
+#endif // icu4x_PersonName_D_HPP
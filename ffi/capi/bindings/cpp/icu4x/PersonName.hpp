@@ -0,0 +1,80 @@
+#ifndef icu4x_PersonName_HPP
+#define icu4x_PersonName_HPP
+
+#include "PersonName.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+#include "Locale.hpp"
+#include "PersonNameFieldKind.hpp"
+#include "PersonNamePreferredOrder.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    icu4x::capi::PersonName* icu4x_PersonName_create_mv1(void);
+
+    void icu4x_PersonName_set_field_mv1(icu4x::capi::PersonName* self, icu4x::capi::PersonNameFieldKind kind, diplomat::capi::DiplomatStringView value);
+
+    void icu4x_PersonName_set_locale_mv1(icu4x::capi::PersonName* self, const icu4x::capi::Locale* locale);
+
+    void icu4x_PersonName_set_preferred_order_mv1(icu4x::capi::PersonName* self, icu4x::capi::PersonNamePreferredOrder order);
+
+    void icu4x_PersonName_destroy_mv1(PersonName* self);
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline std::unique_ptr<icu4x::PersonName> icu4x::PersonName::create() {
+    auto result = icu4x::capi::icu4x_PersonName_create_mv1();
+    return std::unique_ptr<icu4x::PersonName>(icu4x::PersonName::FromFFI(result));
+}
+
+inline void icu4x::PersonName::set_field(icu4x::PersonNameFieldKind kind, std::string_view value) {
+    icu4x::capi::icu4x_PersonName_set_field_mv1(this->AsFFI(),
+        kind.AsFFI(),
+        {value.data(), value.size()});
+}
+
+inline void icu4x::PersonName::set_locale(const icu4x::Locale& locale) {
+    icu4x::capi::icu4x_PersonName_set_locale_mv1(this->AsFFI(),
+        locale.AsFFI());
+}
+
+inline void icu4x::PersonName::set_preferred_order(icu4x::PersonNamePreferredOrder order) {
+    icu4x::capi::icu4x_PersonName_set_preferred_order_mv1(this->AsFFI(),
+        order.AsFFI());
+}
+
+inline const icu4x::capi::PersonName* icu4x::PersonName::AsFFI() const {
+    return reinterpret_cast<const icu4x::capi::PersonName*>(this);
+}
+
+inline icu4x::capi::PersonName* icu4x::PersonName::AsFFI() {
+    return reinterpret_cast<icu4x::capi::PersonName*>(this);
+}
+
+inline const icu4x::PersonName* icu4x::PersonName::FromFFI(const icu4x::capi::PersonName* ptr) {
+    return reinterpret_cast<const icu4x::PersonName*>(ptr);
+}
+
+inline icu4x::PersonName* icu4x::PersonName::FromFFI(icu4x::capi::PersonName* ptr) {
+    return reinterpret_cast<icu4x::PersonName*>(ptr);
+}
+
+inline void icu4x::PersonName::operator delete(void* ptr) {
+    icu4x::capi::icu4x_PersonName_destroy_mv1(reinterpret_cast<icu4x::capi::PersonName*>(ptr));
+}
+
+
+#endif // icu4x_PersonName_HPP
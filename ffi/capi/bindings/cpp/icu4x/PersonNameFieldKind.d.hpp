@@ -0,0 +1,64 @@
+#ifndef icu4x_PersonNameFieldKind_D_HPP
+#define icu4x_PersonNameFieldKind_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    enum PersonNameFieldKind {
+      PersonNameFieldKind_Title = 0,
+      PersonNameFieldKind_Given = 1,
+      PersonNameFieldKind_Given2 = 2,
+      PersonNameFieldKind_Surname = 3,
+      PersonNameFieldKind_Surname2 = 4,
+      PersonNameFieldKind_Generation = 5,
+      PersonNameFieldKind_Credentials = 6,
+    };
+
+    typedef struct PersonNameFieldKind_option {union { PersonNameFieldKind ok; }; bool is_ok; } PersonNameFieldKind_option;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `NameFieldKind`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.NameFieldKind.html) for more information.
+ */
+class PersonNameFieldKind {
+public:
+    enum Value {
+        Title = 0,
+        Given = 1,
+        Given2 = 2,
+        Surname = 3,
+        Surname2 = 4,
+        Generation = 5,
+        Credentials = 6,
+    };
+
+    PersonNameFieldKind(): value(Value::Title) {}
+
+    // Implicit conversions between enum and ::Value
+    constexpr PersonNameFieldKind(Value v) : value(v) {}
+    constexpr operator Value() const { return value; }
+    // Prevent usage as boolean value
+    explicit operator bool() const = delete;
+
+    inline icu4x::capi::PersonNameFieldKind AsFFI() const;
+    inline static icu4x::PersonNameFieldKind FromFFI(icu4x::capi::PersonNameFieldKind c_enum);
+private:
+    Value value;
+};
+
+} // namespace
+#endif // icu4x_PersonNameFieldKind_D_HPP
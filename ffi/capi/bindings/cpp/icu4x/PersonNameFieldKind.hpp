@@ -0,0 +1,43 @@
+#ifndef icu4x_PersonNameFieldKind_HPP
+#define icu4x_PersonNameFieldKind_HPP
+
+#include "PersonNameFieldKind.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline icu4x::capi::PersonNameFieldKind icu4x::PersonNameFieldKind::AsFFI() const {
+    return static_cast<icu4x::capi::PersonNameFieldKind>(value);
+}
+
+inline icu4x::PersonNameFieldKind icu4x::PersonNameFieldKind::FromFFI(icu4x::capi::PersonNameFieldKind c_enum) {
+    switch (c_enum) {
+        case icu4x::capi::PersonNameFieldKind_Title:
+        case icu4x::capi::PersonNameFieldKind_Given:
+        case icu4x::capi::PersonNameFieldKind_Given2:
+        case icu4x::capi::PersonNameFieldKind_Surname:
+        case icu4x::capi::PersonNameFieldKind_Surname2:
+        case icu4x::capi::PersonNameFieldKind_Generation:
+        case icu4x::capi::PersonNameFieldKind_Credentials:
+            return static_cast<icu4x::PersonNameFieldKind::Value>(c_enum);
+        default:
+            std::abort();
+    }
+}
+#endif // icu4x_PersonNameFieldKind_HPP
@@ -0,0 +1,56 @@
+#ifndef icu4x_PersonNamePreferredOrder_D_HPP
+#define icu4x_PersonNamePreferredOrder_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    enum PersonNamePreferredOrder {
+      PersonNamePreferredOrder_Default = 0,
+      PersonNamePreferredOrder_GivenFirst = 1,
+      PersonNamePreferredOrder_SurnameFirst = 2,
+    };
+
+    typedef struct PersonNamePreferredOrder_option {union { PersonNamePreferredOrder ok; }; bool is_ok; } PersonNamePreferredOrder_option;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `PreferredOrder`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.PreferredOrder.html) for more information.
+ */
+class PersonNamePreferredOrder {
+public:
+    enum Value {
+        Default = 0,
+        GivenFirst = 1,
+        SurnameFirst = 2,
+    };
+
+    PersonNamePreferredOrder(): value(Value::Default) {}
+
+    // Implicit conversions between enum and ::Value
+    constexpr PersonNamePreferredOrder(Value v) : value(v) {}
+    constexpr operator Value() const { return value; }
+    // Prevent usage as boolean value
+    explicit operator bool() const = delete;
+
+    inline icu4x::capi::PersonNamePreferredOrder AsFFI() const;
+    inline static icu4x::PersonNamePreferredOrder FromFFI(icu4x::capi::PersonNamePreferredOrder c_enum);
+private:
+    Value value;
+};
+
+} // namespace
+#endif // icu4x_PersonNamePreferredOrder_D_HPP
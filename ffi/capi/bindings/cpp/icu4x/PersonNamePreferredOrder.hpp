@@ -0,0 +1,39 @@
+#ifndef icu4x_PersonNamePreferredOrder_HPP
+#define icu4x_PersonNamePreferredOrder_HPP
+
+#include "PersonNamePreferredOrder.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline icu4x::capi::PersonNamePreferredOrder icu4x::PersonNamePreferredOrder::AsFFI() const {
+    return static_cast<icu4x::capi::PersonNamePreferredOrder>(value);
+}
+
+inline icu4x::PersonNamePreferredOrder icu4x::PersonNamePreferredOrder::FromFFI(icu4x::capi::PersonNamePreferredOrder c_enum) {
+    switch (c_enum) {
+        case icu4x::capi::PersonNamePreferredOrder_Default:
+        case icu4x::capi::PersonNamePreferredOrder_GivenFirst:
+        case icu4x::capi::PersonNamePreferredOrder_SurnameFirst:
+            return static_cast<icu4x::PersonNamePreferredOrder::Value>(c_enum);
+        default:
+            std::abort();
+    }
+}
+#endif // icu4x_PersonNamePreferredOrder_HPP
@@ -0,0 +1,106 @@
+#ifndef icu4x_PersonNamesFormatter_D_HPP
+#define icu4x_PersonNamesFormatter_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+namespace icu4x {
+namespace capi { struct DataProvider; }
+class DataProvider;
+namespace capi { struct Locale; }
+class Locale;
+namespace capi { struct PersonName; }
+class PersonName;
+namespace capi { struct PersonNamesFormatter; }
+class PersonNamesFormatter;
+class PersonNamesFormatterError;
+class PersonNamesFormatterFormality;
+class PersonNamesFormatterLength;
+class PersonNamesFormatterOrder;
+class PersonNamesFormatterUsage;
+}
+
+
+namespace icu4x {
+namespace capi {
+    struct PersonNamesFormatter;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Person Names Formatter object, capable of formatting a {@link PersonName}
+ * according to the conventions of a locale, such as "Wells, H. G.".
+ *
+ * See the [Rust documentation for `PersonNamesFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html) for more information.
+ */
+class PersonNamesFormatter {
+public:
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link PersonNamesFormatter} using compiled data.
+     *
+     * See the [Rust documentation for `try_new_unstable`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.try_new_unstable) for more information.
+   */
+  inline static diplomat::result<std::unique_ptr<icu4x::PersonNamesFormatter>, icu4x::PersonNamesFormatterError> create(const icu4x::Locale& locale, icu4x::PersonNamesFormatterOrder order, icu4x::PersonNamesFormatterLength length, icu4x::PersonNamesFormatterUsage usage, icu4x::PersonNamesFormatterFormality formality);
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link PersonNamesFormatter} using a particular data source.
+     *
+     * Names must then be formatted with {@link Self::format_with_provider}, using the same data source.
+     *
+     * See the [Rust documentation for `try_new_unstable`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.try_new_unstable) for more information.
+   */
+  inline static diplomat::result<std::unique_ptr<icu4x::PersonNamesFormatter>, icu4x::PersonNamesFormatterError> create_with_provider(const icu4x::DataProvider& provider, const icu4x::Locale& locale, icu4x::PersonNamesFormatterOrder order, icu4x::PersonNamesFormatterLength length, icu4x::PersonNamesFormatterUsage usage, icu4x::PersonNamesFormatterFormality formality);
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link PersonName} to a string, using compiled data.
+     *
+     * See the [Rust documentation for `format_to_string`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.format_to_string) for more information.
+   */
+  inline diplomat::result<std::string, icu4x::PersonNamesFormatterError> format(const icu4x::PersonName& name) const;
+  template<typename W>
+  inline diplomat::result<std::monostate, icu4x::PersonNamesFormatterError> format_write(const icu4x::PersonName& name, W& writeable_output) const;
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link PersonName} to a string, using a particular data source.
+     *
+     * See the [Rust documentation for `format_to_string`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.format_to_string) for more information.
+   */
+  inline diplomat::result<std::string, icu4x::PersonNamesFormatterError> format_with_provider(const icu4x::DataProvider& provider, const icu4x::PersonName& name) const;
+  template<typename W>
+  inline diplomat::result<std::monostate, icu4x::PersonNamesFormatterError> format_with_provider_write(const icu4x::DataProvider& provider, const icu4x::PersonName& name, W& writeable_output) const;
+
+    inline const icu4x::capi::PersonNamesFormatter* AsFFI() const;
+    inline icu4x::capi::PersonNamesFormatter* AsFFI();
+    inline static const icu4x::PersonNamesFormatter* FromFFI(const icu4x::capi::PersonNamesFormatter* ptr);
+    inline static icu4x::PersonNamesFormatter* FromFFI(icu4x::capi::PersonNamesFormatter* ptr);
+    inline static void operator delete(void* ptr);
+private:
+    PersonNamesFormatter() = delete;
+    PersonNamesFormatter(const icu4x::PersonNamesFormatter&) = delete;
+    PersonNamesFormatter(icu4x::PersonNamesFormatter&&) noexcept = delete;
+    PersonNamesFormatter operator=(const icu4x::PersonNamesFormatter&) = delete;
+    PersonNamesFormatter operator=(icu4x::PersonNamesFormatter&&) noexcept = delete;
+    static void operator delete[](void*, size_t) = delete;
+};
+
+} // namespace
+#endif // icu4x_PersonNamesFormatter_D_HPP
@@ -0,0 +1,123 @@
+#ifndef icu4x_PersonNamesFormatter_HPP
+#define icu4x_PersonNamesFormatter_HPP
+
+#include "PersonNamesFormatter.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+#include "DataProvider.hpp"
+#include "Locale.hpp"
+#include "PersonName.hpp"
+#include "PersonNamesFormatterError.hpp"
+#include "PersonNamesFormatterFormality.hpp"
+#include "PersonNamesFormatterLength.hpp"
+#include "PersonNamesFormatterOrder.hpp"
+#include "PersonNamesFormatterUsage.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    typedef struct icu4x_PersonNamesFormatter_create_mv1_result {union {icu4x::capi::PersonNamesFormatter* ok; icu4x::capi::PersonNamesFormatterError err;}; bool is_ok;} icu4x_PersonNamesFormatter_create_mv1_result;
+    icu4x_PersonNamesFormatter_create_mv1_result icu4x_PersonNamesFormatter_create_mv1(const icu4x::capi::Locale* locale, icu4x::capi::PersonNamesFormatterOrder order, icu4x::capi::PersonNamesFormatterLength length, icu4x::capi::PersonNamesFormatterUsage usage, icu4x::capi::PersonNamesFormatterFormality formality);
+
+    typedef struct icu4x_PersonNamesFormatter_create_with_provider_mv1_result {union {icu4x::capi::PersonNamesFormatter* ok; icu4x::capi::PersonNamesFormatterError err;}; bool is_ok;} icu4x_PersonNamesFormatter_create_with_provider_mv1_result;
+    icu4x_PersonNamesFormatter_create_with_provider_mv1_result icu4x_PersonNamesFormatter_create_with_provider_mv1(const icu4x::capi::DataProvider* provider, const icu4x::capi::Locale* locale, icu4x::capi::PersonNamesFormatterOrder order, icu4x::capi::PersonNamesFormatterLength length, icu4x::capi::PersonNamesFormatterUsage usage, icu4x::capi::PersonNamesFormatterFormality formality);
+
+    typedef struct icu4x_PersonNamesFormatter_format_mv1_result {union { icu4x::capi::PersonNamesFormatterError err;}; bool is_ok;} icu4x_PersonNamesFormatter_format_mv1_result;
+    icu4x_PersonNamesFormatter_format_mv1_result icu4x_PersonNamesFormatter_format_mv1(const icu4x::capi::PersonNamesFormatter* self, const icu4x::capi::PersonName* name, diplomat::capi::DiplomatWrite* write);
+
+    typedef struct icu4x_PersonNamesFormatter_format_with_provider_mv1_result {union { icu4x::capi::PersonNamesFormatterError err;}; bool is_ok;} icu4x_PersonNamesFormatter_format_with_provider_mv1_result;
+    icu4x_PersonNamesFormatter_format_with_provider_mv1_result icu4x_PersonNamesFormatter_format_with_provider_mv1(const icu4x::capi::PersonNamesFormatter* self, const icu4x::capi::DataProvider* provider, const icu4x::capi::PersonName* name, diplomat::capi::DiplomatWrite* write);
+
+    void icu4x_PersonNamesFormatter_destroy_mv1(PersonNamesFormatter* self);
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline diplomat::result<std::unique_ptr<icu4x::PersonNamesFormatter>, icu4x::PersonNamesFormatterError> icu4x::PersonNamesFormatter::create(const icu4x::Locale& locale, icu4x::PersonNamesFormatterOrder order, icu4x::PersonNamesFormatterLength length, icu4x::PersonNamesFormatterUsage usage, icu4x::PersonNamesFormatterFormality formality) {
+    auto result = icu4x::capi::icu4x_PersonNamesFormatter_create_mv1(locale.AsFFI(),
+        order.AsFFI(),
+        length.AsFFI(),
+        usage.AsFFI(),
+        formality.AsFFI());
+    return result.is_ok ? diplomat::result<std::unique_ptr<icu4x::PersonNamesFormatter>, icu4x::PersonNamesFormatterError>(diplomat::Ok<std::unique_ptr<icu4x::PersonNamesFormatter>>(std::unique_ptr<icu4x::PersonNamesFormatter>(icu4x::PersonNamesFormatter::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<icu4x::PersonNamesFormatter>, icu4x::PersonNamesFormatterError>(diplomat::Err<icu4x::PersonNamesFormatterError>(icu4x::PersonNamesFormatterError::FromFFI(result.err)));
+}
+
+inline diplomat::result<std::unique_ptr<icu4x::PersonNamesFormatter>, icu4x::PersonNamesFormatterError> icu4x::PersonNamesFormatter::create_with_provider(const icu4x::DataProvider& provider, const icu4x::Locale& locale, icu4x::PersonNamesFormatterOrder order, icu4x::PersonNamesFormatterLength length, icu4x::PersonNamesFormatterUsage usage, icu4x::PersonNamesFormatterFormality formality) {
+    auto result = icu4x::capi::icu4x_PersonNamesFormatter_create_with_provider_mv1(provider.AsFFI(),
+        locale.AsFFI(),
+        order.AsFFI(),
+        length.AsFFI(),
+        usage.AsFFI(),
+        formality.AsFFI());
+    return result.is_ok ? diplomat::result<std::unique_ptr<icu4x::PersonNamesFormatter>, icu4x::PersonNamesFormatterError>(diplomat::Ok<std::unique_ptr<icu4x::PersonNamesFormatter>>(std::unique_ptr<icu4x::PersonNamesFormatter>(icu4x::PersonNamesFormatter::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<icu4x::PersonNamesFormatter>, icu4x::PersonNamesFormatterError>(diplomat::Err<icu4x::PersonNamesFormatterError>(icu4x::PersonNamesFormatterError::FromFFI(result.err)));
+}
+
+inline diplomat::result<std::string, icu4x::PersonNamesFormatterError> icu4x::PersonNamesFormatter::format(const icu4x::PersonName& name) const {
+    std::string output;
+    diplomat::capi::DiplomatWrite write = diplomat::WriteFromString(output);
+    auto result = icu4x::capi::icu4x_PersonNamesFormatter_format_mv1(this->AsFFI(),
+        name.AsFFI(),
+        &write);
+    return result.is_ok ? diplomat::result<std::string, icu4x::PersonNamesFormatterError>(diplomat::Ok<std::string>(std::move(output))) : diplomat::result<std::string, icu4x::PersonNamesFormatterError>(diplomat::Err<icu4x::PersonNamesFormatterError>(icu4x::PersonNamesFormatterError::FromFFI(result.err)));
+}
+template<typename W>
+inline diplomat::result<std::monostate, icu4x::PersonNamesFormatterError> icu4x::PersonNamesFormatter::format_write(const icu4x::PersonName& name, W& writeable) const {
+    diplomat::capi::DiplomatWrite write = diplomat::WriteTrait<W>::Construct(writeable);
+    auto result = icu4x::capi::icu4x_PersonNamesFormatter_format_mv1(this->AsFFI(),
+        name.AsFFI(),
+        &write);
+    return result.is_ok ? diplomat::result<std::monostate, icu4x::PersonNamesFormatterError>(diplomat::Ok<std::monostate>()) : diplomat::result<std::monostate, icu4x::PersonNamesFormatterError>(diplomat::Err<icu4x::PersonNamesFormatterError>(icu4x::PersonNamesFormatterError::FromFFI(result.err)));
+}
+
+inline diplomat::result<std::string, icu4x::PersonNamesFormatterError> icu4x::PersonNamesFormatter::format_with_provider(const icu4x::DataProvider& provider, const icu4x::PersonName& name) const {
+    std::string output;
+    diplomat::capi::DiplomatWrite write = diplomat::WriteFromString(output);
+    auto result = icu4x::capi::icu4x_PersonNamesFormatter_format_with_provider_mv1(this->AsFFI(),
+        provider.AsFFI(),
+        name.AsFFI(),
+        &write);
+    return result.is_ok ? diplomat::result<std::string, icu4x::PersonNamesFormatterError>(diplomat::Ok<std::string>(std::move(output))) : diplomat::result<std::string, icu4x::PersonNamesFormatterError>(diplomat::Err<icu4x::PersonNamesFormatterError>(icu4x::PersonNamesFormatterError::FromFFI(result.err)));
+}
+template<typename W>
+inline diplomat::result<std::monostate, icu4x::PersonNamesFormatterError> icu4x::PersonNamesFormatter::format_with_provider_write(const icu4x::DataProvider& provider, const icu4x::PersonName& name, W& writeable) const {
+    diplomat::capi::DiplomatWrite write = diplomat::WriteTrait<W>::Construct(writeable);
+    auto result = icu4x::capi::icu4x_PersonNamesFormatter_format_with_provider_mv1(this->AsFFI(),
+        provider.AsFFI(),
+        name.AsFFI(),
+        &write);
+    return result.is_ok ? diplomat::result<std::monostate, icu4x::PersonNamesFormatterError>(diplomat::Ok<std::monostate>()) : diplomat::result<std::monostate, icu4x::PersonNamesFormatterError>(diplomat::Err<icu4x::PersonNamesFormatterError>(icu4x::PersonNamesFormatterError::FromFFI(result.err)));
+}
+
+inline const icu4x::capi::PersonNamesFormatter* icu4x::PersonNamesFormatter::AsFFI() const {
+    return reinterpret_cast<const icu4x::capi::PersonNamesFormatter*>(this);
+}
+
+inline icu4x::capi::PersonNamesFormatter* icu4x::PersonNamesFormatter::AsFFI() {
+    return reinterpret_cast<icu4x::capi::PersonNamesFormatter*>(this);
+}
+
+inline const icu4x::PersonNamesFormatter* icu4x::PersonNamesFormatter::FromFFI(const icu4x::capi::PersonNamesFormatter* ptr) {
+    return reinterpret_cast<const icu4x::PersonNamesFormatter*>(ptr);
+}
+
+inline icu4x::PersonNamesFormatter* icu4x::PersonNamesFormatter::FromFFI(icu4x::capi::PersonNamesFormatter* ptr) {
+    return reinterpret_cast<icu4x::PersonNamesFormatter*>(ptr);
+}
+
+inline void icu4x::PersonNamesFormatter::operator delete(void* ptr) {
+    icu4x::capi::icu4x_PersonNamesFormatter_destroy_mv1(reinterpret_cast<icu4x::capi::PersonNamesFormatter*>(ptr));
+}
+
+
+#endif // icu4x_PersonNamesFormatter_HPP
@@ -0,0 +1,78 @@
+#ifndef icu4x_PersonNamesFormatterError_D_HPP
+#define icu4x_PersonNamesFormatterError_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    enum PersonNamesFormatterError {
+      PersonNamesFormatterError_Unknown = 0,
+      PersonNamesFormatterError_Parse = 2049,
+      PersonNamesFormatterError_InvalidPersonName = 2050,
+      PersonNamesFormatterError_InvalidLocale = 2051,
+      PersonNamesFormatterError_InvalidCldrData = 2052,
+      PersonNamesFormatterError_Pattern = 2053,
+      PersonNamesFormatterError_DataMarkerNotFound = 1,
+      PersonNamesFormatterError_DataIdentifierNotFound = 2,
+      PersonNamesFormatterError_DataInvalidRequest = 3,
+      PersonNamesFormatterError_DataInconsistentData = 4,
+      PersonNamesFormatterError_DataDowncast = 5,
+      PersonNamesFormatterError_DataDeserialize = 6,
+      PersonNamesFormatterError_DataCustom = 7,
+      PersonNamesFormatterError_DataIo = 8,
+    };
+
+    typedef struct PersonNamesFormatterError_option {union { PersonNamesFormatterError ok; }; bool is_ok; } PersonNamesFormatterError_option;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * Additional information: [1](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.PersonNamesFormatterError.html), [2](https://docs.rs/icu_provider/2.0.0/icu_provider/struct.DataError.html), [3](https://docs.rs/icu_provider/2.0.0/icu_provider/enum.DataErrorKind.html)
+ */
+class PersonNamesFormatterError {
+public:
+    enum Value {
+        Unknown = 0,
+        Parse = 2049,
+        InvalidPersonName = 2050,
+        InvalidLocale = 2051,
+        InvalidCldrData = 2052,
+        Pattern = 2053,
+        DataMarkerNotFound = 1,
+        DataIdentifierNotFound = 2,
+        DataInvalidRequest = 3,
+        DataInconsistentData = 4,
+        DataDowncast = 5,
+        DataDeserialize = 6,
+        DataCustom = 7,
+        DataIo = 8,
+    };
+
+    PersonNamesFormatterError(): value(Value::Unknown) {}
+
+    // Implicit conversions between enum and ::Value
+    constexpr PersonNamesFormatterError(Value v) : value(v) {}
+    constexpr operator Value() const { return value; }
+    // Prevent usage as boolean value
+    explicit operator bool() const = delete;
+
+    inline icu4x::capi::PersonNamesFormatterError AsFFI() const;
+    inline static icu4x::PersonNamesFormatterError FromFFI(icu4x::capi::PersonNamesFormatterError c_enum);
+private:
+    Value value;
+};
+
+} // namespace
+#endif // icu4x_PersonNamesFormatterError_D_HPP
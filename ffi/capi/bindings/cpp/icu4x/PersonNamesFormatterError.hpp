@@ -0,0 +1,50 @@
+#ifndef icu4x_PersonNamesFormatterError_HPP
+#define icu4x_PersonNamesFormatterError_HPP
+
+#include "PersonNamesFormatterError.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline icu4x::capi::PersonNamesFormatterError icu4x::PersonNamesFormatterError::AsFFI() const {
+    return static_cast<icu4x::capi::PersonNamesFormatterError>(value);
+}
+
+inline icu4x::PersonNamesFormatterError icu4x::PersonNamesFormatterError::FromFFI(icu4x::capi::PersonNamesFormatterError c_enum) {
+    switch (c_enum) {
+        case icu4x::capi::PersonNamesFormatterError_Unknown:
+        case icu4x::capi::PersonNamesFormatterError_Parse:
+        case icu4x::capi::PersonNamesFormatterError_InvalidPersonName:
+        case icu4x::capi::PersonNamesFormatterError_InvalidLocale:
+        case icu4x::capi::PersonNamesFormatterError_InvalidCldrData:
+        case icu4x::capi::PersonNamesFormatterError_Pattern:
+        case icu4x::capi::PersonNamesFormatterError_DataMarkerNotFound:
+        case icu4x::capi::PersonNamesFormatterError_DataIdentifierNotFound:
+        case icu4x::capi::PersonNamesFormatterError_DataInvalidRequest:
+        case icu4x::capi::PersonNamesFormatterError_DataInconsistentData:
+        case icu4x::capi::PersonNamesFormatterError_DataDowncast:
+        case icu4x::capi::PersonNamesFormatterError_DataDeserialize:
+        case icu4x::capi::PersonNamesFormatterError_DataCustom:
+        case icu4x::capi::PersonNamesFormatterError_DataIo:
+            return static_cast<icu4x::PersonNamesFormatterError::Value>(c_enum);
+        default:
+            std::abort();
+    }
+}
+#endif // icu4x_PersonNamesFormatterError_HPP
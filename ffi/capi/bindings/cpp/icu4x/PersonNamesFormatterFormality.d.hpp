@@ -0,0 +1,54 @@
+#ifndef icu4x_PersonNamesFormatterFormality_D_HPP
+#define icu4x_PersonNamesFormatterFormality_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    enum PersonNamesFormatterFormality {
+      PersonNamesFormatterFormality_Formal = 0,
+      PersonNamesFormatterFormality_Informal = 1,
+    };
+
+    typedef struct PersonNamesFormatterFormality_option {union { PersonNamesFormatterFormality ok; }; bool is_ok; } PersonNamesFormatterFormality_option;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `FormattingFormality`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.FormattingFormality.html) for more information.
+ */
+class PersonNamesFormatterFormality {
+public:
+    enum Value {
+        Formal = 0,
+        Informal = 1,
+    };
+
+    PersonNamesFormatterFormality(): value(Value::Formal) {}
+
+    // Implicit conversions between enum and ::Value
+    constexpr PersonNamesFormatterFormality(Value v) : value(v) {}
+    constexpr operator Value() const { return value; }
+    // Prevent usage as boolean value
+    explicit operator bool() const = delete;
+
+    inline icu4x::capi::PersonNamesFormatterFormality AsFFI() const;
+    inline static icu4x::PersonNamesFormatterFormality FromFFI(icu4x::capi::PersonNamesFormatterFormality c_enum);
+private:
+    Value value;
+};
+
+} // namespace
+#endif // icu4x_PersonNamesFormatterFormality_D_HPP
@@ -0,0 +1,38 @@
+#ifndef icu4x_PersonNamesFormatterFormality_HPP
+#define icu4x_PersonNamesFormatterFormality_HPP
+
+#include "PersonNamesFormatterFormality.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline icu4x::capi::PersonNamesFormatterFormality icu4x::PersonNamesFormatterFormality::AsFFI() const {
+    return static_cast<icu4x::capi::PersonNamesFormatterFormality>(value);
+}
+
+inline icu4x::PersonNamesFormatterFormality icu4x::PersonNamesFormatterFormality::FromFFI(icu4x::capi::PersonNamesFormatterFormality c_enum) {
+    switch (c_enum) {
+        case icu4x::capi::PersonNamesFormatterFormality_Formal:
+        case icu4x::capi::PersonNamesFormatterFormality_Informal:
+            return static_cast<icu4x::PersonNamesFormatterFormality::Value>(c_enum);
+        default:
+            std::abort();
+    }
+}
+#endif // icu4x_PersonNamesFormatterFormality_HPP
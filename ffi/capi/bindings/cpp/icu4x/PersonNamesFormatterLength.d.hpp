@@ -0,0 +1,56 @@
+#ifndef icu4x_PersonNamesFormatterLength_D_HPP
+#define icu4x_PersonNamesFormatterLength_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    enum PersonNamesFormatterLength {
+      PersonNamesFormatterLength_Short = 0,
+      PersonNamesFormatterLength_Medium = 1,
+      PersonNamesFormatterLength_Long = 2,
+    };
+
+    typedef struct PersonNamesFormatterLength_option {union { PersonNamesFormatterLength ok; }; bool is_ok; } PersonNamesFormatterLength_option;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `FormattingLength`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.FormattingLength.html) for more information.
+ */
+class PersonNamesFormatterLength {
+public:
+    enum Value {
+        Short = 0,
+        Medium = 1,
+        Long = 2,
+    };
+
+    PersonNamesFormatterLength(): value(Value::Medium) {}
+
+    // Implicit conversions between enum and ::Value
+    constexpr PersonNamesFormatterLength(Value v) : value(v) {}
+    constexpr operator Value() const { return value; }
+    // Prevent usage as boolean value
+    explicit operator bool() const = delete;
+
+    inline icu4x::capi::PersonNamesFormatterLength AsFFI() const;
+    inline static icu4x::PersonNamesFormatterLength FromFFI(icu4x::capi::PersonNamesFormatterLength c_enum);
+private:
+    Value value;
+};
+
+} // namespace
+#endif // icu4x_PersonNamesFormatterLength_D_HPP
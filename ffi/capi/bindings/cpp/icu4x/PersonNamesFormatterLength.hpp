@@ -0,0 +1,39 @@
+#ifndef icu4x_PersonNamesFormatterLength_HPP
+#define icu4x_PersonNamesFormatterLength_HPP
+
+#include "PersonNamesFormatterLength.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline icu4x::capi::PersonNamesFormatterLength icu4x::PersonNamesFormatterLength::AsFFI() const {
+    return static_cast<icu4x::capi::PersonNamesFormatterLength>(value);
+}
+
+inline icu4x::PersonNamesFormatterLength icu4x::PersonNamesFormatterLength::FromFFI(icu4x::capi::PersonNamesFormatterLength c_enum) {
+    switch (c_enum) {
+        case icu4x::capi::PersonNamesFormatterLength_Short:
+        case icu4x::capi::PersonNamesFormatterLength_Medium:
+        case icu4x::capi::PersonNamesFormatterLength_Long:
+            return static_cast<icu4x::PersonNamesFormatterLength::Value>(c_enum);
+        default:
+            std::abort();
+    }
+}
+#endif // icu4x_PersonNamesFormatterLength_HPP
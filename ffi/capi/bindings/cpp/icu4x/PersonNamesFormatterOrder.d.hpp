@@ -0,0 +1,56 @@
+#ifndef icu4x_PersonNamesFormatterOrder_D_HPP
+#define icu4x_PersonNamesFormatterOrder_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    enum PersonNamesFormatterOrder {
+      PersonNamesFormatterOrder_GivenFirst = 0,
+      PersonNamesFormatterOrder_SurnameFirst = 1,
+      PersonNamesFormatterOrder_Sorting = 2,
+    };
+
+    typedef struct PersonNamesFormatterOrder_option {union { PersonNamesFormatterOrder ok; }; bool is_ok; } PersonNamesFormatterOrder_option;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `FormattingOrder`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.FormattingOrder.html) for more information.
+ */
+class PersonNamesFormatterOrder {
+public:
+    enum Value {
+        GivenFirst = 0,
+        SurnameFirst = 1,
+        Sorting = 2,
+    };
+
+    PersonNamesFormatterOrder(): value(Value::GivenFirst) {}
+
+    // Implicit conversions between enum and ::Value
+    constexpr PersonNamesFormatterOrder(Value v) : value(v) {}
+    constexpr operator Value() const { return value; }
+    // Prevent usage as boolean value
+    explicit operator bool() const = delete;
+
+    inline icu4x::capi::PersonNamesFormatterOrder AsFFI() const;
+    inline static icu4x::PersonNamesFormatterOrder FromFFI(icu4x::capi::PersonNamesFormatterOrder c_enum);
+private:
+    Value value;
+};
+
+} // namespace
+#endif // icu4x_PersonNamesFormatterOrder_D_HPP
@@ -0,0 +1,39 @@
+#ifndef icu4x_PersonNamesFormatterOrder_HPP
+#define icu4x_PersonNamesFormatterOrder_HPP
+
+#include "PersonNamesFormatterOrder.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline icu4x::capi::PersonNamesFormatterOrder icu4x::PersonNamesFormatterOrder::AsFFI() const {
+    return static_cast<icu4x::capi::PersonNamesFormatterOrder>(value);
+}
+
+inline icu4x::PersonNamesFormatterOrder icu4x::PersonNamesFormatterOrder::FromFFI(icu4x::capi::PersonNamesFormatterOrder c_enum) {
+    switch (c_enum) {
+        case icu4x::capi::PersonNamesFormatterOrder_GivenFirst:
+        case icu4x::capi::PersonNamesFormatterOrder_SurnameFirst:
+        case icu4x::capi::PersonNamesFormatterOrder_Sorting:
+            return static_cast<icu4x::PersonNamesFormatterOrder::Value>(c_enum);
+        default:
+            std::abort();
+    }
+}
+#endif // icu4x_PersonNamesFormatterOrder_HPP
@@ -0,0 +1,56 @@
+#ifndef icu4x_PersonNamesFormatterUsage_D_HPP
+#define icu4x_PersonNamesFormatterUsage_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    enum PersonNamesFormatterUsage {
+      PersonNamesFormatterUsage_Addressing = 0,
+      PersonNamesFormatterUsage_Referring = 1,
+      PersonNamesFormatterUsage_Monogram = 2,
+    };
+
+    typedef struct PersonNamesFormatterUsage_option {union { PersonNamesFormatterUsage ok; }; bool is_ok; } PersonNamesFormatterUsage_option;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `FormattingUsage`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.FormattingUsage.html) for more information.
+ */
+class PersonNamesFormatterUsage {
+public:
+    enum Value {
+        Addressing = 0,
+        Referring = 1,
+        Monogram = 2,
+    };
+
+    PersonNamesFormatterUsage(): value(Value::Referring) {}
+
+    // Implicit conversions between enum and ::Value
+    constexpr PersonNamesFormatterUsage(Value v) : value(v) {}
+    constexpr operator Value() const { return value; }
+    // Prevent usage as boolean value
+    explicit operator bool() const = delete;
+
+    inline icu4x::capi::PersonNamesFormatterUsage AsFFI() const;
+    inline static icu4x::PersonNamesFormatterUsage FromFFI(icu4x::capi::PersonNamesFormatterUsage c_enum);
+private:
+    Value value;
+};
+
+} // namespace
+#endif // icu4x_PersonNamesFormatterUsage_D_HPP
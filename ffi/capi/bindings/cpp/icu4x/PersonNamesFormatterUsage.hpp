@@ -0,0 +1,39 @@
+#ifndef icu4x_PersonNamesFormatterUsage_HPP
+#define icu4x_PersonNamesFormatterUsage_HPP
+
+#include "PersonNamesFormatterUsage.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline icu4x::capi::PersonNamesFormatterUsage icu4x::PersonNamesFormatterUsage::AsFFI() const {
+    return static_cast<icu4x::capi::PersonNamesFormatterUsage>(value);
+}
+
+inline icu4x::PersonNamesFormatterUsage icu4x::PersonNamesFormatterUsage::FromFFI(icu4x::capi::PersonNamesFormatterUsage c_enum) {
+    switch (c_enum) {
+        case icu4x::capi::PersonNamesFormatterUsage_Addressing:
+        case icu4x::capi::PersonNamesFormatterUsage_Referring:
+        case icu4x::capi::PersonNamesFormatterUsage_Monogram:
+            return static_cast<icu4x::PersonNamesFormatterUsage::Value>(c_enum);
+        default:
+            std::abort();
+    }
+}
+#endif // icu4x_PersonNamesFormatterUsage_HPP
@@ -0,0 +1,94 @@
+#ifndef icu4x_RelativeTimeFormatter_D_HPP
+#define icu4x_RelativeTimeFormatter_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+namespace icu4x {
+namespace capi { struct DataProvider; }
+class DataProvider;
+namespace capi { struct Decimal; }
+class Decimal;
+namespace capi { struct Locale; }
+class Locale;
+namespace capi { struct RelativeTimeFormatter; }
+class RelativeTimeFormatter;
+class DataError;
+class RelativeTimeLength;
+class RelativeTimeNumeric;
+class RelativeTimeUnit;
+}
+
+
+namespace icu4x {
+namespace capi {
+    struct RelativeTimeFormatter;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Relative Time Format object, capable of formatting a {@link Decimal} number of
+ * a fixed time unit relative to now, such as "in 3 days" or "yesterday".
+ *
+ * See the [Rust documentation for `RelativeTimeFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html) for more information.
+ */
+class RelativeTimeFormatter {
+public:
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link RelativeTimeFormatter} for the given unit and length, using compiled data.
+     *
+     * See the [Rust documentation for `try_new_long_second`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html#method.try_new_long_second) for more information.
+   */
+  inline static diplomat::result<std::unique_ptr<icu4x::RelativeTimeFormatter>, icu4x::DataError> create(const icu4x::Locale& locale, icu4x::RelativeTimeUnit unit, icu4x::RelativeTimeLength length, icu4x::RelativeTimeNumeric numeric);
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link RelativeTimeFormatter} for the given unit and length, using a particular data source.
+     *
+     * See the [Rust documentation for `try_new_long_second`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html#method.try_new_long_second) for more information.
+   */
+  inline static diplomat::result<std::unique_ptr<icu4x::RelativeTimeFormatter>, icu4x::DataError> create_with_provider(const icu4x::DataProvider& provider, const icu4x::Locale& locale, icu4x::RelativeTimeUnit unit, icu4x::RelativeTimeLength length, icu4x::RelativeTimeNumeric numeric);
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link Decimal} number of units relative to now.
+     *
+     * Negative values are formatted in the past, positive values (and zero) in the future.
+     *
+     * See the [Rust documentation for `format`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html#method.format) for more information.
+   */
+  inline std::string format(const icu4x::Decimal& value) const;
+  template<typename W>
+  inline void format_write(const icu4x::Decimal& value, W& writeable_output) const;
+
+    inline const icu4x::capi::RelativeTimeFormatter* AsFFI() const;
+    inline icu4x::capi::RelativeTimeFormatter* AsFFI();
+    inline static const icu4x::RelativeTimeFormatter* FromFFI(const icu4x::capi::RelativeTimeFormatter* ptr);
+    inline static icu4x::RelativeTimeFormatter* FromFFI(icu4x::capi::RelativeTimeFormatter* ptr);
+    inline static void operator delete(void* ptr);
+private:
+    RelativeTimeFormatter() = delete;
+    RelativeTimeFormatter(const icu4x::RelativeTimeFormatter&) = delete;
+    RelativeTimeFormatter(icu4x::RelativeTimeFormatter&&) noexcept = delete;
+    RelativeTimeFormatter operator=(const icu4x::RelativeTimeFormatter&) = delete;
+    RelativeTimeFormatter operator=(icu4x::RelativeTimeFormatter&&) noexcept = delete;
+    static void operator delete[](void*, size_t) = delete;
+};
+
+} // namespace
+#endif // icu4x_RelativeTimeFormatter_D_HPP
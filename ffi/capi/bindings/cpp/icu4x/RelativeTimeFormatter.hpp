@@ -0,0 +1,96 @@
+#ifndef icu4x_RelativeTimeFormatter_HPP
+#define icu4x_RelativeTimeFormatter_HPP
+
+#include "RelativeTimeFormatter.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+#include "DataError.hpp"
+#include "DataProvider.hpp"
+#include "Decimal.hpp"
+#include "Locale.hpp"
+#include "RelativeTimeLength.hpp"
+#include "RelativeTimeNumeric.hpp"
+#include "RelativeTimeUnit.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    typedef struct icu4x_RelativeTimeFormatter_create_mv1_result {union {icu4x::capi::RelativeTimeFormatter* ok; icu4x::capi::DataError err;}; bool is_ok;} icu4x_RelativeTimeFormatter_create_mv1_result;
+    icu4x_RelativeTimeFormatter_create_mv1_result icu4x_RelativeTimeFormatter_create_mv1(const icu4x::capi::Locale* locale, icu4x::capi::RelativeTimeUnit unit, icu4x::capi::RelativeTimeLength length, icu4x::capi::RelativeTimeNumeric numeric);
+
+    typedef struct icu4x_RelativeTimeFormatter_create_with_provider_mv1_result {union {icu4x::capi::RelativeTimeFormatter* ok; icu4x::capi::DataError err;}; bool is_ok;} icu4x_RelativeTimeFormatter_create_with_provider_mv1_result;
+    icu4x_RelativeTimeFormatter_create_with_provider_mv1_result icu4x_RelativeTimeFormatter_create_with_provider_mv1(const icu4x::capi::DataProvider* provider, const icu4x::capi::Locale* locale, icu4x::capi::RelativeTimeUnit unit, icu4x::capi::RelativeTimeLength length, icu4x::capi::RelativeTimeNumeric numeric);
+
+    void icu4x_RelativeTimeFormatter_format_mv1(const icu4x::capi::RelativeTimeFormatter* self, const icu4x::capi::Decimal* value, diplomat::capi::DiplomatWrite* write);
+
+    void icu4x_RelativeTimeFormatter_destroy_mv1(RelativeTimeFormatter* self);
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline diplomat::result<std::unique_ptr<icu4x::RelativeTimeFormatter>, icu4x::DataError> icu4x::RelativeTimeFormatter::create(const icu4x::Locale& locale, icu4x::RelativeTimeUnit unit, icu4x::RelativeTimeLength length, icu4x::RelativeTimeNumeric numeric) {
+    auto result = icu4x::capi::icu4x_RelativeTimeFormatter_create_mv1(locale.AsFFI(),
+        unit.AsFFI(),
+        length.AsFFI(),
+        numeric.AsFFI());
+    return result.is_ok ? diplomat::result<std::unique_ptr<icu4x::RelativeTimeFormatter>, icu4x::DataError>(diplomat::Ok<std::unique_ptr<icu4x::RelativeTimeFormatter>>(std::unique_ptr<icu4x::RelativeTimeFormatter>(icu4x::RelativeTimeFormatter::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<icu4x::RelativeTimeFormatter>, icu4x::DataError>(diplomat::Err<icu4x::DataError>(icu4x::DataError::FromFFI(result.err)));
+}
+
+inline diplomat::result<std::unique_ptr<icu4x::RelativeTimeFormatter>, icu4x::DataError> icu4x::RelativeTimeFormatter::create_with_provider(const icu4x::DataProvider& provider, const icu4x::Locale& locale, icu4x::RelativeTimeUnit unit, icu4x::RelativeTimeLength length, icu4x::RelativeTimeNumeric numeric) {
+    auto result = icu4x::capi::icu4x_RelativeTimeFormatter_create_with_provider_mv1(provider.AsFFI(),
+        locale.AsFFI(),
+        unit.AsFFI(),
+        length.AsFFI(),
+        numeric.AsFFI());
+    return result.is_ok ? diplomat::result<std::unique_ptr<icu4x::RelativeTimeFormatter>, icu4x::DataError>(diplomat::Ok<std::unique_ptr<icu4x::RelativeTimeFormatter>>(std::unique_ptr<icu4x::RelativeTimeFormatter>(icu4x::RelativeTimeFormatter::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<icu4x::RelativeTimeFormatter>, icu4x::DataError>(diplomat::Err<icu4x::DataError>(icu4x::DataError::FromFFI(result.err)));
+}
+
+inline std::string icu4x::RelativeTimeFormatter::format(const icu4x::Decimal& value) const {
+    std::string output;
+    diplomat::capi::DiplomatWrite write = diplomat::WriteFromString(output);
+    icu4x::capi::icu4x_RelativeTimeFormatter_format_mv1(this->AsFFI(),
+        value.AsFFI(),
+        &write);
+    return output;
+}
+template<typename W>
+inline void icu4x::RelativeTimeFormatter::format_write(const icu4x::Decimal& value, W& writeable) const {
+    diplomat::capi::DiplomatWrite write = diplomat::WriteTrait<W>::Construct(writeable);
+    icu4x::capi::icu4x_RelativeTimeFormatter_format_mv1(this->AsFFI(),
+        value.AsFFI(),
+        &write);
+}
+
+inline const icu4x::capi::RelativeTimeFormatter* icu4x::RelativeTimeFormatter::AsFFI() const {
+    return reinterpret_cast<const icu4x::capi::RelativeTimeFormatter*>(this);
+}
+
+inline icu4x::capi::RelativeTimeFormatter* icu4x::RelativeTimeFormatter::AsFFI() {
+    return reinterpret_cast<icu4x::capi::RelativeTimeFormatter*>(this);
+}
+
+inline const icu4x::RelativeTimeFormatter* icu4x::RelativeTimeFormatter::FromFFI(const icu4x::capi::RelativeTimeFormatter* ptr) {
+    return reinterpret_cast<const icu4x::RelativeTimeFormatter*>(ptr);
+}
+
+inline icu4x::RelativeTimeFormatter* icu4x::RelativeTimeFormatter::FromFFI(icu4x::capi::RelativeTimeFormatter* ptr) {
+    return reinterpret_cast<icu4x::RelativeTimeFormatter*>(ptr);
+}
+
+inline void icu4x::RelativeTimeFormatter::operator delete(void* ptr) {
+    icu4x::capi::icu4x_RelativeTimeFormatter_destroy_mv1(reinterpret_cast<icu4x::capi::RelativeTimeFormatter*>(ptr));
+}
+
+
+#endif // icu4x_RelativeTimeFormatter_HPP
@@ -0,0 +1,56 @@
+#ifndef icu4x_RelativeTimeLength_D_HPP
+#define icu4x_RelativeTimeLength_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    enum RelativeTimeLength {
+      RelativeTimeLength_Long = 0,
+      RelativeTimeLength_Short = 1,
+      RelativeTimeLength_Narrow = 2,
+    };
+
+    typedef struct RelativeTimeLength_option {union { RelativeTimeLength ok; }; bool is_ok; } RelativeTimeLength_option;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * The width of the unit names used by a {@link RelativeTimeFormatter}.
+ */
+class RelativeTimeLength {
+public:
+    enum Value {
+        Long = 0,
+        Short = 1,
+        Narrow = 2,
+    };
+
+    RelativeTimeLength(): value(Value::Long) {}
+
+    // Implicit conversions between enum and ::Value
+    constexpr RelativeTimeLength(Value v) : value(v) {}
+    constexpr operator Value() const { return value; }
+    // Prevent usage as boolean value
+    explicit operator bool() const = delete;
+
+    inline icu4x::capi::RelativeTimeLength AsFFI() const;
+    inline static icu4x::RelativeTimeLength FromFFI(icu4x::capi::RelativeTimeLength c_enum);
+private:
+    Value value;
+};
+
+} // namespace
+#endif // icu4x_RelativeTimeLength_D_HPP
@@ -0,0 +1,39 @@
+#ifndef icu4x_RelativeTimeLength_HPP
+#define icu4x_RelativeTimeLength_HPP
+
+#include "RelativeTimeLength.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline icu4x::capi::RelativeTimeLength icu4x::RelativeTimeLength::AsFFI() const {
+    return static_cast<icu4x::capi::RelativeTimeLength>(value);
+}
+
+inline icu4x::RelativeTimeLength icu4x::RelativeTimeLength::FromFFI(icu4x::capi::RelativeTimeLength c_enum) {
+    switch (c_enum) {
+        case icu4x::capi::RelativeTimeLength_Long:
+        case icu4x::capi::RelativeTimeLength_Short:
+        case icu4x::capi::RelativeTimeLength_Narrow:
+            return static_cast<icu4x::RelativeTimeLength::Value>(c_enum);
+        default:
+            std::abort();
+    }
+}
+#endif // icu4x_RelativeTimeLength_HPP
@@ -0,0 +1,54 @@
+#ifndef icu4x_RelativeTimeNumeric_D_HPP
+#define icu4x_RelativeTimeNumeric_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    enum RelativeTimeNumeric {
+      RelativeTimeNumeric_Always = 0,
+      RelativeTimeNumeric_Auto = 1,
+    };
+
+    typedef struct RelativeTimeNumeric_option {union { RelativeTimeNumeric ok; }; bool is_ok; } RelativeTimeNumeric_option;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `Numeric`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/options/enum.Numeric.html) for more information.
+ */
+class RelativeTimeNumeric {
+public:
+    enum Value {
+        Always = 0,
+        Auto = 1,
+    };
+
+    RelativeTimeNumeric(): value(Value::Always) {}
+
+    // Implicit conversions between enum and ::Value
+    constexpr RelativeTimeNumeric(Value v) : value(v) {}
+    constexpr operator Value() const { return value; }
+    // Prevent usage as boolean value
+    explicit operator bool() const = delete;
+
+    inline icu4x::capi::RelativeTimeNumeric AsFFI() const;
+    inline static icu4x::RelativeTimeNumeric FromFFI(icu4x::capi::RelativeTimeNumeric c_enum);
+private:
+    Value value;
+};
+
+} // namespace
+#endif // icu4x_RelativeTimeNumeric_D_HPP
@@ -0,0 +1,66 @@
+#ifndef icu4x_RelativeTimeUnit_D_HPP
+#define icu4x_RelativeTimeUnit_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    enum RelativeTimeUnit {
+      RelativeTimeUnit_Second = 0,
+      RelativeTimeUnit_Minute = 1,
+      RelativeTimeUnit_Hour = 2,
+      RelativeTimeUnit_Day = 3,
+      RelativeTimeUnit_Week = 4,
+      RelativeTimeUnit_Month = 5,
+      RelativeTimeUnit_Quarter = 6,
+      RelativeTimeUnit_Year = 7,
+    };
+
+    typedef struct RelativeTimeUnit_option {union { RelativeTimeUnit ok; }; bool is_ok; } RelativeTimeUnit_option;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * The time unit formatted by a {@link RelativeTimeFormatter}.
+ */
+class RelativeTimeUnit {
+public:
+    enum Value {
+        Second = 0,
+        Minute = 1,
+        Hour = 2,
+        Day = 3,
+        Week = 4,
+        Month = 5,
+        Quarter = 6,
+        Year = 7,
+    };
+
+    RelativeTimeUnit(): value(Value::Day) {}
+
+    // Implicit conversions between enum and ::Value
+    constexpr RelativeTimeUnit(Value v) : value(v) {}
+    constexpr operator Value() const { return value; }
+    // Prevent usage as boolean value
+    explicit operator bool() const = delete;
+
+    inline icu4x::capi::RelativeTimeUnit AsFFI() const;
+    inline static icu4x::RelativeTimeUnit FromFFI(icu4x::capi::RelativeTimeUnit c_enum);
+private:
+    Value value;
+};
+
+} // namespace
+#endif // icu4x_RelativeTimeUnit_D_HPP
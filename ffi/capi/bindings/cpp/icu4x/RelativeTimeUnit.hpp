@@ -0,0 +1,44 @@
+#ifndef icu4x_RelativeTimeUnit_HPP
+#define icu4x_RelativeTimeUnit_HPP
+
+#include "RelativeTimeUnit.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline icu4x::capi::RelativeTimeUnit icu4x::RelativeTimeUnit::AsFFI() const {
+    return static_cast<icu4x::capi::RelativeTimeUnit>(value);
+}
+
+inline icu4x::RelativeTimeUnit icu4x::RelativeTimeUnit::FromFFI(icu4x::capi::RelativeTimeUnit c_enum) {
+    switch (c_enum) {
+        case icu4x::capi::RelativeTimeUnit_Second:
+        case icu4x::capi::RelativeTimeUnit_Minute:
+        case icu4x::capi::RelativeTimeUnit_Hour:
+        case icu4x::capi::RelativeTimeUnit_Day:
+        case icu4x::capi::RelativeTimeUnit_Week:
+        case icu4x::capi::RelativeTimeUnit_Month:
+        case icu4x::capi::RelativeTimeUnit_Quarter:
+        case icu4x::capi::RelativeTimeUnit_Year:
+            return static_cast<icu4x::RelativeTimeUnit::Value>(c_enum);
+        default:
+            std::abort();
+    }
+}
+#endif // icu4x_RelativeTimeUnit_HPP
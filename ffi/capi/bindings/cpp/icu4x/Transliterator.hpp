@@ -0,0 +1,86 @@
+#ifndef icu4x_Transliterator_HPP
+#define icu4x_Transliterator_HPP
+
+#include "Transliterator.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+#include "DataError.hpp"
+#include "DataProvider.hpp"
+#include "Locale.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    typedef struct icu4x_Transliterator_create_mv1_result {union {icu4x::capi::Transliterator* ok; icu4x::capi::DataError err;}; bool is_ok;} icu4x_Transliterator_create_mv1_result;
+    icu4x_Transliterator_create_mv1_result icu4x_Transliterator_create_mv1(const icu4x::capi::Locale* locale);
+
+    typedef struct icu4x_Transliterator_create_with_provider_mv1_result {union {icu4x::capi::Transliterator* ok; icu4x::capi::DataError err;}; bool is_ok;} icu4x_Transliterator_create_with_provider_mv1_result;
+    icu4x_Transliterator_create_with_provider_mv1_result icu4x_Transliterator_create_with_provider_mv1(const icu4x::capi::DataProvider* provider, const icu4x::capi::Locale* locale);
+
+    void icu4x_Transliterator_transliterate_mv1(const icu4x::capi::Transliterator* self, diplomat::capi::DiplomatStringView input, diplomat::capi::DiplomatWrite* write);
+
+    void icu4x_Transliterator_destroy_mv1(Transliterator* self);
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline diplomat::result<std::unique_ptr<icu4x::Transliterator>, icu4x::DataError> icu4x::Transliterator::create(const icu4x::Locale& locale) {
+    auto result = icu4x::capi::icu4x_Transliterator_create_mv1(locale.AsFFI());
+    return result.is_ok ? diplomat::result<std::unique_ptr<icu4x::Transliterator>, icu4x::DataError>(diplomat::Ok<std::unique_ptr<icu4x::Transliterator>>(std::unique_ptr<icu4x::Transliterator>(icu4x::Transliterator::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<icu4x::Transliterator>, icu4x::DataError>(diplomat::Err<icu4x::DataError>(icu4x::DataError::FromFFI(result.err)));
+}
+
+inline diplomat::result<std::unique_ptr<icu4x::Transliterator>, icu4x::DataError> icu4x::Transliterator::create_with_provider(const icu4x::DataProvider& provider, const icu4x::Locale& locale) {
+    auto result = icu4x::capi::icu4x_Transliterator_create_with_provider_mv1(provider.AsFFI(),
+        locale.AsFFI());
+    return result.is_ok ? diplomat::result<std::unique_ptr<icu4x::Transliterator>, icu4x::DataError>(diplomat::Ok<std::unique_ptr<icu4x::Transliterator>>(std::unique_ptr<icu4x::Transliterator>(icu4x::Transliterator::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<icu4x::Transliterator>, icu4x::DataError>(diplomat::Err<icu4x::DataError>(icu4x::DataError::FromFFI(result.err)));
+}
+
+inline std::string icu4x::Transliterator::transliterate(std::string_view input) const {
+    std::string output;
+    diplomat::capi::DiplomatWrite write = diplomat::WriteFromString(output);
+    icu4x::capi::icu4x_Transliterator_transliterate_mv1(this->AsFFI(),
+        {input.data(), input.size()},
+        &write);
+    return output;
+}
+template<typename W>
+inline void icu4x::Transliterator::transliterate_write(std::string_view input, W& writeable) const {
+    diplomat::capi::DiplomatWrite write = diplomat::WriteTrait<W>::Construct(writeable);
+    icu4x::capi::icu4x_Transliterator_transliterate_mv1(this->AsFFI(),
+        {input.data(), input.size()},
+        &write);
+}
+
+inline const icu4x::capi::Transliterator* icu4x::Transliterator::AsFFI() const {
+    return reinterpret_cast<const icu4x::capi::Transliterator*>(this);
+}
+
+inline icu4x::capi::Transliterator* icu4x::Transliterator::AsFFI() {
+    return reinterpret_cast<icu4x::capi::Transliterator*>(this);
+}
+
+inline const icu4x::Transliterator* icu4x::Transliterator::FromFFI(const icu4x::capi::Transliterator* ptr) {
+    return reinterpret_cast<const icu4x::Transliterator*>(ptr);
+}
+
+inline icu4x::Transliterator* icu4x::Transliterator::FromFFI(icu4x::capi::Transliterator* ptr) {
+    return reinterpret_cast<icu4x::Transliterator*>(ptr);
+}
+
+inline void icu4x::Transliterator::operator delete(void* ptr) {
+    icu4x::capi::icu4x_Transliterator_destroy_mv1(reinterpret_cast<icu4x::capi::Transliterator*>(ptr));
+}
+
+
+#endif // icu4x_Transliterator_HPP
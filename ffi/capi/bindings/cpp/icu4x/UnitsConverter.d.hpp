@@ -0,0 +1,56 @@
+#ifndef icu4x_UnitsConverter_D_HPP
+#define icu4x_UnitsConverter_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    struct UnitsConverter;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Units Converter object, capable of converting values from one unit to another.
+ *
+ * See the [Rust documentation for `UnitsConverter`](https://docs.rs/icu/2.0.0/icu/experimental/units/converter/struct.UnitsConverter.html) for more information.
+ */
+class UnitsConverter {
+public:
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Converts `value` from the input unit to the output unit.
+     *
+     * See the [Rust documentation for `convert`](https://docs.rs/icu/2.0.0/icu/experimental/units/converter/struct.UnitsConverter.html#method.convert) for more information.
+   */
+  inline double convert(double value) const;
+
+    inline const icu4x::capi::UnitsConverter* AsFFI() const;
+    inline icu4x::capi::UnitsConverter* AsFFI();
+    inline static const icu4x::UnitsConverter* FromFFI(const icu4x::capi::UnitsConverter* ptr);
+    inline static icu4x::UnitsConverter* FromFFI(icu4x::capi::UnitsConverter* ptr);
+    inline static void operator delete(void* ptr);
+private:
+    UnitsConverter() = delete;
+    UnitsConverter(const icu4x::UnitsConverter&) = delete;
+    UnitsConverter(icu4x::UnitsConverter&&) noexcept = delete;
+    UnitsConverter operator=(const icu4x::UnitsConverter&) = delete;
+    UnitsConverter operator=(icu4x::UnitsConverter&&) noexcept = delete;
+    static void operator delete[](void*, size_t) = delete;
+};
+
+} // namespace
+#endif // icu4x_UnitsConverter_D_HPP
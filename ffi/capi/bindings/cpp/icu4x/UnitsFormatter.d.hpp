@@ -0,0 +1,90 @@
+#ifndef icu4x_UnitsFormatter_D_HPP
+#define icu4x_UnitsFormatter_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+namespace icu4x {
+namespace capi { struct DataProvider; }
+class DataProvider;
+namespace capi { struct Decimal; }
+class Decimal;
+namespace capi { struct Locale; }
+class Locale;
+namespace capi { struct UnitsFormatter; }
+class UnitsFormatter;
+class DataError;
+class UnitsFormatterWidth;
+}
+
+
+namespace icu4x {
+namespace capi {
+    struct UnitsFormatter;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Units Formatter object, capable of formatting a {@link Decimal} as a quantity of
+ * a particular unit, such as "12 meters".
+ *
+ * See the [Rust documentation for `UnitsFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/units/formatter/struct.UnitsFormatter.html) for more information.
+ */
+class UnitsFormatter {
+public:
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link UnitsFormatter} for the given CLDR unit identifier (such as `meter`), using compiled data.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/units/formatter/struct.UnitsFormatter.html#method.try_new) for more information.
+   */
+  inline static diplomat::result<std::unique_ptr<icu4x::UnitsFormatter>, icu4x::DataError> create(const icu4x::Locale& locale, std::string_view unit, icu4x::UnitsFormatterWidth width);
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link UnitsFormatter} for the given CLDR unit identifier (such as `meter`), using a particular data source.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/units/formatter/struct.UnitsFormatter.html#method.try_new) for more information.
+   */
+  inline static diplomat::result<std::unique_ptr<icu4x::UnitsFormatter>, icu4x::DataError> create_with_provider(const icu4x::DataProvider& provider, const icu4x::Locale& locale, std::string_view unit, icu4x::UnitsFormatterWidth width);
+
+  /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link Decimal} as a quantity of this formatter's unit.
+     *
+     * See the [Rust documentation for `format_fixed_decimal`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/units/formatter/struct.UnitsFormatter.html#method.format_fixed_decimal) for more information.
+   */
+  inline std::string format(const icu4x::Decimal& value) const;
+  template<typename W>
+  inline void format_write(const icu4x::Decimal& value, W& writeable_output) const;
+
+    inline const icu4x::capi::UnitsFormatter* AsFFI() const;
+    inline icu4x::capi::UnitsFormatter* AsFFI();
+    inline static const icu4x::UnitsFormatter* FromFFI(const icu4x::capi::UnitsFormatter* ptr);
+    inline static icu4x::UnitsFormatter* FromFFI(icu4x::capi::UnitsFormatter* ptr);
+    inline static void operator delete(void* ptr);
+private:
+    UnitsFormatter() = delete;
+    UnitsFormatter(const icu4x::UnitsFormatter&) = delete;
+    UnitsFormatter(icu4x::UnitsFormatter&&) noexcept = delete;
+    UnitsFormatter operator=(const icu4x::UnitsFormatter&) = delete;
+    UnitsFormatter operator=(icu4x::UnitsFormatter&&) noexcept = delete;
+    static void operator delete[](void*, size_t) = delete;
+};
+
+} // namespace
+#endif // icu4x_UnitsFormatter_D_HPP
@@ -0,0 +1,92 @@
+#ifndef icu4x_UnitsFormatter_HPP
+#define icu4x_UnitsFormatter_HPP
+
+#include "UnitsFormatter.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+#include "DataError.hpp"
+#include "DataProvider.hpp"
+#include "Decimal.hpp"
+#include "Locale.hpp"
+#include "UnitsFormatterWidth.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    typedef struct icu4x_UnitsFormatter_create_mv1_result {union {icu4x::capi::UnitsFormatter* ok; icu4x::capi::DataError err;}; bool is_ok;} icu4x_UnitsFormatter_create_mv1_result;
+    icu4x_UnitsFormatter_create_mv1_result icu4x_UnitsFormatter_create_mv1(const icu4x::capi::Locale* locale, diplomat::capi::DiplomatStringView unit, icu4x::capi::UnitsFormatterWidth width);
+
+    typedef struct icu4x_UnitsFormatter_create_with_provider_mv1_result {union {icu4x::capi::UnitsFormatter* ok; icu4x::capi::DataError err;}; bool is_ok;} icu4x_UnitsFormatter_create_with_provider_mv1_result;
+    icu4x_UnitsFormatter_create_with_provider_mv1_result icu4x_UnitsFormatter_create_with_provider_mv1(const icu4x::capi::DataProvider* provider, const icu4x::capi::Locale* locale, diplomat::capi::DiplomatStringView unit, icu4x::capi::UnitsFormatterWidth width);
+
+    void icu4x_UnitsFormatter_format_mv1(const icu4x::capi::UnitsFormatter* self, const icu4x::capi::Decimal* value, diplomat::capi::DiplomatWrite* write);
+
+    void icu4x_UnitsFormatter_destroy_mv1(UnitsFormatter* self);
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline diplomat::result<std::unique_ptr<icu4x::UnitsFormatter>, icu4x::DataError> icu4x::UnitsFormatter::create(const icu4x::Locale& locale, std::string_view unit, icu4x::UnitsFormatterWidth width) {
+    auto result = icu4x::capi::icu4x_UnitsFormatter_create_mv1(locale.AsFFI(),
+        {unit.data(), unit.size()},
+        width.AsFFI());
+    return result.is_ok ? diplomat::result<std::unique_ptr<icu4x::UnitsFormatter>, icu4x::DataError>(diplomat::Ok<std::unique_ptr<icu4x::UnitsFormatter>>(std::unique_ptr<icu4x::UnitsFormatter>(icu4x::UnitsFormatter::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<icu4x::UnitsFormatter>, icu4x::DataError>(diplomat::Err<icu4x::DataError>(icu4x::DataError::FromFFI(result.err)));
+}
+
+inline diplomat::result<std::unique_ptr<icu4x::UnitsFormatter>, icu4x::DataError> icu4x::UnitsFormatter::create_with_provider(const icu4x::DataProvider& provider, const icu4x::Locale& locale, std::string_view unit, icu4x::UnitsFormatterWidth width) {
+    auto result = icu4x::capi::icu4x_UnitsFormatter_create_with_provider_mv1(provider.AsFFI(),
+        locale.AsFFI(),
+        {unit.data(), unit.size()},
+        width.AsFFI());
+    return result.is_ok ? diplomat::result<std::unique_ptr<icu4x::UnitsFormatter>, icu4x::DataError>(diplomat::Ok<std::unique_ptr<icu4x::UnitsFormatter>>(std::unique_ptr<icu4x::UnitsFormatter>(icu4x::UnitsFormatter::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<icu4x::UnitsFormatter>, icu4x::DataError>(diplomat::Err<icu4x::DataError>(icu4x::DataError::FromFFI(result.err)));
+}
+
+inline std::string icu4x::UnitsFormatter::format(const icu4x::Decimal& value) const {
+    std::string output;
+    diplomat::capi::DiplomatWrite write = diplomat::WriteFromString(output);
+    icu4x::capi::icu4x_UnitsFormatter_format_mv1(this->AsFFI(),
+        value.AsFFI(),
+        &write);
+    return output;
+}
+template<typename W>
+inline void icu4x::UnitsFormatter::format_write(const icu4x::Decimal& value, W& writeable) const {
+    diplomat::capi::DiplomatWrite write = diplomat::WriteTrait<W>::Construct(writeable);
+    icu4x::capi::icu4x_UnitsFormatter_format_mv1(this->AsFFI(),
+        value.AsFFI(),
+        &write);
+}
+
+inline const icu4x::capi::UnitsFormatter* icu4x::UnitsFormatter::AsFFI() const {
+    return reinterpret_cast<const icu4x::capi::UnitsFormatter*>(this);
+}
+
+inline icu4x::capi::UnitsFormatter* icu4x::UnitsFormatter::AsFFI() {
+    return reinterpret_cast<icu4x::capi::UnitsFormatter*>(this);
+}
+
+inline const icu4x::UnitsFormatter* icu4x::UnitsFormatter::FromFFI(const icu4x::capi::UnitsFormatter* ptr) {
+    return reinterpret_cast<const icu4x::UnitsFormatter*>(ptr);
+}
+
+inline icu4x::UnitsFormatter* icu4x::UnitsFormatter::FromFFI(icu4x::capi::UnitsFormatter* ptr) {
+    return reinterpret_cast<icu4x::UnitsFormatter*>(ptr);
+}
+
+inline void icu4x::UnitsFormatter::operator delete(void* ptr) {
+    icu4x::capi::icu4x_UnitsFormatter_destroy_mv1(reinterpret_cast<icu4x::capi::UnitsFormatter*>(ptr));
+}
+
+
+#endif // icu4x_UnitsFormatter_HPP
@@ -0,0 +1,56 @@
+#ifndef icu4x_UnitsFormatterWidth_D_HPP
+#define icu4x_UnitsFormatterWidth_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    enum UnitsFormatterWidth {
+      UnitsFormatterWidth_Long = 0,
+      UnitsFormatterWidth_Short = 1,
+      UnitsFormatterWidth_Narrow = 2,
+    };
+
+    typedef struct UnitsFormatterWidth_option {union { UnitsFormatterWidth ok; }; bool is_ok; } UnitsFormatterWidth_option;
+} // namespace capi
+} // namespace
+
+namespace icu4x {
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `Width`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/units/options/enum.Width.html) for more information.
+ */
+class UnitsFormatterWidth {
+public:
+    enum Value {
+        Long = 0,
+        Short = 1,
+        Narrow = 2,
+    };
+
+    UnitsFormatterWidth(): value(Value::Short) {}
+
+    // Implicit conversions between enum and ::Value
+    constexpr UnitsFormatterWidth(Value v) : value(v) {}
+    constexpr operator Value() const { return value; }
+    // Prevent usage as boolean value
+    explicit operator bool() const = delete;
+
+    inline icu4x::capi::UnitsFormatterWidth AsFFI() const;
+    inline static icu4x::UnitsFormatterWidth FromFFI(icu4x::capi::UnitsFormatterWidth c_enum);
+private:
+    Value value;
+};
+
+} // namespace
+#endif // icu4x_UnitsFormatterWidth_D_HPP
@@ -0,0 +1,39 @@
+#ifndef icu4x_UnitsFormatterWidth_HPP
+#define icu4x_UnitsFormatterWidth_HPP
+
+#include "UnitsFormatterWidth.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace icu4x {
+namespace capi {
+    extern "C" {
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline icu4x::capi::UnitsFormatterWidth icu4x::UnitsFormatterWidth::AsFFI() const {
+    return static_cast<icu4x::capi::UnitsFormatterWidth>(value);
+}
+
+inline icu4x::UnitsFormatterWidth icu4x::UnitsFormatterWidth::FromFFI(icu4x::capi::UnitsFormatterWidth c_enum) {
+    switch (c_enum) {
+        case icu4x::capi::UnitsFormatterWidth_Long:
+        case icu4x::capi::UnitsFormatterWidth_Short:
+        case icu4x::capi::UnitsFormatterWidth_Narrow:
+            return static_cast<icu4x::UnitsFormatterWidth::Value>(c_enum);
+        default:
+            std::abort();
+    }
+}
+#endif // icu4x_UnitsFormatterWidth_HPP
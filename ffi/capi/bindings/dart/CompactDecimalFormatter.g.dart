@@ -0,0 +1,135 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// An ICU4X Compact Decimal Format object, capable of formatting a [Decimal] in a compact form,
+/// such as "1.2K" or "1.2 thousand".
+///
+/// See the [Rust documentation for `CompactDecimalFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html) for more information.
+final class CompactDecimalFormatter implements ffi.Finalizable {
+  final ffi.Pointer<ffi.Opaque> _ffi;
+
+  // These are "used" in the sense that they keep dependencies alive
+  // ignore: unused_field
+  final core.List<Object> _selfEdge;
+
+  // This takes in a list of lifetime edges (including for &self borrows)
+  // corresponding to data this may borrow from. These should be flat arrays containing
+  // references to objects, and this object will hold on to them to keep them alive and
+  // maintain borrow validity.
+  CompactDecimalFormatter._fromFfi(this._ffi, this._selfEdge) {
+    if (_selfEdge.isEmpty) {
+      _finalizer.attach(this, _ffi.cast());
+    }
+  }
+
+  @_DiplomatFfiUse('icu4x_CompactDecimalFormatter_destroy_mv1')
+  static final _finalizer = ffi.NativeFinalizer(ffi.Native.addressOf(_icu4x_CompactDecimalFormatter_destroy_mv1));
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Creates a new [CompactDecimalFormatter] for the short compact style (e.g. "1.2K"), using compiled data.
+  ///
+  /// See the [Rust documentation for `try_new_short`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html#method.try_new_short) for more information.
+  ///
+  /// Throws [DataError] on failure.
+  factory CompactDecimalFormatter.short(Locale locale, [DecimalGroupingStrategy? groupingStrategy]) {
+    final result = _icu4x_CompactDecimalFormatter_create_short_mv1(locale._ffi, groupingStrategy != null ? _ResultInt32Void.ok(groupingStrategy.index) : _ResultInt32Void.err());
+    if (!result.isOk) {
+      throw DataError.values[result.union.err];
+    }
+    return CompactDecimalFormatter._fromFfi(result.union.ok, []);
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Creates a new [CompactDecimalFormatter] for the short compact style (e.g. "1.2K"), using a particular data source.
+  ///
+  /// See the [Rust documentation for `try_new_short`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html#method.try_new_short) for more information.
+  ///
+  /// Throws [DataError] on failure.
+  factory CompactDecimalFormatter.shortWithProvider(DataProvider provider, Locale locale, [DecimalGroupingStrategy? groupingStrategy]) {
+    final result = _icu4x_CompactDecimalFormatter_create_short_with_provider_mv1(provider._ffi, locale._ffi, groupingStrategy != null ? _ResultInt32Void.ok(groupingStrategy.index) : _ResultInt32Void.err());
+    if (!result.isOk) {
+      throw DataError.values[result.union.err];
+    }
+    return CompactDecimalFormatter._fromFfi(result.union.ok, []);
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Creates a new [CompactDecimalFormatter] for the long compact style (e.g. "1.2 thousand"), using compiled data.
+  ///
+  /// See the [Rust documentation for `try_new_long`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html#method.try_new_long) for more information.
+  ///
+  /// Throws [DataError] on failure.
+  factory CompactDecimalFormatter.long(Locale locale, [DecimalGroupingStrategy? groupingStrategy]) {
+    final result = _icu4x_CompactDecimalFormatter_create_long_mv1(locale._ffi, groupingStrategy != null ? _ResultInt32Void.ok(groupingStrategy.index) : _ResultInt32Void.err());
+    if (!result.isOk) {
+      throw DataError.values[result.union.err];
+    }
+    return CompactDecimalFormatter._fromFfi(result.union.ok, []);
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Creates a new [CompactDecimalFormatter] for the long compact style (e.g. "1.2 thousand"), using a particular data source.
+  ///
+  /// See the [Rust documentation for `try_new_long`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html#method.try_new_long) for more information.
+  ///
+  /// Throws [DataError] on failure.
+  factory CompactDecimalFormatter.longWithProvider(DataProvider provider, Locale locale, [DecimalGroupingStrategy? groupingStrategy]) {
+    final result = _icu4x_CompactDecimalFormatter_create_long_with_provider_mv1(provider._ffi, locale._ffi, groupingStrategy != null ? _ResultInt32Void.ok(groupingStrategy.index) : _ResultInt32Void.err());
+    if (!result.isOk) {
+      throw DataError.values[result.union.err];
+    }
+    return CompactDecimalFormatter._fromFfi(result.union.ok, []);
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Formats a [Decimal] to a string, rounding it to the precision of the compact pattern.
+  ///
+  /// See the [Rust documentation for `format_fixed_decimal`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html#method.format_fixed_decimal) for more information.
+  String format(Decimal value) {
+    final write = _Write();
+    _icu4x_CompactDecimalFormatter_format_mv1(_ffi, value._ffi, write._ffi);
+    return write.finalize();
+  }
+
+}
+
+@_DiplomatFfiUse('icu4x_CompactDecimalFormatter_destroy_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Void>)>(isLeaf: true, symbol: 'icu4x_CompactDecimalFormatter_destroy_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_CompactDecimalFormatter_destroy_mv1(ffi.Pointer<ffi.Void> self);
+
+@_DiplomatFfiUse('icu4x_CompactDecimalFormatter_create_short_mv1')
+@ffi.Native<_ResultOpaqueInt32 Function(ffi.Pointer<ffi.Opaque>, _ResultInt32Void)>(isLeaf: true, symbol: 'icu4x_CompactDecimalFormatter_create_short_mv1')
+// ignore: non_constant_identifier_names
+external _ResultOpaqueInt32 _icu4x_CompactDecimalFormatter_create_short_mv1(ffi.Pointer<ffi.Opaque> locale, _ResultInt32Void groupingStrategy);
+
+@_DiplomatFfiUse('icu4x_CompactDecimalFormatter_create_short_with_provider_mv1')
+@ffi.Native<_ResultOpaqueInt32 Function(ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>, _ResultInt32Void)>(isLeaf: true, symbol: 'icu4x_CompactDecimalFormatter_create_short_with_provider_mv1')
+// ignore: non_constant_identifier_names
+external _ResultOpaqueInt32 _icu4x_CompactDecimalFormatter_create_short_with_provider_mv1(ffi.Pointer<ffi.Opaque> provider, ffi.Pointer<ffi.Opaque> locale, _ResultInt32Void groupingStrategy);
+
+@_DiplomatFfiUse('icu4x_CompactDecimalFormatter_create_long_mv1')
+@ffi.Native<_ResultOpaqueInt32 Function(ffi.Pointer<ffi.Opaque>, _ResultInt32Void)>(isLeaf: true, symbol: 'icu4x_CompactDecimalFormatter_create_long_mv1')
+// ignore: non_constant_identifier_names
+external _ResultOpaqueInt32 _icu4x_CompactDecimalFormatter_create_long_mv1(ffi.Pointer<ffi.Opaque> locale, _ResultInt32Void groupingStrategy);
+
+@_DiplomatFfiUse('icu4x_CompactDecimalFormatter_create_long_with_provider_mv1')
+@ffi.Native<_ResultOpaqueInt32 Function(ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>, _ResultInt32Void)>(isLeaf: true, symbol: 'icu4x_CompactDecimalFormatter_create_long_with_provider_mv1')
+// ignore: non_constant_identifier_names
+external _ResultOpaqueInt32 _icu4x_CompactDecimalFormatter_create_long_with_provider_mv1(ffi.Pointer<ffi.Opaque> provider, ffi.Pointer<ffi.Opaque> locale, _ResultInt32Void groupingStrategy);
+
+@_DiplomatFfiUse('icu4x_CompactDecimalFormatter_format_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>)>(isLeaf: true, symbol: 'icu4x_CompactDecimalFormatter_format_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_CompactDecimalFormatter_format_mv1(ffi.Pointer<ffi.Opaque> self, ffi.Pointer<ffi.Opaque> value, ffi.Pointer<ffi.Opaque> write);
+
+// dart format on
@@ -0,0 +1,17 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// See the [Rust documentation for `Width`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/options/enum.Width.html) for more information.
+enum CurrencyFormatterWidth {
+
+  short,
+
+  narrow;
+
+}
+
+// dart format on
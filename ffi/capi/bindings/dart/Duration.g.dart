@@ -0,0 +1,119 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+final class _DurationFfi extends ffi.Struct {
+  @ffi.Int32()
+  external int sign;
+  @ffi.Uint64()
+  external int years;
+  @ffi.Uint64()
+  external int months;
+  @ffi.Uint64()
+  external int weeks;
+  @ffi.Uint64()
+  external int days;
+  @ffi.Uint64()
+  external int hours;
+  @ffi.Uint64()
+  external int minutes;
+  @ffi.Uint64()
+  external int seconds;
+  @ffi.Uint64()
+  external int milliseconds;
+  @ffi.Uint64()
+  external int microseconds;
+  @ffi.Uint64()
+  external int nanoseconds;
+}
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// A duration of time, such as "1 hour, 2 minutes".
+///
+/// See the [Rust documentation for `Duration`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.Duration.html) for more information.
+final class Duration {
+  DurationSign sign;
+  int years;
+  int months;
+  int weeks;
+  int days;
+  int hours;
+  int minutes;
+  int seconds;
+  int milliseconds;
+  int microseconds;
+  int nanoseconds;
+
+  Duration({required this.sign, required this.years, required this.months, required this.weeks, required this.days, required this.hours, required this.minutes, required this.seconds, required this.milliseconds, required this.microseconds, required this.nanoseconds});
+
+  // This struct contains borrowed fields, so this takes in a list of
+  // "edges" corresponding to where each lifetime's data may have been borrowed from
+  // and passes it down to individual fields containing the borrow.
+  // This method does not attempt to handle any dependencies between lifetimes, the caller
+  // should handle this when constructing edge arrays.
+  // ignore: unused_element
+  Duration._fromFfi(_DurationFfi ffi) :
+    sign = DurationSign.values[ffi.sign],
+    years = ffi.years,
+    months = ffi.months,
+    weeks = ffi.weeks,
+    days = ffi.days,
+    hours = ffi.hours,
+    minutes = ffi.minutes,
+    seconds = ffi.seconds,
+    milliseconds = ffi.milliseconds,
+    microseconds = ffi.microseconds,
+    nanoseconds = ffi.nanoseconds;
+
+  // ignore: unused_element
+  _DurationFfi _toFfi(ffi.Allocator temp) {
+    final struct = ffi.Struct.create<_DurationFfi>();
+    struct.sign = sign.index;
+    struct.years = years;
+    struct.months = months;
+    struct.weeks = weeks;
+    struct.days = days;
+    struct.hours = hours;
+    struct.minutes = minutes;
+    struct.seconds = seconds;
+    struct.milliseconds = milliseconds;
+    struct.microseconds = microseconds;
+    struct.nanoseconds = nanoseconds;
+    return struct;
+  }
+
+
+  @override
+  bool operator ==(Object other) =>
+      other is Duration &&
+      other.sign == sign &&
+      other.years == years &&
+      other.months == months &&
+      other.weeks == weeks &&
+      other.days == days &&
+      other.hours == hours &&
+      other.minutes == minutes &&
+      other.seconds == seconds &&
+      other.milliseconds == milliseconds &&
+      other.microseconds == microseconds &&
+      other.nanoseconds == nanoseconds;
+
+  @override
+  int get hashCode => Object.hashAll([
+        sign,
+        years,
+        months,
+        weeks,
+        days,
+        hours,
+        minutes,
+        seconds,
+        milliseconds,
+        microseconds,
+        nanoseconds,
+      ]);
+}
+
+// dart format on
@@ -0,0 +1,102 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// An ICU4X Duration Formatter object, capable of formatting a [Duration], such as
+/// "1 hr, 2 min" or "1:02:03".
+///
+/// See the [Rust documentation for `DurationFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html) for more information.
+final class DurationFormatter implements ffi.Finalizable {
+  final ffi.Pointer<ffi.Opaque> _ffi;
+
+  // These are "used" in the sense that they keep dependencies alive
+  // ignore: unused_field
+  final core.List<Object> _selfEdge;
+
+  // This takes in a list of lifetime edges (including for &self borrows)
+  // corresponding to data this may borrow from. These should be flat arrays containing
+  // references to objects, and this object will hold on to them to keep them alive and
+  // maintain borrow validity.
+  DurationFormatter._fromFfi(this._ffi, this._selfEdge) {
+    if (_selfEdge.isEmpty) {
+      _finalizer.attach(this, _ffi.cast());
+    }
+  }
+
+  @_DiplomatFfiUse('icu4x_DurationFormatter_destroy_mv1')
+  static final _finalizer = ffi.NativeFinalizer(ffi.Native.addressOf(_icu4x_DurationFormatter_destroy_mv1));
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Creates a new [DurationFormatter] using compiled data.
+  ///
+  /// `fractional_digits` is the number of fractional digits (0 to 9) used for sub-second
+  /// units in the digital style; if absent, as many digits as necessary are shown.
+  ///
+  /// See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html#method.try_new) for more information.
+  ///
+  /// Throws [DurationFormatterLoadError] on failure.
+  factory DurationFormatter(Locale locale, DurationFormatterStyle style, [int? fractionalDigits]) {
+    final result = _icu4x_DurationFormatter_create_mv1(locale._ffi, style.index, fractionalDigits != null ? _ResultUint8Void.ok(fractionalDigits) : _ResultUint8Void.err());
+    if (!result.isOk) {
+      throw DurationFormatterLoadError.values.firstWhere((v) => v._ffi == result.union.err);
+    }
+    return DurationFormatter._fromFfi(result.union.ok, []);
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Creates a new [DurationFormatter] using a particular data source.
+  ///
+  /// `fractional_digits` is the number of fractional digits (0 to 9) used for sub-second
+  /// units in the digital style; if absent, as many digits as necessary are shown.
+  ///
+  /// See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html#method.try_new) for more information.
+  ///
+  /// Throws [DurationFormatterLoadError] on failure.
+  factory DurationFormatter.withProvider(DataProvider provider, Locale locale, DurationFormatterStyle style, [int? fractionalDigits]) {
+    final result = _icu4x_DurationFormatter_create_with_provider_mv1(provider._ffi, locale._ffi, style.index, fractionalDigits != null ? _ResultUint8Void.ok(fractionalDigits) : _ResultUint8Void.err());
+    if (!result.isOk) {
+      throw DurationFormatterLoadError.values.firstWhere((v) => v._ffi == result.union.err);
+    }
+    return DurationFormatter._fromFfi(result.union.ok, []);
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Formats a [Duration] to a string.
+  ///
+  /// See the [Rust documentation for `format`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html#method.format) for more information.
+  String format(Duration duration) {
+    final temp = _FinalizedArena();
+    final write = _Write();
+    _icu4x_DurationFormatter_format_mv1(_ffi, duration._toFfi(temp.arena), write._ffi);
+    return write.finalize();
+  }
+
+}
+
+@_DiplomatFfiUse('icu4x_DurationFormatter_destroy_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Void>)>(isLeaf: true, symbol: 'icu4x_DurationFormatter_destroy_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_DurationFormatter_destroy_mv1(ffi.Pointer<ffi.Void> self);
+
+@_DiplomatFfiUse('icu4x_DurationFormatter_create_mv1')
+@ffi.Native<_ResultOpaqueInt32 Function(ffi.Pointer<ffi.Opaque>, ffi.Int32, _ResultUint8Void)>(isLeaf: true, symbol: 'icu4x_DurationFormatter_create_mv1')
+// ignore: non_constant_identifier_names
+external _ResultOpaqueInt32 _icu4x_DurationFormatter_create_mv1(ffi.Pointer<ffi.Opaque> locale, int style, _ResultUint8Void fractionalDigits);
+
+@_DiplomatFfiUse('icu4x_DurationFormatter_create_with_provider_mv1')
+@ffi.Native<_ResultOpaqueInt32 Function(ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>, ffi.Int32, _ResultUint8Void)>(isLeaf: true, symbol: 'icu4x_DurationFormatter_create_with_provider_mv1')
+// ignore: non_constant_identifier_names
+external _ResultOpaqueInt32 _icu4x_DurationFormatter_create_with_provider_mv1(ffi.Pointer<ffi.Opaque> provider, ffi.Pointer<ffi.Opaque> locale, int style, _ResultUint8Void fractionalDigits);
+
+@_DiplomatFfiUse('icu4x_DurationFormatter_format_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Opaque>, _DurationFfi, ffi.Pointer<ffi.Opaque>)>(isLeaf: true, symbol: 'icu4x_DurationFormatter_format_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_DurationFormatter_format_mv1(ffi.Pointer<ffi.Opaque> self, _DurationFfi duration, ffi.Pointer<ffi.Opaque> write);
+
+// dart format on
@@ -0,0 +1,70 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// Additional information: [1](https://docs.rs/icu/2.0.0/icu/experimental/duration/enum.DurationFormatterOptionsError.html), [2](https://docs.rs/icu_provider/2.0.0/icu_provider/struct.DataError.html), [3](https://docs.rs/icu_provider/2.0.0/icu_provider/enum.DataErrorKind.html)
+enum DurationFormatterLoadError {
+
+  unknown,
+
+  displayAlwaysFractional,
+
+  previousFractional,
+
+  previousNumeric,
+
+  fractionalDigitsOutOfRange,
+
+  dataMarkerNotFound,
+
+  dataIdentifierNotFound,
+
+  dataInvalidRequest,
+
+  dataInconsistentData,
+
+  dataDowncast,
+
+  dataDeserialize,
+
+  dataCustom,
+
+  dataIo;
+
+  int get _ffi {
+    switch (this) {
+      case unknown:
+        return 0;
+      case displayAlwaysFractional:
+        return 2049;
+      case previousFractional:
+        return 2050;
+      case previousNumeric:
+        return 2051;
+      case fractionalDigitsOutOfRange:
+        return 2052;
+      case dataMarkerNotFound:
+        return 1;
+      case dataIdentifierNotFound:
+        return 2;
+      case dataInvalidRequest:
+        return 3;
+      case dataInconsistentData:
+        return 4;
+      case dataDowncast:
+        return 5;
+      case dataDeserialize:
+        return 6;
+      case dataCustom:
+        return 7;
+      case dataIo:
+        return 8;
+    }
+  }
+
+}
+
+// dart format on
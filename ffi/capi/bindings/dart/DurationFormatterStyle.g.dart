@@ -0,0 +1,21 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// See the [Rust documentation for `BaseStyle`](https://docs.rs/icu/2.0.0/icu/experimental/duration/options/enum.BaseStyle.html) for more information.
+enum DurationFormatterStyle {
+
+  narrow,
+
+  short,
+
+  long,
+
+  digital;
+
+}
+
+// dart format on
@@ -0,0 +1,163 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// A list of locales ordered by preference, such as one parsed from an
+/// HTTP `Accept-Language` header or the `LANGUAGE` environment variable.
+///
+/// See the [Rust documentation for `LanguagePriorityList`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html) for more information.
+final class LocalePriorityList implements ffi.Finalizable {
+  final ffi.Pointer<ffi.Opaque> _ffi;
+
+  // These are "used" in the sense that they keep dependencies alive
+  // ignore: unused_field
+  final core.List<Object> _selfEdge;
+
+  // This takes in a list of lifetime edges (including for &self borrows)
+  // corresponding to data this may borrow from. These should be flat arrays containing
+  // references to objects, and this object will hold on to them to keep them alive and
+  // maintain borrow validity.
+  LocalePriorityList._fromFfi(this._ffi, this._selfEdge) {
+    if (_selfEdge.isEmpty) {
+      _finalizer.attach(this, _ffi.cast());
+    }
+  }
+
+  @_DiplomatFfiUse('icu4x_LocalePriorityList_destroy_mv1')
+  static final _finalizer = ffi.NativeFinalizer(ffi.Native.addressOf(_icu4x_LocalePriorityList_destroy_mv1));
+
+  /// Parses an HTTP `Accept-Language` header value, such as `"fr-CH, fr;q=0.9, *;q=0.5"`.
+  ///
+  /// See the [Rust documentation for `try_from_accept_language`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.try_from_accept_language) for more information.
+  ///
+  /// Throws [LocaleParseError] on failure.
+  factory LocalePriorityList.fromAcceptLanguage(String s) {
+    final temp = _FinalizedArena();
+    final result = _icu4x_LocalePriorityList_from_accept_language_mv1(s._utf8AllocIn(temp.arena));
+    if (!result.isOk) {
+      throw LocaleParseError.values[result.union.err];
+    }
+    return LocalePriorityList._fromFfi(result.union.ok, []);
+  }
+
+  /// Parses an HTTP `Accept-Language` header value, skipping or repairing malformed entries.
+  ///
+  /// See the [Rust documentation for `from_accept_language_lossy`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.from_accept_language_lossy) for more information.
+  factory LocalePriorityList.fromAcceptLanguageLossy(String s) {
+    final temp = _FinalizedArena();
+    final result = _icu4x_LocalePriorityList_from_accept_language_lossy_mv1(s._utf8AllocIn(temp.arena));
+    return LocalePriorityList._fromFfi(result, []);
+  }
+
+  /// Parses a colon-separated list of locales, such as the `LANGUAGE` environment variable.
+  ///
+  /// See the [Rust documentation for `try_from_colon_separated`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.try_from_colon_separated) for more information.
+  ///
+  /// Throws [LocaleParseError] on failure.
+  factory LocalePriorityList.fromColonSeparated(String s) {
+    final temp = _FinalizedArena();
+    final result = _icu4x_LocalePriorityList_from_colon_separated_mv1(s._utf8AllocIn(temp.arena));
+    if (!result.isOk) {
+      throw LocaleParseError.values[result.union.err];
+    }
+    return LocalePriorityList._fromFfi(result.union.ok, []);
+  }
+
+  /// Parses a colon-separated list of locales, skipping or repairing malformed entries,
+  /// such as POSIX locale names like `de_DE.UTF-8`.
+  ///
+  /// See the [Rust documentation for `from_colon_separated_lossy`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.from_colon_separated_lossy) for more information.
+  factory LocalePriorityList.fromColonSeparatedLossy(String s) {
+    final temp = _FinalizedArena();
+    final result = _icu4x_LocalePriorityList_from_colon_separated_lossy_mv1(s._utf8AllocIn(temp.arena));
+    return LocalePriorityList._fromFfi(result, []);
+  }
+
+  /// The number of entries in this list, including wildcards.
+  ///
+  /// See the [Rust documentation for `len`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.len) for more information.
+  int get length {
+    final result = _icu4x_LocalePriorityList_length_mv1(_ffi);
+    return result;
+  }
+
+  /// Returns the locale of the entry at the given index.
+  ///
+  /// Returns `None` if the index is out of bounds or the entry is the wildcard `*`.
+  ///
+  /// See the [Rust documentation for `iter`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.iter) for more information.
+  Locale? localeAt(int index) {
+    final result = _icu4x_LocalePriorityList_locale_at_mv1(_ffi, index);
+    return result.address == 0 ? null : Locale._fromFfi(result, []);
+  }
+
+  /// Returns whether the entry at the given index is the wildcard `*`.
+  ///
+  /// See the [Rust documentation for `iter`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.iter) for more information.
+  bool isWildcardAt(int index) {
+    final result = _icu4x_LocalePriorityList_is_wildcard_at_mv1(_ffi, index);
+    return result;
+  }
+
+  /// Returns the weight of the entry at the given index, in thousandths between 0 and 1000.
+  ///
+  /// Returns `None` if the index is out of bounds.
+  ///
+  /// See the [Rust documentation for `Weight`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.Weight.html) for more information.
+  int? weightAt(int index) {
+    final result = _icu4x_LocalePriorityList_weight_at_mv1(_ffi, index);
+    if (!result.isOk) {
+      return null;
+    }
+    return result.union.ok;
+  }
+
+}
+
+@_DiplomatFfiUse('icu4x_LocalePriorityList_destroy_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Void>)>(isLeaf: true, symbol: 'icu4x_LocalePriorityList_destroy_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_LocalePriorityList_destroy_mv1(ffi.Pointer<ffi.Void> self);
+
+@_DiplomatFfiUse('icu4x_LocalePriorityList_from_accept_language_mv1')
+@ffi.Native<_ResultOpaqueInt32 Function(_SliceUtf8)>(isLeaf: true, symbol: 'icu4x_LocalePriorityList_from_accept_language_mv1')
+// ignore: non_constant_identifier_names
+external _ResultOpaqueInt32 _icu4x_LocalePriorityList_from_accept_language_mv1(_SliceUtf8 s);
+
+@_DiplomatFfiUse('icu4x_LocalePriorityList_from_accept_language_lossy_mv1')
+@ffi.Native<ffi.Pointer<ffi.Opaque> Function(_SliceUtf8)>(isLeaf: true, symbol: 'icu4x_LocalePriorityList_from_accept_language_lossy_mv1')
+// ignore: non_constant_identifier_names
+external ffi.Pointer<ffi.Opaque> _icu4x_LocalePriorityList_from_accept_language_lossy_mv1(_SliceUtf8 s);
+
+@_DiplomatFfiUse('icu4x_LocalePriorityList_from_colon_separated_mv1')
+@ffi.Native<_ResultOpaqueInt32 Function(_SliceUtf8)>(isLeaf: true, symbol: 'icu4x_LocalePriorityList_from_colon_separated_mv1')
+// ignore: non_constant_identifier_names
+external _ResultOpaqueInt32 _icu4x_LocalePriorityList_from_colon_separated_mv1(_SliceUtf8 s);
+
+@_DiplomatFfiUse('icu4x_LocalePriorityList_from_colon_separated_lossy_mv1')
+@ffi.Native<ffi.Pointer<ffi.Opaque> Function(_SliceUtf8)>(isLeaf: true, symbol: 'icu4x_LocalePriorityList_from_colon_separated_lossy_mv1')
+// ignore: non_constant_identifier_names
+external ffi.Pointer<ffi.Opaque> _icu4x_LocalePriorityList_from_colon_separated_lossy_mv1(_SliceUtf8 s);
+
+@_DiplomatFfiUse('icu4x_LocalePriorityList_length_mv1')
+@ffi.Native<ffi.Size Function(ffi.Pointer<ffi.Opaque>)>(isLeaf: true, symbol: 'icu4x_LocalePriorityList_length_mv1')
+// ignore: non_constant_identifier_names
+external int _icu4x_LocalePriorityList_length_mv1(ffi.Pointer<ffi.Opaque> self);
+
+@_DiplomatFfiUse('icu4x_LocalePriorityList_locale_at_mv1')
+@ffi.Native<ffi.Pointer<ffi.Opaque> Function(ffi.Pointer<ffi.Opaque>, ffi.Size)>(isLeaf: true, symbol: 'icu4x_LocalePriorityList_locale_at_mv1')
+// ignore: non_constant_identifier_names
+external ffi.Pointer<ffi.Opaque> _icu4x_LocalePriorityList_locale_at_mv1(ffi.Pointer<ffi.Opaque> self, int index);
+
+@_DiplomatFfiUse('icu4x_LocalePriorityList_is_wildcard_at_mv1')
+@ffi.Native<ffi.Bool Function(ffi.Pointer<ffi.Opaque>, ffi.Size)>(isLeaf: true, symbol: 'icu4x_LocalePriorityList_is_wildcard_at_mv1')
+// ignore: non_constant_identifier_names
+external bool _icu4x_LocalePriorityList_is_wildcard_at_mv1(ffi.Pointer<ffi.Opaque> self, int index);
+
+@_DiplomatFfiUse('icu4x_LocalePriorityList_weight_at_mv1')
+@ffi.Native<_ResultUint16Void Function(ffi.Pointer<ffi.Opaque>, ffi.Size)>(isLeaf: true, symbol: 'icu4x_LocalePriorityList_weight_at_mv1')
+// ignore: non_constant_identifier_names
+external _ResultUint16Void _icu4x_LocalePriorityList_weight_at_mv1(ffi.Pointer<ffi.Opaque> self, int index);
+
+// dart format on
@@ -0,0 +1,54 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// A CLDR unit identifier, such as `meter` or `kilometer-per-hour`.
+///
+/// See the [Rust documentation for `MeasureUnit`](https://docs.rs/icu/2.0.0/icu/experimental/measure/measureunit/struct.MeasureUnit.html) for more information.
+final class MeasureUnit implements ffi.Finalizable {
+  final ffi.Pointer<ffi.Opaque> _ffi;
+
+  // These are "used" in the sense that they keep dependencies alive
+  // ignore: unused_field
+  final core.List<Object> _selfEdge;
+
+  // This takes in a list of lifetime edges (including for &self borrows)
+  // corresponding to data this may borrow from. These should be flat arrays containing
+  // references to objects, and this object will hold on to them to keep them alive and
+  // maintain borrow validity.
+  MeasureUnit._fromFfi(this._ffi, this._selfEdge) {
+    if (_selfEdge.isEmpty) {
+      _finalizer.attach(this, _ffi.cast());
+    }
+  }
+
+  @_DiplomatFfiUse('icu4x_MeasureUnit_destroy_mv1')
+  static final _finalizer = ffi.NativeFinalizer(ffi.Native.addressOf(_icu4x_MeasureUnit_destroy_mv1));
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Parses a CLDR unit identifier, returning `null` if it is not valid.
+  ///
+  /// See the [Rust documentation for `try_from_str`](https://docs.rs/icu/2.0.0/icu/experimental/measure/measureunit/struct.MeasureUnit.html#method.try_from_str) for more information.
+  static MeasureUnit? fromString(String unit) {
+    final temp = _FinalizedArena();
+    final result = _icu4x_MeasureUnit_from_string_mv1(unit._utf8AllocIn(temp.arena));
+    return result.address == 0 ? null : MeasureUnit._fromFfi(result, []);
+  }
+
+}
+
+@_DiplomatFfiUse('icu4x_MeasureUnit_destroy_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Void>)>(isLeaf: true, symbol: 'icu4x_MeasureUnit_destroy_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_MeasureUnit_destroy_mv1(ffi.Pointer<ffi.Void> self);
+
+@_DiplomatFfiUse('icu4x_MeasureUnit_from_string_mv1')
+@ffi.Native<ffi.Pointer<ffi.Opaque> Function(_SliceUtf8)>(isLeaf: true, symbol: 'icu4x_MeasureUnit_from_string_mv1')
+// ignore: non_constant_identifier_names
+external ffi.Pointer<ffi.Opaque> _icu4x_MeasureUnit_from_string_mv1(_SliceUtf8 unit);
+
+// dart format on
@@ -0,0 +1,91 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// A person name to be formatted with a [PersonNamesFormatter], built up field by field.
+///
+/// See the [Rust documentation for `DefaultPersonName`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/provided_struct/struct.DefaultPersonName.html) for more information.
+final class PersonName implements ffi.Finalizable {
+  final ffi.Pointer<ffi.Opaque> _ffi;
+
+  // These are "used" in the sense that they keep dependencies alive
+  // ignore: unused_field
+  final core.List<Object> _selfEdge;
+
+  // This takes in a list of lifetime edges (including for &self borrows)
+  // corresponding to data this may borrow from. These should be flat arrays containing
+  // references to objects, and this object will hold on to them to keep them alive and
+  // maintain borrow validity.
+  PersonName._fromFfi(this._ffi, this._selfEdge) {
+    if (_selfEdge.isEmpty) {
+      _finalizer.attach(this, _ffi.cast());
+    }
+  }
+
+  @_DiplomatFfiUse('icu4x_PersonName_destroy_mv1')
+  static final _finalizer = ffi.NativeFinalizer(ffi.Native.addressOf(_icu4x_PersonName_destroy_mv1));
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Creates an empty [PersonName].
+  factory PersonName() {
+    final result = _icu4x_PersonName_create_mv1();
+    return PersonName._fromFfi(result, []);
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Sets the value of a name field, replacing any previous value.
+  ///
+  /// Ill-formed input is treated as if errors had been replaced with REPLACEMENT CHARACTERs according
+  /// to the WHATWG Encoding Standard.
+  void setField(PersonNameFieldKind kind, String value) {
+    final temp = _FinalizedArena();
+    _icu4x_PersonName_set_field_mv1(_ffi, kind.index, value._utf8AllocIn(temp.arena));
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Sets the locale of the name itself, which may differ from the formatting locale.
+  void setLocale(Locale locale) {
+    _icu4x_PersonName_set_locale_mv1(_ffi, locale._ffi);
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Sets the order in which the name prefers its fields to be displayed.
+  void setPreferredOrder(PersonNamePreferredOrder order) {
+    _icu4x_PersonName_set_preferred_order_mv1(_ffi, order.index);
+  }
+
+}
+
+@_DiplomatFfiUse('icu4x_PersonName_destroy_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Void>)>(isLeaf: true, symbol: 'icu4x_PersonName_destroy_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_PersonName_destroy_mv1(ffi.Pointer<ffi.Void> self);
+
+@_DiplomatFfiUse('icu4x_PersonName_create_mv1')
+@ffi.Native<ffi.Pointer<ffi.Opaque> Function()>(isLeaf: true, symbol: 'icu4x_PersonName_create_mv1')
+// ignore: non_constant_identifier_names
+external ffi.Pointer<ffi.Opaque> _icu4x_PersonName_create_mv1();
+
+@_DiplomatFfiUse('icu4x_PersonName_set_field_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Opaque>, ffi.Int32, _SliceUtf8)>(isLeaf: true, symbol: 'icu4x_PersonName_set_field_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_PersonName_set_field_mv1(ffi.Pointer<ffi.Opaque> self, int kind, _SliceUtf8 value);
+
+@_DiplomatFfiUse('icu4x_PersonName_set_locale_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>)>(isLeaf: true, symbol: 'icu4x_PersonName_set_locale_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_PersonName_set_locale_mv1(ffi.Pointer<ffi.Opaque> self, ffi.Pointer<ffi.Opaque> locale);
+
+@_DiplomatFfiUse('icu4x_PersonName_set_preferred_order_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Opaque>, ffi.Int32)>(isLeaf: true, symbol: 'icu4x_PersonName_set_preferred_order_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_PersonName_set_preferred_order_mv1(ffi.Pointer<ffi.Opaque> self, int order);
+
+// dart format on
@@ -0,0 +1,27 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// See the [Rust documentation for `NameFieldKind`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.NameFieldKind.html) for more information.
+enum PersonNameFieldKind {
+
+  title,
+
+  given,
+
+  given2,
+
+  surname,
+
+  surname2,
+
+  generation,
+
+  credentials;
+
+}
+
+// dart format on
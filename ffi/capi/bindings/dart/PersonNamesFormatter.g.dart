@@ -0,0 +1,123 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// An ICU4X Person Names Formatter object, capable of formatting a [PersonName]
+/// according to the conventions of a locale, such as "Wells, H. G.".
+///
+/// See the [Rust documentation for `PersonNamesFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html) for more information.
+final class PersonNamesFormatter implements ffi.Finalizable {
+  final ffi.Pointer<ffi.Opaque> _ffi;
+
+  // These are "used" in the sense that they keep dependencies alive
+  // ignore: unused_field
+  final core.List<Object> _selfEdge;
+
+  // This takes in a list of lifetime edges (including for &self borrows)
+  // corresponding to data this may borrow from. These should be flat arrays containing
+  // references to objects, and this object will hold on to them to keep them alive and
+  // maintain borrow validity.
+  PersonNamesFormatter._fromFfi(this._ffi, this._selfEdge) {
+    if (_selfEdge.isEmpty) {
+      _finalizer.attach(this, _ffi.cast());
+    }
+  }
+
+  @_DiplomatFfiUse('icu4x_PersonNamesFormatter_destroy_mv1')
+  static final _finalizer = ffi.NativeFinalizer(ffi.Native.addressOf(_icu4x_PersonNamesFormatter_destroy_mv1));
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Creates a new [PersonNamesFormatter] using compiled data.
+  ///
+  /// See the [Rust documentation for `try_new_unstable`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.try_new_unstable) for more information.
+  ///
+  /// Throws [PersonNamesFormatterError] on failure.
+  factory PersonNamesFormatter(Locale locale, PersonNamesFormatterOrder order, PersonNamesFormatterLength length, PersonNamesFormatterUsage usage, PersonNamesFormatterFormality formality) {
+    final result = _icu4x_PersonNamesFormatter_create_mv1(locale._ffi, order.index, length.index, usage.index, formality.index);
+    if (!result.isOk) {
+      throw PersonNamesFormatterError.values.firstWhere((v) => v._ffi == result.union.err);
+    }
+    return PersonNamesFormatter._fromFfi(result.union.ok, []);
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Creates a new [PersonNamesFormatter] using a particular data source.
+  ///
+  /// Names must then be formatted with [Self::format_with_provider], using the same data source.
+  ///
+  /// See the [Rust documentation for `try_new_unstable`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.try_new_unstable) for more information.
+  ///
+  /// Throws [PersonNamesFormatterError] on failure.
+  factory PersonNamesFormatter.withProvider(DataProvider provider, Locale locale, PersonNamesFormatterOrder order, PersonNamesFormatterLength length, PersonNamesFormatterUsage usage, PersonNamesFormatterFormality formality) {
+    final result = _icu4x_PersonNamesFormatter_create_with_provider_mv1(provider._ffi, locale._ffi, order.index, length.index, usage.index, formality.index);
+    if (!result.isOk) {
+      throw PersonNamesFormatterError.values.firstWhere((v) => v._ffi == result.union.err);
+    }
+    return PersonNamesFormatter._fromFfi(result.union.ok, []);
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Formats a [PersonName] to a string, using compiled data.
+  ///
+  /// See the [Rust documentation for `format_to_string`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.format_to_string) for more information.
+  ///
+  /// Throws [PersonNamesFormatterError] on failure.
+  String format(PersonName name) {
+    final write = _Write();
+    final result = _icu4x_PersonNamesFormatter_format_mv1(_ffi, name._ffi, write._ffi);
+    if (!result.isOk) {
+      throw PersonNamesFormatterError.values.firstWhere((v) => v._ffi == result.union.err);
+    }
+    return write.finalize();
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Formats a [PersonName] to a string, using a particular data source.
+  ///
+  /// See the [Rust documentation for `format_to_string`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.format_to_string) for more information.
+  ///
+  /// Throws [PersonNamesFormatterError] on failure.
+  String formatWithProvider(DataProvider provider, PersonName name) {
+    final write = _Write();
+    final result = _icu4x_PersonNamesFormatter_format_with_provider_mv1(_ffi, provider._ffi, name._ffi, write._ffi);
+    if (!result.isOk) {
+      throw PersonNamesFormatterError.values.firstWhere((v) => v._ffi == result.union.err);
+    }
+    return write.finalize();
+  }
+
+}
+
+@_DiplomatFfiUse('icu4x_PersonNamesFormatter_destroy_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Void>)>(isLeaf: true, symbol: 'icu4x_PersonNamesFormatter_destroy_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_PersonNamesFormatter_destroy_mv1(ffi.Pointer<ffi.Void> self);
+
+@_DiplomatFfiUse('icu4x_PersonNamesFormatter_create_mv1')
+@ffi.Native<_ResultOpaqueInt32 Function(ffi.Pointer<ffi.Opaque>, ffi.Int32, ffi.Int32, ffi.Int32, ffi.Int32)>(isLeaf: true, symbol: 'icu4x_PersonNamesFormatter_create_mv1')
+// ignore: non_constant_identifier_names
+external _ResultOpaqueInt32 _icu4x_PersonNamesFormatter_create_mv1(ffi.Pointer<ffi.Opaque> locale, int order, int length, int usage, int formality);
+
+@_DiplomatFfiUse('icu4x_PersonNamesFormatter_create_with_provider_mv1')
+@ffi.Native<_ResultOpaqueInt32 Function(ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>, ffi.Int32, ffi.Int32, ffi.Int32, ffi.Int32)>(isLeaf: true, symbol: 'icu4x_PersonNamesFormatter_create_with_provider_mv1')
+// ignore: non_constant_identifier_names
+external _ResultOpaqueInt32 _icu4x_PersonNamesFormatter_create_with_provider_mv1(ffi.Pointer<ffi.Opaque> provider, ffi.Pointer<ffi.Opaque> locale, int order, int length, int usage, int formality);
+
+@_DiplomatFfiUse('icu4x_PersonNamesFormatter_format_mv1')
+@ffi.Native<_ResultVoidInt32 Function(ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>)>(isLeaf: true, symbol: 'icu4x_PersonNamesFormatter_format_mv1')
+// ignore: non_constant_identifier_names
+external _ResultVoidInt32 _icu4x_PersonNamesFormatter_format_mv1(ffi.Pointer<ffi.Opaque> self, ffi.Pointer<ffi.Opaque> name, ffi.Pointer<ffi.Opaque> write);
+
+@_DiplomatFfiUse('icu4x_PersonNamesFormatter_format_with_provider_mv1')
+@ffi.Native<_ResultVoidInt32 Function(ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>)>(isLeaf: true, symbol: 'icu4x_PersonNamesFormatter_format_with_provider_mv1')
+// ignore: non_constant_identifier_names
+external _ResultVoidInt32 _icu4x_PersonNamesFormatter_format_with_provider_mv1(ffi.Pointer<ffi.Opaque> self, ffi.Pointer<ffi.Opaque> provider, ffi.Pointer<ffi.Opaque> name, ffi.Pointer<ffi.Opaque> write);
+
+// dart format on
@@ -0,0 +1,17 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// See the [Rust documentation for `FormattingFormality`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.FormattingFormality.html) for more information.
+enum PersonNamesFormatterFormality {
+
+  formal,
+
+  informal;
+
+}
+
+// dart format on
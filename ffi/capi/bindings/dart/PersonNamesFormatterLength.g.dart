@@ -0,0 +1,19 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// See the [Rust documentation for `FormattingLength`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.FormattingLength.html) for more information.
+enum PersonNamesFormatterLength {
+
+  short,
+
+  medium,
+
+  long;
+
+}
+
+// dart format on
@@ -0,0 +1,19 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// See the [Rust documentation for `FormattingUsage`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.FormattingUsage.html) for more information.
+enum PersonNamesFormatterUsage {
+
+  addressing,
+
+  referring,
+
+  monogram;
+
+}
+
+// dart format on
@@ -0,0 +1,97 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// An ICU4X Relative Time Format object, capable of formatting a [Decimal] number of
+/// a fixed time unit relative to now, such as "in 3 days" or "yesterday".
+///
+/// See the [Rust documentation for `RelativeTimeFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html) for more information.
+final class RelativeTimeFormatter implements ffi.Finalizable {
+  final ffi.Pointer<ffi.Opaque> _ffi;
+
+  // These are "used" in the sense that they keep dependencies alive
+  // ignore: unused_field
+  final core.List<Object> _selfEdge;
+
+  // This takes in a list of lifetime edges (including for &self borrows)
+  // corresponding to data this may borrow from. These should be flat arrays containing
+  // references to objects, and this object will hold on to them to keep them alive and
+  // maintain borrow validity.
+  RelativeTimeFormatter._fromFfi(this._ffi, this._selfEdge) {
+    if (_selfEdge.isEmpty) {
+      _finalizer.attach(this, _ffi.cast());
+    }
+  }
+
+  @_DiplomatFfiUse('icu4x_RelativeTimeFormatter_destroy_mv1')
+  static final _finalizer = ffi.NativeFinalizer(ffi.Native.addressOf(_icu4x_RelativeTimeFormatter_destroy_mv1));
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Creates a new [RelativeTimeFormatter] for the given unit and length, using compiled data.
+  ///
+  /// See the [Rust documentation for `try_new_long_second`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html#method.try_new_long_second) for more information.
+  ///
+  /// Throws [DataError] on failure.
+  factory RelativeTimeFormatter(Locale locale, RelativeTimeUnit unit, RelativeTimeLength length, RelativeTimeNumeric numeric) {
+    final result = _icu4x_RelativeTimeFormatter_create_mv1(locale._ffi, unit.index, length.index, numeric.index);
+    if (!result.isOk) {
+      throw DataError.values[result.union.err];
+    }
+    return RelativeTimeFormatter._fromFfi(result.union.ok, []);
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Creates a new [RelativeTimeFormatter] for the given unit and length, using a particular data source.
+  ///
+  /// See the [Rust documentation for `try_new_long_second`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html#method.try_new_long_second) for more information.
+  ///
+  /// Throws [DataError] on failure.
+  factory RelativeTimeFormatter.withProvider(DataProvider provider, Locale locale, RelativeTimeUnit unit, RelativeTimeLength length, RelativeTimeNumeric numeric) {
+    final result = _icu4x_RelativeTimeFormatter_create_with_provider_mv1(provider._ffi, locale._ffi, unit.index, length.index, numeric.index);
+    if (!result.isOk) {
+      throw DataError.values[result.union.err];
+    }
+    return RelativeTimeFormatter._fromFfi(result.union.ok, []);
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Formats a [Decimal] number of units relative to now.
+  ///
+  /// Negative values are formatted in the past, positive values (and zero) in the future.
+  ///
+  /// See the [Rust documentation for `format`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html#method.format) for more information.
+  String format(Decimal value) {
+    final write = _Write();
+    _icu4x_RelativeTimeFormatter_format_mv1(_ffi, value._ffi, write._ffi);
+    return write.finalize();
+  }
+
+}
+
+@_DiplomatFfiUse('icu4x_RelativeTimeFormatter_destroy_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Void>)>(isLeaf: true, symbol: 'icu4x_RelativeTimeFormatter_destroy_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_RelativeTimeFormatter_destroy_mv1(ffi.Pointer<ffi.Void> self);
+
+@_DiplomatFfiUse('icu4x_RelativeTimeFormatter_create_mv1')
+@ffi.Native<_ResultOpaqueInt32 Function(ffi.Pointer<ffi.Opaque>, ffi.Int32, ffi.Int32, ffi.Int32)>(isLeaf: true, symbol: 'icu4x_RelativeTimeFormatter_create_mv1')
+// ignore: non_constant_identifier_names
+external _ResultOpaqueInt32 _icu4x_RelativeTimeFormatter_create_mv1(ffi.Pointer<ffi.Opaque> locale, int unit, int length, int numeric);
+
+@_DiplomatFfiUse('icu4x_RelativeTimeFormatter_create_with_provider_mv1')
+@ffi.Native<_ResultOpaqueInt32 Function(ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>, ffi.Int32, ffi.Int32, ffi.Int32)>(isLeaf: true, symbol: 'icu4x_RelativeTimeFormatter_create_with_provider_mv1')
+// ignore: non_constant_identifier_names
+external _ResultOpaqueInt32 _icu4x_RelativeTimeFormatter_create_with_provider_mv1(ffi.Pointer<ffi.Opaque> provider, ffi.Pointer<ffi.Opaque> locale, int unit, int length, int numeric);
+
+@_DiplomatFfiUse('icu4x_RelativeTimeFormatter_format_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>)>(isLeaf: true, symbol: 'icu4x_RelativeTimeFormatter_format_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_RelativeTimeFormatter_format_mv1(ffi.Pointer<ffi.Opaque> self, ffi.Pointer<ffi.Opaque> value, ffi.Pointer<ffi.Opaque> write);
+
+// dart format on
@@ -0,0 +1,19 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// The width of the unit names used by a [RelativeTimeFormatter].
+enum RelativeTimeLength {
+
+  long,
+
+  short,
+
+  narrow;
+
+}
+
+// dart format on
@@ -0,0 +1,29 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// The time unit formatted by a [RelativeTimeFormatter].
+enum RelativeTimeUnit {
+
+  second,
+
+  minute,
+
+  hour,
+
+  day,
+
+  week,
+
+  month,
+
+  quarter,
+
+  year;
+
+}
+
+// dart format on
@@ -0,0 +1,99 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// An ICU4X Transliterator object, capable of transliterating text from one script or
+/// form to another, as identified by a BCP-47 `-t-` locale such as `und-Arab-t-und-beng`.
+///
+/// See the [Rust documentation for `Transliterator`](https://docs.rs/icu/2.0.0/icu/experimental/transliterate/struct.Transliterator.html) for more information.
+final class Transliterator implements ffi.Finalizable {
+  final ffi.Pointer<ffi.Opaque> _ffi;
+
+  // These are "used" in the sense that they keep dependencies alive
+  // ignore: unused_field
+  final core.List<Object> _selfEdge;
+
+  // This takes in a list of lifetime edges (including for &self borrows)
+  // corresponding to data this may borrow from. These should be flat arrays containing
+  // references to objects, and this object will hold on to them to keep them alive and
+  // maintain borrow validity.
+  Transliterator._fromFfi(this._ffi, this._selfEdge) {
+    if (_selfEdge.isEmpty) {
+      _finalizer.attach(this, _ffi.cast());
+    }
+  }
+
+  @_DiplomatFfiUse('icu4x_Transliterator_destroy_mv1')
+  static final _finalizer = ffi.NativeFinalizer(ffi.Native.addressOf(_icu4x_Transliterator_destroy_mv1));
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Creates a new [Transliterator] for the given BCP-47 `-t-` locale, using compiled data.
+  ///
+  /// See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/transliterate/struct.Transliterator.html#method.try_new) for more information.
+  ///
+  /// Throws [DataError] on failure.
+  factory Transliterator(Locale locale) {
+    final result = _icu4x_Transliterator_create_mv1(locale._ffi);
+    if (!result.isOk) {
+      throw DataError.values[result.union.err];
+    }
+    return Transliterator._fromFfi(result.union.ok, []);
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Creates a new [Transliterator] for the given BCP-47 `-t-` locale, using a particular data source.
+  ///
+  /// See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/transliterate/struct.Transliterator.html#method.try_new) for more information.
+  ///
+  /// Throws [DataError] on failure.
+  factory Transliterator.withProvider(DataProvider provider, Locale locale) {
+    final result = _icu4x_Transliterator_create_with_provider_mv1(provider._ffi, locale._ffi);
+    if (!result.isOk) {
+      throw DataError.values[result.union.err];
+    }
+    return Transliterator._fromFfi(result.union.ok, []);
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Transliterates `input`.
+  ///
+  /// Ill-formed input is treated as if errors had been replaced with REPLACEMENT CHARACTERs according
+  /// to the WHATWG Encoding Standard.
+  ///
+  /// See the [Rust documentation for `transliterate`](https://docs.rs/icu/2.0.0/icu/experimental/transliterate/struct.Transliterator.html#method.transliterate) for more information.
+  String transliterate(String input) {
+    final temp = _FinalizedArena();
+    final write = _Write();
+    _icu4x_Transliterator_transliterate_mv1(_ffi, input._utf8AllocIn(temp.arena), write._ffi);
+    return write.finalize();
+  }
+
+}
+
+@_DiplomatFfiUse('icu4x_Transliterator_destroy_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Void>)>(isLeaf: true, symbol: 'icu4x_Transliterator_destroy_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_Transliterator_destroy_mv1(ffi.Pointer<ffi.Void> self);
+
+@_DiplomatFfiUse('icu4x_Transliterator_create_mv1')
+@ffi.Native<_ResultOpaqueInt32 Function(ffi.Pointer<ffi.Opaque>)>(isLeaf: true, symbol: 'icu4x_Transliterator_create_mv1')
+// ignore: non_constant_identifier_names
+external _ResultOpaqueInt32 _icu4x_Transliterator_create_mv1(ffi.Pointer<ffi.Opaque> locale);
+
+@_DiplomatFfiUse('icu4x_Transliterator_create_with_provider_mv1')
+@ffi.Native<_ResultOpaqueInt32 Function(ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>)>(isLeaf: true, symbol: 'icu4x_Transliterator_create_with_provider_mv1')
+// ignore: non_constant_identifier_names
+external _ResultOpaqueInt32 _icu4x_Transliterator_create_with_provider_mv1(ffi.Pointer<ffi.Opaque> provider, ffi.Pointer<ffi.Opaque> locale);
+
+@_DiplomatFfiUse('icu4x_Transliterator_transliterate_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Opaque>, _SliceUtf8, ffi.Pointer<ffi.Opaque>)>(isLeaf: true, symbol: 'icu4x_Transliterator_transliterate_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_Transliterator_transliterate_mv1(ffi.Pointer<ffi.Opaque> self, _SliceUtf8 input, ffi.Pointer<ffi.Opaque> write);
+
+// dart format on
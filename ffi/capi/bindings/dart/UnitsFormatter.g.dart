@@ -0,0 +1,97 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// An ICU4X Units Formatter object, capable of formatting a [Decimal] as a quantity of
+/// a particular unit, such as "12 meters".
+///
+/// See the [Rust documentation for `UnitsFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/units/formatter/struct.UnitsFormatter.html) for more information.
+final class UnitsFormatter implements ffi.Finalizable {
+  final ffi.Pointer<ffi.Opaque> _ffi;
+
+  // These are "used" in the sense that they keep dependencies alive
+  // ignore: unused_field
+  final core.List<Object> _selfEdge;
+
+  // This takes in a list of lifetime edges (including for &self borrows)
+  // corresponding to data this may borrow from. These should be flat arrays containing
+  // references to objects, and this object will hold on to them to keep them alive and
+  // maintain borrow validity.
+  UnitsFormatter._fromFfi(this._ffi, this._selfEdge) {
+    if (_selfEdge.isEmpty) {
+      _finalizer.attach(this, _ffi.cast());
+    }
+  }
+
+  @_DiplomatFfiUse('icu4x_UnitsFormatter_destroy_mv1')
+  static final _finalizer = ffi.NativeFinalizer(ffi.Native.addressOf(_icu4x_UnitsFormatter_destroy_mv1));
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Creates a new [UnitsFormatter] for the given CLDR unit identifier (such as `meter`), using compiled data.
+  ///
+  /// See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/units/formatter/struct.UnitsFormatter.html#method.try_new) for more information.
+  ///
+  /// Throws [DataError] on failure.
+  factory UnitsFormatter(Locale locale, String unit, UnitsFormatterWidth width) {
+    final temp = _FinalizedArena();
+    final result = _icu4x_UnitsFormatter_create_mv1(locale._ffi, unit._utf8AllocIn(temp.arena), width.index);
+    if (!result.isOk) {
+      throw DataError.values[result.union.err];
+    }
+    return UnitsFormatter._fromFfi(result.union.ok, []);
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Creates a new [UnitsFormatter] for the given CLDR unit identifier (such as `meter`), using a particular data source.
+  ///
+  /// See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/units/formatter/struct.UnitsFormatter.html#method.try_new) for more information.
+  ///
+  /// Throws [DataError] on failure.
+  factory UnitsFormatter.withProvider(DataProvider provider, Locale locale, String unit, UnitsFormatterWidth width) {
+    final temp = _FinalizedArena();
+    final result = _icu4x_UnitsFormatter_create_with_provider_mv1(provider._ffi, locale._ffi, unit._utf8AllocIn(temp.arena), width.index);
+    if (!result.isOk) {
+      throw DataError.values[result.union.err];
+    }
+    return UnitsFormatter._fromFfi(result.union.ok, []);
+  }
+
+  /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+  ///
+  /// Formats a [Decimal] as a quantity of this formatter's unit.
+  ///
+  /// See the [Rust documentation for `format_fixed_decimal`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/units/formatter/struct.UnitsFormatter.html#method.format_fixed_decimal) for more information.
+  String format(Decimal value) {
+    final write = _Write();
+    _icu4x_UnitsFormatter_format_mv1(_ffi, value._ffi, write._ffi);
+    return write.finalize();
+  }
+
+}
+
+@_DiplomatFfiUse('icu4x_UnitsFormatter_destroy_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Void>)>(isLeaf: true, symbol: 'icu4x_UnitsFormatter_destroy_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_UnitsFormatter_destroy_mv1(ffi.Pointer<ffi.Void> self);
+
+@_DiplomatFfiUse('icu4x_UnitsFormatter_create_mv1')
+@ffi.Native<_ResultOpaqueInt32 Function(ffi.Pointer<ffi.Opaque>, _SliceUtf8, ffi.Int32)>(isLeaf: true, symbol: 'icu4x_UnitsFormatter_create_mv1')
+// ignore: non_constant_identifier_names
+external _ResultOpaqueInt32 _icu4x_UnitsFormatter_create_mv1(ffi.Pointer<ffi.Opaque> locale, _SliceUtf8 unit, int width);
+
+@_DiplomatFfiUse('icu4x_UnitsFormatter_create_with_provider_mv1')
+@ffi.Native<_ResultOpaqueInt32 Function(ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>, _SliceUtf8, ffi.Int32)>(isLeaf: true, symbol: 'icu4x_UnitsFormatter_create_with_provider_mv1')
+// ignore: non_constant_identifier_names
+external _ResultOpaqueInt32 _icu4x_UnitsFormatter_create_with_provider_mv1(ffi.Pointer<ffi.Opaque> provider, ffi.Pointer<ffi.Opaque> locale, _SliceUtf8 unit, int width);
+
+@_DiplomatFfiUse('icu4x_UnitsFormatter_format_mv1')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>)>(isLeaf: true, symbol: 'icu4x_UnitsFormatter_format_mv1')
+// ignore: non_constant_identifier_names
+external void _icu4x_UnitsFormatter_format_mv1(ffi.Pointer<ffi.Opaque> self, ffi.Pointer<ffi.Opaque> value, ffi.Pointer<ffi.Opaque> write);
+
+// dart format on
@@ -0,0 +1,19 @@
+// generated by diplomat-tool
+// dart format off
+
+part of 'lib.g.dart';
+
+/// 🚧 This API is experimental and may experience breaking changes outside major releases.
+///
+/// See the [Rust documentation for `Width`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/units/options/enum.Width.html) for more information.
+enum UnitsFormatterWidth {
+
+  long,
+
+  short,
+
+  narrow;
+
+}
+
+// dart format on
@@ -42,7 +42,10 @@ part 'CollatorNumericOrdering.g.dart';
 part 'CollatorOptions.g.dart';
 part 'CollatorResolvedOptions.g.dart';
 part 'CollatorStrength.g.dart';
+part 'CompactDecimalFormatter.g.dart';
 part 'ComposingNormalizer.g.dart';
+part 'CurrencyFormatter.g.dart';
+part 'CurrencyFormatterWidth.g.dart';
 part 'DataError.g.dart';
 part 'DataProvider.g.dart';
 part 'Date.g.dart';
@@ -70,6 +73,11 @@ part 'DecomposingNormalizer.g.dart';
 part 'DisplayNamesFallback.g.dart';
 part 'DisplayNamesOptions.g.dart';
 part 'DisplayNamesStyle.g.dart';
+part 'Duration.g.dart';
+part 'DurationFormatter.g.dart';
+part 'DurationFormatterLoadError.g.dart';
+part 'DurationFormatterStyle.g.dart';
+part 'DurationSign.g.dart';
 part 'EastAsianWidth.g.dart';
 part 'EmojiSetData.g.dart';
 part 'ExemplarCharacters.g.dart';
@@ -113,13 +121,29 @@ part 'LocaleFallbackPriority.g.dart';
 part 'LocaleFallbacker.g.dart';
 part 'LocaleFallbackerWithConfig.g.dart';
 part 'LocaleParseError.g.dart';
+part 'LocalePriorityList.g.dart';
 part 'Logger.g.dart';
+part 'LongCurrencyFormatter.g.dart';
+part 'MeasureUnit.g.dart';
+part 'PersonName.g.dart';
+part 'PersonNameFieldKind.g.dart';
+part 'PersonNamePreferredOrder.g.dart';
+part 'PersonNamesFormatter.g.dart';
+part 'PersonNamesFormatterError.g.dart';
+part 'PersonNamesFormatterFormality.g.dart';
+part 'PersonNamesFormatterLength.g.dart';
+part 'PersonNamesFormatterOrder.g.dart';
+part 'PersonNamesFormatterUsage.g.dart';
 part 'PluralCategories.g.dart';
 part 'PluralCategory.g.dart';
 part 'PluralOperands.g.dart';
 part 'PluralRules.g.dart';
 part 'PropertyValueNameToEnumMapper.g.dart';
 part 'RegionDisplayNames.g.dart';
+part 'RelativeTimeFormatter.g.dart';
+part 'RelativeTimeLength.g.dart';
+part 'RelativeTimeNumeric.g.dart';
+part 'RelativeTimeUnit.g.dart';
 part 'ReorderedIndexMap.g.dart';
 part 'Rfc9557ParseError.g.dart';
 part 'Script.g.dart';
@@ -149,6 +173,11 @@ part 'TitlecaseMapper.g.dart';
 part 'TitlecaseOptions.g.dart';
 part 'TrailingCase.g.dart';
 part 'TransformResult.g.dart';
+part 'Transliterator.g.dart';
+part 'UnitsConverter.g.dart';
+part 'UnitsConverterFactory.g.dart';
+part 'UnitsFormatter.g.dart';
+part 'UnitsFormatterWidth.g.dart';
 part 'UtcOffset.g.dart';
 part 'VariantOffsets.g.dart';
 part 'VariantOffsetsCalculator.g.dart';
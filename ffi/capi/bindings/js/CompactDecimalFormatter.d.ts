@@ -0,0 +1,70 @@
+// generated by diplomat-tool
+import type { DataError } from "./DataError"
+import type { DataProvider } from "./DataProvider"
+import type { Decimal } from "./Decimal"
+import type { DecimalGroupingStrategy } from "./DecimalGroupingStrategy"
+import type { Locale } from "./Locale"
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Compact Decimal Format object, capable of formatting a {@link Decimal} in a compact form,
+ * such as "1.2K" or "1.2 thousand".
+ *
+ * See the [Rust documentation for `CompactDecimalFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html) for more information.
+ */
+export class CompactDecimalFormatter {
+    /** @internal */
+    get ffiValue(): pointer;
+    /** @internal */
+    constructor();
+
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link CompactDecimalFormatter} for the short compact style (e.g. "1.2K"), using compiled data.
+     *
+     * See the [Rust documentation for `try_new_short`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html#method.try_new_short) for more information.
+     */
+    static createShort(locale: Locale, groupingStrategy: DecimalGroupingStrategy | null): CompactDecimalFormatter;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link CompactDecimalFormatter} for the short compact style (e.g. "1.2K"), using a particular data source.
+     *
+     * See the [Rust documentation for `try_new_short`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html#method.try_new_short) for more information.
+     */
+    static createShortWithProvider(provider: DataProvider, locale: Locale, groupingStrategy: DecimalGroupingStrategy | null): CompactDecimalFormatter;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link CompactDecimalFormatter} for the long compact style (e.g. "1.2 thousand"), using compiled data.
+     *
+     * See the [Rust documentation for `try_new_long`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html#method.try_new_long) for more information.
+     */
+    static createLong(locale: Locale, groupingStrategy: DecimalGroupingStrategy | null): CompactDecimalFormatter;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link CompactDecimalFormatter} for the long compact style (e.g. "1.2 thousand"), using a particular data source.
+     *
+     * See the [Rust documentation for `try_new_long`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html#method.try_new_long) for more information.
+     */
+    static createLongWithProvider(provider: DataProvider, locale: Locale, groupingStrategy: DecimalGroupingStrategy | null): CompactDecimalFormatter;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link Decimal} to a string, rounding it to the precision of the compact pattern.
+     *
+     * See the [Rust documentation for `format_fixed_decimal`](https://docs.rs/icu/2.0.0/icu/experimental/compactdecimal/struct.CompactDecimalFormatter.html#method.format_fixed_decimal) for more information.
+     */
+    format(value: Decimal): string;
+}
\ No newline at end of file
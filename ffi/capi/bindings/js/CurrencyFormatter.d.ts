@@ -0,0 +1,54 @@
+// generated by diplomat-tool
+import type { CurrencyFormatterWidth } from "./CurrencyFormatterWidth"
+import type { DataError } from "./DataError"
+import type { DataProvider } from "./DataProvider"
+import type { Decimal } from "./Decimal"
+import type { Locale } from "./Locale"
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Currency Formatter object, capable of formatting a {@link Decimal} as an amount
+ * of a particular currency, such as "$12,345.67".
+ *
+ * See the [Rust documentation for `CurrencyFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/formatter/struct.CurrencyFormatter.html) for more information.
+ */
+export class CurrencyFormatter {
+    /** @internal */
+    get ffiValue(): pointer;
+
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link CurrencyFormatter} for the given ISO 4217 currency code, using a particular data source.
+     *
+     * Returns {@link DataError::IdentifierNotFound} if the currency code is not well-formed.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/formatter/struct.CurrencyFormatter.html#method.try_new) for more information.
+     */
+    static createWithProvider(provider: DataProvider, locale: Locale, currencyCode: string, width: CurrencyFormatterWidth): CurrencyFormatter;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link Decimal} as an amount of this formatter's currency.
+     *
+     * See the [Rust documentation for `format_fixed_decimal`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/formatter/struct.CurrencyFormatter.html#method.format_fixed_decimal) for more information.
+     */
+    format(value: Decimal): string;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link CurrencyFormatter} for the given ISO 4217 currency code, using compiled data.
+     *
+     * Returns {@link DataError::IdentifierNotFound} if the currency code is not well-formed.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/formatter/struct.CurrencyFormatter.html#method.try_new) for more information.
+     */
+    constructor(locale: Locale, currencyCode: string, width: CurrencyFormatterWidth);
+}
\ No newline at end of file
@@ -0,0 +1,156 @@
+// generated by diplomat-tool
+import { CurrencyFormatterWidth } from "./CurrencyFormatterWidth.mjs"
+import { DataError } from "./DataError.mjs"
+import { DataProvider } from "./DataProvider.mjs"
+import { Decimal } from "./Decimal.mjs"
+import { Locale } from "./Locale.mjs"
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+const CurrencyFormatter_box_destroy_registry = new FinalizationRegistry((ptr) => {
+    wasm.icu4x_CurrencyFormatter_destroy_mv1(ptr);
+});
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Currency Formatter object, capable of formatting a {@link Decimal} as an amount
+ * of a particular currency, such as "$12,345.67".
+ *
+ * See the [Rust documentation for `CurrencyFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/formatter/struct.CurrencyFormatter.html) for more information.
+ */
+export class CurrencyFormatter {
+    // Internal ptr reference:
+    #ptr = null;
+
+    // Lifetimes are only to keep dependencies alive.
+    // Since JS won't garbage collect until there are no incoming edges.
+    #selfEdge = [];
+
+    #internalConstructor(symbol, ptr, selfEdge) {
+        if (symbol !== diplomatRuntime.internalConstructor) {
+            console.error("CurrencyFormatter is an Opaque type. You cannot call its constructor.");
+            return;
+        }
+        this.#ptr = ptr;
+        this.#selfEdge = selfEdge;
+
+        // Are we being borrowed? If not, we can register.
+        if (this.#selfEdge.length === 0) {
+            CurrencyFormatter_box_destroy_registry.register(this, this.#ptr);
+        }
+
+        return this;
+    }
+    /** @internal */
+    get ffiValue() {
+        return this.#ptr;
+    }
+
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link CurrencyFormatter} for the given ISO 4217 currency code, using compiled data.
+     *
+     * Returns {@link DataError::IdentifierNotFound} if the currency code is not well-formed.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/formatter/struct.CurrencyFormatter.html#method.try_new) for more information.
+     */
+    #defaultConstructor(locale, currencyCode, width) {
+        let functionCleanupArena = new diplomatRuntime.CleanupArena();
+
+        const currencyCodeSlice = functionCleanupArena.alloc(diplomatRuntime.DiplomatBuf.sliceWrapper(wasm, diplomatRuntime.DiplomatBuf.str8(wasm, currencyCode)));
+        const diplomatReceive = new diplomatRuntime.DiplomatReceiveBuf(wasm, 5, 4, true);
+
+
+        const result = wasm.icu4x_CurrencyFormatter_create_mv1(diplomatReceive.buffer, locale.ffiValue, currencyCodeSlice.ptr, width.ffiValue);
+
+        try {
+            if (!diplomatReceive.resultFlag) {
+                const cause = new DataError(diplomatRuntime.internalConstructor, diplomatRuntime.enumDiscriminant(wasm, diplomatReceive.buffer));
+                throw new globalThis.Error('DataError.' + cause.value, { cause });
+            }
+            return new CurrencyFormatter(diplomatRuntime.internalConstructor, diplomatRuntime.ptrRead(wasm, diplomatReceive.buffer), []);
+        }
+
+        finally {
+            functionCleanupArena.free();
+
+            diplomatReceive.free();
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link CurrencyFormatter} for the given ISO 4217 currency code, using a particular data source.
+     *
+     * Returns {@link DataError::IdentifierNotFound} if the currency code is not well-formed.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/formatter/struct.CurrencyFormatter.html#method.try_new) for more information.
+     */
+    static createWithProvider(provider, locale, currencyCode, width) {
+        let functionCleanupArena = new diplomatRuntime.CleanupArena();
+
+        const currencyCodeSlice = functionCleanupArena.alloc(diplomatRuntime.DiplomatBuf.sliceWrapper(wasm, diplomatRuntime.DiplomatBuf.str8(wasm, currencyCode)));
+        const diplomatReceive = new diplomatRuntime.DiplomatReceiveBuf(wasm, 5, 4, true);
+
+
+        const result = wasm.icu4x_CurrencyFormatter_create_with_provider_mv1(diplomatReceive.buffer, provider.ffiValue, locale.ffiValue, currencyCodeSlice.ptr, width.ffiValue);
+
+        try {
+            if (!diplomatReceive.resultFlag) {
+                const cause = new DataError(diplomatRuntime.internalConstructor, diplomatRuntime.enumDiscriminant(wasm, diplomatReceive.buffer));
+                throw new globalThis.Error('DataError.' + cause.value, { cause });
+            }
+            return new CurrencyFormatter(diplomatRuntime.internalConstructor, diplomatRuntime.ptrRead(wasm, diplomatReceive.buffer), []);
+        }
+
+        finally {
+            functionCleanupArena.free();
+
+            diplomatReceive.free();
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link Decimal} as an amount of this formatter's currency.
+     *
+     * See the [Rust documentation for `format_fixed_decimal`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/formatter/struct.CurrencyFormatter.html#method.format_fixed_decimal) for more information.
+     */
+    format(value) {
+        const write = new diplomatRuntime.DiplomatWriteBuf(wasm);
+
+    wasm.icu4x_CurrencyFormatter_format_mv1(this.ffiValue, value.ffiValue, write.buffer);
+
+        try {
+            return write.readString8();
+        }
+
+        finally {
+            write.free();
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link CurrencyFormatter} for the given ISO 4217 currency code, using compiled data.
+     *
+     * Returns {@link DataError::IdentifierNotFound} if the currency code is not well-formed.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/formatter/struct.CurrencyFormatter.html#method.try_new) for more information.
+     */
+    constructor(locale, currencyCode, width) {
+        if (arguments[0] === diplomatRuntime.exposeConstructor) {
+            return this.#internalConstructor(...Array.prototype.slice.call(arguments, 1));
+        } else if (arguments[0] === diplomatRuntime.internalConstructor) {
+            return this.#internalConstructor(...arguments);
+        } else {
+            return this.#defaultConstructor(...arguments);
+        }
+    }
+}
\ No newline at end of file
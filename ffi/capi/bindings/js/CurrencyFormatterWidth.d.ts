@@ -0,0 +1,26 @@
+// generated by diplomat-tool
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `Width`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/options/enum.Width.html) for more information.
+ */
+export class CurrencyFormatterWidth {
+
+    /** @internal */
+    static fromValue(value: CurrencyFormatterWidth | string): CurrencyFormatterWidth;
+
+    get value(): string;
+
+    /** @internal */
+    get ffiValue(): number;
+
+    static Short : CurrencyFormatterWidth;
+    static Narrow : CurrencyFormatterWidth;
+
+
+    constructor(value: CurrencyFormatterWidth | string );
+}
\ No newline at end of file
@@ -0,0 +1,74 @@
+// generated by diplomat-tool
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `Width`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/options/enum.Width.html) for more information.
+ */
+export class CurrencyFormatterWidth {
+    #value = undefined;
+
+    static #values = new Map([
+        ["Short", 0],
+        ["Narrow", 1]
+    ]);
+
+    static getAllEntries() {
+        return CurrencyFormatterWidth.#values.entries();
+    }
+
+    #internalConstructor(value) {
+        if (arguments.length > 1 && arguments[0] === diplomatRuntime.internalConstructor) {
+            // We pass in two internalConstructor arguments to create *new*
+            // instances of this type, otherwise the enums are treated as singletons.
+            if (arguments[1] === diplomatRuntime.internalConstructor ) {
+                this.#value = arguments[2];
+                return this;
+            }
+            return CurrencyFormatterWidth.#objectValues[arguments[1]];
+        }
+
+        if (value instanceof CurrencyFormatterWidth) {
+            return value;
+        }
+
+        let intVal = CurrencyFormatterWidth.#values.get(value);
+
+        // Nullish check, checks for null or undefined
+        if (intVal != null) {
+            return CurrencyFormatterWidth.#objectValues[intVal];
+        }
+
+        throw TypeError(value + " is not a CurrencyFormatterWidth and does not correspond to any of its enumerator values.");
+    }
+
+    /** @internal */
+    static fromValue(value) {
+        return new CurrencyFormatterWidth(value);
+    }
+
+    get value(){
+        return [...CurrencyFormatterWidth.#values.keys()][this.#value];
+    }
+
+    /** @internal */
+    get ffiValue(){
+        return this.#value;
+    }
+    static #objectValues = [
+        new CurrencyFormatterWidth(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 0),
+        new CurrencyFormatterWidth(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 1),
+    ];
+
+    static Short = CurrencyFormatterWidth.#objectValues[0];
+    static Narrow = CurrencyFormatterWidth.#objectValues[1];
+
+
+    constructor(value) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
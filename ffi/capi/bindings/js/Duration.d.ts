@@ -0,0 +1,60 @@
+// generated by diplomat-tool
+import type { DurationSign } from "./DurationSign"
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+export type Duration_obj = {
+    sign: DurationSign;
+    years: bigint;
+    months: bigint;
+    weeks: bigint;
+    days: bigint;
+    hours: bigint;
+    minutes: bigint;
+    seconds: bigint;
+    milliseconds: bigint;
+    microseconds: bigint;
+    nanoseconds: bigint;
+};
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * A duration of time, such as "1 hour, 2 minutes".
+ *
+ * See the [Rust documentation for `Duration`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.Duration.html) for more information.
+ */
+export class Duration {
+    get sign(): DurationSign;
+    set sign(value: DurationSign);
+    get years(): bigint;
+    set years(value: bigint);
+    get months(): bigint;
+    set months(value: bigint);
+    get weeks(): bigint;
+    set weeks(value: bigint);
+    get days(): bigint;
+    set days(value: bigint);
+    get hours(): bigint;
+    set hours(value: bigint);
+    get minutes(): bigint;
+    set minutes(value: bigint);
+    get seconds(): bigint;
+    set seconds(value: bigint);
+    get milliseconds(): bigint;
+    set milliseconds(value: bigint);
+    get microseconds(): bigint;
+    set microseconds(value: bigint);
+    get nanoseconds(): bigint;
+    set nanoseconds(value: bigint);
+    /** @internal */
+    static fromFields(structObj : Duration_obj) : Duration;
+
+    /**
+    * Create `Duration` from an object that contains all of `Duration`s fields.
+    * Optional fields do not need to be included in the provided object.
+    */
+    constructor(structObj: Duration_obj);
+
+}
\ No newline at end of file
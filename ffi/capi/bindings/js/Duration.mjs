@@ -0,0 +1,258 @@
+// generated by diplomat-tool
+import { DurationSign } from "./DurationSign.mjs"
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * A duration of time, such as "1 hour, 2 minutes".
+ *
+ * See the [Rust documentation for `Duration`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.Duration.html) for more information.
+ */
+export class Duration {
+    #sign;
+    get sign() {
+        return this.#sign;
+    }
+    set sign(value){
+        this.#sign = value;
+    }
+    #years;
+    get years() {
+        return this.#years;
+    }
+    set years(value){
+        this.#years = value;
+    }
+    #months;
+    get months() {
+        return this.#months;
+    }
+    set months(value){
+        this.#months = value;
+    }
+    #weeks;
+    get weeks() {
+        return this.#weeks;
+    }
+    set weeks(value){
+        this.#weeks = value;
+    }
+    #days;
+    get days() {
+        return this.#days;
+    }
+    set days(value){
+        this.#days = value;
+    }
+    #hours;
+    get hours() {
+        return this.#hours;
+    }
+    set hours(value){
+        this.#hours = value;
+    }
+    #minutes;
+    get minutes() {
+        return this.#minutes;
+    }
+    set minutes(value){
+        this.#minutes = value;
+    }
+    #seconds;
+    get seconds() {
+        return this.#seconds;
+    }
+    set seconds(value){
+        this.#seconds = value;
+    }
+    #milliseconds;
+    get milliseconds() {
+        return this.#milliseconds;
+    }
+    set milliseconds(value){
+        this.#milliseconds = value;
+    }
+    #microseconds;
+    get microseconds() {
+        return this.#microseconds;
+    }
+    set microseconds(value){
+        this.#microseconds = value;
+    }
+    #nanoseconds;
+    get nanoseconds() {
+        return this.#nanoseconds;
+    }
+    set nanoseconds(value){
+        this.#nanoseconds = value;
+    }
+    /** @internal */
+    static fromFields(structObj) {
+        return new Duration(structObj);
+    }
+
+    #internalConstructor(structObj) {
+        if (typeof structObj !== "object") {
+            throw new Error("Duration's constructor takes an object of Duration's fields.");
+        }
+
+        if ("sign" in structObj) {
+            this.#sign = structObj.sign;
+        } else {
+            throw new Error("Missing required field sign.");
+        }
+
+        if ("years" in structObj) {
+            this.#years = structObj.years;
+        } else {
+            throw new Error("Missing required field years.");
+        }
+
+        if ("months" in structObj) {
+            this.#months = structObj.months;
+        } else {
+            throw new Error("Missing required field months.");
+        }
+
+        if ("weeks" in structObj) {
+            this.#weeks = structObj.weeks;
+        } else {
+            throw new Error("Missing required field weeks.");
+        }
+
+        if ("days" in structObj) {
+            this.#days = structObj.days;
+        } else {
+            throw new Error("Missing required field days.");
+        }
+
+        if ("hours" in structObj) {
+            this.#hours = structObj.hours;
+        } else {
+            throw new Error("Missing required field hours.");
+        }
+
+        if ("minutes" in structObj) {
+            this.#minutes = structObj.minutes;
+        } else {
+            throw new Error("Missing required field minutes.");
+        }
+
+        if ("seconds" in structObj) {
+            this.#seconds = structObj.seconds;
+        } else {
+            throw new Error("Missing required field seconds.");
+        }
+
+        if ("milliseconds" in structObj) {
+            this.#milliseconds = structObj.milliseconds;
+        } else {
+            throw new Error("Missing required field milliseconds.");
+        }
+
+        if ("microseconds" in structObj) {
+            this.#microseconds = structObj.microseconds;
+        } else {
+            throw new Error("Missing required field microseconds.");
+        }
+
+        if ("nanoseconds" in structObj) {
+            this.#nanoseconds = structObj.nanoseconds;
+        } else {
+            throw new Error("Missing required field nanoseconds.");
+        }
+
+        return this;
+    }
+
+    // Return this struct in FFI function friendly format.
+    // Returns an array that can be expanded with spread syntax (...)
+    _intoFFI(
+        functionCleanupArena,
+        appendArrayMap
+    ) {
+        let buffer = diplomatRuntime.DiplomatBuf.struct(wasm, 88, 8);
+
+        this._writeToArrayBuffer(wasm.memory.buffer, buffer.ptr, functionCleanupArena, appendArrayMap);
+
+        functionCleanupArena.alloc(buffer);
+
+        return buffer.ptr;
+    }
+
+    static _fromSuppliedValue(internalConstructor, obj) {
+        if (internalConstructor !== diplomatRuntime.internalConstructor) {
+            throw new Error("_fromSuppliedValue cannot be called externally.");
+        }
+
+        if (obj instanceof Duration) {
+            return obj;
+        }
+
+        return Duration.fromFields(obj);
+    }
+
+    _writeToArrayBuffer(
+        arrayBuffer,
+        offset,
+        functionCleanupArena,
+        appendArrayMap
+    ) {
+        diplomatRuntime.writeToArrayBuffer(arrayBuffer, offset + 0, this.#sign.ffiValue, Int32Array);
+        diplomatRuntime.writeToArrayBuffer(arrayBuffer, offset + 8, this.#years, BigUint64Array);
+        diplomatRuntime.writeToArrayBuffer(arrayBuffer, offset + 16, this.#months, BigUint64Array);
+        diplomatRuntime.writeToArrayBuffer(arrayBuffer, offset + 24, this.#weeks, BigUint64Array);
+        diplomatRuntime.writeToArrayBuffer(arrayBuffer, offset + 32, this.#days, BigUint64Array);
+        diplomatRuntime.writeToArrayBuffer(arrayBuffer, offset + 40, this.#hours, BigUint64Array);
+        diplomatRuntime.writeToArrayBuffer(arrayBuffer, offset + 48, this.#minutes, BigUint64Array);
+        diplomatRuntime.writeToArrayBuffer(arrayBuffer, offset + 56, this.#seconds, BigUint64Array);
+        diplomatRuntime.writeToArrayBuffer(arrayBuffer, offset + 64, this.#milliseconds, BigUint64Array);
+        diplomatRuntime.writeToArrayBuffer(arrayBuffer, offset + 72, this.#microseconds, BigUint64Array);
+        diplomatRuntime.writeToArrayBuffer(arrayBuffer, offset + 80, this.#nanoseconds, BigUint64Array);
+    }
+
+    // This struct contains borrowed fields, so this takes in a list of
+    // "edges" corresponding to where each lifetime's data may have been borrowed from
+    // and passes it down to individual fields containing the borrow.
+    // This method does not attempt to handle any dependencies between lifetimes, the caller
+    // should handle this when constructing edge arrays.
+    static _fromFFI(internalConstructor, ptr) {
+        if (internalConstructor !== diplomatRuntime.internalConstructor) {
+            throw new Error("Duration._fromFFI is not meant to be called externally. Please use the default constructor.");
+        }
+        let structObj = {};
+        const signDeref = diplomatRuntime.enumDiscriminant(wasm, ptr);
+        structObj.sign = new DurationSign(diplomatRuntime.internalConstructor, signDeref);
+        const yearsDeref = (new BigUint64Array(wasm.memory.buffer, ptr + 8, 1))[0];
+        structObj.years = yearsDeref;
+        const monthsDeref = (new BigUint64Array(wasm.memory.buffer, ptr + 16, 1))[0];
+        structObj.months = monthsDeref;
+        const weeksDeref = (new BigUint64Array(wasm.memory.buffer, ptr + 24, 1))[0];
+        structObj.weeks = weeksDeref;
+        const daysDeref = (new BigUint64Array(wasm.memory.buffer, ptr + 32, 1))[0];
+        structObj.days = daysDeref;
+        const hoursDeref = (new BigUint64Array(wasm.memory.buffer, ptr + 40, 1))[0];
+        structObj.hours = hoursDeref;
+        const minutesDeref = (new BigUint64Array(wasm.memory.buffer, ptr + 48, 1))[0];
+        structObj.minutes = minutesDeref;
+        const secondsDeref = (new BigUint64Array(wasm.memory.buffer, ptr + 56, 1))[0];
+        structObj.seconds = secondsDeref;
+        const millisecondsDeref = (new BigUint64Array(wasm.memory.buffer, ptr + 64, 1))[0];
+        structObj.milliseconds = millisecondsDeref;
+        const microsecondsDeref = (new BigUint64Array(wasm.memory.buffer, ptr + 72, 1))[0];
+        structObj.microseconds = microsecondsDeref;
+        const nanosecondsDeref = (new BigUint64Array(wasm.memory.buffer, ptr + 80, 1))[0];
+        structObj.nanoseconds = nanosecondsDeref;
+
+        return new Duration(structObj);
+    }
+
+
+    constructor(structObj) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
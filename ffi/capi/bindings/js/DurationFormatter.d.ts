@@ -0,0 +1,57 @@
+// generated by diplomat-tool
+import type { DataProvider } from "./DataProvider"
+import type { Duration } from "./Duration"
+import type { DurationFormatterLoadError } from "./DurationFormatterLoadError"
+import type { DurationFormatterStyle } from "./DurationFormatterStyle"
+import type { Duration_obj } from "./Duration"
+import type { Locale } from "./Locale"
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Duration Formatter object, capable of formatting a {@link Duration}, such as
+ * "1 hr, 2 min" or "1:02:03".
+ *
+ * See the [Rust documentation for `DurationFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html) for more information.
+ */
+export class DurationFormatter {
+    /** @internal */
+    get ffiValue(): pointer;
+
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link DurationFormatter} using a particular data source.
+     *
+     * `fractional_digits` is the number of fractional digits (0 to 9) used for sub-second
+     * units in the digital style; if absent, as many digits as necessary are shown.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html#method.try_new) for more information.
+     */
+    static createWithProvider(provider: DataProvider, locale: Locale, style: DurationFormatterStyle, fractionalDigits: number | null): DurationFormatter;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link Duration} to a string.
+     *
+     * See the [Rust documentation for `format`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html#method.format) for more information.
+     */
+    format(duration: Duration_obj): string;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link DurationFormatter} using compiled data.
+     *
+     * `fractional_digits` is the number of fractional digits (0 to 9) used for sub-second
+     * units in the digital style; if absent, as many digits as necessary are shown.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html#method.try_new) for more information.
+     */
+    constructor(locale: Locale, style: DurationFormatterStyle, fractionalDigits: number | null);
+}
\ No newline at end of file
@@ -0,0 +1,161 @@
+// generated by diplomat-tool
+import { DataProvider } from "./DataProvider.mjs"
+import { Duration } from "./Duration.mjs"
+import { DurationFormatterLoadError } from "./DurationFormatterLoadError.mjs"
+import { DurationFormatterStyle } from "./DurationFormatterStyle.mjs"
+import { Locale } from "./Locale.mjs"
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+const DurationFormatter_box_destroy_registry = new FinalizationRegistry((ptr) => {
+    wasm.icu4x_DurationFormatter_destroy_mv1(ptr);
+});
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Duration Formatter object, capable of formatting a {@link Duration}, such as
+ * "1 hr, 2 min" or "1:02:03".
+ *
+ * See the [Rust documentation for `DurationFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html) for more information.
+ */
+export class DurationFormatter {
+    // Internal ptr reference:
+    #ptr = null;
+
+    // Lifetimes are only to keep dependencies alive.
+    // Since JS won't garbage collect until there are no incoming edges.
+    #selfEdge = [];
+
+    #internalConstructor(symbol, ptr, selfEdge) {
+        if (symbol !== diplomatRuntime.internalConstructor) {
+            console.error("DurationFormatter is an Opaque type. You cannot call its constructor.");
+            return;
+        }
+        this.#ptr = ptr;
+        this.#selfEdge = selfEdge;
+
+        // Are we being borrowed? If not, we can register.
+        if (this.#selfEdge.length === 0) {
+            DurationFormatter_box_destroy_registry.register(this, this.#ptr);
+        }
+
+        return this;
+    }
+    /** @internal */
+    get ffiValue() {
+        return this.#ptr;
+    }
+
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link DurationFormatter} using compiled data.
+     *
+     * `fractional_digits` is the number of fractional digits (0 to 9) used for sub-second
+     * units in the digital style; if absent, as many digits as necessary are shown.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html#method.try_new) for more information.
+     */
+    #defaultConstructor(locale, style, fractionalDigits) {
+        let functionCleanupArena = new diplomatRuntime.CleanupArena();
+
+        const diplomatReceive = new diplomatRuntime.DiplomatReceiveBuf(wasm, 5, 4, true);
+
+
+        const result = wasm.icu4x_DurationFormatter_create_mv1(diplomatReceive.buffer, locale.ffiValue, style.ffiValue, diplomatRuntime.optionToBufferForCalling(wasm, fractionalDigits, 1, 1, functionCleanupArena, (arrayBuffer, offset, jsValue) => [diplomatRuntime.writeToArrayBuffer(arrayBuffer, offset + 0, jsValue, Uint8Array)]));
+
+        try {
+            if (!diplomatReceive.resultFlag) {
+                const cause = new DurationFormatterLoadError(diplomatRuntime.internalConstructor, diplomatRuntime.enumDiscriminant(wasm, diplomatReceive.buffer));
+                throw new globalThis.Error('DurationFormatterLoadError.' + cause.value, { cause });
+            }
+            return new DurationFormatter(diplomatRuntime.internalConstructor, diplomatRuntime.ptrRead(wasm, diplomatReceive.buffer), []);
+        }
+
+        finally {
+            functionCleanupArena.free();
+
+            diplomatReceive.free();
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link DurationFormatter} using a particular data source.
+     *
+     * `fractional_digits` is the number of fractional digits (0 to 9) used for sub-second
+     * units in the digital style; if absent, as many digits as necessary are shown.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html#method.try_new) for more information.
+     */
+    static createWithProvider(provider, locale, style, fractionalDigits) {
+        let functionCleanupArena = new diplomatRuntime.CleanupArena();
+
+        const diplomatReceive = new diplomatRuntime.DiplomatReceiveBuf(wasm, 5, 4, true);
+
+
+        const result = wasm.icu4x_DurationFormatter_create_with_provider_mv1(diplomatReceive.buffer, provider.ffiValue, locale.ffiValue, style.ffiValue, diplomatRuntime.optionToBufferForCalling(wasm, fractionalDigits, 1, 1, functionCleanupArena, (arrayBuffer, offset, jsValue) => [diplomatRuntime.writeToArrayBuffer(arrayBuffer, offset + 0, jsValue, Uint8Array)]));
+
+        try {
+            if (!diplomatReceive.resultFlag) {
+                const cause = new DurationFormatterLoadError(diplomatRuntime.internalConstructor, diplomatRuntime.enumDiscriminant(wasm, diplomatReceive.buffer));
+                throw new globalThis.Error('DurationFormatterLoadError.' + cause.value, { cause });
+            }
+            return new DurationFormatter(diplomatRuntime.internalConstructor, diplomatRuntime.ptrRead(wasm, diplomatReceive.buffer), []);
+        }
+
+        finally {
+            functionCleanupArena.free();
+
+            diplomatReceive.free();
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link Duration} to a string.
+     *
+     * See the [Rust documentation for `format`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html#method.format) for more information.
+     */
+    format(duration) {
+        let functionCleanupArena = new diplomatRuntime.CleanupArena();
+
+        const write = new diplomatRuntime.DiplomatWriteBuf(wasm);
+
+    wasm.icu4x_DurationFormatter_format_mv1(this.ffiValue, Duration._fromSuppliedValue(diplomatRuntime.internalConstructor, duration)._intoFFI(functionCleanupArena, {}, false), write.buffer);
+
+        try {
+            return write.readString8();
+        }
+
+        finally {
+            functionCleanupArena.free();
+
+            write.free();
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link DurationFormatter} using compiled data.
+     *
+     * `fractional_digits` is the number of fractional digits (0 to 9) used for sub-second
+     * units in the digital style; if absent, as many digits as necessary are shown.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/duration/struct.DurationFormatter.html#method.try_new) for more information.
+     */
+    constructor(locale, style, fractionalDigits) {
+        if (arguments[0] === diplomatRuntime.exposeConstructor) {
+            return this.#internalConstructor(...Array.prototype.slice.call(arguments, 1));
+        } else if (arguments[0] === diplomatRuntime.internalConstructor) {
+            return this.#internalConstructor(...arguments);
+        } else {
+            return this.#defaultConstructor(...arguments);
+        }
+    }
+}
\ No newline at end of file
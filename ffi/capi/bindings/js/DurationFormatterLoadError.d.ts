@@ -0,0 +1,37 @@
+// generated by diplomat-tool
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * Additional information: [1](https://docs.rs/icu/2.0.0/icu/experimental/duration/enum.DurationFormatterOptionsError.html), [2](https://docs.rs/icu_provider/2.0.0/icu_provider/struct.DataError.html), [3](https://docs.rs/icu_provider/2.0.0/icu_provider/enum.DataErrorKind.html)
+ */
+export class DurationFormatterLoadError {
+
+    /** @internal */
+    static fromValue(value: DurationFormatterLoadError | string): DurationFormatterLoadError;
+
+    get value(): string;
+
+    /** @internal */
+    get ffiValue(): number;
+
+    static Unknown : DurationFormatterLoadError;
+    static DisplayAlwaysFractional : DurationFormatterLoadError;
+    static PreviousFractional : DurationFormatterLoadError;
+    static PreviousNumeric : DurationFormatterLoadError;
+    static FractionalDigitsOutOfRange : DurationFormatterLoadError;
+    static DataMarkerNotFound : DurationFormatterLoadError;
+    static DataIdentifierNotFound : DurationFormatterLoadError;
+    static DataInvalidRequest : DurationFormatterLoadError;
+    static DataInconsistentData : DurationFormatterLoadError;
+    static DataDowncast : DurationFormatterLoadError;
+    static DataDeserialize : DurationFormatterLoadError;
+    static DataCustom : DurationFormatterLoadError;
+    static DataIo : DurationFormatterLoadError;
+
+
+    constructor(value: DurationFormatterLoadError | string );
+}
\ No newline at end of file
@@ -0,0 +1,111 @@
+// generated by diplomat-tool
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * Additional information: [1](https://docs.rs/icu/2.0.0/icu/experimental/duration/enum.DurationFormatterOptionsError.html), [2](https://docs.rs/icu_provider/2.0.0/icu_provider/struct.DataError.html), [3](https://docs.rs/icu_provider/2.0.0/icu_provider/enum.DataErrorKind.html)
+ */
+export class DurationFormatterLoadError {
+    #value = undefined;
+
+    static #values = new Map([
+        ["Unknown", 0],
+        ["DisplayAlwaysFractional", 2049],
+        ["PreviousFractional", 2050],
+        ["PreviousNumeric", 2051],
+        ["FractionalDigitsOutOfRange", 2052],
+        ["DataMarkerNotFound", 1],
+        ["DataIdentifierNotFound", 2],
+        ["DataInvalidRequest", 3],
+        ["DataInconsistentData", 4],
+        ["DataDowncast", 5],
+        ["DataDeserialize", 6],
+        ["DataCustom", 7],
+        ["DataIo", 8]
+    ]);
+
+    static getAllEntries() {
+        return DurationFormatterLoadError.#values.entries();
+    }
+
+    #internalConstructor(value) {
+        if (arguments.length > 1 && arguments[0] === diplomatRuntime.internalConstructor) {
+            // We pass in two internalConstructor arguments to create *new*
+            // instances of this type, otherwise the enums are treated as singletons.
+            if (arguments[1] === diplomatRuntime.internalConstructor ) {
+                this.#value = arguments[2];
+                return this;
+            }
+            return DurationFormatterLoadError.#objectValues[arguments[1]];
+        }
+
+        if (value instanceof DurationFormatterLoadError) {
+            return value;
+        }
+
+        let intVal = DurationFormatterLoadError.#values.get(value);
+
+        // Nullish check, checks for null or undefined
+        if (intVal != null) {
+            return DurationFormatterLoadError.#objectValues[intVal];
+        }
+
+        throw TypeError(value + " is not a DurationFormatterLoadError and does not correspond to any of its enumerator values.");
+    }
+
+    /** @internal */
+    static fromValue(value) {
+        return new DurationFormatterLoadError(value);
+    }
+
+    get value(){
+        for (let entry of DurationFormatterLoadError.#values) {
+            if (entry[1] == this.#value) {
+                return entry[0];
+            }
+        }
+    }
+
+    /** @internal */
+    get ffiValue(){
+        return this.#value;
+    }
+    static #objectValues = {
+        [0]: new DurationFormatterLoadError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 0),
+        [2049]: new DurationFormatterLoadError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2049),
+        [2050]: new DurationFormatterLoadError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2050),
+        [2051]: new DurationFormatterLoadError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2051),
+        [2052]: new DurationFormatterLoadError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2052),
+        [1]: new DurationFormatterLoadError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 1),
+        [2]: new DurationFormatterLoadError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2),
+        [3]: new DurationFormatterLoadError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 3),
+        [4]: new DurationFormatterLoadError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 4),
+        [5]: new DurationFormatterLoadError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 5),
+        [6]: new DurationFormatterLoadError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 6),
+        [7]: new DurationFormatterLoadError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 7),
+        [8]: new DurationFormatterLoadError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 8),
+    };
+
+    static Unknown = DurationFormatterLoadError.#objectValues[0];
+    static DisplayAlwaysFractional = DurationFormatterLoadError.#objectValues[2049];
+    static PreviousFractional = DurationFormatterLoadError.#objectValues[2050];
+    static PreviousNumeric = DurationFormatterLoadError.#objectValues[2051];
+    static FractionalDigitsOutOfRange = DurationFormatterLoadError.#objectValues[2052];
+    static DataMarkerNotFound = DurationFormatterLoadError.#objectValues[1];
+    static DataIdentifierNotFound = DurationFormatterLoadError.#objectValues[2];
+    static DataInvalidRequest = DurationFormatterLoadError.#objectValues[3];
+    static DataInconsistentData = DurationFormatterLoadError.#objectValues[4];
+    static DataDowncast = DurationFormatterLoadError.#objectValues[5];
+    static DataDeserialize = DurationFormatterLoadError.#objectValues[6];
+    static DataCustom = DurationFormatterLoadError.#objectValues[7];
+    static DataIo = DurationFormatterLoadError.#objectValues[8];
+
+
+    constructor(value) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
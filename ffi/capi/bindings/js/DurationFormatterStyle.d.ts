@@ -0,0 +1,28 @@
+// generated by diplomat-tool
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `BaseStyle`](https://docs.rs/icu/2.0.0/icu/experimental/duration/options/enum.BaseStyle.html) for more information.
+ */
+export class DurationFormatterStyle {
+
+    /** @internal */
+    static fromValue(value: DurationFormatterStyle | string): DurationFormatterStyle;
+
+    get value(): string;
+
+    /** @internal */
+    get ffiValue(): number;
+
+    static Narrow : DurationFormatterStyle;
+    static Short : DurationFormatterStyle;
+    static Long : DurationFormatterStyle;
+    static Digital : DurationFormatterStyle;
+
+
+    constructor(value: DurationFormatterStyle | string );
+}
\ No newline at end of file
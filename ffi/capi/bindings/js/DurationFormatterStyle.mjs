@@ -0,0 +1,80 @@
+// generated by diplomat-tool
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `BaseStyle`](https://docs.rs/icu/2.0.0/icu/experimental/duration/options/enum.BaseStyle.html) for more information.
+ */
+export class DurationFormatterStyle {
+    #value = undefined;
+
+    static #values = new Map([
+        ["Narrow", 0],
+        ["Short", 1],
+        ["Long", 2],
+        ["Digital", 3]
+    ]);
+
+    static getAllEntries() {
+        return DurationFormatterStyle.#values.entries();
+    }
+
+    #internalConstructor(value) {
+        if (arguments.length > 1 && arguments[0] === diplomatRuntime.internalConstructor) {
+            // We pass in two internalConstructor arguments to create *new*
+            // instances of this type, otherwise the enums are treated as singletons.
+            if (arguments[1] === diplomatRuntime.internalConstructor ) {
+                this.#value = arguments[2];
+                return this;
+            }
+            return DurationFormatterStyle.#objectValues[arguments[1]];
+        }
+
+        if (value instanceof DurationFormatterStyle) {
+            return value;
+        }
+
+        let intVal = DurationFormatterStyle.#values.get(value);
+
+        // Nullish check, checks for null or undefined
+        if (intVal != null) {
+            return DurationFormatterStyle.#objectValues[intVal];
+        }
+
+        throw TypeError(value + " is not a DurationFormatterStyle and does not correspond to any of its enumerator values.");
+    }
+
+    /** @internal */
+    static fromValue(value) {
+        return new DurationFormatterStyle(value);
+    }
+
+    get value(){
+        return [...DurationFormatterStyle.#values.keys()][this.#value];
+    }
+
+    /** @internal */
+    get ffiValue(){
+        return this.#value;
+    }
+    static #objectValues = [
+        new DurationFormatterStyle(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 0),
+        new DurationFormatterStyle(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 1),
+        new DurationFormatterStyle(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2),
+        new DurationFormatterStyle(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 3),
+    ];
+
+    static Narrow = DurationFormatterStyle.#objectValues[0];
+    static Short = DurationFormatterStyle.#objectValues[1];
+    static Long = DurationFormatterStyle.#objectValues[2];
+    static Digital = DurationFormatterStyle.#objectValues[3];
+
+
+    constructor(value) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
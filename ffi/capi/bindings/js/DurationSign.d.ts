@@ -0,0 +1,26 @@
+// generated by diplomat-tool
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `DurationSign`](https://docs.rs/icu/2.0.0/icu/experimental/duration/enum.DurationSign.html) for more information.
+ */
+export class DurationSign {
+
+    /** @internal */
+    static fromValue(value: DurationSign | string): DurationSign;
+
+    get value(): string;
+
+    /** @internal */
+    get ffiValue(): number;
+
+    static Positive : DurationSign;
+    static Negative : DurationSign;
+
+
+    constructor(value: DurationSign | string );
+}
\ No newline at end of file
@@ -0,0 +1,74 @@
+// generated by diplomat-tool
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `DurationSign`](https://docs.rs/icu/2.0.0/icu/experimental/duration/enum.DurationSign.html) for more information.
+ */
+export class DurationSign {
+    #value = undefined;
+
+    static #values = new Map([
+        ["Positive", 0],
+        ["Negative", 1]
+    ]);
+
+    static getAllEntries() {
+        return DurationSign.#values.entries();
+    }
+
+    #internalConstructor(value) {
+        if (arguments.length > 1 && arguments[0] === diplomatRuntime.internalConstructor) {
+            // We pass in two internalConstructor arguments to create *new*
+            // instances of this type, otherwise the enums are treated as singletons.
+            if (arguments[1] === diplomatRuntime.internalConstructor ) {
+                this.#value = arguments[2];
+                return this;
+            }
+            return DurationSign.#objectValues[arguments[1]];
+        }
+
+        if (value instanceof DurationSign) {
+            return value;
+        }
+
+        let intVal = DurationSign.#values.get(value);
+
+        // Nullish check, checks for null or undefined
+        if (intVal != null) {
+            return DurationSign.#objectValues[intVal];
+        }
+
+        throw TypeError(value + " is not a DurationSign and does not correspond to any of its enumerator values.");
+    }
+
+    /** @internal */
+    static fromValue(value) {
+        return new DurationSign(value);
+    }
+
+    get value(){
+        return [...DurationSign.#values.keys()][this.#value];
+    }
+
+    /** @internal */
+    get ffiValue(){
+        return this.#value;
+    }
+    static #objectValues = [
+        new DurationSign(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 0),
+        new DurationSign(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 1),
+    ];
+
+    static Positive = DurationSign.#objectValues[0];
+    static Negative = DurationSign.#objectValues[1];
+
+
+    constructor(value) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
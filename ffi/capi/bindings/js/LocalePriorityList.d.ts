@@ -0,0 +1,81 @@
+// generated by diplomat-tool
+import type { Locale } from "./Locale"
+import type { LocaleParseError } from "./LocaleParseError"
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * A list of locales ordered by preference, such as one parsed from an
+ * HTTP `Accept-Language` header or the `LANGUAGE` environment variable.
+ *
+ * See the [Rust documentation for `LanguagePriorityList`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html) for more information.
+ */
+export class LocalePriorityList {
+    /** @internal */
+    get ffiValue(): pointer;
+    /** @internal */
+    constructor();
+
+
+    /**
+     * Parses an HTTP `Accept-Language` header value, such as `"fr-CH, fr;q=0.9, *;q=0.5"`.
+     *
+     * See the [Rust documentation for `try_from_accept_language`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.try_from_accept_language) for more information.
+     */
+    static fromAcceptLanguage(s: string): LocalePriorityList;
+
+    /**
+     * Parses an HTTP `Accept-Language` header value, skipping or repairing malformed entries.
+     *
+     * See the [Rust documentation for `from_accept_language_lossy`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.from_accept_language_lossy) for more information.
+     */
+    static fromAcceptLanguageLossy(s: string): LocalePriorityList;
+
+    /**
+     * Parses a colon-separated list of locales, such as the `LANGUAGE` environment variable.
+     *
+     * See the [Rust documentation for `try_from_colon_separated`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.try_from_colon_separated) for more information.
+     */
+    static fromColonSeparated(s: string): LocalePriorityList;
+
+    /**
+     * Parses a colon-separated list of locales, skipping or repairing malformed entries,
+     * such as POSIX locale names like `de_DE.UTF-8`.
+     *
+     * See the [Rust documentation for `from_colon_separated_lossy`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.from_colon_separated_lossy) for more information.
+     */
+    static fromColonSeparatedLossy(s: string): LocalePriorityList;
+
+    /**
+     * The number of entries in this list, including wildcards.
+     *
+     * See the [Rust documentation for `len`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.len) for more information.
+     */
+    get length(): number;
+
+    /**
+     * Returns the locale of the entry at the given index.
+     *
+     * Returns `None` if the index is out of bounds or the entry is the wildcard `*`.
+     *
+     * See the [Rust documentation for `iter`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.iter) for more information.
+     */
+    localeAt(index: number): Locale | null;
+
+    /**
+     * Returns whether the entry at the given index is the wildcard `*`.
+     *
+     * See the [Rust documentation for `iter`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.iter) for more information.
+     */
+    isWildcardAt(index: number): boolean;
+
+    /**
+     * Returns the weight of the entry at the given index, in thousandths between 0 and 1000.
+     *
+     * Returns `None` if the index is out of bounds.
+     *
+     * See the [Rust documentation for `Weight`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.Weight.html) for more information.
+     */
+    weightAt(index: number): number | null;
+}
\ No newline at end of file
@@ -0,0 +1,230 @@
+// generated by diplomat-tool
+import { Locale } from "./Locale.mjs"
+import { LocaleParseError } from "./LocaleParseError.mjs"
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+const LocalePriorityList_box_destroy_registry = new FinalizationRegistry((ptr) => {
+    wasm.icu4x_LocalePriorityList_destroy_mv1(ptr);
+});
+
+/**
+ * A list of locales ordered by preference, such as one parsed from an
+ * HTTP `Accept-Language` header or the `LANGUAGE` environment variable.
+ *
+ * See the [Rust documentation for `LanguagePriorityList`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html) for more information.
+ */
+export class LocalePriorityList {
+    // Internal ptr reference:
+    #ptr = null;
+
+    // Lifetimes are only to keep dependencies alive.
+    // Since JS won't garbage collect until there are no incoming edges.
+    #selfEdge = [];
+
+    #internalConstructor(symbol, ptr, selfEdge) {
+        if (symbol !== diplomatRuntime.internalConstructor) {
+            console.error("LocalePriorityList is an Opaque type. You cannot call its constructor.");
+            return;
+        }
+        this.#ptr = ptr;
+        this.#selfEdge = selfEdge;
+
+        // Are we being borrowed? If not, we can register.
+        if (this.#selfEdge.length === 0) {
+            LocalePriorityList_box_destroy_registry.register(this, this.#ptr);
+        }
+
+        return this;
+    }
+    /** @internal */
+    get ffiValue() {
+        return this.#ptr;
+    }
+
+
+    /**
+     * Parses an HTTP `Accept-Language` header value, such as `"fr-CH, fr;q=0.9, *;q=0.5"`.
+     *
+     * See the [Rust documentation for `try_from_accept_language`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.try_from_accept_language) for more information.
+     */
+    static fromAcceptLanguage(s) {
+        let functionCleanupArena = new diplomatRuntime.CleanupArena();
+
+        const sSlice = functionCleanupArena.alloc(diplomatRuntime.DiplomatBuf.sliceWrapper(wasm, diplomatRuntime.DiplomatBuf.str8(wasm, s)));
+        const diplomatReceive = new diplomatRuntime.DiplomatReceiveBuf(wasm, 5, 4, true);
+
+
+        const result = wasm.icu4x_LocalePriorityList_from_accept_language_mv1(diplomatReceive.buffer, sSlice.ptr);
+
+        try {
+            if (!diplomatReceive.resultFlag) {
+                const cause = new LocaleParseError(diplomatRuntime.internalConstructor, diplomatRuntime.enumDiscriminant(wasm, diplomatReceive.buffer));
+                throw new globalThis.Error('LocaleParseError.' + cause.value, { cause });
+            }
+            return new LocalePriorityList(diplomatRuntime.internalConstructor, diplomatRuntime.ptrRead(wasm, diplomatReceive.buffer), []);
+        }
+
+        finally {
+            functionCleanupArena.free();
+
+            diplomatReceive.free();
+        }
+    }
+
+    /**
+     * Parses an HTTP `Accept-Language` header value, skipping or repairing malformed entries.
+     *
+     * See the [Rust documentation for `from_accept_language_lossy`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.from_accept_language_lossy) for more information.
+     */
+    static fromAcceptLanguageLossy(s) {
+        let functionCleanupArena = new diplomatRuntime.CleanupArena();
+
+        const sSlice = functionCleanupArena.alloc(diplomatRuntime.DiplomatBuf.sliceWrapper(wasm, diplomatRuntime.DiplomatBuf.str8(wasm, s)));
+
+        const result = wasm.icu4x_LocalePriorityList_from_accept_language_lossy_mv1(sSlice.ptr);
+
+        try {
+            return new LocalePriorityList(diplomatRuntime.internalConstructor, result, []);
+        }
+
+        finally {
+            functionCleanupArena.free();
+
+        }
+    }
+
+    /**
+     * Parses a colon-separated list of locales, such as the `LANGUAGE` environment variable.
+     *
+     * See the [Rust documentation for `try_from_colon_separated`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.try_from_colon_separated) for more information.
+     */
+    static fromColonSeparated(s) {
+        let functionCleanupArena = new diplomatRuntime.CleanupArena();
+
+        const sSlice = functionCleanupArena.alloc(diplomatRuntime.DiplomatBuf.sliceWrapper(wasm, diplomatRuntime.DiplomatBuf.str8(wasm, s)));
+        const diplomatReceive = new diplomatRuntime.DiplomatReceiveBuf(wasm, 5, 4, true);
+
+
+        const result = wasm.icu4x_LocalePriorityList_from_colon_separated_mv1(diplomatReceive.buffer, sSlice.ptr);
+
+        try {
+            if (!diplomatReceive.resultFlag) {
+                const cause = new LocaleParseError(diplomatRuntime.internalConstructor, diplomatRuntime.enumDiscriminant(wasm, diplomatReceive.buffer));
+                throw new globalThis.Error('LocaleParseError.' + cause.value, { cause });
+            }
+            return new LocalePriorityList(diplomatRuntime.internalConstructor, diplomatRuntime.ptrRead(wasm, diplomatReceive.buffer), []);
+        }
+
+        finally {
+            functionCleanupArena.free();
+
+            diplomatReceive.free();
+        }
+    }
+
+    /**
+     * Parses a colon-separated list of locales, skipping or repairing malformed entries,
+     * such as POSIX locale names like `de_DE.UTF-8`.
+     *
+     * See the [Rust documentation for `from_colon_separated_lossy`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.from_colon_separated_lossy) for more information.
+     */
+    static fromColonSeparatedLossy(s) {
+        let functionCleanupArena = new diplomatRuntime.CleanupArena();
+
+        const sSlice = functionCleanupArena.alloc(diplomatRuntime.DiplomatBuf.sliceWrapper(wasm, diplomatRuntime.DiplomatBuf.str8(wasm, s)));
+
+        const result = wasm.icu4x_LocalePriorityList_from_colon_separated_lossy_mv1(sSlice.ptr);
+
+        try {
+            return new LocalePriorityList(diplomatRuntime.internalConstructor, result, []);
+        }
+
+        finally {
+            functionCleanupArena.free();
+
+        }
+    }
+
+    /**
+     * The number of entries in this list, including wildcards.
+     *
+     * See the [Rust documentation for `len`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.len) for more information.
+     */
+    get length() {
+
+        const result = wasm.icu4x_LocalePriorityList_length_mv1(this.ffiValue);
+
+        try {
+            return result;
+        }
+
+        finally {
+        }
+    }
+
+    /**
+     * Returns the locale of the entry at the given index.
+     *
+     * Returns `None` if the index is out of bounds or the entry is the wildcard `*`.
+     *
+     * See the [Rust documentation for `iter`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.iter) for more information.
+     */
+    localeAt(index) {
+
+        const result = wasm.icu4x_LocalePriorityList_locale_at_mv1(this.ffiValue, index);
+
+        try {
+            return result === 0 ? null : new Locale(diplomatRuntime.internalConstructor, result, []);
+        }
+
+        finally {
+        }
+    }
+
+    /**
+     * Returns whether the entry at the given index is the wildcard `*`.
+     *
+     * See the [Rust documentation for `iter`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.LanguagePriorityList.html#method.iter) for more information.
+     */
+    isWildcardAt(index) {
+
+        const result = wasm.icu4x_LocalePriorityList_is_wildcard_at_mv1(this.ffiValue, index);
+
+        try {
+            return result;
+        }
+
+        finally {
+        }
+    }
+
+    /**
+     * Returns the weight of the entry at the given index, in thousandths between 0 and 1000.
+     *
+     * Returns `None` if the index is out of bounds.
+     *
+     * See the [Rust documentation for `Weight`](https://docs.rs/icu/2.0.0/icu/locale/priority_list/struct.Weight.html) for more information.
+     */
+    weightAt(index) {
+        const diplomatReceive = new diplomatRuntime.DiplomatReceiveBuf(wasm, 3, 2, true);
+
+
+        const result = wasm.icu4x_LocalePriorityList_weight_at_mv1(diplomatReceive.buffer, this.ffiValue, index);
+
+        try {
+            if (!diplomatReceive.resultFlag) {
+                return null;
+            }
+            return (new Uint16Array(wasm.memory.buffer, diplomatReceive.buffer, 1))[0];
+        }
+
+        finally {
+            diplomatReceive.free();
+        }
+    }
+
+    constructor(symbol, ptr, selfEdge) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+// generated by diplomat-tool
+import type { DataError } from "./DataError"
+import type { DataProvider } from "./DataProvider"
+import type { Decimal } from "./Decimal"
+import type { Locale } from "./Locale"
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Long Currency Formatter object, capable of formatting a {@link Decimal} as an amount
+ * of a particular currency using its long name, such as "12,345.67 US dollars".
+ *
+ * See the [Rust documentation for `LongCurrencyFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/long_formatter/struct.LongCurrencyFormatter.html) for more information.
+ */
+export class LongCurrencyFormatter {
+    /** @internal */
+    get ffiValue(): pointer;
+
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link LongCurrencyFormatter} for the given ISO 4217 currency code, using a particular data source.
+     *
+     * Returns {@link DataError::IdentifierNotFound} if the currency code is not well-formed.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/long_formatter/struct.LongCurrencyFormatter.html#method.try_new) for more information.
+     */
+    static createWithProvider(provider: DataProvider, locale: Locale, currencyCode: string): LongCurrencyFormatter;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link Decimal} as an amount of this formatter's currency, using its long name.
+     *
+     * See the [Rust documentation for `format_fixed_decimal`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/long_formatter/struct.LongCurrencyFormatter.html#method.format_fixed_decimal) for more information.
+     */
+    format(value: Decimal): string;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link LongCurrencyFormatter} for the given ISO 4217 currency code, using compiled data.
+     *
+     * Returns {@link DataError::IdentifierNotFound} if the currency code is not well-formed.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/currency/long_formatter/struct.LongCurrencyFormatter.html#method.try_new) for more information.
+     */
+    constructor(locale: Locale, currencyCode: string);
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+// generated by diplomat-tool
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+const MeasureUnit_box_destroy_registry = new FinalizationRegistry((ptr) => {
+    wasm.icu4x_MeasureUnit_destroy_mv1(ptr);
+});
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * A CLDR unit identifier, such as `meter` or `kilometer-per-hour`.
+ *
+ * See the [Rust documentation for `MeasureUnit`](https://docs.rs/icu/2.0.0/icu/experimental/measure/measureunit/struct.MeasureUnit.html) for more information.
+ */
+export class MeasureUnit {
+    // Internal ptr reference:
+    #ptr = null;
+
+    // Lifetimes are only to keep dependencies alive.
+    // Since JS won't garbage collect until there are no incoming edges.
+    #selfEdge = [];
+
+    #internalConstructor(symbol, ptr, selfEdge) {
+        if (symbol !== diplomatRuntime.internalConstructor) {
+            console.error("MeasureUnit is an Opaque type. You cannot call its constructor.");
+            return;
+        }
+        this.#ptr = ptr;
+        this.#selfEdge = selfEdge;
+
+        // Are we being borrowed? If not, we can register.
+        if (this.#selfEdge.length === 0) {
+            MeasureUnit_box_destroy_registry.register(this, this.#ptr);
+        }
+
+        return this;
+    }
+    /** @internal */
+    get ffiValue() {
+        return this.#ptr;
+    }
+
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Parses a CLDR unit identifier, returning `null` if it is not valid.
+     *
+     * See the [Rust documentation for `try_from_str`](https://docs.rs/icu/2.0.0/icu/experimental/measure/measureunit/struct.MeasureUnit.html#method.try_from_str) for more information.
+     */
+    static fromString(unit) {
+        let functionCleanupArena = new diplomatRuntime.CleanupArena();
+
+        const unitSlice = functionCleanupArena.alloc(diplomatRuntime.DiplomatBuf.sliceWrapper(wasm, diplomatRuntime.DiplomatBuf.str8(wasm, unit)));
+
+        const result = wasm.icu4x_MeasureUnit_from_string_mv1(unitSlice.ptr);
+
+        try {
+            return result === 0 ? null : new MeasureUnit(diplomatRuntime.internalConstructor, result, []);
+        }
+
+        finally {
+            functionCleanupArena.free();
+
+        }
+    }
+
+    constructor(symbol, ptr, selfEdge) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+// generated by diplomat-tool
+import type { Locale } from "./Locale"
+import type { PersonNameFieldKind } from "./PersonNameFieldKind"
+import type { PersonNamePreferredOrder } from "./PersonNamePreferredOrder"
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * A person name to be formatted with a {@link PersonNamesFormatter}, built up field by field.
+ *
+ * See the [Rust documentation for `DefaultPersonName`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/provided_struct/struct.DefaultPersonName.html) for more information.
+ */
+export class PersonName {
+    /** @internal */
+    get ffiValue(): pointer;
+
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Sets the value of a name field, replacing any previous value.
+     *
+     * Ill-formed input is treated as if errors had been replaced with REPLACEMENT CHARACTERs according
+     * to the WHATWG Encoding Standard.
+     */
+    setField(kind: PersonNameFieldKind, value: string): void;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Sets the locale of the name itself, which may differ from the formatting locale.
+     */
+    setLocale(locale: Locale): void;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Sets the order in which the name prefers its fields to be displayed.
+     */
+    setPreferredOrder(order: PersonNamePreferredOrder): void;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates an empty {@link PersonName}.
+     */
+    constructor();
+}
\ No newline at end of file
@@ -0,0 +1,129 @@
+// generated by diplomat-tool
+import { Locale } from "./Locale.mjs"
+import { PersonNameFieldKind } from "./PersonNameFieldKind.mjs"
+import { PersonNamePreferredOrder } from "./PersonNamePreferredOrder.mjs"
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+const PersonName_box_destroy_registry = new FinalizationRegistry((ptr) => {
+    wasm.icu4x_PersonName_destroy_mv1(ptr);
+});
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * A person name to be formatted with a {@link PersonNamesFormatter}, built up field by field.
+ *
+ * See the [Rust documentation for `DefaultPersonName`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/provided_struct/struct.DefaultPersonName.html) for more information.
+ */
+export class PersonName {
+    // Internal ptr reference:
+    #ptr = null;
+
+    // Lifetimes are only to keep dependencies alive.
+    // Since JS won't garbage collect until there are no incoming edges.
+    #selfEdge = [];
+
+    #internalConstructor(symbol, ptr, selfEdge) {
+        if (symbol !== diplomatRuntime.internalConstructor) {
+            console.error("PersonName is an Opaque type. You cannot call its constructor.");
+            return;
+        }
+        this.#ptr = ptr;
+        this.#selfEdge = selfEdge;
+
+        // Are we being borrowed? If not, we can register.
+        if (this.#selfEdge.length === 0) {
+            PersonName_box_destroy_registry.register(this, this.#ptr);
+        }
+
+        return this;
+    }
+    /** @internal */
+    get ffiValue() {
+        return this.#ptr;
+    }
+
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates an empty {@link PersonName}.
+     */
+    #defaultConstructor() {
+
+        const result = wasm.icu4x_PersonName_create_mv1();
+
+        try {
+            return new PersonName(diplomatRuntime.internalConstructor, result, []);
+        }
+
+        finally {
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Sets the value of a name field, replacing any previous value.
+     *
+     * Ill-formed input is treated as if errors had been replaced with REPLACEMENT CHARACTERs according
+     * to the WHATWG Encoding Standard.
+     */
+    setField(kind, value) {
+        let functionCleanupArena = new diplomatRuntime.CleanupArena();
+
+        const valueSlice = functionCleanupArena.alloc(diplomatRuntime.DiplomatBuf.sliceWrapper(wasm, diplomatRuntime.DiplomatBuf.str8(wasm, value)));
+    wasm.icu4x_PersonName_set_field_mv1(this.ffiValue, kind.ffiValue, valueSlice.ptr);
+
+        try {}
+
+        finally {
+            functionCleanupArena.free();
+
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Sets the locale of the name itself, which may differ from the formatting locale.
+     */
+    setLocale(locale) {
+    wasm.icu4x_PersonName_set_locale_mv1(this.ffiValue, locale.ffiValue);
+
+        try {}
+
+        finally {
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Sets the order in which the name prefers its fields to be displayed.
+     */
+    setPreferredOrder(order) {
+    wasm.icu4x_PersonName_set_preferred_order_mv1(this.ffiValue, order.ffiValue);
+
+        try {}
+
+        finally {
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates an empty {@link PersonName}.
+     */
+    constructor() {
+        if (arguments[0] === diplomatRuntime.exposeConstructor) {
+            return this.#internalConstructor(...Array.prototype.slice.call(arguments, 1));
+        } else if (arguments[0] === diplomatRuntime.internalConstructor) {
+            return this.#internalConstructor(...arguments);
+        } else {
+            return this.#defaultConstructor(...arguments);
+        }
+    }
+}
\ No newline at end of file
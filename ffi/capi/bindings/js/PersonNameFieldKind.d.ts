@@ -0,0 +1,31 @@
+// generated by diplomat-tool
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `NameFieldKind`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.NameFieldKind.html) for more information.
+ */
+export class PersonNameFieldKind {
+
+    /** @internal */
+    static fromValue(value: PersonNameFieldKind | string): PersonNameFieldKind;
+
+    get value(): string;
+
+    /** @internal */
+    get ffiValue(): number;
+
+    static Title : PersonNameFieldKind;
+    static Given : PersonNameFieldKind;
+    static Given2 : PersonNameFieldKind;
+    static Surname : PersonNameFieldKind;
+    static Surname2 : PersonNameFieldKind;
+    static Generation : PersonNameFieldKind;
+    static Credentials : PersonNameFieldKind;
+
+
+    constructor(value: PersonNameFieldKind | string );
+}
\ No newline at end of file
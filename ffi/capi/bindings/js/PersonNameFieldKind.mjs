@@ -0,0 +1,89 @@
+// generated by diplomat-tool
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `NameFieldKind`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.NameFieldKind.html) for more information.
+ */
+export class PersonNameFieldKind {
+    #value = undefined;
+
+    static #values = new Map([
+        ["Title", 0],
+        ["Given", 1],
+        ["Given2", 2],
+        ["Surname", 3],
+        ["Surname2", 4],
+        ["Generation", 5],
+        ["Credentials", 6]
+    ]);
+
+    static getAllEntries() {
+        return PersonNameFieldKind.#values.entries();
+    }
+
+    #internalConstructor(value) {
+        if (arguments.length > 1 && arguments[0] === diplomatRuntime.internalConstructor) {
+            // We pass in two internalConstructor arguments to create *new*
+            // instances of this type, otherwise the enums are treated as singletons.
+            if (arguments[1] === diplomatRuntime.internalConstructor ) {
+                this.#value = arguments[2];
+                return this;
+            }
+            return PersonNameFieldKind.#objectValues[arguments[1]];
+        }
+
+        if (value instanceof PersonNameFieldKind) {
+            return value;
+        }
+
+        let intVal = PersonNameFieldKind.#values.get(value);
+
+        // Nullish check, checks for null or undefined
+        if (intVal != null) {
+            return PersonNameFieldKind.#objectValues[intVal];
+        }
+
+        throw TypeError(value + " is not a PersonNameFieldKind and does not correspond to any of its enumerator values.");
+    }
+
+    /** @internal */
+    static fromValue(value) {
+        return new PersonNameFieldKind(value);
+    }
+
+    get value(){
+        return [...PersonNameFieldKind.#values.keys()][this.#value];
+    }
+
+    /** @internal */
+    get ffiValue(){
+        return this.#value;
+    }
+    static #objectValues = [
+        new PersonNameFieldKind(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 0),
+        new PersonNameFieldKind(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 1),
+        new PersonNameFieldKind(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2),
+        new PersonNameFieldKind(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 3),
+        new PersonNameFieldKind(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 4),
+        new PersonNameFieldKind(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 5),
+        new PersonNameFieldKind(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 6),
+    ];
+
+    static Title = PersonNameFieldKind.#objectValues[0];
+    static Given = PersonNameFieldKind.#objectValues[1];
+    static Given2 = PersonNameFieldKind.#objectValues[2];
+    static Surname = PersonNameFieldKind.#objectValues[3];
+    static Surname2 = PersonNameFieldKind.#objectValues[4];
+    static Generation = PersonNameFieldKind.#objectValues[5];
+    static Credentials = PersonNameFieldKind.#objectValues[6];
+
+
+    constructor(value) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
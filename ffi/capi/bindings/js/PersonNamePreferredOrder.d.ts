@@ -0,0 +1,27 @@
+// generated by diplomat-tool
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `PreferredOrder`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.PreferredOrder.html) for more information.
+ */
+export class PersonNamePreferredOrder {
+
+    /** @internal */
+    static fromValue(value: PersonNamePreferredOrder | string): PersonNamePreferredOrder;
+
+    get value(): string;
+
+    /** @internal */
+    get ffiValue(): number;
+
+    static Default : PersonNamePreferredOrder;
+    static GivenFirst : PersonNamePreferredOrder;
+    static SurnameFirst : PersonNamePreferredOrder;
+
+
+    constructor(value: PersonNamePreferredOrder | string );
+}
\ No newline at end of file
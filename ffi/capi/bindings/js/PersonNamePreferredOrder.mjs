@@ -0,0 +1,77 @@
+// generated by diplomat-tool
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `PreferredOrder`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.PreferredOrder.html) for more information.
+ */
+export class PersonNamePreferredOrder {
+    #value = undefined;
+
+    static #values = new Map([
+        ["Default", 0],
+        ["GivenFirst", 1],
+        ["SurnameFirst", 2]
+    ]);
+
+    static getAllEntries() {
+        return PersonNamePreferredOrder.#values.entries();
+    }
+
+    #internalConstructor(value) {
+        if (arguments.length > 1 && arguments[0] === diplomatRuntime.internalConstructor) {
+            // We pass in two internalConstructor arguments to create *new*
+            // instances of this type, otherwise the enums are treated as singletons.
+            if (arguments[1] === diplomatRuntime.internalConstructor ) {
+                this.#value = arguments[2];
+                return this;
+            }
+            return PersonNamePreferredOrder.#objectValues[arguments[1]];
+        }
+
+        if (value instanceof PersonNamePreferredOrder) {
+            return value;
+        }
+
+        let intVal = PersonNamePreferredOrder.#values.get(value);
+
+        // Nullish check, checks for null or undefined
+        if (intVal != null) {
+            return PersonNamePreferredOrder.#objectValues[intVal];
+        }
+
+        throw TypeError(value + " is not a PersonNamePreferredOrder and does not correspond to any of its enumerator values.");
+    }
+
+    /** @internal */
+    static fromValue(value) {
+        return new PersonNamePreferredOrder(value);
+    }
+
+    get value(){
+        return [...PersonNamePreferredOrder.#values.keys()][this.#value];
+    }
+
+    /** @internal */
+    get ffiValue(){
+        return this.#value;
+    }
+    static #objectValues = [
+        new PersonNamePreferredOrder(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 0),
+        new PersonNamePreferredOrder(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 1),
+        new PersonNamePreferredOrder(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2),
+    ];
+
+    static Default = PersonNamePreferredOrder.#objectValues[0];
+    static GivenFirst = PersonNamePreferredOrder.#objectValues[1];
+    static SurnameFirst = PersonNamePreferredOrder.#objectValues[2];
+
+
+    constructor(value) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+// generated by diplomat-tool
+import type { DataProvider } from "./DataProvider"
+import type { Locale } from "./Locale"
+import type { PersonName } from "./PersonName"
+import type { PersonNamesFormatterError } from "./PersonNamesFormatterError"
+import type { PersonNamesFormatterFormality } from "./PersonNamesFormatterFormality"
+import type { PersonNamesFormatterLength } from "./PersonNamesFormatterLength"
+import type { PersonNamesFormatterOrder } from "./PersonNamesFormatterOrder"
+import type { PersonNamesFormatterUsage } from "./PersonNamesFormatterUsage"
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Person Names Formatter object, capable of formatting a {@link PersonName}
+ * according to the conventions of a locale, such as "Wells, H. G.".
+ *
+ * See the [Rust documentation for `PersonNamesFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html) for more information.
+ */
+export class PersonNamesFormatter {
+    /** @internal */
+    get ffiValue(): pointer;
+
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link PersonNamesFormatter} using a particular data source.
+     *
+     * Names must then be formatted with {@link Self::format_with_provider}, using the same data source.
+     *
+     * See the [Rust documentation for `try_new_unstable`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.try_new_unstable) for more information.
+     */
+    static createWithProvider(provider: DataProvider, locale: Locale, order: PersonNamesFormatterOrder, length: PersonNamesFormatterLength, usage: PersonNamesFormatterUsage, formality: PersonNamesFormatterFormality): PersonNamesFormatter;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link PersonName} to a string, using compiled data.
+     *
+     * See the [Rust documentation for `format_to_string`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.format_to_string) for more information.
+     */
+    format(name: PersonName): string;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link PersonName} to a string, using a particular data source.
+     *
+     * See the [Rust documentation for `format_to_string`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.format_to_string) for more information.
+     */
+    formatWithProvider(provider: DataProvider, name: PersonName): string;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link PersonNamesFormatter} using compiled data.
+     *
+     * See the [Rust documentation for `try_new_unstable`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.try_new_unstable) for more information.
+     */
+    constructor(locale: Locale, order: PersonNamesFormatterOrder, length: PersonNamesFormatterLength, usage: PersonNamesFormatterUsage, formality: PersonNamesFormatterFormality);
+}
\ No newline at end of file
@@ -0,0 +1,182 @@
+// generated by diplomat-tool
+import { DataProvider } from "./DataProvider.mjs"
+import { Locale } from "./Locale.mjs"
+import { PersonName } from "./PersonName.mjs"
+import { PersonNamesFormatterError } from "./PersonNamesFormatterError.mjs"
+import { PersonNamesFormatterFormality } from "./PersonNamesFormatterFormality.mjs"
+import { PersonNamesFormatterLength } from "./PersonNamesFormatterLength.mjs"
+import { PersonNamesFormatterOrder } from "./PersonNamesFormatterOrder.mjs"
+import { PersonNamesFormatterUsage } from "./PersonNamesFormatterUsage.mjs"
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+const PersonNamesFormatter_box_destroy_registry = new FinalizationRegistry((ptr) => {
+    wasm.icu4x_PersonNamesFormatter_destroy_mv1(ptr);
+});
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Person Names Formatter object, capable of formatting a {@link PersonName}
+ * according to the conventions of a locale, such as "Wells, H. G.".
+ *
+ * See the [Rust documentation for `PersonNamesFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html) for more information.
+ */
+export class PersonNamesFormatter {
+    // Internal ptr reference:
+    #ptr = null;
+
+    // Lifetimes are only to keep dependencies alive.
+    // Since JS won't garbage collect until there are no incoming edges.
+    #selfEdge = [];
+
+    #internalConstructor(symbol, ptr, selfEdge) {
+        if (symbol !== diplomatRuntime.internalConstructor) {
+            console.error("PersonNamesFormatter is an Opaque type. You cannot call its constructor.");
+            return;
+        }
+        this.#ptr = ptr;
+        this.#selfEdge = selfEdge;
+
+        // Are we being borrowed? If not, we can register.
+        if (this.#selfEdge.length === 0) {
+            PersonNamesFormatter_box_destroy_registry.register(this, this.#ptr);
+        }
+
+        return this;
+    }
+    /** @internal */
+    get ffiValue() {
+        return this.#ptr;
+    }
+
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link PersonNamesFormatter} using compiled data.
+     *
+     * See the [Rust documentation for `try_new_unstable`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.try_new_unstable) for more information.
+     */
+    #defaultConstructor(locale, order, length, usage, formality) {
+        const diplomatReceive = new diplomatRuntime.DiplomatReceiveBuf(wasm, 5, 4, true);
+
+
+        const result = wasm.icu4x_PersonNamesFormatter_create_mv1(diplomatReceive.buffer, locale.ffiValue, order.ffiValue, length.ffiValue, usage.ffiValue, formality.ffiValue);
+
+        try {
+            if (!diplomatReceive.resultFlag) {
+                const cause = new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.enumDiscriminant(wasm, diplomatReceive.buffer));
+                throw new globalThis.Error('PersonNamesFormatterError.' + cause.value, { cause });
+            }
+            return new PersonNamesFormatter(diplomatRuntime.internalConstructor, diplomatRuntime.ptrRead(wasm, diplomatReceive.buffer), []);
+        }
+
+        finally {
+            diplomatReceive.free();
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link PersonNamesFormatter} using a particular data source.
+     *
+     * Names must then be formatted with {@link Self::format_with_provider}, using the same data source.
+     *
+     * See the [Rust documentation for `try_new_unstable`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.try_new_unstable) for more information.
+     */
+    static createWithProvider(provider, locale, order, length, usage, formality) {
+        const diplomatReceive = new diplomatRuntime.DiplomatReceiveBuf(wasm, 5, 4, true);
+
+
+        const result = wasm.icu4x_PersonNamesFormatter_create_with_provider_mv1(diplomatReceive.buffer, provider.ffiValue, locale.ffiValue, order.ffiValue, length.ffiValue, usage.ffiValue, formality.ffiValue);
+
+        try {
+            if (!diplomatReceive.resultFlag) {
+                const cause = new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.enumDiscriminant(wasm, diplomatReceive.buffer));
+                throw new globalThis.Error('PersonNamesFormatterError.' + cause.value, { cause });
+            }
+            return new PersonNamesFormatter(diplomatRuntime.internalConstructor, diplomatRuntime.ptrRead(wasm, diplomatReceive.buffer), []);
+        }
+
+        finally {
+            diplomatReceive.free();
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link PersonName} to a string, using compiled data.
+     *
+     * See the [Rust documentation for `format_to_string`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.format_to_string) for more information.
+     */
+    format(name) {
+        const diplomatReceive = new diplomatRuntime.DiplomatReceiveBuf(wasm, 5, 4, true);
+
+        const write = new diplomatRuntime.DiplomatWriteBuf(wasm);
+
+
+        const result = wasm.icu4x_PersonNamesFormatter_format_mv1(diplomatReceive.buffer, this.ffiValue, name.ffiValue, write.buffer);
+
+        try {
+            if (!diplomatReceive.resultFlag) {
+                const cause = new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.enumDiscriminant(wasm, diplomatReceive.buffer));
+                throw new globalThis.Error('PersonNamesFormatterError.' + cause.value, { cause });
+            }
+            return write.readString8();
+        }
+
+        finally {
+            diplomatReceive.free();
+            write.free();
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link PersonName} to a string, using a particular data source.
+     *
+     * See the [Rust documentation for `format_to_string`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.format_to_string) for more information.
+     */
+    formatWithProvider(provider, name) {
+        const diplomatReceive = new diplomatRuntime.DiplomatReceiveBuf(wasm, 5, 4, true);
+
+        const write = new diplomatRuntime.DiplomatWriteBuf(wasm);
+
+
+        const result = wasm.icu4x_PersonNamesFormatter_format_with_provider_mv1(diplomatReceive.buffer, this.ffiValue, provider.ffiValue, name.ffiValue, write.buffer);
+
+        try {
+            if (!diplomatReceive.resultFlag) {
+                const cause = new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.enumDiscriminant(wasm, diplomatReceive.buffer));
+                throw new globalThis.Error('PersonNamesFormatterError.' + cause.value, { cause });
+            }
+            return write.readString8();
+        }
+
+        finally {
+            diplomatReceive.free();
+            write.free();
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link PersonNamesFormatter} using compiled data.
+     *
+     * See the [Rust documentation for `try_new_unstable`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/struct.PersonNamesFormatter.html#method.try_new_unstable) for more information.
+     */
+    constructor(locale, order, length, usage, formality) {
+        if (arguments[0] === diplomatRuntime.exposeConstructor) {
+            return this.#internalConstructor(...Array.prototype.slice.call(arguments, 1));
+        } else if (arguments[0] === diplomatRuntime.internalConstructor) {
+            return this.#internalConstructor(...arguments);
+        } else {
+            return this.#defaultConstructor(...arguments);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+// generated by diplomat-tool
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * Additional information: [1](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.PersonNamesFormatterError.html), [2](https://docs.rs/icu_provider/2.0.0/icu_provider/struct.DataError.html), [3](https://docs.rs/icu_provider/2.0.0/icu_provider/enum.DataErrorKind.html)
+ */
+export class PersonNamesFormatterError {
+
+    /** @internal */
+    static fromValue(value: PersonNamesFormatterError | string): PersonNamesFormatterError;
+
+    get value(): string;
+
+    /** @internal */
+    get ffiValue(): number;
+
+    static Unknown : PersonNamesFormatterError;
+    static Parse : PersonNamesFormatterError;
+    static InvalidPersonName : PersonNamesFormatterError;
+    static InvalidLocale : PersonNamesFormatterError;
+    static InvalidCldrData : PersonNamesFormatterError;
+    static Pattern : PersonNamesFormatterError;
+    static DataMarkerNotFound : PersonNamesFormatterError;
+    static DataIdentifierNotFound : PersonNamesFormatterError;
+    static DataInvalidRequest : PersonNamesFormatterError;
+    static DataInconsistentData : PersonNamesFormatterError;
+    static DataDowncast : PersonNamesFormatterError;
+    static DataDeserialize : PersonNamesFormatterError;
+    static DataCustom : PersonNamesFormatterError;
+    static DataIo : PersonNamesFormatterError;
+
+
+    constructor(value: PersonNamesFormatterError | string );
+}
\ No newline at end of file
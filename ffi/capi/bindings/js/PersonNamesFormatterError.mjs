@@ -0,0 +1,114 @@
+// generated by diplomat-tool
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * Additional information: [1](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.PersonNamesFormatterError.html), [2](https://docs.rs/icu_provider/2.0.0/icu_provider/struct.DataError.html), [3](https://docs.rs/icu_provider/2.0.0/icu_provider/enum.DataErrorKind.html)
+ */
+export class PersonNamesFormatterError {
+    #value = undefined;
+
+    static #values = new Map([
+        ["Unknown", 0],
+        ["Parse", 2049],
+        ["InvalidPersonName", 2050],
+        ["InvalidLocale", 2051],
+        ["InvalidCldrData", 2052],
+        ["Pattern", 2053],
+        ["DataMarkerNotFound", 1],
+        ["DataIdentifierNotFound", 2],
+        ["DataInvalidRequest", 3],
+        ["DataInconsistentData", 4],
+        ["DataDowncast", 5],
+        ["DataDeserialize", 6],
+        ["DataCustom", 7],
+        ["DataIo", 8]
+    ]);
+
+    static getAllEntries() {
+        return PersonNamesFormatterError.#values.entries();
+    }
+
+    #internalConstructor(value) {
+        if (arguments.length > 1 && arguments[0] === diplomatRuntime.internalConstructor) {
+            // We pass in two internalConstructor arguments to create *new*
+            // instances of this type, otherwise the enums are treated as singletons.
+            if (arguments[1] === diplomatRuntime.internalConstructor ) {
+                this.#value = arguments[2];
+                return this;
+            }
+            return PersonNamesFormatterError.#objectValues[arguments[1]];
+        }
+
+        if (value instanceof PersonNamesFormatterError) {
+            return value;
+        }
+
+        let intVal = PersonNamesFormatterError.#values.get(value);
+
+        // Nullish check, checks for null or undefined
+        if (intVal != null) {
+            return PersonNamesFormatterError.#objectValues[intVal];
+        }
+
+        throw TypeError(value + " is not a PersonNamesFormatterError and does not correspond to any of its enumerator values.");
+    }
+
+    /** @internal */
+    static fromValue(value) {
+        return new PersonNamesFormatterError(value);
+    }
+
+    get value(){
+        for (let entry of PersonNamesFormatterError.#values) {
+            if (entry[1] == this.#value) {
+                return entry[0];
+            }
+        }
+    }
+
+    /** @internal */
+    get ffiValue(){
+        return this.#value;
+    }
+    static #objectValues = {
+        [0]: new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 0),
+        [2049]: new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2049),
+        [2050]: new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2050),
+        [2051]: new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2051),
+        [2052]: new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2052),
+        [2053]: new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2053),
+        [1]: new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 1),
+        [2]: new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2),
+        [3]: new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 3),
+        [4]: new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 4),
+        [5]: new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 5),
+        [6]: new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 6),
+        [7]: new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 7),
+        [8]: new PersonNamesFormatterError(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 8),
+    };
+
+    static Unknown = PersonNamesFormatterError.#objectValues[0];
+    static Parse = PersonNamesFormatterError.#objectValues[2049];
+    static InvalidPersonName = PersonNamesFormatterError.#objectValues[2050];
+    static InvalidLocale = PersonNamesFormatterError.#objectValues[2051];
+    static InvalidCldrData = PersonNamesFormatterError.#objectValues[2052];
+    static Pattern = PersonNamesFormatterError.#objectValues[2053];
+    static DataMarkerNotFound = PersonNamesFormatterError.#objectValues[1];
+    static DataIdentifierNotFound = PersonNamesFormatterError.#objectValues[2];
+    static DataInvalidRequest = PersonNamesFormatterError.#objectValues[3];
+    static DataInconsistentData = PersonNamesFormatterError.#objectValues[4];
+    static DataDowncast = PersonNamesFormatterError.#objectValues[5];
+    static DataDeserialize = PersonNamesFormatterError.#objectValues[6];
+    static DataCustom = PersonNamesFormatterError.#objectValues[7];
+    static DataIo = PersonNamesFormatterError.#objectValues[8];
+
+
+    constructor(value) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
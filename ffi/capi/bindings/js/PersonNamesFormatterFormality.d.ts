@@ -0,0 +1,26 @@
+// generated by diplomat-tool
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `FormattingFormality`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.FormattingFormality.html) for more information.
+ */
+export class PersonNamesFormatterFormality {
+
+    /** @internal */
+    static fromValue(value: PersonNamesFormatterFormality | string): PersonNamesFormatterFormality;
+
+    get value(): string;
+
+    /** @internal */
+    get ffiValue(): number;
+
+    static Formal : PersonNamesFormatterFormality;
+    static Informal : PersonNamesFormatterFormality;
+
+
+    constructor(value: PersonNamesFormatterFormality | string );
+}
\ No newline at end of file
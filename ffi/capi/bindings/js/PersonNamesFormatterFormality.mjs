@@ -0,0 +1,74 @@
+// generated by diplomat-tool
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `FormattingFormality`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.FormattingFormality.html) for more information.
+ */
+export class PersonNamesFormatterFormality {
+    #value = undefined;
+
+    static #values = new Map([
+        ["Formal", 0],
+        ["Informal", 1]
+    ]);
+
+    static getAllEntries() {
+        return PersonNamesFormatterFormality.#values.entries();
+    }
+
+    #internalConstructor(value) {
+        if (arguments.length > 1 && arguments[0] === diplomatRuntime.internalConstructor) {
+            // We pass in two internalConstructor arguments to create *new*
+            // instances of this type, otherwise the enums are treated as singletons.
+            if (arguments[1] === diplomatRuntime.internalConstructor ) {
+                this.#value = arguments[2];
+                return this;
+            }
+            return PersonNamesFormatterFormality.#objectValues[arguments[1]];
+        }
+
+        if (value instanceof PersonNamesFormatterFormality) {
+            return value;
+        }
+
+        let intVal = PersonNamesFormatterFormality.#values.get(value);
+
+        // Nullish check, checks for null or undefined
+        if (intVal != null) {
+            return PersonNamesFormatterFormality.#objectValues[intVal];
+        }
+
+        throw TypeError(value + " is not a PersonNamesFormatterFormality and does not correspond to any of its enumerator values.");
+    }
+
+    /** @internal */
+    static fromValue(value) {
+        return new PersonNamesFormatterFormality(value);
+    }
+
+    get value(){
+        return [...PersonNamesFormatterFormality.#values.keys()][this.#value];
+    }
+
+    /** @internal */
+    get ffiValue(){
+        return this.#value;
+    }
+    static #objectValues = [
+        new PersonNamesFormatterFormality(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 0),
+        new PersonNamesFormatterFormality(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 1),
+    ];
+
+    static Formal = PersonNamesFormatterFormality.#objectValues[0];
+    static Informal = PersonNamesFormatterFormality.#objectValues[1];
+
+
+    constructor(value) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
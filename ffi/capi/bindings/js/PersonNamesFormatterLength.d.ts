@@ -0,0 +1,27 @@
+// generated by diplomat-tool
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `FormattingLength`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.FormattingLength.html) for more information.
+ */
+export class PersonNamesFormatterLength {
+
+    /** @internal */
+    static fromValue(value: PersonNamesFormatterLength | string): PersonNamesFormatterLength;
+
+    get value(): string;
+
+    /** @internal */
+    get ffiValue(): number;
+
+    static Short : PersonNamesFormatterLength;
+    static Medium : PersonNamesFormatterLength;
+    static Long : PersonNamesFormatterLength;
+
+
+    constructor(value: PersonNamesFormatterLength | string );
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+// generated by diplomat-tool
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `FormattingLength`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.FormattingLength.html) for more information.
+ */
+export class PersonNamesFormatterLength {
+    #value = undefined;
+
+    static #values = new Map([
+        ["Short", 0],
+        ["Medium", 1],
+        ["Long", 2]
+    ]);
+
+    static getAllEntries() {
+        return PersonNamesFormatterLength.#values.entries();
+    }
+
+    #internalConstructor(value) {
+        if (arguments.length > 1 && arguments[0] === diplomatRuntime.internalConstructor) {
+            // We pass in two internalConstructor arguments to create *new*
+            // instances of this type, otherwise the enums are treated as singletons.
+            if (arguments[1] === diplomatRuntime.internalConstructor ) {
+                this.#value = arguments[2];
+                return this;
+            }
+            return PersonNamesFormatterLength.#objectValues[arguments[1]];
+        }
+
+        if (value instanceof PersonNamesFormatterLength) {
+            return value;
+        }
+
+        let intVal = PersonNamesFormatterLength.#values.get(value);
+
+        // Nullish check, checks for null or undefined
+        if (intVal != null) {
+            return PersonNamesFormatterLength.#objectValues[intVal];
+        }
+
+        throw TypeError(value + " is not a PersonNamesFormatterLength and does not correspond to any of its enumerator values.");
+    }
+
+    /** @internal */
+    static fromValue(value) {
+        return new PersonNamesFormatterLength(value);
+    }
+
+    get value(){
+        return [...PersonNamesFormatterLength.#values.keys()][this.#value];
+    }
+
+    /** @internal */
+    get ffiValue(){
+        return this.#value;
+    }
+    static #objectValues = [
+        new PersonNamesFormatterLength(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 0),
+        new PersonNamesFormatterLength(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 1),
+        new PersonNamesFormatterLength(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2),
+    ];
+
+    static Short = PersonNamesFormatterLength.#objectValues[0];
+    static Medium = PersonNamesFormatterLength.#objectValues[1];
+    static Long = PersonNamesFormatterLength.#objectValues[2];
+
+
+    constructor(value) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+// generated by diplomat-tool
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `FormattingOrder`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.FormattingOrder.html) for more information.
+ */
+export class PersonNamesFormatterOrder {
+
+    /** @internal */
+    static fromValue(value: PersonNamesFormatterOrder | string): PersonNamesFormatterOrder;
+
+    get value(): string;
+
+    /** @internal */
+    get ffiValue(): number;
+
+    static GivenFirst : PersonNamesFormatterOrder;
+    static SurnameFirst : PersonNamesFormatterOrder;
+    static Sorting : PersonNamesFormatterOrder;
+
+
+    constructor(value: PersonNamesFormatterOrder | string );
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+// generated by diplomat-tool
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `FormattingOrder`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.FormattingOrder.html) for more information.
+ */
+export class PersonNamesFormatterOrder {
+    #value = undefined;
+
+    static #values = new Map([
+        ["GivenFirst", 0],
+        ["SurnameFirst", 1],
+        ["Sorting", 2]
+    ]);
+
+    static getAllEntries() {
+        return PersonNamesFormatterOrder.#values.entries();
+    }
+
+    #internalConstructor(value) {
+        if (arguments.length > 1 && arguments[0] === diplomatRuntime.internalConstructor) {
+            // We pass in two internalConstructor arguments to create *new*
+            // instances of this type, otherwise the enums are treated as singletons.
+            if (arguments[1] === diplomatRuntime.internalConstructor ) {
+                this.#value = arguments[2];
+                return this;
+            }
+            return PersonNamesFormatterOrder.#objectValues[arguments[1]];
+        }
+
+        if (value instanceof PersonNamesFormatterOrder) {
+            return value;
+        }
+
+        let intVal = PersonNamesFormatterOrder.#values.get(value);
+
+        // Nullish check, checks for null or undefined
+        if (intVal != null) {
+            return PersonNamesFormatterOrder.#objectValues[intVal];
+        }
+
+        throw TypeError(value + " is not a PersonNamesFormatterOrder and does not correspond to any of its enumerator values.");
+    }
+
+    /** @internal */
+    static fromValue(value) {
+        return new PersonNamesFormatterOrder(value);
+    }
+
+    get value(){
+        return [...PersonNamesFormatterOrder.#values.keys()][this.#value];
+    }
+
+    /** @internal */
+    get ffiValue(){
+        return this.#value;
+    }
+    static #objectValues = [
+        new PersonNamesFormatterOrder(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 0),
+        new PersonNamesFormatterOrder(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 1),
+        new PersonNamesFormatterOrder(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2),
+    ];
+
+    static GivenFirst = PersonNamesFormatterOrder.#objectValues[0];
+    static SurnameFirst = PersonNamesFormatterOrder.#objectValues[1];
+    static Sorting = PersonNamesFormatterOrder.#objectValues[2];
+
+
+    constructor(value) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+// generated by diplomat-tool
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `FormattingUsage`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.FormattingUsage.html) for more information.
+ */
+export class PersonNamesFormatterUsage {
+
+    /** @internal */
+    static fromValue(value: PersonNamesFormatterUsage | string): PersonNamesFormatterUsage;
+
+    get value(): string;
+
+    /** @internal */
+    get ffiValue(): number;
+
+    static Addressing : PersonNamesFormatterUsage;
+    static Referring : PersonNamesFormatterUsage;
+    static Monogram : PersonNamesFormatterUsage;
+
+
+    constructor(value: PersonNamesFormatterUsage | string );
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+// generated by diplomat-tool
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `FormattingUsage`](https://docs.rs/icu/2.0.0/icu/experimental/personnames/api/enum.FormattingUsage.html) for more information.
+ */
+export class PersonNamesFormatterUsage {
+    #value = undefined;
+
+    static #values = new Map([
+        ["Addressing", 0],
+        ["Referring", 1],
+        ["Monogram", 2]
+    ]);
+
+    static getAllEntries() {
+        return PersonNamesFormatterUsage.#values.entries();
+    }
+
+    #internalConstructor(value) {
+        if (arguments.length > 1 && arguments[0] === diplomatRuntime.internalConstructor) {
+            // We pass in two internalConstructor arguments to create *new*
+            // instances of this type, otherwise the enums are treated as singletons.
+            if (arguments[1] === diplomatRuntime.internalConstructor ) {
+                this.#value = arguments[2];
+                return this;
+            }
+            return PersonNamesFormatterUsage.#objectValues[arguments[1]];
+        }
+
+        if (value instanceof PersonNamesFormatterUsage) {
+            return value;
+        }
+
+        let intVal = PersonNamesFormatterUsage.#values.get(value);
+
+        // Nullish check, checks for null or undefined
+        if (intVal != null) {
+            return PersonNamesFormatterUsage.#objectValues[intVal];
+        }
+
+        throw TypeError(value + " is not a PersonNamesFormatterUsage and does not correspond to any of its enumerator values.");
+    }
+
+    /** @internal */
+    static fromValue(value) {
+        return new PersonNamesFormatterUsage(value);
+    }
+
+    get value(){
+        return [...PersonNamesFormatterUsage.#values.keys()][this.#value];
+    }
+
+    /** @internal */
+    get ffiValue(){
+        return this.#value;
+    }
+    static #objectValues = [
+        new PersonNamesFormatterUsage(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 0),
+        new PersonNamesFormatterUsage(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 1),
+        new PersonNamesFormatterUsage(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2),
+    ];
+
+    static Addressing = PersonNamesFormatterUsage.#objectValues[0];
+    static Referring = PersonNamesFormatterUsage.#objectValues[1];
+    static Monogram = PersonNamesFormatterUsage.#objectValues[2];
+
+
+    constructor(value) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,54 @@
+// generated by diplomat-tool
+import type { DataError } from "./DataError"
+import type { DataProvider } from "./DataProvider"
+import type { Decimal } from "./Decimal"
+import type { Locale } from "./Locale"
+import type { RelativeTimeLength } from "./RelativeTimeLength"
+import type { RelativeTimeNumeric } from "./RelativeTimeNumeric"
+import type { RelativeTimeUnit } from "./RelativeTimeUnit"
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Relative Time Format object, capable of formatting a {@link Decimal} number of
+ * a fixed time unit relative to now, such as "in 3 days" or "yesterday".
+ *
+ * See the [Rust documentation for `RelativeTimeFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html) for more information.
+ */
+export class RelativeTimeFormatter {
+    /** @internal */
+    get ffiValue(): pointer;
+
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link RelativeTimeFormatter} for the given unit and length, using a particular data source.
+     *
+     * See the [Rust documentation for `try_new_long_second`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html#method.try_new_long_second) for more information.
+     */
+    static createWithProvider(provider: DataProvider, locale: Locale, unit: RelativeTimeUnit, length: RelativeTimeLength, numeric: RelativeTimeNumeric): RelativeTimeFormatter;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link Decimal} number of units relative to now.
+     *
+     * Negative values are formatted in the past, positive values (and zero) in the future.
+     *
+     * See the [Rust documentation for `format`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html#method.format) for more information.
+     */
+    format(value: Decimal): string;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link RelativeTimeFormatter} for the given unit and length, using compiled data.
+     *
+     * See the [Rust documentation for `try_new_long_second`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html#method.try_new_long_second) for more information.
+     */
+    constructor(locale: Locale, unit: RelativeTimeUnit, length: RelativeTimeLength, numeric: RelativeTimeNumeric);
+}
\ No newline at end of file
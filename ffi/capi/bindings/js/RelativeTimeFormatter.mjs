@@ -0,0 +1,144 @@
+// generated by diplomat-tool
+import { DataError } from "./DataError.mjs"
+import { DataProvider } from "./DataProvider.mjs"
+import { Decimal } from "./Decimal.mjs"
+import { Locale } from "./Locale.mjs"
+import { RelativeTimeLength } from "./RelativeTimeLength.mjs"
+import { RelativeTimeNumeric } from "./RelativeTimeNumeric.mjs"
+import { RelativeTimeUnit } from "./RelativeTimeUnit.mjs"
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+const RelativeTimeFormatter_box_destroy_registry = new FinalizationRegistry((ptr) => {
+    wasm.icu4x_RelativeTimeFormatter_destroy_mv1(ptr);
+});
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Relative Time Format object, capable of formatting a {@link Decimal} number of
+ * a fixed time unit relative to now, such as "in 3 days" or "yesterday".
+ *
+ * See the [Rust documentation for `RelativeTimeFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html) for more information.
+ */
+export class RelativeTimeFormatter {
+    // Internal ptr reference:
+    #ptr = null;
+
+    // Lifetimes are only to keep dependencies alive.
+    // Since JS won't garbage collect until there are no incoming edges.
+    #selfEdge = [];
+
+    #internalConstructor(symbol, ptr, selfEdge) {
+        if (symbol !== diplomatRuntime.internalConstructor) {
+            console.error("RelativeTimeFormatter is an Opaque type. You cannot call its constructor.");
+            return;
+        }
+        this.#ptr = ptr;
+        this.#selfEdge = selfEdge;
+
+        // Are we being borrowed? If not, we can register.
+        if (this.#selfEdge.length === 0) {
+            RelativeTimeFormatter_box_destroy_registry.register(this, this.#ptr);
+        }
+
+        return this;
+    }
+    /** @internal */
+    get ffiValue() {
+        return this.#ptr;
+    }
+
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link RelativeTimeFormatter} for the given unit and length, using compiled data.
+     *
+     * See the [Rust documentation for `try_new_long_second`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html#method.try_new_long_second) for more information.
+     */
+    #defaultConstructor(locale, unit, length, numeric) {
+        const diplomatReceive = new diplomatRuntime.DiplomatReceiveBuf(wasm, 5, 4, true);
+
+
+        const result = wasm.icu4x_RelativeTimeFormatter_create_mv1(diplomatReceive.buffer, locale.ffiValue, unit.ffiValue, length.ffiValue, numeric.ffiValue);
+
+        try {
+            if (!diplomatReceive.resultFlag) {
+                const cause = new DataError(diplomatRuntime.internalConstructor, diplomatRuntime.enumDiscriminant(wasm, diplomatReceive.buffer));
+                throw new globalThis.Error('DataError.' + cause.value, { cause });
+            }
+            return new RelativeTimeFormatter(diplomatRuntime.internalConstructor, diplomatRuntime.ptrRead(wasm, diplomatReceive.buffer), []);
+        }
+
+        finally {
+            diplomatReceive.free();
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link RelativeTimeFormatter} for the given unit and length, using a particular data source.
+     *
+     * See the [Rust documentation for `try_new_long_second`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html#method.try_new_long_second) for more information.
+     */
+    static createWithProvider(provider, locale, unit, length, numeric) {
+        const diplomatReceive = new diplomatRuntime.DiplomatReceiveBuf(wasm, 5, 4, true);
+
+
+        const result = wasm.icu4x_RelativeTimeFormatter_create_with_provider_mv1(diplomatReceive.buffer, provider.ffiValue, locale.ffiValue, unit.ffiValue, length.ffiValue, numeric.ffiValue);
+
+        try {
+            if (!diplomatReceive.resultFlag) {
+                const cause = new DataError(diplomatRuntime.internalConstructor, diplomatRuntime.enumDiscriminant(wasm, diplomatReceive.buffer));
+                throw new globalThis.Error('DataError.' + cause.value, { cause });
+            }
+            return new RelativeTimeFormatter(diplomatRuntime.internalConstructor, diplomatRuntime.ptrRead(wasm, diplomatReceive.buffer), []);
+        }
+
+        finally {
+            diplomatReceive.free();
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link Decimal} number of units relative to now.
+     *
+     * Negative values are formatted in the past, positive values (and zero) in the future.
+     *
+     * See the [Rust documentation for `format`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html#method.format) for more information.
+     */
+    format(value) {
+        const write = new diplomatRuntime.DiplomatWriteBuf(wasm);
+
+    wasm.icu4x_RelativeTimeFormatter_format_mv1(this.ffiValue, value.ffiValue, write.buffer);
+
+        try {
+            return write.readString8();
+        }
+
+        finally {
+            write.free();
+        }
+    }
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link RelativeTimeFormatter} for the given unit and length, using compiled data.
+     *
+     * See the [Rust documentation for `try_new_long_second`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/struct.RelativeTimeFormatter.html#method.try_new_long_second) for more information.
+     */
+    constructor(locale, unit, length, numeric) {
+        if (arguments[0] === diplomatRuntime.exposeConstructor) {
+            return this.#internalConstructor(...Array.prototype.slice.call(arguments, 1));
+        } else if (arguments[0] === diplomatRuntime.internalConstructor) {
+            return this.#internalConstructor(...arguments);
+        } else {
+            return this.#defaultConstructor(...arguments);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+// generated by diplomat-tool
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * The width of the unit names used by a {@link RelativeTimeFormatter}.
+ */
+export class RelativeTimeLength {
+
+    /** @internal */
+    static fromValue(value: RelativeTimeLength | string): RelativeTimeLength;
+
+    get value(): string;
+
+    /** @internal */
+    get ffiValue(): number;
+
+    static Long : RelativeTimeLength;
+    static Short : RelativeTimeLength;
+    static Narrow : RelativeTimeLength;
+
+
+    constructor(value: RelativeTimeLength | string );
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+// generated by diplomat-tool
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * The width of the unit names used by a {@link RelativeTimeFormatter}.
+ */
+export class RelativeTimeLength {
+    #value = undefined;
+
+    static #values = new Map([
+        ["Long", 0],
+        ["Short", 1],
+        ["Narrow", 2]
+    ]);
+
+    static getAllEntries() {
+        return RelativeTimeLength.#values.entries();
+    }
+
+    #internalConstructor(value) {
+        if (arguments.length > 1 && arguments[0] === diplomatRuntime.internalConstructor) {
+            // We pass in two internalConstructor arguments to create *new*
+            // instances of this type, otherwise the enums are treated as singletons.
+            if (arguments[1] === diplomatRuntime.internalConstructor ) {
+                this.#value = arguments[2];
+                return this;
+            }
+            return RelativeTimeLength.#objectValues[arguments[1]];
+        }
+
+        if (value instanceof RelativeTimeLength) {
+            return value;
+        }
+
+        let intVal = RelativeTimeLength.#values.get(value);
+
+        // Nullish check, checks for null or undefined
+        if (intVal != null) {
+            return RelativeTimeLength.#objectValues[intVal];
+        }
+
+        throw TypeError(value + " is not a RelativeTimeLength and does not correspond to any of its enumerator values.");
+    }
+
+    /** @internal */
+    static fromValue(value) {
+        return new RelativeTimeLength(value);
+    }
+
+    get value(){
+        return [...RelativeTimeLength.#values.keys()][this.#value];
+    }
+
+    /** @internal */
+    get ffiValue(){
+        return this.#value;
+    }
+    static #objectValues = [
+        new RelativeTimeLength(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 0),
+        new RelativeTimeLength(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 1),
+        new RelativeTimeLength(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2),
+    ];
+
+    static Long = RelativeTimeLength.#objectValues[0];
+    static Short = RelativeTimeLength.#objectValues[1];
+    static Narrow = RelativeTimeLength.#objectValues[2];
+
+
+    constructor(value) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
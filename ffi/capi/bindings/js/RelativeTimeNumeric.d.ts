@@ -0,0 +1,26 @@
+// generated by diplomat-tool
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `Numeric`](https://docs.rs/icu/2.0.0/icu/experimental/relativetime/options/enum.Numeric.html) for more information.
+ */
+export class RelativeTimeNumeric {
+
+    /** @internal */
+    static fromValue(value: RelativeTimeNumeric | string): RelativeTimeNumeric;
+
+    get value(): string;
+
+    /** @internal */
+    get ffiValue(): number;
+
+    static Always : RelativeTimeNumeric;
+    static Auto : RelativeTimeNumeric;
+
+
+    constructor(value: RelativeTimeNumeric | string );
+}
\ No newline at end of file
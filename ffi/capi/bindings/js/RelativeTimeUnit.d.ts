@@ -0,0 +1,32 @@
+// generated by diplomat-tool
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * The time unit formatted by a {@link RelativeTimeFormatter}.
+ */
+export class RelativeTimeUnit {
+
+    /** @internal */
+    static fromValue(value: RelativeTimeUnit | string): RelativeTimeUnit;
+
+    get value(): string;
+
+    /** @internal */
+    get ffiValue(): number;
+
+    static Second : RelativeTimeUnit;
+    static Minute : RelativeTimeUnit;
+    static Hour : RelativeTimeUnit;
+    static Day : RelativeTimeUnit;
+    static Week : RelativeTimeUnit;
+    static Month : RelativeTimeUnit;
+    static Quarter : RelativeTimeUnit;
+    static Year : RelativeTimeUnit;
+
+
+    constructor(value: RelativeTimeUnit | string );
+}
\ No newline at end of file
@@ -0,0 +1,92 @@
+// generated by diplomat-tool
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * The time unit formatted by a {@link RelativeTimeFormatter}.
+ */
+export class RelativeTimeUnit {
+    #value = undefined;
+
+    static #values = new Map([
+        ["Second", 0],
+        ["Minute", 1],
+        ["Hour", 2],
+        ["Day", 3],
+        ["Week", 4],
+        ["Month", 5],
+        ["Quarter", 6],
+        ["Year", 7]
+    ]);
+
+    static getAllEntries() {
+        return RelativeTimeUnit.#values.entries();
+    }
+
+    #internalConstructor(value) {
+        if (arguments.length > 1 && arguments[0] === diplomatRuntime.internalConstructor) {
+            // We pass in two internalConstructor arguments to create *new*
+            // instances of this type, otherwise the enums are treated as singletons.
+            if (arguments[1] === diplomatRuntime.internalConstructor ) {
+                this.#value = arguments[2];
+                return this;
+            }
+            return RelativeTimeUnit.#objectValues[arguments[1]];
+        }
+
+        if (value instanceof RelativeTimeUnit) {
+            return value;
+        }
+
+        let intVal = RelativeTimeUnit.#values.get(value);
+
+        // Nullish check, checks for null or undefined
+        if (intVal != null) {
+            return RelativeTimeUnit.#objectValues[intVal];
+        }
+
+        throw TypeError(value + " is not a RelativeTimeUnit and does not correspond to any of its enumerator values.");
+    }
+
+    /** @internal */
+    static fromValue(value) {
+        return new RelativeTimeUnit(value);
+    }
+
+    get value(){
+        return [...RelativeTimeUnit.#values.keys()][this.#value];
+    }
+
+    /** @internal */
+    get ffiValue(){
+        return this.#value;
+    }
+    static #objectValues = [
+        new RelativeTimeUnit(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 0),
+        new RelativeTimeUnit(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 1),
+        new RelativeTimeUnit(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2),
+        new RelativeTimeUnit(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 3),
+        new RelativeTimeUnit(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 4),
+        new RelativeTimeUnit(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 5),
+        new RelativeTimeUnit(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 6),
+        new RelativeTimeUnit(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 7),
+    ];
+
+    static Second = RelativeTimeUnit.#objectValues[0];
+    static Minute = RelativeTimeUnit.#objectValues[1];
+    static Hour = RelativeTimeUnit.#objectValues[2];
+    static Day = RelativeTimeUnit.#objectValues[3];
+    static Week = RelativeTimeUnit.#objectValues[4];
+    static Month = RelativeTimeUnit.#objectValues[5];
+    static Quarter = RelativeTimeUnit.#objectValues[6];
+    static Year = RelativeTimeUnit.#objectValues[7];
+
+
+    constructor(value) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
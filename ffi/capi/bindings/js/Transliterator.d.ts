@@ -0,0 +1,51 @@
+// generated by diplomat-tool
+import type { DataError } from "./DataError"
+import type { DataProvider } from "./DataProvider"
+import type { Locale } from "./Locale"
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Transliterator object, capable of transliterating text from one script or
+ * form to another, as identified by a BCP-47 `-t-` locale such as `und-Arab-t-und-beng`.
+ *
+ * See the [Rust documentation for `Transliterator`](https://docs.rs/icu/2.0.0/icu/experimental/transliterate/struct.Transliterator.html) for more information.
+ */
+export class Transliterator {
+    /** @internal */
+    get ffiValue(): pointer;
+
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link Transliterator} for the given BCP-47 `-t-` locale, using a particular data source.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/transliterate/struct.Transliterator.html#method.try_new) for more information.
+     */
+    static createWithProvider(provider: DataProvider, locale: Locale): Transliterator;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Transliterates `input`.
+     *
+     * Ill-formed input is treated as if errors had been replaced with REPLACEMENT CHARACTERs according
+     * to the WHATWG Encoding Standard.
+     *
+     * See the [Rust documentation for `transliterate`](https://docs.rs/icu/2.0.0/icu/experimental/transliterate/struct.Transliterator.html#method.transliterate) for more information.
+     */
+    transliterate(input: string): string;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link Transliterator} for the given BCP-47 `-t-` locale, using compiled data.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/transliterate/struct.Transliterator.html#method.try_new) for more information.
+     */
+    constructor(locale: Locale);
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+// generated by diplomat-tool
+import type { DataError } from "./DataError"
+import type { DataProvider } from "./DataProvider"
+import type { MeasureUnit } from "./MeasureUnit"
+import type { UnitsConverter } from "./UnitsConverter"
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Units Converter Factory object, capable of creating converters between
+ * {@link MeasureUnit}s.
+ *
+ * See the [Rust documentation for `ConverterFactory`](https://docs.rs/icu/2.0.0/icu/experimental/units/converter_factory/struct.ConverterFactory.html) for more information.
+ */
+export class UnitsConverterFactory {
+    /** @internal */
+    get ffiValue(): pointer;
+
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link UnitsConverterFactory} using a particular data source.
+     *
+     * See the [Rust documentation for `new`](https://docs.rs/icu/2.0.0/icu/experimental/units/converter_factory/struct.ConverterFactory.html#method.new) for more information.
+     */
+    static createWithProvider(provider: DataProvider): UnitsConverterFactory;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a converter from `input_unit` to `output_unit`, returning `null` if the
+     * units are not convertible (for example, `meter` and `second`).
+     *
+     * See the [Rust documentation for `converter`](https://docs.rs/icu/2.0.0/icu/experimental/units/converter_factory/struct.ConverterFactory.html#method.converter) for more information.
+     */
+    converter(inputUnit: MeasureUnit, outputUnit: MeasureUnit): UnitsConverter | null;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link UnitsConverterFactory} using compiled data.
+     *
+     * See the [Rust documentation for `new`](https://docs.rs/icu/2.0.0/icu/experimental/units/converter_factory/struct.ConverterFactory.html#method.new) for more information.
+     */
+    constructor();
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+// generated by diplomat-tool
+import type { DataError } from "./DataError"
+import type { DataProvider } from "./DataProvider"
+import type { Decimal } from "./Decimal"
+import type { Locale } from "./Locale"
+import type { UnitsFormatterWidth } from "./UnitsFormatterWidth"
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * An ICU4X Units Formatter object, capable of formatting a {@link Decimal} as a quantity of
+ * a particular unit, such as "12 meters".
+ *
+ * See the [Rust documentation for `UnitsFormatter`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/units/formatter/struct.UnitsFormatter.html) for more information.
+ */
+export class UnitsFormatter {
+    /** @internal */
+    get ffiValue(): pointer;
+
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link UnitsFormatter} for the given CLDR unit identifier (such as `meter`), using a particular data source.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/units/formatter/struct.UnitsFormatter.html#method.try_new) for more information.
+     */
+    static createWithProvider(provider: DataProvider, locale: Locale, unit: string, width: UnitsFormatterWidth): UnitsFormatter;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Formats a {@link Decimal} as a quantity of this formatter's unit.
+     *
+     * See the [Rust documentation for `format_fixed_decimal`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/units/formatter/struct.UnitsFormatter.html#method.format_fixed_decimal) for more information.
+     */
+    format(value: Decimal): string;
+
+    /**
+     * 🚧 This API is experimental and may experience breaking changes outside major releases.
+     *
+     * Creates a new {@link UnitsFormatter} for the given CLDR unit identifier (such as `meter`), using compiled data.
+     *
+     * See the [Rust documentation for `try_new`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/units/formatter/struct.UnitsFormatter.html#method.try_new) for more information.
+     */
+    constructor(locale: Locale, unit: string, width: UnitsFormatterWidth);
+}
\ No newline at end of file
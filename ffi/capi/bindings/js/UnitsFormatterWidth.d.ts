@@ -0,0 +1,27 @@
+// generated by diplomat-tool
+import type { pointer, codepoint } from "./diplomat-runtime.d.ts";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `Width`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/units/options/enum.Width.html) for more information.
+ */
+export class UnitsFormatterWidth {
+
+    /** @internal */
+    static fromValue(value: UnitsFormatterWidth | string): UnitsFormatterWidth;
+
+    get value(): string;
+
+    /** @internal */
+    get ffiValue(): number;
+
+    static Long : UnitsFormatterWidth;
+    static Short : UnitsFormatterWidth;
+    static Narrow : UnitsFormatterWidth;
+
+
+    constructor(value: UnitsFormatterWidth | string );
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+// generated by diplomat-tool
+import wasm from "./diplomat-wasm.mjs";
+import * as diplomatRuntime from "./diplomat-runtime.mjs";
+
+
+
+/**
+ * 🚧 This API is experimental and may experience breaking changes outside major releases.
+ *
+ * See the [Rust documentation for `Width`](https://docs.rs/icu/2.0.0/icu/experimental/dimension/units/options/enum.Width.html) for more information.
+ */
+export class UnitsFormatterWidth {
+    #value = undefined;
+
+    static #values = new Map([
+        ["Long", 0],
+        ["Short", 1],
+        ["Narrow", 2]
+    ]);
+
+    static getAllEntries() {
+        return UnitsFormatterWidth.#values.entries();
+    }
+
+    #internalConstructor(value) {
+        if (arguments.length > 1 && arguments[0] === diplomatRuntime.internalConstructor) {
+            // We pass in two internalConstructor arguments to create *new*
+            // instances of this type, otherwise the enums are treated as singletons.
+            if (arguments[1] === diplomatRuntime.internalConstructor ) {
+                this.#value = arguments[2];
+                return this;
+            }
+            return UnitsFormatterWidth.#objectValues[arguments[1]];
+        }
+
+        if (value instanceof UnitsFormatterWidth) {
+            return value;
+        }
+
+        let intVal = UnitsFormatterWidth.#values.get(value);
+
+        // Nullish check, checks for null or undefined
+        if (intVal != null) {
+            return UnitsFormatterWidth.#objectValues[intVal];
+        }
+
+        throw TypeError(value + " is not a UnitsFormatterWidth and does not correspond to any of its enumerator values.");
+    }
+
+    /** @internal */
+    static fromValue(value) {
+        return new UnitsFormatterWidth(value);
+    }
+
+    get value(){
+        return [...UnitsFormatterWidth.#values.keys()][this.#value];
+    }
+
+    /** @internal */
+    get ffiValue(){
+        return this.#value;
+    }
+    static #objectValues = [
+        new UnitsFormatterWidth(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 0),
+        new UnitsFormatterWidth(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 1),
+        new UnitsFormatterWidth(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 2),
+    ];
+
+    static Long = UnitsFormatterWidth.#objectValues[0];
+    static Short = UnitsFormatterWidth.#objectValues[1];
+    static Narrow = UnitsFormatterWidth.#objectValues[2];
+
+
+    constructor(value) {
+        return this.#internalConstructor(...arguments)
+    }
+}
\ No newline at end of file
@@ -10,6 +10,8 @@ export { IsoWeekOfYear, IsoWeekOfYear_obj } from "./IsoWeekOfYear"
 
 export { DisplayNamesOptions, DisplayNamesOptions_obj } from "./DisplayNamesOptions"
 
+export { Duration, Duration_obj } from "./Duration"
+
 export { DateTimeMismatchedCalendarError, DateTimeMismatchedCalendarError_obj } from "./DateTimeMismatchedCalendarError"
 
 export { DecimalLimitError } from "./DecimalLimitError"
@@ -66,6 +68,12 @@ export { Collator } from "./Collator"
 
 export { CodePointSetBuilder } from "./CodePointSetBuilder"
 
+export { CompactDecimalFormatter } from "./CompactDecimalFormatter"
+
+export { CurrencyFormatter } from "./CurrencyFormatter"
+
+export { LongCurrencyFormatter } from "./LongCurrencyFormatter"
+
 export { Date } from "./Date"
 
 export { IsoDate } from "./IsoDate"
@@ -84,6 +92,8 @@ export { LocaleDisplayNamesFormatter } from "./LocaleDisplayNamesFormatter"
 
 export { RegionDisplayNames } from "./RegionDisplayNames"
 
+export { DurationFormatter } from "./DurationFormatter"
+
 export { ExemplarCharacters } from "./ExemplarCharacters"
 
 export { LocaleFallbackIterator } from "./LocaleFallbackIterator"
@@ -114,6 +124,8 @@ export { Locale } from "./Locale"
 
 export { LocaleDirectionality } from "./LocaleDirectionality"
 
+export { LocalePriorityList } from "./LocalePriorityList"
+
 export { Logger } from "./Logger"
 
 export { ComposingNormalizer } from "./ComposingNormalizer"
@@ -126,6 +138,10 @@ export { CanonicalComposition } from "./CanonicalComposition"
 
 export { CanonicalDecomposition } from "./CanonicalDecomposition"
 
+export { PersonName } from "./PersonName"
+
+export { PersonNamesFormatter } from "./PersonNamesFormatter"
+
 export { PluralOperands } from "./PluralOperands"
 
 export { PluralRules } from "./PluralRules"
@@ -146,6 +162,8 @@ export { EmojiSetData } from "./EmojiSetData"
 
 export { DataProvider } from "./DataProvider"
 
+export { RelativeTimeFormatter } from "./RelativeTimeFormatter"
+
 export { ScriptExtensionsSet } from "./ScriptExtensionsSet"
 
 export { ScriptWithExtensions } from "./ScriptWithExtensions"
@@ -194,6 +212,16 @@ export { TimeZoneInfo } from "./TimeZoneInfo"
 
 export { TimeZoneFormatter } from "./TimeZoneFormatter"
 
+export { Transliterator } from "./Transliterator"
+
+export { UnitsFormatter } from "./UnitsFormatter"
+
+export { MeasureUnit } from "./MeasureUnit"
+
+export { UnitsConverter } from "./UnitsConverter"
+
+export { UnitsConverterFactory } from "./UnitsConverterFactory"
+
 export { UtcOffset } from "./UtcOffset"
 
 export { VariantOffsetsCalculator } from "./VariantOffsetsCalculator"
@@ -234,6 +262,8 @@ export { CollatorNumericOrdering } from "./CollatorNumericOrdering"
 
 export { CollatorStrength } from "./CollatorStrength"
 
+export { CurrencyFormatterWidth } from "./CurrencyFormatterWidth"
+
 export { Weekday } from "./Weekday"
 
 export { DateTimeAlignment } from "./DateTimeAlignment"
@@ -252,6 +282,10 @@ export { DisplayNamesStyle } from "./DisplayNamesStyle"
 
 export { LanguageDisplay } from "./LanguageDisplay"
 
+export { DurationFormatterStyle } from "./DurationFormatterStyle"
+
+export { DurationSign } from "./DurationSign"
+
 export { CalendarError } from "./CalendarError"
 
 export { DataError } from "./DataError"
@@ -262,8 +296,12 @@ export { DateTimeWriteError } from "./DateTimeWriteError"
 
 export { DecimalParseError } from "./DecimalParseError"
 
+export { DurationFormatterLoadError } from "./DurationFormatterLoadError"
+
 export { LocaleParseError } from "./LocaleParseError"
 
+export { PersonNamesFormatterError } from "./PersonNamesFormatterError"
+
 export { Rfc9557ParseError } from "./Rfc9557ParseError"
 
 export { LocaleFallbackPriority } from "./LocaleFallbackPriority"
@@ -282,6 +320,18 @@ export { TransformResult } from "./TransformResult"
 
 export { LocaleDirection } from "./LocaleDirection"
 
+export { PersonNameFieldKind } from "./PersonNameFieldKind"
+
+export { PersonNamePreferredOrder } from "./PersonNamePreferredOrder"
+
+export { PersonNamesFormatterFormality } from "./PersonNamesFormatterFormality"
+
+export { PersonNamesFormatterLength } from "./PersonNamesFormatterLength"
+
+export { PersonNamesFormatterOrder } from "./PersonNamesFormatterOrder"
+
+export { PersonNamesFormatterUsage } from "./PersonNamesFormatterUsage"
+
 export { PluralCategory } from "./PluralCategory"
 
 export { BidiPairedBracketType } from "./BidiPairedBracketType"
@@ -312,6 +362,12 @@ export { VerticalOrientation } from "./VerticalOrientation"
 
 export { WordBreak } from "./WordBreak"
 
+export { RelativeTimeLength } from "./RelativeTimeLength"
+
+export { RelativeTimeNumeric } from "./RelativeTimeNumeric"
+
+export { RelativeTimeUnit } from "./RelativeTimeUnit"
+
 export { LineBreakStrictness } from "./LineBreakStrictness"
 
 export { LineBreakWordOption } from "./LineBreakWordOption"
@@ -319,3 +375,5 @@ export { LineBreakWordOption } from "./LineBreakWordOption"
 export { SegmenterWordType } from "./SegmenterWordType"
 
 export { TimeZoneVariant } from "./TimeZoneVariant"
+
+export { UnitsFormatterWidth } from "./UnitsFormatterWidth"
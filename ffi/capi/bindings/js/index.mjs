@@ -8,6 +8,8 @@ export { IsoWeekOfYear } from "./IsoWeekOfYear.mjs"
 
 export { DisplayNamesOptions } from "./DisplayNamesOptions.mjs"
 
+export { Duration } from "./Duration.mjs"
+
 export { DateTimeMismatchedCalendarError } from "./DateTimeMismatchedCalendarError.mjs"
 
 export { DecimalLimitError } from "./DecimalLimitError.mjs"
@@ -64,6 +66,12 @@ export { Collator } from "./Collator.mjs"
 
 export { CodePointSetBuilder } from "./CodePointSetBuilder.mjs"
 
+export { CompactDecimalFormatter } from "./CompactDecimalFormatter.mjs"
+
+export { CurrencyFormatter } from "./CurrencyFormatter.mjs"
+
+export { LongCurrencyFormatter } from "./LongCurrencyFormatter.mjs"
+
 export { Date } from "./Date.mjs"
 
 export { IsoDate } from "./IsoDate.mjs"
@@ -82,6 +90,8 @@ export { LocaleDisplayNamesFormatter } from "./LocaleDisplayNamesFormatter.mjs"
 
 export { RegionDisplayNames } from "./RegionDisplayNames.mjs"
 
+export { DurationFormatter } from "./DurationFormatter.mjs"
+
 export { ExemplarCharacters } from "./ExemplarCharacters.mjs"
 
 export { LocaleFallbackIterator } from "./LocaleFallbackIterator.mjs"
@@ -112,6 +122,8 @@ export { Locale } from "./Locale.mjs"
 
 export { LocaleDirectionality } from "./LocaleDirectionality.mjs"
 
+export { LocalePriorityList } from "./LocalePriorityList.mjs"
+
 export { Logger } from "./Logger.mjs"
 
 export { ComposingNormalizer } from "./ComposingNormalizer.mjs"
@@ -124,6 +136,10 @@ export { CanonicalComposition } from "./CanonicalComposition.mjs"
 
 export { CanonicalDecomposition } from "./CanonicalDecomposition.mjs"
 
+export { PersonName } from "./PersonName.mjs"
+
+export { PersonNamesFormatter } from "./PersonNamesFormatter.mjs"
+
 export { PluralOperands } from "./PluralOperands.mjs"
 
 export { PluralRules } from "./PluralRules.mjs"
@@ -144,6 +160,8 @@ export { EmojiSetData } from "./EmojiSetData.mjs"
 
 export { DataProvider } from "./DataProvider.mjs"
 
+export { RelativeTimeFormatter } from "./RelativeTimeFormatter.mjs"
+
 export { ScriptExtensionsSet } from "./ScriptExtensionsSet.mjs"
 
 export { ScriptWithExtensions } from "./ScriptWithExtensions.mjs"
@@ -192,6 +210,16 @@ export { TimeZoneInfo } from "./TimeZoneInfo.mjs"
 
 export { TimeZoneFormatter } from "./TimeZoneFormatter.mjs"
 
+export { Transliterator } from "./Transliterator.mjs"
+
+export { UnitsFormatter } from "./UnitsFormatter.mjs"
+
+export { MeasureUnit } from "./MeasureUnit.mjs"
+
+export { UnitsConverter } from "./UnitsConverter.mjs"
+
+export { UnitsConverterFactory } from "./UnitsConverterFactory.mjs"
+
 export { UtcOffset } from "./UtcOffset.mjs"
 
 export { VariantOffsetsCalculator } from "./VariantOffsetsCalculator.mjs"
@@ -232,6 +260,8 @@ export { CollatorNumericOrdering } from "./CollatorNumericOrdering.mjs"
 
 export { CollatorStrength } from "./CollatorStrength.mjs"
 
+export { CurrencyFormatterWidth } from "./CurrencyFormatterWidth.mjs"
+
 export { Weekday } from "./Weekday.mjs"
 
 export { DateTimeAlignment } from "./DateTimeAlignment.mjs"
@@ -250,6 +280,10 @@ export { DisplayNamesStyle } from "./DisplayNamesStyle.mjs"
 
 export { LanguageDisplay } from "./LanguageDisplay.mjs"
 
+export { DurationFormatterStyle } from "./DurationFormatterStyle.mjs"
+
+export { DurationSign } from "./DurationSign.mjs"
+
 export { CalendarError } from "./CalendarError.mjs"
 
 export { DataError } from "./DataError.mjs"
@@ -260,8 +294,12 @@ export { DateTimeWriteError } from "./DateTimeWriteError.mjs"
 
 export { DecimalParseError } from "./DecimalParseError.mjs"
 
+export { DurationFormatterLoadError } from "./DurationFormatterLoadError.mjs"
+
 export { LocaleParseError } from "./LocaleParseError.mjs"
 
+export { PersonNamesFormatterError } from "./PersonNamesFormatterError.mjs"
+
 export { Rfc9557ParseError } from "./Rfc9557ParseError.mjs"
 
 export { LocaleFallbackPriority } from "./LocaleFallbackPriority.mjs"
@@ -280,6 +318,18 @@ export { TransformResult } from "./TransformResult.mjs"
 
 export { LocaleDirection } from "./LocaleDirection.mjs"
 
+export { PersonNameFieldKind } from "./PersonNameFieldKind.mjs"
+
+export { PersonNamePreferredOrder } from "./PersonNamePreferredOrder.mjs"
+
+export { PersonNamesFormatterFormality } from "./PersonNamesFormatterFormality.mjs"
+
+export { PersonNamesFormatterLength } from "./PersonNamesFormatterLength.mjs"
+
+export { PersonNamesFormatterOrder } from "./PersonNamesFormatterOrder.mjs"
+
+export { PersonNamesFormatterUsage } from "./PersonNamesFormatterUsage.mjs"
+
 export { PluralCategory } from "./PluralCategory.mjs"
 
 export { BidiPairedBracketType } from "./BidiPairedBracketType.mjs"
@@ -310,6 +360,12 @@ export { VerticalOrientation } from "./VerticalOrientation.mjs"
 
 export { WordBreak } from "./WordBreak.mjs"
 
+export { RelativeTimeLength } from "./RelativeTimeLength.mjs"
+
+export { RelativeTimeNumeric } from "./RelativeTimeNumeric.mjs"
+
+export { RelativeTimeUnit } from "./RelativeTimeUnit.mjs"
+
 export { LineBreakStrictness } from "./LineBreakStrictness.mjs"
 
 export { LineBreakWordOption } from "./LineBreakWordOption.mjs"
@@ -317,3 +373,5 @@ export { LineBreakWordOption } from "./LineBreakWordOption.mjs"
 export { SegmenterWordType } from "./SegmenterWordType.mjs"
 
 export { TimeZoneVariant } from "./TimeZoneVariant.mjs"
+
+export { UnitsFormatterWidth } from "./UnitsFormatterWidth.mjs"
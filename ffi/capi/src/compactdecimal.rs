@@ -0,0 +1,172 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#[diplomat::bridge]
+#[diplomat::abi_rename = "icu4x_{0}_mv1"]
+#[diplomat::attr(auto, namespace = "icu4x")]
+pub mod ffi {
+    use alloc::boxed::Box;
+
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use crate::unstable::decimal::ffi::DecimalGroupingStrategy;
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use crate::unstable::errors::ffi::DataError;
+    use crate::unstable::fixed_decimal::ffi::Decimal;
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use crate::unstable::locale_core::ffi::Locale;
+    #[cfg(feature = "buffer_provider")]
+    use crate::unstable::provider::ffi::DataProvider;
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use icu_experimental::compactdecimal::CompactDecimalFormatterPreferences;
+
+    use writeable::Writeable;
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    ///
+    /// An ICU4X Compact Decimal Format object, capable of formatting a [`Decimal`] in a compact form,
+    /// such as "1.2K" or "1.2 thousand".
+    #[diplomat::opaque]
+    #[diplomat::rust_link(icu::experimental::compactdecimal::CompactDecimalFormatter, Struct)]
+    #[diplomat::rust_link(
+        icu::experimental::compactdecimal::FormattedCompactDecimal,
+        Struct,
+        hidden
+    )]
+    pub struct CompactDecimalFormatter(
+        pub icu_experimental::compactdecimal::CompactDecimalFormatter,
+    );
+
+    impl CompactDecimalFormatter {
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`CompactDecimalFormatter`] for the short compact style (e.g. "1.2K"), using compiled data.
+        #[diplomat::rust_link(
+            icu::experimental::compactdecimal::CompactDecimalFormatter::try_new_short,
+            FnInStruct
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::compactdecimal::CompactDecimalFormatterOptions,
+            Struct,
+            hidden
+        )]
+        #[diplomat::attr(all(supports = fallible_constructors, supports = named_constructors), named_constructor = "short")]
+        #[cfg(feature = "compiled_data")]
+        pub fn create_short(
+            locale: &Locale,
+            grouping_strategy: Option<DecimalGroupingStrategy>,
+        ) -> Result<Box<CompactDecimalFormatter>, DataError> {
+            let prefs = CompactDecimalFormatterPreferences::from(&locale.0);
+            Ok(Box::new(CompactDecimalFormatter(
+                icu_experimental::compactdecimal::CompactDecimalFormatter::try_new_short(
+                    prefs,
+                    super::options(grouping_strategy),
+                )?,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`CompactDecimalFormatter`] for the short compact style (e.g. "1.2K"), using a particular data source.
+        #[diplomat::rust_link(
+            icu::experimental::compactdecimal::CompactDecimalFormatter::try_new_short,
+            FnInStruct
+        )]
+        #[diplomat::attr(all(supports = fallible_constructors, supports = named_constructors), named_constructor = "short_with_provider")]
+        #[cfg(feature = "buffer_provider")]
+        pub fn create_short_with_provider(
+            provider: &DataProvider,
+            locale: &Locale,
+            grouping_strategy: Option<DecimalGroupingStrategy>,
+        ) -> Result<Box<CompactDecimalFormatter>, DataError> {
+            let prefs = CompactDecimalFormatterPreferences::from(&locale.0);
+            Ok(Box::new(CompactDecimalFormatter(
+                icu_experimental::compactdecimal::CompactDecimalFormatter::try_new_short_with_buffer_provider(
+                    provider.get()?,
+                    prefs,
+                    super::options(grouping_strategy),
+                )?,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`CompactDecimalFormatter`] for the long compact style (e.g. "1.2 thousand"), using compiled data.
+        #[diplomat::rust_link(
+            icu::experimental::compactdecimal::CompactDecimalFormatter::try_new_long,
+            FnInStruct
+        )]
+        #[diplomat::attr(all(supports = fallible_constructors, supports = named_constructors), named_constructor = "long")]
+        #[cfg(feature = "compiled_data")]
+        pub fn create_long(
+            locale: &Locale,
+            grouping_strategy: Option<DecimalGroupingStrategy>,
+        ) -> Result<Box<CompactDecimalFormatter>, DataError> {
+            let prefs = CompactDecimalFormatterPreferences::from(&locale.0);
+            Ok(Box::new(CompactDecimalFormatter(
+                icu_experimental::compactdecimal::CompactDecimalFormatter::try_new_long(
+                    prefs,
+                    super::options(grouping_strategy),
+                )?,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`CompactDecimalFormatter`] for the long compact style (e.g. "1.2 thousand"), using a particular data source.
+        #[diplomat::rust_link(
+            icu::experimental::compactdecimal::CompactDecimalFormatter::try_new_long,
+            FnInStruct
+        )]
+        #[diplomat::attr(all(supports = fallible_constructors, supports = named_constructors), named_constructor = "long_with_provider")]
+        #[cfg(feature = "buffer_provider")]
+        pub fn create_long_with_provider(
+            provider: &DataProvider,
+            locale: &Locale,
+            grouping_strategy: Option<DecimalGroupingStrategy>,
+        ) -> Result<Box<CompactDecimalFormatter>, DataError> {
+            let prefs = CompactDecimalFormatterPreferences::from(&locale.0);
+            Ok(Box::new(CompactDecimalFormatter(
+                icu_experimental::compactdecimal::CompactDecimalFormatter::try_new_long_with_buffer_provider(
+                    provider.get()?,
+                    prefs,
+                    super::options(grouping_strategy),
+                )?,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Formats a [`Decimal`] to a string, rounding it to the precision of the compact pattern.
+        #[diplomat::rust_link(
+            icu::experimental::compactdecimal::CompactDecimalFormatter::format_fixed_decimal,
+            FnInStruct
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::compactdecimal::CompactDecimalFormatter::format_i64,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::compactdecimal::CompactDecimalFormatter::format_f64,
+            FnInStruct,
+            hidden
+        )]
+        // Experimental, do not generate in demo:
+        #[diplomat::attr(demo_gen, disable)]
+        pub fn format(&self, value: &Decimal, write: &mut DiplomatWrite) {
+            let _infallible = self.0.format_fixed_decimal(&value.0).write_to(write);
+        }
+    }
+}
+
+#[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+fn options(
+    grouping_strategy: Option<crate::unstable::decimal::ffi::DecimalGroupingStrategy>,
+) -> icu_experimental::compactdecimal::CompactDecimalFormatterOptions {
+    let mut options = icu_experimental::compactdecimal::CompactDecimalFormatterOptions::default();
+    if let Some(grouping_strategy) = grouping_strategy {
+        options.decimal_formatter_options.grouping_strategy = Some(grouping_strategy.into());
+    }
+    options
+}
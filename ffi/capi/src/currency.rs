@@ -0,0 +1,238 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#[diplomat::bridge]
+#[diplomat::abi_rename = "icu4x_{0}_mv1"]
+#[diplomat::attr(auto, namespace = "icu4x")]
+pub mod ffi {
+    use alloc::boxed::Box;
+
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use crate::unstable::errors::ffi::DataError;
+    use crate::unstable::fixed_decimal::ffi::Decimal;
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use crate::unstable::locale_core::ffi::Locale;
+    #[cfg(feature = "buffer_provider")]
+    use crate::unstable::provider::ffi::DataProvider;
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use icu_experimental::dimension::currency::formatter::CurrencyFormatterPreferences;
+    use icu_experimental::dimension::currency::CurrencyCode;
+
+    use writeable::Writeable;
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    #[diplomat::rust_link(icu::experimental::dimension::currency::options::Width, Enum)]
+    #[diplomat::enum_convert(icu_experimental::dimension::currency::options::Width, needs_wildcard)]
+    #[non_exhaustive]
+    pub enum CurrencyFormatterWidth {
+        #[diplomat::attr(auto, default)]
+        Short,
+        Narrow,
+    }
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    ///
+    /// An ICU4X Currency Formatter object, capable of formatting a [`Decimal`] as an amount
+    /// of a particular currency, such as "$12,345.67".
+    #[diplomat::opaque]
+    #[diplomat::rust_link(
+        icu::experimental::dimension::currency::formatter::CurrencyFormatter,
+        Struct
+    )]
+    #[diplomat::rust_link(
+        icu::experimental::dimension::currency::format::FormattedCurrency,
+        Struct,
+        hidden
+    )]
+    #[diplomat::rust_link(icu::experimental::dimension::currency::CurrencyCode, Struct, hidden)]
+    pub struct CurrencyFormatter(
+        pub icu_experimental::dimension::currency::formatter::CurrencyFormatter,
+        CurrencyCode,
+    );
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    ///
+    /// An ICU4X Long Currency Formatter object, capable of formatting a [`Decimal`] as an amount
+    /// of a particular currency using its long name, such as "12,345.67 US dollars".
+    #[diplomat::opaque]
+    #[diplomat::rust_link(
+        icu::experimental::dimension::currency::long_formatter::LongCurrencyFormatter,
+        Struct
+    )]
+    #[diplomat::rust_link(
+        icu::experimental::dimension::currency::long_format::LongFormattedCurrency,
+        Struct,
+        hidden
+    )]
+    pub struct LongCurrencyFormatter(
+        pub icu_experimental::dimension::currency::long_formatter::LongCurrencyFormatter,
+        CurrencyCode,
+    );
+
+    impl CurrencyFormatter {
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`CurrencyFormatter`] for the given ISO 4217 currency code, using compiled data.
+        ///
+        /// Returns [`DataError::IdentifierNotFound`] if the currency code is not well-formed.
+        #[diplomat::rust_link(
+            icu::experimental::dimension::currency::formatter::CurrencyFormatter::try_new,
+            FnInStruct
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::dimension::currency::options::CurrencyFormatterOptions,
+            Struct,
+            hidden
+        )]
+        #[diplomat::attr(supports = fallible_constructors, constructor)]
+        #[cfg(feature = "compiled_data")]
+        pub fn create(
+            locale: &Locale,
+            currency_code: &DiplomatStr,
+            width: CurrencyFormatterWidth,
+        ) -> Result<Box<CurrencyFormatter>, DataError> {
+            let prefs = CurrencyFormatterPreferences::from(&locale.0);
+            let currency_code = super::currency_code(currency_code)?;
+            Ok(Box::new(CurrencyFormatter(
+                icu_experimental::dimension::currency::formatter::CurrencyFormatter::try_new(
+                    prefs,
+                    icu_experimental::dimension::currency::options::Width::from(width).into(),
+                )?,
+                currency_code,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`CurrencyFormatter`] for the given ISO 4217 currency code, using a particular data source.
+        ///
+        /// Returns [`DataError::IdentifierNotFound`] if the currency code is not well-formed.
+        #[diplomat::rust_link(
+            icu::experimental::dimension::currency::formatter::CurrencyFormatter::try_new,
+            FnInStruct
+        )]
+        #[diplomat::attr(all(supports = fallible_constructors, supports = named_constructors), named_constructor = "with_provider")]
+        #[cfg(feature = "buffer_provider")]
+        pub fn create_with_provider(
+            provider: &DataProvider,
+            locale: &Locale,
+            currency_code: &DiplomatStr,
+            width: CurrencyFormatterWidth,
+        ) -> Result<Box<CurrencyFormatter>, DataError> {
+            let prefs = CurrencyFormatterPreferences::from(&locale.0);
+            let currency_code = super::currency_code(currency_code)?;
+            Ok(Box::new(CurrencyFormatter(
+                icu_experimental::dimension::currency::formatter::CurrencyFormatter::try_new_with_buffer_provider(
+                    provider.get()?,
+                    prefs,
+                    icu_experimental::dimension::currency::options::Width::from(width).into(),
+                )?,
+                currency_code,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Formats a [`Decimal`] as an amount of this formatter's currency.
+        #[diplomat::rust_link(
+            icu::experimental::dimension::currency::formatter::CurrencyFormatter::format_fixed_decimal,
+            FnInStruct
+        )]
+        // Experimental, do not generate in demo:
+        #[diplomat::attr(demo_gen, disable)]
+        pub fn format(&self, value: &Decimal, write: &mut DiplomatWrite) {
+            let _infallible = self
+                .0
+                .format_fixed_decimal(&value.0, self.1)
+                .write_to(write);
+        }
+    }
+
+    impl LongCurrencyFormatter {
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`LongCurrencyFormatter`] for the given ISO 4217 currency code, using compiled data.
+        ///
+        /// Returns [`DataError::IdentifierNotFound`] if the currency code is not well-formed.
+        #[diplomat::rust_link(
+            icu::experimental::dimension::currency::long_formatter::LongCurrencyFormatter::try_new,
+            FnInStruct
+        )]
+        #[diplomat::attr(supports = fallible_constructors, constructor)]
+        #[cfg(feature = "compiled_data")]
+        pub fn create(
+            locale: &Locale,
+            currency_code: &DiplomatStr,
+        ) -> Result<Box<LongCurrencyFormatter>, DataError> {
+            let prefs = CurrencyFormatterPreferences::from(&locale.0);
+            let currency_code = super::currency_code(currency_code)?;
+            Ok(Box::new(LongCurrencyFormatter(
+                icu_experimental::dimension::currency::long_formatter::LongCurrencyFormatter::try_new(
+                    prefs,
+                    &currency_code,
+                )?,
+                currency_code,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`LongCurrencyFormatter`] for the given ISO 4217 currency code, using a particular data source.
+        ///
+        /// Returns [`DataError::IdentifierNotFound`] if the currency code is not well-formed.
+        #[diplomat::rust_link(
+            icu::experimental::dimension::currency::long_formatter::LongCurrencyFormatter::try_new,
+            FnInStruct
+        )]
+        #[diplomat::attr(all(supports = fallible_constructors, supports = named_constructors), named_constructor = "with_provider")]
+        #[cfg(feature = "buffer_provider")]
+        pub fn create_with_provider(
+            provider: &DataProvider,
+            locale: &Locale,
+            currency_code: &DiplomatStr,
+        ) -> Result<Box<LongCurrencyFormatter>, DataError> {
+            let prefs = CurrencyFormatterPreferences::from(&locale.0);
+            let currency_code = super::currency_code(currency_code)?;
+            Ok(Box::new(LongCurrencyFormatter(
+                icu_experimental::dimension::currency::long_formatter::LongCurrencyFormatter::try_new_with_buffer_provider(
+                    provider.get()?,
+                    prefs,
+                    &currency_code,
+                )?,
+                currency_code,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Formats a [`Decimal`] as an amount of this formatter's currency, using its long name.
+        #[diplomat::rust_link(
+            icu::experimental::dimension::currency::long_formatter::LongCurrencyFormatter::format_fixed_decimal,
+            FnInStruct
+        )]
+        // Experimental, do not generate in demo:
+        #[diplomat::attr(demo_gen, disable)]
+        pub fn format(&self, value: &Decimal, write: &mut DiplomatWrite) {
+            let _infallible = self
+                .0
+                .format_fixed_decimal(&value.0, self.1)
+                .write_to(write);
+        }
+    }
+}
+
+#[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+fn currency_code(
+    code: &[u8],
+) -> Result<icu_experimental::dimension::currency::CurrencyCode, icu_provider::DataError> {
+    tinystr::TinyAsciiStr::try_from_utf8(code)
+        .ok()
+        .filter(|code| code.len() == 3 && code.is_ascii_alphabetic())
+        .map(|code| icu_experimental::dimension::currency::CurrencyCode(code.to_ascii_uppercase()))
+        .ok_or_else(|| {
+            icu_provider::DataErrorKind::IdentifierNotFound
+                .into_error()
+                .with_debug_context("invalid currency code")
+        })
+}
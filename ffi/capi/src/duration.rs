@@ -0,0 +1,185 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#[diplomat::bridge]
+#[diplomat::abi_rename = "icu4x_{0}_mv1"]
+#[diplomat::attr(auto, namespace = "icu4x")]
+pub mod ffi {
+    use alloc::boxed::Box;
+
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use crate::unstable::errors::ffi::DurationFormatterLoadError;
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use crate::unstable::locale_core::ffi::Locale;
+    #[cfg(feature = "buffer_provider")]
+    use crate::unstable::provider::ffi::DataProvider;
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use icu_experimental::duration::DurationFormatterPreferences;
+
+    use writeable::Writeable;
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    #[diplomat::rust_link(icu::experimental::duration::DurationSign, Enum)]
+    #[diplomat::enum_convert(icu_experimental::duration::DurationSign)]
+    #[non_exhaustive]
+    pub enum DurationSign {
+        #[diplomat::attr(auto, default)]
+        Positive,
+        Negative,
+    }
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    ///
+    /// A duration of time, such as "1 hour, 2 minutes".
+    #[diplomat::rust_link(icu::experimental::duration::Duration, Struct)]
+    #[diplomat::rust_link(icu::experimental::duration::Duration::new, FnInStruct, hidden)]
+    pub struct Duration {
+        pub sign: DurationSign,
+        pub years: u64,
+        pub months: u64,
+        pub weeks: u64,
+        pub days: u64,
+        pub hours: u64,
+        pub minutes: u64,
+        pub seconds: u64,
+        pub milliseconds: u64,
+        pub microseconds: u64,
+        pub nanoseconds: u64,
+    }
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    #[diplomat::rust_link(icu::experimental::duration::options::BaseStyle, Enum)]
+    #[diplomat::enum_convert(icu_experimental::duration::options::BaseStyle, needs_wildcard)]
+    #[non_exhaustive]
+    pub enum DurationFormatterStyle {
+        Narrow,
+        #[diplomat::attr(auto, default)]
+        Short,
+        Long,
+        Digital,
+    }
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    ///
+    /// An ICU4X Duration Formatter object, capable of formatting a [`Duration`], such as
+    /// "1 hr, 2 min" or "1:02:03".
+    #[diplomat::opaque]
+    #[diplomat::rust_link(icu::experimental::duration::DurationFormatter, Struct)]
+    #[diplomat::rust_link(icu::experimental::duration::FormattedDuration, Struct, hidden)]
+    pub struct DurationFormatter(pub icu_experimental::duration::DurationFormatter);
+
+    impl DurationFormatter {
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`DurationFormatter`] using compiled data.
+        ///
+        /// `fractional_digits` is the number of fractional digits (0 to 9) used for sub-second
+        /// units in the digital style; if absent, as many digits as necessary are shown.
+        #[diplomat::rust_link(icu::experimental::duration::DurationFormatter::try_new, FnInStruct)]
+        #[diplomat::rust_link(
+            icu::experimental::duration::options::DurationFormatterOptions,
+            Struct,
+            hidden
+        )]
+        #[diplomat::rust_link(icu::experimental::duration::options::FractionalDigits, Enum, hidden)]
+        #[diplomat::rust_link(
+            icu::experimental::duration::ValidatedDurationFormatterOptions,
+            Struct,
+            hidden
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::duration::ValidatedDurationFormatterOptions::validate,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::attr(supports = fallible_constructors, constructor)]
+        #[cfg(feature = "compiled_data")]
+        pub fn create(
+            locale: &Locale,
+            style: DurationFormatterStyle,
+            fractional_digits: Option<u8>,
+        ) -> Result<Box<DurationFormatter>, DurationFormatterLoadError> {
+            let prefs = DurationFormatterPreferences::from(&locale.0);
+            Ok(Box::new(DurationFormatter(
+                icu_experimental::duration::DurationFormatter::try_new(
+                    prefs,
+                    super::options(style, fractional_digits)?,
+                )?,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`DurationFormatter`] using a particular data source.
+        ///
+        /// `fractional_digits` is the number of fractional digits (0 to 9) used for sub-second
+        /// units in the digital style; if absent, as many digits as necessary are shown.
+        #[diplomat::rust_link(icu::experimental::duration::DurationFormatter::try_new, FnInStruct)]
+        #[diplomat::attr(all(supports = fallible_constructors, supports = named_constructors), named_constructor = "with_provider")]
+        #[cfg(feature = "buffer_provider")]
+        pub fn create_with_provider(
+            provider: &DataProvider,
+            locale: &Locale,
+            style: DurationFormatterStyle,
+            fractional_digits: Option<u8>,
+        ) -> Result<Box<DurationFormatter>, DurationFormatterLoadError> {
+            let prefs = DurationFormatterPreferences::from(&locale.0);
+            Ok(Box::new(DurationFormatter(
+                icu_experimental::duration::DurationFormatter::try_new_with_buffer_provider(
+                    provider.get()?,
+                    prefs,
+                    super::options(style, fractional_digits)?,
+                )?,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Formats a [`Duration`] to a string.
+        #[diplomat::rust_link(icu::experimental::duration::DurationFormatter::format, FnInStruct)]
+        // Experimental, do not generate in demo:
+        #[diplomat::attr(demo_gen, disable)]
+        pub fn format(&self, duration: Duration, write: &mut DiplomatWrite) {
+            let duration = icu_experimental::duration::Duration::from(duration);
+            let _infallible = self.0.format(&duration).write_to(write);
+        }
+    }
+}
+
+impl From<ffi::Duration> for icu_experimental::duration::Duration {
+    fn from(other: ffi::Duration) -> Self {
+        Self {
+            sign: other.sign.into(),
+            years: other.years,
+            months: other.months,
+            weeks: other.weeks,
+            days: other.days,
+            hours: other.hours,
+            minutes: other.minutes,
+            seconds: other.seconds,
+            milliseconds: other.milliseconds,
+            microseconds: other.microseconds,
+            nanoseconds: other.nanoseconds,
+        }
+    }
+}
+
+#[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+fn options(
+    style: ffi::DurationFormatterStyle,
+    fractional_digits: Option<u8>,
+) -> Result<
+    icu_experimental::duration::ValidatedDurationFormatterOptions,
+    icu_experimental::duration::DurationFormatterOptionsError,
+> {
+    use icu_experimental::duration::options::{DurationFormatterOptions, FractionalDigits};
+
+    let mut options = DurationFormatterOptions::default();
+    options.base = style.into();
+    options.fractional_digits = match fractional_digits {
+        Some(digits) => FractionalDigits::Fixed(digits),
+        None => FractionalDigits::ShowAll,
+    };
+    icu_experimental::duration::ValidatedDurationFormatterOptions::validate(options)
+}
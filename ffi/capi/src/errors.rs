@@ -139,6 +139,67 @@ pub mod ffi {
         /// Unused
         MissingTimeZoneVariant = 0x01,
     }
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    #[cfg(feature = "experimental")]
+    #[derive(Debug, PartialEq, Eq)]
+    #[repr(C)]
+    #[diplomat::rust_link(
+        icu::experimental::duration::DurationFormatterOptionsError,
+        Enum,
+        compact
+    )]
+    #[diplomat::rust_link(icu_provider::DataError, Struct, compact)]
+    #[diplomat::rust_link(icu_provider::DataErrorKind, Enum, compact)]
+    #[non_exhaustive]
+    pub enum DurationFormatterLoadError {
+        Unknown = 0x00,
+
+        DisplayAlwaysFractional = 0x8_01,
+        PreviousFractional = 0x8_02,
+        PreviousNumeric = 0x8_03,
+        FractionalDigitsOutOfRange = 0x8_04,
+
+        DataMarkerNotFound = 0x01,
+        DataIdentifierNotFound = 0x02,
+        DataInvalidRequest = 0x03,
+        DataInconsistentData = 0x04,
+        DataDowncast = 0x05,
+        DataDeserialize = 0x06,
+        DataCustom = 0x07,
+        DataIo = 0x08,
+    }
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    #[cfg(feature = "experimental")]
+    #[derive(Debug, PartialEq, Eq)]
+    #[repr(C)]
+    #[diplomat::rust_link(
+        icu::experimental::personnames::api::PersonNamesFormatterError,
+        Enum,
+        compact
+    )]
+    #[diplomat::rust_link(icu_provider::DataError, Struct, compact)]
+    #[diplomat::rust_link(icu_provider::DataErrorKind, Enum, compact)]
+    #[non_exhaustive]
+    pub enum PersonNamesFormatterError {
+        Unknown = 0x00,
+
+        Parse = 0x8_01,
+        InvalidPersonName = 0x8_02,
+        InvalidLocale = 0x8_03,
+        InvalidCldrData = 0x8_04,
+        Pattern = 0x8_05,
+
+        DataMarkerNotFound = 0x01,
+        DataIdentifierNotFound = 0x02,
+        DataInvalidRequest = 0x03,
+        DataInconsistentData = 0x04,
+        DataDowncast = 0x05,
+        DataDeserialize = 0x06,
+        DataCustom = 0x07,
+        DataIo = 0x08,
+    }
 }
 
 impl From<icu_provider::DataError> for DataError {
@@ -317,9 +378,93 @@ impl From<icu_locale_core::ParseError> for LocaleParseError {
     }
 }
 
+impl From<icu_locale_core::priority_list::PriorityListParseError> for LocaleParseError {
+    fn from(e: icu_locale_core::priority_list::PriorityListParseError) -> Self {
+        match e {
+            icu_locale_core::priority_list::PriorityListParseError::Language(e) => e.into(),
+            _ => Self::Unknown,
+        }
+    }
+}
+
 #[cfg(any(feature = "timezone", feature = "datetime"))]
 impl From<icu_time::zone::InvalidOffsetError> for TimeZoneInvalidOffsetError {
     fn from(_: icu_time::zone::InvalidOffsetError) -> Self {
         Self
     }
 }
+
+#[cfg(feature = "experimental")]
+impl From<icu_experimental::duration::DurationFormatterOptionsError>
+    for DurationFormatterLoadError
+{
+    fn from(e: icu_experimental::duration::DurationFormatterOptionsError) -> Self {
+        use icu_experimental::duration::DurationFormatterOptionsError as E;
+        match e {
+            E::DisplayAlwaysFractional => Self::DisplayAlwaysFractional,
+            E::PreviousFractional => Self::PreviousFractional,
+            E::PreviousNumeric => Self::PreviousNumeric,
+            E::FractionalDigitsOutOfRange => Self::FractionalDigitsOutOfRange,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl From<icu_provider::DataError> for DurationFormatterLoadError {
+    fn from(e: icu_provider::DataError) -> Self {
+        match e.kind {
+            icu_provider::DataErrorKind::MarkerNotFound => Self::DataMarkerNotFound,
+            icu_provider::DataErrorKind::IdentifierNotFound => Self::DataIdentifierNotFound,
+            icu_provider::DataErrorKind::InvalidRequest => Self::DataInvalidRequest,
+            icu_provider::DataErrorKind::InconsistentData(..) => Self::DataInconsistentData,
+            icu_provider::DataErrorKind::Downcast(..) => Self::DataDowncast,
+            icu_provider::DataErrorKind::Deserialize => Self::DataDeserialize,
+            icu_provider::DataErrorKind::Custom => Self::DataCustom,
+            #[cfg(all(
+                feature = "provider_fs",
+                not(any(target_arch = "wasm32", target_os = "none"))
+            ))]
+            icu_provider::DataErrorKind::Io(..) => Self::DataIo,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl From<icu_experimental::personnames::api::PersonNamesFormatterError>
+    for PersonNamesFormatterError
+{
+    fn from(e: icu_experimental::personnames::api::PersonNamesFormatterError) -> Self {
+        use icu_experimental::personnames::api::PersonNamesFormatterError as E;
+        match e {
+            E::ParseError(_) => Self::Parse,
+            E::InvalidPersonName => Self::InvalidPersonName,
+            E::InvalidLocale => Self::InvalidLocale,
+            E::InvalidCldrData => Self::InvalidCldrData,
+            E::Pattern(_) => Self::Pattern,
+            E::Data(data_error) => data_error.into(),
+        }
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl From<icu_provider::DataError> for PersonNamesFormatterError {
+    fn from(e: icu_provider::DataError) -> Self {
+        match e.kind {
+            icu_provider::DataErrorKind::MarkerNotFound => Self::DataMarkerNotFound,
+            icu_provider::DataErrorKind::IdentifierNotFound => Self::DataIdentifierNotFound,
+            icu_provider::DataErrorKind::InvalidRequest => Self::DataInvalidRequest,
+            icu_provider::DataErrorKind::InconsistentData(..) => Self::DataInconsistentData,
+            icu_provider::DataErrorKind::Downcast(..) => Self::DataDowncast,
+            icu_provider::DataErrorKind::Deserialize => Self::DataDeserialize,
+            icu_provider::DataErrorKind::Custom => Self::DataCustom,
+            #[cfg(all(
+                feature = "provider_fs",
+                not(any(target_arch = "wasm32", target_os = "none"))
+            ))]
+            icu_provider::DataErrorKind::Io(..) => Self::DataIo,
+            _ => Self::Unknown,
+        }
+    }
+}
@@ -72,6 +72,7 @@ pub mod unstable {
     // Common modules
     pub mod errors;
     pub mod locale_core;
+    pub mod locale_priority_list;
     #[cfg(feature = "logging")]
     pub mod logging;
     #[macro_use]
@@ -89,6 +90,10 @@ pub mod unstable {
     pub mod collator;
     #[cfg(feature = "properties")]
     pub mod collections_sets;
+    #[cfg(feature = "experimental")]
+    pub mod compactdecimal;
+    #[cfg(feature = "experimental")]
+    pub mod currency;
     #[cfg(any(feature = "datetime", feature = "timezone", feature = "calendar"))]
     pub mod date;
     #[cfg(feature = "datetime")]
@@ -103,6 +108,8 @@ pub mod unstable {
     pub mod decimal;
     #[cfg(feature = "experimental")]
     pub mod displaynames;
+    #[cfg(feature = "experimental")]
+    pub mod duration;
     #[cfg(feature = "locale")]
     pub mod exemplar_chars;
     #[cfg(feature = "locale")]
@@ -121,6 +128,8 @@ pub mod unstable {
     pub mod normalizer;
     #[cfg(feature = "normalizer")]
     pub mod normalizer_properties;
+    #[cfg(feature = "experimental")]
+    pub mod personnames;
     #[cfg(feature = "plurals")]
     pub mod pluralrules;
     #[cfg(feature = "properties")]
@@ -137,6 +146,8 @@ pub mod unstable {
     pub mod properties_sets;
     #[cfg(feature = "properties")]
     pub mod properties_unisets;
+    #[cfg(feature = "experimental")]
+    pub mod relativetime;
     #[cfg(feature = "properties")]
     pub mod script;
     #[cfg(feature = "segmenter")]
@@ -155,6 +166,12 @@ pub mod unstable {
     pub mod timezone;
     #[cfg(feature = "datetime")]
     pub mod timezone_formatter;
+    #[cfg(feature = "experimental")]
+    pub mod transliterate;
+    #[cfg(feature = "experimental")]
+    pub mod units;
+    #[cfg(feature = "experimental")]
+    pub mod units_converter;
     #[cfg(any(feature = "datetime", feature = "timezone"))]
     pub mod variant_offset;
     #[cfg(feature = "calendar")]
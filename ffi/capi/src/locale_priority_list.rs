@@ -0,0 +1,170 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#[diplomat::bridge]
+#[diplomat::abi_rename = "icu4x_{0}_mv1"]
+#[diplomat::attr(auto, namespace = "icu4x")]
+pub mod ffi {
+    use alloc::boxed::Box;
+    use icu_locale_core::priority_list::LanguageRange;
+
+    use crate::unstable::errors::ffi::LocaleParseError;
+    use crate::unstable::locale_core::ffi::Locale;
+
+    #[diplomat::opaque]
+    /// A list of locales ordered by preference, such as one parsed from an
+    /// HTTP `Accept-Language` header or the `LANGUAGE` environment variable.
+    #[diplomat::rust_link(icu::locale::priority_list::LanguagePriorityList, Struct)]
+    #[diplomat::rust_link(icu::locale::priority_list::WeightedLanguageRange, Struct, hidden)]
+    #[diplomat::rust_link(icu::locale::priority_list::LanguageRange, Enum, hidden)]
+    #[diplomat::rust_link(
+        icu::locale::priority_list::LanguagePriorityList::into_iter,
+        FnInStruct,
+        hidden
+    )]
+    pub struct LocalePriorityList(
+        pub icu_locale_core::priority_list::LanguagePriorityList<icu_locale_core::Locale>,
+    );
+
+    impl LocalePriorityList {
+        /// Parses an HTTP `Accept-Language` header value, such as `"fr-CH, fr;q=0.9, *;q=0.5"`.
+        #[diplomat::rust_link(
+            icu::locale::priority_list::LanguagePriorityList::try_from_accept_language,
+            FnInStruct
+        )]
+        #[diplomat::rust_link(
+            icu::locale::priority_list::LanguagePriorityList::try_from_accept_language_utf8,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::rust_link(icu::locale::priority_list::PriorityListParseError, Enum, hidden)]
+        #[diplomat::attr(all(supports = fallible_constructors, supports = named_constructors), named_constructor)]
+        pub fn from_accept_language(
+            s: &DiplomatStr,
+        ) -> Result<Box<LocalePriorityList>, LocaleParseError> {
+            Ok(Box::new(LocalePriorityList(
+                icu_locale_core::priority_list::LanguagePriorityList::try_from_accept_language_utf8(s)?,
+            )))
+        }
+
+        /// Parses an HTTP `Accept-Language` header value, skipping or repairing malformed entries.
+        #[diplomat::rust_link(
+            icu::locale::priority_list::LanguagePriorityList::from_accept_language_lossy,
+            FnInStruct
+        )]
+        #[diplomat::rust_link(
+            icu::locale::priority_list::LanguagePriorityList::from_accept_language_lossy_utf8,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::attr(supports = named_constructors, named_constructor)]
+        pub fn from_accept_language_lossy(s: &DiplomatStr) -> Box<LocalePriorityList> {
+            Box::new(LocalePriorityList(
+                icu_locale_core::priority_list::LanguagePriorityList::from_accept_language_lossy_utf8(s),
+            ))
+        }
+
+        /// Parses a colon-separated list of locales, such as the `LANGUAGE` environment variable.
+        #[diplomat::rust_link(
+            icu::locale::priority_list::LanguagePriorityList::try_from_colon_separated,
+            FnInStruct
+        )]
+        #[diplomat::rust_link(
+            icu::locale::priority_list::LanguagePriorityList::try_from_colon_separated_utf8,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::attr(all(supports = fallible_constructors, supports = named_constructors), named_constructor)]
+        pub fn from_colon_separated(
+            s: &DiplomatStr,
+        ) -> Result<Box<LocalePriorityList>, LocaleParseError> {
+            Ok(Box::new(LocalePriorityList(
+                icu_locale_core::priority_list::LanguagePriorityList::try_from_colon_separated_utf8(s)?,
+            )))
+        }
+
+        /// Parses a colon-separated list of locales, skipping or repairing malformed entries,
+        /// such as POSIX locale names like `de_DE.UTF-8`.
+        #[diplomat::rust_link(
+            icu::locale::priority_list::LanguagePriorityList::from_colon_separated_lossy,
+            FnInStruct
+        )]
+        #[diplomat::rust_link(
+            icu::locale::priority_list::LanguagePriorityList::from_colon_separated_lossy_utf8,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::attr(supports = named_constructors, named_constructor)]
+        pub fn from_colon_separated_lossy(s: &DiplomatStr) -> Box<LocalePriorityList> {
+            Box::new(LocalePriorityList(
+                icu_locale_core::priority_list::LanguagePriorityList::from_colon_separated_lossy_utf8(s),
+            ))
+        }
+
+        /// The number of entries in this list, including wildcards.
+        #[diplomat::rust_link(icu::locale::priority_list::LanguagePriorityList::len, FnInStruct)]
+        #[diplomat::rust_link(
+            icu::locale::priority_list::LanguagePriorityList::is_empty,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::attr(auto, getter)]
+        pub fn length(&self) -> usize {
+            self.0.len()
+        }
+
+        /// Returns the locale of the entry at the given index.
+        ///
+        /// Returns `None` if the index is out of bounds or the entry is the wildcard `*`.
+        #[diplomat::rust_link(icu::locale::priority_list::LanguagePriorityList::iter, FnInStruct)]
+        #[diplomat::rust_link(
+            icu::locale::priority_list::LanguagePriorityList::languages,
+            FnInStruct,
+            hidden
+        )]
+        pub fn locale_at(&self, index: usize) -> Option<Box<Locale>> {
+            match &self.0.iter().nth(index)?.range {
+                LanguageRange::Language(locale) => Some(Box::new(Locale(locale.clone()))),
+                _ => None,
+            }
+        }
+
+        /// Returns whether the entry at the given index is the wildcard `*`.
+        #[diplomat::rust_link(icu::locale::priority_list::LanguagePriorityList::iter, FnInStruct)]
+        pub fn is_wildcard_at(&self, index: usize) -> bool {
+            matches!(
+                self.0.iter().nth(index).map(|e| &e.range),
+                Some(LanguageRange::Wildcard)
+            )
+        }
+
+        /// Returns the weight of the entry at the given index, in thousandths between 0 and 1000.
+        ///
+        /// Returns `None` if the index is out of bounds.
+        #[diplomat::rust_link(icu::locale::priority_list::Weight, Struct)]
+        #[diplomat::rust_link(
+            icu::locale::priority_list::Weight::to_thousandths,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::rust_link(
+            icu::locale::priority_list::Weight::try_from_thousandths,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::rust_link(
+            icu::locale::priority_list::Weight::MAX,
+            AssociatedConstantInStruct,
+            hidden
+        )]
+        #[diplomat::rust_link(
+            icu::locale::priority_list::Weight::ZERO,
+            AssociatedConstantInStruct,
+            hidden
+        )]
+        pub fn weight_at(&self, index: usize) -> Option<u16> {
+            self.0.iter().nth(index).map(|e| e.weight.to_thousandths())
+        }
+    }
+}
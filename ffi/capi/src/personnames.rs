@@ -0,0 +1,350 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#[diplomat::bridge]
+#[diplomat::abi_rename = "icu4x_{0}_mv1"]
+#[diplomat::attr(auto, namespace = "icu4x")]
+pub mod ffi {
+    use alloc::boxed::Box;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use crate::unstable::errors::ffi::PersonNamesFormatterError;
+    use crate::unstable::locale_core::ffi::Locale;
+    #[cfg(feature = "buffer_provider")]
+    use crate::unstable::provider::ffi::DataProvider;
+    use icu_experimental::personnames::api::{FieldModifierSet, NameField};
+
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use core::fmt::Write;
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    #[diplomat::rust_link(icu::experimental::personnames::api::NameFieldKind, Enum)]
+    #[diplomat::enum_convert(icu_experimental::personnames::api::NameFieldKind)]
+    #[non_exhaustive]
+    pub enum PersonNameFieldKind {
+        Title,
+        Given,
+        Given2,
+        Surname,
+        Surname2,
+        Generation,
+        Credentials,
+    }
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    #[diplomat::rust_link(icu::experimental::personnames::api::PreferredOrder, Enum)]
+    #[diplomat::enum_convert(icu_experimental::personnames::api::PreferredOrder)]
+    #[non_exhaustive]
+    pub enum PersonNamePreferredOrder {
+        #[diplomat::attr(auto, default)]
+        Default,
+        GivenFirst,
+        SurnameFirst,
+    }
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    #[diplomat::rust_link(icu::experimental::personnames::api::FormattingOrder, Enum)]
+    #[diplomat::enum_convert(icu_experimental::personnames::api::FormattingOrder)]
+    #[non_exhaustive]
+    pub enum PersonNamesFormatterOrder {
+        #[diplomat::attr(auto, default)]
+        GivenFirst,
+        SurnameFirst,
+        Sorting,
+    }
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    #[diplomat::rust_link(icu::experimental::personnames::api::FormattingLength, Enum)]
+    #[diplomat::enum_convert(icu_experimental::personnames::api::FormattingLength)]
+    #[non_exhaustive]
+    pub enum PersonNamesFormatterLength {
+        Short,
+        #[diplomat::attr(auto, default)]
+        Medium,
+        Long,
+    }
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    #[diplomat::rust_link(icu::experimental::personnames::api::FormattingUsage, Enum)]
+    #[diplomat::enum_convert(icu_experimental::personnames::api::FormattingUsage)]
+    #[non_exhaustive]
+    pub enum PersonNamesFormatterUsage {
+        Addressing,
+        #[diplomat::attr(auto, default)]
+        Referring,
+        Monogram,
+    }
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    #[diplomat::rust_link(icu::experimental::personnames::api::FormattingFormality, Enum)]
+    #[diplomat::enum_convert(icu_experimental::personnames::api::FormattingFormality)]
+    #[non_exhaustive]
+    pub enum PersonNamesFormatterFormality {
+        #[diplomat::attr(auto, default)]
+        Formal,
+        Informal,
+    }
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    ///
+    /// A person name to be formatted with a [`PersonNamesFormatter`], built up field by field.
+    #[diplomat::opaque]
+    #[diplomat::rust_link(
+        icu::experimental::personnames::provided_struct::DefaultPersonName,
+        Struct
+    )]
+    #[diplomat::rust_link(icu::experimental::personnames::api::PersonName, Trait, hidden)]
+    #[diplomat::rust_link(icu::experimental::personnames::api::NameField, Struct, hidden)]
+    pub struct PersonName {
+        pub(crate) fields: Vec<(NameField, String)>,
+        pub(crate) locale: Option<icu_locale_core::Locale>,
+        pub(crate) preferred_order: Option<icu_experimental::personnames::api::PreferredOrder>,
+    }
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    ///
+    /// An ICU4X Person Names Formatter object, capable of formatting a [`PersonName`]
+    /// according to the conventions of a locale, such as "Wells, H. G.".
+    #[diplomat::opaque]
+    #[diplomat::rust_link(icu::experimental::personnames::PersonNamesFormatter, Struct)]
+    #[diplomat::rust_link(
+        icu::experimental::personnames::formatter::PersonNamesFormatter,
+        Struct,
+        hidden
+    )]
+    pub struct PersonNamesFormatter(pub icu_experimental::personnames::PersonNamesFormatter);
+
+    impl PersonName {
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates an empty [`PersonName`].
+        #[diplomat::attr(auto, constructor)]
+        pub fn create() -> Box<PersonName> {
+            Box::new(PersonName {
+                fields: Vec::new(),
+                locale: None,
+                preferred_order: None,
+            })
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Sets the value of a name field, replacing any previous value.
+        ///
+        /// Ill-formed input is treated as if errors had been replaced with REPLACEMENT CHARACTERs according
+        /// to the WHATWG Encoding Standard.
+        #[diplomat::rust_link(
+            icu::experimental::personnames::api::PersonName::get,
+            FnInTrait,
+            hidden
+        )]
+        pub fn set_field(&mut self, kind: PersonNameFieldKind, value: &DiplomatStr) {
+            let field = NameField {
+                kind: kind.into(),
+                modifier: FieldModifierSet::default(),
+            };
+            let value = String::from_utf8_lossy(value).into_owned();
+            match self.fields.iter_mut().find(|(f, _)| *f == field) {
+                Some((_, v)) => *v = value,
+                None => self.fields.push((field, value)),
+            }
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Sets the locale of the name itself, which may differ from the formatting locale.
+        #[diplomat::rust_link(
+            icu::experimental::personnames::api::PersonName::name_locale,
+            FnInTrait,
+            hidden
+        )]
+        pub fn set_locale(&mut self, locale: &Locale) {
+            self.locale = Some(locale.0.clone());
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Sets the order in which the name prefers its fields to be displayed.
+        #[diplomat::rust_link(
+            icu::experimental::personnames::api::PersonName::preferred_order,
+            FnInTrait,
+            hidden
+        )]
+        pub fn set_preferred_order(&mut self, order: PersonNamePreferredOrder) {
+            self.preferred_order = Some(order.into());
+        }
+    }
+
+    impl PersonNamesFormatter {
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`PersonNamesFormatter`] using compiled data.
+        #[diplomat::rust_link(
+            icu::experimental::personnames::PersonNamesFormatter::try_new_unstable,
+            FnInStruct
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::personnames::api::PersonNamesFormatterOptions,
+            Struct,
+            hidden
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::personnames::api::PersonNamesFormatterOptions::new,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::attr(supports = fallible_constructors, constructor)]
+        #[cfg(feature = "compiled_data")]
+        pub fn create(
+            locale: &Locale,
+            order: PersonNamesFormatterOrder,
+            length: PersonNamesFormatterLength,
+            usage: PersonNamesFormatterUsage,
+            formality: PersonNamesFormatterFormality,
+        ) -> Result<Box<PersonNamesFormatter>, PersonNamesFormatterError> {
+            let options = icu_experimental::personnames::api::PersonNamesFormatterOptions::new(
+                locale.0.clone(),
+                order.into(),
+                length.into(),
+                usage.into(),
+                formality.into(),
+            );
+            Ok(Box::new(PersonNamesFormatter(
+                icu_experimental::personnames::PersonNamesFormatter::try_new_unstable(
+                    &super::CompiledProvider,
+                    options,
+                )?,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`PersonNamesFormatter`] using a particular data source.
+        ///
+        /// Names must then be formatted with [`Self::format_with_provider`], using the same data source.
+        #[diplomat::rust_link(
+            icu::experimental::personnames::PersonNamesFormatter::try_new_unstable,
+            FnInStruct
+        )]
+        #[diplomat::attr(all(supports = fallible_constructors, supports = named_constructors), named_constructor = "with_provider")]
+        #[cfg(feature = "buffer_provider")]
+        pub fn create_with_provider(
+            provider: &DataProvider,
+            locale: &Locale,
+            order: PersonNamesFormatterOrder,
+            length: PersonNamesFormatterLength,
+            usage: PersonNamesFormatterUsage,
+            formality: PersonNamesFormatterFormality,
+        ) -> Result<Box<PersonNamesFormatter>, PersonNamesFormatterError> {
+            let provider = provider.get_unstable()?;
+            let mut target_locale = locale.0.clone();
+            icu_locale::LocaleExpander::try_new_extended_unstable(&provider)?
+                .maximize(&mut target_locale.id);
+            let options = icu_experimental::personnames::api::PersonNamesFormatterOptions {
+                target_locale,
+                order: order.into(),
+                length: length.into(),
+                usage: usage.into(),
+                formality: formality.into(),
+            };
+            Ok(Box::new(PersonNamesFormatter(
+                icu_experimental::personnames::PersonNamesFormatter::try_new_unstable(
+                    &provider, options,
+                )?,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Formats a [`PersonName`] to a string, using compiled data.
+        #[diplomat::rust_link(
+            icu::experimental::personnames::PersonNamesFormatter::format_to_string,
+            FnInStruct
+        )]
+        #[cfg(feature = "compiled_data")]
+        // Experimental, do not generate in demo:
+        #[diplomat::attr(demo_gen, disable)]
+        pub fn format(
+            &self,
+            name: &PersonName,
+            write: &mut DiplomatWrite,
+        ) -> Result<(), PersonNamesFormatterError> {
+            let formatted = self
+                .0
+                .format_to_string(&icu_experimental::provider::Baked, &name.to_default()?)?;
+            let _infallible = write.write_str(&formatted);
+            Ok(())
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Formats a [`PersonName`] to a string, using a particular data source.
+        #[diplomat::rust_link(
+            icu::experimental::personnames::PersonNamesFormatter::format_to_string,
+            FnInStruct
+        )]
+        #[cfg(feature = "buffer_provider")]
+        // Experimental, do not generate in demo:
+        #[diplomat::attr(demo_gen, disable)]
+        pub fn format_with_provider(
+            &self,
+            provider: &DataProvider,
+            name: &PersonName,
+            write: &mut DiplomatWrite,
+        ) -> Result<(), PersonNamesFormatterError> {
+            let formatted = self
+                .0
+                .format_to_string(&provider.get_unstable()?, &name.to_default()?)?;
+            let _infallible = write.write_str(&formatted);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+impl ffi::PersonName {
+    fn to_default(
+        &self,
+    ) -> Result<
+        icu_experimental::personnames::provided_struct::DefaultPersonName,
+        icu_experimental::personnames::api::PersonNamesFormatterError,
+    > {
+        icu_experimental::personnames::provided_struct::DefaultPersonName::new(
+            self.fields.iter().cloned().collect(),
+            self.locale.clone(),
+            self.preferred_order,
+        )
+    }
+}
+
+/// A provider for the compiled data needed by [`PersonNamesFormatter`](icu_experimental::personnames::PersonNamesFormatter),
+/// which is spread across several components.
+#[cfg(feature = "compiled_data")]
+struct CompiledProvider;
+
+#[cfg(feature = "compiled_data")]
+macro_rules! impl_compiled_provider {
+    ($($baked:path: $marker:path),+ $(,)?) => {
+        $(
+            impl icu_provider::DataProvider<$marker> for CompiledProvider {
+                fn load(
+                    &self,
+                    req: icu_provider::DataRequest,
+                ) -> Result<icu_provider::DataResponse<$marker>, icu_provider::DataError> {
+                    icu_provider::DataProvider::<$marker>::load(&$baked, req)
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "compiled_data")]
+impl_compiled_provider!(
+    icu_properties::provider::Baked: icu_properties::provider::PropertyScriptWithExtensionsV1,
+    icu_properties::provider::Baked: icu_properties::provider::PropertyNameShortScriptV1,
+    icu_locale::provider::Baked: icu_locale::provider::LocaleLikelySubtagsLanguageV1,
+    icu_locale::provider::Baked: icu_locale::provider::LocaleParentsV1,
+);
@@ -0,0 +1,316 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#[diplomat::bridge]
+#[diplomat::abi_rename = "icu4x_{0}_mv1"]
+#[diplomat::attr(auto, namespace = "icu4x")]
+pub mod ffi {
+    use alloc::boxed::Box;
+
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use crate::unstable::errors::ffi::DataError;
+    use crate::unstable::fixed_decimal::ffi::Decimal;
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use crate::unstable::locale_core::ffi::Locale;
+    #[cfg(feature = "buffer_provider")]
+    use crate::unstable::provider::ffi::DataProvider;
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use icu_experimental::relativetime::{
+        RelativeTimeFormatterOptions, RelativeTimeFormatterPreferences,
+    };
+
+    use writeable::Writeable;
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    ///
+    /// An ICU4X Relative Time Format object, capable of formatting a [`Decimal`] number of
+    /// a fixed time unit relative to now, such as "in 3 days" or "yesterday".
+    #[diplomat::opaque]
+    #[diplomat::rust_link(icu::experimental::relativetime::RelativeTimeFormatter, Struct)]
+    #[diplomat::rust_link(icu::experimental::relativetime::FormattedRelativeTime, Struct, hidden)]
+    pub struct RelativeTimeFormatter(pub icu_experimental::relativetime::RelativeTimeFormatter);
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    ///
+    /// The time unit formatted by a [`RelativeTimeFormatter`].
+    #[non_exhaustive]
+    pub enum RelativeTimeUnit {
+        Second,
+        Minute,
+        Hour,
+        #[diplomat::attr(auto, default)]
+        Day,
+        Week,
+        Month,
+        Quarter,
+        Year,
+    }
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    ///
+    /// The width of the unit names used by a [`RelativeTimeFormatter`].
+    #[non_exhaustive]
+    pub enum RelativeTimeLength {
+        #[diplomat::attr(auto, default)]
+        Long,
+        Short,
+        Narrow,
+    }
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    #[diplomat::rust_link(icu::experimental::relativetime::options::Numeric, Enum)]
+    #[diplomat::enum_convert(icu_experimental::relativetime::options::Numeric, needs_wildcard)]
+    #[non_exhaustive]
+    pub enum RelativeTimeNumeric {
+        #[diplomat::attr(auto, default)]
+        Always,
+        Auto,
+    }
+
+    impl RelativeTimeFormatter {
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`RelativeTimeFormatter`] for the given unit and length, using compiled data.
+        #[diplomat::rust_link(
+            icu::experimental::relativetime::RelativeTimeFormatter::try_new_long_second,
+            FnInStruct
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::relativetime::RelativeTimeFormatter::try_new_long_minute,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::relativetime::RelativeTimeFormatter::try_new_long_hour,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::relativetime::RelativeTimeFormatter::try_new_long_day,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::relativetime::RelativeTimeFormatter::try_new_long_week,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::relativetime::RelativeTimeFormatter::try_new_long_month,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::relativetime::RelativeTimeFormatter::try_new_long_quarter,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::relativetime::RelativeTimeFormatter::try_new_long_year,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::relativetime::RelativeTimeFormatterOptions,
+            Struct,
+            hidden
+        )]
+        #[diplomat::attr(supports = fallible_constructors, constructor)]
+        #[cfg(feature = "compiled_data")]
+        pub fn create(
+            locale: &Locale,
+            unit: RelativeTimeUnit,
+            length: RelativeTimeLength,
+            numeric: RelativeTimeNumeric,
+        ) -> Result<Box<RelativeTimeFormatter>, DataError> {
+            let prefs = RelativeTimeFormatterPreferences::from(&locale.0);
+            let options = RelativeTimeFormatterOptions {
+                numeric: numeric.into(),
+            };
+            Ok(Box::new(RelativeTimeFormatter(
+                super::compiled_constructor(unit, length)(prefs, options)?,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`RelativeTimeFormatter`] for the given unit and length, using a particular data source.
+        #[diplomat::rust_link(
+            icu::experimental::relativetime::RelativeTimeFormatter::try_new_long_second,
+            FnInStruct
+        )]
+        #[diplomat::attr(all(supports = fallible_constructors, supports = named_constructors), named_constructor = "with_provider")]
+        #[cfg(feature = "buffer_provider")]
+        pub fn create_with_provider(
+            provider: &DataProvider,
+            locale: &Locale,
+            unit: RelativeTimeUnit,
+            length: RelativeTimeLength,
+            numeric: RelativeTimeNumeric,
+        ) -> Result<Box<RelativeTimeFormatter>, DataError> {
+            let prefs = RelativeTimeFormatterPreferences::from(&locale.0);
+            let options = RelativeTimeFormatterOptions {
+                numeric: numeric.into(),
+            };
+            Ok(Box::new(RelativeTimeFormatter(super::buffer_constructor(
+                unit, length,
+            )(
+                provider.get()?,
+                prefs,
+                options,
+            )?)))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Formats a [`Decimal`] number of units relative to now.
+        ///
+        /// Negative values are formatted in the past, positive values (and zero) in the future.
+        #[diplomat::rust_link(
+            icu::experimental::relativetime::RelativeTimeFormatter::format,
+            FnInStruct
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::relativetime::FormattedRelativeTime,
+            Struct,
+            hidden
+        )]
+        // Experimental, do not generate in demo:
+        #[diplomat::attr(demo_gen, disable)]
+        pub fn format(&self, value: &Decimal, write: &mut DiplomatWrite) {
+            let _infallible = self.0.format(value.0.clone()).write_to(write);
+        }
+    }
+}
+
+#[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+use ffi::{RelativeTimeLength, RelativeTimeUnit};
+#[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+use icu_experimental::relativetime::{
+    RelativeTimeFormatterOptions, RelativeTimeFormatterPreferences,
+};
+
+#[cfg(feature = "compiled_data")]
+fn compiled_constructor(
+    unit: RelativeTimeUnit,
+    length: RelativeTimeLength,
+) -> fn(
+    RelativeTimeFormatterPreferences,
+    RelativeTimeFormatterOptions,
+) -> Result<icu_experimental::relativetime::RelativeTimeFormatter, icu_provider::DataError> {
+    use icu_experimental::relativetime::RelativeTimeFormatter as F;
+    match (length, unit) {
+        (RelativeTimeLength::Long, RelativeTimeUnit::Second) => F::try_new_long_second,
+        (RelativeTimeLength::Long, RelativeTimeUnit::Minute) => F::try_new_long_minute,
+        (RelativeTimeLength::Long, RelativeTimeUnit::Hour) => F::try_new_long_hour,
+        (RelativeTimeLength::Long, RelativeTimeUnit::Day) => F::try_new_long_day,
+        (RelativeTimeLength::Long, RelativeTimeUnit::Week) => F::try_new_long_week,
+        (RelativeTimeLength::Long, RelativeTimeUnit::Month) => F::try_new_long_month,
+        (RelativeTimeLength::Long, RelativeTimeUnit::Quarter) => F::try_new_long_quarter,
+        (RelativeTimeLength::Long, RelativeTimeUnit::Year) => F::try_new_long_year,
+        (RelativeTimeLength::Short, RelativeTimeUnit::Second) => F::try_new_short_second,
+        (RelativeTimeLength::Short, RelativeTimeUnit::Minute) => F::try_new_short_minute,
+        (RelativeTimeLength::Short, RelativeTimeUnit::Hour) => F::try_new_short_hour,
+        (RelativeTimeLength::Short, RelativeTimeUnit::Day) => F::try_new_short_day,
+        (RelativeTimeLength::Short, RelativeTimeUnit::Week) => F::try_new_short_week,
+        (RelativeTimeLength::Short, RelativeTimeUnit::Month) => F::try_new_short_month,
+        (RelativeTimeLength::Short, RelativeTimeUnit::Quarter) => F::try_new_short_quarter,
+        (RelativeTimeLength::Short, RelativeTimeUnit::Year) => F::try_new_short_year,
+        (RelativeTimeLength::Narrow, RelativeTimeUnit::Second) => F::try_new_narrow_second,
+        (RelativeTimeLength::Narrow, RelativeTimeUnit::Minute) => F::try_new_narrow_minute,
+        (RelativeTimeLength::Narrow, RelativeTimeUnit::Hour) => F::try_new_narrow_hour,
+        (RelativeTimeLength::Narrow, RelativeTimeUnit::Day) => F::try_new_narrow_day,
+        (RelativeTimeLength::Narrow, RelativeTimeUnit::Week) => F::try_new_narrow_week,
+        (RelativeTimeLength::Narrow, RelativeTimeUnit::Month) => F::try_new_narrow_month,
+        (RelativeTimeLength::Narrow, RelativeTimeUnit::Quarter) => F::try_new_narrow_quarter,
+        (RelativeTimeLength::Narrow, RelativeTimeUnit::Year) => F::try_new_narrow_year,
+    }
+}
+
+#[cfg(feature = "buffer_provider")]
+#[expect(clippy::type_complexity)]
+fn buffer_constructor(
+    unit: RelativeTimeUnit,
+    length: RelativeTimeLength,
+) -> fn(
+    &(dyn icu_provider::buf::BufferProvider + 'static),
+    RelativeTimeFormatterPreferences,
+    RelativeTimeFormatterOptions,
+) -> Result<icu_experimental::relativetime::RelativeTimeFormatter, icu_provider::DataError> {
+    use icu_experimental::relativetime::RelativeTimeFormatter as F;
+    match (length, unit) {
+        (RelativeTimeLength::Long, RelativeTimeUnit::Second) => {
+            F::try_new_long_second_with_buffer_provider
+        }
+        (RelativeTimeLength::Long, RelativeTimeUnit::Minute) => {
+            F::try_new_long_minute_with_buffer_provider
+        }
+        (RelativeTimeLength::Long, RelativeTimeUnit::Hour) => {
+            F::try_new_long_hour_with_buffer_provider
+        }
+        (RelativeTimeLength::Long, RelativeTimeUnit::Day) => {
+            F::try_new_long_day_with_buffer_provider
+        }
+        (RelativeTimeLength::Long, RelativeTimeUnit::Week) => {
+            F::try_new_long_week_with_buffer_provider
+        }
+        (RelativeTimeLength::Long, RelativeTimeUnit::Month) => {
+            F::try_new_long_month_with_buffer_provider
+        }
+        (RelativeTimeLength::Long, RelativeTimeUnit::Quarter) => {
+            F::try_new_long_quarter_with_buffer_provider
+        }
+        (RelativeTimeLength::Long, RelativeTimeUnit::Year) => {
+            F::try_new_long_year_with_buffer_provider
+        }
+        (RelativeTimeLength::Short, RelativeTimeUnit::Second) => {
+            F::try_new_short_second_with_buffer_provider
+        }
+        (RelativeTimeLength::Short, RelativeTimeUnit::Minute) => {
+            F::try_new_short_minute_with_buffer_provider
+        }
+        (RelativeTimeLength::Short, RelativeTimeUnit::Hour) => {
+            F::try_new_short_hour_with_buffer_provider
+        }
+        (RelativeTimeLength::Short, RelativeTimeUnit::Day) => {
+            F::try_new_short_day_with_buffer_provider
+        }
+        (RelativeTimeLength::Short, RelativeTimeUnit::Week) => {
+            F::try_new_short_week_with_buffer_provider
+        }
+        (RelativeTimeLength::Short, RelativeTimeUnit::Month) => {
+            F::try_new_short_month_with_buffer_provider
+        }
+        (RelativeTimeLength::Short, RelativeTimeUnit::Quarter) => {
+            F::try_new_short_quarter_with_buffer_provider
+        }
+        (RelativeTimeLength::Short, RelativeTimeUnit::Year) => {
+            F::try_new_short_year_with_buffer_provider
+        }
+        (RelativeTimeLength::Narrow, RelativeTimeUnit::Second) => {
+            F::try_new_narrow_second_with_buffer_provider
+        }
+        (RelativeTimeLength::Narrow, RelativeTimeUnit::Minute) => {
+            F::try_new_narrow_minute_with_buffer_provider
+        }
+        (RelativeTimeLength::Narrow, RelativeTimeUnit::Hour) => {
+            F::try_new_narrow_hour_with_buffer_provider
+        }
+        (RelativeTimeLength::Narrow, RelativeTimeUnit::Day) => {
+            F::try_new_narrow_day_with_buffer_provider
+        }
+        (RelativeTimeLength::Narrow, RelativeTimeUnit::Week) => {
+            F::try_new_narrow_week_with_buffer_provider
+        }
+        (RelativeTimeLength::Narrow, RelativeTimeUnit::Month) => {
+            F::try_new_narrow_month_with_buffer_provider
+        }
+        (RelativeTimeLength::Narrow, RelativeTimeUnit::Quarter) => {
+            F::try_new_narrow_quarter_with_buffer_provider
+        }
+        (RelativeTimeLength::Narrow, RelativeTimeUnit::Year) => {
+            F::try_new_narrow_year_with_buffer_provider
+        }
+    }
+}
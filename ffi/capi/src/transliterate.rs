@@ -0,0 +1,81 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#[diplomat::bridge]
+#[diplomat::abi_rename = "icu4x_{0}_mv1"]
+#[diplomat::attr(auto, namespace = "icu4x")]
+pub mod ffi {
+    use alloc::boxed::Box;
+    use core::fmt::Write;
+
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use crate::unstable::errors::ffi::DataError;
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use crate::unstable::locale_core::ffi::Locale;
+    #[cfg(feature = "buffer_provider")]
+    use crate::unstable::provider::ffi::DataProvider;
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    ///
+    /// An ICU4X Transliterator object, capable of transliterating text from one script or
+    /// form to another, as identified by a BCP-47 `-t-` locale such as `und-Arab-t-und-beng`.
+    #[diplomat::opaque]
+    #[diplomat::rust_link(icu::experimental::transliterate::Transliterator, Struct)]
+    pub struct Transliterator(pub icu_experimental::transliterate::Transliterator);
+
+    impl Transliterator {
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`Transliterator`] for the given BCP-47 `-t-` locale, using compiled data.
+        #[diplomat::rust_link(
+            icu::experimental::transliterate::Transliterator::try_new,
+            FnInStruct
+        )]
+        #[diplomat::attr(supports = fallible_constructors, constructor)]
+        #[cfg(feature = "compiled_data")]
+        pub fn create(locale: &Locale) -> Result<Box<Transliterator>, DataError> {
+            Ok(Box::new(Transliterator(
+                icu_experimental::transliterate::Transliterator::try_new(&locale.0)?,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`Transliterator`] for the given BCP-47 `-t-` locale, using a particular data source.
+        #[diplomat::rust_link(
+            icu::experimental::transliterate::Transliterator::try_new,
+            FnInStruct
+        )]
+        #[diplomat::attr(all(supports = fallible_constructors, supports = named_constructors), named_constructor = "with_provider")]
+        #[cfg(feature = "buffer_provider")]
+        pub fn create_with_provider(
+            provider: &DataProvider,
+            locale: &Locale,
+        ) -> Result<Box<Transliterator>, DataError> {
+            Ok(Box::new(Transliterator(
+                icu_experimental::transliterate::Transliterator::try_new_with_buffer_provider(
+                    provider.get()?,
+                    &locale.0,
+                )?,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Transliterates `input`.
+        ///
+        /// Ill-formed input is treated as if errors had been replaced with REPLACEMENT CHARACTERs according
+        /// to the WHATWG Encoding Standard.
+        #[diplomat::rust_link(
+            icu::experimental::transliterate::Transliterator::transliterate,
+            FnInStruct
+        )]
+        // Experimental, do not generate in demo:
+        #[diplomat::attr(demo_gen, disable)]
+        pub fn transliterate(&self, input: &DiplomatStr, write: &mut DiplomatWrite) {
+            let input = alloc::string::String::from_utf8_lossy(input).into_owned();
+            let _infallible = write.write_str(&self.0.transliterate(input));
+        }
+    }
+}
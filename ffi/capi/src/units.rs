@@ -0,0 +1,118 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#[diplomat::bridge]
+#[diplomat::abi_rename = "icu4x_{0}_mv1"]
+#[diplomat::attr(auto, namespace = "icu4x")]
+pub mod ffi {
+    use alloc::boxed::Box;
+
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use crate::unstable::errors::ffi::DataError;
+    use crate::unstable::fixed_decimal::ffi::Decimal;
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use crate::unstable::locale_core::ffi::Locale;
+    #[cfg(feature = "buffer_provider")]
+    use crate::unstable::provider::ffi::DataProvider;
+    #[cfg(any(feature = "compiled_data", feature = "buffer_provider"))]
+    use icu_experimental::dimension::units::formatter::UnitsFormatterPreferences;
+
+    use writeable::Writeable;
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    #[diplomat::rust_link(icu::experimental::dimension::units::options::Width, Enum)]
+    #[diplomat::enum_convert(icu_experimental::dimension::units::options::Width, needs_wildcard)]
+    #[non_exhaustive]
+    pub enum UnitsFormatterWidth {
+        Long,
+        #[diplomat::attr(auto, default)]
+        Short,
+        Narrow,
+    }
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    ///
+    /// An ICU4X Units Formatter object, capable of formatting a [`Decimal`] as a quantity of
+    /// a particular unit, such as "12 meters".
+    #[diplomat::opaque]
+    #[diplomat::rust_link(icu::experimental::dimension::units::formatter::UnitsFormatter, Struct)]
+    #[diplomat::rust_link(
+        icu::experimental::dimension::units::format::FormattedUnit,
+        Struct,
+        hidden
+    )]
+    pub struct UnitsFormatter(pub icu_experimental::dimension::units::formatter::UnitsFormatter);
+
+    impl UnitsFormatter {
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`UnitsFormatter`] for the given CLDR unit identifier (such as `meter`), using compiled data.
+        #[diplomat::rust_link(
+            icu::experimental::dimension::units::formatter::UnitsFormatter::try_new,
+            FnInStruct
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::dimension::units::options::UnitsFormatterOptions,
+            Struct,
+            hidden
+        )]
+        #[diplomat::attr(supports = fallible_constructors, constructor)]
+        #[cfg(feature = "compiled_data")]
+        pub fn create(
+            locale: &Locale,
+            unit: &DiplomatStr,
+            width: UnitsFormatterWidth,
+        ) -> Result<Box<UnitsFormatter>, DataError> {
+            let prefs = UnitsFormatterPreferences::from(&locale.0);
+            let unit = core::str::from_utf8(unit).map_err(|_| DataError::IdentifierNotFound)?;
+            Ok(Box::new(UnitsFormatter(
+                icu_experimental::dimension::units::formatter::UnitsFormatter::try_new(
+                    prefs,
+                    unit,
+                    icu_experimental::dimension::units::options::Width::from(width).into(),
+                )?,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`UnitsFormatter`] for the given CLDR unit identifier (such as `meter`), using a particular data source.
+        #[diplomat::rust_link(
+            icu::experimental::dimension::units::formatter::UnitsFormatter::try_new,
+            FnInStruct
+        )]
+        #[diplomat::attr(all(supports = fallible_constructors, supports = named_constructors), named_constructor = "with_provider")]
+        #[cfg(feature = "buffer_provider")]
+        pub fn create_with_provider(
+            provider: &DataProvider,
+            locale: &Locale,
+            unit: &DiplomatStr,
+            width: UnitsFormatterWidth,
+        ) -> Result<Box<UnitsFormatter>, DataError> {
+            let prefs = UnitsFormatterPreferences::from(&locale.0);
+            let unit = core::str::from_utf8(unit).map_err(|_| DataError::IdentifierNotFound)?;
+            Ok(Box::new(UnitsFormatter(
+                icu_experimental::dimension::units::formatter::UnitsFormatter::try_new_with_buffer_provider(
+                    provider.get()?,
+                    prefs,
+                    unit,
+                    icu_experimental::dimension::units::options::Width::from(width).into(),
+                )?,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Formats a [`Decimal`] as a quantity of this formatter's unit.
+        #[diplomat::rust_link(
+            icu::experimental::dimension::units::formatter::UnitsFormatter::format_fixed_decimal,
+            FnInStruct
+        )]
+        // Experimental, do not generate in demo:
+        #[diplomat::attr(demo_gen, disable)]
+        pub fn format(&self, value: &Decimal, write: &mut DiplomatWrite) {
+            let _infallible = self.0.format_fixed_decimal(&value.0).write_to(write);
+        }
+    }
+}
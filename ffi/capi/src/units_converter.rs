@@ -0,0 +1,131 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#[diplomat::bridge]
+#[diplomat::abi_rename = "icu4x_{0}_mv1"]
+#[diplomat::attr(auto, namespace = "icu4x")]
+pub mod ffi {
+    use alloc::boxed::Box;
+
+    #[cfg(feature = "buffer_provider")]
+    use crate::unstable::errors::ffi::DataError;
+    #[cfg(feature = "buffer_provider")]
+    use crate::unstable::provider::ffi::DataProvider;
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    ///
+    /// A CLDR unit identifier, such as `meter` or `kilometer-per-hour`.
+    #[diplomat::opaque]
+    #[diplomat::rust_link(icu::experimental::measure::measureunit::MeasureUnit, Struct)]
+    pub struct MeasureUnit(pub icu_experimental::measure::measureunit::MeasureUnit);
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    ///
+    /// An ICU4X Units Converter Factory object, capable of creating converters between
+    /// [`MeasureUnit`]s.
+    #[diplomat::opaque]
+    #[diplomat::rust_link(icu::experimental::units::converter_factory::ConverterFactory, Struct)]
+    pub struct UnitsConverterFactory(
+        pub icu_experimental::units::converter_factory::ConverterFactory,
+    );
+
+    /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+    ///
+    /// An ICU4X Units Converter object, capable of converting values from one unit to another.
+    #[diplomat::opaque]
+    #[diplomat::rust_link(icu::experimental::units::converter::UnitsConverter, Struct)]
+    pub struct UnitsConverter(pub icu_experimental::units::converter::UnitsConverter<f64>);
+
+    impl MeasureUnit {
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Parses a CLDR unit identifier, returning `null` if it is not valid.
+        #[diplomat::rust_link(
+            icu::experimental::measure::measureunit::MeasureUnit::try_from_str,
+            FnInStruct
+        )]
+        #[diplomat::rust_link(
+            icu::experimental::measure::measureunit::MeasureUnit::try_from_utf8,
+            FnInStruct,
+            hidden
+        )]
+        #[diplomat::rust_link(icu::experimental::measure::parser::InvalidUnitError, Struct, hidden)]
+        pub fn from_string(unit: &DiplomatStr) -> Option<Box<MeasureUnit>> {
+            icu_experimental::measure::measureunit::MeasureUnit::try_from_utf8(unit)
+                .ok()
+                .map(MeasureUnit)
+                .map(Box::new)
+        }
+    }
+
+    impl UnitsConverterFactory {
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`UnitsConverterFactory`] using compiled data.
+        #[diplomat::rust_link(
+            icu::experimental::units::converter_factory::ConverterFactory::new,
+            FnInStruct
+        )]
+        #[diplomat::attr(auto, constructor)]
+        #[cfg(feature = "compiled_data")]
+        pub fn create() -> Box<UnitsConverterFactory> {
+            Box::new(UnitsConverterFactory(
+                icu_experimental::units::converter_factory::ConverterFactory::new(),
+            ))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a new [`UnitsConverterFactory`] using a particular data source.
+        #[diplomat::rust_link(
+            icu::experimental::units::converter_factory::ConverterFactory::new,
+            FnInStruct
+        )]
+        #[diplomat::attr(all(supports = fallible_constructors, supports = named_constructors), named_constructor = "with_provider")]
+        #[cfg(feature = "buffer_provider")]
+        pub fn create_with_provider(
+            provider: &DataProvider,
+        ) -> Result<Box<UnitsConverterFactory>, DataError> {
+            Ok(Box::new(UnitsConverterFactory(
+                icu_experimental::units::converter_factory::ConverterFactory::try_new_with_buffer_provider(
+                    provider.get()?,
+                )?,
+            )))
+        }
+
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Creates a converter from `input_unit` to `output_unit`, returning `null` if the
+        /// units are not convertible (for example, `meter` and `second`).
+        #[diplomat::rust_link(
+            icu::experimental::units::converter_factory::ConverterFactory::converter,
+            FnInStruct
+        )]
+        pub fn converter(
+            &self,
+            input_unit: &MeasureUnit,
+            output_unit: &MeasureUnit,
+        ) -> Option<Box<UnitsConverter>> {
+            self.0
+                .converter(&input_unit.0, &output_unit.0)
+                .map(UnitsConverter)
+                .map(Box::new)
+        }
+    }
+
+    impl UnitsConverter {
+        /// 🚧 This API is experimental and may experience breaking changes outside major releases.
+        ///
+        /// Converts `value` from the input unit to the output unit.
+        #[diplomat::rust_link(
+            icu::experimental::units::converter::UnitsConverter::convert,
+            FnInStruct
+        )]
+        // Experimental, do not generate in demo:
+        #[diplomat::attr(demo_gen, disable)]
+        pub fn convert(&self, value: f64) -> f64 {
+            self.0.convert(&value)
+        }
+    }
+}
@@ -0,0 +1,195 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::cmp::Ordering;
+use ecma402_traits::collator::{
+    options::{CaseFirst, Numeric, Punctuation, Sensitivity, Usage},
+    Options,
+};
+use ecma402_traits::Locale;
+use icu::collator::options::{AlternateHandling, CaseLevel, CollatorOptions, Strength};
+use icu::collator::preferences::{CollationCaseFirst, CollationNumericOrdering, CollationType};
+use icu::collator::{CollatorBorrowed, CollatorPreferences};
+
+#[derive(Debug)]
+pub struct Collator(CollatorBorrowed<'static>);
+
+impl Collator {
+    /// Compares two strings using the locale and options this collator was created with.
+    ///
+    /// [`ecma402_traits::collator::Collator::compare`] does not take `self`, so it can only
+    /// use the root collation; this is what `Intl.Collator.prototype.compare` does.
+    pub fn compare_strs<P, Q>(&self, first: P, second: Q) -> i8
+    where
+        P: AsRef<str>,
+        Q: AsRef<str>,
+    {
+        to_i8(self.0.compare(first.as_ref(), second.as_ref()))
+    }
+}
+
+fn to_i8(ordering: Ordering) -> i8 {
+    match ordering {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+impl ecma402_traits::collator::Collator for Collator {
+    type Error = icu_provider::DataError;
+
+    fn try_new<L>(locale: L, opts: Options) -> Result<Self, Self::Error>
+    where
+        L: Locale,
+        Self: Sized,
+    {
+        let locale = crate::to_icu4x_locale(&locale);
+
+        let mut prefs = CollatorPreferences::from(&locale);
+        if opts.usage == Usage::Search {
+            prefs.collation_type = Some(CollationType::Search);
+        }
+        if opts.numeric == Numeric::Use {
+            prefs.numeric_ordering = Some(CollationNumericOrdering::True);
+        }
+        // `CaseFirst::False` is the option's default, so it does not override the locale.
+        match opts.case_first {
+            CaseFirst::Upper => prefs.case_first = Some(CollationCaseFirst::Upper),
+            CaseFirst::Lower => prefs.case_first = Some(CollationCaseFirst::Lower),
+            CaseFirst::False => {}
+        }
+
+        let mut options = CollatorOptions::default();
+        match opts.sensitivity {
+            Sensitivity::Base => options.strength = Some(Strength::Primary),
+            Sensitivity::Accent => options.strength = Some(Strength::Secondary),
+            Sensitivity::Case => {
+                options.strength = Some(Strength::Primary);
+                options.case_level = Some(CaseLevel::On);
+            }
+            Sensitivity::Variant => options.strength = Some(Strength::Tertiary),
+        }
+        if opts.punctuation == Punctuation::Ignore {
+            options.alternate_handling = Some(AlternateHandling::Shifted);
+        }
+
+        Ok(Self(CollatorBorrowed::try_new(prefs, options)?))
+    }
+
+    fn compare<P, Q>(first: P, second: Q) -> i8
+    where
+        P: AsRef<str>,
+        Q: AsRef<str>,
+    {
+        #[expect(clippy::unwrap_used)] // the root collation is always included in compiled data
+        let collator = CollatorBorrowed::try_new(Default::default(), Default::default()).unwrap();
+        to_i8(collator.compare(first.as_ref(), second.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::testing::TestLocale;
+    use ecma402_traits::collator;
+    use ecma402_traits::collator::Collator;
+    use icu_provider::DataError;
+
+    #[test]
+    fn compare_per_locale() -> Result<(), DataError> {
+        #[derive(Debug, Clone)]
+        struct TestCase {
+            locale: TestLocale,
+            opts: collator::Options,
+            pairs: &'static [(&'static str, &'static str)],
+            expected: &'static [i8],
+        }
+        let tests = [
+            TestCase {
+                locale: TestLocale("en"),
+                opts: Default::default(),
+                pairs: &[("a", "b"), ("a", "A"), ("a", "á"), ("b", "a"), ("a", "a")],
+                expected: &[-1, -1, -1, 1, 0],
+            },
+            TestCase {
+                locale: TestLocale("en"),
+                opts: collator::Options {
+                    sensitivity: collator::options::Sensitivity::Base,
+                    ..Default::default()
+                },
+                pairs: &[("a", "b"), ("a", "A"), ("a", "á")],
+                expected: &[-1, 0, 0],
+            },
+            TestCase {
+                locale: TestLocale("en"),
+                opts: collator::Options {
+                    sensitivity: collator::options::Sensitivity::Accent,
+                    ..Default::default()
+                },
+                pairs: &[("a", "A"), ("a", "á")],
+                expected: &[0, -1],
+            },
+            TestCase {
+                locale: TestLocale("en"),
+                opts: collator::Options {
+                    sensitivity: collator::options::Sensitivity::Case,
+                    ..Default::default()
+                },
+                pairs: &[("a", "A"), ("a", "á")],
+                expected: &[-1, 0],
+            },
+            TestCase {
+                locale: TestLocale("en"),
+                opts: collator::Options {
+                    numeric: collator::options::Numeric::Use,
+                    ..Default::default()
+                },
+                pairs: &[("2", "10")],
+                expected: &[-1],
+            },
+            TestCase {
+                locale: TestLocale("en"),
+                opts: collator::Options {
+                    case_first: collator::options::CaseFirst::Upper,
+                    ..Default::default()
+                },
+                pairs: &[("a", "A")],
+                expected: &[1],
+            },
+            TestCase {
+                locale: TestLocale("en"),
+                opts: collator::Options {
+                    punctuation: collator::options::Punctuation::Ignore,
+                    ..Default::default()
+                },
+                pairs: &[("co-op", "coop")],
+                expected: &[0],
+            },
+            TestCase {
+                locale: TestLocale("sv"),
+                opts: Default::default(),
+                pairs: &[("ä", "z")],
+                expected: &[1],
+            },
+        ];
+        for (i, test) in tests.into_iter().enumerate() {
+            let collator = super::Collator::try_new(test.locale, test.opts)?;
+            assert_eq!(
+                test.pairs
+                    .iter()
+                    .map(|(a, b)| collator.compare_strs(a, b))
+                    .collect::<Vec<_>>(),
+                test.expected,
+                "for test case: {i}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn compare_root() {
+        assert_eq!(super::Collator::compare("a", "b"), -1);
+        assert_eq!(super::Collator::compare("ä", "z"), -1);
+    }
+}
@@ -0,0 +1,340 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::fmt;
+use ecma402_traits::datetimeformat::{
+    options::{HourCycle, Month, Style, TimeZoneStyle, Weekday},
+    DateTimeFormatOptions,
+};
+use ecma402_traits::Locale;
+use icu::datetime::fieldsets::builder::{DateFields, FieldSetBuilder, ZoneStyle};
+use icu::datetime::fieldsets::enums::CompositeFieldSet;
+use icu::datetime::options::{Length, SubsecondDigits, TimePrecision, YearStyle};
+use icu::datetime::{DateTimeFormatter, DateTimeFormatterLoadError};
+use icu::locale::extensions::unicode::key;
+use icu::locale::subtags::subtag;
+use icu::time::zone::UtcOffset;
+use icu::time::{DateTime, TimeZone, ZonedDateTime};
+use icu_provider::DataError;
+use writeable::Writeable;
+
+#[derive(Debug)]
+pub struct DateTimeFormat {
+    formatter: DateTimeFormatter<CompositeFieldSet>,
+    time_zone: TimeZone,
+    offset: UtcOffset,
+}
+
+/// Resolves the `timeZone` option.
+///
+/// ICU4X does not include a time zone database, so only UTC and fixed offsets
+/// (such as `+05:30`) can be resolved to a UTC offset.
+fn resolve_time_zone(
+    time_zone: Option<&str>,
+) -> Result<(TimeZone, UtcOffset), DateTimeFormatterLoadError> {
+    match time_zone {
+        None | Some("UTC" | "Etc/UTC" | "GMT" | "Etc/GMT") => {
+            Ok((TimeZone(subtag!("utc")), UtcOffset::zero()))
+        }
+        Some(offset) => UtcOffset::try_from_str(offset)
+            .map(|offset| (TimeZone::UNKNOWN, offset))
+            .map_err(|_| {
+                DataError::custom("Only UTC and offset time zones are supported")
+                    .with_debug_context(offset)
+                    .into()
+            }),
+    }
+}
+
+/// Converts the ECMA-402 style and component options into a [`FieldSetBuilder`].
+fn to_field_set_builder(
+    opts: &DateTimeFormatOptions,
+) -> Result<FieldSetBuilder, DateTimeFormatterLoadError> {
+    let mut builder = FieldSetBuilder::new();
+    let has_components = opts.weekday.is_some()
+        || opts.era.is_some()
+        || opts.year.is_some()
+        || opts.month.is_some()
+        || opts.day.is_some()
+        || opts.hour.is_some()
+        || opts.minute.is_some()
+        || opts.second.is_some()
+        || opts.fractional_second_digits.is_some()
+        || opts.time_zone_style.is_some();
+
+    if opts.date_style.is_some() || opts.time_style.is_some() {
+        if has_components {
+            return Err(DataError::custom(
+                "dateStyle and timeStyle cannot be combined with component options",
+            )
+            .into());
+        }
+        if let Some(ref date_style) = opts.date_style {
+            builder.date_fields = Some(match date_style {
+                Style::Full => DateFields::YMDE,
+                Style::Long | Style::Medium | Style::Short => DateFields::YMD,
+            });
+        }
+        if let Some(ref time_style) = opts.time_style {
+            builder.time_precision = Some(match time_style {
+                Style::Full | Style::Long | Style::Medium => TimePrecision::Second,
+                Style::Short => TimePrecision::Minute,
+            });
+            builder.zone_style = match time_style {
+                Style::Full => Some(ZoneStyle::SpecificLong),
+                Style::Long => Some(ZoneStyle::SpecificShort),
+                Style::Medium | Style::Short => None,
+            };
+        }
+        builder.length = Some(
+            match opts.date_style.as_ref().or(opts.time_style.as_ref()) {
+                Some(Style::Full | Style::Long) => Length::Long,
+                Some(Style::Medium) => Length::Medium,
+                Some(Style::Short) | None => Length::Short,
+            },
+        );
+        return Ok(builder);
+    }
+
+    if !has_components {
+        // The ECMA-402 default is a numeric date.
+        builder.date_fields = Some(DateFields::YMD);
+        builder.length = Some(Length::Short);
+        return Ok(builder);
+    }
+
+    builder.date_fields = match (
+        opts.year.is_some(),
+        opts.month.is_some(),
+        opts.day.is_some(),
+        opts.weekday.is_some(),
+    ) {
+        (false, false, false, false) => None,
+        (false, false, false, true) => Some(DateFields::E),
+        (false, false, true, false) => Some(DateFields::D),
+        (false, false, true, true) => Some(DateFields::DE),
+        (false, true, false, false) => Some(DateFields::M),
+        (false, true, true, false) => Some(DateFields::MD),
+        (false, true, _, true) => Some(DateFields::MDE),
+        (true, false, false, false) => Some(DateFields::Y),
+        (true, true, false, false) => Some(DateFields::YM),
+        (true, _, _, false) => Some(DateFields::YMD),
+        (true, _, _, true) => Some(DateFields::YMDE),
+    };
+    if opts.era.is_some() && opts.year.is_some() {
+        builder.year_style = Some(YearStyle::WithEra);
+    }
+    builder.length = Some(match (&opts.month, &opts.weekday) {
+        (Some(Month::Long), _) | (None, Some(Weekday::Long)) => Length::Long,
+        (Some(Month::Short), _) | (None, Some(Weekday::Short)) => Length::Medium,
+        _ => Length::Short,
+    });
+
+    builder.time_precision = match (
+        &opts.hour,
+        &opts.minute,
+        &opts.second,
+        opts.fractional_second_digits,
+    ) {
+        (_, _, _, Some(digits)) => Some(TimePrecision::Subsecond(
+            SubsecondDigits::try_from_int(digits.get()).ok_or(DataError::custom(
+                "fractionalSecondDigits must be between 1 and 9",
+            ))?,
+        )),
+        (_, _, Some(_), None) => Some(TimePrecision::Second),
+        (_, Some(_), None, None) => Some(TimePrecision::Minute),
+        (Some(_), None, None, None) => Some(TimePrecision::Hour),
+        (None, None, None, None) => None,
+    };
+
+    builder.zone_style = opts.time_zone_style.as_ref().map(|style| match style {
+        TimeZoneStyle::Long => ZoneStyle::SpecificLong,
+        TimeZoneStyle::Short => ZoneStyle::SpecificShort,
+    });
+
+    Ok(builder)
+}
+
+impl ecma402_traits::datetimeformat::DateTimeFormat for DateTimeFormat {
+    type Error = DateTimeFormatterLoadError;
+
+    fn try_new<L>(locale: L, opts: DateTimeFormatOptions) -> Result<Self, Self::Error>
+    where
+        L: Locale,
+        Self: Sized,
+    {
+        let mut locale = crate::to_icu4x_locale(&locale);
+        if let Some(ref calendar) = opts.calendar {
+            crate::set_keyword(&mut locale, key!("ca"), &calendar.0)?;
+        }
+        if let Some(ref numbering_system) = opts.numbering_system {
+            crate::set_keyword(&mut locale, key!("nu"), &numbering_system.0)?;
+        }
+        if let Some(ref hour_cycle) = opts.hour_cycle {
+            // ICU4X does not support `h24`, which is not used by any locale.
+            let hour_cycle = match hour_cycle {
+                HourCycle::H24 => &HourCycle::H23,
+                hour_cycle => hour_cycle,
+            };
+            crate::set_keyword(&mut locale, key!("hc"), &hour_cycle.to_string())?;
+        }
+
+        let (time_zone, offset) =
+            resolve_time_zone(opts.time_zone.as_ref().map(|tz| tz.0.as_str()))?;
+
+        let field_set = to_field_set_builder(&opts)?
+            .build_composite()
+            .map_err(|e| DataError::custom("Invalid field set").with_display_context(&e))?;
+
+        Ok(Self {
+            formatter: DateTimeFormatter::try_new((&locale).into(), field_set)?,
+            time_zone,
+            offset,
+        })
+    }
+
+    /// Formats `date`, given in milliseconds since the UNIX epoch.
+    fn format<W>(&self, date: f64, writer: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        // The range of valid ECMAScript time values, see `TimeClip`.
+        if !date.is_finite() || date.abs() > 8.64e15 {
+            return Err(fmt::Error);
+        }
+        let ZonedDateTime { date, time, .. } =
+            ZonedDateTime::from_epoch_milliseconds_and_utc_offset(date as i64, self.offset);
+        let zone = self
+            .time_zone
+            .with_offset(Some(self.offset))
+            .at_date_time_iso(DateTime { date, time });
+        self.formatter
+            .format(&ZonedDateTime { date, time, zone })
+            .write_to(writer)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::testing::TestLocale;
+    use core::num::NonZeroU8;
+    use ecma402_traits::datetimeformat::options::*;
+    use ecma402_traits::datetimeformat::{DateTimeFormat, DateTimeFormatOptions};
+    use icu::datetime::DateTimeFormatterLoadError;
+
+    // 2020-09-13T12:26:40.123Z
+    const DATE: f64 = 1_600_000_000_123.0;
+
+    #[test]
+    fn format_per_locale() -> Result<(), DateTimeFormatterLoadError> {
+        #[derive(Debug, Clone)]
+        struct TestCase {
+            locale: TestLocale,
+            opts: DateTimeFormatOptions,
+            expected: &'static str,
+        }
+        let tests = [
+            TestCase {
+                locale: TestLocale("en-US"),
+                opts: Default::default(),
+                expected: "9/13/20",
+            },
+            TestCase {
+                locale: TestLocale("en-US"),
+                opts: DateTimeFormatOptions {
+                    date_style: Some(Style::Full),
+                    ..Default::default()
+                },
+                expected: "Sunday, September 13, 2020",
+            },
+            TestCase {
+                locale: TestLocale("en-US"),
+                opts: DateTimeFormatOptions {
+                    date_style: Some(Style::Medium),
+                    time_style: Some(Style::Short),
+                    ..Default::default()
+                },
+                expected: "Sep 13, 2020, 12:26\u{202f}PM",
+            },
+            TestCase {
+                locale: TestLocale("de"),
+                opts: DateTimeFormatOptions {
+                    date_style: Some(Style::Long),
+                    ..Default::default()
+                },
+                expected: "13. September 2020",
+            },
+            TestCase {
+                locale: TestLocale("en-US"),
+                opts: DateTimeFormatOptions {
+                    year: Some(DisplaySize::Numeric),
+                    month: Some(Month::Short),
+                    ..Default::default()
+                },
+                expected: "Sep 2020",
+            },
+            TestCase {
+                locale: TestLocale("en-US"),
+                opts: DateTimeFormatOptions {
+                    hour: Some(DisplaySize::Numeric),
+                    minute: Some(DisplaySize::TwoDigit),
+                    hour_cycle: Some(HourCycle::H23),
+                    ..Default::default()
+                },
+                expected: "12:26",
+            },
+            TestCase {
+                locale: TestLocale("en-US"),
+                opts: DateTimeFormatOptions {
+                    second: Some(DisplaySize::Numeric),
+                    fractional_second_digits: NonZeroU8::new(3),
+                    time_zone: Some("+05:30".into()),
+                    hour_cycle: Some(HourCycle::H23),
+                    ..Default::default()
+                },
+                expected: "17:56:40.123",
+            },
+            TestCase {
+                locale: TestLocale("en-US"),
+                opts: DateTimeFormatOptions {
+                    date_style: Some(Style::Long),
+                    calendar: Some("japanese".into()),
+                    ..Default::default()
+                },
+                expected: "September 13, 2 Reiwa",
+            },
+        ];
+        for (i, test) in tests.into_iter().enumerate() {
+            let dtf = super::DateTimeFormat::try_new(test.locale, test.opts)?;
+            let mut result = String::new();
+            dtf.format(DATE, &mut result).unwrap();
+            assert_eq!(result, test.expected, "for test case: {i}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_options() {
+        for opts in [
+            DateTimeFormatOptions {
+                date_style: Some(Style::Long),
+                year: Some(DisplaySize::Numeric),
+                ..Default::default()
+            },
+            DateTimeFormatOptions {
+                time_zone: Some("America/Chicago".into()),
+                ..Default::default()
+            },
+            DateTimeFormatOptions {
+                fractional_second_digits: NonZeroU8::new(10),
+                ..Default::default()
+            },
+        ] {
+            assert!(super::DateTimeFormat::try_new(TestLocale("en"), opts).is_err());
+        }
+
+        let dtf = super::DateTimeFormat::try_new(TestLocale("en"), Default::default()).unwrap();
+        assert!(dtf.format(f64::NAN, &mut String::new()).is_err());
+    }
+}
@@ -0,0 +1,201 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::fmt;
+use ecma402_traits::displaynames::{
+    options::{Fallback, Style, Type},
+    Options,
+};
+use ecma402_traits::Locale;
+use icu::experimental::dimension::provider::currency::displayname::CurrencyDisplaynameV1;
+use icu::experimental::displaynames::{
+    DisplayNamesOptions, LocaleDisplayNamesFormatter, RegionDisplayNames, ScriptDisplayNames,
+};
+use icu::locale::subtags::{Region, Script};
+use icu_provider::prelude::*;
+
+pub struct DisplayNames {
+    fallback: Fallback,
+    names: Names,
+}
+
+#[expect(clippy::large_enum_variant)] // only one is ever constructed per formatter
+enum Names {
+    Language(LocaleDisplayNamesFormatter),
+    Region(RegionDisplayNames),
+    Script(ScriptDisplayNames),
+    Currency(DataLocale),
+}
+
+impl fmt::Debug for DisplayNames {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DisplayNames")
+            .field("fallback", &self.fallback)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ecma402_traits::displaynames::DisplayNames for DisplayNames {
+    type Error = DataError;
+
+    fn try_new<L>(locale: L, opts: Options) -> Result<Self, Self::Error>
+    where
+        L: Locale,
+        Self: Sized,
+    {
+        let locale = crate::to_icu4x_locale(&locale);
+
+        let mut options = DisplayNamesOptions::default();
+        options.style = Some(match opts.style {
+            Style::Narrow => icu::experimental::displaynames::Style::Narrow,
+            Style::Short => icu::experimental::displaynames::Style::Short,
+            Style::Long => icu::experimental::displaynames::Style::Long,
+        });
+        options.fallback = match opts.fallback {
+            Fallback::Code => icu::experimental::displaynames::Fallback::Code,
+            Fallback::None => icu::experimental::displaynames::Fallback::None,
+        };
+
+        let names = match opts.in_type {
+            Type::Language => Names::Language(LocaleDisplayNamesFormatter::try_new(
+                (&locale).into(),
+                options,
+            )?),
+            Type::Region => Names::Region(RegionDisplayNames::try_new((&locale).into(), options)?),
+            Type::Script => Names::Script(ScriptDisplayNames::try_new((&locale).into(), options)?),
+            Type::Currency => Names::Currency(DataLocale::from(&locale)),
+        };
+
+        Ok(Self {
+            fallback: opts.fallback,
+            names,
+        })
+    }
+
+    /// Writes the display name of `code`, which is a language tag, region, script,
+    /// or currency code depending on the type this was created with.
+    ///
+    /// Writes nothing if no display name is found and the fallback is [`Fallback::None`].
+    fn format<W, L>(&self, code: L, writer: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+        L: Locale,
+    {
+        let code = code.to_string();
+        let name = match &self.names {
+            Names::Language(formatter) => {
+                let locale = icu::locale::Locale::try_from_str(&code).map_err(|_| fmt::Error)?;
+                // The formatter applies the fallback itself.
+                return writer.write_str(&formatter.of(&locale));
+            }
+            Names::Region(names) => names.of(Region::try_from_str(&code).map_err(|_| fmt::Error)?),
+            Names::Script(names) => names.of(Script::try_from_str(&code).map_err(|_| fmt::Error)?),
+            Names::Currency(locale) => {
+                if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_alphabetic()) {
+                    return Err(fmt::Error);
+                }
+                let code = code.to_ascii_uppercase();
+                let attributes =
+                    DataMarkerAttributes::try_from_str(&code).map_err(|_| fmt::Error)?;
+                let response = DataProvider::<CurrencyDisplaynameV1>::load(
+                    &icu::experimental::provider::Baked,
+                    DataRequest {
+                        id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                            attributes, locale,
+                        ),
+                        ..Default::default()
+                    },
+                )
+                .ok();
+                return match (response, &self.fallback) {
+                    (Some(response), _) => writer.write_str(&response.payload.get().display_name),
+                    (None, Fallback::Code) => writer.write_str(&code),
+                    (None, Fallback::None) => Ok(()),
+                };
+            }
+        };
+        match (name, &self.fallback) {
+            (Some(name), _) => writer.write_str(name),
+            (None, Fallback::Code) => writer.write_str(&code),
+            (None, Fallback::None) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::testing::TestLocale;
+    use ecma402_traits::displaynames;
+    use ecma402_traits::displaynames::DisplayNames;
+    use icu_provider::DataError;
+
+    #[test]
+    fn format_per_locale() -> Result<(), DataError> {
+        struct TestCase {
+            locale: TestLocale,
+            opts: displaynames::Options,
+            codes: &'static [&'static str],
+            expected: &'static [&'static str],
+        }
+        let tests = [
+            TestCase {
+                locale: TestLocale("en"),
+                opts: Default::default(),
+                codes: &["US", "419", "AE", "XY"],
+                expected: &[
+                    "United States",
+                    "Latin America",
+                    "United Arab Emirates",
+                    "XY",
+                ],
+            },
+            TestCase {
+                locale: TestLocale("de"),
+                opts: Default::default(),
+                codes: &["DE", "US"],
+                expected: &["Deutschland", "Vereinigte Staaten"],
+            },
+            TestCase {
+                locale: TestLocale("en"),
+                opts: displaynames::Options {
+                    fallback: displaynames::options::Fallback::None,
+                    ..Default::default()
+                },
+                codes: &["XY"],
+                expected: &[""],
+            },
+            TestCase {
+                locale: TestLocale("en"),
+                opts: displaynames::Options {
+                    in_type: displaynames::options::Type::Script,
+                    ..Default::default()
+                },
+                codes: &["Latn", "Cyrl"],
+                expected: &["Latin", "Cyrillic"],
+            },
+        ];
+        for (i, test) in tests.into_iter().enumerate() {
+            let dn = super::DisplayNames::try_new(test.locale, test.opts)?;
+            assert_eq!(
+                test.codes
+                    .iter()
+                    .map(|c| {
+                        let mut result = String::new();
+                        dn.format(TestLocale(c), &mut result).unwrap();
+                        result
+                    })
+                    .collect::<Vec<_>>(),
+                test.expected,
+                "for test case: {i}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_code() {
+        let dn = super::DisplayNames::try_new(TestLocale("en"), Default::default()).unwrap();
+        assert!(dn.format(TestLocale("USA"), &mut String::new()).is_err());
+    }
+}
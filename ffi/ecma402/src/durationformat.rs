@@ -0,0 +1,134 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! `ecma402_traits` does not define a duration format trait, so this module provides
+//! the options and types of `Intl.DurationFormat` directly.
+
+use core::fmt;
+use ecma402_traits::Locale;
+use icu::experimental::duration::options::{BaseStyle, DurationFormatterOptions, FractionalDigits};
+use icu::experimental::duration::{DurationFormatter, ValidatedDurationFormatterOptions};
+use icu_provider::DataError;
+use writeable::Writeable;
+
+pub use icu::experimental::duration::{Duration, DurationSign};
+
+/// Contains the API configuration as prescribed by ECMA 402.
+pub mod options {
+    /// The base style of the formatted duration.
+    #[derive(Eq, PartialEq, Debug, Clone, Copy)]
+    #[non_exhaustive]
+    pub enum Style {
+        /// For example, "1 hour, 2 minutes".
+        Long,
+        /// For example, "1 hr, 2 min".
+        Short,
+        /// For example, "1h 2m".
+        Narrow,
+        /// For example, "1:02:00".
+        Digital,
+    }
+}
+
+/// The options set by the user at construction time, mirroring those of `Intl.DurationFormat`.
+#[derive(Eq, PartialEq, Debug, Clone)]
+#[non_exhaustive]
+pub struct Options {
+    /// The style of the formatted duration.
+    pub style: options::Style,
+    /// The number of fractional digits to display for seconds, between 0 and 9.
+    ///
+    /// If `None`, as many digits as necessary are displayed.
+    pub fractional_digits: Option<u8>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            style: options::Style::Short,
+            fractional_digits: None,
+        }
+    }
+}
+
+pub struct DurationFormat(DurationFormatter);
+
+impl fmt::Debug for DurationFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DurationFormat").finish_non_exhaustive()
+    }
+}
+
+impl DurationFormat {
+    /// Creates a new [`DurationFormat`].
+    pub fn try_new<L>(locale: L, opts: Options) -> Result<Self, DataError>
+    where
+        L: Locale,
+    {
+        let locale = crate::to_icu4x_locale(&locale);
+
+        let mut options = DurationFormatterOptions::default();
+        options.base = match opts.style {
+            options::Style::Long => BaseStyle::Long,
+            options::Style::Short => BaseStyle::Short,
+            options::Style::Narrow => BaseStyle::Narrow,
+            options::Style::Digital => BaseStyle::Digital,
+        };
+        if let Some(digits) = opts.fractional_digits {
+            options.fractional_digits = FractionalDigits::Fixed(digits);
+        }
+        let options = ValidatedDurationFormatterOptions::validate(options).map_err(|e| {
+            DataError::custom("Invalid duration format options").with_display_context(&e)
+        })?;
+
+        Ok(Self(DurationFormatter::try_new((&locale).into(), options)?))
+    }
+
+    /// Formats `duration`.
+    pub fn format<W>(&self, duration: &Duration, writer: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        self.0.format(duration).write_to(writer)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::testing::TestLocale;
+
+    #[test]
+    fn format() {
+        let duration = Duration {
+            sign: DurationSign::Positive,
+            hours: 12,
+            minutes: 1,
+            seconds: 32,
+            milliseconds: 130,
+            ..Default::default()
+        };
+
+        let df = DurationFormat::try_new(
+            TestLocale("und"),
+            Options {
+                style: options::Style::Digital,
+                fractional_digits: None,
+            },
+        )
+        .unwrap();
+        let mut result = String::new();
+        df.format(&duration, &mut result).unwrap();
+        assert_eq!(result, "12:01:32.13");
+
+        assert!(DurationFormat::try_new(
+            TestLocale("und"),
+            Options {
+                style: options::Style::Digital,
+                fractional_digits: Some(10),
+            },
+        )
+        .is_err());
+    }
+}
@@ -32,6 +32,41 @@ pub mod pluralrules;
 /// [link]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/ListFormat
 pub mod list;
 
+/// Implements ECMA-402 [`Intl.Collator`][link].
+///
+/// [link]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator
+pub mod collator;
+
+/// Implements ECMA-402 [`Intl.NumberFormat`][link].
+///
+/// [link]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat
+pub mod numberformat;
+
+/// Implements ECMA-402 [`Intl.DateTimeFormat`][link].
+///
+/// [link]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat
+pub mod datetimeformat;
+
+/// Implements ECMA-402 [`Intl.RelativeTimeFormat`][link].
+///
+/// [link]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat
+pub mod relativetime;
+
+/// Implements ECMA-402 [`Intl.DisplayNames`][link].
+///
+/// [link]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames
+pub mod displaynames;
+
+/// Implements ECMA-402 [`Intl.Segmenter`][link].
+///
+/// [link]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter
+pub mod segmenter;
+
+/// Implements ECMA-402 [`Intl.DurationFormat`][link].
+///
+/// [link]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat
+pub mod durationformat;
+
 /// An adapter between [`DataLocale`] and [`ecma402_traits::Locale`].
 #[derive(Debug, Hash, Clone, PartialEq)]
 pub struct DataLocale(icu_provider::DataLocale);
@@ -50,3 +85,23 @@ impl std::fmt::Display for crate::DataLocale {
         self.0.fmt(f)
     }
 }
+
+/// Parses an [`ecma402_traits::Locale`] into an ICU4X [`Locale`](icu::locale::Locale).
+pub(crate) fn to_icu4x_locale(locale: &impl ecma402_traits::Locale) -> icu::locale::Locale {
+    #[expect(clippy::unwrap_used)] // ecma402_traits::Locale::to_string is a valid locale
+    icu::locale::Locale::try_from_str(&locale.to_string()).unwrap()
+}
+
+/// Sets a Unicode extension keyword on `locale`, as ECMA-402 does for options such as
+/// `calendar` or `numberingSystem` that override the locale's `-u-` keywords.
+pub(crate) fn set_keyword(
+    locale: &mut icu::locale::Locale,
+    key: icu::locale::extensions::unicode::Key,
+    value: &str,
+) -> Result<(), icu_provider::DataError> {
+    let value = icu::locale::extensions::unicode::Value::try_from_str(value).map_err(|_| {
+        icu_provider::DataError::custom("Invalid option value").with_debug_context(value)
+    })?;
+    locale.extensions.unicode.keywords.set(key, value);
+    Ok(())
+}
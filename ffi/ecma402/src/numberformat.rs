@@ -0,0 +1,339 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::fmt;
+use ecma402_traits::numberformat::{
+    options::{CompactDisplay, CurrencyDisplay, Notation, SignDisplay, Style},
+    Options,
+};
+use ecma402_traits::Locale;
+use fixed_decimal::{Decimal, FloatPrecision, SignedRoundingMode, UnsignedRoundingMode};
+use icu::decimal::DecimalFormatter;
+use icu::experimental::compactdecimal::CompactDecimalFormatter;
+use icu::experimental::dimension::currency::formatter::CurrencyFormatter;
+use icu::experimental::dimension::currency::long_formatter::LongCurrencyFormatter;
+use icu::experimental::dimension::currency::options::{CurrencyFormatterOptions, Width};
+use icu::experimental::dimension::currency::CurrencyCode;
+use icu::experimental::dimension::percent::formatter::PercentFormatter;
+use icu::experimental::dimension::units::formatter::UnitsFormatter;
+use icu::locale::extensions::unicode::key;
+use icu_provider::DataError;
+use writeable::Writeable;
+
+pub struct NumberFormat {
+    opts: Options,
+    formatter: Formatter,
+}
+
+enum Formatter {
+    Decimal(DecimalFormatter),
+    Compact(CompactDecimalFormatter),
+    Percent(PercentFormatter<DecimalFormatter>),
+    Currency(CurrencyFormatter, CurrencyCode),
+    LongCurrency(LongCurrencyFormatter, CurrencyCode),
+    Unit(UnitsFormatter),
+}
+
+impl fmt::Debug for NumberFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NumberFormat")
+            .field("opts", &self.opts)
+            .finish_non_exhaustive()
+    }
+}
+
+impl NumberFormat {
+    /// Applies the digit options to `value`, using the ECMA-402 defaults for the
+    /// fraction digits when no option is given.
+    fn round(&self, value: &mut Decimal, default_min_fraction: u8, default_max_fraction: u8) {
+        let opts = &self.opts;
+        let mode = SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfExpand);
+        if opts.minimum_significant_digits.is_some() || opts.maximum_significant_digits.is_some() {
+            let min = opts.minimum_significant_digits.unwrap_or(1) as i16;
+            let max = opts.maximum_significant_digits.unwrap_or(21) as i16;
+            let magnitude = value.absolute.nonzero_magnitude_start();
+            value.round_with_mode(magnitude - max + 1, mode);
+            value
+                .absolute
+                .pad_end(value.absolute.nonzero_magnitude_start() - min + 1);
+        } else {
+            let min = opts.minimum_fraction_digits.unwrap_or(default_min_fraction);
+            let max = opts
+                .maximum_fraction_digits
+                .unwrap_or(default_max_fraction)
+                .max(min);
+            value.round_with_mode(-(max as i16), mode);
+            value.absolute.pad_end(-(min as i16));
+        }
+        if let Some(min) = opts.minimum_integer_digits {
+            value.absolute.pad_start(min as i16);
+        }
+    }
+}
+
+fn currency_code(opts: &Options) -> Result<CurrencyCode, DataError> {
+    let currency = opts
+        .currency
+        .as_ref()
+        .ok_or(DataError::custom("The currency style requires a currency"))?;
+    tinystr::TinyAsciiStr::<3>::try_from_str(&currency.0)
+        .ok()
+        .filter(|code| code.is_ascii_alphabetic())
+        .map(|code| CurrencyCode(code.to_ascii_uppercase()))
+        .ok_or(DataError::custom("Invalid currency code").with_debug_context(&currency.0))
+}
+
+impl ecma402_traits::numberformat::NumberFormat for NumberFormat {
+    type Error = DataError;
+
+    fn try_new<L>(locale: L, opts: Options) -> Result<Self, Self::Error>
+    where
+        L: Locale,
+        Self: Sized,
+    {
+        let mut locale = crate::to_icu4x_locale(&locale);
+        if let Some(ref numbering_system) = opts.numbering_system {
+            crate::set_keyword(&mut locale, key!("nu"), &numbering_system.0)?;
+        }
+
+        let formatter = match (&opts.style, &opts.notation) {
+            (_, Notation::Scientific | Notation::Engineering) => {
+                return Err(DataError::custom("Scientific notation is not supported"))
+            }
+            (Style::Decimal, Notation::Compact) => {
+                Formatter::Compact(match opts.compact_display {
+                    Some(CompactDisplay::Long) => CompactDecimalFormatter::try_new_long,
+                    Some(CompactDisplay::Short) | None => CompactDecimalFormatter::try_new_short,
+                }((&locale).into(), Default::default())?)
+            }
+            (_, Notation::Compact) => {
+                return Err(DataError::custom(
+                    "Compact notation is only supported for the decimal style",
+                ))
+            }
+            (Style::Decimal, Notation::Standard) => Formatter::Decimal(DecimalFormatter::try_new(
+                (&locale).into(),
+                Default::default(),
+            )?),
+            (Style::Percent, Notation::Standard) => Formatter::Percent(PercentFormatter::try_new(
+                (&locale).into(),
+                Default::default(),
+            )?),
+            (Style::Currency, Notation::Standard) => {
+                let code = currency_code(&opts)?;
+                let width = match opts.currency_display {
+                    CurrencyDisplay::Symbol => Width::Short,
+                    CurrencyDisplay::NarrowSymbol => Width::Narrow,
+                    CurrencyDisplay::Name => {
+                        return Ok(Self {
+                            formatter: Formatter::LongCurrency(
+                                LongCurrencyFormatter::try_new((&locale).into(), &code)?,
+                                code,
+                            ),
+                            opts,
+                        })
+                    }
+                    CurrencyDisplay::Code => {
+                        return Err(DataError::custom("Currency codes are not supported"))
+                    }
+                };
+                Formatter::Currency(
+                    CurrencyFormatter::try_new(
+                        (&locale).into(),
+                        CurrencyFormatterOptions::from(width),
+                    )?,
+                    code,
+                )
+            }
+            (Style::Unit, Notation::Standard) => {
+                let unit = opts
+                    .unit
+                    .as_ref()
+                    .ok_or(DataError::custom("The unit style requires a unit"))?;
+                Formatter::Unit(UnitsFormatter::try_new(
+                    (&locale).into(),
+                    &unit.0,
+                    Default::default(),
+                )?)
+            }
+        };
+
+        Ok(Self { opts, formatter })
+    }
+
+    fn format<W>(&self, number: f64, writer: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        let mut value =
+            Decimal::try_from_f64(number, FloatPrecision::RoundTrip).map_err(|_| fmt::Error)?;
+        value.apply_sign_display(match self.opts.sign_display {
+            SignDisplay::Auto => fixed_decimal::SignDisplay::Auto,
+            SignDisplay::Never => fixed_decimal::SignDisplay::Never,
+            SignDisplay::Always => fixed_decimal::SignDisplay::Always,
+            SignDisplay::ExceptZero => fixed_decimal::SignDisplay::ExceptZero,
+        });
+        match &self.formatter {
+            Formatter::Decimal(f) => {
+                self.round(&mut value, 0, 3);
+                f.format(&value).write_to(writer)
+            }
+            // The compact formatter picks its own precision.
+            Formatter::Compact(f) => f.format_fixed_decimal(&value).write_to(writer),
+            Formatter::Percent(f) => {
+                value.multiply_pow10(2);
+                value.absolute.trim_start();
+                self.round(&mut value, 0, 0);
+                f.format(&value).write_to(writer)
+            }
+            Formatter::Currency(f, code) => {
+                self.round(&mut value, 2, 2);
+                f.format_fixed_decimal(&value, *code).write_to(writer)
+            }
+            Formatter::LongCurrency(f, code) => {
+                self.round(&mut value, 2, 2);
+                f.format_fixed_decimal(&value, *code).write_to(writer)
+            }
+            Formatter::Unit(f) => {
+                self.round(&mut value, 0, 3);
+                f.format_fixed_decimal(&value).write_to(writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::testing::TestLocale;
+    use ecma402_traits::numberformat;
+    use ecma402_traits::numberformat::NumberFormat;
+    use icu_provider::DataError;
+
+    #[test]
+    fn format_per_locale() -> Result<(), DataError> {
+        #[derive(Debug, Clone)]
+        struct TestCase {
+            locale: TestLocale,
+            opts: numberformat::Options,
+            numbers: &'static [f64],
+            expected: &'static [&'static str],
+        }
+        let tests = [
+            TestCase {
+                locale: TestLocale("en-US"),
+                opts: Default::default(),
+                numbers: &[0.0, 1.5, -1234.5678, 1e21],
+                expected: &["0", "1.5", "-1,234.568", "1,000,000,000,000,000,000,000"],
+            },
+            TestCase {
+                locale: TestLocale("de"),
+                opts: Default::default(),
+                numbers: &[1234.5],
+                expected: &["1.234,5"],
+            },
+            TestCase {
+                locale: TestLocale("en-US"),
+                opts: numberformat::Options {
+                    minimum_integer_digits: Some(3),
+                    minimum_fraction_digits: Some(2),
+                    ..Default::default()
+                },
+                numbers: &[1.5, 1.005],
+                expected: &["001.50", "001.005"],
+            },
+            TestCase {
+                locale: TestLocale("en-US"),
+                opts: numberformat::Options {
+                    maximum_significant_digits: Some(2),
+                    ..Default::default()
+                },
+                numbers: &[1234.5, 0.01234, 0.5],
+                expected: &["1,200", "0.012", "0.5"],
+            },
+            TestCase {
+                locale: TestLocale("en-US"),
+                opts: numberformat::Options {
+                    sign_display: numberformat::options::SignDisplay::ExceptZero,
+                    ..Default::default()
+                },
+                numbers: &[1.0, 0.0, -1.0],
+                expected: &["+1", "0", "-1"],
+            },
+            TestCase {
+                locale: TestLocale("en-US"),
+                opts: numberformat::Options {
+                    style: numberformat::options::Style::Percent,
+                    ..Default::default()
+                },
+                numbers: &[0.25, 1.0, 0.1234],
+                expected: &["25%", "100%", "12%"],
+            },
+            TestCase {
+                locale: TestLocale("en-US"),
+                opts: numberformat::Options {
+                    style: numberformat::options::Style::Currency,
+                    currency: Some("USD".into()),
+                    ..Default::default()
+                },
+                numbers: &[1234.5, 0.125],
+                expected: &["$1,234.50", "$0.13"],
+            },
+            TestCase {
+                locale: TestLocale("en-US"),
+                opts: numberformat::Options {
+                    notation: numberformat::options::Notation::Compact,
+                    ..Default::default()
+                },
+                numbers: &[1234.0, 1_500_000.0],
+                expected: &["1.2K", "1.5M"],
+            },
+            TestCase {
+                locale: TestLocale("en-US"),
+                opts: numberformat::Options {
+                    numbering_system: Some("arab".into()),
+                    ..Default::default()
+                },
+                numbers: &[123.0],
+                expected: &["١٢٣"],
+            },
+        ];
+        for (i, test) in tests.into_iter().enumerate() {
+            let nf = super::NumberFormat::try_new(test.locale, test.opts)?;
+            assert_eq!(
+                test.numbers
+                    .iter()
+                    .map(|n| {
+                        let mut result = String::new();
+                        nf.format(*n, &mut result).unwrap();
+                        result
+                    })
+                    .collect::<Vec<_>>(),
+                test.expected,
+                "for test case: {i}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_options() {
+        for opts in [
+            numberformat::Options {
+                notation: numberformat::options::Notation::Scientific,
+                ..Default::default()
+            },
+            numberformat::Options {
+                style: numberformat::options::Style::Currency,
+                ..Default::default()
+            },
+            numberformat::Options {
+                style: numberformat::options::Style::Currency,
+                currency: Some("US$".into()),
+                ..Default::default()
+            },
+        ] {
+            assert!(super::NumberFormat::try_new(TestLocale("en"), opts).is_err());
+        }
+    }
+}
@@ -0,0 +1,112 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::fmt;
+use ecma402_traits::relativetime::{
+    options::{Numeric, Style},
+    Options,
+};
+use ecma402_traits::Locale;
+use fixed_decimal::Decimal;
+use icu::experimental::relativetime::{
+    options::Numeric as IcuNumeric, RelativeTimeFormatter, RelativeTimeFormatterOptions,
+};
+use writeable::Writeable;
+
+pub struct RelativeTimeFormat(RelativeTimeFormatter);
+
+impl fmt::Debug for RelativeTimeFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RelativeTimeFormat").finish_non_exhaustive()
+    }
+}
+
+impl ecma402_traits::relativetime::RelativeTimeFormat for RelativeTimeFormat {
+    type Error = icu_provider::DataError;
+
+    fn try_new<L>(locale: L, opts: Options) -> Result<Self, Self::Error>
+    where
+        L: Locale,
+        Self: Sized,
+    {
+        let locale = crate::to_icu4x_locale(&locale);
+
+        let options = RelativeTimeFormatterOptions {
+            numeric: match opts.numeric {
+                Numeric::Always => IcuNumeric::Always,
+                Numeric::Auto => IcuNumeric::Auto,
+            },
+        };
+
+        Ok(Self(match opts.style {
+            Style::Long => RelativeTimeFormatter::try_new_long_day,
+            Style::Short => RelativeTimeFormatter::try_new_short_day,
+            Style::Narrow => RelativeTimeFormatter::try_new_narrow_day,
+        }((&locale).into(), options)?))
+    }
+
+    fn format<W>(&self, days: i32, writer: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        self.0.format(Decimal::from(days)).write_to(writer)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::testing::TestLocale;
+    use ecma402_traits::relativetime;
+    use ecma402_traits::relativetime::RelativeTimeFormat;
+    use icu_provider::DataError;
+
+    #[test]
+    fn format_per_locale() -> Result<(), DataError> {
+        struct TestCase {
+            locale: TestLocale,
+            opts: relativetime::Options,
+            days: &'static [i32],
+            expected: &'static [&'static str],
+        }
+        let tests = [
+            TestCase {
+                locale: TestLocale("en"),
+                opts: Default::default(),
+                days: &[-2, -1, 0, 1, 2],
+                expected: &["2 days ago", "yesterday", "today", "tomorrow", "in 2 days"],
+            },
+            TestCase {
+                locale: TestLocale("en"),
+                opts: relativetime::Options {
+                    numeric: relativetime::options::Numeric::Always,
+                    style: relativetime::options::Style::Long,
+                },
+                days: &[-1, 0, 1],
+                expected: &["1 day ago", "in 0 days", "in 1 day"],
+            },
+            TestCase {
+                locale: TestLocale("es"),
+                opts: Default::default(),
+                days: &[-2, 2],
+                expected: &["anteayer", "pasado mañana"],
+            },
+        ];
+        for (i, test) in tests.into_iter().enumerate() {
+            let rtf = super::RelativeTimeFormat::try_new(test.locale, test.opts)?;
+            assert_eq!(
+                test.days
+                    .iter()
+                    .map(|d| {
+                        let mut result = String::new();
+                        rtf.format(*d, &mut result).unwrap();
+                        result
+                    })
+                    .collect::<Vec<_>>(),
+                test.expected,
+                "for test case: {i}"
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! `ecma402_traits` does not define a segmenter trait, so this module provides
+//! the options and types of `Intl.Segmenter` directly.
+
+use ecma402_traits::Locale;
+use icu::segmenter::options::{SentenceBreakOptions, WordBreakOptions};
+use icu::segmenter::{
+    GraphemeClusterSegmenter, GraphemeClusterSegmenterBorrowed, SentenceSegmenter, WordSegmenter,
+};
+
+/// Contains the API configuration as prescribed by ECMA 402.
+pub mod options {
+    /// The unit of text that the input is split into.
+    #[derive(Eq, PartialEq, Debug, Clone, Copy)]
+    #[non_exhaustive]
+    pub enum Granularity {
+        /// Split the input into user-perceived characters (extended grapheme clusters).
+        Grapheme,
+        /// Split the input into words.
+        Word,
+        /// Split the input into sentences.
+        Sentence,
+    }
+}
+
+/// The options set by the user at construction time, mirroring those of `Intl.Segmenter`.
+#[derive(Eq, PartialEq, Debug, Clone)]
+#[non_exhaustive]
+pub struct Options {
+    /// The unit of text that the input is split into.
+    pub granularity: options::Granularity,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            granularity: options::Granularity::Grapheme,
+        }
+    }
+}
+
+/// A segment of the input, as returned by `Intl.Segmenter.prototype.segment`.
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct Segment<'s> {
+    /// The text of the segment.
+    pub segment: &'s str,
+    /// The UTF-8 index of the segment in the input.
+    ///
+    /// Note that ECMAScript uses UTF-16 indices.
+    pub index: usize,
+    /// Whether the segment is word-like, such as letters or numbers.
+    ///
+    /// This is `None` unless the granularity is [`options::Granularity::Word`].
+    pub is_word_like: Option<bool>,
+}
+
+#[derive(Debug)]
+pub struct Segmenter(Inner);
+
+#[derive(Debug)]
+#[expect(clippy::large_enum_variant)] // only one is ever constructed per segmenter
+enum Inner {
+    Grapheme(GraphemeClusterSegmenterBorrowed<'static>),
+    Word(WordSegmenter),
+    Sentence(SentenceSegmenter),
+}
+
+impl Segmenter {
+    /// Creates a new [`Segmenter`], using the locale for tailorings of the
+    /// word and sentence rules.
+    pub fn try_new<L>(locale: L, opts: Options) -> Result<Self, icu_provider::DataError>
+    where
+        L: Locale,
+    {
+        let locale = crate::to_icu4x_locale(&locale);
+        Ok(Self(match opts.granularity {
+            options::Granularity::Grapheme => Inner::Grapheme(GraphemeClusterSegmenter::new()),
+            options::Granularity::Word => {
+                let mut options = WordBreakOptions::default();
+                options.content_locale = Some(&locale.id);
+                Inner::Word(WordSegmenter::try_new_dictionary(options)?)
+            }
+            options::Granularity::Sentence => {
+                let mut options = SentenceBreakOptions::default();
+                options.content_locale = Some(&locale.id);
+                Inner::Sentence(SentenceSegmenter::try_new(options)?)
+            }
+        }))
+    }
+
+    /// Splits `input` into segments.
+    pub fn segment<'s>(&self, input: &'s str) -> impl Iterator<Item = Segment<'s>> + 's {
+        let boundaries: Vec<(usize, Option<bool>)> = match &self.0 {
+            Inner::Grapheme(segmenter) => segmenter.segment_str(input).map(|i| (i, None)).collect(),
+            Inner::Word(segmenter) => segmenter
+                .as_borrowed()
+                .segment_str(input)
+                .iter_with_word_type()
+                .map(|(i, word_type)| (i, Some(word_type.is_word_like())))
+                .collect(),
+            Inner::Sentence(segmenter) => segmenter
+                .as_borrowed()
+                .segment_str(input)
+                .map(|i| (i, None))
+                .collect(),
+        };
+        // The first boundary is always at the start of the input.
+        let starts = boundaries.clone().into_iter().map(|(i, _)| i);
+        starts
+            .zip(boundaries.into_iter().skip(1))
+            .map(move |(start, (end, is_word_like))| Segment {
+                segment: input.get(start..end).unwrap_or_default(),
+                index: start,
+                is_word_like,
+            })
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::testing::TestLocale;
+
+    #[test]
+    fn segment() {
+        let segmenter = Segmenter::try_new(TestLocale("en"), Default::default()).unwrap();
+        assert_eq!(
+            segmenter
+                .segment("e\u{301}🇺🇸!")
+                .map(|s| (s.segment, s.index))
+                .collect::<Vec<_>>(),
+            [("e\u{301}", 0), ("🇺🇸", 3), ("!", 11)]
+        );
+
+        let segmenter = Segmenter::try_new(
+            TestLocale("en"),
+            Options {
+                granularity: options::Granularity::Word,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            segmenter
+                .segment("Hello, 42 worlds.")
+                .map(|s| (s.segment, s.is_word_like.unwrap()))
+                .collect::<Vec<_>>(),
+            [
+                ("Hello", true),
+                (",", false),
+                (" ", false),
+                ("42", true),
+                (" ", false),
+                ("worlds", true),
+                (".", false)
+            ]
+        );
+
+        let segmenter = Segmenter::try_new(
+            TestLocale("en"),
+            Options {
+                granularity: options::Granularity::Sentence,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            segmenter
+                .segment("One. Two? Three")
+                .map(|s| s.segment)
+                .collect::<Vec<_>>(),
+            ["One. ", "Two? ", "Three"]
+        );
+        assert_eq!(segmenter.segment("").count(), 0);
+    }
+}
@@ -2,6 +2,7 @@
 include!("calendar_japanese_extended_v1.rs.data");
 include!("calendar_japanese_modern_v1.rs.data");
 include!("calendar_week_v1.rs.data");
+include!("calendar_week_v2.rs.data");
 /// Marks a type as a data provider. You can then use macros like
 /// `impl_core_helloworld_v1` to add implementations.
 ///
@@ -39,5 +40,6 @@ macro_rules! impl_data_provider {
         impl_calendar_japanese_extended_v1!($provider);
         impl_calendar_japanese_modern_v1!($provider);
         impl_calendar_week_v1!($provider);
+        impl_calendar_week_v2!($provider);
     };
 }
@@ -121,6 +121,12 @@ include!("property_binary_default_ignorable_code_point_v1.rs.data");
 include!("property_binary_extended_pictographic_v1.rs.data");
 include!("property_name_parse_vertical_orientation_v1.rs.data");
 include!("property_name_parse_canonical_combining_class_v1.rs.data");
+include!("property_binary_emoji_keycap_sequence_v1.rs.data");
+include!("property_binary_rgi_emoji_flag_sequence_v1.rs.data");
+include!("property_binary_rgi_emoji_modifier_sequence_v1.rs.data");
+include!("property_binary_rgi_emoji_tag_sequence_v1.rs.data");
+include!("property_binary_rgi_emoji_v1.rs.data");
+include!("property_binary_rgi_emoji_zwj_sequence_v1.rs.data");
 /// Marks a type as a data provider. You can then use macros like
 /// `impl_core_helloworld_v1` to add implementations.
 ///
@@ -277,5 +283,11 @@ macro_rules! impl_data_provider {
         impl_property_binary_extended_pictographic_v1!($provider);
         impl_property_name_parse_vertical_orientation_v1!($provider);
         impl_property_name_parse_canonical_combining_class_v1!($provider);
+        impl_property_binary_emoji_keycap_sequence_v1!($provider);
+        impl_property_binary_rgi_emoji_flag_sequence_v1!($provider);
+        impl_property_binary_rgi_emoji_modifier_sequence_v1!($provider);
+        impl_property_binary_rgi_emoji_tag_sequence_v1!($provider);
+        impl_property_binary_rgi_emoji_v1!($provider);
+        impl_property_binary_rgi_emoji_zwj_sequence_v1!($provider);
     };
 }
@@ -6,6 +6,8 @@ use crate::datapath::marker_to_path;
 use crate::manifest::Manifest;
 use icu_provider::prelude::*;
 use icu_provider::DynamicDryDataProvider;
+use icu_provider::IterableDynamicDataProvider;
+use std::collections::BTreeSet;
 use std::fmt::Debug;
 use std::fmt::Write;
 use std::fs;
@@ -107,6 +109,14 @@ impl FsDataProvider {
         metadata.checksum = checksum;
         Ok((metadata, path))
     }
+
+    fn locale_from_file_name(&self, name: std::ffi::OsString) -> Option<DataLocale> {
+        name.to_str()?
+            .strip_suffix(self.manifest.file_extension)?
+            .strip_suffix('.')?
+            .parse()
+            .ok()
+    }
 }
 
 impl DynamicDataProvider<BufferMarker> for FsDataProvider {
@@ -133,3 +143,44 @@ impl DynamicDryDataProvider<BufferMarker> for FsDataProvider {
         Ok(self.dry_load_internal(marker, req)?.0)
     }
 }
+
+impl IterableDynamicDataProvider<BufferMarker> for FsDataProvider {
+    fn iter_ids_for_marker(
+        &self,
+        marker: DataMarkerInfo,
+    ) -> Result<BTreeSet<DataIdentifierCow<'_>>, DataError> {
+        let mut path = marker_to_path(marker.id, &self.root);
+        if marker.is_singleton {
+            path.set_extension(self.manifest.file_extension);
+            return if path.exists() {
+                Ok([Default::default()].into_iter().collect())
+            } else {
+                Err(DataErrorKind::MarkerNotFound.with_marker(marker))
+            };
+        }
+        if !path.is_dir() {
+            return Err(DataErrorKind::MarkerNotFound.with_marker(marker));
+        }
+        let mut ids = BTreeSet::new();
+        for entry in fs::read_dir(&path).map_err(|e| DataError::from(e).with_path_context(&path))? {
+            let entry = entry?;
+            let Ok(name) = entry.file_name().into_string() else {
+                continue;
+            };
+            if entry.file_type()?.is_dir() {
+                // Marker attributes are stored as directories containing the locale files
+                let Ok(attributes) = DataMarkerAttributes::try_from_str(&name) else {
+                    continue;
+                };
+                for entry in fs::read_dir(entry.path())? {
+                    if let Some(locale) = self.locale_from_file_name(entry?.file_name()) {
+                        ids.insert(DataIdentifierCow::from_owned(attributes.to_owned(), locale));
+                    }
+                }
+            } else if let Some(locale) = self.locale_from_file_name(name.into()) {
+                ids.insert(DataIdentifierCow::from_locale(locale));
+            }
+        }
+        Ok(ids)
+    }
+}
@@ -135,3 +135,25 @@ fn prefix_match() {
         .is_err());
     }
 }
+
+#[test]
+fn test_iter() {
+    for path in PATHS {
+        let provider = FsDataProvider::try_new(path.into()).unwrap();
+        assert_eq!(
+            provider.iter_ids_for_marker(HelloWorldV1::INFO).unwrap(),
+            HelloWorldProvider.iter_ids().unwrap(),
+            "{path}"
+        );
+
+        icu_provider::data_marker!(WrongV1, HelloWorld<'static>);
+
+        assert!(matches!(
+            provider.iter_ids_for_marker(WrongV1::INFO),
+            Err(DataError {
+                kind: DataErrorKind::MarkerNotFound,
+                ..
+            })
+        ));
+    }
+}
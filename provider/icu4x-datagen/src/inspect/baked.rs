@@ -0,0 +1,371 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Reads the `*.rs.data` files written by the baked exporter.
+//!
+//! Baked data cannot be loaded without compiling it, so payloads are represented by their
+//! Rust expressions, with insignificant whitespace removed. This makes the output of
+//! `--pretty` and non-`--pretty` exports comparable.
+
+use eyre::{eyre, WrapErr};
+use icu_provider::prelude::*;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// The contents of a single baked data marker.
+pub(super) struct BakedMarker {
+    pub(super) checksum: Option<u64>,
+    pub(super) payloads: BTreeMap<DataIdentifierCow<'static>, String>,
+}
+
+/// Reads all `*.rs.data` files in the given directory, keyed by unqualified marker name.
+pub(super) fn read(root: &Path) -> eyre::Result<BTreeMap<String, BakedMarker>> {
+    let mut markers = BTreeMap::new();
+    for entry in std::fs::read_dir(root).wrap_err_with(|| root.display().to_string())? {
+        let path = entry?.path();
+        if !path.to_string_lossy().ends_with(".rs.data") {
+            continue;
+        }
+        let source = std::fs::read_to_string(&path)?;
+        let (name, marker) = parse(&normalize(&source))
+            .wrap_err_with(|| format!("Cannot parse {}", path.display()))?;
+        markers.insert(name, marker);
+    }
+    Ok(markers)
+}
+
+/// Removes comments and all whitespace that is not required to separate tokens.
+fn normalize(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut rest = source;
+    let mut pending_space = false;
+    while let Some(c) = rest.chars().next() {
+        let len = if rest.starts_with("//") {
+            pending_space = true;
+            rest = &rest[rest.find('\n').unwrap_or(rest.len())..];
+            continue;
+        } else if c.is_whitespace() {
+            pending_space = true;
+            rest = &rest[c.len_utf8()..];
+            continue;
+        } else if let Some(len) = literal_len(rest) {
+            len
+        } else if is_ident_char(c) {
+            rest.find(|c| !is_ident_char(c)).unwrap_or(rest.len())
+        } else {
+            c.len_utf8()
+        };
+        if pending_space && is_ident_char(c) && out.chars().last().is_some_and(is_ident_char) {
+            out.push(' ');
+        }
+        out.push_str(&rest[..len]);
+        rest = &rest[len..];
+        pending_space = false;
+    }
+    out
+}
+
+/// Returns the length of the string, raw string, or character literal (including byte
+/// literals) at the start of `s`, or `None` if `s` does not start with a literal.
+///
+/// Lifetimes are not literals.
+fn literal_len(s: &str) -> Option<usize> {
+    let prefix = usize::from(s.starts_with('b'));
+    let body = &s[prefix..];
+    if let Some(raw) = body.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let terminator = format!("\"{}", "#".repeat(hashes));
+        let contents = raw[hashes..].strip_prefix('"')?;
+        let end = contents.find(&terminator)?;
+        return Some(s.len() - contents.len() + end + terminator.len());
+    }
+    let (quote, contents) = match body.chars().next()? {
+        q @ ('"' | '\'') => (q, &body[1..]),
+        _ => return None,
+    };
+    let mut chars = contents.char_indices();
+    if quote == '\'' {
+        // `'x'` or `'\…'`, as opposed to a lifetime `'x`
+        match chars.next()? {
+            (_, '\\') => {}
+            (_, c) => {
+                return (contents[c.len_utf8()..].starts_with('\''))
+                    .then(|| s.len() - contents.len() + c.len_utf8() + 1)
+            }
+        }
+        chars.next()?;
+    }
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            c if c == quote => return Some(s.len() - contents.len() + i + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn parse(s: &str) -> eyre::Result<(String, BakedMarker)> {
+    let marker_start = find_after(s, 0, "impl icu_provider::DataProvider<")
+        .ok_or_else(|| eyre!("No DataProvider implementation found"))?;
+    let marker_end = find_after(s, marker_start, ">for$provider")
+        .ok_or_else(|| eyre!("Invalid DataProvider implementation"))?
+        - ">for$provider".len();
+    let marker_path = &s[marker_start..marker_end];
+    let name = marker_path
+        .rsplit("::")
+        .next()
+        .unwrap_or(marker_path)
+        .to_string();
+
+    let checksum = find_after(s, 0, "_CHECKSUM:u64=")
+        .or_else(|| find_after(s, 0, "with_checksum("))
+        .and_then(|i| {
+            s[i..]
+                .split(|c: char| !c.is_ascii_digit())
+                .next()?
+                .parse()
+                .ok()
+        });
+
+    let mut payloads = BTreeMap::new();
+
+    if let Some(i) = find_after(s, 0, "pub const SINGLETON_") {
+        let start = find_after(s, i, "=&").ok_or_else(|| eyre!("Invalid singleton"))?;
+        let end = scan_expression(s, start);
+        payloads.insert(Default::default(), s[start..end].to_string());
+    } else if let Some(i) = find_after(s, 0, "from_trie_and_refs_unchecked(") {
+        // Data stored as `pub const`s, referenced through `Self::IDENT`
+        let (trie, trie_end) = byte_string_after(s, i)?;
+        let refs_start =
+            find_after(s, trie_end, "&[").ok_or_else(|| eyre!("Invalid references"))?;
+        let refs = split_list(s, refs_start);
+        let mut consts = BTreeMap::new();
+        let mut i = 0;
+        while let Some(start) = find_after(s, i, "pub const ") {
+            let ident_end = start + s[start..].find(':').unwrap_or(0);
+            let expr_start = find_after(s, ident_end, "=").unwrap_or(ident_end);
+            let expr_end = scan_expression(s, expr_start);
+            consts.insert(&s[start..ident_end], &s[expr_start..expr_end]);
+            i = expr_end;
+        }
+        let values = refs
+            .into_iter()
+            .map(|mut expr| {
+                while let Some(alias) = expr.strip_prefix("Self::") {
+                    expr = consts.get(alias).copied().unwrap_or_default();
+                }
+                expr.strip_prefix('&').unwrap_or(expr).to_string()
+            })
+            .collect::<Vec<_>>();
+        insert_all(&mut payloads, &trie, &values)?;
+    } else if let Some(i) = find_after(s, 0, "const VALUES:") {
+        let (trie, _) = byte_string_after(s, find_after(s, 0, "const TRIE:").unwrap_or(0))?;
+        let start = find_after(s, i, "=").ok_or_else(|| eyre!("Invalid values"))?;
+        let values = if s[i..start].contains("VarZeroSlice") {
+            // Values encoded as a `VarZeroSlice`. Show each element as a byte string.
+            let (bytes, _) = byte_string_after(s, start)?;
+            zerovec::VarZeroSlice::<[u8]>::parse_bytes(&bytes)
+                .map_err(|e| eyre!("Invalid VarZeroSlice: {e:?}"))?
+                .iter()
+                .map(|b| format!("b\"{}\"", b.escape_ascii()))
+                .collect()
+        } else {
+            split_list(s, start + 2)
+                .into_iter()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        };
+        insert_all(&mut payloads, &trie, &values)?;
+    }
+
+    Ok((name, BakedMarker { checksum, payloads }))
+}
+
+/// Returns the index directly after the first occurrence of `pattern` at or after `from`.
+fn find_after(s: &str, from: usize, pattern: &str) -> Option<usize> {
+    Some(from + s.get(from..)?.find(pattern)? + pattern.len())
+}
+
+/// Decodes the first byte string literal at or after `from`, returning the
+/// bytes and the index after the literal.
+fn byte_string_after(s: &str, from: usize) -> eyre::Result<(Vec<u8>, usize)> {
+    let start = find_after(s, from, "b\"").ok_or_else(|| eyre!("No byte string found"))?;
+    let mut bytes = Vec::new();
+    let mut chars = s[start..].char_indices();
+    while let Some((_, c)) = chars.next() {
+        match c {
+            '"' => return Ok((bytes, start + chars.offset())),
+            '\\' => match chars.next().map(|(_, c)| c) {
+                Some('x') => {
+                    let hex = chars.by_ref().take(2).map(|(_, c)| c).collect::<String>();
+                    bytes.push(u8::from_str_radix(&hex, 16)?);
+                }
+                Some('0') => bytes.push(0),
+                Some('n') => bytes.push(b'\n'),
+                Some('r') => bytes.push(b'\r'),
+                Some('t') => bytes.push(b'\t'),
+                Some(c @ ('\\' | '"' | '\'')) => bytes.push(c as u8),
+                c => eyre::bail!("Unknown escape sequence {c:?}"),
+            },
+            c => {
+                let mut buf = [0; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            }
+        }
+    }
+    eyre::bail!("Unterminated byte string")
+}
+
+/// Returns the end of the expression starting at `start`, which is the first top-level
+/// `,`, `;`, or unbalanced closing bracket.
+fn scan_expression(s: &str, start: usize) -> usize {
+    let bytes = s.as_bytes();
+    let mut depth = 0usize;
+    let mut i = start;
+    while let Some(&b) = bytes.get(i) {
+        // Literals, but not identifiers ending in `b` or `r`
+        if matches!(b, b'"' | b'\'')
+            || matches!(b, b'b' | b'r') && (i == 0 || !is_ident_char(char::from(bytes[i - 1])))
+        {
+            if let Some(len) = literal_len(&s[i..]) {
+                i += len;
+                continue;
+            }
+        }
+        match b {
+            b'(' | b'[' | b'{' => depth += 1,
+            b'<' if i > 0 && !matches!(bytes[i - 1], b'-' | b'=') => depth += 1,
+            b'>' if i > 0 && !matches!(bytes[i - 1], b'-' | b'=') => {
+                depth = depth.saturating_sub(1)
+            }
+            b')' | b']' | b'}' if depth == 0 => return i,
+            b')' | b']' | b'}' => depth -= 1,
+            b',' | b';' if depth == 0 => return i,
+            _ => {}
+        }
+        i += 1;
+    }
+    i
+}
+
+/// Splits the bracketed list starting at `start` (directly after the opening bracket)
+/// into its elements.
+fn split_list(s: &str, start: usize) -> Vec<&str> {
+    let mut elements = Vec::new();
+    let mut i = start;
+    while s.as_bytes().get(i).is_some_and(|&b| b != b']') {
+        let end = scan_expression(s, i);
+        if end > i {
+            elements.push(&s[i..end]);
+        }
+        i = end;
+        if s.as_bytes().get(i) == Some(&b',') {
+            i += 1;
+        }
+    }
+    elements
+}
+
+fn insert_all(
+    payloads: &mut BTreeMap<DataIdentifierCow<'static>, String>,
+    trie: &[u8],
+    values: &[String],
+) -> eyre::Result<()> {
+    for (key, index) in zerotrie::ZeroTrieSimpleAscii::from_store(trie).iter() {
+        let id = if let Some((locale, attributes)) =
+            key.split_once(icu_provider::baked::zerotrie::ID_SEPARATOR as char)
+        {
+            DataIdentifierCow::from_owned(
+                DataMarkerAttributes::try_from_str(attributes)
+                    .map_err(|_| eyre!("Invalid attributes {attributes:?}"))?
+                    .to_owned(),
+                locale.parse()?,
+            )
+        } else {
+            DataIdentifierCow::from_locale(key.parse()?)
+        };
+        let value = values
+            .get(index)
+            .ok_or_else(|| eyre!("Index {index} out of bounds for {key:?}"))?;
+        payloads.insert(id, value.clone());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_normalize() {
+    assert_eq!(
+        normalize("// @generated\nimpl icu_provider :: DataProvider < a :: B > for $ provider {\n    x: \"a  b\" }"),
+        "impl icu_provider::DataProvider<a::B>for$provider{x:\"a  b\"}"
+    );
+    assert_eq!(
+        normalize("f( '\"' , \"a  b\" )\n// c\ng( '\\'' , b'\"' , & 'static x )"),
+        "f('\"',\"a  b\")g('\\'',b'\"',&'static x)"
+    );
+    assert_eq!(
+        normalize("f( r#\" \"a  \" \"# , r\"b  \\\" , br\" c \" , r#type )"),
+        "f(r#\" \"a  \" \"#,r\"b  \\\",br\" c \",r#type)"
+    );
+}
+
+#[test]
+fn test_scan_expression() {
+    let s = normalize("x( '\"' , r#\"a, \"b)\"# ) , y");
+    assert_eq!(&s[..scan_expression(&s, 0)], "x('\"',r#\"a, \"b)\"#)");
+}
+
+#[test]
+fn test_parse() {
+    use icu_provider::hello_world::HelloWorldV1;
+
+    let trie = zerotrie::ZeroTrieSimpleAscii::from_iter([("de", 0usize), ("en", 1), ("en-GB", 1)]);
+    let source = format!(
+        r#"
+        impl $provider {{
+            const DATA_HELLO_WORLD_V1: icu_provider::baked::zerotrie::Data<icu_provider::hello_world::HelloWorldV1> = {{
+                const TRIE: icu_provider::baked::zerotrie::ZeroTrieSimpleAscii<&'static [u8]> = icu_provider::baked::zerotrie::ZeroTrieSimpleAscii {{ store: b"{}" }};
+                const VALUES: &'static [<icu_provider::hello_world::HelloWorldV1 as icu_provider::baked::zerotrie::DynamicDataMarker>::DataStruct] = &[
+                    icu_provider::hello_world::HelloWorld {{ message: alloc::borrow::Cow::Borrowed("Hallo, Welt") }},
+                    icu_provider::hello_world::HelloWorld {{ message: alloc::borrow::Cow::Borrowed("Hello, World") }},
+                ];
+                unsafe {{ icu_provider::baked::zerotrie::Data::from_trie_and_values_unchecked(TRIE, VALUES) }}
+            }};
+        }}
+        impl icu_provider::DataProvider<icu_provider::hello_world::HelloWorldV1> for $provider {{
+            fn load(&self, req: icu_provider::DataRequest) -> Result<icu_provider::DataResponse<icu_provider::hello_world::HelloWorldV1>, icu_provider::DataError> {{
+                let metadata = icu_provider::DataResponseMetadata::default().with_checksum(1234u64);
+            }}
+        }}
+        "#,
+        trie.as_bytes().escape_ascii()
+    );
+
+    let (name, marker) = parse(&normalize(&source)).unwrap();
+    assert_eq!(name, format!("{:?}", HelloWorldV1::INFO));
+    assert_eq!(marker.checksum, Some(1234));
+    assert_eq!(
+        marker.payloads.into_iter().collect::<Vec<_>>(),
+        vec![
+            (
+                DataIdentifierCow::from_locale("de".parse().unwrap()),
+                "icu_provider::hello_world::HelloWorld{message:alloc::borrow::Cow::Borrowed(\"Hallo, Welt\")}".into()
+            ),
+            (
+                DataIdentifierCow::from_locale("en".parse().unwrap()),
+                "icu_provider::hello_world::HelloWorld{message:alloc::borrow::Cow::Borrowed(\"Hello, World\")}".into()
+            ),
+            (
+                DataIdentifierCow::from_locale("en-GB".parse().unwrap()),
+                "icu_provider::hello_world::HelloWorld{message:alloc::borrow::Cow::Borrowed(\"Hello, World\")}".into()
+            ),
+        ]
+    );
+}
@@ -0,0 +1,528 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The `inspect` and `diff` subcommands, which read previously exported data.
+//!
+//! Blob and fs data are deserialized into their data structs and compared as JSON,
+//! so data sets in different formats (or fs syntaxes) can be compared semantically.
+//! Baked data is compared by its Rust expressions, see [`baked`].
+
+mod baked;
+
+use crate::{marker_lookup, ReexportableBufferProvider};
+use clap::{Args, Subcommand};
+use eyre::WrapErr;
+use icu_provider::export::ExportableProvider;
+use icu_provider::prelude::*;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+#[derive(Subcommand)]
+pub(crate) enum Command {
+    #[command(
+        about = "List the markers, identifiers, payload sizes, and checksums of exported data."
+    )]
+    Inspect(InspectArgs),
+    #[command(
+        about = "Compare two exported data sets by marker and identifier. Exits with status 1 if they differ."
+    )]
+    Diff(DiffArgs),
+}
+
+#[derive(Args)]
+pub(crate) struct InspectArgs {
+    #[arg(help = "Path to a blob file, or to the root directory of fs or baked data.")]
+    path: PathBuf,
+
+    #[command(flatten)]
+    filter: FilterArgs,
+
+    #[arg(long)]
+    #[arg(help = "Only print one line per marker.")]
+    summary: bool,
+}
+
+#[derive(Args)]
+pub(crate) struct DiffArgs {
+    #[arg(help = "Path to the old data: a blob file, or the root directory of fs or baked data.")]
+    old: PathBuf,
+
+    #[arg(help = "Path to the new data: a blob file, or the root directory of fs or baked data.")]
+    new: PathBuf,
+
+    #[command(flatten)]
+    filter: FilterArgs,
+
+    #[arg(long)]
+    #[arg(help = "Only list changed identifiers, without the changes to their payloads.")]
+    summary: bool,
+}
+
+#[derive(Args)]
+struct FilterArgs {
+    #[arg(long, short, num_args = 1..)]
+    #[arg(help = "Only include these data markers. Accepts multiple arguments.")]
+    markers: Vec<String>,
+
+    #[arg(long, short, num_args = 1..)]
+    #[arg(help = "Only include identifiers with these locales. Accepts multiple arguments.")]
+    locales: Vec<String>,
+}
+
+pub(crate) fn run(command: Command) -> eyre::Result<()> {
+    match command {
+        Command::Inspect(args) => inspect(args),
+        Command::Diff(args) => {
+            if diff(args)? {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+    }
+}
+
+fn inspect(args: InspectArgs) -> eyre::Result<()> {
+    let data = DataSet::open(&args.path)?;
+    let filter = Filter::new(&args.filter)?;
+    let mut out = std::io::stdout().lock();
+
+    for marker in data.markers().into_iter().filter(|&m| filter.marker(m)) {
+        let mut total_size = 0;
+        let mut lines = Vec::new();
+        // Identical payloads are only listed once, as in `fingerprints.csv`
+        let mut seen = HashMap::new();
+        let ids = data.ids(marker)?;
+        for id in ids.iter().filter(|id| filter.id(id)) {
+            let bytes = data.bytes(marker, id)?;
+            total_size += bytes.len();
+            let hash = twox_hash::XxHash64::oneshot(0, &bytes);
+            let id = id_to_string(id);
+            if let Some(deduped) = seen.get(&hash) {
+                lines.push(format!("  {id}: -> {deduped}"));
+            } else {
+                lines.push(format!("  {id}: {}B, {hash:016x}", bytes.len()));
+                seen.insert(hash, id);
+            }
+        }
+        let checksum = match data.checksum(marker, &ids)? {
+            Some(checksum) => format!(", checksum {checksum}"),
+            None => String::new(),
+        };
+        writeln!(
+            out,
+            "{marker:?}: {} identifiers, {} unique payloads, {total_size}B{checksum}",
+            lines.len(),
+            seen.len(),
+        )?;
+        if !args.summary {
+            for line in lines {
+                writeln!(out, "{line}")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns whether there were any differences.
+fn diff(args: DiffArgs) -> eyre::Result<bool> {
+    let old = DataSet::open(&args.old)?;
+    let new = DataSet::open(&args.new)?;
+    if matches!(old, DataSet::Baked(_)) != matches!(new, DataSet::Baked(_)) {
+        eyre::bail!("Baked data can only be compared with baked data");
+    }
+    let filter = Filter::new(&args.filter)?;
+    let mut out = std::io::stdout().lock();
+    let mut differences = false;
+
+    let old_markers = old.markers();
+    let new_markers = new.markers();
+
+    for &marker in old_markers
+        .union(&new_markers)
+        .filter(|&&m| filter.marker(m))
+    {
+        differences |= match (old_markers.contains(&marker), new_markers.contains(&marker)) {
+            (true, false) => {
+                writeln!(out, "- {marker:?}")?;
+                true
+            }
+            (false, true) => {
+                writeln!(out, "+ {marker:?}")?;
+                true
+            }
+            _ => diff_marker(&old, &new, marker, &filter, args.summary, &mut out)?,
+        }
+    }
+    Ok(differences)
+}
+
+fn diff_marker(
+    old: &DataSet,
+    new: &DataSet,
+    marker: DataMarkerInfo,
+    filter: &Filter,
+    summary: bool,
+    out: &mut impl Write,
+) -> eyre::Result<bool> {
+    let mut differences = false;
+    let old_ids = old.ids(marker)?;
+    let new_ids = new.ids(marker)?;
+
+    let (old_checksum, new_checksum) = (
+        old.checksum(marker, &old_ids)?,
+        new.checksum(marker, &new_ids)?,
+    );
+    if old_checksum != new_checksum {
+        let show = |c: Option<u64>| c.map(|c| c.to_string()).unwrap_or_else(|| "none".into());
+        writeln!(
+            out,
+            "~ {marker:?}: checksum {} -> {}",
+            show(old_checksum),
+            show(new_checksum)
+        )?;
+        differences = true;
+    }
+
+    for id in old_ids.union(&new_ids).filter(|id| filter.id(id)) {
+        let id_str = id_to_string(id);
+        match (old_ids.contains(id), new_ids.contains(id)) {
+            (true, false) => writeln!(out, "- {marker:?} {id_str}")?,
+            (false, true) => writeln!(out, "+ {marker:?} {id_str}")?,
+            _ => {
+                if old.bytes(marker, id)? == new.bytes(marker, id)? {
+                    continue;
+                }
+                let deltas = match (old.content(marker, id)?, new.content(marker, id)?) {
+                    (Content::Json(a), Content::Json(b)) => {
+                        let mut deltas = Vec::new();
+                        json_deltas(&mut String::new(), &a, &b, &mut deltas);
+                        deltas
+                    }
+                    (Content::Baked(a), Content::Baked(b)) => baked_delta(&a, &b),
+                    _ => unreachable!("checked in diff()"),
+                };
+                if deltas.is_empty() {
+                    // Same data in a different encoding
+                    continue;
+                }
+                writeln!(out, "~ {marker:?} {id_str}")?;
+                if !summary {
+                    for delta in deltas {
+                        writeln!(out, "    {delta}")?;
+                    }
+                }
+            }
+        }
+        differences = true;
+    }
+    Ok(differences)
+}
+
+/// Collects the differences between two JSON values as human-readable lines,
+/// identifying values by a path such as `.patterns[0].start`.
+fn json_deltas(path: &mut String, old: &Value, new: &Value, deltas: &mut Vec<String>) {
+    let len = path.len();
+    match (old, new) {
+        (Value::Object(a), Value::Object(b)) => {
+            for key in a.keys().chain(b.keys()).collect::<BTreeSet<_>>() {
+                path.push('.');
+                path.push_str(key);
+                match (a.get(key), b.get(key)) {
+                    (Some(a), Some(b)) => json_deltas(path, a, b, deltas),
+                    (Some(a), None) => deltas.push(format!("- {path}: {a}")),
+                    (None, Some(b)) => deltas.push(format!("+ {path}: {b}")),
+                    (None, None) => unreachable!("key comes from one of the maps"),
+                }
+                path.truncate(len);
+            }
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            for i in 0..a.len().max(b.len()) {
+                use std::fmt::Write;
+                let _infallible = write!(path, "[{i}]");
+                match (a.get(i), b.get(i)) {
+                    (Some(a), Some(b)) => json_deltas(path, a, b, deltas),
+                    (Some(a), None) => deltas.push(format!("- {path}: {a}")),
+                    (None, Some(b)) => deltas.push(format!("+ {path}: {b}")),
+                    (None, None) => unreachable!("index is in bounds of one of the arrays"),
+                }
+                path.truncate(len);
+            }
+        }
+        (a, b) if a == b => {}
+        (a, b) => deltas.push(format!(
+            "~ {}: {a} -> {b}",
+            if path.is_empty() { "." } else { path }
+        )),
+    }
+}
+
+/// Shows the part of two baked expressions that differs, with some context.
+fn baked_delta(old: &str, new: &str) -> Vec<String> {
+    const CONTEXT: usize = 40;
+    let prefix = old
+        .char_indices()
+        .zip(new.chars())
+        .find(|&((_, a), b)| a != b)
+        .map(|((i, _), _)| i)
+        .unwrap_or(old.len().min(new.len()));
+    let suffix = old[prefix..]
+        .chars()
+        .rev()
+        .zip(new[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum::<usize>();
+    let context_start = old[..prefix]
+        .char_indices()
+        .rev()
+        .nth(CONTEXT - 1)
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    let excerpt = |s: &str| {
+        let end = s.len() - suffix;
+        let context_end = s[end..]
+            .char_indices()
+            .nth(CONTEXT)
+            .map(|(i, _)| end + i)
+            .unwrap_or(s.len());
+        format!(
+            "{}{}{}",
+            if context_start > 0 { "…" } else { "" },
+            &s[context_start..context_end],
+            if context_end < s.len() { "…" } else { "" }
+        )
+    };
+    vec![format!("- {}", excerpt(old)), format!("+ {}", excerpt(new))]
+}
+
+fn id_to_string(id: &DataIdentifierCow) -> String {
+    if id.marker_attributes.is_empty() {
+        id.locale.to_string()
+    } else {
+        format!("{}/{}", id.locale, id.marker_attributes.as_str())
+    }
+}
+
+struct Filter {
+    markers: Option<BTreeSet<DataMarkerInfo>>,
+    locales: Option<BTreeSet<String>>,
+}
+
+impl Filter {
+    fn new(args: &FilterArgs) -> eyre::Result<Self> {
+        Ok(Self {
+            markers: if args.markers.is_empty() {
+                None
+            } else {
+                Some(
+                    args.markers
+                        .iter()
+                        .map(|k| match marker_lookup().get(k.as_str()) {
+                            Some(Some(marker)) => Ok(*marker),
+                            Some(None) => {
+                                eyre::bail!("Marker {k:?} requires `experimental` Cargo feature")
+                            }
+                            None => eyre::bail!("Unknown marker {k:?}"),
+                        })
+                        .collect::<Result<_, _>>()?,
+                )
+            },
+            locales: if args.locales.is_empty() {
+                None
+            } else {
+                Some(
+                    args.locales
+                        .iter()
+                        .map(|l| {
+                            Ok(l.parse::<DataLocale>()
+                                .wrap_err_with(|| l.clone())?
+                                .to_string())
+                        })
+                        .collect::<eyre::Result<_>>()?,
+                )
+            },
+        })
+    }
+
+    fn marker(&self, marker: DataMarkerInfo) -> bool {
+        self.markers.as_ref().is_none_or(|m| m.contains(&marker))
+    }
+
+    fn id(&self, id: &DataIdentifierCow) -> bool {
+        self.locales
+            .as_ref()
+            .is_none_or(|l| l.contains(&id.locale.to_string()))
+    }
+}
+
+enum DataSet {
+    Buffer(ReexportableBufferProvider),
+    Baked(BTreeMap<DataMarkerInfo, baked::BakedMarker>),
+}
+
+enum Content {
+    Json(Value),
+    Baked(String),
+}
+
+impl DataSet {
+    /// Opens a blob file, an fs directory (identified by its `manifest.json`),
+    /// or a baked directory (identified by its `mod.rs`).
+    fn open(path: &Path) -> eyre::Result<Self> {
+        if path.is_file() {
+            Ok(Self::Buffer(ReexportableBufferProvider(Box::new(
+                icu_provider_blob::BlobDataProvider::try_new_from_blob(
+                    std::fs::read(path)
+                        .wrap_err_with(|| path.display().to_string())?
+                        .into(),
+                )?,
+            ))))
+        } else if path.join("manifest.json").exists() {
+            Ok(Self::Buffer(ReexportableBufferProvider(Box::new(
+                icu_provider_fs::FsDataProvider::try_new(path.to_path_buf())?,
+            ))))
+        } else if path.join("mod.rs").exists() {
+            baked::read(path)?
+                .into_iter()
+                .map(|(name, data)| match marker_lookup().get(&name) {
+                    Some(&Some(marker)) => Ok((marker, data)),
+                    Some(None) => {
+                        eyre::bail!("Marker {name:?} requires `experimental` Cargo feature")
+                    }
+                    None => eyre::bail!("Unknown marker {name:?}"),
+                })
+                .collect::<eyre::Result<_>>()
+                .map(Self::Baked)
+        } else {
+            eyre::bail!(
+                "{} is neither a blob file, nor a directory containing fs or baked data",
+                path.display()
+            )
+        }
+    }
+
+    fn markers(&self) -> BTreeSet<DataMarkerInfo> {
+        match self {
+            Self::Buffer(provider) => provider
+                .supported_markers()
+                .into_iter()
+                .filter(|&m| provider.iter_ids_for_marker(m).is_ok())
+                .collect(),
+            Self::Baked(markers) => markers.keys().copied().collect(),
+        }
+    }
+
+    fn ids(&self, marker: DataMarkerInfo) -> eyre::Result<BTreeSet<DataIdentifierCow<'static>>> {
+        Ok(match self {
+            Self::Buffer(provider) => provider
+                .iter_ids_for_marker(marker)?
+                .into_iter()
+                .map(|id| id.as_borrowed().into_owned())
+                .collect(),
+            Self::Baked(markers) => markers
+                .get(&marker)
+                .map(|m| m.payloads.keys().cloned().collect())
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Returns the checksum from the [`DataResponseMetadata`], which is per marker.
+    fn checksum(
+        &self,
+        marker: DataMarkerInfo,
+        ids: &BTreeSet<DataIdentifierCow>,
+    ) -> eyre::Result<Option<u64>> {
+        Ok(match self {
+            Self::Buffer(provider) => match ids.first() {
+                Some(id) => provider.0.load_data(marker, request(id))?.metadata.checksum,
+                None => None,
+            },
+            Self::Baked(markers) => markers.get(&marker).and_then(|m| m.checksum),
+        })
+    }
+
+    /// Returns the payload in its stored form.
+    fn bytes(&self, marker: DataMarkerInfo, id: &DataIdentifierCow) -> eyre::Result<Vec<u8>> {
+        Ok(match self {
+            Self::Buffer(provider) => provider
+                .0
+                .load_data(marker, request(id))?
+                .payload
+                .get()
+                .to_vec(),
+            Self::Baked(markers) => markers
+                .get(&marker)
+                .and_then(|m| m.payloads.get(id))
+                .map(|p| p.as_bytes().to_vec())
+                .unwrap_or_default(),
+        })
+    }
+
+    fn content(&self, marker: DataMarkerInfo, id: &DataIdentifierCow) -> eyre::Result<Content> {
+        Ok(match self {
+            Self::Buffer(provider) => {
+                let mut json = Vec::new();
+                provider
+                    .load_data(marker, request(id))?
+                    .payload
+                    .serialize(&mut serde_json::Serializer::new(&mut json))?;
+                Content::Json(serde_json::from_slice(&json)?)
+            }
+            Self::Baked(_) => Content::Baked(String::from_utf8(self.bytes(marker, id)?)?),
+        })
+    }
+}
+
+fn request<'a>(id: &'a DataIdentifierCow) -> DataRequest<'a> {
+    DataRequest {
+        id: id.as_borrowed(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_json_deltas() {
+    let old = serde_json::json!({ "a": [1, 2], "b": { "c": "x" }, "d": true });
+    let new = serde_json::json!({ "a": [1, 3, 4], "b": { "c": "y" }, "e": null });
+    let mut deltas = Vec::new();
+    json_deltas(&mut String::new(), &old, &new, &mut deltas);
+    assert_eq!(
+        deltas,
+        [
+            "~ .a[1]: 2 -> 3",
+            "+ .a[2]: 4",
+            r#"~ .b.c: "x" -> "y""#,
+            "- .d: true",
+            "+ .e: null",
+        ]
+    );
+
+    let mut deltas = Vec::new();
+    json_deltas(
+        &mut String::new(),
+        &Value::from(1),
+        &Value::from(2),
+        &mut deltas,
+    );
+    assert_eq!(deltas, ["~ .: 1 -> 2"]);
+}
+
+#[test]
+fn test_baked_delta() {
+    assert_eq!(
+        baked_delta("Foo{a:1,b:2}", "Foo{a:1,b:3}"),
+        ["- Foo{a:1,b:2}", "+ Foo{a:1,b:3}"]
+    );
+    let long = "x".repeat(100);
+    assert_eq!(
+        baked_delta(&format!("{long}1{long}"), &format!("{long}22{long}")),
+        [
+            format!("- …{}1{}…", "x".repeat(40), "x".repeat(40)),
+            format!("+ …{}22{}…", "x".repeat(40), "x".repeat(40)),
+        ]
+    );
+}
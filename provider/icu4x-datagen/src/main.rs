@@ -9,6 +9,13 @@
 //! $ icu4x-datagen --markers all --locales de en-AU --format blob --out data.postcard
 //! ```
 //!
+//! Existing data (blob, fs, or baked) can be examined and compared with the `inspect` and `diff` subcommands:
+//!
+//! ```bash
+//! $ icu4x-datagen inspect data.postcard --markers HelloWorldV1
+//! $ icu4x-datagen diff old.postcard data.postcard --summary
+//! ```
+//!
 //! More details can be found by running `--help`, or by consulting the [`icu_provider_export`] documentation.
 
 // If no exporter feature is enabled this all doesn't make sense
@@ -39,8 +46,12 @@ use simple_logger::SimpleLogger;
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+#[cfg(feature = "inspect")]
+mod inspect;
+
 #[derive(Parser)]
 #[command(name = "icu4x-datagen")]
+#[command(subcommand_negates_reqs = true)]
 #[command(author = "The ICU4X Project Developers", version = option_env!("CARGO_PKG_VERSION"))]
 #[command(about = format!("Learn more at: https://docs.rs/icu_provider_export/{}", option_env!("CARGO_PKG_VERSION").unwrap_or("")), long_about = None)]
 struct Cli {
@@ -48,11 +59,15 @@ struct Cli {
     #[arg(help = "Requests verbose output")]
     verbose: bool,
 
-    #[arg(long, value_enum)]
+    #[command(subcommand)]
+    #[cfg(feature = "inspect")]
+    command: Option<inspect::Command>,
+
+    #[arg(long, value_enum, required = true)]
     #[arg(
         help = "Select the output format: a directory tree of files (fs), a single blob (blob), or a Rust module (baked)."
     )]
-    format: Format,
+    format: Option<Format>,
 
     #[arg(short = 'W', long)]
     #[arg(help = "Delete the output before writing data.")]
@@ -138,6 +153,57 @@ struct Cli {
     #[cfg(feature = "provider")]
     tzdb_root: Option<PathBuf>,
 
+    #[arg(long, value_name = "TAG", default_value = "latest")]
+    #[arg(
+        help = "Download the Unicode Character Database of this version (https://www.unicode.org/Public/)\n\
+                  Use 'latest' for the latest version verified to work with this version of the binary.\n\
+                  Ignored if '--ucd-root' is present. Requires binary to be built with `networking` Cargo feature (enabled by default)."
+    )]
+    #[cfg_attr(not(feature = "networking"), arg(hide = true))]
+    #[cfg(feature = "provider")]
+    ucd_tag: String,
+
+    #[arg(long, value_name = "PATH")]
+    #[arg(
+        help = "Path to a local Unicode Character Database directory (see https://www.unicode.org/Public/)."
+    )]
+    #[cfg(feature = "provider")]
+    ucd_root: Option<PathBuf>,
+
+    #[arg(long, value_name = "TAG", default_value = "latest")]
+    #[arg(
+        help = "Download Unicode emoji data of this version (https://www.unicode.org/Public/emoji/)\n\
+                  Use 'latest' for the latest version verified to work with this version of the binary.\n\
+                  Ignored if '--emoji-root' is present. Requires binary to be built with `networking` Cargo feature (enabled by default)."
+    )]
+    #[cfg_attr(not(feature = "networking"), arg(hide = true))]
+    #[cfg(feature = "provider")]
+    emoji_tag: String,
+
+    #[arg(long, value_name = "PATH")]
+    #[arg(
+        help = "Path to a local directory with Unicode emoji data files (see https://www.unicode.org/Public/emoji/)."
+    )]
+    #[cfg(feature = "provider")]
+    emoji_root: Option<PathBuf>,
+
+    #[arg(long, value_name = "TAG", default_value = "latest")]
+    #[arg(
+        help = "Download CLDR XML data of this release (https://unicode.org/Public/cldr/)\n\
+                  Use 'latest' for the latest version verified to work with this version of the binary.\n\
+                  Ignored if '--cldr-common-root' is present. Requires binary to be built with `networking` Cargo feature (enabled by default)."
+    )]
+    #[cfg_attr(not(feature = "networking"), arg(hide = true))]
+    #[cfg(feature = "provider")]
+    cldr_common_tag: String,
+
+    #[arg(long, value_name = "PATH")]
+    #[arg(
+        help = "Path to a local CLDR XML directory containing `common` (see https://unicode.org/Public/cldr/)."
+    )]
+    #[cfg(feature = "provider")]
+    cldr_common_root: Option<PathBuf>,
+
     #[arg(long, value_enum, default_value_t = TrieType::Small)]
     #[arg(
         help = "Whether to optimize CodePointTrie data structures for size (\"small\") or speed (\"fast\").\n\
@@ -289,6 +355,15 @@ fn main() -> eyre::Result<()> {
             .unwrap()
     }
 
+    #[cfg(feature = "inspect")]
+    if let Some(command) = cli.command {
+        return inspect::run(command);
+    }
+
+    let Some(format) = cli.format else {
+        eyre::bail!("--format is required");
+    };
+
     let markers = if !cli.markers.is_empty() {
         match cli.markers.as_slice() {
             [x] if x == "none" => Default::default(),
@@ -351,6 +426,18 @@ fn main() -> eyre::Result<()> {
             eyre::bail!(
                 "Timezone data is required for this invocation, set --tzdb-path or --tzdb-tag"
             );
+        } else if SourceDataProvider::is_missing_ucd_error(e) {
+            eyre::bail!(
+                "Unicode Character Database data is required for this invocation, set --ucd-root or --ucd-tag"
+            );
+        } else if SourceDataProvider::is_missing_emoji_error(e) {
+            eyre::bail!(
+                "Unicode emoji data is required for this invocation, set --emoji-root or --emoji-tag"
+            );
+        } else if SourceDataProvider::is_missing_cldr_common_error(e) {
+            eyre::bail!(
+                "CLDR XML data is required for this invocation, set --cldr-common-root or --cldr-common-tag"
+            );
         }
 
         Err(e.into())
@@ -371,7 +458,7 @@ fn main() -> eyre::Result<()> {
                 std::fs::read(cli.input_blob.unwrap())?.into(),
             )?;
             let fallbacker = LocaleFallbacker::try_new_with_buffer_provider(&provider)?;
-            (Box::new(ReexportableBlobDataProvider(provider)), fallbacker)
+            (Box::new(ReexportableBufferProvider(Box::new(provider))), fallbacker)
         },
 
         #[cfg(all(not(feature = "provider"), feature = "blob_input"))]
@@ -438,6 +525,38 @@ fn main() -> eyre::Result<()> {
                 (None, _) => p,
             };
 
+            p = match (cli.ucd_root, cli.ucd_tag.as_str()) {
+                (Some(path), _) => p.with_ucd(&path)?,
+                #[cfg(feature = "networking")]
+                (_, "latest") => p.with_ucd_for_tag(SourceDataProvider::TESTED_UCD_TAG),
+                #[cfg(feature = "networking")]
+                (_, tag) => p.with_ucd_for_tag(tag),
+                #[cfg(not(feature = "networking"))]
+                (None, _) => p,
+            };
+
+            p = match (cli.emoji_root, cli.emoji_tag.as_str()) {
+                (Some(path), _) => p.with_emoji(&path)?,
+                #[cfg(feature = "networking")]
+                (_, "latest") => p.with_emoji_for_tag(SourceDataProvider::TESTED_EMOJI_TAG),
+                #[cfg(feature = "networking")]
+                (_, tag) => p.with_emoji_for_tag(tag),
+                #[cfg(not(feature = "networking"))]
+                (None, _) => p,
+            };
+
+            p = match (cli.cldr_common_root, cli.cldr_common_tag.as_str()) {
+                (Some(path), _) => p.with_cldr_common(&path)?,
+                #[cfg(feature = "networking")]
+                (_, "latest") => {
+                    p.with_cldr_common_for_tag(SourceDataProvider::TESTED_CLDR_COMMON_TAG)
+                }
+                #[cfg(feature = "networking")]
+                (_, tag) => p.with_cldr_common_for_tag(tag),
+                #[cfg(not(feature = "networking"))]
+                (None, _) => p,
+            };
+
             if cli.locales.as_slice() == ["recommended"] {
                 preprocessed_locales = Some(PreprocessedLocales::Locales(
                     p.locales_for_coverage_levels([
@@ -493,7 +612,7 @@ fn main() -> eyre::Result<()> {
             DeduplicationStrategy::RetainBaseLanguages
         }
         Some(Deduplication::None) => DeduplicationStrategy::None,
-        None => match cli.format {
+        None => match format {
             Format::Fs | Format::Blob => DeduplicationStrategy::None,
             Format::Baked if cli.no_internal_fallback && cli.deduplication.is_none() =>
                 eyre::bail!("--no-internal-fallback requires an explicit --deduplication value. Baked exporter would default to maximal deduplication, which might not be intended"),
@@ -528,7 +647,7 @@ fn main() -> eyre::Result<()> {
         driver.with_segmenter_models(cli.segmenter_models.clone())
     };
 
-    let metadata: Result<ExportMetadata, DataError> = match cli.format {
+    let metadata: Result<ExportMetadata, DataError> = match format {
         #[cfg(not(feature = "fs_exporter"))]
         Format::Fs => {
             eyre::bail!("Exporting to an FsProvider requires the `fs_exporter` Cargo feature")
@@ -650,9 +769,9 @@ macro_rules! cb {
         }
 
 
-        #[cfg(feature = "blob_input")]
+        #[cfg(any(feature = "blob_input", feature = "inspect"))]
         icu_provider::export::make_exportable_provider!(
-            ReexportableBlobDataProvider,
+            ReexportableBufferProvider,
             [
                 icu_provider::hello_world::HelloWorldV1,
                 $(
@@ -670,32 +789,33 @@ macro_rules! cb {
 extern crate alloc;
 icu_provider_registry::registry!(cb);
 
-#[cfg(feature = "blob_input")]
+#[cfg(any(feature = "blob_input", feature = "inspect"))]
 use icu_provider::buf::DeserializingBufferProvider;
-#[cfg(feature = "blob_input")]
+#[cfg(any(feature = "blob_input", feature = "inspect"))]
 use icu_provider::prelude::*;
-#[cfg(feature = "blob_input")]
-use icu_provider_blob::BlobDataProvider;
 
-#[cfg(feature = "blob_input")]
-struct ReexportableBlobDataProvider(icu_provider_blob::BlobDataProvider);
+#[cfg(any(feature = "blob_input", feature = "inspect"))]
+type IterableBufferProvider = Box<dyn IterableDynamicDataProvider<BufferMarker> + Sync>;
+
+/// Makes a [`BlobDataProvider`](icu_provider_blob::BlobDataProvider) or
+/// [`FsDataProvider`](icu_provider_fs::FsDataProvider) exportable.
+#[cfg(any(feature = "blob_input", feature = "inspect"))]
+struct ReexportableBufferProvider(IterableBufferProvider);
 
-#[cfg(feature = "blob_input")]
-impl<M: DataMarker> DataProvider<M> for ReexportableBlobDataProvider
+#[cfg(any(feature = "blob_input", feature = "inspect"))]
+impl<M: DataMarker> DataProvider<M> for ReexportableBufferProvider
 where
-    BlobDataProvider: AsDeserializingBufferProvider,
-    for<'a> DeserializingBufferProvider<'a, BlobDataProvider>: DataProvider<M>,
+    for<'a> DeserializingBufferProvider<'a, IterableBufferProvider>: DataProvider<M>,
 {
     fn load(&self, req: DataRequest) -> Result<DataResponse<M>, DataError> {
         self.0.as_deserializing().load(req)
     }
 }
 
-#[cfg(feature = "blob_input")]
-impl<M: DataMarker> IterableDataProvider<M> for ReexportableBlobDataProvider
+#[cfg(any(feature = "blob_input", feature = "inspect"))]
+impl<M: DataMarker> IterableDataProvider<M> for ReexportableBufferProvider
 where
-    BlobDataProvider: AsDeserializingBufferProvider,
-    for<'a> DeserializingBufferProvider<'a, BlobDataProvider>: DataProvider<M>,
+    for<'a> DeserializingBufferProvider<'a, IterableBufferProvider>: DataProvider<M>,
 {
     fn iter_ids(&self) -> Result<std::collections::BTreeSet<DataIdentifierCow<'_>>, DataError> {
         self.0.iter_ids_for_marker(M::INFO)
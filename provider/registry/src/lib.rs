@@ -24,6 +24,7 @@ macro_rules! registry(
             icu::calendar::provider::CalendarJapaneseModernV1: CalendarJapaneseModernV1,
             icu::calendar::provider::CalendarJapaneseExtendedV1: CalendarJapaneseExtendedV1,
             icu::calendar::provider::CalendarWeekV1: CalendarWeekV1,
+            icu::calendar::provider::CalendarWeekV2: CalendarWeekV2,
             icu::casemap::provider::CaseMapV1: CaseMapV1,
             icu::casemap::provider::CaseMapUnfoldV1: CaseMapUnfoldV1,
             icu::collator::provider::CollationRootV1: CollationRootV1,
@@ -60,6 +61,7 @@ macro_rules! registry(
             icu::locale::provider::LocaleLikelySubtagsScriptRegionV1: LocaleLikelySubtagsScriptRegionV1,
             icu::locale::provider::LocaleParentsV1: LocaleParentsV1,
             icu::locale::provider::LocaleScriptDirectionV1: LocaleScriptDirectionV1,
+            icu::locale::provider::LocaleValidityV1: LocaleValidityV1,
             icu::normalizer::provider::NormalizerNfcV1: NormalizerNfcV1,
             icu::normalizer::provider::NormalizerNfdDataV1: NormalizerNfdDataV1,
             icu::normalizer::provider::NormalizerNfdTablesV1: NormalizerNfdTablesV1,
@@ -69,6 +71,7 @@ macro_rules! registry(
             icu::normalizer::provider::NormalizerUts46DataV1: NormalizerUts46DataV1,
             icu::plurals::provider::PluralsCardinalV1: PluralsCardinalV1,
             icu::plurals::provider::PluralsOrdinalV1: PluralsOrdinalV1,
+            icu::properties::provider::PropertyCharacterNamesV1: PropertyCharacterNamesV1,
             icu::properties::provider::PropertyNameLongBidiClassV1: PropertyNameLongBidiClassV1,
             icu::properties::provider::PropertyNameLongCanonicalCombiningClassV1: PropertyNameLongCanonicalCombiningClassV1,
             icu::properties::provider::PropertyNameLongEastAsianWidthV1: PropertyNameLongEastAsianWidthV1,
@@ -190,6 +193,12 @@ macro_rules! registry(
             icu::properties::provider::PropertyEnumWordBreakV1: PropertyEnumWordBreakV1,
             icu::properties::provider::PropertyEnumBidiMirroringGlyphV1: PropertyEnumBidiMirroringGlyphV1,
             icu::properties::provider::PropertyBinaryBasicEmojiV1: PropertyBinaryBasicEmojiV1,
+            icu::properties::provider::PropertyBinaryEmojiKeycapSequenceV1: PropertyBinaryEmojiKeycapSequenceV1,
+            icu::properties::provider::PropertyBinaryRgiEmojiV1: PropertyBinaryRgiEmojiV1,
+            icu::properties::provider::PropertyBinaryRgiEmojiFlagSequenceV1: PropertyBinaryRgiEmojiFlagSequenceV1,
+            icu::properties::provider::PropertyBinaryRgiEmojiModifierSequenceV1: PropertyBinaryRgiEmojiModifierSequenceV1,
+            icu::properties::provider::PropertyBinaryRgiEmojiTagSequenceV1: PropertyBinaryRgiEmojiTagSequenceV1,
+            icu::properties::provider::PropertyBinaryRgiEmojiZwjSequenceV1: PropertyBinaryRgiEmojiZwjSequenceV1,
             icu::properties::provider::PropertyScriptWithExtensionsV1: PropertyScriptWithExtensionsV1,
             icu::segmenter::provider::SegmenterDictionaryExtendedV1: SegmenterDictionaryExtendedV1,
             icu::segmenter::provider::SegmenterDictionaryAutoV1: SegmenterDictionaryAutoV1,
@@ -288,6 +297,7 @@ macro_rules! registry(
             icu::experimental::relativetime::provider::LongYearRelativeV1: LongYearRelativeV1,
             icu::experimental::relativetime::provider::ShortYearRelativeV1: ShortYearRelativeV1,
             icu::experimental::relativetime::provider::NarrowYearRelativeV1: NarrowYearRelativeV1,
+            icu::experimental::relativetime::provider::RelativeAtTimeV1: RelativeAtTimeV1,
             icu::experimental::personnames::provider::PersonNamesFormatV1: PersonNamesFormatV1,
             icu::experimental::transliterate::provider::TransliteratorRulesV1: TransliteratorRulesV1,
             icu::experimental::units::provider::UnitsInfoV1: UnitsInfoV1,
@@ -124,6 +124,14 @@ pub(crate) struct DayPeriodSymbols {
     pub(crate) pm: Cow<'static, str>,
     pub(crate) noon: Option<Cow<'static, str>>,
     pub(crate) midnight: Option<Cow<'static, str>>,
+    pub(crate) morning1: Option<Cow<'static, str>>,
+    pub(crate) morning2: Option<Cow<'static, str>>,
+    pub(crate) afternoon1: Option<Cow<'static, str>>,
+    pub(crate) afternoon2: Option<Cow<'static, str>>,
+    pub(crate) evening1: Option<Cow<'static, str>>,
+    pub(crate) evening2: Option<Cow<'static, str>>,
+    pub(crate) night1: Option<Cow<'static, str>>,
+    pub(crate) night2: Option<Cow<'static, str>>,
 }
 
 #[derive(PartialEq, Debug, Deserialize, Clone)]
@@ -202,6 +210,11 @@ impl DateTimeFormats {
     }
 }
 
+#[derive(PartialEq, Clone, Debug, Deserialize)]
+pub(crate) struct DateTimeFormatVariants {
+    pub(crate) standard: LengthPatterns,
+}
+
 #[derive(PartialEq, Clone, Debug, Deserialize)]
 pub(crate) struct AvailableFormats(pub(crate) HashMap<String, String>);
 
@@ -236,6 +249,10 @@ pub(crate) struct Dates {
     pub(crate) time_skeletons: LengthPatterns,
     #[serde(rename = "dateTimeFormats")]
     pub(crate) datetime_formats: DateTimeFormats,
+    #[serde(rename = "dateTimeFormats-atTime")]
+    pub(crate) datetime_formats_at_time: Option<DateTimeFormatVariants>,
+    #[serde(rename = "dateTimeFormats-relative")]
+    pub(crate) datetime_formats_relative: Option<DateTimeFormatVariants>,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
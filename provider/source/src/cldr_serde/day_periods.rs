@@ -0,0 +1,37 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON dayPeriods.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/dayPeriods.json>
+
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// A single day period rule, either a point in time or a range.
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Rule {
+    #[serde(rename = "_at")]
+    pub(crate) at: Option<String>,
+    #[serde(rename = "_from")]
+    pub(crate) from: Option<String>,
+    #[serde(rename = "_before")]
+    pub(crate) before: Option<String>,
+}
+
+/// The day period rules of a language, keyed by day period (e.g. "morning1").
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct RuleSet(pub(crate) BTreeMap<String, Rule>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Supplemental {
+    #[serde(rename = "dayPeriodRuleSet")]
+    pub(crate) day_period_rule_set: HashMap<String, RuleSet>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Resource {
+    pub(crate) supplemental: Supplemental,
+}
@@ -14,6 +14,7 @@ pub(crate) mod coverage_levels;
 pub(crate) mod currencies;
 #[cfg(feature = "experimental")]
 pub(crate) mod date_fields;
+pub(crate) mod day_periods;
 pub(crate) mod directionality;
 #[cfg_attr(not(feature = "experimental"), allow(dead_code))]
 pub(crate) mod displaynames;
@@ -30,6 +31,7 @@ pub(crate) mod personnames;
 #[cfg(feature = "experimental")]
 pub(crate) mod plural_ranges;
 pub(crate) mod plurals;
+pub(crate) mod time_data;
 pub(crate) mod time_zones;
 #[cfg(feature = "experimental")]
 pub(crate) mod transforms;
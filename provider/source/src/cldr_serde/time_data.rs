@@ -0,0 +1,31 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON timeData.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/timeData.json>
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// The hour formats used in a region.
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct HourFormats {
+    /// Space-separated list of allowed hour formats, such as "h hB H", in order of preference.
+    #[serde(rename = "_allowed")]
+    pub(crate) allowed: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Supplemental {
+    /// Keyed by region (e.g. "US"), or by language and region (e.g. "ta_IN").
+    #[serde(rename = "timeData")]
+    pub(crate) time_data: HashMap<String, HourFormats>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Resource {
+    pub(crate) supplemental: Supplemental,
+}
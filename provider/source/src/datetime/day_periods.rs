@@ -0,0 +1,185 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_serde;
+use crate::SourceDataProvider;
+use icu::locale::LanguageIdentifier;
+use icu_provider::prelude::*;
+
+/// The flexible day periods, in the order in which they are stored in the data.
+pub(crate) const FLEXIBLE_DAY_PERIODS: [&str; 8] = [
+    "morning1",
+    "morning2",
+    "afternoon1",
+    "afternoon2",
+    "evening1",
+    "evening2",
+    "night1",
+    "night2",
+];
+
+fn parse_hour(time: &str) -> Result<usize, DataError> {
+    match time.split_once(':') {
+        Some((hour, "00")) => hour
+            .parse::<usize>()
+            .ok()
+            .filter(|&h| h <= 24)
+            .ok_or_else(|| {
+                DataError::custom("Invalid hour in day period rule").with_debug_context(time)
+            }),
+        _ => {
+            Err(DataError::custom("Day period rules must start on the hour")
+                .with_debug_context(time))
+        }
+    }
+}
+
+impl SourceDataProvider {
+    /// Returns, for each hour of the day, the index into [`FLEXIBLE_DAY_PERIODS`] of the
+    /// flexible day period in effect, or `None` if the locale has no day period rules.
+    pub(crate) fn flexible_day_period_hours(
+        &self,
+        locale: &DataLocale,
+    ) -> Result<Option<[Option<u8>; 24]>, DataError> {
+        let rule_sets = &self
+            .cldr()?
+            .core()
+            .read_and_parse::<cldr_serde::day_periods::Resource>("supplemental/dayPeriods.json")?
+            .supplemental
+            .day_period_rule_set;
+
+        let language = locale.language.as_str();
+        let Some(rule_set) = locale
+            .script
+            .and_then(|s| rule_sets.get(&format!("{language}_{s}")))
+            .or_else(|| {
+                locale
+                    .region
+                    .and_then(|r| rule_sets.get(&format!("{language}_{r}")))
+            })
+            .or_else(|| rule_sets.get(language))
+        else {
+            return Ok(None);
+        };
+
+        let mut hours = [None; 24];
+        for (period, rule) in rule_set.0.iter() {
+            let Some(index) = FLEXIBLE_DAY_PERIODS.iter().position(|p| p == period) else {
+                // am, pm, noon, and midnight are handled by the 'a' and 'b' symbols
+                continue;
+            };
+            let (Some(from), Some(before)) = (&rule.from, &rule.before) else {
+                return Err(DataError::custom("Flexible day period without a range")
+                    .with_display_context(period));
+            };
+            let (from, before) = (parse_hour(from)?, parse_hour(before)?);
+            // Ranges such as 21:00-06:00 wrap around midnight
+            let range = if from < before {
+                from..before
+            } else {
+                from..before + 24
+            };
+            for hour in range {
+                hours[hour % 24] = Some(index as u8);
+            }
+        }
+        Ok(Some(hours))
+    }
+
+    /// Whether the locale's region uses flexible day periods ("B") rather than AM/PM ("a")
+    /// in its default 12-hour time format.
+    ///
+    /// This follows the first allowed hour format in CLDR's time data, which is what
+    /// UTS 35 uses for the "C" input skeleton symbol.
+    pub(crate) fn prefers_flexible_day_periods(
+        &self,
+        locale: &DataLocale,
+    ) -> Result<bool, DataError> {
+        let time_data = &self
+            .cldr()?
+            .core()
+            .read_and_parse::<cldr_serde::time_data::Resource>("supplemental/timeData.json")?
+            .supplemental
+            .time_data;
+
+        let mut langid = LanguageIdentifier::from((locale.language, locale.script, locale.region));
+        self.cldr()?
+            .extended_locale_expander()?
+            .maximize(&mut langid);
+
+        let hour_formats = langid
+            .region
+            .and_then(|r| {
+                time_data
+                    .get(&format!("{}_{r}", langid.language))
+                    .or_else(|| time_data.get(r.as_str()))
+            })
+            .or_else(|| time_data.get("001"))
+            .ok_or_else(|| DataError::custom("Missing default entry in timeData.json"))?;
+
+        Ok(hour_formats
+            .allowed
+            .split(' ')
+            .next()
+            .is_some_and(|f| f.ends_with('B')))
+    }
+}
+
+#[test]
+fn test_flexible_day_period_hours() {
+    use icu::locale::langid;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let en = provider
+        .flexible_day_period_hours(&langid!("en").into())
+        .unwrap()
+        .unwrap();
+    // night1 wraps around midnight
+    assert_eq!(en[0], Some(6));
+    assert_eq!(en[5], Some(6));
+    assert_eq!(en[6], Some(0));
+    assert_eq!(en[12], Some(2));
+    assert_eq!(en[20], Some(4));
+    assert_eq!(en[21], Some(6));
+
+    // Regional variants use the rules of the language
+    let es_ar = provider
+        .flexible_day_period_hours(&langid!("es-AR").into())
+        .unwrap()
+        .unwrap();
+    assert_eq!(es_ar[5], Some(0));
+    assert_eq!(es_ar[13], Some(4));
+    assert_eq!(es_ar[23], Some(6));
+
+    assert_eq!(
+        provider
+            .flexible_day_period_hours(&langid!("haw").into())
+            .unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_prefers_flexible_day_periods() {
+    use icu::locale::langid;
+
+    let provider = SourceDataProvider::new_testing();
+
+    for (langid, expected) in [
+        (langid!("en"), false),
+        (langid!("fr"), false),
+        (langid!("und"), false),
+        (langid!("zh-TW"), true),
+        (langid!("en-TW"), true),
+    ] {
+        assert_eq!(
+            provider
+                .prefers_flexible_day_periods(&langid.clone().into())
+                .unwrap(),
+            expected,
+            "{langid}"
+        );
+    }
+}
@@ -7,6 +7,7 @@ use crate::SourceDataProvider;
 use icu::calendar::AnyCalendarKind;
 use icu_provider::prelude::*;
 
+mod day_periods;
 mod legacy;
 mod names;
 mod neo;
@@ -167,8 +168,6 @@ mod test {
     }
 
     #[test]
-    #[ignore] // TODO(#5643)
-    #[allow(unreachable_code, unused_variables, unused_mut)]
     fn test_datetime_skeletons() {
         use icu::datetime::provider::pattern::runtime::Pattern;
         use icu::datetime::provider::skeleton::PluralPattern;
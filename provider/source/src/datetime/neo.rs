@@ -208,8 +208,8 @@ fn weekday_convert(
 }
 
 fn dayperiods_convert(
-    _datagen: &SourceDataProvider,
-    _locale: &DataLocale,
+    datagen: &SourceDataProvider,
+    locale: &DataLocale,
     data: &ca::Dates,
     _calendar: DatagenCalendar,
     context: Context,
@@ -219,14 +219,52 @@ fn dayperiods_convert(
 
     let mut periods = vec![&*day_periods.am, &*day_periods.pm];
 
+    let flexible_names = [
+        &day_periods.morning1,
+        &day_periods.morning2,
+        &day_periods.afternoon1,
+        &day_periods.afternoon2,
+        &day_periods.evening1,
+        &day_periods.evening2,
+        &day_periods.night1,
+        &day_periods.night2,
+    ]
+    .map(|name| name.as_deref().unwrap_or_default());
+
+    let hour_map = if flexible_names.iter().any(|name| !name.is_empty()) {
+        datagen.flexible_day_period_hours(locale)?.map(|hours| {
+            hours
+                .iter()
+                .map(|index| match index {
+                    Some(index) => char::from(b'0' + index),
+                    None => '-',
+                })
+                .collect::<String>()
+        })
+    } else {
+        None
+    };
+
     if let Some(ref noon) = day_periods.noon {
         periods.push(noon);
-    } else if day_periods.midnight.is_some() {
+    } else if day_periods.midnight.is_some() || hour_map.is_some() {
         periods.push(""); // blank entry to separate midnight
     };
 
     if let Some(ref midnight) = day_periods.midnight {
         periods.push(midnight)
+    } else if hour_map.is_some() {
+        periods.push(""); // blank entry to separate the flexible day periods
+    }
+
+    if let Some(ref hour_map) = hour_map {
+        periods.push(hour_map);
+        let len = flexible_names
+            .iter()
+            .rposition(|name| !name.is_empty())
+            .map(|i| i + 1)
+            .unwrap_or_default();
+        periods.extend(&flexible_names[..len]);
     }
 
     Ok(LinearNames {
@@ -70,8 +70,10 @@ fn select_pattern<'data>(
     bag: components::Bag,
     skeletons: &DateSkeletonPatterns<'data>,
     preferred_hour_cycle: CoarseHourCycle,
+    flexible_day_periods: bool,
     length_patterns: &GenericLengthPatterns<'data>,
 ) -> PatternsWithDistance<PatternPlurals<'data>> {
+    use icu::datetime::provider::fields::{self, Field, FieldLength, FieldSymbol};
     use icu::datetime::provider::pattern::{runtime, PatternItem};
     use icu::datetime::provider::skeleton::{create_best_pattern_for_fields, BestSkeleton};
     use icu_locale_core::preferences::extensions::unicode::keywords::HourCycle;
@@ -80,7 +82,24 @@ fn select_pattern<'data>(
         CoarseHourCycle::H11H12 => HourCycle::H12,
         CoarseHourCycle::H23 => HourCycle::H23,
     };
-    let fields = bag.to_vec_fields(default_hour_cycle);
+    let mut fields = bag.to_vec_fields(default_hour_cycle);
+    if flexible_day_periods {
+        // Request a "B" skeleton such as "Bhm" for 12-hour times
+        if let Some(index) = fields.iter().position(|field| {
+            matches!(
+                field.symbol,
+                FieldSymbol::Hour(fields::Hour::H11 | fields::Hour::H12)
+            )
+        }) {
+            fields.insert(
+                index,
+                Field {
+                    symbol: FieldSymbol::DayPeriod(fields::DayPeriod::Flexible),
+                    length: FieldLength::One,
+                },
+            );
+        }
+    }
     match create_best_pattern_for_fields(skeletons, length_patterns, &fields, &bag, false) {
         BestSkeleton::AllFieldsMatch(p, distance) => PatternsWithDistance { inner: p, distance },
         BestSkeleton::MissingOrExtraFields(p, distance) => {
@@ -152,6 +171,7 @@ impl SourceDataProvider {
 
         let length_combinations_v1 = GenericLengthPatterns::from(&data.datetime_formats);
         let time_lengths_v1 = TimeLengths::from_serde(&data, locale);
+        let flexible_day_periods = self.prefers_flexible_day_periods(locale)?;
         let skeleton_patterns =
             DateSkeletonPatterns::from(&data.datetime_formats.available_formats);
 
@@ -219,12 +239,13 @@ impl SourceDataProvider {
                     components,
                     &skeleton_patterns,
                     time_lengths_v1.preferred_hour_cycle,
+                    flexible_day_periods,
                     &length_combinations_v1,
                 ));
                 match components {
                     components::Bag {
                         era: None,
-                        year: Some(_),
+                        year: Some(components::Year::Numeric | components::Year::TwoDigit),
                         ..
                     } => {
                         // TODO(#4478): Use CLDR data when it becomes available
@@ -240,12 +261,14 @@ impl SourceDataProvider {
                                 components_with_full_year,
                                 &skeleton_patterns,
                                 time_lengths_v1.preferred_hour_cycle,
+                                flexible_day_periods,
                                 &length_combinations_v1,
                             ))),
                             variant1: Some(expand_pp_to_pe(select_pattern(
                                 components_with_era,
                                 &skeleton_patterns,
                                 time_lengths_v1.preferred_hour_cycle,
+                                flexible_day_periods,
                                 &length_combinations_v1,
                             ))),
                         }
@@ -262,12 +285,14 @@ impl SourceDataProvider {
                                 components_with_minute,
                                 &skeleton_patterns,
                                 time_lengths_v1.preferred_hour_cycle,
+                                flexible_day_periods,
                                 &length_combinations_v1,
                             ))),
                             variant1: Some(expand_pp_to_pe(select_pattern(
                                 components_with_second,
                                 &skeleton_patterns,
                                 time_lengths_v1.preferred_hour_cycle,
+                                flexible_day_periods,
                                 &length_combinations_v1,
                             ))),
                         }
@@ -453,6 +478,22 @@ fn test_check_for_field() {
         DataMarkerAttributes::from_str_or_panic("eh0"),
         "h"
     ));
+    assert!(check_for_field(
+        DataMarkerAttributes::from_str_or_panic("y0w"),
+        "y0"
+    ));
+    assert!(!check_for_field(
+        DataMarkerAttributes::from_str_or_panic("y0w"),
+        "y"
+    ));
+    assert!(check_for_field(
+        DataMarkerAttributes::from_str_or_panic("y0w"),
+        "w"
+    ));
+    assert!(!check_for_field(
+        DataMarkerAttributes::from_str_or_panic("m0w0"),
+        "w"
+    ));
 }
 
 /// Convert from a semantic time field set to classical component options for calculating the pattern.
@@ -503,12 +544,17 @@ fn gen_date_components(
         filtered_components.era = date_bag.era;
         filtered_components.year = date_bag.year;
     }
+    if check_for_field(attributes, "y0") {
+        // week-numbering year
+        filtered_components.year = Some(components::Year::NumericWeekOf);
+    }
     if check_for_field(attributes, "m0") {
         filtered_components.month = date_bag.month;
     }
     if check_for_field(attributes, "m0")
         && !check_for_field(attributes, "y")
         && !check_for_field(attributes, "d")
+        && !check_for_field(attributes, "w0")
     {
         // standalone month: use the skeleton length
         filtered_components.month = match length {
@@ -518,6 +564,20 @@ fn gen_date_components(
             _ => unreachable!(),
         };
     }
+    if check_for_field(attributes, "m0") && check_for_field(attributes, "w0") {
+        // week of month: CLDR only has patterns with the full month name
+        filtered_components.month = match length {
+            Length::Long => Some(components::Month::Long),
+            Length::Medium | Length::Short => Some(components::Month::Short),
+            _ => unreachable!(),
+        };
+    }
+    if check_for_field(attributes, "w") {
+        filtered_components.week = Some(components::Week::NumericWeekOfYear);
+    }
+    if check_for_field(attributes, "w0") {
+        filtered_components.week = Some(components::Week::WeekOfMonth);
+    }
     if check_for_field(attributes, "d") {
         filtered_components.day = date_bag.day;
     }
@@ -712,6 +772,46 @@ fn test_en_overlap_patterns() {
     );
 }
 
+#[test]
+fn test_flexible_day_period_patterns() {
+    use icu::locale::locale;
+
+    let provider = SourceDataProvider::new_testing();
+    let data = provider
+        .get_datetime_resources(&locale!("ja").into(), Some(DatagenCalendar::Gregorian))
+        .unwrap();
+    let skeleton_patterns = DateSkeletonPatterns::from(&data.datetime_formats.available_formats);
+    let length_combinations_v1 = GenericLengthPatterns::from(&data.datetime_formats);
+
+    let mut bag = components::Bag::empty();
+    bag.hour = Some(components::Numeric::Numeric);
+    bag.minute = Some(components::Numeric::Numeric);
+
+    for (hour_cycle, flexible_day_periods, expected) in [
+        (HourCycle::H12, false, "ah:mm"),
+        (HourCycle::H12, true, "Bh:mm"),
+        // Flexible day periods only apply to 12-hour times
+        (HourCycle::H23, true, "H:mm"),
+    ] {
+        bag.hour_cycle = Some(hour_cycle);
+        let PatternPlurals::SinglePattern(pattern) = select_pattern(
+            bag,
+            &skeleton_patterns,
+            CoarseHourCycle::H23,
+            flexible_day_periods,
+            &length_combinations_v1,
+        )
+        .into_inner() else {
+            unreachable!()
+        };
+        assert_eq!(
+            reference::Pattern::from(&pattern),
+            expected.parse().unwrap(),
+            "{hour_cycle:?} {flexible_day_periods}"
+        );
+    }
+}
+
 /// This is a test that should eventually be moved to CLDR.
 ///
 /// See: https://unicode-org.atlassian.net/browse/CLDR-14993
@@ -835,6 +935,7 @@ mod date_skeleton_consistency_tests {
             components,
             data.skeleton_patterns,
             data.preferred_hour_cycle,
+            false,
             data.length_combinations_v1,
         )
         .into_inner()
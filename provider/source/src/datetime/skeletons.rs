@@ -41,7 +41,6 @@ impl From<&cldr_serde::ca::AvailableFormats> for DateSkeletonPatterns<'_> {
                 let pattern_str = patterns.get("other").expect("Other variant must exist");
                 let pattern = pattern_str.parse().expect("Unable to parse a pattern");
 
-                #[allow(unreachable_code, unused_variables, unused_mut)] // TODO(#5643)
                 let mut pattern_plurals = if patterns.len() == 1 {
                     PatternPlurals::SinglePattern(pattern)
                 } else {
@@ -243,6 +242,10 @@ mod test {
         "Gy", "GyM", "GyMMM", "GyMMMdEEEE", "GyMMMdE", "GyMMMM", "GyMMMMdE", "GyMMMMd", "GyMMMd",
         // Time zones
         "HHmmZ", "Hmsv", "Hmsvvvv", "Hmv", "Hmvvvv", "hmsv", "hmsvvvv", "hmv", "hmvvvv",
+        // Weeks
+        "MMMMW", "yw",
+        // Flexible day periods
+        "Bh", "Bhm", "Bhms", "EBhm", "EBhms",
     ];
 
     // NOTE: If you are moving this to the SUPPORTED section, make sure to remove the match
@@ -250,12 +253,8 @@ mod test {
     //       and then regenerate baked data (`cargo make bakeddata components/datetime`)
     #[rustfmt::skip]
     const UNSUPPORTED_STRING_SKELETONS: &[&str] = &[
-        // TODO(#487) - Flexible day periods
-        "Bh", "Bhm", "Bhms", "EBhm", "EBhms",
         // TODO(#501) - Quarters
         "yQ", "yQQQ", "yQQQQ",
-        // TODO(#5643) - Weeks
-        "MMMMW", "yw",
     ];
 
     #[test]
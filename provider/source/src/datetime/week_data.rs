@@ -8,13 +8,41 @@ use crate::cldr_serde::{
 };
 use crate::IterableDataProviderCached;
 use crate::SourceDataProvider;
-use icu::calendar::provider::{CalendarWeekV1, WeekData, WeekdaySet};
+use icu::calendar::provider::{CalendarWeekV1, CalendarWeekV2, WeekData, WeekDataV2, WeekdaySet};
 use icu_provider::prelude::*;
 use std::collections::HashSet;
 
 impl DataProvider<CalendarWeekV1> for SourceDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<CalendarWeekV1>, DataError> {
         self.check_req::<CalendarWeekV1>(req)?;
+        let WeekDataV2 {
+            first_weekday,
+            weekend,
+            ..
+        } = self.week_data_v2(req)?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(WeekData {
+                first_weekday,
+                weekend,
+            }),
+        })
+    }
+}
+
+impl DataProvider<CalendarWeekV2> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<CalendarWeekV2>, DataError> {
+        self.check_req::<CalendarWeekV2>(req)?;
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(self.week_data_v2(req)?),
+        })
+    }
+}
+
+impl SourceDataProvider {
+    fn week_data_v2(&self, req: DataRequest) -> Result<WeekDataV2, DataError> {
         let territory = req
             .id
             .locale
@@ -56,18 +84,38 @@ impl DataProvider<CalendarWeekV1> for SourceDataProvider {
             WeekdaySet::new(&[weekend_start.into(), weekend_end.into()])
         };
 
-        Ok(DataResponse {
-            metadata: Default::default(),
-            payload: DataPayload::from_owned(WeekData {
-                first_weekday,
-                weekend,
-            }),
+        let min_week_days: u8 = week_data
+            .min_days
+            .get(&territory)
+            .or_else(|| week_data.min_days.get(&DEFAULT_TERRITORY))
+            .ok_or(DataError::custom(
+                "Missing default entry for minDays in weekData.json",
+            ))?
+            .parse()
+            .map_err(|_| DataError::custom("Invalid minDays in weekData.json"))?;
+
+        Ok(WeekDataV2 {
+            first_weekday,
+            weekend,
+            min_week_days,
         })
     }
 }
 
 impl IterableDataProviderCached<CalendarWeekV1> for SourceDataProvider {
     fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        self.week_data_ids()
+    }
+}
+
+impl IterableDataProviderCached<CalendarWeekV2> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        self.week_data_ids()
+    }
+}
+
+impl SourceDataProvider {
+    fn week_data_ids(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
         let week_data: &cldr_serde::week_data::Resource = self
             .cldr()?
             .core()
@@ -101,27 +149,29 @@ fn test_basic_cldr_week_data_v2() {
 
     let provider = SourceDataProvider::new_testing();
 
-    let default_week_data: DataResponse<CalendarWeekV1> =
+    let default_week_data: DataResponse<CalendarWeekV2> =
         provider.load(Default::default()).unwrap();
     assert_eq!(Monday, default_week_data.payload.get().first_weekday);
+    assert_eq!(1, default_week_data.payload.get().min_week_days);
     assert_eq!(
         WeekdaySet::new(&[Saturday, Sunday]),
         default_week_data.payload.get().weekend
     );
 
-    let fr_week_data: DataResponse<CalendarWeekV1> = provider
+    let fr_week_data: DataResponse<CalendarWeekV2> = provider
         .load(DataRequest {
             id: DataIdentifierCow::from_locale(langid!("und-FR").into()).as_borrowed(),
             ..Default::default()
         })
         .unwrap();
     assert_eq!(Monday, fr_week_data.payload.get().first_weekday);
+    assert_eq!(4, fr_week_data.payload.get().min_week_days);
     assert_eq!(
         WeekdaySet::new(&[Saturday, Sunday]),
         fr_week_data.payload.get().weekend
     );
 
-    let iq_week_data: DataResponse<CalendarWeekV1> = provider
+    let iq_week_data: DataResponse<CalendarWeekV2> = provider
         .load(DataRequest {
             id: DataIdentifierCow::from_locale(langid!("und-IQ").into()).as_borrowed(),
             ..Default::default()
@@ -129,12 +179,13 @@ fn test_basic_cldr_week_data_v2() {
         .unwrap();
     // Only first_weekday is defined for IQ, min_week_days uses the default.
     assert_eq!(Saturday, iq_week_data.payload.get().first_weekday);
+    assert_eq!(1, iq_week_data.payload.get().min_week_days);
     assert_eq!(
         WeekdaySet::new(&[Friday, Saturday]),
         iq_week_data.payload.get().weekend
     );
 
-    let gg_week_data: DataResponse<CalendarWeekV1> = provider
+    let gg_week_data: DataResponse<CalendarWeekV2> = provider
         .load(DataRequest {
             id: DataIdentifierCow::from_locale(langid!("und-GG").into()).as_borrowed(),
             ..Default::default()
@@ -145,12 +196,13 @@ fn test_basic_cldr_week_data_v2() {
         default_week_data.payload.get().first_weekday,
         gg_week_data.payload.get().first_weekday
     );
+    assert_eq!(4, gg_week_data.payload.get().min_week_days);
     assert_eq!(
         WeekdaySet::new(&[Saturday, Sunday]),
         gg_week_data.payload.get().weekend
     );
 
-    let ir_week_data: DataResponse<CalendarWeekV1> = provider
+    let ir_week_data: DataResponse<CalendarWeekV2> = provider
         .load(DataRequest {
             id: DataIdentifierCow::from_locale(langid!("und-IR").into()).as_borrowed(),
             ..Default::default()
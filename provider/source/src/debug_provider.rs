@@ -32,6 +32,12 @@ where
             Box::new(['\0'; 10])
         } else if type_id == TypeId::of::<DecimalSymbols>() {
             Box::new(DecimalSymbols::new_en_for_testing())
+        } else if type_id == TypeId::of::<icu::calendar::provider::WeekDataV2>() {
+            Box::new(icu::calendar::provider::WeekDataV2 {
+                first_weekday: icu::calendar::types::Weekday::Monday,
+                weekend: icu::calendar::provider::WeekdaySet::new(&[]),
+                min_week_days: 1,
+            })
         } else {
             panic!(
                 "Don't how how to create for debug: {}",
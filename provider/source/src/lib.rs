@@ -13,7 +13,7 @@
 //! * `networking`
 //!   * enables networking support to download CLDR and ICU source data from GitHub
 //! * `use_wasm` / `use_icu4c`
-//!   * see the documentation on [`icu_codepointtrie_builder`](icu_codepointtrie_builder#build-configuration)
+//!   * deprecated, these have no effect
 //! * `experimental`
 //!   * enables markers defined in the unstable `icu::experimental` module
 
@@ -74,15 +74,21 @@ mod tests;
 /// If a required data source has not been set, `DataProvider::load` will
 /// fail with the appropriate error:
 /// * [`is_missing_cldr_error`](Self::is_missing_cldr_error)
+/// * [`is_missing_cldr_common_error`](Self::is_missing_cldr_common_error)
+/// * [`is_missing_emoji_error`](Self::is_missing_emoji_error)
 /// * [`is_missing_icuexport_error`](Self::is_missing_icuexport_error)
 /// * [`is_missing_segmenter_lstm_error`](Self::is_missing_segmenter_lstm_error)
+/// * [`is_missing_ucd_error`](Self::is_missing_ucd_error)
 #[allow(clippy::exhaustive_structs)] // any information will be added to SourceData
 #[derive(Debug, Clone)]
 pub struct SourceDataProvider {
     cldr_paths: Option<Arc<CldrCache>>,
+    cldr_common_paths: Option<Arc<SerdeCache>>,
+    emoji_paths: Option<Arc<SerdeCache>>,
     icuexport_paths: Option<Arc<SerdeCache>>,
     segmenter_lstm_paths: Option<Arc<SerdeCache>>,
     tzdb_paths: Option<Arc<TzdbCache>>,
+    ucd_paths: Option<Arc<SerdeCache>>,
     trie_type: TrieType,
     collation_root_han: CollationRootHan,
     pub(crate) timezone_horizon: time_zones::Timestamp,
@@ -121,12 +127,24 @@ impl SourceDataProvider {
     /// The TZDB tag that has been verified to work with this version of `SourceDataProvider`.
     pub const TESTED_TZDB_TAG: &'static str = "2025b";
 
+    /// The Unicode Character Database version that has been verified to work with this version of `SourceDataProvider`.
+    pub const TESTED_UCD_TAG: &'static str = "16.0.0";
+
+    /// The Unicode emoji data version that has been verified to work with this version of `SourceDataProvider`.
+    pub const TESTED_EMOJI_TAG: &'static str = "16.0";
+
+    /// The CLDR release whose XML `common` data has been verified to work with this version of `SourceDataProvider`.
+    pub const TESTED_CLDR_COMMON_TAG: &'static str = "47.0";
+
     /// A provider using the data that has been verified to work with this version of `SourceDataProvider`.
     ///
     /// See [`TESTED_CLDR_TAG`](Self::TESTED_CLDR_TAG),
     /// [`TESTED_ICUEXPORT_TAG`](Self::TESTED_ICUEXPORT_TAG),
     /// [`TESTED_SEGMENTER_LSTM_TAG`](Self::TESTED_SEGMENTER_LSTM_TAG),
-    /// [`TESTED_TZDB_TAG`](Self::TESTED_TZDB_TAG).
+    /// [`TESTED_TZDB_TAG`](Self::TESTED_TZDB_TAG),
+    /// [`TESTED_UCD_TAG`](Self::TESTED_UCD_TAG),
+    /// [`TESTED_EMOJI_TAG`](Self::TESTED_EMOJI_TAG),
+    /// [`TESTED_CLDR_COMMON_TAG`](Self::TESTED_CLDR_COMMON_TAG).
     ///
     /// ✨ *Enabled with the `networking` Cargo feature.*
     #[cfg(feature = "networking")]
@@ -141,6 +159,9 @@ impl SourceDataProvider {
                     .with_icuexport_for_tag(Self::TESTED_ICUEXPORT_TAG)
                     .with_segmenter_lstm_for_tag(Self::TESTED_SEGMENTER_LSTM_TAG)
                     .with_tzdb_for_tag(Self::TESTED_TZDB_TAG)
+                    .with_ucd_for_tag(Self::TESTED_UCD_TAG)
+                    .with_emoji_for_tag(Self::TESTED_EMOJI_TAG)
+                    .with_cldr_common_for_tag(Self::TESTED_CLDR_COMMON_TAG)
             })
             .clone()
     }
@@ -153,9 +174,12 @@ impl SourceDataProvider {
     pub fn new_custom() -> Self {
         Self {
             cldr_paths: None,
+            cldr_common_paths: None,
+            emoji_paths: None,
             icuexport_paths: None,
             segmenter_lstm_paths: None,
             tzdb_paths: None,
+            ucd_paths: None,
             trie_type: Default::default(),
             timezone_horizon: time_zones::Timestamp::try_offset_only_from_str(
                 "2015-01-01T00:00:00Z",
@@ -211,6 +235,37 @@ impl SourceDataProvider {
         })
     }
 
+    /// Adds Unicode Character Database source data to the provider. The path should point to
+    /// a local `UCD` directory or ZIP file (see [unicode.org](https://www.unicode.org/Public/)).
+    pub fn with_ucd(self, root: &Path) -> Result<Self, DataError> {
+        Ok(Self {
+            ucd_paths: Some(Arc::new(SerdeCache::new(AbstractFs::new(root)?))),
+            ..self
+        })
+    }
+
+    /// Adds Unicode emoji source data to the provider. The path should point to a local
+    /// directory containing `emoji-sequences.txt` and `emoji-zwj-sequences.txt`
+    /// (see [unicode.org](https://www.unicode.org/Public/emoji/)).
+    pub fn with_emoji(self, root: &Path) -> Result<Self, DataError> {
+        Ok(Self {
+            emoji_paths: Some(Arc::new(SerdeCache::new(AbstractFs::new(root)?))),
+            ..self
+        })
+    }
+
+    /// Adds CLDR XML source data to the provider. The path should point to a local
+    /// `cldr-common-{tag}` directory or ZIP file, containing the `common` directory
+    /// (see [unicode.org](https://unicode.org/Public/cldr/)).
+    ///
+    /// This is used for data that is not available in CLDR JSON, such as subtag validity.
+    pub fn with_cldr_common(self, root: &Path) -> Result<Self, DataError> {
+        Ok(Self {
+            cldr_common_paths: Some(Arc::new(SerdeCache::new(AbstractFs::new(root)?))),
+            ..self
+        })
+    }
+
     /// Adds CLDR source data to the provider. The data will be downloaded from GitHub
     /// using the given tag (see [GitHub releases](https://github.com/unicode-org/cldr-json/releases)).
     ///
@@ -282,9 +337,65 @@ impl SourceDataProvider {
         }
     }
 
+    /// Adds Unicode Character Database source data to the provider. The data will be downloaded
+    /// from unicode.org using the given version (see [unicode.org](https://www.unicode.org/Public/)).
+    ///
+    /// Also see: [`TESTED_UCD_TAG`](Self::TESTED_UCD_TAG)
+    ///
+    /// ✨ *Enabled with the `networking` Cargo feature.*
+    #[cfg(feature = "networking")]
+    pub fn with_ucd_for_tag(self, tag: &str) -> Self {
+        Self {
+            ucd_paths: Some(Arc::new(SerdeCache::new(AbstractFs::new_from_url(
+                format!("https://www.unicode.org/Public/{tag}/ucd/UCD.zip"),
+            )))),
+            ..self
+        }
+    }
+
+    /// Adds Unicode emoji source data to the provider. The data will be downloaded from
+    /// unicode.org using the given version (see [unicode.org](https://www.unicode.org/Public/emoji/)).
+    ///
+    /// Also see: [`TESTED_EMOJI_TAG`](Self::TESTED_EMOJI_TAG)
+    ///
+    /// ✨ *Enabled with the `networking` Cargo feature.*
+    #[cfg(feature = "networking")]
+    pub fn with_emoji_for_tag(self, tag: &str) -> Self {
+        Self {
+            emoji_paths: Some(Arc::new(SerdeCache::new(AbstractFs::new_from_url(
+                format!("https://www.unicode.org/Public/emoji/{tag}/"),
+            )))),
+            ..self
+        }
+    }
+
+    /// Adds CLDR XML source data to the provider. The data will be downloaded from
+    /// unicode.org using the given release (see [unicode.org](https://unicode.org/Public/cldr/)).
+    ///
+    /// Also see: [`TESTED_CLDR_COMMON_TAG`](Self::TESTED_CLDR_COMMON_TAG)
+    ///
+    /// ✨ *Enabled with the `networking` Cargo feature.*
+    #[cfg(feature = "networking")]
+    pub fn with_cldr_common_for_tag(self, tag: &str) -> Self {
+        let major = tag.split('.').next().unwrap_or(tag);
+        Self {
+            cldr_common_paths: Some(Arc::new(SerdeCache::new(AbstractFs::new_from_url(
+                format!("https://unicode.org/Public/cldr/{major}/cldr-common-{tag}.zip"),
+            )))),
+            ..self
+        }
+    }
+
     const MISSING_CLDR_ERROR: DataError =
         DataError::custom("Missing CLDR data. Use `.with_cldr[_for_tag]` to set CLDR data.");
 
+    const MISSING_CLDR_COMMON_ERROR: DataError = DataError::custom(
+        "Missing CLDR XML data. Use `.with_cldr_common[_for_tag]` to set CLDR XML data.",
+    );
+
+    const MISSING_EMOJI_ERROR: DataError =
+        DataError::custom("Missing emoji data. Use `.with_emoji[_for_tag]` to set emoji data.");
+
     const MISSING_ICUEXPORT_ERROR: DataError =
         DataError::custom("Missing ICU data. Use `.with_icuexport[_for_tag]` to set ICU data.");
 
@@ -295,12 +406,27 @@ impl SourceDataProvider {
     const MISSING_TZDB_ERROR: DataError =
         DataError::custom("Missing tzdb data. Use `.with_tzdb[_for_tag]` to set tzdb data.");
 
+    const MISSING_UCD_ERROR: DataError =
+        DataError::custom("Missing UCD data. Use `.with_ucd[_for_tag]` to set UCD data.");
+
     /// Identifies errors that are due to missing CLDR data.
     pub fn is_missing_cldr_error(mut e: DataError) -> bool {
         e.marker = None;
         e == Self::MISSING_CLDR_ERROR
     }
 
+    /// Identifies errors that are due to missing CLDR XML data.
+    pub fn is_missing_cldr_common_error(mut e: DataError) -> bool {
+        e.marker = None;
+        e == Self::MISSING_CLDR_COMMON_ERROR
+    }
+
+    /// Identifies errors that are due to missing Unicode emoji data.
+    pub fn is_missing_emoji_error(mut e: DataError) -> bool {
+        e.marker = None;
+        e == Self::MISSING_EMOJI_ERROR
+    }
+
     /// Identifies errors that are due to missing ICU export data.
     pub fn is_missing_icuexport_error(mut e: DataError) -> bool {
         e.marker = None;
@@ -319,10 +445,26 @@ impl SourceDataProvider {
         e == Self::MISSING_TZDB_ERROR
     }
 
+    /// Identifies errors that are due to missing Unicode Character Database data.
+    pub fn is_missing_ucd_error(mut e: DataError) -> bool {
+        e.marker = None;
+        e == Self::MISSING_UCD_ERROR
+    }
+
     fn cldr(&self) -> Result<&CldrCache, DataError> {
         self.cldr_paths.as_deref().ok_or(Self::MISSING_CLDR_ERROR)
     }
 
+    fn cldr_common(&self) -> Result<&SerdeCache, DataError> {
+        self.cldr_common_paths
+            .as_deref()
+            .ok_or(Self::MISSING_CLDR_COMMON_ERROR)
+    }
+
+    fn emoji(&self) -> Result<&SerdeCache, DataError> {
+        self.emoji_paths.as_deref().ok_or(Self::MISSING_EMOJI_ERROR)
+    }
+
     fn icuexport(&self) -> Result<&SerdeCache, DataError> {
         self.icuexport_paths
             .as_deref()
@@ -339,6 +481,10 @@ impl SourceDataProvider {
         self.tzdb_paths.as_deref().ok_or(Self::MISSING_TZDB_ERROR)
     }
 
+    fn ucd(&self) -> Result<&SerdeCache, DataError> {
+        self.ucd_paths.as_deref().ok_or(Self::MISSING_UCD_ERROR)
+    }
+
     /// Set this to use tries optimized for speed instead of data size
     pub fn with_fast_tries(self) -> Self {
         Self {
@@ -6,3 +6,4 @@ pub(crate) mod aliases;
 pub(crate) mod directionality;
 pub(crate) mod likely_subtags;
 pub(crate) mod parents;
+pub(crate) mod validity;
@@ -0,0 +1,346 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Subtag validity data, read from CLDR's `common/validity/*.xml` and
+//! `common/bcp47/*.xml` files.
+//!
+//! Sample files:
+//! <https://github.com/unicode-org/cldr/blob/main/common/validity/language.xml>
+//! <https://github.com/unicode-org/cldr/blob/main/common/bcp47/calendar.xml>
+
+use crate::SourceDataProvider;
+use icu::locale::provider::*;
+use icu_provider::prelude::*;
+use std::collections::{BTreeMap, HashSet};
+use tinystr::TinyAsciiStr;
+use zerovec::{ZeroMap, ZeroMap2d};
+
+impl DataProvider<LocaleValidityV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<LocaleValidityV1>, DataError> {
+        self.check_req::<LocaleValidityV1>(req)?;
+        let common = self.cldr_common()?;
+
+        let validity = |file: &str| -> Result<BTreeMap<String, IdStatus>, DataError> {
+            let path = format!("common/validity/{file}.xml");
+            parse_validity(&common.root.read_to_string(&path)?)
+                .map_err(|e| e.with_display_context(&path))
+        };
+
+        let mut unicode = Bcp47Keys::default();
+        let mut transform = Bcp47Keys::default();
+        let mut files = common
+            .list("common/bcp47")?
+            .filter(|f| f.ends_with(".xml"))
+            .collect::<Vec<_>>();
+        files.sort();
+        for file in files {
+            let path = format!("common/bcp47/{file}");
+            parse_bcp47(
+                &common.root.read_to_string(&path)?,
+                &mut unicode,
+                &mut transform,
+            )
+            .map_err(|e| e.with_display_context(&path))?;
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(LocaleValidity {
+                languages: to_map(validity("language")?)?,
+                scripts: to_map(validity("script")?)?,
+                regions: to_map(validity("region")?)?,
+                variants: to_map(validity("variant")?)?,
+                subdivisions: to_map(validity("subdivision")?)?,
+                unicode_keys: to_map(unicode.keys)?,
+                unicode_values: to_map2d(unicode.values)?,
+                transform_keys: to_map(transform.keys)?,
+                transform_values: to_map2d(transform.values)?,
+            }),
+        })
+    }
+}
+
+impl crate::IterableDataProviderCached<LocaleValidityV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
+
+#[derive(Default)]
+struct Bcp47Keys {
+    keys: BTreeMap<String, IdStatus>,
+    values: BTreeMap<String, BTreeMap<String, IdStatus>>,
+}
+
+fn to_map<const N: usize>(
+    codes: BTreeMap<String, IdStatus>,
+) -> Result<ZeroMap<'static, tinystr::UnvalidatedTinyAsciiStr<N>, IdStatus>, DataError> {
+    codes
+        .into_iter()
+        .map(|(code, status)| {
+            Ok((
+                TinyAsciiStr::<N>::try_from_str(&code)
+                    .map_err(|_| DataError::custom("Invalid code").with_display_context(&code))?
+                    .to_unvalidated(),
+                status,
+            ))
+        })
+        .collect()
+}
+
+fn to_map2d(
+    values: BTreeMap<String, BTreeMap<String, IdStatus>>,
+) -> Result<ZeroMap2d<'static, tinystr::UnvalidatedTinyAsciiStr<2>, str, IdStatus>, DataError> {
+    let mut map = ZeroMap2d::new();
+    for (key, values) in values {
+        let key = TinyAsciiStr::<2>::try_from_str(&key)
+            .map_err(|_| DataError::custom("Invalid key").with_display_context(&key))?
+            .to_unvalidated();
+        for (value, status) in values {
+            map.insert(&key, value.as_str(), &status);
+        }
+    }
+    Ok(map)
+}
+
+/// Parses the `<id>` elements of a validity file, expanding ranges.
+fn parse_validity(xml: &str) -> Result<BTreeMap<String, IdStatus>, DataError> {
+    let mut codes = BTreeMap::new();
+    for tag in Tag::iter(&strip_comments(xml)) {
+        if tag.name != "id" {
+            continue;
+        }
+        let status = match tag.attribute("idStatus") {
+            Some("regular") => IdStatus::Regular,
+            Some("special") => IdStatus::Special,
+            Some("macroregion") => IdStatus::Macroregion,
+            Some("deprecated") => IdStatus::Deprecated,
+            Some("private_use") => IdStatus::PrivateUse,
+            Some("unknown") => IdStatus::Unknown,
+            // Reserved codes are not valid
+            Some("reserved") => continue,
+            s => {
+                return Err(DataError::custom("Unknown idStatus")
+                    .with_display_context(&s.unwrap_or_default()))
+            }
+        };
+        for item in tag.text.split_whitespace() {
+            for code in expand_range(item)? {
+                codes.insert(code, status);
+            }
+        }
+    }
+    Ok(codes)
+}
+
+/// Expands a range such as `aaa~f` (`aaa` to `aaf`), where the end replaces
+/// the last characters of the start and each replaced position is incremented
+/// independently.
+fn expand_range(item: &str) -> Result<Vec<String>, DataError> {
+    let Some((start, end)) = item.split_once('~') else {
+        return Ok(vec![item.to_string()]);
+    };
+    let Some(prefix) = start
+        .len()
+        .checked_sub(end.len())
+        .and_then(|i| start.get(..i))
+    else {
+        return Err(DataError::custom("Invalid range").with_display_context(item));
+    };
+    let mut codes = vec![prefix.to_string()];
+    for (s, e) in start.bytes().skip(prefix.len()).zip(end.bytes()) {
+        if s > e {
+            return Err(DataError::custom("Invalid range").with_display_context(item));
+        }
+        codes = codes
+            .into_iter()
+            .flat_map(|code| (s..=e).map(move |c| format!("{code}{}", c as char)))
+            .collect();
+    }
+    Ok(codes)
+}
+
+/// Parses the `<key>` and `<type>` elements of a BCP 47 file, sorting them into
+/// Unicode (`-u-`) and transform (`-t-`) keys.
+fn parse_bcp47(
+    xml: &str,
+    unicode: &mut Bcp47Keys,
+    transform: &mut Bcp47Keys,
+) -> Result<(), DataError> {
+    let status = |tag: &Tag| {
+        if tag.attribute("deprecated") == Some("true") {
+            IdStatus::Deprecated
+        } else {
+            IdStatus::Regular
+        }
+    };
+
+    // The key that the following types belong to
+    let mut current = None;
+    for tag in Tag::iter(&strip_comments(xml)) {
+        match tag.name {
+            "key" => {
+                let name = tag
+                    .attribute("name")
+                    .ok_or_else(|| DataError::custom("Missing key name"))?;
+                let keys = if tag.attribute("extension") == Some("t") {
+                    &mut *transform
+                } else {
+                    &mut *unicode
+                };
+                // Keys can be split across files, and are deprecated if any part is
+                let key_status = keys.keys.entry(name.to_string()).or_insert(status(&tag));
+                if status(&tag) == IdStatus::Deprecated {
+                    *key_status = IdStatus::Deprecated;
+                }
+                current = Some((tag.attribute("extension") == Some("t"), name.to_string()));
+            }
+            "type" => {
+                let Some((is_transform, key)) = &current else {
+                    return Err(DataError::custom("Type outside of key"));
+                };
+                let name = tag
+                    .attribute("name")
+                    .ok_or_else(|| DataError::custom("Missing type name"))?;
+                let keys = if *is_transform {
+                    &mut *transform
+                } else {
+                    &mut *unicode
+                };
+                keys.values
+                    .entry(key.clone())
+                    .or_default()
+                    .insert(name.to_string(), status(&tag));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn strip_comments(xml: &str) -> String {
+    let mut out = String::with_capacity(xml.len());
+    let mut rest = xml;
+    while let Some((before, after)) = rest.split_once("<!--") {
+        out.push_str(before);
+        rest = after.split_once("-->").map(|(_, a)| a).unwrap_or_default();
+    }
+    out.push_str(rest);
+    out
+}
+
+/// The start tag of an XML element, followed by the text up to the next tag.
+struct Tag<'a> {
+    name: &'a str,
+    attributes: Vec<(&'a str, &'a str)>,
+    text: &'a str,
+}
+
+impl<'a> Tag<'a> {
+    fn iter(xml: &'a str) -> impl Iterator<Item = Tag<'a>> {
+        xml.split('<').skip(1).filter_map(|piece| {
+            let (body, text) = piece.split_once('>')?;
+            if body.starts_with(['/', '?', '!']) {
+                return None;
+            }
+            let body = body.trim_end_matches('/');
+            let (name, mut rest) = body.split_once(char::is_whitespace).unwrap_or((body, ""));
+            let mut attributes = Vec::new();
+            while let Some((key, value)) = rest.split_once('=') {
+                let value = value.trim_start();
+                let quote = value.chars().next()?;
+                let (value, after) = value.get(1..)?.split_once(quote)?;
+                attributes.push((key.trim(), value));
+                rest = after;
+            }
+            Some(Tag {
+                name,
+                attributes,
+                text,
+            })
+        })
+    }
+
+    fn attribute(&self, key: &str) -> Option<&'a str> {
+        self.attributes
+            .iter()
+            .find(|&&(k, _)| k == key)
+            .map(|&(_, v)| v)
+    }
+}
+
+#[test]
+fn test_expand_range() {
+    assert_eq!(expand_range("aaa~c").unwrap(), ["aaa", "aab", "aac"]);
+    assert_eq!(expand_range("ad02~4").unwrap(), ["ad02", "ad03", "ad04"]);
+    assert_eq!(
+        expand_range("qaa~bb").unwrap(),
+        ["qaa", "qab", "qba", "qbb"]
+    );
+    assert_eq!(expand_range("Latn").unwrap(), ["Latn"]);
+    assert!(expand_range("abc~abcd").is_err());
+    assert!(expand_range("ab~a").is_err());
+}
+
+#[test]
+fn test_basic() {
+    use icu::locale::{InvalidSubtag, LocaleValidator, ValidityErrorKind};
+
+    let provider = SourceDataProvider::new_testing();
+    let data: DataResponse<LocaleValidityV1> = provider.load(Default::default()).unwrap();
+    let data = data.payload.get();
+
+    let language = |s: &str| {
+        data.languages
+            .get_copied(&TinyAsciiStr::try_from_str(s).unwrap().to_unvalidated())
+    };
+    assert_eq!(language("en"), Some(IdStatus::Regular));
+    assert_eq!(language("aac"), Some(IdStatus::Regular));
+    assert_eq!(language("mul"), Some(IdStatus::Special));
+    assert_eq!(language("iw"), Some(IdStatus::Deprecated));
+    assert_eq!(language("qaa"), Some(IdStatus::PrivateUse));
+    assert_eq!(language("und"), Some(IdStatus::Unknown));
+    assert_eq!(language("xyz"), None);
+
+    let validator = LocaleValidator::try_new_unstable(&provider).unwrap();
+    for s in [
+        "en-Latn-US",
+        "de-419",
+        "sl-rozaj-biske",
+        "ja-u-ca-japanese-nu-jpanfin",
+        "de-u-co-phonebk-kr-latn-digit",
+        "en-u-ca-islamic-umalqura",
+        "und-Latn-t-ru-m0-ungegn",
+        "en-t-x0-foo",
+    ] {
+        assert_eq!(
+            validator.validate(&s.parse().unwrap()),
+            Ok(()),
+            "{s} should be valid"
+        );
+    }
+
+    let error = validator
+        .validate(&"en-u-ca-ethiopic-amete-alem".parse().unwrap())
+        .unwrap_err();
+    assert!(matches!(error.subtag, InvalidSubtag::UnicodeValue(..)));
+    assert_eq!(error.kind, ValidityErrorKind::Deprecated);
+
+    for (s, kind) in [
+        ("xyz", ValidityErrorKind::Unknown),
+        ("en-Qaaa", ValidityErrorKind::PrivateUse),
+        ("en-XA", ValidityErrorKind::PrivateUse),
+        ("en-AA", ValidityErrorKind::PrivateUse),
+        ("en-DD", ValidityErrorKind::Deprecated),
+        ("de-u-co-xxx", ValidityErrorKind::Unknown),
+        ("de-u-vt-0041", ValidityErrorKind::Deprecated),
+        ("de-t-m0-xxx", ValidityErrorKind::Unknown),
+    ] {
+        assert_eq!(
+            validator.validate(&s.parse().unwrap()).map_err(|e| e.kind),
+            Err(kind),
+            "{s}"
+        );
+    }
+}
@@ -8,7 +8,6 @@ use crate::SourceDataProvider;
 use icu::properties::provider::PropertyEnumBidiMirroringGlyphV1;
 use icu_provider::prelude::*;
 
-#[cfg(any(feature = "use_wasm", feature = "use_icu4c"))]
 impl SourceDataProvider {
     fn get_code_point_prop_map<'a>(
         &'a self,
@@ -26,10 +25,7 @@ impl SourceDataProvider {
     }
 }
 
-// implement data provider 2 different ways, based on whether or not
-// features exist that enable the use of CPT Builder (ex: `use_wasm` or `use_icu4c`)
 impl DataProvider<PropertyEnumBidiMirroringGlyphV1> for SourceDataProvider {
-    #[cfg(any(feature = "use_wasm", feature = "use_icu4c"))]
     fn load(
         &self,
         req: DataRequest,
@@ -102,17 +98,6 @@ impl DataProvider<PropertyEnumBidiMirroringGlyphV1> for SourceDataProvider {
             ),
         })
     }
-
-    #[cfg(not(any(feature = "use_wasm", feature = "use_icu4c")))]
-    fn load(
-        &self,
-        req: DataRequest,
-    ) -> Result<DataResponse<PropertyEnumBidiMirroringGlyphV1>, DataError> {
-        self.check_req::<PropertyEnumBidiMirroringGlyphV1>(req)?;
-        return Err(DataError::custom(
-            "icu_provider_source must be built with use_icu4c or use_wasm to build Bidi auxiliary properties data",
-        ));
-    }
 }
 
 impl crate::IterableDataProviderCached<PropertyEnumBidiMirroringGlyphV1> for SourceDataProvider {
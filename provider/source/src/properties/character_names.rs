@@ -0,0 +1,355 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::SourceDataProvider;
+use icu::properties::provider::names::PropertyCharacterNames;
+use icu::properties::provider::PropertyCharacterNamesV1;
+use icu_provider::prelude::*;
+use potential_utf::PotentialCodePoint;
+use std::collections::{BTreeMap, HashSet};
+use zerovec::vecs::Index32;
+use zerovec::{VarZeroVec, ZeroVec};
+
+/// The names of ranges in `UnicodeData.txt` whose characters are named after their code points.
+const HEX_NAMED_RANGES: &[(&str, &str)] = &[
+    ("CJK Ideograph", "CJK UNIFIED IDEOGRAPH-"),
+    ("Tangut Ideograph", "TANGUT IDEOGRAPH-"),
+];
+
+/// The names of ranges in `UnicodeData.txt` whose characters have no name, or whose names
+/// are derived at runtime.
+const UNNAMED_RANGES: &[&str] = &[
+    "Hangul Syllable",
+    "Non Private Use High Surrogate",
+    "Private Use High Surrogate",
+    "Low Surrogate",
+    "Private Use",
+    "Plane 15 Private Use",
+    "Plane 16 Private Use",
+];
+
+const ALIAS_TYPES: &[&str] = &[
+    "correction",
+    "control",
+    "alternate",
+    "figment",
+    "abbreviation",
+];
+
+impl DataProvider<PropertyCharacterNamesV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<PropertyCharacterNamesV1>, DataError> {
+        self.check_req::<PropertyCharacterNamesV1>(req)?;
+
+        let unicode_data = self.ucd()?.root.read_to_string("UnicodeData.txt")?;
+        let name_aliases = self.ucd()?.root.read_to_string("NameAliases.txt")?;
+
+        let mut names = Vec::<(u32, &str)>::new();
+        let mut hex_name_ranges = Vec::<(u32, u32, &str)>::new();
+        let mut range_start = None;
+        for line in unicode_data.lines().filter(|l| !l.is_empty()) {
+            let mut fields = line.split(';');
+            let (Some(cp), Some(name)) = (fields.next(), fields.next()) else {
+                return Err(
+                    DataError::custom("Invalid UnicodeData.txt line").with_display_context(line)
+                );
+            };
+            let cp = u32::from_str_radix(cp, 16).map_err(|e| {
+                DataError::custom("Invalid UnicodeData.txt code point").with_display_context(&e)
+            })?;
+
+            if let Some(range) = name
+                .strip_prefix('<')
+                .and_then(|n| n.strip_suffix(", First>"))
+            {
+                range_start = Some((range, cp));
+                continue;
+            }
+            if let Some(range) = name
+                .strip_prefix('<')
+                .and_then(|n| n.strip_suffix(", Last>"))
+            {
+                let Some((start_range, start)) = range_start.take().filter(|&(r, _)| r == range)
+                else {
+                    return Err(DataError::custom("Unmatched UnicodeData.txt range")
+                        .with_display_context(range));
+                };
+                if let Some(&(_, prefix)) = HEX_NAMED_RANGES
+                    .iter()
+                    .find(|(r, _)| start_range.starts_with(r))
+                {
+                    hex_name_ranges.push((start, cp, prefix));
+                } else if !UNNAMED_RANGES.contains(&start_range) {
+                    return Err(DataError::custom("Unknown UnicodeData.txt range")
+                        .with_display_context(range));
+                }
+                if start_range == "Hangul Syllable" && (start, cp) != (0xAC00, 0xD7A3) {
+                    return Err(DataError::custom("Unexpected Hangul syllable range"));
+                }
+                continue;
+            }
+            if name.starts_with('<') {
+                // <control>
+                continue;
+            }
+
+            // Individually listed names like CJK COMPATIBILITY IDEOGRAPH-F900
+            if let Some(prefix) = name
+                .strip_suffix(&format!("{cp:04X}"))
+                .filter(|p| p.ends_with('-'))
+            {
+                match hex_name_ranges.last_mut() {
+                    Some((_, end, p)) if *p == prefix && *end + 1 == cp => *end = cp,
+                    _ => hex_name_ranges.push((cp, cp, prefix)),
+                }
+                continue;
+            }
+
+            names.push((cp, name));
+        }
+        hex_name_ranges.sort();
+
+        let mut aliases = Vec::<(u32, &str, u8)>::new();
+        for line in name_aliases
+            .lines()
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        {
+            let [cp, alias, alias_type] = line.split(';').collect::<Vec<_>>()[..] else {
+                return Err(
+                    DataError::custom("Invalid NameAliases.txt line").with_display_context(line)
+                );
+            };
+            let cp = u32::from_str_radix(cp, 16).map_err(|e| {
+                DataError::custom("Invalid NameAliases.txt code point").with_display_context(&e)
+            })?;
+            let alias_type = ALIAS_TYPES
+                .iter()
+                .position(|&t| t == alias_type)
+                .ok_or_else(|| {
+                    DataError::custom("Unknown name alias type").with_display_context(alias_type)
+                })?;
+            aliases.push((cp, alias, alias_type as u8));
+        }
+        // Stable, to keep the order of aliases of the same code point
+        aliases.sort_by_key(|&(cp, _, _)| cp);
+
+        let words = names
+            .iter()
+            .flat_map(|(_, name)| name.split(' '))
+            .map(|word| (word, 0))
+            .collect::<BTreeMap<_, _>>()
+            .into_keys()
+            .collect::<Vec<_>>();
+        if words.len() > u16::MAX as usize {
+            return Err(DataError::custom(
+                "Too many distinct words in character names",
+            ));
+        }
+
+        let mut loose_order = (0..names.len() + aliases.len()).collect::<Vec<_>>();
+        loose_order.sort_by_cached_key(|&i| {
+            loose_key(
+                names
+                    .get(i)
+                    .map(|&(_, n)| n)
+                    .unwrap_or_else(|| aliases[i - names.len()].1),
+            )
+        });
+        for pair in loose_order.windows(2) {
+            let entry = |i: usize| {
+                names
+                    .get(i)
+                    .map(|&(cp, n)| (cp, n))
+                    .unwrap_or_else(|| (aliases[i - names.len()].0, aliases[i - names.len()].1))
+            };
+            let ((cp0, name0), (cp1, name1)) = (entry(pair[0]), entry(pair[1]));
+            // U+116C HANGUL JUNGSEONG OE and U+1180 HANGUL JUNGSEONG O-E only differ in
+            // a medial hyphen, which is handled at runtime.
+            if loose_key(name0) == loose_key(name1) && ![cp0, cp1].contains(&0x1180) {
+                return Err(DataError::custom(
+                    "Character names are not unique under loose matching",
+                )
+                .with_display_context(name0)
+                .with_display_context(name1));
+            }
+        }
+
+        let mut hex_name_prefixes = Vec::<&str>::new();
+        let hex_name_ranges = hex_name_ranges
+            .into_iter()
+            .map(|(start, end, prefix)| {
+                let index = hex_name_prefixes
+                    .iter()
+                    .position(|&p| p == prefix)
+                    .unwrap_or_else(|| {
+                        hex_name_prefixes.push(prefix);
+                        hex_name_prefixes.len() - 1
+                    });
+                (
+                    PotentialCodePoint::from_u24(start),
+                    PotentialCodePoint::from_u24(end),
+                    index as u8,
+                )
+            })
+            .collect::<ZeroVec<_>>();
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(PropertyCharacterNames {
+                words: VarZeroVec::<str, Index32>::from(&words),
+                code_points: names
+                    .iter()
+                    .map(|&(cp, _)| PotentialCodePoint::from_u24(cp))
+                    .collect(),
+                names: VarZeroVec::<_, Index32>::from(
+                    &names
+                        .iter()
+                        .map(|(_, name)| {
+                            name.split(' ')
+                                .map(|word| words.binary_search(&word).unwrap() as u16)
+                                .collect::<ZeroVec<u16>>()
+                        })
+                        .collect::<Vec<_>>(),
+                ),
+                hex_name_ranges,
+                hex_name_prefixes: VarZeroVec::from(&hex_name_prefixes),
+                alias_code_points: aliases
+                    .iter()
+                    .map(|&(cp, _, _)| PotentialCodePoint::from_u24(cp))
+                    .collect(),
+                aliases: VarZeroVec::from(&aliases.iter().map(|&(_, a, _)| a).collect::<Vec<_>>()),
+                alias_types: aliases.iter().map(|&(_, _, t)| t).collect(),
+                loose_order: loose_order.into_iter().map(|i| i as u32).collect(),
+            }),
+        })
+    }
+}
+
+impl crate::IterableDataProviderCached<PropertyCharacterNamesV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
+
+/// The loose matching key of a name (see UAX44-LM2), which is uppercase and has no
+/// spaces or medial hyphens.
+fn loose_key(name: &str) -> Vec<u8> {
+    let bytes = name.as_bytes();
+    bytes
+        .iter()
+        .enumerate()
+        .filter(|&(i, &b)| {
+            b != b' '
+                && !(b == b'-'
+                    && i > 0
+                    && bytes[i - 1].is_ascii_alphanumeric()
+                    && bytes.get(i + 1).is_some_and(u8::is_ascii_alphanumeric))
+        })
+        .map(|(_, &b)| b.to_ascii_uppercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu::properties::{CharacterNames, NameAliasType};
+
+    #[test]
+    fn test_names() {
+        let provider = SourceDataProvider::new_testing();
+        let names = CharacterNames::try_new_unstable(&provider).unwrap();
+        let names = names.as_borrowed();
+
+        for (c, name) in [
+            ('A', "LATIN CAPITAL LETTER A"),
+            ('á', "LATIN SMALL LETTER A WITH ACUTE"),
+            ('-', "HYPHEN-MINUS"),
+            ('\u{0F39}', "TIBETAN MARK TSA -PHRU"),
+            ('\u{116C}', "HANGUL JUNGSEONG OE"),
+            ('\u{1180}', "HANGUL JUNGSEONG O-E"),
+            ('\u{FEFF}', "ZERO WIDTH NO-BREAK SPACE"),
+            ('😀', "GRINNING FACE"),
+            ('\u{AC00}', "HANGUL SYLLABLE GA"),
+            ('한', "HANGUL SYLLABLE HAN"),
+            ('\u{C5D0}', "HANGUL SYLLABLE E"),
+            ('\u{D7A3}', "HANGUL SYLLABLE HIH"),
+            ('一', "CJK UNIFIED IDEOGRAPH-4E00"),
+            ('\u{20000}', "CJK UNIFIED IDEOGRAPH-20000"),
+            ('\u{F900}', "CJK COMPATIBILITY IDEOGRAPH-F900"),
+            ('\u{17000}', "TANGUT IDEOGRAPH-17000"),
+            ('\u{1B170}', "NUSHU CHARACTER-1B170"),
+        ] {
+            assert_eq!(names.name(c).map(|n| n.to_string()).as_deref(), Some(name));
+            assert_eq!(names.get(name), Some(c), "{name}");
+        }
+
+        for c in ['\0', '\u{85}', '\u{E000}', '\u{FFFF}', '\u{10FFFF}'] {
+            assert!(names.name(c).is_none(), "{c:?}");
+        }
+    }
+
+    #[test]
+    fn test_loose_matching() {
+        let provider = SourceDataProvider::new_testing();
+        let names = CharacterNames::try_new_unstable(&provider).unwrap();
+        let names = names.as_borrowed();
+
+        for (name, c) in [
+            ("latin small letter a with acute", Some('á')),
+            ("LatinSmallLetterAWithAcute", Some('á')),
+            ("latin_small_letter_a_with_acute", Some('á')),
+            (" latin small\tletter a-with-acute ", Some('á')),
+            ("hyphen minus", Some('-')),
+            ("HYPHENMINUS", Some('-')),
+            ("tibetan mark tsa -phru", Some('\u{0F39}')),
+            ("tibetan mark tsa-phru", None),
+            ("hangul jungseong o-e", Some('\u{1180}')),
+            ("hangul jungseong oe", Some('\u{116C}')),
+            ("Hangul_Jungseong_O_E", Some('\u{116C}')),
+            ("hangul syllable gag", Some('\u{AC01}')),
+            ("hangul syllable", None),
+            ("hangul syllable x", None),
+            ("cjk unified ideograph 4e00", Some('一')),
+            ("CJK UNIFIED IDEOGRAPH-04E00", None),
+            ("CJK UNIFIED IDEOGRAPH-F900", None),
+            ("CJK COMPATIBILITY IDEOGRAPH-4E00", None),
+            ("LATIN CAPITAL LETTER", None),
+            ("LATIN CAPITAL LETTER AA", None),
+            ("LATIN CAPITAL LETTER Á", None),
+            ("", None),
+        ] {
+            assert_eq!(names.get(name), c, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_aliases() {
+        let provider = SourceDataProvider::new_testing();
+        let names = CharacterNames::try_new_unstable(&provider).unwrap();
+        let names = names.as_borrowed();
+
+        assert_eq!(
+            names.aliases('\0').collect::<Vec<_>>(),
+            [
+                ("NULL", NameAliasType::Control),
+                ("NUL", NameAliasType::Abbreviation)
+            ]
+        );
+        assert_eq!(
+            names.aliases('\u{FEFF}').collect::<Vec<_>>(),
+            [
+                ("BYTE ORDER MARK", NameAliasType::Alternate),
+                ("BOM", NameAliasType::Abbreviation),
+                ("ZWNBSP", NameAliasType::Abbreviation)
+            ]
+        );
+        assert_eq!(names.aliases('A').count(), 0);
+
+        assert_eq!(names.get("null"), Some('\0'));
+        assert_eq!(names.get("line feed"), Some('\n'));
+        assert_eq!(names.get("LF"), Some('\n'));
+        assert_eq!(names.get("byte order mark"), Some('\u{FEFF}'));
+        assert_eq!(names.get("LATIN CAPITAL LETTER GHA"), Some('\u{01A2}'));
+        assert_eq!(names.get("LATIN CAPITAL LETTER OI"), Some('\u{01A2}'));
+    }
+}
@@ -7,7 +7,7 @@ use icu::collections::codepointinvlist::CodePointInversionListBuilder;
 use icu::collections::codepointinvliststringlist::CodePointInversionListAndStringList;
 use icu::properties::provider::*;
 use icu_provider::prelude::*;
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use zerovec::VarZeroVec;
 
 impl SourceDataProvider {
@@ -72,6 +72,132 @@ macro_rules! expand {
 
 expand!((PropertyBinaryBasicEmojiV1, "Basic_Emoji"));
 
+/// The files from the Unicode emoji data that define properties of strings.
+const EMOJI_SEQUENCE_FILES: &[&str] = &["emoji-sequences.txt", "emoji-zwj-sequences.txt"];
+
+impl SourceDataProvider {
+    /// Collects the code points and sequences of the given types from the emoji sequence files.
+    fn get_emoji_sequences(
+        &self,
+        types: &[&str],
+    ) -> Result<CodePointInversionListAndStringList<'static>, DataError> {
+        let mut builder = CodePointInversionListBuilder::new();
+        let mut strings = BTreeSet::new();
+        for file in EMOJI_SEQUENCE_FILES {
+            for line in self.emoji()?.root.read_to_string(file)?.lines() {
+                let line = line.split('#').next().unwrap_or_default().trim();
+                if line.is_empty() {
+                    continue;
+                }
+                let mut fields = line.split(';').map(str::trim);
+                let (Some(code_points), Some(ty)) = (fields.next(), fields.next()) else {
+                    return Err(
+                        DataError::custom("Invalid emoji sequence line").with_display_context(line)
+                    );
+                };
+                if !types.contains(&ty) {
+                    continue;
+                }
+                let parse = |cp| {
+                    u32::from_str_radix(cp, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| {
+                            DataError::custom("Invalid emoji code point").with_display_context(cp)
+                        })
+                };
+                if let Some((start, end)) = code_points.split_once("..") {
+                    builder.add_range(parse(start)?..=parse(end)?);
+                } else {
+                    let sequence = code_points
+                        .split_whitespace()
+                        .map(parse)
+                        .collect::<Result<String, _>>()?;
+                    let mut chars = sequence.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => builder.add_char(c),
+                        _ => {
+                            strings.insert(sequence);
+                        }
+                    }
+                }
+            }
+        }
+        CodePointInversionListAndStringList::try_from(
+            builder.build(),
+            VarZeroVec::from(&strings.into_iter().collect::<Vec<_>>()),
+        )
+        .map_err(|_| {
+            DataError::custom(
+                "Error in constructing CodePointInversionListAndStringList from emoji sequences",
+            )
+        })
+    }
+}
+
+macro_rules! expand_sequences {
+    ($(($marker:ident, [$($ty:literal),+])),+) => {
+        $(
+            impl DataProvider<$marker> for SourceDataProvider {
+                fn load(
+                    &self,
+                    req: DataRequest,
+                ) -> Result<DataResponse<$marker>, DataError> {
+                    self.check_req::<$marker>(req)?;
+                    Ok(DataResponse {
+                        metadata: Default::default(),
+                        payload: DataPayload::from_owned(
+                            PropertyUnicodeSet::CPInversionListStrList(
+                                self.get_emoji_sequences(&[$($ty),+])?,
+                            ),
+                        ),
+                    })
+                }
+            }
+
+            impl crate::IterableDataProviderCached<$marker> for SourceDataProvider {
+                fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+                    Ok(HashSet::from_iter([Default::default()]))
+                }
+            }
+        )+
+    };
+}
+
+expand_sequences!(
+    (
+        PropertyBinaryEmojiKeycapSequenceV1,
+        ["Emoji_Keycap_Sequence"]
+    ),
+    (
+        PropertyBinaryRgiEmojiFlagSequenceV1,
+        ["RGI_Emoji_Flag_Sequence"]
+    ),
+    (
+        PropertyBinaryRgiEmojiModifierSequenceV1,
+        ["RGI_Emoji_Modifier_Sequence"]
+    ),
+    (
+        PropertyBinaryRgiEmojiTagSequenceV1,
+        ["RGI_Emoji_Tag_Sequence"]
+    ),
+    (
+        PropertyBinaryRgiEmojiZwjSequenceV1,
+        ["RGI_Emoji_ZWJ_Sequence"]
+    ),
+    (
+        PropertyBinaryRgiEmojiV1,
+        [
+            "Basic_Emoji",
+            "Emoji_Keycap_Sequence",
+            "RGI_Emoji_Flag_Sequence",
+            "RGI_Emoji_Modifier_Sequence",
+            "RGI_Emoji_Tag_Sequence",
+            "RGI_Emoji_ZWJ_Sequence"
+        ]
+    )
+);
+
 #[test]
 fn test_basic() {
     use icu::properties::{props::BasicEmoji, EmojiSetData};
@@ -90,3 +216,52 @@ fn test_basic() {
     assert!(basic_emoji.contains_str("\u{1F6E4}\u{FE0F}")); // railway track
     assert!(!basic_emoji.contains_str("\u{0033}\u{FE0F}\u{20E3}")); // Emoji_Keycap_Sequence, keycap 3
 }
+
+#[test]
+fn test_sequences() {
+    use icu::properties::props::{
+        BasicEmoji, EmojiKeycapSequence, RgiEmoji, RgiEmojiFlagSequence, RgiEmojiModifierSequence,
+        RgiEmojiTagSequence, RgiEmojiZwjSequence,
+    };
+    use icu::properties::EmojiSetData;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let keycaps = EmojiSetData::try_new_unstable::<EmojiKeycapSequence>(&provider).unwrap();
+    let keycaps = keycaps.as_borrowed();
+    assert!(keycaps.contains_str("#\u{FE0F}\u{20E3}"));
+    assert!(keycaps.contains_str("9\u{FE0F}\u{20E3}"));
+    assert!(!keycaps.contains_str("9\u{20E3}"));
+    assert!(!keycaps.contains('9'));
+
+    let flags = EmojiSetData::try_new_unstable::<RgiEmojiFlagSequence>(&provider).unwrap();
+    let flags = flags.as_borrowed();
+    assert!(flags.contains_str("🇺🇦"));
+    assert!(!flags.contains_str("🇺🇺"));
+    assert!(!flags.contains('🇺'));
+
+    let modifiers = EmojiSetData::try_new_unstable::<RgiEmojiModifierSequence>(&provider).unwrap();
+    assert!(modifiers.as_borrowed().contains_str("\u{1F44B}\u{1F3FD}"));
+
+    let tags = EmojiSetData::try_new_unstable::<RgiEmojiTagSequence>(&provider).unwrap();
+    assert!(tags
+        .as_borrowed()
+        .contains_str("\u{1F3F4}\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}"));
+
+    let zwj = EmojiSetData::try_new_unstable::<RgiEmojiZwjSequence>(&provider).unwrap();
+    let zwj = zwj.as_borrowed();
+    assert!(zwj.contains_str("\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}"));
+    assert!(!zwj.contains_str("\u{1F3F3}\u{200D}\u{1F308}"));
+
+    // Basic_Emoji from the emoji data matches the one from ICU
+    let basic = EmojiSetData::try_new_unstable::<BasicEmoji>(&provider).unwrap();
+    let basic = basic.as_code_point_inversion_list_string_list().unwrap();
+    let rgi = EmojiSetData::try_new_unstable::<RgiEmoji>(&provider).unwrap();
+    let rgi = rgi.as_borrowed();
+    assert!(basic.code_points().iter_chars().all(|c| rgi.contains(c)));
+    assert!(basic.strings().iter().all(|s| rgi.contains_str(s)));
+    assert!(rgi.contains_str("9\u{FE0F}\u{20E3}"));
+    assert!(rgi.contains_str("🇺🇦"));
+    assert!(rgi.contains_str("\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}"));
+    assert!(!rgi.contains('9'));
+}
@@ -7,6 +7,7 @@
 
 mod bidi;
 mod bin_cp_set;
+mod character_names;
 mod emoji_set;
 mod enum_codepointtrie;
 mod script;
@@ -83,7 +83,6 @@ pub(crate) mod code_point_prop {
         pub(crate) _short_name: String,
         #[serde(rename = "ranges")]
         pub(crate) _ranges: Vec<CodePointPropertyMapRange>,
-        #[cfg(any(feature = "use_wasm", feature = "use_icu4c"))]
         pub(crate) code_point_trie: super::CodePointTrieToml,
     }
 
@@ -91,7 +90,6 @@ pub(crate) mod code_point_prop {
     pub(crate) struct Main {
         // TODO: update icuexportdata to print a different TOML header than "enum_property"
         #[serde(default)]
-        #[cfg(any(feature = "use_wasm", feature = "use_icu4c"))]
         pub(crate) enum_property: Vec<CodePointPropertyMap>,
     }
 }
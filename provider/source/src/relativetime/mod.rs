@@ -8,8 +8,9 @@ use crate::SourceDataProvider;
 use icu::experimental::relativetime::provider::*;
 use icu::plurals::provider::PluralElementsPackedCow;
 use icu::plurals::PluralElements;
-use icu_pattern::SinglePlaceholderPattern;
+use icu_pattern::{DoublePlaceholderPattern, QuoteMode, SinglePlaceholderPattern};
 use icu_provider::prelude::*;
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::sync::OnceLock;
 
@@ -135,6 +136,60 @@ make_data_provider!(
     NarrowYearRelativeV1,
 );
 
+impl DataProvider<RelativeAtTimeV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<RelativeAtTimeV1>, DataError> {
+        self.check_req::<RelativeAtTimeV1>(req)?;
+        let resource: &cldr_serde::ca::Resource = self
+            .cldr()?
+            .dates("gregorian")
+            .read_and_parse(req.id.locale, "ca-gregorian.json")?;
+        let dates = resource
+            .main
+            .value
+            .dates
+            .calendars
+            .get("gregorian")
+            .expect("CLDR file contains a gregorian calendar");
+
+        // The relative patterns are meant for relative dates, but are missing in older CLDR versions
+        let patterns = &dates
+            .datetime_formats_relative
+            .as_ref()
+            .or(dates.datetime_formats_at_time.as_ref())
+            .ok_or_else(|| DataError::custom("Missing atTime patterns"))?
+            .standard;
+
+        let parse = |pattern: &cldr_serde::ca::LengthPattern| {
+            DoublePlaceholderPattern::try_from_str(
+                pattern.get_pattern(),
+                QuoteMode::QuotingSupported.into(),
+            )
+            .map(Cow::Owned)
+            .map_err(|e| DataError::custom("Could not parse pattern").with_display_context(&e))
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(RelativeAtTimePatterns {
+                long: parse(&patterns.long)?,
+                medium: parse(&patterns.medium)?,
+                short: parse(&patterns.short)?,
+            }),
+        })
+    }
+}
+
+impl IterableDataProviderCached<RelativeAtTimeV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(self
+            .cldr()?
+            .dates("gregorian")
+            .list_locales()?
+            .map(DataIdentifierCow::from_locale)
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,4 +257,33 @@ mod tests {
             "خلال 100 سنة"
         );
     }
+
+    #[test]
+    fn test_at_time() {
+        let provider = SourceDataProvider::new_testing();
+        let load = |locale| {
+            let data: DataPayload<RelativeAtTimeV1> = provider
+                .load(DataRequest {
+                    id: DataIdentifierBorrowed::for_locale(&locale),
+                    ..Default::default()
+                })
+                .unwrap()
+                .payload;
+            data
+        };
+
+        let en = load(langid!("en").into());
+        assert_writeable_eq!(
+            en.get().long.interpolate(("5:30 PM", "yesterday")),
+            "yesterday at 5:30 PM"
+        );
+        assert_writeable_eq!(
+            en.get().short.interpolate(("5:30 PM", "yesterday")),
+            "yesterday, 5:30 PM"
+        );
+
+        // Uses the relative pattern, which differs from the atTime pattern
+        let ja = load(langid!("ja").into());
+        assert_writeable_eq!(ja.get().long.interpolate(("17:30", "昨日")), "昨日の 17:30");
+    }
 }
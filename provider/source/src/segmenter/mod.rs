@@ -98,7 +98,6 @@ fn fill_bounded(dst: &mut [u8], r: RangeInclusive<u32>, value: u8) {
     dst[start..=end].fill(value);
 }
 
-#[cfg(any(feature = "use_wasm", feature = "use_icu4c"))]
 fn generate_rule_break_data(
     provider: &SourceDataProvider,
     rules_file: &str,
@@ -632,7 +631,6 @@ fn generate_rule_break_data(
     }
 }
 
-#[cfg(any(feature = "use_wasm", feature = "use_icu4c"))]
 fn generate_rule_break_data_override(
     provider: &SourceDataProvider,
     rules_file: &str,
@@ -706,25 +704,17 @@ macro_rules! implement {
     ($marker:ident, $rules:literal) => {
         impl DataProvider<$marker> for SourceDataProvider {
             fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
-                #[cfg(not(any(feature = "use_wasm", feature = "use_icu4c")))]
-                return Err(DataError::custom(
-                    "icu_provider_source must be built with use_icu4c or use_wasm to build segmentation rules",
-                )
-                .with_req($marker::INFO, req));
-                #[cfg(any(feature = "use_wasm", feature = "use_icu4c"))]
-                return {
-                    self.check_req::<$marker>(req)?;
-                    let data = generate_rule_break_data(
-                        &hardcoded_segmenter_provider(),
-                        $rules,
-                        self.trie_type(),
-                    );
-
-                    Ok(DataResponse {
-                        metadata: Default::default(),
-                        payload: DataPayload::from_owned(data),
-                    })
-                };
+                self.check_req::<$marker>(req)?;
+                let data = generate_rule_break_data(
+                    &hardcoded_segmenter_provider(),
+                    $rules,
+                    self.trie_type(),
+                );
+
+                Ok(DataResponse {
+                    metadata: Default::default(),
+                    payload: DataPayload::from_owned(data),
+                })
             }
         }
 
@@ -733,32 +723,24 @@ macro_rules! implement {
                 Ok(HashSet::from_iter([Default::default()]))
             }
         }
-    }
+    };
 }
 
 macro_rules! implement_override {
     ($marker:ident, $rules:literal, [$($supported:expr),*]) => {
         impl DataProvider<$marker> for SourceDataProvider {
             fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
-                #[cfg(not(any(feature = "use_wasm", feature = "use_icu4c")))]
-                return Err(DataError::custom(
-                    "icu_provider_source must be built with use_icu4c or use_wasm to build segmentation rules",
-                )
-                .with_req($marker::INFO, req));
-                #[cfg(any(feature = "use_wasm", feature = "use_icu4c"))]
-                return {
-                    self.check_req::<$marker>(req)?;
-                    let data = generate_rule_break_data_override(
-                        &hardcoded_segmenter_provider(),
-                        $rules,
-                        self.trie_type(),
-                    );
-
-                    Ok(DataResponse {
-                        metadata: Default::default(),
-                        payload: DataPayload::from_owned(data),
-                    })
-                };
+                self.check_req::<$marker>(req)?;
+                let data = generate_rule_break_data_override(
+                    &hardcoded_segmenter_provider(),
+                    $rules,
+                    self.trie_type(),
+                );
+
+                Ok(DataResponse {
+                    metadata: Default::default(),
+                    payload: DataPayload::from_owned(data),
+                })
             }
         }
 
@@ -111,6 +111,9 @@ pub(crate) enum AbstractFs {
     Zip(RwLock<Result<ZipData, String>>),
     Tar(RwLock<Result<TarArchive, String>>),
     Memory(BTreeMap<&'static str, &'static [u8]>),
+    /// A directory on a web server, from which files are downloaded individually
+    #[cfg(feature = "networking")]
+    Remote(String),
 }
 
 impl Debug for AbstractFs {
@@ -119,6 +122,32 @@ impl Debug for AbstractFs {
     }
 }
 
+#[cfg(feature = "networking")]
+fn download(resource: &String) -> Result<PathBuf, DataError> {
+    let root = std::env::var_os("ICU4X_SOURCE_CACHE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| std::env::temp_dir().join("icu4x-source-cache/"))
+        .join(resource.rsplit("//").next().unwrap());
+    if !root.exists() {
+        log::info!("Downloading {resource}");
+        std::fs::create_dir_all(root.parent().unwrap())?;
+        let mut retry = 5;
+        let mut response = loop {
+            match ureq::get(resource).call() {
+                Ok(r) => break r.into_body().into_reader(),
+                Err(e) if retry > 0 => {
+                    log::warn!("Download error {e:?}, retrying...");
+                    std::thread::sleep(std::time::Duration::from_secs(2));
+                    retry -= 1;
+                }
+                Err(e) => return Err(DataError::custom("Download").with_display_context(&e)),
+            }
+        };
+        std::io::copy(&mut response, &mut BufWriter::new(File::create(&root)?))?;
+    }
+    Ok(root)
+}
+
 impl AbstractFs {
     pub fn new(root: &Path) -> Result<Self, DataError> {
         if std::fs::metadata(root)
@@ -160,7 +189,9 @@ impl AbstractFs {
 
     #[cfg(feature = "networking")]
     pub fn new_from_url(path: String) -> Self {
-        if path.ends_with(".zip") {
+        if path.ends_with('/') {
+            Self::Remote(path)
+        } else if path.ends_with(".zip") {
             Self::Zip(RwLock::new(Err(path)))
         } else {
             Self::Tar(RwLock::new(Err(path)))
@@ -168,34 +199,6 @@ impl AbstractFs {
     }
 
     fn init(&self) -> Result<(), DataError> {
-        #[cfg(feature = "networking")]
-        fn download(resource: &String) -> Result<PathBuf, DataError> {
-            let root = std::env::var_os("ICU4X_SOURCE_CACHE")
-                .map(PathBuf::from)
-                .unwrap_or_else(|| std::env::temp_dir().join("icu4x-source-cache/"))
-                .join(resource.rsplit("//").next().unwrap());
-            if !root.exists() {
-                log::info!("Downloading {resource}");
-                std::fs::create_dir_all(root.parent().unwrap())?;
-                let mut retry = 5;
-                let mut response = loop {
-                    match ureq::get(resource).call() {
-                        Ok(r) => break r.into_body().into_reader(),
-                        Err(e) if retry > 0 => {
-                            log::warn!("Download error {e:?}, retrying...");
-                            std::thread::sleep(std::time::Duration::from_secs(2));
-                            retry -= 1;
-                        }
-                        Err(e) => {
-                            return Err(DataError::custom("Download").with_display_context(&e))
-                        }
-                    }
-                };
-                std::io::copy(&mut response, &mut BufWriter::new(File::create(&root)?))?;
-            }
-            Ok(root)
-        }
-
         #[cfg(feature = "networking")]
         if let Self::Zip(lock) = self {
             if lock.read().expect("poison").is_ok() {
@@ -306,6 +309,11 @@ impl AbstractFs {
             Self::Memory(map) => map.get(path).copied().map(Vec::from).ok_or_else(|| {
                 DataError::custom("Not found in icu4x-datagen's data/").with_display_context(path)
             }),
+            #[cfg(feature = "networking")]
+            Self::Remote(root) => {
+                let path = download(&format!("{root}{path}"))?;
+                std::fs::read(&path).map_err(|e| DataError::from(e).with_path_context(&path))
+            }
         }
     }
 
@@ -359,6 +367,11 @@ impl AbstractFs {
                 .map(String::from)
                 .collect::<HashSet<_>>()
                 .into_iter(),
+            #[cfg(feature = "networking")]
+            Self::Remote(root) => {
+                return Err(DataError::custom("Cannot list remote directory")
+                    .with_display_context(&format!("{root}{path}")))
+            }
         })
     }
 
@@ -383,6 +396,8 @@ impl AbstractFs {
                 .file_list
                 .contains(path),
             Self::Memory(map) => map.contains_key(path),
+            #[cfg(feature = "networking")]
+            Self::Remote(_) => self.read_to_buf(path).is_ok(),
         })
     }
 }
@@ -237,6 +237,7 @@ impl SourceDataProvider {
                         ("cldr-core/supplemental/aliases.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/aliases.json").as_slice()),
                         ("cldr-core/supplemental/calendarData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/calendarData.json").as_slice()),
                         ("cldr-core/supplemental/currencyData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/currencyData.json").as_slice()),
+                        ("cldr-core/supplemental/dayPeriods.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/dayPeriods.json").as_slice()),
                         ("cldr-core/supplemental/units.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/units.json").as_slice()),
                         ("cldr-core/supplemental/likelySubtags.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/likelySubtags.json").as_slice()),
                         ("cldr-core/supplemental/metaZones.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/metaZones.json").as_slice()),
@@ -247,6 +248,7 @@ impl SourceDataProvider {
                         ("cldr-core/supplemental/parentLocales.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/parentLocales.json").as_slice()),
                         ("cldr-core/supplemental/pluralRanges.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/pluralRanges.json").as_slice()),
                         ("cldr-core/supplemental/plurals.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/plurals.json").as_slice()),
+                        ("cldr-core/supplemental/timeData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/timeData.json").as_slice()),
                         ("cldr-core/supplemental/weekData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/weekData.json").as_slice()),
                         ("cldr-dates-full/main/ar/ca-generic.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar/ca-generic.json").as_slice()),
                         ("cldr-dates-full/main/ar-EG/ca-generic.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar-EG/ca-generic.json").as_slice()),
@@ -694,6 +696,32 @@ impl SourceDataProvider {
                         ("vanguard.zi", include_bytes!("../../tests/data/tzdb/vanguard.zi").as_slice())
                     ].into_iter().collect(),
                 ), transitions: Default::default() })),
+                ucd_paths: Some(Arc::new(SerdeCache::new(AbstractFs::Memory(
+                    [
+                        ("NameAliases.txt", include_bytes!("../../tests/data/ucd/NameAliases.txt").as_slice()),
+                        ("UnicodeData.txt", include_bytes!("../../tests/data/ucd/UnicodeData.txt").as_slice())
+                    ].into_iter().collect(),
+                )))),
+                emoji_paths: Some(Arc::new(SerdeCache::new(AbstractFs::Memory(
+                    [
+                        ("emoji-sequences.txt", include_bytes!("../../tests/data/emoji/emoji-sequences.txt").as_slice()),
+                        ("emoji-zwj-sequences.txt", include_bytes!("../../tests/data/emoji/emoji-zwj-sequences.txt").as_slice())
+                    ].into_iter().collect(),
+                )))),
+                cldr_common_paths: Some(Arc::new(SerdeCache::new(AbstractFs::Memory(
+                    [
+                        ("common/bcp47/calendar.xml", include_bytes!("../../tests/data/cldr-common/common/bcp47/calendar.xml").as_slice()),
+                        ("common/bcp47/collation.xml", include_bytes!("../../tests/data/cldr-common/common/bcp47/collation.xml").as_slice()),
+                        ("common/bcp47/number.xml", include_bytes!("../../tests/data/cldr-common/common/bcp47/number.xml").as_slice()),
+                        ("common/bcp47/transform.xml", include_bytes!("../../tests/data/cldr-common/common/bcp47/transform.xml").as_slice()),
+                        ("common/bcp47/transform_private_use.xml", include_bytes!("../../tests/data/cldr-common/common/bcp47/transform_private_use.xml").as_slice()),
+                        ("common/validity/language.xml", include_bytes!("../../tests/data/cldr-common/common/validity/language.xml").as_slice()),
+                        ("common/validity/region.xml", include_bytes!("../../tests/data/cldr-common/common/validity/region.xml").as_slice()),
+                        ("common/validity/script.xml", include_bytes!("../../tests/data/cldr-common/common/validity/script.xml").as_slice()),
+                        ("common/validity/subdivision.xml", include_bytes!("../../tests/data/cldr-common/common/validity/subdivision.xml").as_slice()),
+                        ("common/validity/variant.xml", include_bytes!("../../tests/data/cldr-common/common/validity/variant.xml").as_slice())
+                    ].into_iter().collect(),
+                )))),
                 ..SourceDataProvider::new_custom()
             })
             .clone()
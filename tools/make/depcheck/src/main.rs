@@ -279,7 +279,7 @@ fn main() {
     test_dep_list(
         "icu_provider_source",
         "normal",
-        "--features use_icu4c",
+        "",
         &[
             &basic,
             &serde,
@@ -154,6 +154,18 @@ lazy_static::lazy_static! {
         // Not planned for 2.0: Temporal doesn't yet want this.
         "icu::calendar::types::YearAmbiguity",
 
+        // Not planned yet: locale-aware week numbering and the week field sets.
+        // FFI exposes ISO weeks via Date::week_of_year.
+        "icu::calendar::types::DayOfYearInfo",
+        "icu::calendar::Date::day_of_year_info",
+        "icu::calendar::week::RelativeUnit",
+        "icu::calendar::week::WeekOf",
+        "icu::calendar::week::WeekInformation::min_week_days",
+        "icu::calendar::week::WeekInformation::week_of_month",
+        "icu::calendar::week::WeekInformation::week_of_year",
+        "icu::datetime::fieldsets::MW",
+        "icu::datetime::fieldsets::YW",
+
         // Not planned for 2.0: datetime dynamic field sets (and builder) need FFI design work,
         // and all functionality is available via static field sets
         // <https://github.com/unicode-org/icu4x/issues/6445>
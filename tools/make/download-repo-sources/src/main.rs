@@ -228,6 +228,47 @@ fn main() -> eyre::Result<()> {
     tzdb_data.remove("Makefile");
     tzdb_data.remove("ziguard.awk");
 
+    std::fs::remove_dir_all(out_root.join("tests/data/ucd"))?;
+    extract_zip(
+        cached(&format!(
+            "https://www.unicode.org/Public/{}/ucd/UCD.zip",
+            SourceDataProvider::TESTED_UCD_TAG,
+        ))
+        .with_context(|| "Failed to download UCD ZIP".to_owned())?,
+        UCD_GLOB.iter().copied().map(String::from).collect(),
+        out_root.join("tests/data/ucd"),
+        &mut Default::default(),
+    )?;
+
+    std::fs::remove_dir_all(out_root.join("tests/data/emoji"))?;
+    std::fs::create_dir(out_root.join("tests/data/emoji"))?;
+    for file in EMOJI_GLOB {
+        std::fs::copy(
+            cached(&format!(
+                "https://www.unicode.org/Public/emoji/{}/{file}",
+                SourceDataProvider::TESTED_EMOJI_TAG,
+            ))
+            .with_context(|| format!("Failed to download {file}"))?,
+            out_root.join("tests/data/emoji").join(file),
+        )?;
+    }
+
+    std::fs::remove_dir_all(out_root.join("tests/data/cldr-common"))?;
+    extract_zip(
+        cached(&format!(
+            "https://unicode.org/Public/cldr/{}/cldr-common-{}.zip",
+            SourceDataProvider::TESTED_CLDR_COMMON_TAG
+                .split('.')
+                .next()
+                .unwrap(),
+            SourceDataProvider::TESTED_CLDR_COMMON_TAG,
+        ))
+        .with_context(|| "Failed to download CLDR common ZIP".to_owned())?,
+        CLDR_COMMON_GLOB.iter().copied().map(String::from).collect(),
+        out_root.join("tests/data/cldr-common"),
+        &mut Default::default(),
+    )?;
+
     let cldr_data = cldr_data
         .iter()
         .map(|path| {
@@ -252,6 +293,29 @@ fn main() -> eyre::Result<()> {
         })
         .collect::<Vec<_>>()
         .join(",\n                        ");
+    let ucd_data = UCD_GLOB
+        .iter()
+        .map(|path| {
+            format!(r#"("{path}", include_bytes!("../../tests/data/ucd/{path}").as_slice())"#)
+        })
+        .collect::<Vec<_>>()
+        .join(",\n                        ");
+    let emoji_data = EMOJI_GLOB
+        .iter()
+        .map(|path| {
+            format!(r#"("{path}", include_bytes!("../../tests/data/emoji/{path}").as_slice())"#)
+        })
+        .collect::<Vec<_>>()
+        .join(",\n                        ");
+    let cldr_common_data = CLDR_COMMON_GLOB
+        .iter()
+        .map(|path| {
+            format!(
+                r#"("{path}", include_bytes!("../../tests/data/cldr-common/{path}").as_slice())"#
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",\n                        ");
     let tzdb_data: String = tzdb_data
         .iter()
         .map(|path| {
@@ -297,6 +361,21 @@ impl SourceDataProvider {{
                         {tzdb_data}
                     ].into_iter().collect(),
                 ), transitions: Default::default() }})),
+                ucd_paths: Some(Arc::new(SerdeCache::new(AbstractFs::Memory(
+                    [
+                        {ucd_data}
+                    ].into_iter().collect(),
+                )))),
+                emoji_paths: Some(Arc::new(SerdeCache::new(AbstractFs::Memory(
+                    [
+                        {emoji_data}
+                    ].into_iter().collect(),
+                )))),
+                cldr_common_paths: Some(Arc::new(SerdeCache::new(AbstractFs::Memory(
+                    [
+                        {cldr_common_data}
+                    ].into_iter().collect(),
+                )))),
                 ..SourceDataProvider::new_custom()
             }})
             .clone()
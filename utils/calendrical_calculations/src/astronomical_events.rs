@@ -0,0 +1,148 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The functions in this module compute the times of astronomical events using the
+//! algorithms from _Calendrical Calculations_ by Reingold & Dershowitz.
+//!
+//! Moments are represented as `f64` numbers of days in universal time, where the integer part
+//! is the [`RataDie`] of the date and the fractional part is the time of day.
+//!
+//! Locations are given as a latitude in degrees from -90 to 90 (positive is north), a longitude
+//! in degrees from -180 to 180 (positive is east), and an elevation in meters.
+
+use crate::astronomy::{Astronomical, Location};
+use crate::rata_die::{Moment, RataDie};
+
+/// The angle of depression of the sun at civil dawn and dusk, in degrees.
+pub const CIVIL_TWILIGHT: f64 = 6.0;
+
+/// The angle of depression of the sun at nautical dawn and dusk, in degrees.
+pub const NAUTICAL_TWILIGHT: f64 = 12.0;
+
+/// The angle of depression of the sun at astronomical dawn and dusk, in degrees.
+pub const ASTRONOMICAL_TWILIGHT: f64 = 18.0;
+
+fn location(latitude: f64, longitude: f64, elevation: f64) -> Location {
+    debug_assert!((-90.0..=90.0).contains(&latitude), "{latitude}");
+    debug_assert!((-180.0..=180.0).contains(&longitude), "{longitude}");
+    Location {
+        latitude,
+        longitude,
+        elevation,
+        utc_offset: 0.0,
+    }
+}
+
+/// The moment of sunrise on the given date at the given location, or `None` if the sun
+/// does not rise on that date.
+///
+/// The date is interpreted in local mean time at the location.
+pub fn sunrise(date: RataDie, latitude: f64, longitude: f64, elevation: f64) -> Option<f64> {
+    Astronomical::sunrise(date.as_moment(), location(latitude, longitude, elevation))
+        .map(Moment::inner)
+}
+
+/// The moment of sunset on the given date at the given location, or `None` if the sun
+/// does not set on that date.
+///
+/// The date is interpreted in local mean time at the location.
+pub fn sunset(date: RataDie, latitude: f64, longitude: f64, elevation: f64) -> Option<f64> {
+    Astronomical::sunset(date.as_moment(), location(latitude, longitude, elevation))
+        .map(Moment::inner)
+}
+
+/// The moment in the morning of the given date at which the sun is `depression` degrees below
+/// the horizon at the given location, or `None` if that does not happen on that date.
+///
+/// The date is interpreted in local mean time at the location.
+pub fn dawn(date: RataDie, latitude: f64, longitude: f64, depression: f64) -> Option<f64> {
+    Astronomical::dawn(
+        date.to_f64_date(),
+        location(latitude, longitude, 0.0),
+        depression,
+    )
+    .map(Moment::inner)
+}
+
+/// The moment in the evening of the given date at which the sun is `depression` degrees below
+/// the horizon at the given location, or `None` if that does not happen on that date.
+///
+/// The date is interpreted in local mean time at the location.
+pub fn dusk(date: RataDie, latitude: f64, longitude: f64, depression: f64) -> Option<f64> {
+    Astronomical::dusk(
+        date.to_f64_date(),
+        location(latitude, longitude, 0.0),
+        depression,
+    )
+    .map(Moment::inner)
+}
+
+/// The phase of the moon at the given moment, as the difference between the longitudes of the
+/// moon and the sun in degrees, from 0 (new moon) to 360.
+pub fn lunar_phase(moment: f64) -> f64 {
+    let moment = Moment::new(moment);
+    Astronomical::lunar_phase(moment, Astronomical::julian_centuries(moment))
+}
+
+/// The first moment at or after the given moment at which the phase of the moon
+/// (see [`lunar_phase`]) is `phase` degrees.
+pub fn lunar_phase_at_or_after(phase: f64, moment: f64) -> f64 {
+    Astronomical::lunar_phase_at_or_after(phase, Moment::new(moment)).inner()
+}
+
+/// The longitude of the sun at the given moment, in degrees from 0 (March equinox) to 360.
+pub fn solar_longitude(moment: f64) -> f64 {
+    Astronomical::solar_longitude(Astronomical::julian_centuries(Moment::new(moment)))
+}
+
+/// The first moment at or after the given moment at which the longitude of the sun
+/// (see [`solar_longitude`]) is `angle` degrees.
+pub fn solar_longitude_after(angle: f64, moment: f64) -> f64 {
+    Astronomical::solar_longitude_after(angle, Moment::new(moment)).inner()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iso::fixed_from_iso;
+
+    #[test]
+    fn test_events_in_order() {
+        // Greenwich
+        let date = fixed_from_iso(2024, 3, 20);
+        let (lat, long) = (51.4769, 0.0);
+
+        let events = [
+            dawn(date, lat, long, ASTRONOMICAL_TWILIGHT).unwrap(),
+            dawn(date, lat, long, NAUTICAL_TWILIGHT).unwrap(),
+            dawn(date, lat, long, CIVIL_TWILIGHT).unwrap(),
+            sunrise(date, lat, long, 0.0).unwrap(),
+            sunset(date, lat, long, 0.0).unwrap(),
+            dusk(date, lat, long, CIVIL_TWILIGHT).unwrap(),
+            dusk(date, lat, long, NAUTICAL_TWILIGHT).unwrap(),
+            dusk(date, lat, long, ASTRONOMICAL_TWILIGHT).unwrap(),
+        ];
+        for pair in events.windows(2) {
+            assert!(pair[0] < pair[1], "{events:?}");
+        }
+        assert_eq!(events[0].floor(), date.to_f64_date());
+        assert_eq!(events[7].floor(), date.to_f64_date());
+    }
+
+    #[test]
+    fn test_phase_and_longitude_after() {
+        let start = fixed_from_iso(2024, 1, 1).to_f64_date();
+        for angle in [0.0, 90.0, 180.0, 270.0] {
+            let moment = lunar_phase_at_or_after(angle, start);
+            assert!(moment >= start && moment < start + 29.6);
+            assert!(((lunar_phase(moment) - angle + 180.0).rem_euclid(360.0) - 180.0).abs() < 1e-3);
+
+            let moment = solar_longitude_after(angle, start);
+            assert!(moment >= start && moment < start + 366.0);
+            assert!(
+                ((solar_longitude(moment) - angle + 180.0).rem_euclid(360.0) - 180.0).abs() < 1e-3
+            );
+        }
+    }
+}
@@ -343,6 +343,21 @@ impl Astronomical {
         equation.signum() * equation.abs().min(12.0 / 24.0)
     }
 
+    /// The standard time of dawn at a given location on a given date, or `None` if there is no
+    /// dawn on that date.
+    ///
+    /// Based on functions from _Calendrical Calculations_ by Reingold & Dershowitz.
+    /// Reference lisp code: `dawn` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+    pub fn dawn(date: f64, location: Location, alpha: f64) -> Option<Moment> {
+        let morning = true;
+        let moment_of_depression =
+            Self::moment_of_depression(Moment::new(date + (6.0 / 24.0)), location, alpha, morning)?;
+        Some(Location::standard_from_local(
+            moment_of_depression,
+            location,
+        ))
+    }
+
     /// The standard time of dusk at a given location on a given date, or `None` if there is no
     /// dusk on that date.
     ///
@@ -1495,6 +1510,16 @@ impl Astronomical {
         }
     }
 
+    /// Standard time of sunrise on the date of the given moment and at the given location.
+    /// Returns `None` if there is no such sunrise.
+    ///
+    /// Based on functions from _Calendrical Calculations_ by Reingold & Dershowitz.
+    /// Lisp code reference: `sunrise` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+    pub fn sunrise(date: Moment, location: Location) -> Option<Moment> {
+        let alpha = Self::refraction(location) + (16.0 / 60.0);
+        Self::dawn(date.inner(), location, alpha)
+    }
+
     /// Standard time of sunset on the date of the given moment and at the given location.
     /// Returns `None` if there is no such sunset.
     ///
@@ -1579,6 +1604,45 @@ impl Astronomical {
         Moment::new(invert_angular(lunar_phase_f64, phase, (a, b)))
     }
 
+    /// Moment in universal time of the first time at or after the given moment when the lunar phase
+    /// is equal to the `phase` given.
+    ///
+    /// Based on functions from _Calendrical Calculations_ by Reingold & Dershowitz.
+    /// Lisp code reference: `lunar-phase-at-or-after` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+    pub fn lunar_phase_at_or_after(phase: f64, moment: Moment) -> Moment {
+        let julian_centuries = Self::julian_centuries(moment);
+        let tau = moment.inner()
+            + (MEAN_SYNODIC_MONTH / 360.0)
+                * (phase - Self::lunar_phase(moment, julian_centuries)).rem_euclid(360.0);
+        let a = moment.inner().max(tau - 2.0);
+        let b = tau + 2.0;
+
+        let lunar_phase_f64 = |x: f64| -> f64 {
+            Self::lunar_phase(Moment::new(x), Self::julian_centuries(Moment::new(x)))
+        };
+
+        Moment::new(invert_angular(lunar_phase_f64, phase, (a, b)))
+    }
+
+    /// Moment in universal time of the first time at or after the given moment when the solar
+    /// longitude is equal to the `angle` given.
+    ///
+    /// Based on functions from _Calendrical Calculations_ by Reingold & Dershowitz.
+    /// Lisp code reference: `solar-longitude-after` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+    pub fn solar_longitude_after(angle: f64, moment: Moment) -> Moment {
+        let rate = MEAN_TROPICAL_YEAR / 360.0;
+        let tau = moment.inner()
+            + rate
+                * (angle - Self::solar_longitude(Self::julian_centuries(moment))).rem_euclid(360.0);
+        let a = moment.inner().max(tau - 5.0);
+        let b = tau + 5.0;
+
+        let solar_longitude_f64 =
+            |x: f64| -> f64 { Self::solar_longitude(Self::julian_centuries(Moment::new(x))) };
+
+        Moment::new(invert_angular(solar_longitude_f64, angle, (a, b)))
+    }
+
     /// The longitude of the Sun at a given Moment in degrees.
     /// Moment is not directly used but is enconded from the argument `julian_centuries` which is the result of calling `Self::julian_centuries(moment) in an earlier function`.
     ///
@@ -83,6 +83,11 @@ pub struct Chinese;
 #[allow(clippy::exhaustive_structs)] // newtype
 pub struct Dangi;
 
+/// A type implementing [`ChineseBased`] for the Vietnamese lunar calendar
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+#[allow(clippy::exhaustive_structs)] // newtype
+pub struct Vietnamese;
+
 impl ChineseBased for Chinese {
     fn utc_offset(fixed: RataDie) -> f64 {
         use crate::iso::const_fixed_from_iso as iso;
@@ -125,6 +130,24 @@ impl ChineseBased for Dangi {
     const DEBUG_NAME: &'static str = "dangi";
 }
 
+impl ChineseBased for Vietnamese {
+    fn utc_offset(fixed: RataDie) -> f64 {
+        use crate::iso::const_fixed_from_iso as iso;
+        // Before 1968, Vietnam used calendars computed for China, represented as UTC+8h.
+        // In 1968, North Vietnam adopted a calendar computed for its own time zone, which
+        // was used in all of Vietnam from 1976. Currently, UTC+7h is used.
+        if fixed < const { iso(1968, 1, 1) } {
+            8.0 / 24.0
+        } else {
+            7.0 / 24.0
+        }
+    }
+
+    /// The equivalent first day in the Chinese calendar, Feb. 15, -2636
+    const EPOCH: RataDie = Chinese::EPOCH;
+    const DEBUG_NAME: &'static str = "vietnamese";
+}
+
 /// Marks the bounds of a lunar year
 #[derive(Debug, Copy, Clone)]
 #[allow(clippy::exhaustive_structs)] // we're comfortable making frequent breaking changes to this crate
@@ -612,6 +635,10 @@ mod test {
             YearBounds::compute::<Chinese>(Chinese::EPOCH).new_year,
             Chinese::EPOCH
         );
+        assert_eq!(
+            YearBounds::compute::<Vietnamese>(Vietnamese::EPOCH).new_year,
+            Vietnamese::EPOCH
+        );
     }
 
     #[test]
@@ -806,4 +833,27 @@ mod test {
             assert_eq!(d, case.expected_day, "Day check failed for case: {case:?}");
         }
     }
+
+    #[test]
+    fn test_tet() {
+        // Years in which Tết differs from the Chinese New Year
+        let cases = [
+            ((1968, 1, 29), (1968, 1, 30)),
+            ((1985, 1, 21), (1985, 2, 20)),
+            ((2007, 2, 17), (2007, 2, 18)),
+            ((2030, 2, 2), (2030, 2, 3)),
+        ];
+
+        for ((y, m, d), (cy, cm, cd)) in cases {
+            let tet = crate::iso::fixed_from_iso(y, m, d);
+            let prev_solstice = winter_solstice_on_or_before::<Vietnamese>(tet);
+            let result = new_year_on_or_before_fixed_date::<Vietnamese>(tet, prev_solstice).0;
+            assert_eq!(result, tet, "Tết check failed for {y}");
+
+            let new_year = crate::iso::fixed_from_iso(cy, cm, cd);
+            let prev_solstice = winter_solstice_on_or_before::<Chinese>(new_year);
+            let result = new_year_on_or_before_fixed_date::<Chinese>(new_year, prev_solstice).0;
+            assert_eq!(result, new_year, "Chinese New Year check failed for {y}");
+        }
+    }
 }
@@ -0,0 +1,349 @@
+// This file is part of ICU4X.
+//
+// The contents of this file implement algorithms from Calendrical Calculations
+// by Reingold & Dershowitz, Cambridge University Press, 4th edition (2018),
+// which have been released as Lisp code at <https://github.com/EdReingold/calendar-code2/>
+// under the Apache-2.0 license. Accordingly, this file is released under
+// the Apache License, Version 2.0 which can be found at the calendrical_calculations
+// package root or at http://www.apache.org/licenses/LICENSE-2.0.
+
+//! The astronomical Hindu solar and lunisolar calendars.
+//!
+//! These use modern astronomy (the `astro-hindu-*` functions of _Calendrical Calculations_)
+//! rather than the traditional Sūrya Siddhānta rules. Solar months begin with the sun's entry
+//! into a sidereal zodiac sign, judged at sunset; lunisolar days are the lunar day (tithi)
+//! current at sunrise, and lunisolar months run from new moon to new moon (amānta).
+
+use crate::astronomy::*;
+use crate::helpers::{i64_to_saturated_i32, next, poly};
+use crate::rata_die::{Moment, RataDie};
+#[allow(unused_imports)]
+use core_maths::*;
+
+pub use crate::astronomy::Location;
+
+/// The epoch of the Kali Yuga, February 18, 3102 BCE (Julian).
+///
+/// Lisp code reference: `hindu-epoch` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+pub const HINDU_EPOCH: RataDie = crate::julian::fixed_from_julian(-3101, 2, 18);
+
+/// The mean length of the sidereal year in days.
+///
+/// Lisp code reference: `hindu-sidereal-year` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+pub const HINDU_SIDEREAL_YEAR: f64 = 365.0 + 279457.0 / 1080000.0;
+
+/// The difference between Kali Yuga years and years of the Śaka era, used by the solar calendar.
+///
+/// Lisp code reference: `hindu-solar-era` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+pub const HINDU_SOLAR_ERA: i32 = 3179;
+
+/// The difference between Kali Yuga years and years of the Vikrama era, used by the lunisolar calendar.
+///
+/// Lisp code reference: `hindu-lunar-era` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+pub const HINDU_LUNAR_ERA: i32 = 3044;
+
+/// The precession at the moment of the sidereal year start in 285 CE, when the sidereal
+/// and tropical zodiacs coincided; precomputed from [`precession`].
+///
+/// Lisp code reference: `sidereal-start` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+const SIDEREAL_START: f64 = 336.1360765905204;
+
+/// The location of Ujjain, the traditional reference city of Hindu astronomy.
+///
+/// Lisp code reference: `ujjain` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+pub const UJJAIN: Location = Location {
+    latitude: 23.0 + 9.0 / 60.0,
+    longitude: 75.0 + 46.0 / 60.0 + 6.0 / 3600.0,
+    elevation: 0.0,
+    utc_offset: (5.0 + 461.0 / 9000.0) / 24.0,
+};
+
+/// The location of New Delhi, in Indian Standard Time.
+pub const NEW_DELHI: Location = Location {
+    latitude: 28.6139,
+    longitude: 77.209,
+    elevation: 216.0,
+    utc_offset: 5.5 / 24.0,
+};
+
+/// The location of Mumbai, in Indian Standard Time.
+pub const MUMBAI: Location = Location {
+    latitude: 19.076,
+    longitude: 72.8777,
+    elevation: 14.0,
+    utc_offset: 5.5 / 24.0,
+};
+
+/// The location of Chennai, in Indian Standard Time.
+pub const CHENNAI: Location = Location {
+    latitude: 13.0827,
+    longitude: 80.2707,
+    elevation: 6.0,
+    utc_offset: 5.5 / 24.0,
+};
+
+/// The location of Kolkata, in Indian Standard Time.
+pub const KOLKATA: Location = Location {
+    latitude: 22.5726,
+    longitude: 88.3639,
+    elevation: 9.0,
+    utc_offset: 5.5 / 24.0,
+};
+
+/// Precession of the equinox at the given moment (in universal time), in degrees.
+///
+/// Lisp code reference: `precession` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+pub(crate) fn precession(moment: Moment) -> f64 {
+    let c = Astronomical::julian_centuries(moment);
+    let secs = |s: f64| s / 3600.0;
+    let eta = poly(c, &[0.0, secs(47.0029), secs(-0.03302), secs(0.000060)]).rem_euclid(360.0);
+    let cap_p = poly(c, &[174.876384, secs(-869.8089), secs(0.03536)]).rem_euclid(360.0);
+    let p = poly(c, &[0.0, secs(5029.0966), secs(1.11113), secs(0.000006)]).rem_euclid(360.0);
+    let cap_a = eta.to_radians().cos() * cap_p.to_radians().sin();
+    let cap_b = cap_p.to_radians().cos();
+    let arg = cap_a.atan2(cap_b).to_degrees().rem_euclid(360.0);
+    (p + cap_p - arg).rem_euclid(360.0)
+}
+
+/// The sidereal longitude of the sun at the given moment (in universal time), in degrees.
+///
+/// Lisp code reference: `sidereal-solar-longitude` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+pub(crate) fn sidereal_solar_longitude(moment: Moment) -> f64 {
+    (Astronomical::solar_longitude(Astronomical::julian_centuries(moment)) - precession(moment)
+        + SIDEREAL_START)
+        .rem_euclid(360.0)
+}
+
+/// The sidereal zodiac sign (1 to 12, starting with Mesha) of the sun at the given moment.
+///
+/// Lisp code reference: `sidereal-zodiac` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+pub(crate) fn sidereal_zodiac(moment: Moment) -> u8 {
+    (sidereal_solar_longitude(moment) / 30.0) as u8 + 1
+}
+
+/// The Kali Yuga year current at the given moment.
+///
+/// Lisp code reference: `astro-hindu-calendar-year` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+pub(crate) fn calendar_year(moment: Moment) -> i32 {
+    i64_to_saturated_i32(
+        ((moment - HINDU_EPOCH.as_moment()) / HINDU_SIDEREAL_YEAR
+            - sidereal_solar_longitude(moment) / 360.0)
+            .round() as i64,
+    )
+}
+
+/// The lunar day (tithi, 1 to 30) current at the given moment.
+///
+/// Lisp code reference: `astro-lunar-day-from-moment` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+pub(crate) fn lunar_day_from_moment(moment: Moment) -> u8 {
+    let phase = Astronomical::lunar_phase(moment, Astronomical::julian_centuries(moment));
+    ((phase / 12.0) as u8 + 1).min(30)
+}
+
+/// The moment of sunrise on the given date at the given location, in universal time.
+pub(crate) fn sunrise(date: RataDie, location: Location) -> Moment {
+    // Sunrise exists every day at the latitudes of the Indian subcontinent; fall back
+    // to 6 AM for locations where it does not.
+    let standard =
+        Astronomical::sunrise(date.as_moment(), location).unwrap_or(date.as_moment() + 6.0 / 24.0);
+    Location::universal_from_standard(standard, location)
+}
+
+/// The moment of sunset on the given date at the given location, in universal time.
+pub(crate) fn sunset(date: RataDie, location: Location) -> Moment {
+    // Sunset exists every day at the latitudes of the Indian subcontinent; fall back
+    // to 6 PM for locations where it does not.
+    let standard =
+        Astronomical::sunset(date.as_moment(), location).unwrap_or(date.as_moment() + 18.0 / 24.0);
+    Location::universal_from_standard(standard, location)
+}
+
+/// The first day of the given month of the given Hindu solar (Śaka) year at the given location.
+///
+/// Based on functions from _Calendrical Calculations_ by Reingold & Dershowitz.
+/// Lisp code reference: `fixed-from-astro-hindu-solar` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+pub fn hindu_solar_month_start(year: i32, month: u8, location: Location) -> RataDie {
+    let approx = HINDU_EPOCH - 3
+        + (((year + HINDU_SOLAR_ERA) as f64 + (month as f64 - 1.0) / 12.0) * HINDU_SIDEREAL_YEAR)
+            .floor() as i64;
+    next(approx, |i| sidereal_zodiac(sunset(i, location)) == month)
+}
+
+/// The R.D. of the given Hindu solar (Śaka) date at the given location.
+///
+/// Lisp code reference: `fixed-from-astro-hindu-solar` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+pub fn fixed_from_hindu_solar(year: i32, month: u8, day: u8, location: Location) -> RataDie {
+    hindu_solar_month_start(year, month, location) + day as i64 - 1
+}
+
+/// The Hindu solar (Śaka) year, month and day of the given R.D. at the given location.
+///
+/// Lisp code reference: `astro-hindu-solar-from-fixed` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+pub fn hindu_solar_from_fixed(date: RataDie, location: Location) -> (i32, u8, u8) {
+    let critical = sunset(date, location);
+    let month = sidereal_zodiac(critical);
+    let year = calendar_year(critical) - HINDU_SOLAR_ERA;
+    let approx = date - 3 - (sidereal_solar_longitude(critical).floor() as i64).rem_euclid(30);
+    let begin = next(approx, |i| sidereal_zodiac(sunset(i, location)) == month);
+    (year, month, (date - begin + 1) as u8)
+}
+
+/// The lunar day (tithi) at sunrise on the given R.D. at the given location.
+///
+/// A lunar day is a leap day if it is the same lunar day as on the previous day, and a lunar
+/// day is expunged if it starts and ends between two sunrises.
+///
+/// Lisp code reference: `astro-hindu-lunar-from-fixed` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+pub fn hindu_lunar_day(date: RataDie, location: Location) -> u8 {
+    lunar_day_from_moment(sunrise(date, location))
+}
+
+/// The Hindu lunisolar (Vikrama) year, month, leap month, day and leap day of the given R.D.
+/// at the given location, using amānta months.
+///
+/// Lisp code reference: `astro-hindu-lunar-from-fixed` in <https://github.com/EdReingold/calendar-code2/blob/main/calendar.l>
+pub fn hindu_lunar_from_fixed(date: RataDie, location: Location) -> (i32, u8, bool, u8, bool) {
+    let critical = sunrise(date, location);
+    let day = lunar_day_from_moment(critical);
+    let leap_day = day == hindu_lunar_day(date - 1, location);
+    let last_new_moon = Astronomical::new_moon_before(critical);
+    let next_new_moon = Astronomical::new_moon_at_or_after(critical);
+    let solar_month = sidereal_zodiac(last_new_moon);
+    let leap_month = solar_month == sidereal_zodiac(next_new_moon);
+    let month = solar_month % 12 + 1;
+    let year = calendar_year(if month <= 2 {
+        (date + 180).as_moment()
+    } else {
+        date.as_moment()
+    }) - HINDU_LUNAR_ERA;
+    (year, month, leap_month, day, leap_day)
+}
+
+/// The new moon that begins the given Hindu lunisolar (Vikrama) year, i.e. the first
+/// new moon with the sun in sidereal Mīna.
+fn hindu_lunar_year_new_moon(year: i32) -> Moment {
+    // The mean moment of the sun's entry into Mīna before the year starts
+    let mina = HINDU_EPOCH.as_moment()
+        + ((year + HINDU_LUNAR_ERA - 1) as f64 + 11.0 / 12.0) * HINDU_SIDEREAL_YEAR;
+    let mut new_moon = Astronomical::new_moon_at_or_after(mina - 20.0);
+    while sidereal_zodiac(new_moon) != 12 {
+        new_moon = Astronomical::new_moon_at_or_after(new_moon + 1.0);
+    }
+    new_moon
+}
+
+/// The first day of the amānta month that begins with the given new moon, which is the
+/// first day whose sunrise follows the new moon.
+fn month_start(new_moon: Moment, location: Location) -> RataDie {
+    let day = Location::standard_from_universal(new_moon, location).as_rata_die();
+    if sunrise(day, location) > new_moon {
+        day
+    } else {
+        day + 1
+    }
+}
+
+/// Returns the first day of the given Hindu lunisolar (Vikrama) year at the given location.
+pub fn hindu_lunar_new_year(year: i32, location: Location) -> RataDie {
+    month_start(hindu_lunar_year_new_moon(year), location)
+}
+
+/// Returns the month structure of the given Hindu lunisolar (Vikrama) year at the given location.
+///
+/// This returns the first day of the year, the length of each month (true for 30-day, false
+/// for 29-day), which months are leap (adhika) months, and the number of the expunged (kṣaya)
+/// month, if any. Months are indexed by their position in the year; in years without a leap
+/// month, month 13 will have value false.
+///
+/// A leap month precedes the regular month with the same number. A year with an expunged
+/// month always has two leap months.
+pub fn hindu_lunar_month_structure(
+    year: i32,
+    location: Location,
+) -> (RataDie, [bool; 13], [bool; 13], Option<u8>) {
+    let mut month_lengths = [false; 13];
+    let mut leap_months = [false; 13];
+    let mut expunged = None;
+
+    let mut new_moon = hindu_lunar_year_new_moon(year);
+    let next_year_new_moon = hindu_lunar_year_new_moon(year + 1);
+    let new_year = month_start(new_moon, location);
+
+    let mut start = new_year;
+    let mut zodiac = sidereal_zodiac(new_moon);
+    let mut number = zodiac % 12 + 1;
+    for (length, leap) in month_lengths.iter_mut().zip(leap_months.iter_mut()) {
+        if new_moon >= next_year_new_moon {
+            break;
+        }
+        let next_new_moon = Astronomical::new_moon_at_or_after(new_moon + 1.0);
+        let next_start = month_start(next_new_moon, location);
+        let next_zodiac = sidereal_zodiac(next_new_moon);
+        let next_number = next_zodiac % 12 + 1;
+
+        *length = next_start - start == 30;
+        *leap = next_zodiac == zodiac;
+        if !*leap && next_number != number % 12 + 1 {
+            expunged = Some(number % 12 + 1);
+        }
+
+        new_moon = next_new_moon;
+        start = next_start;
+        zodiac = next_zodiac;
+        number = next_number;
+    }
+
+    (new_year, month_lengths, leap_months, expunged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iso::fixed_from_iso;
+
+    #[test]
+    fn test_sidereal_start() {
+        // Mesha saṅkrānti in 285 CE according to the Sūrya Siddhānta
+        let moment = crate::julian::fixed_from_julian(285, 3, 17).as_moment();
+        assert!((precession(moment) - SIDEREAL_START).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_solar_roundtrip() {
+        for location in [UJJAIN, NEW_DELHI, CHENNAI] {
+            for rd in (700_000..740_000).step_by(97) {
+                let date = RataDie::new(rd);
+                let (y, m, d) = hindu_solar_from_fixed(date, location);
+                assert_eq!(fixed_from_hindu_solar(y, m, d, location), date);
+            }
+        }
+    }
+
+    #[test]
+    fn test_solar_new_year() {
+        // Mesha saṅkrānti, the solar new year, falls on April 14 in the 21st century
+        let (y, m, d) = hindu_solar_from_fixed(fixed_from_iso(2024, 4, 14), UJJAIN);
+        assert_eq!((y, m, d), (1946, 1, 1));
+    }
+
+    #[test]
+    fn test_lunar_structure() {
+        for year in [2000, 2060, 2080, 2081] {
+            let (new_year, lengths, leaps, expunged) = hindu_lunar_month_structure(year, UJJAIN);
+            let next_new_year = hindu_lunar_new_year(year + 1, UJJAIN);
+            let months = 12 + leaps.iter().filter(|&&l| l).count() - expunged.is_some() as usize;
+            let days = lengths
+                .iter()
+                .take(months)
+                .map(|&l| if l { 30 } else { 29 })
+                .sum::<i64>();
+            assert_eq!(new_year + days, next_new_year, "{year}");
+
+            // The first day of the year is the first of Chaitra
+            let (y, m, _, _, _) = hindu_lunar_from_fixed(new_year, UJJAIN);
+            assert_eq!((y, m), (year, 1));
+            let (y, m, _, _, _) = hindu_lunar_from_fixed(new_year - 1, UJJAIN);
+            assert_eq!((y, m), (year - 1, 12));
+        }
+    }
+}
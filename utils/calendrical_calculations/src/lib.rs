@@ -35,6 +35,8 @@
 )]
 #![warn(missing_docs)]
 
+/// Times of astronomical events, such as sunrise, sunset, moon phases, and solar longitudes
+pub mod astronomical_events;
 mod astronomy;
 /// Chinese-like lunar calendars (Chinese, Dangi)
 pub mod chinese_based;
@@ -49,6 +51,8 @@ pub mod hebrew;
 pub mod hebrew_keviyah;
 /// Additional math helpers
 pub mod helpers;
+/// The astronomical Hindu calendars
+pub mod hindu;
 /// Various islamic lunar calendars
 pub mod islamic;
 /// The ISO calendar (also usable as Gregorian)
@@ -2,13 +2,21 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use libc::{setlocale, LC_ALL, LC_TIME};
+#[cfg(feature = "compiled_data")]
+use icu_calendar::{types::Weekday, week::WeekInformation};
+use icu_locale_core::extensions::unicode::{key, value, Value};
+use icu_locale_core::Locale;
+#[cfg(feature = "compiled_data")]
+use icu_time::{zone::IanaParser, TimeZone};
+use libc::{freelocale, newlocale, nl_langinfo_l, setlocale, LC_ALL, LC_TIME, LC_TIME_MASK, T_FMT};
 use std::collections::HashMap;
-use std::ffi::CStr;
-use std::ptr;
+use std::ffi::{CStr, CString};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::{env, fs, ptr};
 
-use crate::RetrievalError;
+use crate::parse::posix::PosixLocale;
+use crate::{LocaleError, ParseError, RetrievalError};
 
 #[derive(Hash, Eq, PartialEq, Debug)]
 pub enum LocaleCategory {
@@ -65,8 +73,6 @@ pub fn get_raw_locales() -> Result<Vec<String>, RetrievalError> {
     Ok(locales)
 }
 
-// TODO: Add a function to return all the locales POSIX categories explicitly
-
 /// Retrieves locale for `LC_ALL` POSIX category. Also returns other categories if any are explicitly
 /// set in the thread
 pub fn get_raw_locale_categories() -> Result<HashMap<LocaleCategory, String>, RetrievalError> {
@@ -117,3 +123,205 @@ pub fn get_system_calendars() -> Result<String, RetrievalError> {
     }
     Err(RetrievalError::NullLocale)
 }
+
+/// Get the current time zone of the system as an IANA identifier.
+///
+/// The time zone is looked up in the following order:
+/// 1. The `TZ` environment variable, if it names a zone (e.g. `Europe/Berlin`, `:Asia/Tokyo`
+///    or a path into the zoneinfo database)
+/// 2. The target of the `/etc/localtime` symlink
+/// 3. The contents of `/etc/timezone`
+pub fn get_system_time_zone() -> Result<String, RetrievalError> {
+    get_time_zone_from_root(Path::new("/"), env::var("TZ").ok().as_deref())
+}
+
+/// Get the current time zone of the system as an ICU4X [`TimeZone`].
+///
+/// Identifiers that are not known to ICU4X map to [`TimeZone::UNKNOWN`].
+/// See [`get_system_time_zone`] for how the time zone is detected.
+///
+/// ✨ *Enabled with the `compiled_data` Cargo feature.*
+#[cfg(feature = "compiled_data")]
+pub fn get_system_bcp47_time_zone() -> Result<TimeZone, RetrievalError> {
+    get_system_time_zone().map(|iana| IanaParser::new().parse(&iana))
+}
+
+/// Same as [`get_system_time_zone`], but resolves the configuration files relative to `root`
+/// and uses `tz` as the value of the `TZ` environment variable.
+///
+/// A `TZ` value holding a POSIX rule (such as `CET-1CEST,M3.5.0,M10.5.0/3`) does not name an
+/// IANA zone and results in an error.
+pub fn get_time_zone_from_root(root: &Path, tz: Option<&str>) -> Result<String, RetrievalError> {
+    if let Some(tz) = tz.map(|tz| tz.strip_prefix(':').unwrap_or(tz)) {
+        if !tz.is_empty() {
+            return if tz.starts_with('/') {
+                zone_from_zoneinfo_path(Path::new(tz))
+            } else if is_iana_like(tz) {
+                Some(tz.to_string())
+            } else {
+                None
+            }
+            .ok_or_else(|| RetrievalError::Other(format!("Unsupported TZ value: {tz}")));
+        }
+    }
+
+    let etc = root.join("etc");
+    if let Ok(target) = fs::read_link(etc.join("localtime")) {
+        // Relative targets are relative to the directory containing the symlink
+        let target = if target.is_absolute() {
+            target
+        } else {
+            etc.join(target)
+        };
+        if let Some(zone) = zone_from_zoneinfo_path(&target) {
+            return Ok(zone);
+        }
+    }
+
+    if let Ok(contents) = fs::read_to_string(etc.join("timezone")) {
+        if let Some(zone) = contents
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'))
+        {
+            return Ok(zone.to_string());
+        }
+    }
+
+    Err(RetrievalError::NullTimeZone)
+}
+
+/// Extracts the zone name from a path such as `/usr/share/zoneinfo/Europe/Berlin`
+fn zone_from_zoneinfo_path(path: &Path) -> Option<String> {
+    let path: PathBuf = path.components().collect();
+    let path = path.to_str()?;
+    let (_, zone) = path.rsplit_once("zoneinfo/")?;
+    // `posix/` and `right/` hold the same zones with different leap second handling
+    let zone = zone
+        .strip_prefix("posix/")
+        .or_else(|| zone.strip_prefix("right/"))
+        .unwrap_or(zone);
+    is_iana_like(zone).then(|| zone.to_string())
+}
+
+fn is_iana_like(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_alphabetic())
+        && s.bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'/' | b'_' | b'-' | b'+'))
+}
+
+/// Build a [`Locale`] from all POSIX locale categories of the current thread.
+///
+/// See [`locale_from_categories`] for how the categories are combined.
+pub fn get_locale_with_category_preferences() -> Result<Locale, LocaleError> {
+    Ok(locale_from_categories(&get_raw_locale_categories()?)?)
+}
+
+/// Combine POSIX locale categories into a single [`Locale`].
+///
+/// The base locale is taken from `LC_ALL`, falling back to `LC_MESSAGES`, `LC_CTYPE` and
+/// finally `C`. Categories that are set to a different locale are reflected as `-u-` keywords:
+///
+/// | Category      | Keywords   | Source                                                       |
+/// |---------------|------------|--------------------------------------------------------------|
+/// | `LC_TIME`     | `hc`, `fw` | The system time format and ICU4X week data                   |
+/// | `LC_NUMERIC`  | `nu`       | Always `latn`, as POSIX numeric formatting uses ASCII digits |
+///
+/// Other categories, such as `LC_MEASUREMENT` and `LC_COLLATE`, are ignored, as their
+/// keywords cannot be derived without locale data that ICU4X does not provide. The `fw` keyword is only set
+/// with the `compiled_data` Cargo feature.
+pub fn locale_from_categories(
+    categories: &HashMap<LocaleCategory, String>,
+) -> Result<Locale, ParseError> {
+    let base = [
+        LocaleCategory::All,
+        LocaleCategory::Messages,
+        LocaleCategory::Character,
+    ]
+    .iter()
+    .find_map(|category| categories.get(category))
+    .map_or("C", String::as_str);
+    let mut locale = PosixLocale::try_from_str(base)?.try_convert_lossy()?;
+
+    let divergent = |category| {
+        categories
+            .get(&category)
+            .map(String::as_str)
+            .filter(|raw| *raw != base)
+    };
+    let keywords = &mut locale.extensions.unicode.keywords;
+
+    if let Some(raw) = divergent(LocaleCategory::Time) {
+        if let Some(hour_cycle) = hour_cycle_for_time_locale(raw) {
+            keywords.set(key!("hc"), hour_cycle);
+        }
+        #[cfg(feature = "compiled_data")]
+        if let Some(first_weekday) = first_weekday_for_time_locale(raw)? {
+            keywords.set(key!("fw"), first_weekday);
+        }
+    }
+
+    if divergent(LocaleCategory::Number).is_some() {
+        keywords.set(key!("nu"), value!("latn"));
+    }
+
+    Ok(locale)
+}
+
+/// Derives the `fw` keyword from the ICU4X week data of the region of `raw`
+#[cfg(feature = "compiled_data")]
+fn first_weekday_for_time_locale(raw: &str) -> Result<Option<Value>, ParseError> {
+    let time_locale = PosixLocale::try_from_str(raw)?.try_convert_lossy()?;
+    Ok(WeekInformation::try_new((&time_locale).into())
+        .ok()
+        .map(|week| match week.first_weekday {
+            Weekday::Monday => value!("mon"),
+            Weekday::Tuesday => value!("tue"),
+            Weekday::Wednesday => value!("wed"),
+            Weekday::Thursday => value!("thu"),
+            Weekday::Friday => value!("fri"),
+            Weekday::Saturday => value!("sat"),
+            Weekday::Sunday => value!("sun"),
+        }))
+}
+
+/// Derives the `hc` keyword from the `T_FMT` of an installed locale, if available
+fn hour_cycle_for_time_locale(raw: &str) -> Option<Value> {
+    let raw = CString::new(raw).ok()?;
+    // SAFETY: `raw` is a valid nul-terminated string, and a `NULL` base creates a new locale object
+    let locale = unsafe { newlocale(LC_TIME_MASK, raw.as_ptr(), ptr::null_mut()) };
+    if locale.is_null() {
+        return None;
+    }
+
+    // SAFETY: `locale` is a valid locale object created above
+    let format_ptr = unsafe { nl_langinfo_l(T_FMT, locale) };
+    let hour_cycle = if format_ptr.is_null() {
+        None
+    } else {
+        // SAFETY: `nl_langinfo_l` returns a nul-terminated string that lives as long as `locale`
+        let format = unsafe { CStr::from_ptr(format_ptr) }.to_bytes();
+        hour_cycle_for_time_format(format)
+    };
+
+    // SAFETY: `locale` was created by `newlocale` and is not used afterwards
+    unsafe { freelocale(locale) };
+    hour_cycle
+}
+
+fn hour_cycle_for_time_format(format: &[u8]) -> Option<Value> {
+    let mut bytes = format.iter();
+    while let Some(&byte) = bytes.next() {
+        if byte != b'%' {
+            continue;
+        }
+        // Skip the `E` and `O` modifiers of alternative representations
+        let conversion = bytes.by_ref().find(|b| !matches!(b, b'E' | b'O'))?;
+        match conversion {
+            b'I' | b'l' | b'r' => return Some(value!("h12")),
+            b'H' | b'k' | b'R' | b'T' => return Some(value!("h23")),
+            _ => {}
+        }
+    }
+    None
+}
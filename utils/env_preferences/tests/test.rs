@@ -8,10 +8,15 @@ mod parse;
 #[cfg(target_os = "linux")]
 #[cfg(test)]
 mod linux_tests {
-    use env_preferences::posix::{get_raw_locale_categories, get_system_calendars, LocaleCategory};
+    use env_preferences::posix::{
+        get_raw_locale_categories, get_system_calendars, get_time_zone_from_root,
+        locale_from_categories, LocaleCategory,
+    };
     use env_preferences::RetrievalError;
-    use icu_locale_core::Locale;
+    use icu_locale_core::{locale, Locale};
     use libc::setlocale;
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
 
     // Testing fetching of locale, as `get_locales` fetches the locales for category
     // `LC_ALL`. For this category this should return non empty
@@ -66,6 +71,119 @@ mod linux_tests {
         let calendar_locale = get_system_calendars().unwrap();
         assert_eq!(test_calendar_locale.to_string(), calendar_locale);
     }
+
+    /// An empty fake root directory with an `etc` directory, removed on drop
+    struct FakeRoot(PathBuf);
+
+    impl FakeRoot {
+        fn new(name: &str) -> Self {
+            let root =
+                std::env::temp_dir().join(format!("env_preferences-{}-{name}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&root);
+            std::fs::create_dir_all(root.join("etc")).unwrap();
+            Self(root)
+        }
+    }
+
+    impl Drop for FakeRoot {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    impl std::ops::Deref for FakeRoot {
+        type Target = Path;
+
+        fn deref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn test_time_zone_from_tz() {
+        let root = FakeRoot::new("tz");
+        std::os::unix::fs::symlink(
+            "/usr/share/zoneinfo/Europe/Berlin",
+            root.join("etc/localtime"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_time_zone_from_root(&root, Some("Asia/Tokyo")),
+            Ok("Asia/Tokyo".to_string())
+        );
+        assert_eq!(
+            get_time_zone_from_root(&root, Some(":America/New_York")),
+            Ok("America/New_York".to_string())
+        );
+        assert_eq!(
+            get_time_zone_from_root(&root, Some("/usr/share/zoneinfo/posix/Australia/Sydney")),
+            Ok("Australia/Sydney".to_string())
+        );
+        assert!(get_time_zone_from_root(&root, Some("CET-1CEST,M3.5.0,M10.5.0/3")).is_err());
+        // An empty `TZ` is ignored
+        assert_eq!(
+            get_time_zone_from_root(&root, Some("")),
+            Ok("Europe/Berlin".to_string())
+        );
+    }
+
+    #[test]
+    fn test_time_zone_from_localtime() {
+        let root = FakeRoot::new("localtime");
+        std::os::unix::fs::symlink(
+            "../usr/share/zoneinfo/America/Sao_Paulo",
+            root.join("etc/localtime"),
+        )
+        .unwrap();
+        std::fs::write(root.join("etc/timezone"), "Europe/Paris\n").unwrap();
+
+        assert_eq!(
+            get_time_zone_from_root(&root, None),
+            Ok("America/Sao_Paulo".to_string())
+        );
+    }
+
+    #[test]
+    fn test_time_zone_from_timezone_file() {
+        let root = FakeRoot::new("timezone");
+        assert_eq!(
+            get_time_zone_from_root(&root, None),
+            Err(RetrievalError::NullTimeZone)
+        );
+
+        std::fs::write(root.join("etc/timezone"), "Europe/Paris\n").unwrap();
+        assert_eq!(
+            get_time_zone_from_root(&root, None),
+            Ok("Europe/Paris".to_string())
+        );
+    }
+
+    #[test]
+    fn test_locale_from_categories() {
+        let categories = HashMap::from([(LocaleCategory::All, "de_DE.UTF-8".to_string())]);
+        assert_eq!(locale_from_categories(&categories), Ok(locale!("de-DE")));
+
+        // Using "C" for `LC_TIME` since it is available on every system
+        let categories = HashMap::from([
+            (LocaleCategory::Messages, "de_DE.UTF-8".to_string()),
+            (LocaleCategory::Time, "C".to_string()),
+            (LocaleCategory::Number, "en_US.UTF-8".to_string()),
+            (LocaleCategory::Measurement, "en_US.UTF-8".to_string()),
+            (LocaleCategory::Collate, "zh_TW.UTF-8".to_string()),
+        ]);
+        // `LC_MEASUREMENT` and `LC_COLLATE` are ignored, and `fw` comes from ICU4X week data,
+        // which needs the `compiled_data` feature
+        let expected = if cfg!(feature = "compiled_data") {
+            "de-DE-u-fw-sun-hc-h23-nu-latn"
+        } else {
+            "de-DE-u-hc-h23-nu-latn"
+        };
+        assert_eq!(
+            locale_from_categories(&categories),
+            Ok(expected.parse::<Locale>().unwrap())
+        );
+    }
 }
 
 #[cfg(target_os = "macos")]
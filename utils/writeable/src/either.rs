@@ -32,6 +32,27 @@ where
         }
     }
 
+    fn write_to_utf16<W: Utf16Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        match self {
+            Either::Left(w) => w.write_to_utf16(sink),
+            Either::Right(w) => w.write_to_utf16(sink),
+        }
+    }
+
+    fn write_to_utf16_parts<S: Utf16PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        match self {
+            Either::Left(w) => w.write_to_utf16_parts(sink),
+            Either::Right(w) => w.write_to_utf16_parts(sink),
+        }
+    }
+
+    fn writeable_utf16_length_hint(&self) -> LengthHint {
+        match self {
+            Either::Left(w) => w.writeable_utf16_length_hint(),
+            Either::Right(w) => w.writeable_utf16_length_hint(),
+        }
+    }
+
     fn write_to_string(&self) -> Cow<'_, str> {
         match self {
             Either::Left(w) => w.write_to_string(),
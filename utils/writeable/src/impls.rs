@@ -32,6 +32,11 @@ macro_rules! impl_write_num {
             fn writeable_length_hint(&self) -> $crate::LengthHint {
                 LengthHint::exact(self.checked_ilog10().unwrap_or(0) as usize + 1)
             }
+
+            fn writeable_utf16_length_hint(&self) -> $crate::LengthHint {
+                // ASCII output
+                self.writeable_length_hint()
+            }
         }
 
         impl $crate::Writeable for $i {
@@ -46,6 +51,11 @@ macro_rules! impl_write_num {
                 $crate::LengthHint::exact(if self.is_negative() { 1 } else { 0 })
                     + self.unsigned_abs().writeable_length_hint()
             }
+
+            fn writeable_utf16_length_hint(&self) -> $crate::LengthHint {
+                // ASCII output
+                self.writeable_length_hint()
+            }
         }
 
         #[test]
@@ -144,11 +154,21 @@ impl Writeable for char {
         sink.write_char(*self)
     }
 
+    #[inline]
+    fn write_to_utf16<W: Utf16Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        sink.write_char(*self)
+    }
+
     #[inline]
     fn writeable_length_hint(&self) -> LengthHint {
         LengthHint::exact(self.len_utf8())
     }
 
+    #[inline]
+    fn writeable_utf16_length_hint(&self) -> LengthHint {
+        LengthHint::exact(self.len_utf16())
+    }
+
     #[inline]
     fn write_to_string(&self) -> Cow<'_, str> {
         let mut s = String::with_capacity(self.len_utf8());
@@ -168,11 +188,26 @@ impl<T: Writeable + ?Sized> Writeable for &T {
         (*self).write_to_parts(sink)
     }
 
+    #[inline]
+    fn write_to_utf16<W: Utf16Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        (*self).write_to_utf16(sink)
+    }
+
+    #[inline]
+    fn write_to_utf16_parts<W: Utf16PartsWrite + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        (*self).write_to_utf16_parts(sink)
+    }
+
     #[inline]
     fn writeable_length_hint(&self) -> LengthHint {
         (*self).writeable_length_hint()
     }
 
+    #[inline]
+    fn writeable_utf16_length_hint(&self) -> LengthHint {
+        (*self).writeable_utf16_length_hint()
+    }
+
     #[inline]
     fn write_to_string(&self) -> Cow<'_, str> {
         (*self).write_to_string()
@@ -191,10 +226,22 @@ macro_rules! impl_write_smart_pointer {
                 core::borrow::Borrow::<T>::borrow(self).write_to_parts(sink)
             }
             #[inline]
+            fn write_to_utf16<W: Utf16Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+                core::borrow::Borrow::<T>::borrow(self).write_to_utf16(sink)
+            }
+            #[inline]
+            fn write_to_utf16_parts<W: Utf16PartsWrite + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+                core::borrow::Borrow::<T>::borrow(self).write_to_utf16_parts(sink)
+            }
+            #[inline]
             fn writeable_length_hint(&self) -> LengthHint {
                 core::borrow::Borrow::<T>::borrow(self).writeable_length_hint()
             }
             #[inline]
+            fn writeable_utf16_length_hint(&self) -> LengthHint {
+                core::borrow::Borrow::<T>::borrow(self).writeable_utf16_length_hint()
+            }
+            #[inline]
             fn write_to_string(&self) -> Cow<'_, str> {
                 core::borrow::Borrow::<T>::borrow(self).write_to_string()
             }
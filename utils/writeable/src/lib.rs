@@ -29,6 +29,9 @@
 //!
 //! This crate also exports [`TryWriteable`], a writeable that supports a custom error.
 //!
+//! Output can also be written to UTF-16 sinks implementing [`Utf16Write`]. By default this
+//! transcodes the UTF-8 output, but types can write UTF-16 directly where that is cheaper.
+//!
 //! # Benchmarks
 //!
 //! The benchmarks to generate the following data can be found in the `benches` directory.
@@ -87,6 +90,7 @@ mod parts_write_adapter;
 mod testing;
 mod to_string_or_borrow;
 mod try_writeable;
+mod utf16;
 
 use alloc::borrow::Cow;
 use alloc::string::String;
@@ -95,6 +99,7 @@ use core::fmt;
 pub use cmp::{cmp_str, cmp_utf8};
 pub use to_string_or_borrow::to_string_or_borrow;
 pub use try_writeable::TryWriteable;
+pub use utf16::{Utf16PartsWrite, Utf16Write};
 
 /// Helper types for trait impls.
 pub mod adapters {
@@ -131,7 +136,9 @@ pub mod adapters {
 #[doc(hidden)] // for testing and macros
 pub mod _internal {
     pub use super::testing::try_writeable_to_parts_for_test;
+    pub use super::testing::utf8_to_utf16_parts_for_test;
     pub use super::testing::writeable_to_parts_for_test;
+    pub use super::testing::writeable_to_utf16_parts_for_test;
     pub use alloc::string::String;
 }
 
@@ -273,6 +280,43 @@ pub trait Writeable {
         LengthHint::undefined()
     }
 
+    /// Writes a string to the given UTF-16 sink. Errors from the sink are bubbled up.
+    /// The default implementation delegates to `write_to_utf16_parts`, and discards any
+    /// `Part` annotations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use writeable::Writeable;
+    ///
+    /// let mut buffer = Vec::<u16>::new();
+    /// "αβγ".write_to_utf16(&mut buffer).unwrap();
+    ///
+    /// assert_eq!(buffer, [0x3B1, 0x3B2, 0x3B3]);
+    /// ```
+    fn write_to_utf16<W: Utf16Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.write_to_utf16_parts(&mut utf16::Utf16WriteAsUtf16PartsWrite(sink))
+    }
+
+    /// Write UTF-16 code units and `Part` annotations to the given sink. Errors from the
+    /// sink are bubbled up. The default implementation transcodes the output of
+    /// `write_to_parts`.
+    ///
+    /// Override this method (and [`Self::write_to_utf16`] if it can be done more efficiently)
+    /// if the UTF-16 output can be produced without transcoding, for example if the
+    /// underlying data is stored as UTF-16.
+    fn write_to_utf16_parts<S: Utf16PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        self.write_to_parts(utf16::Utf16PartsWriteAsPartsWrite::from_mut(sink))
+    }
+
+    /// Returns a hint for the number of UTF-16 code units that will be written to the sink.
+    ///
+    /// The default implementation derives a hint from [`Self::writeable_length_hint`].
+    /// Override this method if a tighter hint can be computed quickly.
+    fn writeable_utf16_length_hint(&self) -> LengthHint {
+        utf16::utf16_length_hint(self.writeable_length_hint())
+    }
+
     /// Creates a new `String` with the data from this `Writeable`. Like `ToString`,
     /// but smaller and faster.
     ///
@@ -374,6 +418,7 @@ macro_rules! impl_display_with_writeable {
 /// - Equality of string content
 /// - Equality of parts ([`*_parts_eq`] only)
 /// - Validity of size hint
+/// - Equality of UTF-16 output, parts, and validity of the UTF-16 size hint
 ///
 /// # Examples
 ///
@@ -431,6 +476,7 @@ macro_rules! assert_writeable_eq {
         let actual_writeable = &$actual_writeable;
         let (actual_str, actual_parts) = $crate::_internal::writeable_to_parts_for_test(actual_writeable);
         let actual_len = actual_str.len();
+        let (expected_utf16, expected_utf16_parts) = $crate::_internal::utf8_to_utf16_parts_for_test(&actual_str, &actual_parts);
         assert_eq!(actual_str, $expected_str, $($arg)*);
         assert_eq!(actual_str, $crate::Writeable::write_to_string(actual_writeable), $($arg)+);
         let length_hint = $crate::Writeable::writeable_length_hint(actual_writeable);
@@ -448,6 +494,28 @@ macro_rules! assert_writeable_eq {
             );
         }
         assert_eq!(actual_writeable.to_string(), $expected_str);
+        let (actual_utf16, actual_utf16_parts) = $crate::_internal::writeable_to_utf16_parts_for_test(actual_writeable);
+        assert_eq!(
+            (&actual_utf16, &actual_utf16_parts),
+            (&expected_utf16, &expected_utf16_parts),
+            "UTF-16 output does not match UTF-8 output: {}",
+            format!($($arg)*),
+        );
+        let actual_utf16_len = actual_utf16.len();
+        let utf16_length_hint = $crate::Writeable::writeable_utf16_length_hint(actual_writeable);
+        let lower = utf16_length_hint.0;
+        assert!(
+            lower <= actual_utf16_len,
+            "UTF-16 hint lower bound {lower} larger than actual length {actual_utf16_len}: {}",
+            format!($($arg)*),
+        );
+        if let Some(upper) = utf16_length_hint.1 {
+            assert!(
+                actual_utf16_len <= upper,
+                "UTF-16 hint upper bound {upper} smaller than actual length {actual_utf16_len}: {}",
+                format!($($arg)*),
+            );
+        }
         actual_parts // return for assert_writeable_parts_eq
     }};
 }
@@ -96,11 +96,26 @@ impl<T: Writeable + ?Sized> Writeable for WithPart<T> {
         sink.with_part(self.part, |w| self.writeable.write_to_parts(w))
     }
 
+    #[inline]
+    fn write_to_utf16<W: Utf16Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.writeable.write_to_utf16(sink)
+    }
+
+    #[inline]
+    fn write_to_utf16_parts<W: Utf16PartsWrite + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        sink.with_part(self.part, |w| self.writeable.write_to_utf16_parts(w))
+    }
+
     #[inline]
     fn writeable_length_hint(&self) -> LengthHint {
         self.writeable.writeable_length_hint()
     }
 
+    #[inline]
+    fn writeable_utf16_length_hint(&self) -> LengthHint {
+        self.writeable.writeable_utf16_length_hint()
+    }
+
     #[inline]
     fn write_to_string(&self) -> Cow<'_, str> {
         self.writeable.write_to_string()
@@ -75,3 +75,68 @@ pub fn try_writeable_to_parts_for_test<W: TryWriteable>(
     let (actual_str, actual_parts) = writer.finish();
     (actual_str, actual_parts, result.err())
 }
+
+pub(crate) struct Utf16TestWriter {
+    pub(crate) string: Vec<u16>,
+    pub(crate) parts: Vec<(usize, usize, Part)>,
+}
+
+impl Utf16Write for Utf16TestWriter {
+    fn write_utf16(&mut self, s: &[u16]) -> fmt::Result {
+        self.string.write_utf16(s)
+    }
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        Utf16Write::write_str(&mut self.string, s)
+    }
+}
+
+impl Utf16PartsWrite for Utf16TestWriter {
+    type SubPartsWrite = Self;
+    fn with_part(
+        &mut self,
+        part: Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> fmt::Result,
+    ) -> fmt::Result {
+        let start = self.string.len();
+        f(self)?;
+        let end = self.string.len();
+        if start < end {
+            self.parts.push((start, end, part));
+        }
+        Ok(())
+    }
+}
+
+pub fn writeable_to_utf16_parts_for_test<W: Writeable>(
+    writeable: &W,
+) -> (Vec<u16>, Vec<(usize, usize, Part)>) {
+    let mut writer = Utf16TestWriter {
+        string: Vec::new(),
+        parts: Vec::new(),
+    };
+    #[expect(clippy::expect_used)] // for test code
+    writeable
+        .write_to_utf16_parts(&mut writer)
+        .expect("Vec writer infallible");
+    // Sort by first open and last closed
+    writer
+        .parts
+        .sort_unstable_by_key(|(begin, end, _)| (*begin, end.wrapping_neg()));
+    (writer.string, writer.parts)
+}
+
+/// Converts a string and parts with UTF-8 indices into the equivalent UTF-16 string
+/// and parts with UTF-16 indices.
+pub fn utf8_to_utf16_parts_for_test(
+    s: &str,
+    parts: &[(usize, usize, Part)],
+) -> (Vec<u16>, Vec<(usize, usize, Part)>) {
+    let utf16_index = |i: usize| s.get(..i).map(|p| p.encode_utf16().count()).unwrap_or(i);
+    (
+        s.encode_utf16().collect(),
+        parts
+            .iter()
+            .map(|&(begin, end, part)| (utf16_index(begin), utf16_index(end), part))
+            .collect(),
+    )
+}
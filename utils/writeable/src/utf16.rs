@@ -0,0 +1,214 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::*;
+use alloc::vec::Vec;
+
+/// A sink for UTF-16 code units, the UTF-16 analog of [`fmt::Write`].
+///
+/// Only [`write_utf16`](Utf16Write::write_utf16) needs to be implemented; the other
+/// methods transcode UTF-8 input into UTF-16 before passing it on.
+///
+/// A surrogate pair is never split across calls to [`write_utf16`](Utf16Write::write_utf16)
+/// by the default implementations.
+///
+/// # Examples
+///
+/// ```
+/// use writeable::Utf16Write;
+///
+/// let mut buffer = Vec::<u16>::new();
+/// buffer.write_str("a😀").unwrap();
+/// buffer.write_utf16(&[0x62]).unwrap();
+///
+/// assert_eq!(String::from_utf16(&buffer).unwrap(), "a😀b");
+/// ```
+pub trait Utf16Write {
+    /// Writes a slice of UTF-16 code units into this sink.
+    fn write_utf16(&mut self, s: &[u16]) -> fmt::Result;
+
+    /// Writes a string slice into this sink, transcoding it to UTF-16.
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut buffer = [0u16; 32];
+        let mut len = 0;
+        for c in s.chars() {
+            if len + 2 > buffer.len() {
+                #[expect(clippy::indexing_slicing)] // len <= buffer.len()
+                self.write_utf16(&buffer[..len])?;
+                len = 0;
+            }
+            #[expect(clippy::indexing_slicing)] // at least two slots are free
+            let encoded = c.encode_utf16(&mut buffer[len..]);
+            len += encoded.len();
+        }
+        if len == 0 {
+            return Ok(());
+        }
+        #[expect(clippy::indexing_slicing)] // len <= buffer.len()
+        self.write_utf16(&buffer[..len])
+    }
+
+    /// Writes a [`char`] into this sink, transcoding it to UTF-16.
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.write_utf16(c.encode_utf16(&mut [0u16; 2]))
+    }
+}
+
+impl Utf16Write for Vec<u16> {
+    #[inline]
+    fn write_utf16(&mut self, s: &[u16]) -> fmt::Result {
+        self.extend_from_slice(s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.extend(s.encode_utf16());
+        Ok(())
+    }
+}
+
+impl<W: Utf16Write + ?Sized> Utf16Write for &mut W {
+    #[inline]
+    fn write_utf16(&mut self, s: &[u16]) -> fmt::Result {
+        (**self).write_utf16(s)
+    }
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        (**self).write_str(s)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        (**self).write_char(c)
+    }
+}
+
+/// A UTF-16 sink that supports annotating parts of the string with [`Part`]s.
+///
+/// This is the UTF-16 analog of [`PartsWrite`]. Implementations should report
+/// part boundaries as indices in UTF-16 code units.
+pub trait Utf16PartsWrite: Utf16Write {
+    type SubPartsWrite: Utf16PartsWrite + ?Sized;
+
+    /// Annotates all strings written by the closure with the given [`Part`].
+    fn with_part(
+        &mut self,
+        part: Part,
+        f: impl FnMut(&mut Self::SubPartsWrite) -> fmt::Result,
+    ) -> fmt::Result;
+}
+
+/// Converts a hint in UTF-8 bytes into a hint in UTF-16 code units.
+///
+/// Every UTF-16 code unit corresponds to between one and three UTF-8 bytes.
+pub(crate) fn utf16_length_hint(utf8: LengthHint) -> LengthHint {
+    LengthHint(utf8.0.div_ceil(3), utf8.1)
+}
+
+/// A wrapper around a type implementing [`Utf16Write`] that implements [`Utf16PartsWrite`]
+/// by discarding all parts.
+pub(crate) struct Utf16WriteAsUtf16PartsWrite<W: Utf16Write + ?Sized>(pub(crate) W);
+
+impl<W: Utf16Write + ?Sized> Utf16Write for Utf16WriteAsUtf16PartsWrite<W> {
+    #[inline]
+    fn write_utf16(&mut self, s: &[u16]) -> fmt::Result {
+        self.0.write_utf16(s)
+    }
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write_str(s)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.0.write_char(c)
+    }
+}
+
+impl<W: Utf16Write + ?Sized> Utf16PartsWrite for Utf16WriteAsUtf16PartsWrite<W> {
+    type SubPartsWrite = Self;
+
+    #[inline]
+    fn with_part(
+        &mut self,
+        _part: Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> fmt::Result,
+    ) -> fmt::Result {
+        f(self)
+    }
+}
+
+/// A wrapper around a type implementing [`Utf16PartsWrite`] that implements [`PartsWrite`]
+/// by transcoding everything written to it.
+#[repr(transparent)]
+pub(crate) struct Utf16PartsWriteAsPartsWrite<W: Utf16PartsWrite + ?Sized>(W);
+
+impl<W: Utf16PartsWrite + ?Sized> Utf16PartsWriteAsPartsWrite<W> {
+    #[inline]
+    pub(crate) fn from_mut(sink: &mut W) -> &mut Self {
+        // Safety: Self is repr(transparent) over W
+        unsafe { &mut *(sink as *mut W as *mut Self) }
+    }
+}
+
+impl<W: Utf16PartsWrite + ?Sized> fmt::Write for Utf16PartsWriteAsPartsWrite<W> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write_str(s)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.0.write_char(c)
+    }
+}
+
+impl<W: Utf16PartsWrite + ?Sized> PartsWrite for Utf16PartsWriteAsPartsWrite<W> {
+    type SubPartsWrite = Utf16PartsWriteAsPartsWrite<W::SubPartsWrite>;
+
+    #[inline]
+    fn with_part(
+        &mut self,
+        part: Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> fmt::Result,
+    ) -> fmt::Result {
+        self.0
+            .with_part(part, |sink| f(Utf16PartsWriteAsPartsWrite::from_mut(sink)))
+    }
+}
+
+#[test]
+fn test_length_hint() {
+    for s in ["", "abc", "αβγ", "你好", "😀", "a😀β你"] {
+        let hint = utf16_length_hint(LengthHint::exact(s.len()));
+        let len = s.encode_utf16().count();
+        assert!(hint.0 <= len, "{s}");
+        assert!(hint.1.is_none_or(|upper| len <= upper), "{s}");
+    }
+}
+
+#[test]
+fn test_write_str_chunks() {
+    struct Chunks(Vec<Vec<u16>>);
+
+    impl Utf16Write for Chunks {
+        fn write_utf16(&mut self, s: &[u16]) -> fmt::Result {
+            self.0.push(s.to_vec());
+            Ok(())
+        }
+    }
+
+    let s = "😀".repeat(40);
+    let mut chunks = Chunks(Vec::new());
+    chunks.write_str(&s).unwrap();
+    assert!(chunks.0.len() > 1);
+    for chunk in &chunks.0 {
+        // No chunk ends with a high surrogate
+        assert!(!chunk.last().is_some_and(|u| (0xD800..0xDC00).contains(u)));
+    }
+    assert_eq!(chunks.0.concat(), s.encode_utf16().collect::<Vec<_>>());
+}
@@ -3,8 +3,14 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use std::fmt;
+use std::fmt::Write;
 use writeable::assert_writeable_eq;
+use writeable::assert_writeable_parts_eq;
 use writeable::LengthHint;
+use writeable::Part;
+use writeable::PartsWrite;
+use writeable::Utf16PartsWrite;
+use writeable::Utf16Write;
 use writeable::Writeable;
 
 /// A sample type implementing Writeable
@@ -32,3 +38,69 @@ fn test_basic() {
     };
     assert_writeable_eq!(&message, input_string);
 }
+
+/// A sample type that stores UTF-16 and implements the UTF-16 methods natively
+struct Utf16Message<'s> {
+    greeting: &'s [u16],
+    name: &'s [u16],
+}
+
+const GREETING: Part = Part {
+    category: "message",
+    value: "greeting",
+};
+
+const NAME: Part = Part {
+    category: "message",
+    value: "name",
+};
+
+impl Writeable for Utf16Message<'_> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        sink.with_part(GREETING, |w| {
+            w.write_str(&String::from_utf16_lossy(self.greeting))
+        })?;
+        sink.write_str(", ")?;
+        sink.with_part(NAME, |w| w.write_str(&String::from_utf16_lossy(self.name)))
+    }
+
+    fn write_to_utf16_parts<S: Utf16PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        sink.with_part(GREETING, |w| w.write_utf16(self.greeting))?;
+        sink.write_str(", ")?;
+        sink.with_part(NAME, |w| w.write_utf16(self.name))
+    }
+
+    fn writeable_utf16_length_hint(&self) -> LengthHint {
+        LengthHint::exact(self.greeting.len() + 2 + self.name.len())
+    }
+}
+
+writeable::impl_display_with_writeable!(Utf16Message<'_>);
+
+#[test]
+fn test_utf16() {
+    let greeting = "Grüß".encode_utf16().collect::<Vec<_>>();
+    let name = "😀 Ünal".encode_utf16().collect::<Vec<_>>();
+    let message = Utf16Message {
+        greeting: &greeting,
+        name: &name,
+    };
+
+    // Also checks the UTF-16 output and parts against the UTF-8 ones
+    assert_writeable_parts_eq!(&message, "Grüß, 😀 Ünal", [(0, 6, GREETING), (8, 18, NAME)]);
+
+    let mut utf16 = Vec::new();
+    message.write_to_utf16(&mut utf16).unwrap();
+    assert_eq!(String::from_utf16(&utf16).unwrap(), "Grüß, 😀 Ünal");
+    assert_eq!(
+        message.writeable_utf16_length_hint(),
+        LengthHint::exact(utf16.len())
+    );
+
+    // Types without a native implementation are transcoded
+    let mut utf16 = Vec::new();
+    WriteableMessage { message: "Grüß" }
+        .write_to_utf16(&mut utf16)
+        .unwrap();
+    assert_eq!(utf16, greeting);
+}
@@ -189,10 +189,136 @@ fn get_subtags_bench_helper<M: criterion::measurement::Measurement>(
     g.finish();
 }
 
+fn prefix_subtags_bench(c: &mut Criterion) {
+    let mut g = c.benchmark_group("prefix/subtags_full");
+
+    let strings = testdata::short_subtags::STRINGS;
+    let litemap = testdata::strings_to_litemap(strings);
+    let prefixes = ["", "a", "en", "s", "zh", "zz"];
+
+    // Baseline: filter the full iterator
+    g.bench_function("SimpleAscii/iter", |b| {
+        let trie = ZeroTrieSimpleAscii::try_from(&litemap).unwrap();
+        b.iter(|| {
+            for prefix in black_box(prefixes) {
+                black_box(
+                    black_box(&trie)
+                        .iter()
+                        .filter(|(k, _)| k.starts_with(prefix))
+                        .count(),
+                );
+            }
+        });
+    });
+
+    g.bench_function("SimpleAscii/iter_prefix", |b| {
+        let trie = ZeroTrieSimpleAscii::try_from(&litemap).unwrap();
+        b.iter(|| {
+            for prefix in black_box(prefixes) {
+                black_box(black_box(&trie).iter_prefix(prefix).count());
+            }
+        });
+    });
+
+    g.bench_function("SimpleAscii/prefix_values", |b| {
+        let trie = ZeroTrieSimpleAscii::try_from(&litemap).unwrap();
+        b.iter(|| {
+            for prefix in black_box(prefixes) {
+                black_box(black_box(&trie).prefix_values(prefix).count());
+            }
+        });
+    });
+
+    g.bench_function("PerfectHash/iter", |b| {
+        let trie = ZeroTriePerfectHash::try_from(&litemap).unwrap();
+        b.iter(|| {
+            for prefix in black_box(prefixes) {
+                black_box(
+                    black_box(&trie)
+                        .iter()
+                        .filter(|(k, _)| k.starts_with(prefix.as_bytes()))
+                        .count(),
+                );
+            }
+        });
+    });
+
+    g.bench_function("PerfectHash/iter_prefix", |b| {
+        let trie = ZeroTriePerfectHash::try_from(&litemap).unwrap();
+        b.iter(|| {
+            for prefix in black_box(prefixes) {
+                black_box(black_box(&trie).iter_prefix(prefix).count());
+            }
+        });
+    });
+
+    g.bench_function("PerfectHash/prefix_values", |b| {
+        let trie = ZeroTriePerfectHash::try_from(&litemap).unwrap();
+        b.iter(|| {
+            for prefix in black_box(prefixes) {
+                black_box(black_box(&trie).prefix_values(prefix).count());
+            }
+        });
+    });
+
+    g.finish();
+}
+
+fn longest_prefix_subtags_bench(c: &mut Criterion) {
+    let mut g = c.benchmark_group("longest_prefix/subtags_full");
+
+    let strings = testdata::short_subtags::STRINGS;
+    let litemap = testdata::strings_to_litemap(strings);
+    let queries: Vec<String> = strings.iter().map(|s| format!("{s}-Latn-US")).collect();
+
+    // Baseline: step a cursor through the query
+    g.bench_function("SimpleAscii/cursor", |b| {
+        let trie = ZeroTrieSimpleAscii::try_from(&litemap).unwrap();
+        b.iter(|| {
+            for query in black_box(&queries) {
+                let mut longest_prefix = None;
+                let mut cursor = black_box(&trie).cursor();
+                for (i, b) in query.bytes().enumerate() {
+                    if cursor.is_empty() {
+                        break;
+                    }
+                    if let Some(value) = cursor.take_value() {
+                        longest_prefix = Some((i, value));
+                    }
+                    cursor.step(b);
+                }
+                black_box(longest_prefix);
+            }
+        });
+    });
+
+    g.bench_function("SimpleAscii/get_longest_prefix", |b| {
+        let trie = ZeroTrieSimpleAscii::try_from(&litemap).unwrap();
+        b.iter(|| {
+            for query in black_box(&queries) {
+                black_box(black_box(&trie).get_longest_prefix(query));
+            }
+        });
+    });
+
+    g.bench_function("PerfectHash/get_longest_prefix", |b| {
+        let trie = ZeroTriePerfectHash::try_from(&litemap).unwrap();
+        b.iter(|| {
+            for query in black_box(&queries) {
+                black_box(black_box(&trie).get_longest_prefix(query));
+            }
+        });
+    });
+
+    g.finish();
+}
+
 criterion_group!(
     benches,
     get_basic_bench,
     get_subtags_bench_medium,
-    get_subtags_bench_large
+    get_subtags_bench_large,
+    prefix_subtags_bench,
+    longest_prefix_subtags_bench
 );
 criterion_main!(benches);
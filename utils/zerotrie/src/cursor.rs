@@ -8,6 +8,7 @@
 //! and the `Cursor` types in this module.
 
 use crate::reader;
+use crate::reader::ZeroTrieValueIterator;
 use crate::ZeroAsciiIgnoreCaseTrie;
 use crate::ZeroTrieSimpleAscii;
 
@@ -67,6 +68,9 @@ where
     ///
     /// // The longest prefix is "abc" which is length 3:
     /// assert_eq!(longest_prefix, 3);
+    ///
+    /// // The same can be done with a single call:
+    /// assert_eq!(trie.cursor().get_longest_prefix(query), Some((3, 0)));
     /// ```
     #[inline]
     pub fn cursor(&self) -> ZeroTrieSimpleAsciiCursor<'_> {
@@ -159,7 +163,7 @@ pub struct AsciiProbeResult {
     pub total_siblings: u8,
 }
 
-impl ZeroTrieSimpleAsciiCursor<'_> {
+impl<'a> ZeroTrieSimpleAsciiCursor<'a> {
     /// Steps the cursor one character into the trie based on the character's byte value.
     ///
     /// # Examples
@@ -341,9 +345,69 @@ impl ZeroTrieSimpleAsciiCursor<'_> {
     pub fn is_empty(&self) -> bool {
         self.trie.is_empty()
     }
+
+    /// Finds the longest prefix of `input` such that the cursor's current position
+    /// followed by that prefix is contained in the trie, returning the length of
+    /// the prefix in bytes together with its value.
+    ///
+    /// The cursor is not stepped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerotrie::ZeroTrieSimpleAscii;
+    ///
+    /// // A trie with two values: "abc" and "abcdef"
+    /// let trie = ZeroTrieSimpleAscii::from_bytes(b"abc\x80def\x81");
+    ///
+    /// let mut cursor = trie.cursor();
+    /// cursor.step(b'a');
+    /// cursor.step(b'b');
+    ///
+    /// // "ab" followed by "cdefgh" has the longest prefix "abcdef"
+    /// assert_eq!(cursor.get_longest_prefix(b"cdefgh"), Some((4, 1)));
+    /// // "ab" followed by "cdxy" has the longest prefix "abc"
+    /// assert_eq!(cursor.get_longest_prefix(b"cdxy"), Some((1, 0)));
+    /// assert_eq!(cursor.get_longest_prefix(b"x"), None);
+    /// ```
+    pub fn get_longest_prefix(&self, input: &[u8]) -> Option<(usize, usize)> {
+        reader::get_longest_prefix_parameterized::<ZeroTrieSimpleAscii<[u8]>>(
+            self.trie.store,
+            input,
+        )
+    }
+
+    /// Returns an iterator over the values of all strings that start with the cursor's
+    /// current position, including the value at the current position (unless it was
+    /// already taken with [`Self::take_value()`]).
+    ///
+    /// The iterator does not allocate and uses constant memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerotrie::ZeroTrieSimpleAscii;
+    ///
+    /// // A trie with three values: "abc", "abcdef", and "abx"
+    /// let trie: ZeroTrieSimpleAscii<Vec<u8>> =
+    ///     [("abc", 0), ("abcdef", 1), ("abx", 2)].into_iter().collect();
+    ///
+    /// let mut cursor = trie.cursor();
+    /// cursor.step(b'a');
+    /// cursor.step(b'b');
+    /// assert!(cursor.values().eq([0, 1, 2]));
+    ///
+    /// cursor.step(b'c');
+    /// assert!(cursor.values().eq([0, 1]));
+    /// assert_eq!(cursor.take_value(), Some(0));
+    /// assert!(cursor.values().eq([1]));
+    /// ```
+    pub fn values(&self) -> ZeroTrieValueIterator<'a> {
+        ZeroTrieValueIterator::new(self.trie.store, false)
+    }
 }
 
-impl ZeroAsciiIgnoreCaseTrieCursor<'_> {
+impl<'a> ZeroAsciiIgnoreCaseTrieCursor<'a> {
     /// Steps the cursor one byte into the trie.
     ///
     /// Returns the byte if matched, which may be a different case than the input byte.
@@ -409,6 +473,25 @@ impl ZeroAsciiIgnoreCaseTrieCursor<'_> {
     pub fn is_empty(&self) -> bool {
         self.trie.is_empty()
     }
+
+    /// Finds the longest prefix of `input` such that the cursor's current position
+    /// followed by that prefix is contained in the trie, ignoring case.
+    ///
+    /// For more details, see [`ZeroTrieSimpleAsciiCursor::get_longest_prefix`].
+    pub fn get_longest_prefix(&self, input: &[u8]) -> Option<(usize, usize)> {
+        reader::get_longest_prefix_parameterized::<ZeroAsciiIgnoreCaseTrie<[u8]>>(
+            self.trie.store,
+            input,
+        )
+    }
+
+    /// Returns an iterator over the values of all strings that start with the cursor's
+    /// current position.
+    ///
+    /// For more details, see [`ZeroTrieSimpleAsciiCursor::values`].
+    pub fn values(&self) -> ZeroTrieValueIterator<'a> {
+        ZeroTrieValueIterator::new(self.trie.store, false)
+    }
 }
 
 impl fmt::Write for ZeroTrieSimpleAsciiCursor<'_> {
@@ -78,6 +78,7 @@ pub use error::ZeroTrieBuildError;
 pub use crate::zerotrie::ZeroTrieStringIterator;
 #[cfg(feature = "alloc")]
 pub use reader::ZeroTrieIterator;
+pub use reader::ZeroTrieValueIterator;
 
 #[doc(hidden)]
 pub mod _internal {
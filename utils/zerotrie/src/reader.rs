@@ -616,6 +616,233 @@ pub(crate) fn take_value(trie: &mut &[u8]) -> Option<usize> {
     }
 }
 
+/// Steps through a branch node, given the branch node's varint `x` and the trie after the
+/// varint, returning the matched byte and the sub-trie of the matched edge.
+///
+/// The matched byte may differ from `c` in case for ignore-case tries.
+#[inline]
+fn step_branch_parameterized<T: ZeroTrieWithOptions + ?Sized>(
+    mut trie: &[u8],
+    x: usize,
+    c: u8,
+) -> Option<(u8, &[u8])> {
+    let (x, w) = if x >= 256 { (x & 0xff, x >> 8) } else { (x, 0) };
+    let w = if matches!(T::OPTIONS.capacity_mode, CapacityMode::Extended) {
+        w
+    } else {
+        // See the table above `step_parameterized` regarding this assertion
+        debug_assert!(w <= 3, "get: w > 3 but we assume w <= 3");
+        w & 0x3
+    };
+    let x = if x == 0 { 256 } else { x };
+    let (search, i, matched);
+    if matches!(T::OPTIONS.phf_mode, PhfMode::BinaryOnly) || x < 16 {
+        // binary search
+        (search, trie) = trie.debug_split_at(x);
+        let bsearch_result = if matches!(T::OPTIONS.case_sensitivity, CaseSensitivity::IgnoreCase) {
+            search.binary_search_by_key(&c.to_ascii_lowercase(), |x| x.to_ascii_lowercase())
+        } else {
+            search.binary_search(&c)
+        };
+        i = bsearch_result.ok()?;
+        matched = *search.get(i).debug_unwrap_or(&c);
+    } else {
+        // phf
+        (search, trie) = trie.debug_split_at(x * 2 + 1);
+        i = PerfectByteHashMap::from_store(search).get(c)?;
+        matched = c;
+    }
+    let trie = if w == 0 {
+        get_branch_w0(trie, i, x)
+    } else {
+        get_branch(trie, i, x, w)
+    };
+    Some((matched, trie))
+}
+
+/// Walks the trie along `prefix`, returning the sub-trie containing the suffixes of all
+/// strings that start with `prefix`, or `None` if there are no such strings.
+///
+/// `on_match` is called with the trie bytes matched by the prefix, which may differ from the
+/// prefix in case for ignore-case tries. If the prefix ends inside of a span node, `on_match`
+/// receives the whole span, and the returned sub-trie starts after the span.
+pub(crate) fn get_subtrie_parameterized<'a, T: ZeroTrieWithOptions + ?Sized>(
+    mut trie: &'a [u8],
+    mut prefix: &[u8],
+    mut on_match: impl FnMut(&[u8]),
+) -> Option<&'a [u8]> {
+    loop {
+        let Some((c, temp)) = prefix.split_first() else {
+            return Some(trie);
+        };
+        let (b, x);
+        (b, trie) = trie.split_first()?;
+        match byte_type(*b) {
+            NodeType::Ascii => {
+                let is_match = if matches!(T::OPTIONS.case_sensitivity, CaseSensitivity::IgnoreCase)
+                {
+                    b.eq_ignore_ascii_case(c)
+                } else {
+                    b == c
+                };
+                if !is_match {
+                    return None;
+                }
+                on_match(core::slice::from_ref(b));
+                prefix = temp;
+            }
+            NodeType::Span => {
+                if !matches!(T::OPTIONS.ascii_mode, AsciiMode::BinarySpans) {
+                    debug_assert!(false, "Span node found in ASCII trie!");
+                    return None;
+                }
+                let trie_span;
+                (x, trie) = read_varint_meta3(*b, trie);
+                (trie_span, trie) = trie.debug_split_at(x);
+                let len = Ord::min(x, prefix.len());
+                let (prefix_span, rest) = prefix.debug_split_at(len);
+                if trie_span.get(..len) != Some(prefix_span) {
+                    return None;
+                }
+                on_match(trie_span);
+                prefix = rest;
+            }
+            NodeType::Value => {
+                // Value node, but not at end of prefix
+                (_, trie) = read_varint_meta3(*b, trie);
+            }
+            NodeType::Branch => {
+                let matched;
+                (x, trie) = read_varint_meta2(*b, trie);
+                (matched, trie) = step_branch_parameterized::<T>(trie, x, *c)?;
+                on_match(&[matched]);
+                prefix = temp;
+            }
+        }
+    }
+}
+
+/// Finds the longest prefix of `ascii` that is contained in the trie, returning its
+/// length and value.
+pub(crate) fn get_longest_prefix_parameterized<T: ZeroTrieWithOptions + ?Sized>(
+    mut trie: &[u8],
+    ascii: &[u8],
+) -> Option<(usize, usize)> {
+    let mut result = None;
+    let mut remaining = ascii;
+    loop {
+        let (b, x);
+        (b, trie) = match trie.split_first() {
+            Some(v) => v,
+            None => return result,
+        };
+        let byte_type = byte_type(*b);
+        if matches!(byte_type, NodeType::Value) {
+            // The string consumed so far is in the trie
+            (x, trie) = read_varint_meta3(*b, trie);
+            result = Some((ascii.len() - remaining.len(), x));
+            continue;
+        }
+        let Some((c, temp)) = remaining.split_first() else {
+            return result;
+        };
+        match byte_type {
+            NodeType::Ascii => {
+                let is_match = if matches!(T::OPTIONS.case_sensitivity, CaseSensitivity::IgnoreCase)
+                {
+                    b.eq_ignore_ascii_case(c)
+                } else {
+                    b == c
+                };
+                if !is_match {
+                    return result;
+                }
+                remaining = temp;
+            }
+            NodeType::Span => {
+                if !matches!(T::OPTIONS.ascii_mode, AsciiMode::BinarySpans) {
+                    debug_assert!(false, "Span node found in ASCII trie!");
+                    return result;
+                }
+                let (trie_span, ascii_span);
+                (x, trie) = read_varint_meta3(*b, trie);
+                (trie_span, trie) = trie.debug_split_at(x);
+                let Some(split) = remaining.split_at_checked(x) else {
+                    return result;
+                };
+                (ascii_span, remaining) = split;
+                if trie_span != ascii_span {
+                    return result;
+                }
+            }
+            NodeType::Value => {
+                debug_assert!(false, "value nodes are handled above");
+                return result;
+            }
+            NodeType::Branch => {
+                (x, trie) = read_varint_meta2(*b, trie);
+                trie = match step_branch_parameterized::<T>(trie, x, *c) {
+                    Some((_, trie)) => trie,
+                    None => return result,
+                };
+                remaining = temp;
+            }
+        }
+    }
+}
+
+/// Iterator type for walking the values contained in a ZeroTrie.
+///
+/// Unlike [`ZeroTrieIterator`], this does not reconstruct the keys, and it therefore
+/// iterates in constant memory without allocating.
+#[derive(Debug, Clone)]
+pub struct ZeroTrieValueIterator<'a> {
+    /// Whether the PHF is enabled on this trie.
+    use_phf: bool,
+    /// The remaining bytes of the trie. Since the nodes are laid out in iteration order,
+    /// a linear scan visits all values in order.
+    trie: &'a [u8],
+}
+
+impl<'a> ZeroTrieValueIterator<'a> {
+    pub(crate) fn new(trie: &'a [u8], use_phf: bool) -> Self {
+        Self { use_phf, trie }
+    }
+}
+
+impl Iterator for ZeroTrieValueIterator<'_> {
+    type Item = usize;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (b, x);
+            (b, self.trie) = self.trie.split_first()?;
+            match byte_type(*b) {
+                NodeType::Ascii => continue,
+                NodeType::Value => {
+                    (x, self.trie) = read_varint_meta3(*b, self.trie);
+                    return Some(x);
+                }
+                NodeType::Span => {
+                    (x, self.trie) = read_varint_meta3(*b, self.trie);
+                    (_, self.trie) = self.trie.debug_split_at(x);
+                }
+                NodeType::Branch => {
+                    // Skip the search table and the offset table; the first child follows.
+                    (x, self.trie) = read_varint_meta2(*b, self.trie);
+                    let (x, w) = if x >= 256 { (x & 0xff, x >> 8) } else { (x, 0) };
+                    let x = if x == 0 { 256 } else { x };
+                    let search_len = if x < 16 || !self.use_phf {
+                        x
+                    } else {
+                        x * 2 + 1
+                    };
+                    (_, self.trie) = self.trie.debug_split_at(search_len + (x - 1) * (w + 1));
+                }
+            }
+        }
+    }
+}
+
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
@@ -634,10 +861,11 @@ pub struct ZeroTrieIterator<'a> {
 
 #[cfg(feature = "alloc")]
 impl<'a> ZeroTrieIterator<'a> {
-    pub(crate) fn new<S: AsRef<[u8]> + ?Sized>(store: &'a S, use_phf: bool) -> Self {
+    /// Creates an iterator over `trie`, prepending `prefix` to all keys.
+    pub(crate) fn new(trie: &'a [u8], prefix: Vec<u8>, use_phf: bool) -> Self {
         ZeroTrieIterator {
             use_phf,
-            state: alloc::vec![(store.as_ref(), alloc::vec![], 0)],
+            state: alloc::vec![(trie, prefix, 0)],
         }
     }
 }
@@ -710,18 +938,19 @@ impl Iterator for ZeroTrieIterator<'_> {
 }
 
 #[cfg(feature = "alloc")]
-pub(crate) fn get_iter_phf<S: AsRef<[u8]> + ?Sized>(store: &S) -> ZeroTrieIterator<'_> {
-    ZeroTrieIterator::new(store, true)
+pub(crate) fn get_iter_phf(trie: &[u8], prefix: Vec<u8>) -> ZeroTrieIterator<'_> {
+    ZeroTrieIterator::new(trie, prefix, true)
 }
 
 /// # Panics
 /// Panics if the trie contains non-ASCII items.
 #[cfg(feature = "alloc")]
 #[expect(clippy::type_complexity)]
-pub(crate) fn get_iter_ascii_or_panic<S: AsRef<[u8]> + ?Sized>(
-    store: &S,
+pub(crate) fn get_iter_ascii_or_panic(
+    trie: &[u8],
+    prefix: Vec<u8>,
 ) -> core::iter::Map<ZeroTrieIterator<'_>, fn((Vec<u8>, usize)) -> (String, usize)> {
-    ZeroTrieIterator::new(store, false).map(|(k, v)| {
+    ZeroTrieIterator::new(trie, prefix, false).map(|(k, v)| {
         #[expect(clippy::unwrap_used)] // in signature of function
         let ascii_str = String::from_utf8(k).unwrap();
         (ascii_str, v)
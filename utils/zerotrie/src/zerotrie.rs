@@ -2,6 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use crate::options::{PhfMode, ZeroTrieWithOptions};
 use crate::reader;
 
 use core::borrow::Borrow;
@@ -322,6 +323,52 @@ macro_rules! impl_zerotrie_subtype {
             pub fn get<K>(&self, key: K) -> Option<usize> where K: AsRef<[u8]> {
                 reader::get_parameterized::<Self>(self.store.as_ref(), key.as_ref())
             }
+            /// Finds the longest prefix of `input` that is contained in the trie, returning
+            /// the length of the prefix in bytes together with its value.
+            ///
+            /// This is useful for greedy matching, such as tokenizing a string against a dictionary.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use zerotrie::", stringify!($name), ";")]
+            ///
+            /// // A trie with two values: "abc" and "abcdef"
+            #[doc = concat!("let trie: &", stringify!($name), "<[u8]> = ", stringify!($name), "::from_bytes(b\"abc\\x80def\\x81\");")]
+            ///
+            /// assert_eq!(trie.get_longest_prefix(b"abcdxy"), Some((3, 0)));
+            /// assert_eq!(trie.get_longest_prefix(b"abcdefgh"), Some((6, 1)));
+            /// assert_eq!(trie.get_longest_prefix(b"abxy"), None);
+            /// ```
+            pub fn get_longest_prefix<K>(&self, input: K) -> Option<(usize, usize)> where K: AsRef<[u8]> {
+                reader::get_longest_prefix_parameterized::<Self>(self.store.as_ref(), input.as_ref())
+            }
+            /// Returns an iterator over the values of all strings in the trie that start with `prefix`,
+            /// including `prefix` itself.
+            ///
+            /// The values are returned in the same order as [`Self::iter()`]. The iterator does not
+            /// allocate and uses constant memory. To also get the strings, use [`Self::iter_prefix()`].
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use zerotrie::", stringify!($name), ";")]
+            ///
+            /// // A trie with two values: "abc" and "abcdef"
+            #[doc = concat!("let trie: &", stringify!($name), "<[u8]> = ", stringify!($name), "::from_bytes(b\"abc\\x80def\\x81\");")]
+            ///
+            /// assert!(trie.prefix_values(b"ab").eq([0, 1]));
+            /// assert!(trie.prefix_values(b"abcd").eq([1]));
+            /// assert_eq!(trie.prefix_values(b"abx").next(), None);
+            /// ```
+            pub fn prefix_values<K>(&self, prefix: K) -> reader::ZeroTrieValueIterator<'_> where K: AsRef<[u8]> {
+                let trie = reader::get_subtrie_parameterized::<Self>(self.store.as_ref(), prefix.as_ref(), |_| ())
+                    .unwrap_or_default();
+                reader::ZeroTrieValueIterator::new(
+                    trie,
+                    matches!(<Self as ZeroTrieWithOptions>::OPTIONS.phf_mode, PhfMode::UsePhf),
+                )
+            }
             /// Returns `true` if the trie is empty.
             #[inline]
             pub fn is_empty(&self) -> bool {
@@ -414,7 +461,39 @@ macro_rules! impl_zerotrie_subtype {
             /// ```
             #[inline]
             pub fn iter(&self) -> $iter_ty {
-                 $iter_fn(self.as_bytes())
+                 $iter_fn(self.as_bytes(), Vec::new())
+            }
+            /// Returns an iterator over the key/value pairs in this trie whose keys start
+            /// with `prefix`, including `prefix` itself.
+            ///
+            /// ✨ *Enabled with the `alloc` Cargo feature.*
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use zerotrie::", stringify!($name), ";")]
+            ///
+            /// // A trie with two values: "abc" and "abcdef"
+            #[doc = concat!("let trie: &", stringify!($name), "<[u8]> = ", stringify!($name), "::from_bytes(b\"abc\\x80def\\x81\");")]
+            ///
+            /// let mut it = trie.iter_prefix(b"abcd");
+            /// assert_eq!(it.next(), Some(("abcdef".into(), 1)));
+            /// assert_eq!(it.next(), None);
+            ///
+            /// assert_eq!(trie.iter_prefix(b"ab").count(), 2);
+            /// assert_eq!(trie.iter_prefix(b"abx").count(), 0);
+            /// ```
+            pub fn iter_prefix<K>(&self, prefix: K) -> $iter_ty where K: AsRef<[u8]> {
+                let mut key = Vec::new();
+                let trie = reader::get_subtrie_parameterized::<Self>(
+                    self.as_bytes(),
+                    prefix.as_ref(),
+                    |matched| key.extend_from_slice(matched),
+                );
+                match trie {
+                    Some(trie) => $iter_fn(trie, key),
+                    None => $iter_fn(&[], Vec::new()),
+                }
             }
         }
         impl $name<[u8]> {
@@ -788,6 +867,24 @@ where
     {
         impl_dispatch!(&self, get(key))
     }
+    /// Finds the longest prefix of `input` that is contained in the trie, returning
+    /// the length of the prefix in bytes together with its value.
+    pub fn get_longest_prefix<K>(&self, input: K) -> Option<(usize, usize)>
+    where
+        K: AsRef<[u8]>,
+    {
+        impl_dispatch!(&self, get_longest_prefix(input))
+    }
+    /// Returns an iterator over the values of all strings in the trie that start with `prefix`,
+    /// including `prefix` itself.
+    ///
+    /// The iterator does not allocate and uses constant memory.
+    pub fn prefix_values<K>(&self, prefix: K) -> reader::ZeroTrieValueIterator<'_>
+    where
+        K: AsRef<[u8]>,
+    {
+        impl_dispatch!(&self, prefix_values(prefix))
+    }
     /// Returns `true` if the trie is empty.
     pub fn is_empty(&self) -> bool {
         impl_dispatch!(&self, is_empty())
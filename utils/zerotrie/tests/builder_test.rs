@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use litemap::LiteMap;
+use std::collections::BTreeSet;
 use zerotrie::ZeroTriePerfectHash;
 use zerotrie::ZeroTrieSimpleAscii;
 
@@ -23,6 +24,46 @@ macro_rules! assert_bytes_eq {
     };
 }
 
+/// Checks the prefix APIs against the contents of the trie, as returned by `iter()`.
+fn check_prefix_queries(
+    items: &LiteMap<&[u8], usize>,
+    iter: &[(Vec<u8>, usize)],
+    get_longest_prefix: impl Fn(&[u8]) -> Option<(usize, usize)>,
+    prefix_values: impl Fn(&[u8]) -> Vec<usize>,
+    iter_prefix: impl Fn(&[u8]) -> Vec<(Vec<u8>, usize)>,
+) {
+    let mut queries: BTreeSet<Vec<u8>> = NON_EXISTENT_STRINGS
+        .iter()
+        .map(|s| s.as_bytes().to_vec())
+        .collect();
+    for k in items.keys() {
+        // All byte prefixes of the key, which may end inside of a span
+        for i in 0..=k.len() {
+            queries.insert(k[..i].to_vec());
+        }
+        queries.insert([k, &b"x"[..]].concat());
+        queries.insert([k, &b"\xFF"[..]].concat());
+    }
+    for query in queries.iter() {
+        let expected = (0..=query.len())
+            .rev()
+            .find_map(|i| items.get(&query[..i]).map(|v| (i, *v)));
+        assert_eq!(get_longest_prefix(query), expected, "{query:?}");
+
+        let expected: Vec<(Vec<u8>, usize)> = iter
+            .iter()
+            .filter(|(k, _)| k.starts_with(query))
+            .cloned()
+            .collect();
+        assert_eq!(iter_prefix(query), expected, "{query:?}");
+        assert_eq!(
+            prefix_values(query),
+            expected.iter().map(|(_, v)| *v).collect::<Vec<_>>(),
+            "{query:?}"
+        );
+    }
+}
+
 fn check_simple_ascii_trie<S>(items: &LiteMap<&[u8], usize>, trie: &ZeroTrieSimpleAscii<S>)
 where
     S: AsRef<[u8]> + ?Sized,
@@ -43,6 +84,34 @@ where
     // Check that the const builder works
     let const_trie = ZeroTrieSimpleAscii::try_from_litemap_with_const_builder(items).unwrap();
     assert_eq!(trie.as_bytes(), const_trie.as_bytes());
+    // Check the prefix queries on the trie and on the cursor
+    let iter: Vec<_> = trie.iter().map(|(k, v)| (k.into_bytes(), v)).collect();
+    check_prefix_queries(
+        items,
+        &iter,
+        |s| trie.get_longest_prefix(s),
+        |s| trie.prefix_values(s).collect(),
+        |s| {
+            trie.iter_prefix(s)
+                .map(|(k, v)| (k.into_bytes(), v))
+                .collect()
+        },
+    );
+    check_prefix_queries(
+        items,
+        &iter,
+        |s| trie.cursor().get_longest_prefix(s),
+        |s| {
+            let mut cursor = trie.cursor();
+            s.iter().for_each(|b| cursor.step(*b));
+            cursor.values().collect()
+        },
+        |s| {
+            trie.iter_prefix(s)
+                .map(|(k, v)| (k.into_bytes(), v))
+                .collect()
+        },
+    );
 }
 
 fn check_phf_ascii_trie<S>(items: &LiteMap<&[u8], usize>, trie: &ZeroTriePerfectHash<S>)
@@ -64,6 +133,15 @@ where
         items.to_borrowed_keys_values::<[u8], usize, Vec<_>>(),
         recovered_items.to_borrowed_keys_values()
     );
+    // Check the prefix queries
+    let iter: Vec<_> = trie.iter().collect();
+    check_prefix_queries(
+        items,
+        &iter,
+        |s| trie.get_longest_prefix(s),
+        |s| trie.prefix_values(s).collect(),
+        |s| trie.iter_prefix(s).collect(),
+    );
 }
 
 fn check_phf_bytes_trie<S>(items: &LiteMap<&[u8], usize>, trie: &ZeroTriePerfectHash<S>)
@@ -85,6 +163,15 @@ where
         items.to_borrowed_keys_values::<[u8], usize, Vec<_>>(),
         recovered_items.to_borrowed_keys_values()
     );
+    // Check the prefix queries
+    let iter: Vec<_> = trie.iter().collect();
+    check_prefix_queries(
+        items,
+        &iter,
+        |s| trie.get_longest_prefix(s),
+        |s| trie.prefix_values(s).collect(),
+        |s| trie.iter_prefix(s).collect(),
+    );
 }
 
 #[test]
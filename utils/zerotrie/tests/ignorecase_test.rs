@@ -44,3 +44,28 @@ fn test_ignore_case_coverage() {
         ZeroAsciiIgnoreCaseTrie::try_from(&litemap).expect_err(problematic_str);
     }
 }
+
+#[test]
+fn test_ignore_case_prefix() {
+    let litemap = strings_to_litemap(&["", "aBc", "aBcD", "aBce", "aBcF", "aBcghi"]);
+    let trie = ZeroAsciiIgnoreCaseTrie::try_from(&litemap).unwrap();
+
+    assert_eq!(trie.get_longest_prefix("ABCGH"), Some((3, 1)));
+    assert_eq!(trie.get_longest_prefix("abcGHIjk"), Some((6, 5)));
+    assert_eq!(trie.get_longest_prefix("xyz"), Some((0, 0)));
+
+    assert!(trie.prefix_values("ABC").eq([1, 2, 3, 4, 5]));
+    assert!(trie.prefix_values("abcg").eq([5]));
+    assert_eq!(trie.prefix_values("abcx").next(), None);
+
+    // The keys are returned in the case stored in the trie
+    assert_eq!(
+        trie.iter_prefix("ABCF").collect::<Vec<_>>(),
+        [("aBcF".to_string(), 4)]
+    );
+
+    let mut cursor = trie.cursor();
+    cursor.step(b'A');
+    assert_eq!(cursor.get_longest_prefix(b"BCE"), Some((3, 3)));
+    assert!(cursor.values().eq([1, 2, 3, 4, 5]));
+}